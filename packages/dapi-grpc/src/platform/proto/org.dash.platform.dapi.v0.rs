@@ -832,6 +832,210 @@ pub mod get_identity_keys_response {
 #[derive(::dapi_grpc_macros::Mockable)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPendingTransfersRequest {
+    #[prost(oneof = "get_pending_transfers_request::Version", tags = "1")]
+    pub version: ::core::option::Option<get_pending_transfers_request::Version>,
+}
+/// Nested message and enum types in `GetPendingTransfersRequest`.
+pub mod get_pending_transfers_request {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[derive(::dapi_grpc_macros::Mockable)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct GetPendingTransfersRequestV0 {
+        #[prost(bytes = "vec", tag = "1")]
+        #[serde(with = "serde_bytes")]
+        pub identity_id: ::prost::alloc::vec::Vec<u8>,
+        /// Transfers sent by the identity instead of the transfers it receives
+        #[prost(bool, tag = "2")]
+        pub outgoing: bool,
+        #[prost(message, optional, tag = "3")]
+        pub limit: ::core::option::Option<u32>,
+        #[prost(bool, tag = "4")]
+        pub prove: bool,
+    }
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Version {
+        #[prost(message, tag = "1")]
+        V0(GetPendingTransfersRequestV0),
+    }
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(
+    ::dapi_grpc_macros::VersionedGrpcMessage,
+    ::dapi_grpc_macros::VersionedGrpcResponse
+)]
+#[grpc_versions(0)]
+#[derive(::dapi_grpc_macros::Mockable)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPendingTransfersResponse {
+    #[prost(oneof = "get_pending_transfers_response::Version", tags = "1")]
+    pub version: ::core::option::Option<get_pending_transfers_response::Version>,
+}
+/// Nested message and enum types in `GetPendingTransfersResponse`.
+pub mod get_pending_transfers_response {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[derive(::dapi_grpc_macros::Mockable)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct GetPendingTransfersResponseV0 {
+        #[prost(message, optional, tag = "3")]
+        pub metadata: ::core::option::Option<super::ResponseMetadata>,
+        #[prost(oneof = "get_pending_transfers_response_v0::Result", tags = "1, 2")]
+        pub result: ::core::option::Option<get_pending_transfers_response_v0::Result>,
+    }
+    /// Nested message and enum types in `GetPendingTransfersResponseV0`.
+    pub mod get_pending_transfers_response_v0 {
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        #[derive(::dapi_grpc_macros::Mockable)]
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct PendingTransfers {
+            #[prost(bytes = "vec", repeated, tag = "1")]
+            pub pending_transfers: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+        }
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Result {
+            #[prost(message, tag = "1")]
+            PendingTransfers(PendingTransfers),
+            #[prost(message, tag = "2")]
+            Proof(super::super::Proof),
+        }
+    }
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Version {
+        #[prost(message, tag = "1")]
+        V0(GetPendingTransfersResponseV0),
+    }
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(::dapi_grpc_macros::VersionedGrpcMessage)]
+#[grpc_versions(0)]
+#[derive(::dapi_grpc_macros::Mockable)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetIdentityWithdrawalsRequest {
+    #[prost(oneof = "get_identity_withdrawals_request::Version", tags = "1")]
+    pub version: ::core::option::Option<get_identity_withdrawals_request::Version>,
+}
+/// Nested message and enum types in `GetIdentityWithdrawalsRequest`.
+pub mod get_identity_withdrawals_request {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[derive(::dapi_grpc_macros::Mockable)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct GetIdentityWithdrawalsRequestV0 {
+        #[prost(bytes = "vec", tag = "1")]
+        #[serde(with = "serde_bytes")]
+        pub identity_id: ::prost::alloc::vec::Vec<u8>,
+        #[prost(message, optional, tag = "2")]
+        pub limit: ::core::option::Option<u32>,
+        #[prost(bool, tag = "3")]
+        pub prove: bool,
+    }
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Version {
+        #[prost(message, tag = "1")]
+        V0(GetIdentityWithdrawalsRequestV0),
+    }
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(
+    ::dapi_grpc_macros::VersionedGrpcMessage,
+    ::dapi_grpc_macros::VersionedGrpcResponse
+)]
+#[grpc_versions(0)]
+#[derive(::dapi_grpc_macros::Mockable)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetIdentityWithdrawalsResponse {
+    #[prost(oneof = "get_identity_withdrawals_response::Version", tags = "1")]
+    pub version: ::core::option::Option<get_identity_withdrawals_response::Version>,
+}
+/// Nested message and enum types in `GetIdentityWithdrawalsResponse`.
+pub mod get_identity_withdrawals_response {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[derive(::dapi_grpc_macros::Mockable)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct GetIdentityWithdrawalsResponseV0 {
+        #[prost(message, optional, tag = "3")]
+        pub metadata: ::core::option::Option<super::ResponseMetadata>,
+        #[prost(oneof = "get_identity_withdrawals_response_v0::Result", tags = "1, 2")]
+        pub result: ::core::option::Option<get_identity_withdrawals_response_v0::Result>,
+    }
+    /// Nested message and enum types in `GetIdentityWithdrawalsResponseV0`.
+    pub mod get_identity_withdrawals_response_v0 {
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        #[derive(::dapi_grpc_macros::Mockable)]
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Withdrawal {
+            /// Serialized document of the withdrawals contract
+            #[prost(bytes = "vec", tag = "1")]
+            pub document: ::prost::alloc::vec::Vec<u8>,
+            /// Id of the Core transaction of the withdrawal, empty until it is signed
+            #[prost(bytes = "vec", tag = "2")]
+            pub transaction_id: ::prost::alloc::vec::Vec<u8>,
+        }
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        #[derive(::dapi_grpc_macros::Mockable)]
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Withdrawals {
+            #[prost(message, repeated, tag = "1")]
+            pub withdrawals: ::prost::alloc::vec::Vec<Withdrawal>,
+        }
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Result {
+            #[prost(message, tag = "1")]
+            Withdrawals(Withdrawals),
+            #[prost(message, tag = "2")]
+            Proof(super::super::Proof),
+        }
+    }
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Version {
+        #[prost(message, tag = "1")]
+        V0(GetIdentityWithdrawalsResponseV0),
+    }
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(::dapi_grpc_macros::VersionedGrpcMessage)]
+#[grpc_versions(0)]
+#[derive(::dapi_grpc_macros::Mockable)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetProofsRequest {
     #[prost(oneof = "get_proofs_request::Version", tags = "1")]
     pub version: ::core::option::Option<get_proofs_request::Version>,
@@ -1417,6 +1621,237 @@ pub mod get_documents_response {
 #[derive(::dapi_grpc_macros::Mockable)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDocumentsAggregateRequest {
+    #[prost(oneof = "get_documents_aggregate_request::Version", tags = "1")]
+    pub version: ::core::option::Option<get_documents_aggregate_request::Version>,
+}
+/// Nested message and enum types in `GetDocumentsAggregateRequest`.
+pub mod get_documents_aggregate_request {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[derive(::dapi_grpc_macros::Mockable)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct GetDocumentsAggregateRequestV0 {
+        #[prost(bytes = "vec", tag = "1")]
+        #[serde(with = "serde_bytes")]
+        pub data_contract_id: ::prost::alloc::vec::Vec<u8>,
+        #[prost(string, tag = "2")]
+        pub document_type: ::prost::alloc::string::String,
+        #[prost(bytes = "vec", tag = "3")]
+        #[serde(with = "serde_bytes")]
+        pub r#where: ::prost::alloc::vec::Vec<u8>,
+        #[prost(bytes = "vec", tag = "4")]
+        #[serde(with = "serde_bytes")]
+        pub order_by: ::prost::alloc::vec::Vec<u8>,
+        /// Must be 0, every document matched by the where clauses is aggregated
+        #[prost(uint32, tag = "5")]
+        pub limit: u32,
+        /// Integer property to sum, documents are counted when empty
+        #[prost(string, tag = "6")]
+        pub sum_property: ::prost::alloc::string::String,
+        #[prost(bool, tag = "7")]
+        pub prove: bool,
+    }
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Version {
+        #[prost(message, tag = "1")]
+        V0(GetDocumentsAggregateRequestV0),
+    }
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(
+    ::dapi_grpc_macros::VersionedGrpcMessage,
+    ::dapi_grpc_macros::VersionedGrpcResponse
+)]
+#[grpc_versions(0)]
+#[derive(::dapi_grpc_macros::Mockable)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDocumentsAggregateResponse {
+    #[prost(oneof = "get_documents_aggregate_response::Version", tags = "1")]
+    pub version: ::core::option::Option<get_documents_aggregate_response::Version>,
+}
+/// Nested message and enum types in `GetDocumentsAggregateResponse`.
+pub mod get_documents_aggregate_response {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[derive(::dapi_grpc_macros::Mockable)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct GetDocumentsAggregateResponseV0 {
+        #[prost(message, optional, tag = "3")]
+        pub metadata: ::core::option::Option<super::ResponseMetadata>,
+        #[prost(oneof = "get_documents_aggregate_response_v0::Result", tags = "1, 2")]
+        pub result: ::core::option::Option<get_documents_aggregate_response_v0::Result>,
+    }
+    /// Nested message and enum types in `GetDocumentsAggregateResponseV0`.
+    pub mod get_documents_aggregate_response_v0 {
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Result {
+            #[prost(int64, tag = "1")]
+            Value(i64),
+            #[prost(message, tag = "2")]
+            Proof(super::super::Proof),
+        }
+    }
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Version {
+        #[prost(message, tag = "1")]
+        V0(GetDocumentsAggregateResponseV0),
+    }
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(::dapi_grpc_macros::VersionedGrpcMessage)]
+#[grpc_versions(0)]
+#[derive(::dapi_grpc_macros::Mockable)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeToDocumentsRequest {
+    #[prost(oneof = "subscribe_to_documents_request::Version", tags = "1")]
+    pub version: ::core::option::Option<subscribe_to_documents_request::Version>,
+}
+/// Nested message and enum types in `SubscribeToDocumentsRequest`.
+pub mod subscribe_to_documents_request {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[derive(::dapi_grpc_macros::Mockable)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SubscribeToDocumentsRequestV0 {
+        #[prost(bytes = "vec", tag = "1")]
+        #[serde(with = "serde_bytes")]
+        pub data_contract_id: ::prost::alloc::vec::Vec<u8>,
+        #[prost(string, tag = "2")]
+        pub document_type: ::prost::alloc::string::String,
+        #[prost(bytes = "vec", tag = "3")]
+        #[serde(with = "serde_bytes")]
+        pub r#where: ::prost::alloc::vec::Vec<u8>,
+        #[prost(bool, tag = "4")]
+        pub prove: bool,
+    }
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Version {
+        #[prost(message, tag = "1")]
+        V0(SubscribeToDocumentsRequestV0),
+    }
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(::dapi_grpc_macros::Mockable)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeToDocumentsResponse {
+    #[prost(oneof = "subscribe_to_documents_response::Version", tags = "1")]
+    pub version: ::core::option::Option<subscribe_to_documents_response::Version>,
+}
+/// Nested message and enum types in `SubscribeToDocumentsResponse`.
+pub mod subscribe_to_documents_response {
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[derive(::dapi_grpc_macros::Mockable)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SubscribeToDocumentsResponseV0 {
+        #[prost(message, repeated, tag = "1")]
+        pub changes: ::prost::alloc::vec::Vec<
+            subscribe_to_documents_response_v0::DocumentChange,
+        >,
+        /// Proof of the changed documents in the latest committed state when the response is
+        /// built, which can be newer than the block of the changes, only set when requested
+        #[prost(message, optional, tag = "2")]
+        pub proof: ::core::option::Option<super::Proof>,
+        #[prost(message, optional, tag = "3")]
+        pub metadata: ::core::option::Option<super::ResponseMetadata>,
+    }
+    /// Nested message and enum types in `SubscribeToDocumentsResponseV0`.
+    pub mod subscribe_to_documents_response_v0 {
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        #[derive(::dapi_grpc_macros::Mockable)]
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct DocumentChange {
+            #[prost(enumeration = "ChangeType", tag = "1")]
+            pub change_type: i32,
+            #[prost(bytes = "vec", tag = "2")]
+            pub document_id: ::prost::alloc::vec::Vec<u8>,
+            /// The serialized document, empty when the document was deleted
+            #[prost(bytes = "vec", tag = "3")]
+            pub document: ::prost::alloc::vec::Vec<u8>,
+        }
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            PartialEq,
+            Eq,
+            Hash,
+            PartialOrd,
+            Ord,
+            ::prost::Enumeration
+        )]
+        #[repr(i32)]
+        pub enum ChangeType {
+            Created = 0,
+            Replaced = 1,
+            Deleted = 2,
+        }
+        impl ChangeType {
+            /// String value of the enum field names used in the ProtoBuf definition.
+            ///
+            /// The values are not transformed in any way and thus are considered stable
+            /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    ChangeType::Created => "CREATED",
+                    ChangeType::Replaced => "REPLACED",
+                    ChangeType::Deleted => "DELETED",
+                }
+            }
+            /// Creates an enum from field names used in the ProtoBuf definition.
+            pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+                match value {
+                    "CREATED" => Some(Self::Created),
+                    "REPLACED" => Some(Self::Replaced),
+                    "DELETED" => Some(Self::Deleted),
+                    _ => None,
+                }
+            }
+        }
+    }
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Version {
+        #[prost(message, tag = "1")]
+        V0(SubscribeToDocumentsResponseV0),
+    }
+}
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(::dapi_grpc_macros::VersionedGrpcMessage)]
+#[grpc_versions(0)]
+#[derive(::dapi_grpc_macros::Mockable)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentitiesByPublicKeyHashesRequest {
     #[prost(oneof = "get_identities_by_public_key_hashes_request::Version", tags = "1")]
     pub version: ::core::option::Option<
@@ -2306,9 +2741,69 @@ pub mod platform_client {
         }
         pub async fn get_identity_keys(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetIdentityKeysRequest>,
+            request: impl tonic::IntoRequest<super::GetIdentityKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetIdentityKeysResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/getIdentityKeys",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "getIdentityKeys",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_identity_nonce(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetIdentityNonceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetIdentityNonceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/getIdentityNonce",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "getIdentityNonce",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_identity_contract_nonce(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetIdentityContractNonceRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetIdentityKeysResponse>,
+            tonic::Response<super::GetIdentityContractNonceResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2322,23 +2817,23 @@ pub mod platform_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/org.dash.platform.dapi.v0.Platform/getIdentityKeys",
+                "/org.dash.platform.dapi.v0.Platform/getIdentityContractNonce",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "org.dash.platform.dapi.v0.Platform",
-                        "getIdentityKeys",
+                        "getIdentityContractNonce",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_identity_nonce(
+        pub async fn get_identity_balance(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetIdentityNonceRequest>,
+            request: impl tonic::IntoRequest<super::GetIdentityBalanceRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetIdentityNonceResponse>,
+            tonic::Response<super::GetIdentityBalanceResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2352,23 +2847,23 @@ pub mod platform_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/org.dash.platform.dapi.v0.Platform/getIdentityNonce",
+                "/org.dash.platform.dapi.v0.Platform/getIdentityBalance",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "org.dash.platform.dapi.v0.Platform",
-                        "getIdentityNonce",
+                        "getIdentityBalance",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_identity_contract_nonce(
+        pub async fn get_identity_balance_and_revision(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetIdentityContractNonceRequest>,
+            request: impl tonic::IntoRequest<super::GetIdentityBalanceAndRevisionRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetIdentityContractNonceResponse>,
+            tonic::Response<super::GetIdentityBalanceAndRevisionResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2382,23 +2877,23 @@ pub mod platform_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/org.dash.platform.dapi.v0.Platform/getIdentityContractNonce",
+                "/org.dash.platform.dapi.v0.Platform/getIdentityBalanceAndRevision",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "org.dash.platform.dapi.v0.Platform",
-                        "getIdentityContractNonce",
+                        "getIdentityBalanceAndRevision",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_identity_balance(
+        pub async fn get_pending_transfers(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetIdentityBalanceRequest>,
+            request: impl tonic::IntoRequest<super::GetPendingTransfersRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetIdentityBalanceResponse>,
+            tonic::Response<super::GetPendingTransfersResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2412,23 +2907,23 @@ pub mod platform_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/org.dash.platform.dapi.v0.Platform/getIdentityBalance",
+                "/org.dash.platform.dapi.v0.Platform/getPendingTransfers",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "org.dash.platform.dapi.v0.Platform",
-                        "getIdentityBalance",
+                        "getPendingTransfers",
                     ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_identity_balance_and_revision(
+        pub async fn get_identity_withdrawals(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetIdentityBalanceAndRevisionRequest>,
+            request: impl tonic::IntoRequest<super::GetIdentityWithdrawalsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetIdentityBalanceAndRevisionResponse>,
+            tonic::Response<super::GetIdentityWithdrawalsResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2442,14 +2937,14 @@ pub mod platform_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/org.dash.platform.dapi.v0.Platform/getIdentityBalanceAndRevision",
+                "/org.dash.platform.dapi.v0.Platform/getIdentityWithdrawals",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "org.dash.platform.dapi.v0.Platform",
-                        "getIdentityBalanceAndRevision",
+                        "getIdentityWithdrawals",
                     ),
                 );
             self.inner.unary(req, path, codec).await
@@ -2598,6 +3093,36 @@ pub mod platform_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_documents_aggregate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetDocumentsAggregateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetDocumentsAggregateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/getDocumentsAggregate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "getDocumentsAggregate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn get_identities_by_public_key_hashes(
             &mut self,
             request: impl tonic::IntoRequest<
@@ -2814,6 +3339,38 @@ pub mod platform_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn subscribe_to_documents(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeToDocumentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::SubscribeToDocumentsResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/subscribeToDocuments",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "subscribeToDocuments",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -2879,6 +3436,20 @@ pub mod platform_server {
             tonic::Response<super::GetIdentityBalanceAndRevisionResponse>,
             tonic::Status,
         >;
+        async fn get_pending_transfers(
+            &self,
+            request: tonic::Request<super::GetPendingTransfersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPendingTransfersResponse>,
+            tonic::Status,
+        >;
+        async fn get_identity_withdrawals(
+            &self,
+            request: tonic::Request<super::GetIdentityWithdrawalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetIdentityWithdrawalsResponse>,
+            tonic::Status,
+        >;
         async fn get_proofs(
             &self,
             request: tonic::Request<super::GetProofsRequest>,
@@ -2914,6 +3485,13 @@ pub mod platform_server {
             tonic::Response<super::GetDocumentsResponse>,
             tonic::Status,
         >;
+        async fn get_documents_aggregate(
+            &self,
+            request: tonic::Request<super::GetDocumentsAggregateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetDocumentsAggregateResponse>,
+            tonic::Status,
+        >;
         async fn get_identities_by_public_key_hashes(
             &self,
             request: tonic::Request<super::GetIdentitiesByPublicKeyHashesRequest>,
@@ -2963,6 +3541,22 @@ pub mod platform_server {
             tonic::Response<super::GetEpochsInfoResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the subscribeToDocuments method.
+        type subscribeToDocumentsStream: futures_core::Stream<
+                Item = std::result::Result<
+                    super::SubscribeToDocumentsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        async fn subscribe_to_documents(
+            &self,
+            request: tonic::Request<super::SubscribeToDocumentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::subscribeToDocumentsStream>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct PlatformServer<T: Platform> {
@@ -3418,6 +4012,98 @@ pub mod platform_server {
                     };
                     Box::pin(fut)
                 }
+                "/org.dash.platform.dapi.v0.Platform/getPendingTransfers" => {
+                    #[allow(non_camel_case_types)]
+                    struct getPendingTransfersSvc<T: Platform>(pub Arc<T>);
+                    impl<
+                        T: Platform,
+                    > tonic::server::UnaryService<super::GetPendingTransfersRequest>
+                    for getPendingTransfersSvc<T> {
+                        type Response = super::GetPendingTransfersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetPendingTransfersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_pending_transfers(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = getPendingTransfersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/org.dash.platform.dapi.v0.Platform/getIdentityWithdrawals" => {
+                    #[allow(non_camel_case_types)]
+                    struct getIdentityWithdrawalsSvc<T: Platform>(pub Arc<T>);
+                    impl<
+                        T: Platform,
+                    > tonic::server::UnaryService<super::GetIdentityWithdrawalsRequest>
+                    for getIdentityWithdrawalsSvc<T> {
+                        type Response = super::GetIdentityWithdrawalsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetIdentityWithdrawalsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_identity_withdrawals(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = getIdentityWithdrawalsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/org.dash.platform.dapi.v0.Platform/getProofs" => {
                     #[allow(non_camel_case_types)]
                     struct getProofsSvc<T: Platform>(pub Arc<T>);
@@ -3646,6 +4332,52 @@ pub mod platform_server {
                     };
                     Box::pin(fut)
                 }
+                "/org.dash.platform.dapi.v0.Platform/getDocumentsAggregate" => {
+                    #[allow(non_camel_case_types)]
+                    struct getDocumentsAggregateSvc<T: Platform>(pub Arc<T>);
+                    impl<
+                        T: Platform,
+                    > tonic::server::UnaryService<super::GetDocumentsAggregateRequest>
+                    for getDocumentsAggregateSvc<T> {
+                        type Response = super::GetDocumentsAggregateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetDocumentsAggregateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_documents_aggregate(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = getDocumentsAggregateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/org.dash.platform.dapi.v0.Platform/getIdentitiesByPublicKeyHashes" => {
                     #[allow(non_camel_case_types)]
                     struct getIdentitiesByPublicKeyHashesSvc<T: Platform>(pub Arc<T>);
@@ -3987,6 +4719,54 @@ pub mod platform_server {
                     };
                     Box::pin(fut)
                 }
+                "/org.dash.platform.dapi.v0.Platform/subscribeToDocuments" => {
+                    #[allow(non_camel_case_types)]
+                    struct subscribeToDocumentsSvc<T: Platform>(pub Arc<T>);
+                    impl<
+                        T: Platform,
+                    > tonic::server::ServerStreamingService<
+                        super::SubscribeToDocumentsRequest,
+                    > for subscribeToDocumentsSvc<T> {
+                        type Response = super::SubscribeToDocumentsResponse;
+                        type ResponseStream = T::subscribeToDocumentsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeToDocumentsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).subscribe_to_documents(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = subscribeToDocumentsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -11,14 +11,42 @@ use rand::{rngs::SmallRng, seq::IteratorRandom, SeedableRng};
 
 const DEFAULT_BASE_BAN_PERIOD: Duration = Duration::from_secs(60);
 
+/// Weight of the latest request in moving averages of [Address] health.
+const HEALTH_EWMA_ALPHA: f64 = 0.3;
+/// Penalty added to the score of an [Address] for each block it lags behind the most up-to-date node.
+const HEIGHT_LAG_PENALTY: Duration = Duration::from_millis(500);
+/// Penalty added to the score of an [Address] whose requests all fail, scaled down by its error rate.
+const ERROR_RATE_PENALTY: Duration = Duration::from_secs(5);
+
+/// Strategy used to select an [Address] from [AddressList] for a request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AddressSelectionStrategy {
+    /// Randomly select a not banned address.
+    #[default]
+    Random,
+    /// Prefer not banned addresses with lower latency, lower error rate and up-to-date chain height.
+    ///
+    /// Two random addresses are compared and the healthier one is selected, so load is still spread
+    /// between nodes and addresses without statistics get a chance to be measured.
+    HealthScored,
+}
+
 /// DAPI address.
-#[derive(Debug, Clone, Eq)]
+#[derive(Debug, Clone)]
 pub struct Address {
     ban_count: usize,
     banned_until: Option<time::Instant>,
     uri: Uri,
+    /// Moving average of response latency of succeeded requests
+    latency: Option<Duration>,
+    /// Moving average of the ratio of failed requests
+    error_rate: f64,
+    /// Platform chain height last reported by the node
+    reported_height: Option<u64>,
 }
 
+impl Eq for Address {}
+
 impl PartialEq<Self> for Address {
     fn eq(&self, other: &Self) -> bool {
         self.uri == other.uri
@@ -43,6 +71,9 @@ impl From<Uri> for Address {
             ban_count: 0,
             banned_until: None,
             uri,
+            latency: None,
+            error_rate: 0.0,
+            reported_height: None,
         }
     }
 }
@@ -72,6 +103,67 @@ impl Address {
     pub fn uri(&self) -> &Uri {
         &self.uri
     }
+
+    /// Moving average of response latency of the node, if it already answered a request successfully.
+    pub fn latency(&self) -> Option<Duration> {
+        self.latency
+    }
+
+    /// Moving average of the ratio of failed requests to the node.
+    pub fn error_rate(&self) -> f64 {
+        self.error_rate
+    }
+
+    /// Platform chain height last reported by the node.
+    pub fn reported_height(&self) -> Option<u64> {
+        self.reported_height
+    }
+
+    /// Record the outcome of a request to the node.
+    ///
+    /// Failed requests can return early, e.g. on connection errors, so only the latency of
+    /// succeeded requests is recorded.
+    fn record_request(&mut self, latency: Duration, succeeded: bool, reported_height: Option<u64>) {
+        if succeeded {
+            self.latency = Some(match self.latency {
+                Some(average) => {
+                    average.mul_f64(1.0 - HEALTH_EWMA_ALPHA) + latency.mul_f64(HEALTH_EWMA_ALPHA)
+                }
+                None => latency,
+            });
+        }
+
+        let failure = if succeeded { 0.0 } else { 1.0 };
+        self.error_rate = self.error_rate * (1.0 - HEALTH_EWMA_ALPHA) + failure * HEALTH_EWMA_ALPHA;
+
+        if reported_height.is_some() {
+            self.reported_height = reported_height;
+        }
+    }
+
+    /// Health score of the node, lower is better.
+    ///
+    /// Nodes without statistics get the best score, so they are measured. The error penalty
+    /// doesn't depend on latency, so failing nodes are penalized even if they never succeeded.
+    fn health_score(&self, max_height: Option<u64>) -> Duration {
+        let latency = self.latency.unwrap_or_default();
+
+        let error_penalty = ERROR_RATE_PENALTY.mul_f64(self.error_rate);
+
+        let height_lag = match (max_height, self.reported_height) {
+            (Some(max_height), Some(height)) => max_height.saturating_sub(height),
+            _ => 0,
+        };
+
+        latency + error_penalty + HEIGHT_LAG_PENALTY * height_lag.min(u32::MAX as u64) as u32
+    }
+
+    /// Check if [Address] can be used at the moment.
+    fn is_live(&self, now: time::Instant) -> bool {
+        self.banned_until
+            .map(|banned_until| banned_until < now)
+            .unwrap_or(true)
+    }
 }
 
 /// [AddressList] errors
@@ -117,11 +209,10 @@ impl AddressList {
 
     /// Bans address
     pub(crate) fn ban_address(&mut self, address: &Address) -> Result<(), AddressListError> {
-        if !self.addresses.remove(address) {
+        let Some(mut banned_address) = self.addresses.take(address) else {
             return Err(AddressListError::AddressNotFound(address.uri.clone()));
         };
 
-        let mut banned_address = address.clone();
         banned_address.ban(&self.base_ban_period);
 
         self.addresses.insert(banned_address);
@@ -131,11 +222,10 @@ impl AddressList {
 
     /// Clears address' ban record
     pub(crate) fn unban_address(&mut self, address: &Address) -> Result<(), AddressListError> {
-        if !self.addresses.remove(address) {
+        let Some(mut unbanned_address) = self.addresses.take(address) else {
             return Err(AddressListError::AddressNotFound(address.uri.clone()));
         };
 
-        let mut unbanned_address = address.clone();
         unbanned_address.unban();
 
         self.addresses.insert(unbanned_address);
@@ -143,34 +233,118 @@ impl AddressList {
         Ok(())
     }
 
+    /// Record the outcome of a request to the address, used by [AddressSelectionStrategy::HealthScored]
+    pub(crate) fn record_request(
+        &mut self,
+        address: &Address,
+        latency: Duration,
+        succeeded: bool,
+        reported_height: Option<u64>,
+    ) -> Result<(), AddressListError> {
+        let Some(mut recorded_address) = self.addresses.take(address) else {
+            return Err(AddressListError::AddressNotFound(address.uri.clone()));
+        };
+
+        recorded_address.record_request(latency, succeeded, reported_height);
+
+        self.addresses.insert(recorded_address);
+
+        Ok(())
+    }
+
     /// Adds a node [Address] to [AddressList]
     /// Returns false if the address is already in the list.
     pub fn add(&mut self, address: Address) -> bool {
         self.addresses.insert(address)
     }
 
-    // TODO: this is the most simple way to add an address
-    //  however we need to support bulk loading (e.g. providing a network name)
-    //  and also fetch updated from SML.
     /// Add a node [Address] to [AddressList] by [Uri].
     /// Returns false if the address is already in the list.
     pub fn add_uri(&mut self, uri: Uri) -> bool {
         self.addresses.insert(uri.into())
     }
 
+    /// Replace addresses in [AddressList] with provided list of [Uri]s, for example
+    /// platform endpoints of evonodes from the current masternode list.
+    ///
+    /// Addresses that are not in the list anymore are removed. Addresses that remain keep
+    /// their ban and health records.
+    pub fn update_uris<I: IntoIterator<Item = Uri>>(&mut self, uris: I) {
+        let addresses: HashSet<Address> = uris.into_iter().map(Address::from).collect();
+
+        self.addresses.retain(|address| addresses.contains(address));
+        self.addresses.extend(addresses);
+    }
+
+    /// Number of addresses in [AddressList].
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    /// Check if [AddressList] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+
     /// Randomly select a not banned address.
     pub fn get_live_address(&self) -> Option<&Address> {
+        self.select_live_address(AddressSelectionStrategy::Random)
+    }
+
+    /// Select a not banned address using provided [AddressSelectionStrategy].
+    pub fn select_live_address(&self, strategy: AddressSelectionStrategy) -> Option<&Address> {
         let now = time::Instant::now();
         let mut rng = SmallRng::from_entropy();
 
+        let live_addresses = self.addresses.iter().filter(|addr| addr.is_live(now));
+
+        match strategy {
+            AddressSelectionStrategy::Random => live_addresses.choose(&mut rng),
+            AddressSelectionStrategy::HealthScored => {
+                let max_height = self.max_reported_height(now);
+
+                live_addresses
+                    .choose_multiple(&mut rng, 2)
+                    .into_iter()
+                    .min_by_key(|addr| addr.health_score(max_height))
+            }
+        }
+    }
+
+    /// Select up to `count` distinct not banned addresses using provided [AddressSelectionStrategy].
+    ///
+    /// With [AddressSelectionStrategy::HealthScored], the healthiest addresses are selected first.
+    pub fn select_live_addresses(
+        &self,
+        strategy: AddressSelectionStrategy,
+        count: usize,
+    ) -> Vec<&Address> {
+        let now = time::Instant::now();
+        let mut rng = SmallRng::from_entropy();
+
+        let live_addresses = self.addresses.iter().filter(|addr| addr.is_live(now));
+
+        match strategy {
+            AddressSelectionStrategy::Random => live_addresses.choose_multiple(&mut rng, count),
+            AddressSelectionStrategy::HealthScored => {
+                let max_height = self.max_reported_height(now);
+
+                let mut addresses: Vec<&Address> = live_addresses.collect();
+                addresses.sort_by_key(|addr| addr.health_score(max_height));
+                addresses.truncate(count);
+
+                addresses
+            }
+        }
+    }
+
+    /// Highest platform chain height reported by not banned addresses.
+    fn max_reported_height(&self, now: time::Instant) -> Option<u64> {
         self.addresses
             .iter()
-            .filter(|addr| {
-                addr.banned_until
-                    .map(|banned_until| banned_until < now)
-                    .unwrap_or(true)
-            })
-            .choose(&mut rng)
+            .filter(|addr| addr.is_live(now))
+            .filter_map(|addr| addr.reported_height)
+            .max()
     }
 }
 
@@ -3,14 +3,16 @@
 use backon::{ExponentialBuilder, Retryable};
 use dapi_grpc::mock::Mockable;
 use dapi_grpc::tonic::async_trait;
+use futures::stream::{FuturesUnordered, StreamExt};
+use std::fmt::Debug;
 use std::sync::RwLock;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::Instrument;
 
 use crate::address_list::AddressListError;
 use crate::{
-    transport::{TransportClient, TransportRequest},
-    Address, AddressList, CanRetry, RequestSettings,
+    transport::{AppliedRequestSettings, TransportClient, TransportRequest, TransportResponse},
+    Address, AddressList, CanRetry, RequestSettings, Uri,
 };
 
 /// General DAPI request error type.
@@ -25,6 +27,9 @@ pub enum DapiClientError<TE> {
     /// [AddressListError] errors
     #[error("address list error: {0}")]
     AddressList(AddressListError),
+    /// Verified responses of a hedged request were not reported at the same chain height.
+    #[error("nodes didn't agree on chain height")]
+    HeightDisagreement,
     #[cfg(feature = "mocks")]
     /// Expectation not found
     #[error("mock expectation not found for request: {0}")]
@@ -38,6 +43,7 @@ impl<TE: CanRetry> CanRetry for DapiClientError<TE> {
             NoAvailableAddresses => false,
             Transport(transport_error, _) => transport_error.is_node_failure(),
             AddressList(_) => false,
+            HeightDisagreement => false,
             #[cfg(feature = "mocks")]
             MockExpectationNotFound(_) => false,
         }
@@ -77,6 +83,234 @@ impl DapiClient {
             dump_dir: None,
         }
     }
+
+    /// DAPI addresses used by this client.
+    pub fn address_list(&self) -> &RwLock<AddressList> {
+        &self.address_list
+    }
+
+    /// Replace DAPI addresses used by this client, for example with an updated masternode list.
+    ///
+    /// See [AddressList::update_uris()] for details.
+    pub fn update_addresses<I: IntoIterator<Item = Uri>>(&self, uris: I) {
+        self.address_list
+            .write()
+            .expect("can't get address list for write")
+            .update_uris(uris);
+    }
+
+    /// Execute the [DapiRequest](crate::DapiRequest) and check the response with `verify`, for example
+    /// by verifying its proof.
+    ///
+    /// When [RequestSettings::hedged_requests] is greater than 1, the request is sent to up to that many
+    /// nodes, each next one after [RequestSettings::hedging_delay], and the first response that passes
+    /// `verify` is returned. Nodes returning responses that don't pass `verify` because of the node, like
+    /// an invalid proof or signature, are banned like failed nodes; errors of `verify` which are not
+    /// [node failures](CanRetry::is_node_failure()), like errors of the client context, don't ban
+    /// the node. With [RequestSettings::hedging_height_agreement], two verified responses with the same
+    /// reported chain height are required. Hedged requests are not retried.
+    ///
+    /// Otherwise, the request is executed with [DapiRequestExecutor::execute()] and then verified.
+    ///
+    /// Returns error of `verify` for the last response if no response passed verification.
+    pub async fn execute_verified<R, T, E, V>(
+        &self,
+        request: R,
+        settings: RequestSettings,
+        verify: V,
+    ) -> Result<Result<T, E>, DapiClientError<<R::Client as TransportClient>::Error>>
+    where
+        R: TransportRequest,
+        V: Fn(R::Response) -> Result<T, E>,
+        E: CanRetry + Debug,
+    {
+        let applied_settings = self
+            .settings
+            .override_by(R::SETTINGS_OVERRIDES)
+            .override_by(settings)
+            .finalize();
+
+        if applied_settings.hedged_requests < 2 {
+            return self.execute(request, settings).await.map(verify);
+        }
+
+        let addresses: Vec<Address> = self
+            .address_list
+            .read()
+            .expect("can't get address list for read")
+            .select_live_addresses(
+                applied_settings.address_selection,
+                applied_settings.hedged_requests,
+            )
+            .into_iter()
+            .cloned()
+            .collect();
+
+        if addresses.is_empty() {
+            return Err(DapiClientError::NoAvailableAddresses);
+        }
+
+        // Send the request to each next node after a delay, unless a verified response is received earlier
+        let mut attempts: FuturesUnordered<_> = addresses
+            .into_iter()
+            .enumerate()
+            .map(|(index, address)| {
+                let transport_request = request.clone();
+                let delay = applied_settings.hedging_delay * index as u32;
+
+                async move {
+                    tokio::time::sleep(delay).await;
+
+                    let response = self
+                        .execute_on_address(transport_request, address.clone(), &applied_settings)
+                        .await;
+
+                    (address, response)
+                }
+            })
+            .collect();
+
+        let mut verified_heights = Vec::new();
+        let mut last_result = Err(DapiClientError::NoAvailableAddresses);
+
+        while let Some((address, response)) = attempts.next().await {
+            let response = match response {
+                Ok(response) => response,
+                Err(error) => {
+                    last_result = Err(error);
+                    continue;
+                }
+            };
+
+            let reported_height = response.reported_height();
+
+            match verify(response) {
+                Ok(object) => {
+                    if !applied_settings.hedging_height_agreement
+                        || (reported_height.is_some()
+                            && verified_heights.contains(&reported_height))
+                    {
+                        return Ok(Ok(object));
+                    }
+
+                    verified_heights.push(reported_height);
+                }
+                Err(error) => {
+                    tracing::warn!(?error, ?address, "hedged response failed verification");
+
+                    if applied_settings.ban_failed_address && error.is_node_failure() {
+                        ignore_removed_address(
+                            self.address_list
+                                .write()
+                                .expect("can't get address list for write")
+                                .ban_address(&address),
+                        )
+                        .map_err(
+                            DapiClientError::<<R::Client as TransportClient>::Error>::AddressList,
+                        )?;
+                    }
+
+                    last_result = Ok(Err(error));
+                }
+            }
+        }
+
+        if !verified_heights.is_empty() {
+            return Err(DapiClientError::HeightDisagreement);
+        }
+
+        last_result
+    }
+
+    /// Execute the transport request on the node at `address`.
+    ///
+    /// Records health of the node and bans or unbans it depending on the response.
+    async fn execute_on_address<R: TransportRequest>(
+        &self,
+        request: R,
+        address: Address,
+        applied_settings: &AppliedRequestSettings,
+    ) -> Result<R::Response, DapiClientError<<R::Client as TransportClient>::Error>> {
+        let response_name = request.response_name();
+
+        let mut transport_client =
+            R::Client::with_uri_and_settings(address.uri().clone(), applied_settings);
+
+        let started_at = Instant::now();
+
+        let response = request
+            .execute_transport(&mut transport_client, applied_settings)
+            .await
+            .map_err(|e| {
+                DapiClientError::<<R::Client as TransportClient>::Error>::Transport(
+                    e,
+                    address.clone(),
+                )
+            });
+
+        // Record node health to prefer healthy, up-to-date nodes in next requests.
+        // Errors which are not node failures are still valid responses of the node.
+        let (succeeded, reported_height) = match &response {
+            Ok(response) => (true, response.reported_height()),
+            Err(error) => (!error.is_node_failure(), None),
+        };
+
+        ignore_removed_address(
+            self.address_list
+                .write()
+                .expect("can't get address list for write")
+                .record_request(&address, started_at.elapsed(), succeeded, reported_height),
+        )
+        .map_err(DapiClientError::<<R::Client as TransportClient>::Error>::AddressList)?;
+
+        match &response {
+            Ok(_) => {
+                // Unban the address if it was banned and node responded successfully this time
+                if address.is_banned() {
+                    let mut address_list = self
+                        .address_list
+                        .write()
+                        .expect("can't get address list for write");
+
+                    ignore_removed_address(address_list.unban_address(&address)).map_err(
+                        DapiClientError::<<R::Client as TransportClient>::Error>::AddressList,
+                    )?;
+                }
+
+                tracing::trace!(?response, "received {} response", response_name);
+            }
+            Err(error) => {
+                if error.is_node_failure() {
+                    if applied_settings.ban_failed_address {
+                        let mut address_list = self
+                            .address_list
+                            .write()
+                            .expect("can't get address list for write");
+
+                        ignore_removed_address(address_list.ban_address(&address)).map_err(
+                            DapiClientError::<<R::Client as TransportClient>::Error>::AddressList,
+                        )?;
+                    }
+                } else {
+                    tracing::trace!(?error, "received error");
+                }
+            }
+        };
+
+        response
+    }
+}
+
+/// Ignore [AddressListError::AddressNotFound], as the address list can be replaced with
+/// [DapiClient::update_addresses()] while a request is in progress.
+fn ignore_removed_address(result: Result<(), AddressListError>) -> Result<(), AddressListError> {
+    match result {
+        Err(AddressListError::AddressNotFound(uri)) => {
+            tracing::debug!(%uri, "address was removed from the address list during the request");
+            Ok(())
+        }
+        result => result,
+    }
 }
 
 #[async_trait]
@@ -122,9 +356,12 @@ impl DapiRequestExecutor for DapiClient {
                 .read()
                 .expect("can't get address list for read");
 
-            let address_result = address_list.get_live_address().cloned().ok_or(
-                DapiClientError::<<R::Client as TransportClient>::Error>::NoAvailableAddresses,
-            );
+            let address_result = address_list
+                .select_live_address(applied_settings.address_selection)
+                .cloned()
+                .ok_or(
+                    DapiClientError::<<R::Client as TransportClient>::Error>::NoAvailableAddresses,
+                );
 
             drop(address_list);
 
@@ -144,61 +381,16 @@ impl DapiRequestExecutor for DapiClient {
             );
 
             let transport_request = request.clone();
-            let response_name = request.response_name();
 
             // Create a future using `async` block that will be returned from the closure on
-            // each retry. Could be just a request future, but need to unpack client first.
+            // each retry. Could be just a request future, but need to unpack address first.
             async move {
                 // It stays wrapped in `Result` since we want to return
                 // `impl Future<Output = Result<...>`, not a `Result` itself.
                 let address = address_result?;
 
-                let mut transport_client =
-                    R::Client::with_uri_and_settings(address.uri().clone(), &applied_settings);
-
-                let response = transport_request
-                    .execute_transport(&mut transport_client, &applied_settings)
+                self.execute_on_address(transport_request, address, &applied_settings)
                     .await
-                    .map_err(|e| {
-                        DapiClientError::<<R::Client as TransportClient>::Error>::Transport(
-                            e,
-                            address.clone(),
-                        )
-                    });
-
-                match &response {
-                    Ok(_) => {
-                        // Unban the address if it was banned and node responded successfully this time
-                        if address.is_banned() {
-                            let mut address_list = self
-                                .address_list
-                                .write()
-                                .expect("can't get address list for write");
-
-                            address_list.unban_address(&address)
-                                .map_err(DapiClientError::<<R::Client as TransportClient>::Error>::AddressList)?;
-                        }
-
-                        tracing::trace!(?response, "received {} response", response_name);
-                    }
-                    Err(error) => {
-                        if error.is_node_failure() {
-                            if applied_settings.ban_failed_address {
-                                let mut address_list = self
-                                    .address_list
-                                    .write()
-                                    .expect("can't get address list for write");
-
-                                address_list.ban_address(&address)
-                                    .map_err(DapiClientError::<<R::Client as TransportClient>::Error>::AddressList)?;
-                            }
-                        } else {
-                            tracing::trace!(?error, "received error");
-                        }
-                    }
-                };
-
-                response
             }
         };
 
@@ -17,6 +17,7 @@ pub use http::Uri;
 
 pub use address_list::Address;
 pub use address_list::AddressList;
+pub use address_list::AddressSelectionStrategy;
 pub use dapi_client::{DapiClient, DapiClientError};
 #[cfg(feature = "dump")]
 pub use dump::DumpData;
@@ -2,11 +2,17 @@
 
 use std::time::Duration;
 
+use crate::AddressSelectionStrategy;
+
 /// Default low-level client timeout
 const DEFAULT_CONNECT_TIMEOUT: Option<Duration> = None;
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 const DEFAULT_RETRIES: usize = 5;
 const DEFAULT_BAN_FAILED_ADDRESS: bool = true;
+const DEFAULT_ADDRESS_SELECTION: AddressSelectionStrategy = AddressSelectionStrategy::Random;
+const DEFAULT_HEDGED_REQUESTS: usize = 1;
+const DEFAULT_HEDGING_DELAY: Duration = Duration::from_millis(500);
+const DEFAULT_HEDGING_HEIGHT_AGREEMENT: bool = false;
 
 /// DAPI request settings.
 ///
@@ -25,6 +31,14 @@ pub struct RequestSettings {
     pub retries: Option<usize>,
     /// Ban DAPI address if node not responded or responded with error.
     pub ban_failed_address: Option<bool>,
+    /// Strategy used to select DAPI address for a request.
+    pub address_selection: Option<AddressSelectionStrategy>,
+    /// Number of DAPI nodes a verified request is sent to in parallel; `1` disables hedging.
+    pub hedged_requests: Option<usize>,
+    /// Delay before a hedged request is sent to each next node.
+    pub hedging_delay: Option<Duration>,
+    /// Require two nodes to return verified responses at the same chain height.
+    pub hedging_height_agreement: Option<bool>,
 }
 
 impl RequestSettings {
@@ -36,6 +50,10 @@ impl RequestSettings {
             timeout: None,
             retries: None,
             ban_failed_address: None,
+            address_selection: None,
+            hedged_requests: None,
+            hedging_delay: None,
+            hedging_height_agreement: None,
         }
     }
 
@@ -48,6 +66,12 @@ impl RequestSettings {
             timeout: rhs.timeout.or(self.timeout),
             retries: rhs.retries.or(self.retries),
             ban_failed_address: rhs.ban_failed_address.or(self.ban_failed_address),
+            address_selection: rhs.address_selection.or(self.address_selection),
+            hedged_requests: rhs.hedged_requests.or(self.hedged_requests),
+            hedging_delay: rhs.hedging_delay.or(self.hedging_delay),
+            hedging_height_agreement: rhs
+                .hedging_height_agreement
+                .or(self.hedging_height_agreement),
         }
     }
 
@@ -60,6 +84,12 @@ impl RequestSettings {
             ban_failed_address: self
                 .ban_failed_address
                 .unwrap_or(DEFAULT_BAN_FAILED_ADDRESS),
+            address_selection: self.address_selection.unwrap_or(DEFAULT_ADDRESS_SELECTION),
+            hedged_requests: self.hedged_requests.unwrap_or(DEFAULT_HEDGED_REQUESTS),
+            hedging_delay: self.hedging_delay.unwrap_or(DEFAULT_HEDGING_DELAY),
+            hedging_height_agreement: self
+                .hedging_height_agreement
+                .unwrap_or(DEFAULT_HEDGING_HEIGHT_AGREEMENT),
         }
     }
 }
@@ -75,4 +105,12 @@ pub struct AppliedRequestSettings {
     pub retries: usize,
     /// Ban DAPI address if node not responded or responded with error.
     pub ban_failed_address: bool,
+    /// Strategy used to select DAPI address for a request.
+    pub address_selection: AddressSelectionStrategy,
+    /// Number of DAPI nodes a verified request is sent to in parallel; `1` disables hedging.
+    pub hedged_requests: usize,
+    /// Delay before a hedged request is sent to each next node.
+    pub hedging_delay: Duration,
+    /// Require two nodes to return verified responses at the same chain height.
+    pub hedging_height_agreement: bool,
 }
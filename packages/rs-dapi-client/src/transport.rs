@@ -18,7 +18,7 @@ pub trait TransportRequest: Clone + Send + Sync + Debug + Mockable {
     type Client: TransportClient;
 
     /// Transport layer response.
-    type Response: Mockable + Send + Debug;
+    type Response: TransportResponse;
 
     /// Settings that will override [DapiClient](crate::DapiClient)'s ones each time the request is executed.
     const SETTINGS_OVERRIDES: RequestSettings;
@@ -44,6 +44,16 @@ pub trait TransportRequest: Clone + Send + Sync + Debug + Mockable {
     ) -> BoxFuture<'c, Result<Self::Response, <Self::Client as TransportClient>::Error>>;
 }
 
+/// Generic transport layer response.
+pub trait TransportResponse: Mockable + Send + Debug {
+    /// Platform chain height reported by the node in the response metadata, if any.
+    ///
+    /// Used to prefer up-to-date nodes, see [AddressSelectionStrategy](crate::AddressSelectionStrategy).
+    fn reported_height(&self) -> Option<u64> {
+        None
+    }
+}
+
 /// Generic way to create a transport client from provided [Uri].
 pub trait TransportClient: Send + Sized {
     /// Error type for the specific client.
@@ -2,11 +2,12 @@
 
 use std::time::Duration;
 
-use super::{CanRetry, TransportClient, TransportRequest};
+use super::{CanRetry, TransportClient, TransportRequest, TransportResponse};
 use crate::{request_settings::AppliedRequestSettings, RequestSettings};
 use dapi_grpc::core::v0::core_client::CoreClient;
 use dapi_grpc::core::v0::{self as core_proto};
 use dapi_grpc::platform::v0::{self as platform_proto, platform_client::PlatformClient};
+use dapi_grpc::platform::VersionedGrpcResponse;
 use dapi_grpc::tonic::Streaming;
 use dapi_grpc::tonic::{transport::Channel, IntoRequest};
 use futures::{future::BoxFuture, FutureExt, TryFutureExt};
@@ -110,6 +111,54 @@ macro_rules! impl_transport_request_grpc {
     };
 }
 
+/// Implement [TransportResponse] for platform gRPC responses that contain [ResponseMetadata](platform_proto::ResponseMetadata).
+macro_rules! impl_transport_response_with_metadata {
+    ($($response:ty),+ $(,)?) => {
+        $(
+            impl TransportResponse for $response {
+                fn reported_height(&self) -> Option<u64> {
+                    self.metadata().ok().map(|metadata| metadata.height)
+                }
+            }
+        )+
+    };
+}
+
+impl_transport_response_with_metadata!(
+    platform_proto::GetIdentityResponse,
+    platform_proto::GetDocumentsResponse,
+    platform_proto::GetDocumentsAggregateResponse,
+    platform_proto::GetDataContractResponse,
+    platform_proto::GetDataContractHistoryResponse,
+    platform_proto::WaitForStateTransitionResultResponse,
+    platform_proto::GetIdentitiesResponse,
+    platform_proto::GetIdentitiesByPublicKeyHashesResponse,
+    platform_proto::GetIdentityByPublicKeyHashResponse,
+    platform_proto::GetIdentityBalanceResponse,
+    platform_proto::GetIdentityNonceResponse,
+    platform_proto::GetIdentityContractNonceResponse,
+    platform_proto::GetIdentityBalanceAndRevisionResponse,
+    platform_proto::GetPendingTransfersResponse,
+    platform_proto::GetIdentityWithdrawalsResponse,
+    platform_proto::GetIdentityKeysResponse,
+    platform_proto::GetEpochsInfoResponse,
+    platform_proto::GetProtocolVersionUpgradeStateResponse,
+    platform_proto::GetProtocolVersionUpgradeVoteStatusResponse,
+    platform_proto::GetDataContractsResponse,
+);
+
+impl TransportResponse for platform_proto::GetConsensusParamsResponse {}
+
+impl TransportResponse for platform_proto::BroadcastStateTransitionResponse {}
+
+impl TransportResponse for core_proto::GetTransactionResponse {}
+
+impl TransportResponse for core_proto::GetStatusResponse {}
+
+impl TransportResponse for core_proto::BroadcastTransactionResponse {}
+
+impl<T: dapi_grpc::mock::Mockable> TransportResponse for Streaming<T> {}
+
 // Link to each platform gRPC request what client and method to use:
 
 const STREAMING_TIMEOUT: Duration = Duration::from_secs(5 * 60);
@@ -130,6 +179,14 @@ impl_transport_request_grpc!(
     get_documents
 );
 
+impl_transport_request_grpc!(
+    platform_proto::GetDocumentsAggregateRequest,
+    platform_proto::GetDocumentsAggregateResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_documents_aggregate
+);
+
 impl_transport_request_grpc!(
     platform_proto::GetDataContractRequest,
     platform_proto::GetDataContractResponse,
@@ -174,6 +231,14 @@ impl_transport_request_grpc!(
     wait_for_state_transition_result
 );
 
+impl_transport_request_grpc!(
+    platform_proto::GetIdentitiesRequest,
+    platform_proto::GetIdentitiesResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_identities
+);
+
 impl_transport_request_grpc!(
     platform_proto::GetIdentitiesByPublicKeyHashesRequest,
     platform_proto::GetIdentitiesByPublicKeyHashesResponse,
@@ -222,6 +287,22 @@ impl_transport_request_grpc!(
     get_identity_balance_and_revision
 );
 
+impl_transport_request_grpc!(
+    platform_proto::GetPendingTransfersRequest,
+    platform_proto::GetPendingTransfersResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_pending_transfers
+);
+
+impl_transport_request_grpc!(
+    platform_proto::GetIdentityWithdrawalsRequest,
+    platform_proto::GetIdentityWithdrawalsResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_identity_withdrawals
+);
+
 impl_transport_request_grpc!(
     platform_proto::GetIdentityKeysRequest,
     platform_proto::GetIdentityKeysResponse,
@@ -262,6 +343,17 @@ impl_transport_request_grpc!(
     get_data_contracts
 );
 
+impl_transport_request_grpc!(
+    platform_proto::SubscribeToDocumentsRequest,
+    Streaming<platform_proto::SubscribeToDocumentsResponse>,
+    PlatformGrpcClient,
+    RequestSettings {
+        timeout: Some(STREAMING_TIMEOUT),
+        ..RequestSettings::default()
+    },
+    subscribe_to_documents
+);
+
 // Link to each core gRPC request what client and method to use:
 
 impl_transport_request_grpc!(
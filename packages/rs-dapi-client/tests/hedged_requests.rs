@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use dapi_grpc::mock::Mockable;
+use futures::{future::BoxFuture, FutureExt};
+use rs_dapi_client::{
+    transport::{AppliedRequestSettings, TransportClient, TransportRequest, TransportResponse},
+    AddressList, AddressSelectionStrategy, CanRetry, DapiClient, DapiClientError, RequestSettings,
+    Uri,
+};
+
+/// Response of a node to [TestRequest].
+#[derive(Debug, Clone)]
+struct TestResponse {
+    height: u64,
+    valid_proof: bool,
+    delay: Duration,
+}
+
+impl Mockable for TestResponse {}
+
+impl TransportResponse for TestResponse {
+    fn reported_height(&self) -> Option<u64> {
+        Some(self.height)
+    }
+}
+
+#[derive(Debug)]
+struct TestTransportError;
+
+impl CanRetry for TestTransportError {
+    fn is_node_failure(&self) -> bool {
+        true
+    }
+}
+
+struct TestClient {
+    uri: Uri,
+}
+
+impl TransportClient for TestClient {
+    type Error = TestTransportError;
+
+    fn with_uri(uri: Uri) -> Self {
+        Self { uri }
+    }
+
+    fn with_uri_and_settings(uri: Uri, _settings: &AppliedRequestSettings) -> Self {
+        Self { uri }
+    }
+}
+
+/// Request answered by each node with its response from `responses`.
+#[derive(Debug, Clone)]
+struct TestRequest {
+    responses: Arc<HashMap<Uri, TestResponse>>,
+}
+
+impl Mockable for TestRequest {}
+
+impl TransportRequest for TestRequest {
+    type Client = TestClient;
+    type Response = TestResponse;
+
+    const SETTINGS_OVERRIDES: RequestSettings = RequestSettings::default();
+
+    fn method_name(&self) -> &'static str {
+        "test"
+    }
+
+    fn execute_transport<'c>(
+        self,
+        client: &'c mut Self::Client,
+        _settings: &AppliedRequestSettings,
+    ) -> BoxFuture<'c, Result<Self::Response, <Self::Client as TransportClient>::Error>> {
+        let response = self.responses.get(&client.uri).cloned();
+
+        async move {
+            let response = response.ok_or(TestTransportError)?;
+            tokio::time::sleep(response.delay).await;
+            Ok(response)
+        }
+        .boxed()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum VerifyError {
+    InvalidProof,
+    ContextProvider,
+}
+
+impl CanRetry for VerifyError {
+    fn is_node_failure(&self) -> bool {
+        matches!(self, VerifyError::InvalidProof)
+    }
+}
+
+fn verify_proof(response: TestResponse) -> Result<u64, VerifyError> {
+    if response.valid_proof {
+        Ok(response.height)
+    } else {
+        Err(VerifyError::InvalidProof)
+    }
+}
+
+fn response(height: u64, valid_proof: bool, delay_ms: u64) -> TestResponse {
+    TestResponse {
+        height,
+        valid_proof,
+        delay: Duration::from_millis(delay_ms),
+    }
+}
+
+/// Create a client with a node for each response and a request answered with these responses.
+fn setup(responses: Vec<TestResponse>) -> (DapiClient, TestRequest, Vec<Uri>) {
+    let uris: Vec<Uri> = (1..=responses.len())
+        .map(|port| Uri::try_from(format!("http://127.0.0.1:{}", port)).expect("valid uri"))
+        .collect();
+
+    let dapi = DapiClient::new(
+        AddressList::from_iter(uris.clone()),
+        RequestSettings::default(),
+    );
+
+    let request = TestRequest {
+        responses: Arc::new(uris.iter().cloned().zip(responses).collect()),
+    };
+
+    (dapi, request, uris)
+}
+
+fn hedged_settings(nodes: usize, height_agreement: bool) -> RequestSettings {
+    RequestSettings {
+        hedged_requests: Some(nodes),
+        hedging_delay: Some(Duration::ZERO),
+        hedging_height_agreement: Some(height_agreement),
+        ..Default::default()
+    }
+}
+
+fn live_uris(dapi: &DapiClient) -> Vec<Uri> {
+    let address_list = dapi
+        .address_list()
+        .read()
+        .expect("address list lock poisoned");
+
+    let mut uris: Vec<Uri> = address_list
+        .select_live_addresses(AddressSelectionStrategy::Random, address_list.len())
+        .into_iter()
+        .map(|address| address.uri().clone())
+        .collect();
+    uris.sort_by_key(|uri| uri.to_string());
+    uris
+}
+
+#[tokio::test]
+async fn test_hedged_request_returns_first_verified_response() {
+    let (dapi, request, uris) = setup(vec![
+        response(10, true, 200),
+        response(11, true, 50),
+        response(12, false, 0),
+    ]);
+
+    let result = dapi
+        .execute_verified(request, hedged_settings(3, false), verify_proof)
+        .await
+        .expect("expected a response");
+
+    assert_eq!(result, Ok(11));
+
+    // The node with an invalid proof is banned
+    assert_eq!(live_uris(&dapi), uris[..2].to_vec());
+}
+
+#[tokio::test]
+async fn test_hedged_request_bans_nodes_with_invalid_proofs_only() {
+    let (dapi, request, uris) = setup(vec![response(10, false, 0), response(10, true, 0)]);
+
+    let result = dapi
+        .execute_verified(request, hedged_settings(2, true), verify_proof)
+        .await;
+
+    // A single verified response can't reach height agreement
+    assert!(matches!(result, Err(DapiClientError::HeightDisagreement)));
+    assert_eq!(live_uris(&dapi), uris[1..].to_vec());
+
+    // Errors of the client context don't ban nodes
+    let (dapi, request, uris) = setup(vec![response(10, true, 0), response(10, true, 0)]);
+
+    let result = dapi
+        .execute_verified(request, hedged_settings(2, false), |_| {
+            Err::<u64, _>(VerifyError::ContextProvider)
+        })
+        .await
+        .expect("expected a response");
+
+    assert_eq!(result, Err(VerifyError::ContextProvider));
+    assert_eq!(live_uris(&dapi), uris);
+}
+
+#[tokio::test]
+async fn test_hedged_request_height_agreement() {
+    let (dapi, request, _) = setup(vec![response(10, true, 0), response(11, true, 0)]);
+
+    let result = dapi
+        .execute_verified(request, hedged_settings(2, true), verify_proof)
+        .await;
+
+    assert!(matches!(result, Err(DapiClientError::HeightDisagreement)));
+
+    let (dapi, request, _) = setup(vec![
+        response(10, true, 0),
+        response(11, true, 100),
+        response(10, true, 200),
+    ]);
+
+    let result = dapi
+        .execute_verified(request, hedged_settings(3, true), verify_proof)
+        .await
+        .expect("expected a response");
+
+    assert_eq!(result, Ok(10));
+}
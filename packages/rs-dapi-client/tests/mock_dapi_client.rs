@@ -1,6 +1,9 @@
-use dapi_grpc::platform::v0::{GetIdentityRequest, GetIdentityResponse, Proof};
+use dapi_grpc::platform::v0::{GetIdentityRequest, GetIdentityResponse, Proof, ResponseMetadata};
 
-use rs_dapi_client::{mock::MockDapiClient, DapiRequest, DapiRequestExecutor, RequestSettings};
+use rs_dapi_client::{
+    mock::MockDapiClient, transport::TransportResponse, AddressList, AddressSelectionStrategy,
+    DapiRequest, DapiRequestExecutor, RequestSettings, Uri,
+};
 
 #[tokio::test]
 async fn test_mock_get_identity_dapi_client() {
@@ -30,3 +33,80 @@ async fn test_mock_get_identity_dapi_client() {
     assert_eq!(result, response);
     assert_eq!(result2, response);
 }
+
+#[test]
+fn test_response_reported_height() {
+    let response: GetIdentityResponse = GetIdentityResponse {
+        version: Some(dapi_grpc::platform::v0::get_identity_response::Version::V0(
+            dapi_grpc::platform::v0::get_identity_response::GetIdentityResponseV0 {
+                result: None,
+                metadata: Some(ResponseMetadata {
+                    height: 42,
+                    ..Default::default()
+                }),
+            },
+        )),
+    };
+
+    assert_eq!(response.reported_height(), Some(42));
+    assert_eq!(GetIdentityResponse::default().reported_height(), None);
+}
+
+#[test]
+fn test_health_scored_address_selection() {
+    let address_list = AddressList::from("http://127.0.0.1:1,http://127.0.0.1:2");
+
+    let address = address_list
+        .select_live_address(AddressSelectionStrategy::HealthScored)
+        .expect("expected a live address");
+
+    assert!(address.latency().is_none());
+    assert_eq!(address.error_rate(), 0.0);
+}
+
+#[test]
+fn test_address_list_update_uris() {
+    let mut address_list = AddressList::from("http://127.0.0.1:1,http://127.0.0.1:2");
+
+    address_list.update_uris([
+        Uri::from_static("http://127.0.0.1:2"),
+        Uri::from_static("http://127.0.0.1:3"),
+    ]);
+
+    assert_eq!(address_list.len(), 2);
+
+    address_list.update_uris([Uri::from_static("http://127.0.0.1:3")]);
+
+    let address = address_list
+        .get_live_address()
+        .expect("expected a live address");
+
+    assert_eq!(address_list.len(), 1);
+    assert_eq!(address.uri(), &Uri::from_static("http://127.0.0.1:3"));
+}
+
+#[test]
+fn test_select_live_addresses_for_hedged_requests() {
+    let address_list =
+        AddressList::from("http://127.0.0.1:1,http://127.0.0.1:2,http://127.0.0.1:3");
+
+    for strategy in [
+        AddressSelectionStrategy::Random,
+        AddressSelectionStrategy::HealthScored,
+    ] {
+        let addresses = address_list.select_live_addresses(strategy, 2);
+
+        assert_eq!(addresses.len(), 2);
+        assert_ne!(addresses[0], addresses[1]);
+    }
+
+    let settings = RequestSettings {
+        hedged_requests: Some(3),
+        ..Default::default()
+    }
+    .override_by(RequestSettings::default())
+    .finalize();
+
+    assert_eq!(settings.hedged_requests, 3);
+    assert!(!settings.hedging_height_agreement);
+}
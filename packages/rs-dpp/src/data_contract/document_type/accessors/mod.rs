@@ -38,6 +38,12 @@ impl DocumentTypeV0Getters for DocumentType {
         }
     }
 
+    fn token_indices(&self) -> &Vec<Index> {
+        match self {
+            DocumentType::V0(v0) => v0.token_indices(),
+        }
+    }
+
     fn index_structure(&self) -> &IndexLevel {
         match self {
             DocumentType::V0(v0) => v0.index_structure(),
@@ -86,6 +92,18 @@ impl DocumentTypeV0Getters for DocumentType {
         }
     }
 
+    fn documents_transferable(&self) -> bool {
+        match self {
+            DocumentType::V0(v0) => v0.documents_transferable(),
+        }
+    }
+
+    fn documents_tradeable(&self) -> bool {
+        match self {
+            DocumentType::V0(v0) => v0.documents_tradeable(),
+        }
+    }
+
     fn data_contract_id(&self) -> Identifier {
         match self {
             DocumentType::V0(v0) => v0.data_contract_id(),
@@ -136,6 +154,12 @@ impl<'a> DocumentTypeV0Getters for DocumentTypeRef<'a> {
         }
     }
 
+    fn token_indices(&self) -> &Vec<Index> {
+        match self {
+            DocumentTypeRef::V0(v0) => v0.token_indices(),
+        }
+    }
+
     fn index_structure(&self) -> &IndexLevel {
         match self {
             DocumentTypeRef::V0(v0) => v0.index_structure(),
@@ -184,6 +208,18 @@ impl<'a> DocumentTypeV0Getters for DocumentTypeRef<'a> {
         }
     }
 
+    fn documents_transferable(&self) -> bool {
+        match self {
+            DocumentTypeRef::V0(v0) => v0.documents_transferable(),
+        }
+    }
+
+    fn documents_tradeable(&self) -> bool {
+        match self {
+            DocumentTypeRef::V0(v0) => v0.documents_tradeable(),
+        }
+    }
+
     fn data_contract_id(&self) -> Identifier {
         match self {
             DocumentTypeRef::V0(v0) => v0.data_contract_id(),
@@ -234,6 +270,12 @@ impl<'a> DocumentTypeV0Getters for DocumentTypeMutRef<'a> {
         }
     }
 
+    fn token_indices(&self) -> &Vec<Index> {
+        match self {
+            DocumentTypeMutRef::V0(v0) => v0.token_indices(),
+        }
+    }
+
     fn index_structure(&self) -> &IndexLevel {
         match self {
             DocumentTypeMutRef::V0(v0) => v0.index_structure(),
@@ -282,6 +324,18 @@ impl<'a> DocumentTypeV0Getters for DocumentTypeMutRef<'a> {
         }
     }
 
+    fn documents_transferable(&self) -> bool {
+        match self {
+            DocumentTypeMutRef::V0(v0) => v0.documents_transferable(),
+        }
+    }
+
+    fn documents_tradeable(&self) -> bool {
+        match self {
+            DocumentTypeMutRef::V0(v0) => v0.documents_tradeable(),
+        }
+    }
+
     fn data_contract_id(&self) -> Identifier {
         match self {
             DocumentTypeMutRef::V0(v0) => v0.data_contract_id(),
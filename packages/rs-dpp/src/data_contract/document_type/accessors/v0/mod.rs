@@ -20,6 +20,9 @@ pub trait DocumentTypeV0Getters {
     /// Returns the indices of the document type.
     fn indices(&self) -> &Vec<Index>;
 
+    /// Returns the tokenized indices of the document type.
+    fn token_indices(&self) -> &Vec<Index>;
+
     /// Returns the index structure of the document type.
     fn index_structure(&self) -> &IndexLevel;
 
@@ -44,6 +47,12 @@ pub trait DocumentTypeV0Getters {
     /// Returns the documents mutable flag of the document type.
     fn documents_mutable(&self) -> bool;
 
+    /// Returns true if the owners of documents of this type can transfer them to another identity.
+    fn documents_transferable(&self) -> bool;
+
+    /// Returns true if the owners of documents of this type can put them up for sale.
+    fn documents_tradeable(&self) -> bool;
+
     /// Returns the data contract id of the document type.
     fn data_contract_id(&self) -> Identifier;
 
@@ -6,6 +6,7 @@ use platform_version::version::PlatformVersion;
 use std::collections::BTreeMap;
 
 mod v0;
+mod v1;
 
 impl DocumentType {
     pub fn try_from_schema(
@@ -36,11 +37,229 @@ impl DocumentType {
                 platform_version,
             )
             .map(|document_type| document_type.into()),
+            1 => DocumentTypeV0::try_from_schema_v1(
+                data_contract_id,
+                name,
+                schema,
+                schema_defs,
+                default_keeps_history,
+                default_mutability,
+                validate,
+                platform_version,
+            )
+            .map(|document_type| document_type.into()),
             version => Err(ProtocolError::UnknownVersionMismatch {
                 method: "try_from_schema".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             }),
         }
     }
 }
+
+#[cfg(all(test, feature = "validation"))]
+mod tests {
+    use super::*;
+    use crate::data_contract::document_type::accessors::DocumentTypeV0Getters;
+    use crate::data_contract::document_type::array::ArrayItemType;
+    use crate::data_contract::document_type::DocumentPropertyType;
+    use platform_value::platform_value;
+
+    fn try_from_schema(
+        schema: Value,
+        platform_version: &PlatformVersion,
+    ) -> Result<DocumentType, ProtocolError> {
+        DocumentType::try_from_schema(
+            Identifier::random(),
+            "document_type_name",
+            schema,
+            None,
+            false,
+            true,
+            true,
+            platform_version,
+        )
+    }
+
+    #[test]
+    fn should_only_accept_index_sums_from_protocol_version_2() {
+        let schema = platform_value!({
+            "type": "object",
+            "indices": [
+                {
+                    "name": "byName",
+                    "properties": [{"name": "asc"}],
+                    "sum": "amount",
+                },
+            ],
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "maxLength": 63,
+                    "position": 0,
+                },
+                "amount": {
+                    "type": "integer",
+                    "position": 1,
+                },
+            },
+            "additionalProperties": false,
+        });
+
+        assert!(matches!(
+            try_from_schema(schema.clone(), PlatformVersion::get(1).unwrap()),
+            Err(ProtocolError::ConsensusError(_))
+        ));
+
+        let document_type = try_from_schema(schema, PlatformVersion::get(2).unwrap())
+            .expect("index sums should be accepted");
+        assert_eq!(
+            document_type.indices()[0].sum_property.as_deref(),
+            Some("amount")
+        );
+    }
+
+    #[test]
+    fn should_only_accept_tokenized_indices_from_protocol_version_2() {
+        let schema_with_message = |message: Value| {
+            platform_value!({
+                "type": "object",
+                "indices": [
+                    {
+                        "name": "byMessageWords",
+                        "properties": [{"message": "asc"}],
+                        "tokenized": true,
+                    },
+                ],
+                "properties": {
+                    "message": message,
+                },
+                "additionalProperties": false,
+            })
+        };
+        let schema = schema_with_message(platform_value!({
+            "type": "string",
+            "maxLength": 256,
+            "position": 0,
+        }));
+
+        assert!(matches!(
+            try_from_schema(schema.clone(), PlatformVersion::get(1).unwrap()),
+            Err(ProtocolError::ConsensusError(_))
+        ));
+
+        let document_type = try_from_schema(schema, PlatformVersion::get(2).unwrap())
+            .expect("tokenized indices should be accepted");
+        assert_eq!(document_type.token_indices().len(), 1);
+        assert!(document_type.indices().is_empty());
+
+        // The length of tokenized strings must be limited
+        let schema = schema_with_message(platform_value!({
+            "type": "string",
+            "position": 0,
+        }));
+        assert!(matches!(
+            try_from_schema(schema, PlatformVersion::get(2).unwrap()),
+            Err(ProtocolError::ConsensusError(_))
+        ));
+    }
+
+    #[test]
+    fn should_only_accept_indexed_arrays_from_protocol_version_2() {
+        let schema = platform_value!({
+            "type": "object",
+            "indices": [
+                {
+                    "name": "byTags",
+                    "properties": [{"tags": "asc"}],
+                },
+            ],
+            "properties": {
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "maxLength": 63,
+                    },
+                    "maxItems": 10,
+                    "position": 0,
+                },
+            },
+            "additionalProperties": false,
+        });
+
+        assert!(matches!(
+            try_from_schema(schema.clone(), PlatformVersion::get(1).unwrap()),
+            Err(ProtocolError::ConsensusError(_))
+        ));
+
+        let document_type = try_from_schema(schema, PlatformVersion::get(2).unwrap())
+            .expect("indexed arrays should be accepted");
+        assert_eq!(document_type.indices()[0].name, "byTags");
+        assert_eq!(
+            document_type
+                .flattened_properties()
+                .get("tags")
+                .map(|property| &property.property_type),
+            Some(&DocumentPropertyType::Array(ArrayItemType::String(
+                None,
+                Some(63)
+            )))
+        );
+    }
+
+    #[test]
+    fn should_only_accept_transferable_document_types_from_protocol_version_2() {
+        let schema = platform_value!({
+            "type": "object",
+            "transferable": true,
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "position": 0,
+                },
+            },
+            "additionalProperties": false,
+        });
+
+        assert!(matches!(
+            try_from_schema(schema.clone(), PlatformVersion::get(1).unwrap()),
+            Err(ProtocolError::ConsensusError(_))
+        ));
+
+        let document_type = try_from_schema(schema, PlatformVersion::get(2).unwrap())
+            .expect("transferable document types should be accepted");
+        assert!(document_type.documents_transferable());
+    }
+
+    #[test]
+    fn should_only_accept_tradeable_document_types_from_protocol_version_2() {
+        let schema = platform_value!({
+            "type": "object",
+            "tradeable": true,
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "position": 0,
+                },
+            },
+            "additionalProperties": false,
+        });
+
+        assert!(matches!(
+            try_from_schema(schema.clone(), PlatformVersion::get(1).unwrap()),
+            Err(ProtocolError::ConsensusError(_))
+        ));
+
+        let document_type = try_from_schema(schema, PlatformVersion::get(2).unwrap())
+            .expect("tradeable document types should be accepted");
+        assert!(document_type.documents_tradeable());
+        assert_eq!(
+            document_type
+                .flattened_properties()
+                .get(crate::document::property_names::PRICE)
+                .map(|property| &property.property_type),
+            Some(&DocumentPropertyType::Integer)
+        );
+    }
+}
@@ -380,6 +380,7 @@ impl DocumentTypeV0 {
             name: String::from(name),
             schema,
             indices,
+            token_indices: vec![],
             index_structure,
             flattened_properties: flattened_document_properties,
             properties: document_properties,
@@ -388,6 +389,8 @@ impl DocumentTypeV0 {
             required_fields,
             documents_keep_history,
             documents_mutable,
+            documents_transferable: false,
+            documents_tradeable: false,
             data_contract_id,
             requires_identity_encryption_bounded_key,
             requires_identity_decryption_bounded_key,
@@ -0,0 +1,941 @@
+use crate::data_contract::document_type::v0::DocumentTypeV0;
+#[cfg(feature = "validation")]
+use crate::data_contract::document_type::v0::StatelessJsonSchemaLazyValidator;
+use indexmap::IndexMap;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::convert::TryInto;
+
+use crate::consensus::basic::data_contract::{
+    DuplicateIndexError, DuplicateIndexNameError, InvalidIndexPropertyTypeError,
+    InvalidIndexedPropertyConstraintError, SystemPropertyIndexAlreadyPresentError,
+    UndefinedIndexPropertyError, UniqueIndicesLimitReachedError,
+};
+use crate::consensus::ConsensusError;
+use crate::data_contract::document_type::array::ArrayItemType;
+use crate::data_contract::document_type::index::Index;
+use crate::data_contract::document_type::index_level::IndexLevel;
+use crate::data_contract::document_type::property::{DocumentProperty, DocumentPropertyType};
+#[cfg(feature = "validation")]
+use crate::data_contract::document_type::schema::{
+    byte_array_has_no_items_as_parent_validator, pattern_is_valid_regex_validator,
+    traversal_validator, validate_max_depth,
+};
+
+use crate::consensus::basic::document::MissingPositionsInDocumentTypePropertiesError;
+use crate::consensus::basic::BasicError;
+use crate::data_contract::document_type::schema::enrich_with_base_schema;
+use crate::data_contract::document_type::{property_names, DocumentType};
+use crate::data_contract::errors::{DataContractError, StructureError};
+use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
+use crate::identity::SecurityLevel;
+use crate::util::json_schema::resolve_uri;
+#[cfg(feature = "validation")]
+use crate::validation::meta_validators::DOCUMENT_META_SCHEMA_V1;
+use crate::version::PlatformVersion;
+use crate::ProtocolError;
+use platform_value::btreemap_extensions::BTreeValueMapHelper;
+use platform_value::{Identifier, Value};
+
+const UNIQUE_INDEX_LIMIT_V0: usize = 16;
+const NOT_ALLOWED_SYSTEM_PROPERTIES: [&str; 1] = ["$id"];
+
+const SYSTEM_PROPERTIES: [&str; 4] = ["$id", "$ownerId", "$createdAt", "$updatedAt"];
+
+const MAX_INDEXED_STRING_PROPERTY_LENGTH: u16 = 63;
+const MAX_TOKENIZED_STRING_PROPERTY_LENGTH: u16 = 1024;
+const MAX_INDEXED_BYTE_ARRAY_PROPERTY_LENGTH: u16 = 255;
+const MAX_INDEXED_ARRAY_ITEMS: usize = 1024;
+
+impl DocumentTypeV0 {
+    // TODO: Split into multiple functions
+    pub(crate) fn try_from_schema_v1(
+        data_contract_id: Identifier,
+        name: &str,
+        schema: Value,
+        schema_defs: Option<&BTreeMap<String, Value>>,
+        default_keeps_history: bool,
+        default_mutability: bool,
+        validate: bool, // we don't need to validate if loaded from state
+        platform_version: &PlatformVersion,
+    ) -> Result<Self, ProtocolError> {
+        // Create a full root JSON Schema from shorten contract document type schema
+        let root_schema = enrich_with_base_schema(
+            schema.clone(),
+            schema_defs.map(|defs| Value::from(defs.clone())),
+            platform_version,
+        )?;
+
+        #[cfg(not(feature = "validation"))]
+        if validate {
+            ProtocolError::CorruptedCodeExecution(
+                "validation is not enabled but is being called on try_from_schema_v1".to_string(),
+            );
+        }
+
+        #[cfg(feature = "validation")]
+        let json_schema_validator = StatelessJsonSchemaLazyValidator::new();
+
+        #[cfg(feature = "validation")]
+        if validate {
+            // Make sure JSON Schema is compilable
+            let root_json_schema = root_schema
+                .try_to_validating_json()
+                .map_err(ProtocolError::ValueError)?;
+
+            json_schema_validator.compile(&root_json_schema, platform_version)?;
+
+            // Validate against JSON Schema
+            DOCUMENT_META_SCHEMA_V1
+                .validate(
+                    &root_schema
+                        .try_to_validating_json()
+                        .map_err(ProtocolError::ValueError)?,
+                )
+                .map_err(|mut errs| ConsensusError::from(errs.next().unwrap()))?;
+
+            // Validate document schema depth
+            let mut result = validate_max_depth(&root_schema, platform_version)?;
+
+            if !result.is_valid() {
+                let error = result.errors.remove(0);
+
+                return Err(ProtocolError::ConsensusError(Box::new(error)));
+            }
+
+            // TODO: Are we still aiming to use RE2 with linear time complexity to protect from ReDoS attacks?
+            //  If not we can remove this validation
+            // Validate reg exp compatibility with RE2 and byteArray usage
+            result.merge(traversal_validator(
+                &root_schema,
+                &[
+                    pattern_is_valid_regex_validator,
+                    byte_array_has_no_items_as_parent_validator,
+                ],
+                platform_version,
+            )?);
+
+            if !result.is_valid() {
+                let error = result.errors.remove(0);
+
+                return Err(ProtocolError::ConsensusError(Box::new(error)));
+            }
+        }
+
+        let schema_map = schema.to_map().map_err(|err| {
+            ProtocolError::DataContractError(DataContractError::InvalidContractStructure(format!(
+                "document schema must be an object: {err}"
+            )))
+        })?;
+
+        // TODO: These properties aren't defined in JSON meta schema
+        // Do documents of this type keep history? (Overrides contract value)
+        let documents_keep_history: bool =
+            Value::inner_optional_bool_value(schema_map, "documentsKeepHistory")
+                .map_err(ProtocolError::ValueError)?
+                .unwrap_or(default_keeps_history);
+
+        // Are documents of this type mutable? (Overrides contract value)
+        let documents_mutable: bool =
+            Value::inner_optional_bool_value(schema_map, "documentsMutable")
+                .map_err(ProtocolError::ValueError)?
+                .unwrap_or(default_mutability);
+
+        // Can documents of this type be transferred to another identity?
+        let documents_transferable: bool =
+            Value::inner_optional_bool_value(schema_map, property_names::TRANSFERABLE)
+                .map_err(ProtocolError::ValueError)?
+                .unwrap_or_default();
+
+        // A transfer rewrites the stored document with its new owner
+        if documents_transferable && !documents_mutable {
+            return Err(ProtocolError::DataContractError(
+                DataContractError::InvalidContractStructure(
+                    "transferable documents must be mutable".to_string(),
+                ),
+            ));
+        }
+
+        // Can documents of this type be put up for sale?
+        let documents_tradeable: bool =
+            Value::inner_optional_bool_value(schema_map, property_names::TRADEABLE)
+                .map_err(ProtocolError::ValueError)?
+                .unwrap_or_default();
+
+        // Setting a price or selling a document rewrites the stored document
+        if documents_tradeable && !documents_mutable {
+            return Err(ProtocolError::DataContractError(
+                DataContractError::InvalidContractStructure(
+                    "tradeable documents must be mutable".to_string(),
+                ),
+            ));
+        }
+
+        // Extract the properties
+        let property_values = Value::inner_optional_index_map::<u64>(
+            schema_map,
+            property_names::PROPERTIES,
+            property_names::POSITION,
+        )?
+        .unwrap_or_default();
+
+        #[cfg(feature = "validation")]
+        if validate {
+            // We should validate that the positions are continuous
+            for (pos, value) in property_values.values().enumerate() {
+                if value.get_integer::<u32>(property_names::POSITION)? != pos as u32 {
+                    return Err(ConsensusError::BasicError(
+                        BasicError::MissingPositionsInDocumentTypePropertiesError(
+                            MissingPositionsInDocumentTypePropertiesError::new(
+                                pos as u32,
+                                data_contract_id,
+                                name.to_string(),
+                            ),
+                        ),
+                    )
+                    .into());
+                }
+            }
+        }
+
+        // Prepare internal data for efficient querying
+        let mut flattened_document_properties: IndexMap<String, DocumentProperty> = IndexMap::new();
+        let mut document_properties: IndexMap<String, DocumentProperty> = IndexMap::new();
+
+        let required_fields = Value::inner_recursive_optional_array_of_strings(
+            schema_map,
+            "".to_string(),
+            property_names::PROPERTIES,
+            property_names::REQUIRED,
+        );
+
+        // Based on the property name, determine the type
+        for (property_key, property_value) in property_values {
+            // TODO: It's very inefficient. It must be done in one iteration and flattened properties
+            //  must keep a reference? We even could keep only one collection
+            insert_values(
+                &mut flattened_document_properties,
+                &required_fields,
+                None,
+                property_key.clone(),
+                property_value,
+                &root_schema,
+            )?;
+
+            insert_values_nested(
+                &mut document_properties,
+                &required_fields,
+                property_key,
+                property_value,
+                &root_schema,
+            )?;
+        }
+
+        // The sale price of tradeable documents is kept with the document as a system property
+        if documents_tradeable {
+            let price_property = DocumentProperty {
+                property_type: DocumentPropertyType::Integer,
+                required: false,
+            };
+            flattened_document_properties.insert(
+                crate::document::property_names::PRICE.to_string(),
+                price_property.clone(),
+            );
+            document_properties.insert(
+                crate::document::property_names::PRICE.to_string(),
+                price_property,
+            );
+        }
+
+        // Initialize indices
+        let index_values =
+            Value::inner_optional_array_slice_value(schema_map, property_names::INDICES)?;
+
+        let mut index_names: HashSet<String> = HashSet::new();
+        let mut unique_indices_count = 0;
+        let mut tokenized_properties: HashSet<String> = HashSet::new();
+
+        let indices: Vec<Index> = index_values
+            .map(|index_values| {
+                index_values
+                    .iter()
+                    .map(|index_value| {
+                        let index: Index = index_value
+                            .as_map()
+                            .ok_or(ProtocolError::DataContractError(
+                                DataContractError::InvalidContractStructure(
+                                    "index definition is not a map as expected".to_string(),
+                                ),
+                            ))?
+                            .as_slice()
+                            .try_into()?;
+
+                        #[cfg(feature = "validation")]
+                        if validate {
+                            // Unique indices produces significant load on the system during state validation
+                            // so we need to limit their number to prevent of spikes and DoS attacks
+                            if index.unique {
+                                unique_indices_count += 1;
+                                if unique_indices_count > UNIQUE_INDEX_LIMIT_V0 {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        UniqueIndicesLimitReachedError::new(
+                                            name.to_string(),
+                                            UNIQUE_INDEX_LIMIT_V0,
+                                        )
+                                        .into(),
+                                    )));
+                                }
+                            }
+
+                            // Index names must be unique for the document type
+                            if !index_names.insert(index.name.to_owned()) {
+                                return Err(ProtocolError::ConsensusError(Box::new(
+                                    DuplicateIndexNameError::new(name.to_string(), index.name)
+                                        .into(),
+                                )));
+                            }
+
+                            // Validate indexed properties
+                            index.properties.iter().try_for_each(|index_property| {
+                                // Do not allow to index already indexed system properties
+                                if NOT_ALLOWED_SYSTEM_PROPERTIES
+                                    .contains(&index_property.name.as_str())
+                                {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        SystemPropertyIndexAlreadyPresentError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                            index_property.name.to_owned(),
+                                        )
+                                        .into(),
+                                    )));
+                                }
+
+                                // Indexed property must be defined in user schema if it's not a system one
+                                if !SYSTEM_PROPERTIES.contains(&index_property.name.as_str()) {
+                                    let property_definition = flattened_document_properties
+                                        .get(&index_property.name)
+                                        .ok_or_else(|| {
+                                            ProtocolError::ConsensusError(Box::new(
+                                                UndefinedIndexPropertyError::new(
+                                                    name.to_owned(),
+                                                    index.name.to_owned(),
+                                                    index_property.name.to_owned(),
+                                                )
+                                                .into(),
+                                            ))
+                                        })?;
+
+                                    // The items of an array are each indexed like a property of their type
+                                    let array_item_property_type;
+                                    let indexed_property_type =
+                                        match &property_definition.property_type {
+                                            DocumentPropertyType::Array(
+                                                array_item_type @ (ArrayItemType::String(..)
+                                                | ArrayItemType::ByteArray(..)
+                                                | ArrayItemType::Identifier
+                                                | ArrayItemType::Integer
+                                                | ArrayItemType::Number),
+                                            ) => {
+                                                array_item_property_type =
+                                                    array_item_type.as_document_property_type();
+                                                &array_item_property_type
+                                            }
+                                            property_type => property_type,
+                                        };
+
+                                    // Tokenized strings are indexed by words, the length of the
+                                    // string limits how many of them a document has
+                                    let max_string_length = if index.tokenized {
+                                        MAX_TOKENIZED_STRING_PROPERTY_LENGTH
+                                    } else {
+                                        MAX_INDEXED_STRING_PROPERTY_LENGTH
+                                    };
+
+                                    // Validate indexed property type
+                                    match indexed_property_type {
+                                        // Objects and arrays of other items aren't supported for indexing yet
+                                        DocumentPropertyType::Array(_)
+                                        | DocumentPropertyType::Object(_)
+                                        | DocumentPropertyType::VariableTypeArray(_) => {
+                                            Err(ProtocolError::ConsensusError(Box::new(
+                                                InvalidIndexPropertyTypeError::new(
+                                                    name.to_owned(),
+                                                    index.name.to_owned(),
+                                                    index_property.name.to_owned(),
+                                                    property_definition.property_type.name(),
+                                                )
+                                                .into(),
+                                            )))
+                                        }
+                                        // Indexed byte array size must be limited
+                                        DocumentPropertyType::ByteArray(_, maybe_max_size)
+                                            if maybe_max_size.is_none()
+                                                || maybe_max_size.unwrap()
+                                                    > MAX_INDEXED_BYTE_ARRAY_PROPERTY_LENGTH =>
+                                        {
+                                            Err(ProtocolError::ConsensusError(Box::new(
+                                                InvalidIndexedPropertyConstraintError::new(
+                                                    name.to_owned(),
+                                                    index.name.to_owned(),
+                                                    index_property.name.to_owned(),
+                                                    "maxItems".to_string(),
+                                                    format!(
+                                                        "should be less or equal {}",
+                                                        MAX_INDEXED_BYTE_ARRAY_PROPERTY_LENGTH
+                                                    ),
+                                                )
+                                                .into(),
+                                            )))
+                                        }
+                                        // Indexed string length must be limited
+                                        DocumentPropertyType::String(_, maybe_max_length)
+                                            if maybe_max_length.is_none()
+                                                || maybe_max_length.unwrap() > max_string_length =>
+                                        {
+                                            Err(ProtocolError::ConsensusError(Box::new(
+                                                InvalidIndexedPropertyConstraintError::new(
+                                                    name.to_owned(),
+                                                    index.name.to_owned(),
+                                                    index_property.name.to_owned(),
+                                                    "maxLength".to_string(),
+                                                    format!(
+                                                        "should be less or equal {}",
+                                                        max_string_length
+                                                    ),
+                                                )
+                                                .into(),
+                                            )))
+                                        }
+                                        _ => Ok(()),
+                                    }
+                                } else {
+                                    Ok(())
+                                }
+                            })?;
+
+                            // The summed property must be an integer defined in user schema
+                            if let Some(sum_property) = &index.sum_property {
+                                let property_definition = flattened_document_properties
+                                    .get(sum_property)
+                                    .ok_or_else(|| {
+                                        ProtocolError::ConsensusError(Box::new(
+                                            UndefinedIndexPropertyError::new(
+                                                name.to_owned(),
+                                                index.name.to_owned(),
+                                                sum_property.to_owned(),
+                                            )
+                                            .into(),
+                                        ))
+                                    })?;
+
+                                if property_definition.property_type
+                                    != DocumentPropertyType::Integer
+                                {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        InvalidIndexPropertyTypeError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                            sum_property.to_owned(),
+                                            property_definition.property_type.name(),
+                                        )
+                                        .into(),
+                                    )));
+                                }
+                            }
+
+                            // A document has an entry for each item of an indexed array, so an index can
+                            // only have one array property, it can't be unique nor have a sum, and
+                            // the number of items must be limited
+                            let mut array_properties =
+                                index.properties.iter().filter(|index_property| {
+                                    matches!(
+                                        flattened_document_properties
+                                            .get(&index_property.name)
+                                            .map(|property| &property.property_type),
+                                        Some(DocumentPropertyType::Array(_))
+                                    )
+                                });
+                            if let Some(array_property) = array_properties.next() {
+                                if array_properties.next().is_some()
+                                    || index.unique
+                                    || index.sum_property.is_some()
+                                {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        InvalidIndexedPropertyConstraintError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                            array_property.name.to_owned(),
+                                            "type".to_string(),
+                                            "should be the only array property of a non unique index without sum"
+                                                .to_string(),
+                                        )
+                                        .into(),
+                                    )));
+                                }
+
+                                let max_items =
+                                    array_property_max_items(&schema, &array_property.name)?;
+                                if max_items.map_or(true, |max_items| {
+                                    max_items > MAX_INDEXED_ARRAY_ITEMS
+                                }) {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        InvalidIndexedPropertyConstraintError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                            array_property.name.to_owned(),
+                                            "maxItems".to_string(),
+                                            format!(
+                                                "should be less or equal {}",
+                                                MAX_INDEXED_ARRAY_ITEMS
+                                            ),
+                                        )
+                                        .into(),
+                                    )));
+                                }
+                            }
+
+                            // A tokenized index is on a single string property and only indexes its words
+                            if index.tokenized {
+                                let Some(index_property) = index.properties.first() else {
+                                    return Err(ProtocolError::DataContractError(
+                                        DataContractError::InvalidContractStructure(
+                                            "index should have properties".to_string(),
+                                        ),
+                                    ));
+                                };
+
+                                if index.properties.len() > 1
+                                    || index.unique
+                                    || index.sum_property.is_some()
+                                {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        InvalidIndexedPropertyConstraintError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                            index_property.name.to_owned(),
+                                            "tokenized".to_string(),
+                                            "should be the only property of a non unique index without sum"
+                                                .to_string(),
+                                        )
+                                        .into(),
+                                    )));
+                                }
+
+                                let property_type = flattened_document_properties
+                                    .get(&index_property.name)
+                                    .map(|property_definition| &property_definition.property_type);
+
+                                if !matches!(property_type, Some(DocumentPropertyType::String(..)))
+                                {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        InvalidIndexPropertyTypeError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                            index_property.name.to_owned(),
+                                            property_type
+                                                .map(|property_type| property_type.name())
+                                                .unwrap_or_else(|| "system".to_string()),
+                                        )
+                                        .into(),
+                                    )));
+                                }
+
+                                // Two tokenized indices on a property would hold the same words
+                                if !tokenized_properties.insert(index_property.name.to_owned()) {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        DuplicateIndexError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                        )
+                                        .into(),
+                                    )));
+                                }
+                            }
+                        }
+
+                        Ok(index)
+                    })
+                    .collect::<Result<Vec<Index>, ProtocolError>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        // Tokenized indices are stored apart from the index structure as they index words
+        let (token_indices, indices): (Vec<Index>, Vec<Index>) =
+            indices.into_iter().partition(|index| index.tokenized);
+
+        let index_structure =
+            IndexLevel::try_from_indices(indices.as_slice(), name, platform_version)?;
+
+        // Collect binary and identifier properties
+        let (identifier_paths, binary_paths) = DocumentType::find_identifier_and_binary_paths(
+            &document_properties,
+            &platform_version
+                .dpp
+                .contract_versions
+                .document_type_versions,
+        )?;
+
+        let security_level_requirement = schema
+            .get_optional_integer::<u8>(property_names::SECURITY_LEVEL_REQUIREMENT)?
+            .map(SecurityLevel::try_from)
+            .transpose()?
+            .unwrap_or(SecurityLevel::HIGH);
+
+        let requires_identity_encryption_bounded_key = schema
+            .get_optional_integer::<u8>(property_names::REQUIRES_IDENTITY_ENCRYPTION_BOUNDED_KEY)?
+            .map(StorageKeyRequirements::try_from)
+            .transpose()?;
+
+        let requires_identity_decryption_bounded_key = schema
+            .get_optional_integer::<u8>(property_names::REQUIRES_IDENTITY_DECRYPTION_BOUNDED_KEY)?
+            .map(StorageKeyRequirements::try_from)
+            .transpose()?;
+
+        Ok(DocumentTypeV0 {
+            name: String::from(name),
+            schema,
+            indices,
+            token_indices,
+            index_structure,
+            flattened_properties: flattened_document_properties,
+            properties: document_properties,
+            identifier_paths,
+            binary_paths,
+            required_fields,
+            documents_keep_history,
+            documents_mutable,
+            documents_transferable,
+            documents_tradeable,
+            data_contract_id,
+            requires_identity_encryption_bounded_key,
+            requires_identity_decryption_bounded_key,
+            security_level_requirement,
+            #[cfg(feature = "validation")]
+            json_schema_validator,
+        })
+    }
+}
+
+fn insert_values(
+    document_properties: &mut IndexMap<String, DocumentProperty>,
+    known_required: &BTreeSet<String>,
+    prefix: Option<String>,
+    property_key: String,
+    property_value: &Value,
+    root_schema: &Value,
+) -> Result<(), ProtocolError> {
+    let mut to_visit: Vec<(Option<String>, String, &Value)> =
+        vec![(prefix, property_key, property_value)];
+
+    while let Some((prefix, property_key, property_value)) = to_visit.pop() {
+        let prefixed_property_key = match prefix {
+            None => property_key,
+            Some(prefix) => [prefix, property_key].join(".").to_owned(),
+        };
+
+        let mut inner_properties = property_value.to_btree_ref_string_map()?;
+
+        if let Some(schema_ref) = inner_properties
+            .get_optional_str(property_names::REF)
+            .map_err(ProtocolError::ValueError)?
+        {
+            let referenced_sub_schema = resolve_uri(root_schema, schema_ref).map_err(|err| {
+                ProtocolError::Generic(format!("invalid schema reference url: {err}"))
+            })?;
+
+            inner_properties = referenced_sub_schema.to_btree_ref_string_map()?
+        }
+
+        let type_value = inner_properties
+            .get_str(property_names::TYPE)
+            .map_err(ProtocolError::ValueError)?;
+
+        let is_required = known_required.contains(&prefixed_property_key);
+        let field_type: DocumentPropertyType;
+
+        match type_value {
+            "array" => {
+                // Only handling bytearrays for v1
+                // Return an error if it is not a byte array
+                field_type = match inner_properties.get_optional_bool(property_names::BYTE_ARRAY)? {
+                    Some(inner_bool) => {
+                        if inner_bool {
+                            match inner_properties
+                                .get_optional_str(property_names::CONTENT_MEDIA_TYPE)?
+                            {
+                                Some("application/x.dash.dpp.identifier") => {
+                                    DocumentPropertyType::Identifier
+                                }
+                                Some(_) | None => DocumentPropertyType::ByteArray(
+                                    inner_properties
+                                        .get_optional_integer(property_names::MIN_ITEMS)?,
+                                    inner_properties
+                                        .get_optional_integer(property_names::MAX_ITEMS)?,
+                                ),
+                            }
+                        } else {
+                            return Err(ProtocolError::DataContractError(
+                                DataContractError::InvalidContractStructure(
+                                    "byteArray should always be true if defined".to_string(),
+                                ),
+                            ));
+                        }
+                    }
+                    // Other arrays are typed by the schema of their items
+                    None => DocumentPropertyType::Array(array_items_type(&inner_properties)?),
+                };
+
+                document_properties.insert(
+                    prefixed_property_key,
+                    DocumentProperty {
+                        property_type: field_type,
+                        required: is_required,
+                    },
+                );
+            }
+            "object" => {
+                if let Some(properties_as_value) = inner_properties.get(property_names::PROPERTIES)
+                {
+                    let properties =
+                        properties_as_value
+                            .as_map()
+                            .ok_or(ProtocolError::StructureError(
+                                StructureError::ValueWrongType("properties must be a map"),
+                            ))?;
+
+                    for (object_property_key, object_property_value) in properties.iter() {
+                        let object_property_string = object_property_key
+                            .as_text()
+                            .ok_or(ProtocolError::StructureError(StructureError::KeyWrongType(
+                                "property key must be a string",
+                            )))?
+                            .to_string();
+                        to_visit.push((
+                            Some(prefixed_property_key.clone()),
+                            object_property_string,
+                            object_property_value,
+                        ));
+                    }
+                }
+            }
+
+            "string" => {
+                field_type = DocumentPropertyType::String(
+                    inner_properties.get_optional_integer(property_names::MIN_LENGTH)?,
+                    inner_properties.get_optional_integer(property_names::MAX_LENGTH)?,
+                );
+                document_properties.insert(
+                    prefixed_property_key,
+                    DocumentProperty {
+                        property_type: field_type,
+                        required: is_required,
+                    },
+                );
+            }
+
+            _ => {
+                field_type = DocumentPropertyType::try_from_name(type_value)?;
+
+                document_properties.insert(
+                    prefixed_property_key,
+                    DocumentProperty {
+                        property_type: field_type,
+                        required: is_required,
+                    },
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+fn insert_values_nested(
+    document_properties: &mut IndexMap<String, DocumentProperty>,
+    known_required: &BTreeSet<String>,
+    property_key: String,
+    property_value: &Value,
+    root_schema: &Value,
+) -> Result<(), ProtocolError> {
+    let mut inner_properties = property_value.to_btree_ref_string_map()?;
+
+    if let Some(schema_ref) = inner_properties
+        .get_optional_str(property_names::REF)
+        .map_err(ProtocolError::ValueError)?
+    {
+        let referenced_sub_schema = resolve_uri(root_schema, schema_ref).map_err(|err| {
+            ProtocolError::Generic(format!("invalid schema reference url: {err}"))
+        })?;
+
+        inner_properties = referenced_sub_schema.to_btree_ref_string_map()?;
+    }
+
+    let type_value = inner_properties
+        .get_str(property_names::TYPE)
+        .map_err(ProtocolError::ValueError)?;
+
+    let is_required = known_required.contains(&property_key);
+
+    let field_type = match type_value {
+        "integer" => DocumentPropertyType::Integer,
+        "number" => DocumentPropertyType::Number,
+        "string" => DocumentPropertyType::String(
+            inner_properties.get_optional_integer(property_names::MIN_LENGTH)?,
+            inner_properties.get_optional_integer(property_names::MAX_LENGTH)?,
+        ),
+        "array" => {
+            // Only handling bytearrays for v1
+            // Return an error if it is not a byte array
+            match inner_properties.get_optional_bool(property_names::BYTE_ARRAY)? {
+                Some(inner_bool) => {
+                    if inner_bool {
+                        match inner_properties
+                            .get_optional_str(property_names::CONTENT_MEDIA_TYPE)?
+                        {
+                            Some("application/x.dash.dpp.identifier") => {
+                                DocumentPropertyType::Identifier
+                            }
+                            Some(_) | None => DocumentPropertyType::ByteArray(
+                                inner_properties.get_optional_integer(property_names::MIN_ITEMS)?,
+                                inner_properties.get_optional_integer(property_names::MAX_ITEMS)?,
+                            ),
+                        }
+                    } else {
+                        return Err(ProtocolError::DataContractError(
+                            DataContractError::InvalidContractStructure(
+                                "byteArray should always be true if defined".to_string(),
+                            ),
+                        ));
+                    }
+                }
+                // Other arrays are typed by the schema of their items
+                None => DocumentPropertyType::Array(array_items_type(&inner_properties)?),
+            }
+        }
+        "object" => {
+            let mut nested_properties = IndexMap::new();
+            if let Some(properties_as_value) = inner_properties.get(property_names::PROPERTIES) {
+                let properties =
+                    properties_as_value
+                        .as_map()
+                        .ok_or(ProtocolError::StructureError(
+                            StructureError::ValueWrongType("properties must be a map"),
+                        ))?;
+
+                let mut sorted_properties: Vec<_> = properties.iter().collect();
+
+                sorted_properties.sort_by(|(_, value_1), (_, value_2)| {
+                    let pos_1: u64 = value_1
+                        .get_integer(property_names::POSITION)
+                        .expect("expected a position");
+                    let pos_2: u64 = value_2
+                        .get_integer(property_names::POSITION)
+                        .expect("expected a position");
+                    pos_1.cmp(&pos_2)
+                });
+
+                // Create a new set with the prefix removed from the keys
+                let stripped_required: BTreeSet<String> = known_required
+                    .iter()
+                    .filter_map(|key| {
+                        if key.starts_with(&property_key) && key.len() > property_key.len() {
+                            Some(key[property_key.len() + 1..].to_string())
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+
+                for (object_property_key, object_property_value) in properties.iter() {
+                    let object_property_string = object_property_key
+                        .as_text()
+                        .ok_or(ProtocolError::StructureError(StructureError::KeyWrongType(
+                            "property key must be a string",
+                        )))?
+                        .to_string();
+
+                    insert_values_nested(
+                        &mut nested_properties,
+                        &stripped_required,
+                        object_property_string,
+                        object_property_value,
+                        root_schema,
+                    )?;
+                }
+            }
+            document_properties.insert(
+                property_key,
+                DocumentProperty {
+                    property_type: DocumentPropertyType::Object(nested_properties),
+                    required: is_required,
+                },
+            );
+            return Ok(());
+        }
+        _ => DocumentPropertyType::try_from_name(type_value)?,
+    };
+
+    document_properties.insert(
+        property_key,
+        DocumentProperty {
+            property_type: field_type,
+            required: is_required,
+        },
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "validation")]
+/// Returns the maximum number of items of an array property given its path in the document
+/// schema, references are not followed.
+fn array_property_max_items(
+    schema: &Value,
+    property_path: &str,
+) -> Result<Option<usize>, ProtocolError> {
+    let mut property_schema = schema;
+    for property_name in property_path.split('.') {
+        let Some(inner_property_schema) = property_schema
+            .get_optional_value(property_names::PROPERTIES)?
+            .map(|properties| properties.get_optional_value(property_name))
+            .transpose()?
+            .flatten()
+        else {
+            return Ok(None);
+        };
+        property_schema = inner_property_schema;
+    }
+    Ok(property_schema.get_optional_integer(property_names::MAX_ITEMS)?)
+}
+
+/// Returns the type of the items of an array property, arrays of items without a supported type
+/// keep being treated as arrays of booleans.
+fn array_items_type(
+    inner_properties: &BTreeMap<String, &Value>,
+) -> Result<ArrayItemType, ProtocolError> {
+    let Some(items) = inner_properties.get(property_names::ITEMS) else {
+        return Ok(ArrayItemType::Boolean);
+    };
+    let items = items.to_btree_ref_string_map()?;
+
+    let items_type = match items.get_optional_str(property_names::TYPE)? {
+        Some("string") => ArrayItemType::String(
+            items.get_optional_integer(property_names::MIN_LENGTH)?,
+            items.get_optional_integer(property_names::MAX_LENGTH)?,
+        ),
+        Some("integer") => ArrayItemType::Integer,
+        Some("number") => ArrayItemType::Number,
+        Some("array") if items.get_optional_bool(property_names::BYTE_ARRAY)? == Some(true) => {
+            match items.get_optional_str(property_names::CONTENT_MEDIA_TYPE)? {
+                Some("application/x.dash.dpp.identifier") => ArrayItemType::Identifier,
+                Some(_) | None => ArrayItemType::ByteArray(
+                    items.get_optional_integer(property_names::MIN_ITEMS)?,
+                    items.get_optional_integer(property_names::MAX_ITEMS)?,
+                ),
+            }
+        }
+        _ => ArrayItemType::Boolean,
+    };
+
+    Ok(items_type)
+}
@@ -15,16 +15,26 @@ use anyhow::anyhow;
 
 use platform_value::{Value, ValueMap};
 use rand::distributions::{Alphanumeric, DistString};
-use std::{collections::BTreeMap, convert::TryFrom};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
+};
 
 pub mod random_index;
 
+/// Words longer than this are not indexed by tokenized indices
+pub const MAX_INDEXED_TOKEN_LENGTH: usize = 63;
+
 // Indices documentation:  https://dashplatform.readme.io/docs/reference-data-contracts#document-indices
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Index {
     pub name: String,
     pub properties: Vec<IndexProperty>,
     pub unique: bool,
+    /// An integer property that is summed in a sum tree for each combination of index values
+    pub sum_property: Option<String>,
+    /// The words of the single string property of the index are indexed instead of its value
+    pub tokenized: bool,
 }
 
 impl Index {
@@ -51,6 +61,18 @@ impl Index {
             .map(|property| property.name.clone())
             .collect()
     }
+
+    /// The words of a text indexed by a tokenized index.
+    ///
+    /// Words are the lowercase runs of alphanumeric characters of the text, those longer than
+    /// `MAX_INDEXED_TOKEN_LENGTH` bytes are left out.
+    pub fn tokens(text: &str) -> BTreeSet<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase())
+            .filter(|word| word.len() <= MAX_INDEXED_TOKEN_LENGTH)
+            .collect()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -184,7 +206,9 @@ impl TryFrom<&[(Value, Value)]> for Index {
         // For properties, we iterate each and move it to IndexProperty
 
         let mut unique = false;
+        let mut tokenized = false;
         let mut name = None;
+        let mut sum_property = None;
         let mut index_properties: Vec<IndexProperty> = Vec::new();
 
         for (key_value, value_value) in index_type_value_map {
@@ -210,6 +234,25 @@ impl TryFrom<&[(Value, Value)]> for Index {
                         unique = value_value.as_bool().expect("confirmed as bool");
                     }
                 }
+                "tokenized" => {
+                    if value_value.is_bool() {
+                        tokenized = value_value.as_bool().expect("confirmed as bool");
+                    }
+                }
+                "sum" => {
+                    sum_property = Some(
+                        value_value
+                            .as_text()
+                            .ok_or({
+                                ProtocolError::DataContractError(
+                                    DataContractError::InvalidContractStructure(
+                                        "index sum should be a property name".to_string(),
+                                    ),
+                                )
+                            })?
+                            .to_owned(),
+                    );
+                }
                 "properties" => {
                     let properties =
                         value_value.as_array().ok_or(ProtocolError::StructureError(
@@ -244,6 +287,8 @@ impl TryFrom<&[(Value, Value)]> for Index {
             name,
             properties: index_properties,
             unique,
+            sum_property,
+            tokenized,
         })
     }
 }
@@ -52,6 +52,8 @@ impl Index {
             name: index_name,
             properties,
             unique,
+            sum_property: None,
+            tokenized: false,
         })
     }
 }
@@ -1,8 +1,11 @@
+use crate::data_contract::document_type::DocumentPropertyType;
 use crate::data_contract::errors::DataContractError;
 use crate::ProtocolError;
+use byteorder::{BigEndian, ReadBytesExt};
 use integer_encoding::VarInt;
 use platform_value::Value;
 use serde::{Deserialize, Serialize};
+use std::io::BufReader;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub enum ArrayItemType {
@@ -16,6 +19,77 @@ pub enum ArrayItemType {
 }
 
 impl ArrayItemType {
+    /// The property type of a single item, the items of an indexed array are each indexed like a
+    /// property of this type
+    pub fn as_document_property_type(&self) -> DocumentPropertyType {
+        let to_u16 =
+            |size: &Option<usize>| size.map(|size| u16::try_from(size).unwrap_or(u16::MAX));
+        match self {
+            ArrayItemType::Integer => DocumentPropertyType::Integer,
+            ArrayItemType::Number => DocumentPropertyType::Number,
+            ArrayItemType::String(min, max) => {
+                DocumentPropertyType::String(to_u16(min), to_u16(max))
+            }
+            ArrayItemType::ByteArray(min, max) => {
+                DocumentPropertyType::ByteArray(to_u16(min), to_u16(max))
+            }
+            ArrayItemType::Identifier => DocumentPropertyType::Identifier,
+            ArrayItemType::Boolean => DocumentPropertyType::Boolean,
+            ArrayItemType::Date => DocumentPropertyType::Date,
+        }
+    }
+
+    /// Reads an item encoded by `encode_value_with_size` from the buffer
+    pub fn read_from(&self, buf: &mut BufReader<&[u8]>) -> Result<Value, ProtocolError> {
+        match self {
+            ArrayItemType::String(_, _) => {
+                let bytes = DocumentPropertyType::read_varint_value(buf)?;
+                let string = String::from_utf8(bytes).map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading string from serialized array",
+                    ))
+                })?;
+                Ok(Value::Text(string))
+            }
+            ArrayItemType::Date | ArrayItemType::Number => {
+                let number = buf.read_f64::<BigEndian>().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading date/number from serialized array",
+                    ))
+                })?;
+                Ok(Value::Float(number))
+            }
+            ArrayItemType::Integer => {
+                let integer = buf.read_i64::<BigEndian>().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading integer from serialized array",
+                    ))
+                })?;
+                Ok(Value::I64(integer))
+            }
+            ArrayItemType::ByteArray(_, _) => {
+                Ok(Value::Bytes(DocumentPropertyType::read_varint_value(buf)?))
+            }
+            ArrayItemType::Identifier => {
+                let bytes = DocumentPropertyType::read_varint_value(buf)?;
+                let id: [u8; 32] = bytes.try_into().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading identifier from serialized array",
+                    ))
+                })?;
+                Ok(Value::Identifier(id))
+            }
+            ArrayItemType::Boolean => {
+                let value = buf.read_u8().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading bool from serialized array",
+                    ))
+                })?;
+                Ok(Value::Bool(value != 0))
+            }
+        }
+    }
+
     pub fn encode_value_with_size(&self, value: Value) -> Result<Vec<u8>, ProtocolError> {
         match self {
             ArrayItemType::String(_, _) => {
@@ -523,10 +523,16 @@ impl DocumentPropertyType {
                     Ok((Some(Value::Map(values)), false))
                 }
             }
-            DocumentPropertyType::Array(_array_field_type) => {
-                Err(ProtocolError::DataContractError(
-                    DataContractError::Unsupported("serialization of arrays not yet supported"),
-                ))
+            DocumentPropertyType::Array(array_field_type) => {
+                let item_count: usize = buf.read_varint().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading varint of array length",
+                    ))
+                })?;
+                let items = (0..item_count)
+                    .map(|_| array_field_type.read_from(buf))
+                    .collect::<Result<Vec<Value>, ProtocolError>>()?;
+                Ok((Some(Value::Array(items)), false))
             }
             DocumentPropertyType::VariableTypeArray(_) => Err(ProtocolError::DataContractError(
                 DataContractError::Unsupported("serialization of arrays not yet supported"),
@@ -824,6 +830,12 @@ impl DocumentPropertyType {
                     "we should never try encoding an object",
                 ),
             )),
+            // Arrays are indexed by their items, so a key is always for a single item
+            DocumentPropertyType::Array(array_field_type) if !matches!(value, Value::Array(_)) => {
+                array_field_type
+                    .as_document_property_type()
+                    .encode_value_for_tree_keys(value)
+            }
             DocumentPropertyType::Array(_) | DocumentPropertyType::VariableTypeArray(_) => {
                 Err(ProtocolError::DataContractError(
                     DataContractError::EncodingDataStructureNotSupported(
@@ -908,13 +920,15 @@ impl DocumentPropertyType {
                     "we should never try encoding an object",
                 ),
             )),
-            DocumentPropertyType::Array(_) | DocumentPropertyType::VariableTypeArray(_) => {
-                Err(ProtocolError::DataContractError(
-                    DataContractError::EncodingDataStructureNotSupported(
-                        "we should never try encoding an array",
-                    ),
-                ))
-            }
+            // Arrays are queried by their items
+            DocumentPropertyType::Array(array_field_type) => array_field_type
+                .as_document_property_type()
+                .value_from_string(str),
+            DocumentPropertyType::VariableTypeArray(_) => Err(ProtocolError::DataContractError(
+                DataContractError::EncodingDataStructureNotSupported(
+                    "we should never try encoding an array",
+                ),
+            )),
         }
     }
 
@@ -28,6 +28,10 @@ impl DocumentTypeV0Getters for DocumentTypeV0 {
         &self.indices
     }
 
+    fn token_indices(&self) -> &Vec<Index> {
+        &self.token_indices
+    }
+
     fn index_structure(&self) -> &IndexLevel {
         &self.index_structure
     }
@@ -60,6 +64,14 @@ impl DocumentTypeV0Getters for DocumentTypeV0 {
         self.documents_mutable
     }
 
+    fn documents_transferable(&self) -> bool {
+        self.documents_transferable
+    }
+
+    fn documents_tradeable(&self) -> bool {
+        self.documents_tradeable
+    }
+
     fn data_contract_id(&self) -> Identifier {
         self.data_contract_id
     }
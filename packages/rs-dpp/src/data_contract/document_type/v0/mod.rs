@@ -39,6 +39,8 @@ pub struct DocumentTypeV0 {
     pub(in crate::data_contract) name: String,
     pub(in crate::data_contract) schema: Value,
     pub(in crate::data_contract) indices: Vec<Index>,
+    /// Tokenized indices, they are not part of the index structure
+    pub(in crate::data_contract) token_indices: Vec<Index>,
     pub(in crate::data_contract) index_structure: IndexLevel,
     /// Flattened properties flatten all objects for quick lookups for indexes
     /// Document field should not contain sub objects.
@@ -53,6 +55,10 @@ pub struct DocumentTypeV0 {
     pub(in crate::data_contract) documents_keep_history: bool,
     /// Are documents mutable?
     pub(in crate::data_contract) documents_mutable: bool,
+    /// Can documents be transferred to another identity?
+    pub(in crate::data_contract) documents_transferable: bool,
+    /// Can documents be put up for sale and purchased by another identity?
+    pub(in crate::data_contract) documents_tradeable: bool,
     pub(in crate::data_contract) data_contract_id: Identifier,
     /// Encryption key storage requirements
     pub(in crate::data_contract) requires_identity_encryption_bounded_key:
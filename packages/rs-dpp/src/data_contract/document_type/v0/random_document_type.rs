@@ -403,6 +403,7 @@ impl DocumentTypeV0 {
             name,
             schema: schema.into(),
             indices,
+            token_indices: vec![],
             index_structure,
             flattened_properties: properties.clone(),
             properties,
@@ -411,6 +412,8 @@ impl DocumentTypeV0 {
             required_fields,
             documents_keep_history,
             documents_mutable,
+            documents_transferable: false,
+            documents_tradeable: false,
             data_contract_id,
             requires_identity_encryption_bounded_key: None,
             requires_identity_decryption_bounded_key: None,
@@ -0,0 +1,2 @@
+mod v0;
+pub(in crate::document) use v0::*;
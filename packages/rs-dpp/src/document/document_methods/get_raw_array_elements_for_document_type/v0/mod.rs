@@ -0,0 +1,34 @@
+use crate::data_contract::document_type::methods::DocumentTypeV0Methods;
+use crate::data_contract::document_type::DocumentTypeRef;
+use crate::document::DocumentV0Getters;
+use crate::version::PlatformVersion;
+use crate::ProtocolError;
+use platform_value::btreemap_extensions::BTreeValueMapPathHelper;
+use platform_value::Value;
+use std::collections::BTreeSet;
+
+pub trait DocumentGetRawArrayElementsForDocumentTypeV0: DocumentV0Getters {
+    /// Return the distinct serialized items of an array value given the path to its key for a
+    /// document type, sorted by their serialized form.
+    fn get_raw_array_elements_for_document_type_v0(
+        &self,
+        key_path: &str,
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Vec<Vec<u8>>>, ProtocolError> {
+        let Some(value) = self.properties().get_optional_at_path(key_path)? else {
+            return Ok(None);
+        };
+        let Value::Array(items) = value else {
+            return Err(ProtocolError::DecodingError(format!(
+                "expected an array at {}",
+                key_path
+            )));
+        };
+        let elements = items
+            .iter()
+            .map(|item| document_type.serialize_value_for_key(key_path, item, platform_version))
+            .collect::<Result<BTreeSet<Vec<u8>>, ProtocolError>>()?;
+        Ok(Some(elements.into_iter().collect()))
+    }
+}
@@ -3,10 +3,12 @@ use crate::data_contract::DataContract;
 use crate::version::PlatformVersion;
 use crate::ProtocolError;
 
+mod get_raw_array_elements_for_document_type;
 mod get_raw_for_contract;
 mod get_raw_for_document_type;
 mod hash;
 
+pub(in crate::document) use get_raw_array_elements_for_document_type::*;
 pub(in crate::document) use get_raw_for_contract::*;
 pub(in crate::document) use get_raw_for_document_type::*;
 pub(in crate::document) use hash::*;
@@ -31,6 +33,15 @@ pub trait DocumentMethodsV0 {
         platform_version: &PlatformVersion,
     ) -> Result<Option<Vec<u8>>, ProtocolError>;
 
+    /// Return the distinct serialized items of an array value given the path to its key for a
+    /// document type, or None if the document has no value at this path.
+    fn get_raw_array_elements_for_document_type(
+        &self,
+        key_path: &str,
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Vec<Vec<u8>>>, ProtocolError>;
+
     fn hash(
         &self,
         contract: &DataContract,
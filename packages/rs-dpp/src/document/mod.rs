@@ -33,8 +33,8 @@ pub const INITIAL_REVISION: u64 = 1;
 use crate::data_contract::document_type::DocumentTypeRef;
 use crate::data_contract::DataContract;
 use crate::document::document_methods::{
-    DocumentGetRawForContractV0, DocumentGetRawForDocumentTypeV0, DocumentHashV0Method,
-    DocumentMethodsV0,
+    DocumentGetRawArrayElementsForDocumentTypeV0, DocumentGetRawForContractV0,
+    DocumentGetRawForDocumentTypeV0, DocumentHashV0Method, DocumentMethodsV0,
 };
 use crate::document::errors::DocumentError;
 use crate::version::PlatformVersion;
@@ -136,6 +136,38 @@ impl DocumentMethodsV0 for Document {
         }
     }
 
+    /// Return the distinct serialized items of an array value given the path to its key for a
+    /// document type, or None if the document has no value at this path.
+    fn get_raw_array_elements_for_document_type(
+        &self,
+        key_path: &str,
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Vec<Vec<u8>>>, ProtocolError> {
+        match self {
+            Document::V0(document_v0) => {
+                match platform_version
+                    .dpp
+                    .document_versions
+                    .document_method_versions
+                    .get_raw_array_elements_for_document_type
+                {
+                    0 => document_v0.get_raw_array_elements_for_document_type_v0(
+                        key_path,
+                        document_type,
+                        platform_version,
+                    ),
+                    version => Err(ProtocolError::UnknownVersionMismatch {
+                        method: "DocumentMethodV0::get_raw_array_elements_for_document_type"
+                            .to_string(),
+                        known_versions: vec![0],
+                        received: version,
+                    }),
+                }
+            }
+        }
+    }
+
     fn hash(
         &self,
         contract: &DataContract,
@@ -184,9 +216,12 @@ impl DocumentMethodsV0 for Document {
 mod tests {
     use super::*;
     use crate::data_contract::accessors::v0::DataContractV0Getters;
+    use crate::data_contract::conversion::json::DataContractJsonConversionMethodsV0;
+    use crate::data_contract::document_type::accessors::DocumentTypeV0Getters;
     use crate::data_contract::document_type::random_document::CreateRandomDocument;
     use crate::document::serialization_traits::DocumentPlatformConversionMethodsV0;
-    use crate::tests::json_document::json_document_to_contract;
+    use crate::tests::json_document::{json_document_to_contract, json_document_to_json_value};
+    use platform_value::btreemap_extensions::BTreeValueMapHelper;
 
     use regex::Regex;
 
@@ -302,4 +337,43 @@ mod tests {
                 .expect("expected to deserialize domain document");
         }
     }
+
+    #[test]
+    fn test_serialize_deserialize_tradeable_document_keeps_price() {
+        let platform_version = PlatformVersion::latest();
+        let mut contract_value = json_document_to_json_value(
+            "../rs-drive/tests/supporting_files/contract/dashpay/dashpay-contract.json",
+        )
+        .expect("expected to get contract json");
+        contract_value["documentSchemas"]["profile"]["tradeable"] = true.into();
+        let contract = DataContract::from_json(contract_value, false, platform_version)
+            .expect("expected to get contract");
+
+        let document_type = contract
+            .document_type_for_name("profile")
+            .expect("expected to get profile document type");
+        assert!(document_type.documents_tradeable());
+
+        let mut document = document_type
+            .random_document(Some(3333), platform_version)
+            .expect("expected to get a random document");
+        document.set_u64(property_names::PRICE, 50000);
+
+        let serialized = <Document as DocumentPlatformConversionMethodsV0>::serialize(
+            &document,
+            document_type,
+            platform_version,
+        )
+        .expect("should serialize");
+        let deserialized = Document::from_bytes(&serialized, document_type, platform_version)
+            .expect("expected to deserialize profile document");
+
+        assert_eq!(
+            deserialized
+                .properties()
+                .get_optional_integer::<u64>(property_names::PRICE)
+                .expect("expected price to be an integer"),
+            Some(50000)
+        );
+    }
 }
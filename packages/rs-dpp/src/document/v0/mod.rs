@@ -20,7 +20,8 @@ use platform_value::Value;
 use serde::{Deserialize, Serialize};
 
 use crate::document::document_methods::{
-    DocumentGetRawForContractV0, DocumentGetRawForDocumentTypeV0, DocumentHashV0Method,
+    DocumentGetRawArrayElementsForDocumentTypeV0, DocumentGetRawForContractV0,
+    DocumentGetRawForDocumentTypeV0, DocumentHashV0Method,
 };
 
 use crate::identity::TimestampMillis;
@@ -68,6 +69,10 @@ impl DocumentGetRawForDocumentTypeV0 for DocumentV0 {
     //automatically done
 }
 
+impl DocumentGetRawArrayElementsForDocumentTypeV0 for DocumentV0 {
+    //automatically done
+}
+
 impl DocumentHashV0Method for DocumentV0 {
     //automatically done
 }
@@ -37,13 +37,16 @@ use crate::consensus::basic::identity::{
     InvalidAssetLockTransactionOutputReturnSizeError,
     InvalidIdentityAssetLockProofChainLockValidationError,
     InvalidIdentityAssetLockTransactionError, InvalidIdentityAssetLockTransactionOutputError,
-    InvalidIdentityCreditTransferAmountError, InvalidIdentityCreditWithdrawalTransitionAmountError,
+    InvalidIdentityCreditTransferAmountError, InvalidIdentityCreditTransferRecipientsCountError,
+    InvalidIdentityCreditWithdrawalTransitionAmountError,
     InvalidIdentityCreditWithdrawalTransitionCoreFeeError,
     InvalidIdentityCreditWithdrawalTransitionOutputScriptError, InvalidIdentityKeySignatureError,
     InvalidIdentityPublicKeyDataError, InvalidIdentityPublicKeySecurityLevelError,
+    InvalidIdentityRecoverTransitionMasterKeysError, InvalidIdentityRecoveryDelayError,
     InvalidIdentityUpdateTransitionDisableKeysError, InvalidIdentityUpdateTransitionEmptyError,
-    InvalidInstantAssetLockProofError, InvalidInstantAssetLockProofSignatureError,
-    MissingMasterPublicKeyError, NotImplementedIdentityCreditWithdrawalTransitionPoolingError,
+    InvalidIdentityUpdateTransitionKeyRotationError, InvalidInstantAssetLockProofError,
+    InvalidInstantAssetLockProofSignatureError, MissingMasterPublicKeyError,
+    NotImplementedIdentityCreditWithdrawalTransitionPoolingError,
 };
 use crate::consensus::basic::invalid_identifier_error::InvalidIdentifierError;
 use crate::consensus::basic::state_transition::{
@@ -312,6 +315,24 @@ pub enum BasicError {
 
     #[error(transparent)]
     IdentityCreditTransferToSelfError(IdentityCreditTransferToSelfError),
+
+    #[error(transparent)]
+    InvalidIdentityCreditTransferRecipientsCountError(
+        InvalidIdentityCreditTransferRecipientsCountError,
+    ),
+
+    #[error(transparent)]
+    InvalidIdentityRecoverTransitionMasterKeysError(
+        InvalidIdentityRecoverTransitionMasterKeysError,
+    ),
+
+    #[error(transparent)]
+    InvalidIdentityUpdateTransitionKeyRotationError(
+        InvalidIdentityUpdateTransitionKeyRotationError,
+    ),
+
+    #[error(transparent)]
+    InvalidIdentityRecoveryDelayError(InvalidIdentityRecoveryDelayError),
 }
 
 impl From<BasicError> for ConsensusError {
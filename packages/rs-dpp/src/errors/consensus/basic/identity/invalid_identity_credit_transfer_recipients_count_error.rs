@@ -0,0 +1,48 @@
+use crate::consensus::basic::BasicError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::consensus::ConsensusError;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "Credit transfer must have between 1 and {max_recipients} recipients, got {recipients_count}"
+)]
+#[platform_serialize(unversioned)]
+pub struct InvalidIdentityCreditTransferRecipientsCountError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pub recipients_count: u16,
+    pub max_recipients: u16,
+}
+
+impl InvalidIdentityCreditTransferRecipientsCountError {
+    pub fn new(recipients_count: u16, max_recipients: u16) -> Self {
+        Self {
+            recipients_count,
+            max_recipients,
+        }
+    }
+
+    pub fn recipients_count(&self) -> u16 {
+        self.recipients_count
+    }
+
+    pub fn max_recipients(&self) -> u16 {
+        self.max_recipients
+    }
+}
+
+impl From<InvalidIdentityCreditTransferRecipientsCountError> for ConsensusError {
+    fn from(err: InvalidIdentityCreditTransferRecipientsCountError) -> Self {
+        Self::BasicError(BasicError::InvalidIdentityCreditTransferRecipientsCountError(err))
+    }
+}
@@ -0,0 +1,35 @@
+use crate::consensus::basic::BasicError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::consensus::ConsensusError;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity Recover Transition must contain new master authentication keys if and only if it proposes a recovery")]
+#[platform_serialize(unversioned)]
+pub struct InvalidIdentityRecoverTransitionMasterKeysError;
+
+impl Default for InvalidIdentityRecoverTransitionMasterKeysError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InvalidIdentityRecoverTransitionMasterKeysError {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl From<InvalidIdentityRecoverTransitionMasterKeysError> for ConsensusError {
+    fn from(err: InvalidIdentityRecoverTransitionMasterKeysError) -> Self {
+        Self::BasicError(BasicError::InvalidIdentityRecoverTransitionMasterKeysError(
+            err,
+        ))
+    }
+}
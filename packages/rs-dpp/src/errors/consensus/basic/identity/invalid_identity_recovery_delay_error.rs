@@ -0,0 +1,47 @@
+use crate::consensus::basic::BasicError;
+use crate::errors::ProtocolError;
+use crate::identity::TimestampMillis;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::consensus::ConsensusError;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity recovery delay must be at least {min_recovery_delay_ms} ms, got {recovery_delay_ms} ms")]
+#[platform_serialize(unversioned)]
+pub struct InvalidIdentityRecoveryDelayError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pub recovery_delay_ms: TimestampMillis,
+    pub min_recovery_delay_ms: TimestampMillis,
+}
+
+impl InvalidIdentityRecoveryDelayError {
+    pub fn new(recovery_delay_ms: TimestampMillis, min_recovery_delay_ms: TimestampMillis) -> Self {
+        Self {
+            recovery_delay_ms,
+            min_recovery_delay_ms,
+        }
+    }
+
+    pub fn recovery_delay_ms(&self) -> TimestampMillis {
+        self.recovery_delay_ms
+    }
+
+    pub fn min_recovery_delay_ms(&self) -> TimestampMillis {
+        self.min_recovery_delay_ms
+    }
+}
+
+impl From<InvalidIdentityRecoveryDelayError> for ConsensusError {
+    fn from(err: InvalidIdentityRecoveryDelayError) -> Self {
+        Self::BasicError(BasicError::InvalidIdentityRecoveryDelayError(err))
+    }
+}
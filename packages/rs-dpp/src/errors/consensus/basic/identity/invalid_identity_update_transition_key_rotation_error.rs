@@ -0,0 +1,50 @@
+use crate::consensus::basic::BasicError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::consensus::ConsensusError;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "Identity Update Transition key rotation must disable keys, add replacement keys and have a grace period between 1 and {max_grace_period_ms} ms, got {grace_period_ms} ms"
+)]
+#[platform_serialize(unversioned)]
+pub struct InvalidIdentityUpdateTransitionKeyRotationError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pub grace_period_ms: u64,
+    pub max_grace_period_ms: u64,
+}
+
+impl InvalidIdentityUpdateTransitionKeyRotationError {
+    pub fn new(grace_period_ms: u64, max_grace_period_ms: u64) -> Self {
+        Self {
+            grace_period_ms,
+            max_grace_period_ms,
+        }
+    }
+
+    pub fn grace_period_ms(&self) -> u64 {
+        self.grace_period_ms
+    }
+
+    pub fn max_grace_period_ms(&self) -> u64 {
+        self.max_grace_period_ms
+    }
+}
+
+impl From<InvalidIdentityUpdateTransitionKeyRotationError> for ConsensusError {
+    fn from(err: InvalidIdentityUpdateTransitionKeyRotationError) -> Self {
+        Self::BasicError(BasicError::InvalidIdentityUpdateTransitionKeyRotationError(
+            err,
+        ))
+    }
+}
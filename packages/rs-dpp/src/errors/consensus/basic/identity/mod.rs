@@ -13,14 +13,18 @@ pub use invalid_identity_asset_lock_proof_chain_lock_validation_error::*;
 pub use invalid_identity_asset_lock_transaction_error::*;
 pub use invalid_identity_asset_lock_transaction_output_error::*;
 pub use invalid_identity_credit_transfer_amount_error::*;
+pub use invalid_identity_credit_transfer_recipients_count_error::*;
 pub use invalid_identity_credit_withdrawal_transition_amount_error::*;
 pub use invalid_identity_credit_withdrawal_transition_core_fee_error::*;
 pub use invalid_identity_credit_withdrawal_transition_output_script_error::*;
 pub use invalid_identity_key_signature_error::*;
 pub use invalid_identity_public_key_data_error::*;
 pub use invalid_identity_public_key_security_level_error::*;
+pub use invalid_identity_recover_transition_master_keys_error::*;
+pub use invalid_identity_recovery_delay_error::*;
 pub use invalid_identity_update_transition_disable_keys_error::*;
 pub use invalid_identity_update_transition_empty_error::*;
+pub use invalid_identity_update_transition_key_rotation_error::*;
 pub use invalid_instant_asset_lock_proof_error::*;
 pub use invalid_instant_asset_lock_proof_signature_error::*;
 pub use missing_master_public_key_error::*;
@@ -41,14 +45,18 @@ mod invalid_identity_asset_lock_proof_chain_lock_validation_error;
 mod invalid_identity_asset_lock_transaction_error;
 mod invalid_identity_asset_lock_transaction_output_error;
 mod invalid_identity_credit_transfer_amount_error;
+mod invalid_identity_credit_transfer_recipients_count_error;
 mod invalid_identity_credit_withdrawal_transition_amount_error;
 mod invalid_identity_credit_withdrawal_transition_core_fee_error;
 mod invalid_identity_credit_withdrawal_transition_output_script_error;
 mod invalid_identity_key_signature_error;
 mod invalid_identity_public_key_data_error;
 mod invalid_identity_public_key_security_level_error;
+mod invalid_identity_recover_transition_master_keys_error;
+mod invalid_identity_recovery_delay_error;
 mod invalid_identity_update_transition_disable_keys_error;
 mod invalid_identity_update_transition_empty_error;
+mod invalid_identity_update_transition_key_rotation_error;
 mod invalid_instant_asset_lock_proof_error;
 mod invalid_instant_asset_lock_proof_signature_error;
 mod missing_master_public_key_error;
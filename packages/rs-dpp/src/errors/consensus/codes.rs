@@ -111,6 +111,10 @@ impl ErrorWithCode for BasicError {
             Self::InvalidIdentityUpdateTransitionEmptyError(_) => 1063,
             Self::InvalidIdentityUpdateTransitionDisableKeysError(_) => 1064,
             Self::IdentityCreditTransferToSelfError(_) => 1070,
+            Self::InvalidIdentityCreditTransferRecipientsCountError(_) => 1071,
+            Self::InvalidIdentityRecoverTransitionMasterKeysError(_) => 1072,
+            Self::InvalidIdentityUpdateTransitionKeyRotationError(_) => 1073,
+            Self::InvalidIdentityRecoveryDelayError(_) => 1074,
 
             // State Transition
             Self::InvalidStateTransitionTypeError { .. } => 1043,
@@ -165,6 +169,11 @@ impl ErrorWithCode for StateError {
             Self::DuplicateUniqueIndexError { .. } => 4009,
             Self::InvalidDocumentRevisionError { .. } => 4010,
             Self::DocumentTimestampsAreEqualError(_) => 4025,
+            Self::DocumentNotTransferableError(_) => 4030,
+            Self::DocumentTransferToSelfError(_) => 4031,
+            Self::DocumentNotTradeableError(_) => 4032,
+            Self::DocumentNotForSaleError(_) => 4033,
+            Self::DocumentIncorrectPurchasePriceError(_) => 4034,
 
             // Identity
             Self::IdentityAlreadyExistsError(_) => 4011,
@@ -181,6 +190,11 @@ impl ErrorWithCode for StateError {
             Self::IdentityInsufficientBalanceError(_) => 4026,
             Self::IdentityPublicKeyAlreadyExistsForUniqueContractBoundsError(_) => 4028,
             Self::InvalidAssetLockProofValueError(_) => 4029,
+            Self::PendingTransferNotFoundError(_) => 4035,
+            Self::IdentityRecoveryProposalAlreadyExistsError(_) => 4036,
+            Self::IdentityRecoveryProposalNotFoundError(_) => 4037,
+            Self::IdentityRecoveryProposalNotExecutableError(_) => 4038,
+            Self::IdentityKeySpendingLimitExceededError(_) => 4039,
         }
     }
 }
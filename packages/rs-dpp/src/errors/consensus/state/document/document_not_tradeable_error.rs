@@ -0,0 +1,47 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "{document_id} document can not be sold: document type {document_type_name} is not tradeable"
+)]
+#[platform_serialize(unversioned)]
+pub struct DocumentNotTradeableError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    document_id: Identifier,
+    document_type_name: String,
+}
+
+impl DocumentNotTradeableError {
+    pub fn new(document_id: Identifier, document_type_name: String) -> Self {
+        Self {
+            document_id,
+            document_type_name,
+        }
+    }
+
+    pub fn document_id(&self) -> &Identifier {
+        &self.document_id
+    }
+
+    pub fn document_type_name(&self) -> &String {
+        &self.document_type_name
+    }
+}
+
+impl From<DocumentNotTradeableError> for ConsensusError {
+    fn from(err: DocumentNotTradeableError) -> Self {
+        Self::StateError(StateError::DocumentNotTradeableError(err))
+    }
+}
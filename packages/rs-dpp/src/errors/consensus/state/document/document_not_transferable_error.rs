@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("{document_id} document can not be transferred: document type {document_type_name} is not transferable")]
+#[platform_serialize(unversioned)]
+pub struct DocumentNotTransferableError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    document_id: Identifier,
+    document_type_name: String,
+}
+
+impl DocumentNotTransferableError {
+    pub fn new(document_id: Identifier, document_type_name: String) -> Self {
+        Self {
+            document_id,
+            document_type_name,
+        }
+    }
+
+    pub fn document_id(&self) -> &Identifier {
+        &self.document_id
+    }
+
+    pub fn document_type_name(&self) -> &String {
+        &self.document_type_name
+    }
+}
+
+impl From<DocumentNotTransferableError> for ConsensusError {
+    fn from(err: DocumentNotTransferableError) -> Self {
+        Self::StateError(StateError::DocumentNotTransferableError(err))
+    }
+}
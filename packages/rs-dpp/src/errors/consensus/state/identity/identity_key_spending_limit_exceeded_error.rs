@@ -0,0 +1,72 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::fee::Credits;
+use crate::identity::KeyID;
+use crate::prelude::Identifier;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Key {key_id} of identity {identity_id} can't spend {amount} credits, it already spent {spent} of its {spending_limit} credits limit this epoch")]
+#[platform_serialize(unversioned)]
+pub struct IdentityKeySpendingLimitExceededError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pub identity_id: Identifier,
+    pub key_id: KeyID,
+    pub spending_limit: Credits,
+    pub spent: Credits,
+    pub amount: Credits,
+}
+
+impl IdentityKeySpendingLimitExceededError {
+    pub fn new(
+        identity_id: Identifier,
+        key_id: KeyID,
+        spending_limit: Credits,
+        spent: Credits,
+        amount: Credits,
+    ) -> Self {
+        Self {
+            identity_id,
+            key_id,
+            spending_limit,
+            spent,
+            amount,
+        }
+    }
+
+    pub fn identity_id(&self) -> &Identifier {
+        &self.identity_id
+    }
+
+    pub fn key_id(&self) -> KeyID {
+        self.key_id
+    }
+
+    pub fn spending_limit(&self) -> Credits {
+        self.spending_limit
+    }
+
+    pub fn spent(&self) -> Credits {
+        self.spent
+    }
+
+    pub fn amount(&self) -> Credits {
+        self.amount
+    }
+}
+impl From<IdentityKeySpendingLimitExceededError> for ConsensusError {
+    fn from(err: IdentityKeySpendingLimitExceededError) -> Self {
+        Self::StateError(StateError::IdentityKeySpendingLimitExceededError(err))
+    }
+}
@@ -0,0 +1,38 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::prelude::Identifier;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity {identity_id} already has a recovery proposal")]
+#[platform_serialize(unversioned)]
+pub struct IdentityRecoveryProposalAlreadyExistsError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pub identity_id: Identifier,
+}
+
+impl IdentityRecoveryProposalAlreadyExistsError {
+    pub fn new(identity_id: Identifier) -> Self {
+        Self { identity_id }
+    }
+
+    pub fn identity_id(&self) -> &Identifier {
+        &self.identity_id
+    }
+}
+impl From<IdentityRecoveryProposalAlreadyExistsError> for ConsensusError {
+    fn from(err: IdentityRecoveryProposalAlreadyExistsError) -> Self {
+        Self::StateError(StateError::IdentityRecoveryProposalAlreadyExistsError(err))
+    }
+}
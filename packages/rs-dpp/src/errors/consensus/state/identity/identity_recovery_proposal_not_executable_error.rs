@@ -0,0 +1,64 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::identity::TimestampMillis;
+use crate::prelude::Identifier;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Recovery proposal of identity {identity_id} has {approvals} approvals out of {required_approvals} required and can't be executed before {executable_at}")]
+#[platform_serialize(unversioned)]
+pub struct IdentityRecoveryProposalNotExecutableError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pub identity_id: Identifier,
+    pub approvals: u16,
+    pub required_approvals: u16,
+    pub executable_at: TimestampMillis,
+}
+
+impl IdentityRecoveryProposalNotExecutableError {
+    pub fn new(
+        identity_id: Identifier,
+        approvals: u16,
+        required_approvals: u16,
+        executable_at: TimestampMillis,
+    ) -> Self {
+        Self {
+            identity_id,
+            approvals,
+            required_approvals,
+            executable_at,
+        }
+    }
+
+    pub fn identity_id(&self) -> &Identifier {
+        &self.identity_id
+    }
+
+    pub fn approvals(&self) -> u16 {
+        self.approvals
+    }
+
+    pub fn required_approvals(&self) -> u16 {
+        self.required_approvals
+    }
+
+    pub fn executable_at(&self) -> TimestampMillis {
+        self.executable_at
+    }
+}
+impl From<IdentityRecoveryProposalNotExecutableError> for ConsensusError {
+    fn from(err: IdentityRecoveryProposalNotExecutableError) -> Self {
+        Self::StateError(StateError::IdentityRecoveryProposalNotExecutableError(err))
+    }
+}
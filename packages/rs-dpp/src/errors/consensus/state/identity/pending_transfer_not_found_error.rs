@@ -0,0 +1,46 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::prelude::Identifier;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity {identity_id} has no pending transfer {transfer_id}")]
+#[platform_serialize(unversioned)]
+pub struct PendingTransferNotFoundError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pub identity_id: Identifier,
+    pub transfer_id: Identifier,
+}
+
+impl PendingTransferNotFoundError {
+    pub fn new(identity_id: Identifier, transfer_id: Identifier) -> Self {
+        Self {
+            identity_id,
+            transfer_id,
+        }
+    }
+
+    pub fn identity_id(&self) -> &Identifier {
+        &self.identity_id
+    }
+
+    pub fn transfer_id(&self) -> &Identifier {
+        &self.transfer_id
+    }
+}
+impl From<PendingTransferNotFoundError> for ConsensusError {
+    fn from(err: PendingTransferNotFoundError) -> Self {
+        Self::StateError(StateError::PendingTransferNotFoundError(err))
+    }
+}
@@ -9,10 +9,15 @@ use crate::consensus::state::data_contract::data_contract_is_readonly_error::Dat
 #[cfg(feature = "state-transition-validation")]
 use crate::consensus::state::data_trigger::DataTriggerError;
 use crate::consensus::state::document::document_already_present_error::DocumentAlreadyPresentError;
+use crate::consensus::state::document::document_incorrect_purchase_price_error::DocumentIncorrectPurchasePriceError;
+use crate::consensus::state::document::document_not_for_sale_error::DocumentNotForSaleError;
 use crate::consensus::state::document::document_not_found_error::DocumentNotFoundError;
+use crate::consensus::state::document::document_not_tradeable_error::DocumentNotTradeableError;
+use crate::consensus::state::document::document_not_transferable_error::DocumentNotTransferableError;
 use crate::consensus::state::document::document_owner_id_mismatch_error::DocumentOwnerIdMismatchError;
 use crate::consensus::state::document::document_timestamp_window_violation_error::DocumentTimestampWindowViolationError;
 use crate::consensus::state::document::document_timestamps_mismatch_error::DocumentTimestampsMismatchError;
+use crate::consensus::state::document::document_transfer_to_self_error::DocumentTransferToSelfError;
 use crate::consensus::state::document::duplicate_unique_index_error::DuplicateUniqueIndexError;
 use crate::consensus::state::document::invalid_document_revision_error::InvalidDocumentRevisionError;
 use crate::consensus::state::identity::duplicated_identity_public_key_id_state_error::DuplicatedIdentityPublicKeyIdStateError;
@@ -24,6 +29,11 @@ use crate::consensus::state::identity::invalid_identity_public_key_id_error::Inv
 use crate::consensus::state::identity::invalid_identity_revision_error::InvalidIdentityRevisionError;
 use crate::consensus::state::identity::max_identity_public_key_limit_reached_error::MaxIdentityPublicKeyLimitReachedError;
 use crate::consensus::state::identity::missing_identity_public_key_ids_error::MissingIdentityPublicKeyIdsError;
+use crate::consensus::state::identity::identity_key_spending_limit_exceeded_error::IdentityKeySpendingLimitExceededError;
+use crate::consensus::state::identity::identity_recovery_proposal_already_exists_error::IdentityRecoveryProposalAlreadyExistsError;
+use crate::consensus::state::identity::identity_recovery_proposal_not_executable_error::IdentityRecoveryProposalNotExecutableError;
+use crate::consensus::state::identity::identity_recovery_proposal_not_found_error::IdentityRecoveryProposalNotFoundError;
+use crate::consensus::state::identity::pending_transfer_not_found_error::PendingTransferNotFoundError;
 use crate::consensus::state::identity::{
     IdentityAlreadyExistsError, IdentityInsufficientBalanceError,
 };
@@ -125,6 +135,36 @@ pub enum StateError {
 
     #[error(transparent)]
     DataContractConfigUpdateError(DataContractConfigUpdateError),
+
+    #[error(transparent)]
+    DocumentNotTransferableError(DocumentNotTransferableError),
+
+    #[error(transparent)]
+    DocumentTransferToSelfError(DocumentTransferToSelfError),
+
+    #[error(transparent)]
+    DocumentNotTradeableError(DocumentNotTradeableError),
+
+    #[error(transparent)]
+    DocumentNotForSaleError(DocumentNotForSaleError),
+
+    #[error(transparent)]
+    DocumentIncorrectPurchasePriceError(DocumentIncorrectPurchasePriceError),
+
+    #[error(transparent)]
+    PendingTransferNotFoundError(PendingTransferNotFoundError),
+
+    #[error(transparent)]
+    IdentityRecoveryProposalAlreadyExistsError(IdentityRecoveryProposalAlreadyExistsError),
+
+    #[error(transparent)]
+    IdentityRecoveryProposalNotFoundError(IdentityRecoveryProposalNotFoundError),
+
+    #[error(transparent)]
+    IdentityRecoveryProposalNotExecutableError(IdentityRecoveryProposalNotExecutableError),
+
+    #[error(transparent)]
+    IdentityKeySpendingLimitExceededError(IdentityKeySpendingLimitExceededError),
 }
 
 impl From<StateError> for ConsensusError {
@@ -69,6 +69,9 @@ pub enum KnownCostItem {
     VerifySignatureEcdsaHash160,
     /// The cost for a Bip13ScriptHash signature verification
     VerifySignatureBip13ScriptHash,
+    /// The cost for every public key of the redeem script checked by a Bip13ScriptHash
+    /// signature verification
+    VerifySignatureBip13ScriptHashPerPublicKey,
     /// The cost for a Eddsa25519Hash160 signature verification
     VerifySignatureEddsa25519Hash160,
 }
@@ -95,6 +98,10 @@ lazy_static! {
             (KnownCostItem::VerifySignatureBLS12_381, 6000u64),
             (KnownCostItem::VerifySignatureEcdsaHash160, 4000u64),
             (KnownCostItem::VerifySignatureBip13ScriptHash, 6000u64),
+            (
+                KnownCostItem::VerifySignatureBip13ScriptHashPerPublicKey,
+                3000u64
+            ),
             (KnownCostItem::VerifySignatureEddsa25519Hash160, 3000u64),
         ])
     )]);
@@ -65,6 +65,12 @@ impl IdentityPublicKeyGettersV0 for IdentityPublicKey {
         }
     }
 
+    fn is_disabled_at(&self, time_ms: TimestampMillis) -> bool {
+        match self {
+            IdentityPublicKey::V0(v0) => v0.is_disabled_at(time_ms),
+        }
+    }
+
     fn contract_bounds(&self) -> Option<&ContractBounds> {
         match self {
             IdentityPublicKey::V0(v0) => v0.contract_bounds(),
@@ -35,6 +35,9 @@ pub trait IdentityPublicKeyGettersV0 {
     /// Is public key disabled
     fn is_disabled(&self) -> bool;
 
+    /// Is public key disabled at the given time, keys can be scheduled to be disabled in the future
+    fn is_disabled_at(&self, time_ms: TimestampMillis) -> bool;
+
     /// Contract bounds
     fn contract_bounds(&self) -> Option<&ContractBounds>;
 }
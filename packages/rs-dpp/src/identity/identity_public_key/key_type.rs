@@ -13,7 +13,8 @@ use lazy_static::lazy_static;
 use crate::block::epoch::Epoch;
 use crate::fee::default_costs::EpochCosts;
 use crate::fee::default_costs::KnownCostItem::{
-    VerifySignatureBLS12_381, VerifySignatureBip13ScriptHash, VerifySignatureEcdsaHash160,
+    VerifySignatureBLS12_381, VerifySignatureBip13ScriptHash,
+    VerifySignatureBip13ScriptHashPerPublicKey, VerifySignatureEcdsaHash160,
     VerifySignatureEcdsaSecp256k1, VerifySignatureEddsa25519Hash160,
 };
 use crate::fee::Credits;
@@ -57,7 +58,8 @@ lazy_static! {
         (KeyType::ECDSA_SECP256K1, 33),
         (KeyType::BLS12_381, 48),
         (KeyType::ECDSA_HASH160, 20),
-        (KeyType::BIP13_SCRIPT_HASH, 20)
+        (KeyType::BIP13_SCRIPT_HASH, 20),
+        (KeyType::EDDSA_25519_HASH160, 20)
     ]
     .iter()
     .copied()
@@ -98,35 +100,53 @@ impl KeyType {
         }
     }
 
+    /// The cost of verifying a signature made with a key of this type.
+    ///
+    /// `checked_public_keys` is the number of public keys a `BIP13_SCRIPT_HASH` signature may
+    /// be checked against, every other key type checks a single public key. It's only charged
+    /// from v1.
     pub fn signature_verify_cost(
         &self,
+        checked_public_keys: u16,
         platform_version: &PlatformVersion,
         epoch: &Epoch,
     ) -> Result<Credits, ProtocolError> {
         match platform_version.dpp.costs.signature_verify {
-            0 => Ok(match self {
-                KeyType::ECDSA_SECP256K1 => {
-                    epoch.cost_for_known_cost_item(VerifySignatureEcdsaSecp256k1)
-                }
-                KeyType::BLS12_381 => epoch.cost_for_known_cost_item(VerifySignatureBLS12_381),
-                KeyType::ECDSA_HASH160 => {
-                    epoch.cost_for_known_cost_item(VerifySignatureEcdsaHash160)
-                }
-                KeyType::BIP13_SCRIPT_HASH => {
-                    epoch.cost_for_known_cost_item(VerifySignatureBip13ScriptHash)
-                }
-                KeyType::EDDSA_25519_HASH160 => {
-                    epoch.cost_for_known_cost_item(VerifySignatureEddsa25519Hash160)
-                }
+            0 => Ok(self.signature_verify_cost_v0(epoch)),
+            1 => Ok(match self {
+                KeyType::BIP13_SCRIPT_HASH => epoch
+                    .cost_for_known_cost_item(VerifySignatureBip13ScriptHash)
+                    .saturating_add(
+                        epoch
+                            .cost_for_known_cost_item(VerifySignatureBip13ScriptHashPerPublicKey)
+                            .saturating_mul(checked_public_keys as Credits),
+                    ),
+                _ => self.signature_verify_cost_v0(epoch),
             }),
             version => Err(ProtocolError::UnknownVersionMismatch {
                 method: "KeyType::signature_verify_cost".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             }),
         }
     }
 
+    fn signature_verify_cost_v0(&self, epoch: &Epoch) -> Credits {
+        match self {
+            KeyType::ECDSA_SECP256K1 => {
+                epoch.cost_for_known_cost_item(VerifySignatureEcdsaSecp256k1)
+            }
+            KeyType::BLS12_381 => epoch.cost_for_known_cost_item(VerifySignatureBLS12_381),
+            KeyType::ECDSA_HASH160 => epoch.cost_for_known_cost_item(VerifySignatureEcdsaHash160),
+            KeyType::BIP13_SCRIPT_HASH => {
+                epoch.cost_for_known_cost_item(VerifySignatureBip13ScriptHash)
+            }
+            KeyType::EDDSA_25519_HASH160 => {
+                epoch.cost_for_known_cost_item(VerifySignatureEddsa25519Hash160)
+            }
+        }
+    }
+
     #[cfg(feature = "random-public-keys")]
     /// Gets the default size of the public key
     fn random_public_key_data_v0(&self, rng: &mut StdRng) -> Vec<u8> {
@@ -212,7 +232,7 @@ impl KeyType {
             KeyType::EDDSA_25519_HASH160 => {
                 let key_pair = ed25519_dalek::SigningKey::generate(rng);
                 (
-                    key_pair.verifying_key().to_bytes().to_vec(),
+                    ripemd160_sha256(key_pair.verifying_key().as_bytes()).to_vec(),
                     key_pair.to_bytes().to_vec(),
                 )
             }
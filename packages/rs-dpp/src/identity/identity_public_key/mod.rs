@@ -110,6 +110,9 @@ impl IdentityPublicKey {
 
 #[cfg(test)]
 mod tests {
+    use crate::identity::identity_public_key::accessors::v0::{
+        IdentityPublicKeyGettersV0, IdentityPublicKeySettersV0,
+    };
     use crate::identity::identity_public_key::v0::IdentityPublicKeyV0;
     use crate::identity::IdentityPublicKey;
     use crate::serialization::{PlatformDeserializable, PlatformSerializable};
@@ -134,4 +137,26 @@ mod tests {
                 .expect("expected to deserialize key");
         assert_eq!(key, unserialized)
     }
+
+    #[test]
+    fn test_identity_key_is_disabled_at() {
+        let mut rng = rand::rngs::StdRng::from_entropy();
+        let mut key: IdentityPublicKey =
+            IdentityPublicKeyV0::random_ecdsa_master_authentication_key_with_rng(
+                1,
+                &mut rng,
+                LATEST_PLATFORM_VERSION,
+            )
+            .expect("expected a random key")
+            .0
+            .into();
+
+        assert!(!key.is_disabled_at(1000));
+
+        key.set_disabled_at(1000);
+
+        assert!(!key.is_disabled_at(999));
+        assert!(key.is_disabled_at(1000));
+        assert!(key.is_disabled_at(1001));
+    }
 }
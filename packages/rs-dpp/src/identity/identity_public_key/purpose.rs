@@ -1,4 +1,6 @@
-use crate::identity::Purpose::{AUTHENTICATION, DECRYPTION, ENCRYPTION, SYSTEM, VOTING, WITHDRAW};
+use crate::identity::Purpose::{
+    AUTHENTICATION, DECRYPTION, ENCRYPTION, RECOVERY, SYSTEM, VOTING, WITHDRAW,
+};
 use anyhow::bail;
 use bincode::{Decode, Encode};
 #[cfg(feature = "cbor")]
@@ -37,6 +39,8 @@ pub enum Purpose {
     SYSTEM = 4,
     /// this key cannot be used for signing documents
     VOTING = 5,
+    /// this key can only be used by a guardian to recover the identity
+    RECOVERY = 6,
 }
 
 impl TryFrom<u8> for Purpose {
@@ -49,6 +53,7 @@ impl TryFrom<u8> for Purpose {
             3 => Ok(WITHDRAW),
             4 => Ok(SYSTEM),
             5 => Ok(VOTING),
+            6 => Ok(RECOVERY),
             value => bail!("unrecognized purpose: {}", value),
         }
     }
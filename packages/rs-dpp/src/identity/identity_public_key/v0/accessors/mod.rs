@@ -46,6 +46,11 @@ impl IdentityPublicKeyGettersV0 for IdentityPublicKeyV0 {
         self.disabled_at.is_some()
     }
 
+    fn is_disabled_at(&self, time_ms: TimestampMillis) -> bool {
+        self.disabled_at
+            .is_some_and(|disabled_at| disabled_at <= time_ms)
+    }
+
     fn contract_bounds(&self) -> Option<&ContractBounds> {
         self.contract_bounds.as_ref()
     }
@@ -0,0 +1,102 @@
+use crate::block::epoch::EpochIndex;
+use crate::fee::Credits;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use serde::{Deserialize, Serialize};
+
+/// The credits transferred or withdrawn by an identity key with a spending limit during an epoch.
+/// Only the spending of the current epoch counts against the limit of the key.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[platform_serialize(unversioned)]
+pub struct KeySpending {
+    /// The epoch in which the credits were spent
+    pub epoch_index: EpochIndex,
+    /// The credits spent by the key during the epoch
+    pub spent: Credits,
+}
+
+impl KeySpending {
+    /// The credits spent during the given epoch, spending of a previous epoch is not counted
+    pub fn spent_in_epoch(&self, epoch_index: EpochIndex) -> Credits {
+        if self.epoch_index == epoch_index {
+            self.spent
+        } else {
+            0
+        }
+    }
+
+    /// The spending of the key once the amount is spent during the given epoch, if it stays
+    /// within the spending limit
+    pub fn checked_spend(
+        &self,
+        epoch_index: EpochIndex,
+        amount: Credits,
+        spending_limit: Credits,
+    ) -> Option<KeySpending> {
+        let spent = self.spent_in_epoch(epoch_index).checked_add(amount)?;
+
+        (spent <= spending_limit).then_some(KeySpending { epoch_index, spent })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+
+    #[test]
+    fn should_reset_spending_once_the_epoch_changed() {
+        let key_spending = KeySpending {
+            epoch_index: 3,
+            spent: 800,
+        };
+
+        assert_eq!(key_spending.checked_spend(3, 300, 1000), None);
+        assert_eq!(
+            key_spending.checked_spend(3, 200, 1000),
+            Some(KeySpending {
+                epoch_index: 3,
+                spent: 1000
+            })
+        );
+        assert_eq!(
+            key_spending.checked_spend(4, 300, 1000),
+            Some(KeySpending {
+                epoch_index: 4,
+                spent: 300
+            })
+        );
+    }
+
+    #[test]
+    fn should_serialize_key_spending() {
+        let key_spending = KeySpending {
+            epoch_index: 3,
+            spent: 800,
+        };
+
+        let serialized = key_spending
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+
+        let deserialized =
+            KeySpending::deserialize_from_bytes(&serialized).expect("expected to deserialize");
+
+        assert_eq!(key_spending, deserialized);
+    }
+}
@@ -24,9 +24,12 @@ mod identity_facade;
 #[cfg(feature = "factories")]
 pub mod identity_factory;
 pub mod identity_nonce;
+pub mod key_spending;
 mod methods;
+pub mod pending_transfer;
 #[cfg(feature = "random-identities")]
 pub mod random;
+pub mod recovery_proposal;
 mod v0;
 pub mod versions;
 
@@ -0,0 +1,107 @@
+use crate::block::block_info::BlockInfo;
+use crate::fee::Credits;
+use crate::prelude::{IdentityNonce, TimestampMillis};
+use crate::util::hash::hash_double;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// The condition after which a pending transfer is released to its recipient
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransferReleaseCondition {
+    /// Released at the end of the first block with a time of at least this time in milliseconds
+    BlockTime(TimestampMillis),
+    /// Released at the end of the first block with a core chain locked height of at least this height
+    CoreBlockHeight(u32),
+}
+
+impl TransferReleaseCondition {
+    /// Returns true if the transfer is released at the end of the block
+    pub fn is_reached(&self, block_info: &BlockInfo) -> bool {
+        match self {
+            TransferReleaseCondition::BlockTime(time_ms) => block_info.time_ms >= *time_ms,
+            TransferReleaseCondition::CoreBlockHeight(core_height) => {
+                block_info.core_height >= *core_height
+            }
+        }
+    }
+}
+
+/// Credits removed from the balance of the sender that the recipient receives once the
+/// release condition is reached, unless the sender cancels the transfer before
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[platform_serialize(unversioned)]
+pub struct PendingTransfer {
+    pub id: Identifier,
+    pub sender_id: Identifier,
+    pub recipient_id: Identifier,
+    pub amount: Credits,
+    pub release_condition: TransferReleaseCondition,
+}
+
+impl PendingTransfer {
+    /// The id of the transfer scheduled by the sender with the identity nonce
+    pub fn id_from_sender_and_nonce(sender_id: Identifier, nonce: IdentityNonce) -> Identifier {
+        let mut buf = sender_id.to_vec();
+        buf.extend_from_slice(&nonce.to_be_bytes());
+
+        Identifier::new(hash_double(buf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+
+    #[test]
+    fn should_release_once_the_condition_is_reached() {
+        let block_info = BlockInfo {
+            time_ms: 1000,
+            core_height: 10,
+            ..Default::default()
+        };
+
+        assert!(TransferReleaseCondition::BlockTime(1000).is_reached(&block_info));
+        assert!(!TransferReleaseCondition::BlockTime(1001).is_reached(&block_info));
+        assert!(TransferReleaseCondition::CoreBlockHeight(9).is_reached(&block_info));
+        assert!(!TransferReleaseCondition::CoreBlockHeight(11).is_reached(&block_info));
+    }
+
+    #[test]
+    fn should_serialize_pending_transfer() {
+        let sender_id = Identifier::random();
+
+        let pending_transfer = PendingTransfer {
+            id: PendingTransfer::id_from_sender_and_nonce(sender_id, 1),
+            sender_id,
+            recipient_id: Identifier::random(),
+            amount: 100000,
+            release_condition: TransferReleaseCondition::CoreBlockHeight(1000),
+        };
+
+        let serialized = pending_transfer
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+
+        let deserialized =
+            PendingTransfer::deserialize_from_bytes(&serialized).expect("expected to deserialize");
+
+        assert_eq!(pending_transfer, deserialized);
+    }
+}
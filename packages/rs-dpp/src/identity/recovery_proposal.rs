@@ -0,0 +1,114 @@
+use crate::identity::{IdentityPublicKey, KeyID, TimestampMillis};
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// A replacement of the master keys of an identity proposed by the guardians holding its
+/// recovery keys. The master keys of the identity can veto it until the recovery delay passed.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[platform_serialize(unversioned)]
+pub struct IdentityRecoveryProposal {
+    /// The master keys replacing all the current master keys of the identity
+    pub new_master_keys: Vec<IdentityPublicKey>,
+    /// The recovery keys of the guardians that approved the proposal
+    pub approving_key_ids: BTreeSet<KeyID>,
+    /// The time of the block in which the proposal was made
+    pub proposed_at: TimestampMillis,
+}
+
+impl IdentityRecoveryProposal {
+    /// The amount of approvals needed to recover an identity, a majority of its recovery keys
+    pub fn required_approvals(recovery_key_count: usize) -> usize {
+        recovery_key_count / 2 + 1
+    }
+
+    /// The amount of approvals given by keys that are still enabled recovery keys of the identity
+    pub fn approvals(&self, recovery_key_ids: &BTreeSet<KeyID>) -> usize {
+        self.approving_key_ids
+            .intersection(recovery_key_ids)
+            .count()
+    }
+
+    /// Returns true if a majority of the recovery keys approved the proposal
+    pub fn is_approved(&self, recovery_key_ids: &BTreeSet<KeyID>) -> bool {
+        self.approvals(recovery_key_ids) >= Self::required_approvals(recovery_key_ids.len())
+    }
+
+    /// The time after which the proposal can be executed if it wasn't vetoed
+    pub fn executable_at(&self, recovery_delay_ms: TimestampMillis) -> TimestampMillis {
+        self.proposed_at.saturating_add(recovery_delay_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity::identity_public_key::v0::IdentityPublicKeyV0;
+    use crate::identity::{KeyType, Purpose, SecurityLevel};
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+    use platform_value::BinaryData;
+
+    fn recovery_proposal(approving_key_ids: BTreeSet<KeyID>) -> IdentityRecoveryProposal {
+        IdentityRecoveryProposal {
+            new_master_keys: vec![IdentityPublicKeyV0 {
+                id: 5,
+                purpose: Purpose::AUTHENTICATION,
+                security_level: SecurityLevel::MASTER,
+                contract_bounds: None,
+                key_type: KeyType::ECDSA_SECP256K1,
+                read_only: false,
+                data: BinaryData::new(vec![2; 33]),
+                disabled_at: None,
+            }
+            .into()],
+            approving_key_ids,
+            proposed_at: 1000,
+        }
+    }
+
+    #[test]
+    fn should_require_a_majority_of_enabled_recovery_keys() {
+        let recovery_key_ids = BTreeSet::from([1, 2, 3]);
+
+        assert!(!recovery_proposal(BTreeSet::from([1])).is_approved(&recovery_key_ids));
+        assert!(recovery_proposal(BTreeSet::from([1, 3])).is_approved(&recovery_key_ids));
+
+        // approvals of keys that were disabled since are not counted
+        let recovery_key_ids = BTreeSet::from([1, 2, 4]);
+
+        assert!(!recovery_proposal(BTreeSet::from([1, 3])).is_approved(&recovery_key_ids));
+
+        assert_eq!(
+            recovery_proposal(BTreeSet::from([1])).executable_at(500),
+            1500
+        );
+    }
+
+    #[test]
+    fn should_serialize_recovery_proposal() {
+        let recovery_proposal = recovery_proposal(BTreeSet::from([1, 2]));
+
+        let serialized = recovery_proposal
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+
+        let deserialized = IdentityRecoveryProposal::deserialize_from_bytes(&serialized)
+            .expect("expected to deserialize");
+
+        assert_eq!(recovery_proposal, deserialized);
+    }
+}
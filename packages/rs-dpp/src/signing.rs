@@ -4,6 +4,8 @@ use crate::consensus::signature::{
 };
 use crate::identity::KeyType;
 use crate::serialization::PlatformMessageSignable;
+#[cfg(feature = "message-signing")]
+use crate::util::ed25519_signer;
 #[cfg(feature = "message-signature-verification")]
 use crate::validation::SimpleConsensusValidationResult;
 use crate::{BlsModule, ProtocolError};
@@ -116,15 +118,19 @@ impl PlatformMessageSignable for &[u8] {
                 Ok(signature.to_vec())
             }
 
+            // the signature is prefixed with the Ed25519 public key, as only its hash is known
+            KeyType::EDDSA_25519_HASH160 => {
+                let signature = ed25519_signer::sign(self, private_key)?;
+                Ok(signature.to_vec())
+            }
+
             // the default behavior from
             // https://github.com/dashevo/platform/blob/6b02b26e5cd3a7c877c5fdfe40c4a4385a8dda15/packages/js-dpp/lib/stateTransition/AbstractStateTransition.js#L187
             // is to return the error for the BIP13_SCRIPT_HASH
-            KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => {
-                Err(ProtocolError::InvalidSigningKeyTypeError(format!(
-                    "key type {} can not sign",
-                    key_type.to_string()
-                )))
-            }
+            KeyType::BIP13_SCRIPT_HASH => Err(ProtocolError::InvalidSigningKeyTypeError(format!(
+                "key type {} can not sign",
+                key_type.to_string()
+            ))),
         }
     }
 }
@@ -18,7 +18,9 @@ mod state_transition_types;
 pub mod state_transition_factory;
 
 pub mod errors;
+use crate::util::ed25519_signer;
 use crate::util::hash::{hash_double_to_vec, hash_single, ripemd160_sha256};
+use crate::util::multisig_signer;
 
 pub mod proof_result;
 mod serialization;
@@ -40,6 +42,8 @@ use crate::identity::signer::Signer;
 use crate::identity::state_transition::OptionallyAssetLockProved;
 use crate::identity::{IdentityPublicKey, KeyID, KeyType, Purpose, SecurityLevel};
 use crate::prelude::AssetLockProof;
+#[cfg(feature = "state-transition-validation")]
+use crate::prelude::TimestampMillis;
 pub use state_transitions::*;
 
 use crate::serialization::Signable;
@@ -71,6 +75,9 @@ use crate::state_transition::identity_credit_transfer_transition::{
 use crate::state_transition::identity_credit_withdrawal_transition::{
     IdentityCreditWithdrawalTransition, IdentityCreditWithdrawalTransitionSignable,
 };
+use crate::state_transition::identity_recover_transition::{
+    IdentityRecoverTransition, IdentityRecoverTransitionSignable,
+};
 use crate::state_transition::identity_topup_transition::{
     IdentityTopUpTransition, IdentityTopUpTransitionSignable,
 };
@@ -93,6 +100,7 @@ macro_rules! call_method {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method($args),
             StateTransition::IdentityUpdate(st) => st.$method($args),
             StateTransition::IdentityCreditTransfer(st) => st.$method($args),
+            StateTransition::IdentityRecover(st) => st.$method($args),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -105,6 +113,7 @@ macro_rules! call_method {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method(),
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
+            StateTransition::IdentityRecover(st) => st.$method(),
         }
     };
 }
@@ -120,6 +129,7 @@ macro_rules! call_getter_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => Some(st.$method($args)),
             StateTransition::IdentityUpdate(st) => Some(st.$method($args)),
             StateTransition::IdentityCreditTransfer(st) => Some(st.$method($args)),
+            StateTransition::IdentityRecover(st) => Some(st.$method($args)),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -132,6 +142,7 @@ macro_rules! call_getter_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => Some(st.$method()),
             StateTransition::IdentityUpdate(st) => Some(st.$method()),
             StateTransition::IdentityCreditTransfer(st) => Some(st.$method()),
+            StateTransition::IdentityRecover(st) => Some(st.$method()),
         }
     };
 }
@@ -147,6 +158,7 @@ macro_rules! call_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method($args),
             StateTransition::IdentityUpdate(st) => st.$method($args),
             StateTransition::IdentityCreditTransfer(st) => st.$method($args),
+            StateTransition::IdentityRecover(st) => st.$method($args),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -159,6 +171,7 @@ macro_rules! call_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method(),
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
+            StateTransition::IdentityRecover(st) => st.$method(),
         }
     };
 }
@@ -178,6 +191,7 @@ macro_rules! call_errorable_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method($args),
             StateTransition::IdentityUpdate(st) => st.$method($args),
             StateTransition::IdentityCreditTransfer(st) => st.$method($args),
+            StateTransition::IdentityRecover(st) => st.$method($args),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -194,6 +208,7 @@ macro_rules! call_errorable_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method(),
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
+            StateTransition::IdentityRecover(st) => st.$method(),
         }
     };
 }
@@ -213,6 +228,7 @@ macro_rules! call_static_method {
             StateTransition::IdentityCreditTransfer(_) => {
                 IdentityCreditTransferTransition::$method()
             }
+            StateTransition::IdentityRecover(_) => IdentityRecoverTransition::$method(),
         }
     };
 }
@@ -244,6 +260,7 @@ pub enum StateTransition {
     IdentityCreditWithdrawal(IdentityCreditWithdrawalTransition),
     IdentityUpdate(IdentityUpdateTransition),
     IdentityCreditTransfer(IdentityCreditTransferTransition),
+    IdentityRecover(IdentityRecoverTransition),
 }
 
 impl OptionallyAssetLockProved for StateTransition {
@@ -281,6 +298,7 @@ impl StateTransition {
             Self::IdentityCreditWithdrawal(_) => "IdentityCreditWithdrawal",
             Self::IdentityUpdate(_) => "IdentityUpdate",
             Self::IdentityCreditTransfer(_) => "IdentityCreditTransfer",
+            Self::IdentityRecover(_) => "IdentityRecover",
         }
     }
 
@@ -384,6 +402,10 @@ impl StateTransition {
                 st.verify_public_key_level_and_purpose(identity_public_key)?;
                 st.verify_public_key_is_enabled(identity_public_key)?;
             }
+            StateTransition::IdentityRecover(st) => {
+                st.verify_public_key_level_and_purpose(identity_public_key)?;
+                st.verify_public_key_is_enabled(identity_public_key)?;
+            }
             StateTransition::IdentityCreate(_) => {
                 return Err(ProtocolError::CorruptedCodeExecution(
                     "identity create can not be called for identity signing".to_string(),
@@ -456,15 +478,23 @@ impl StateTransition {
                 }
                 self.sign_by_private_key(private_key, identity_public_key.key_type(), bls)
             }
+            KeyType::EDDSA_25519_HASH160 => {
+                let pub_key_hash = ed25519_signer::public_key_hash(private_key)?;
+
+                if identity_public_key.data().as_slice() != pub_key_hash {
+                    return Err(ProtocolError::InvalidSignaturePublicKeyError(
+                        InvalidSignaturePublicKeyError::new(identity_public_key.data().to_vec()),
+                    ));
+                }
+                self.sign_by_private_key(private_key, identity_public_key.key_type(), bls)
+            }
 
             // the default behavior from
             // https://github.com/dashevo/platform/blob/6b02b26e5cd3a7c877c5fdfe40c4a4385a8dda15/packages/js-dpp/lib/stateTransition/AbstractStateTransitionIdentitySigned.js#L108
             // is to return the error for the BIP13_SCRIPT_HASH
-            KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => {
-                Err(ProtocolError::InvalidIdentityPublicKeyTypeError(
-                    InvalidIdentityPublicKeyTypeError::new(identity_public_key.key_type()),
-                ))
-            }
+            KeyType::BIP13_SCRIPT_HASH => Err(ProtocolError::InvalidIdentityPublicKeyTypeError(
+                InvalidIdentityPublicKeyTypeError::new(identity_public_key.key_type()),
+            )),
         }?;
 
         self.set_signature_public_key_id(identity_public_key.id());
@@ -490,10 +520,15 @@ impl StateTransition {
                 self.set_signature(signature.to_vec().into());
             }
 
+            KeyType::EDDSA_25519_HASH160 => {
+                let signature = ed25519_signer::sign(&data, private_key)?;
+                self.set_signature(signature.to_vec().into());
+            }
+
             // the default behavior from
             // https://github.com/dashevo/platform/blob/6b02b26e5cd3a7c877c5fdfe40c4a4385a8dda15/packages/js-dpp/lib/stateTransition/AbstractStateTransition.js#L187
             // is to return the error for the BIP13_SCRIPT_HASH
-            KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => {
+            KeyType::BIP13_SCRIPT_HASH => {
                 return Err(ProtocolError::InvalidIdentityPublicKeyTypeError(
                     InvalidIdentityPublicKeyTypeError::new(key_type),
                 ))
@@ -515,11 +550,10 @@ impl StateTransition {
                 self.verify_ecdsa_hash_160_signature_by_public_key_hash(public_key)
             }
             KeyType::BLS12_381 => self.verify_bls_signature_by_public_key(public_key, bls),
-            KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => {
-                Err(ProtocolError::InvalidIdentityPublicKeyTypeError(
-                    InvalidIdentityPublicKeyTypeError::new(public_key_type),
-                ))
+            KeyType::EDDSA_25519_HASH160 => {
+                self.verify_eddsa_hash_160_signature_by_public_key_hash(public_key)
             }
+            KeyType::BIP13_SCRIPT_HASH => self.verify_multisig_signature_by_script_hash(public_key),
         }
     }
 
@@ -536,6 +570,33 @@ impl StateTransition {
             ));
         }
 
+        self.verify_signature_with_enabled_key(public_key, bls)
+    }
+
+    /// Verifies the signature with a key that may be scheduled to be disabled, the key is only
+    /// rejected once it is disabled at the given block time.
+    #[cfg(feature = "state-transition-validation")]
+    pub fn verify_signature_at_time(
+        &self,
+        public_key: &IdentityPublicKey,
+        block_time_ms: TimestampMillis,
+        bls: &impl BlsModule,
+    ) -> Result<(), ProtocolError> {
+        if public_key.is_disabled_at(block_time_ms) {
+            return Err(ProtocolError::PublicKeyIsDisabledError(
+                PublicKeyIsDisabledError::new(public_key.id()),
+            ));
+        }
+
+        self.verify_signature_with_enabled_key(public_key, bls)
+    }
+
+    #[cfg(feature = "state-transition-validation")]
+    fn verify_signature_with_enabled_key(
+        &self,
+        public_key: &IdentityPublicKey,
+        bls: &impl BlsModule,
+    ) -> Result<(), ProtocolError> {
         let signature = self.signature();
         if signature.is_empty() {
             return Err(ProtocolError::StateTransitionIsNotSignedError(
@@ -559,8 +620,13 @@ impl StateTransition {
 
             KeyType::BLS12_381 => self.verify_bls_signature_by_public_key(public_key_bytes, bls),
 
-            // per https://github.com/dashevo/platform/pull/353, signing and verification is not supported
-            KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => Ok(()),
+            KeyType::EDDSA_25519_HASH160 => {
+                self.verify_eddsa_hash_160_signature_by_public_key_hash(public_key_bytes)
+            }
+
+            KeyType::BIP13_SCRIPT_HASH => {
+                self.verify_multisig_signature_by_script_hash(public_key_bytes)
+            }
         }
     }
 
@@ -586,6 +652,50 @@ impl StateTransition {
         )
     }
 
+    #[cfg(all(feature = "state-transition-validation"))]
+    /// Verifies an EdDSA signature, prefixed with its Ed25519 public key, with the public key hash
+    fn verify_eddsa_hash_160_signature_by_public_key_hash(
+        &self,
+        public_key_hash: &[u8],
+    ) -> Result<(), ProtocolError> {
+        if self.signature().is_empty() {
+            return Err(ProtocolError::StateTransitionIsNotSignedError(
+                StateTransitionIsNotSignedError::new(self.clone()),
+            ));
+        }
+        let data = self.signable_bytes()?;
+        ed25519_signer::verify_data_signature(&data, self.signature().as_slice(), public_key_hash)
+            .map_err(|_| {
+                ProtocolError::from(ConsensusError::SignatureError(
+                    SignatureError::InvalidStateTransitionSignatureError(
+                        InvalidStateTransitionSignatureError::new(),
+                    ),
+                ))
+            })
+    }
+
+    #[cfg(all(feature = "state-transition-validation"))]
+    /// Verifies a multisig signature, carrying its redeem script, with the script hash
+    fn verify_multisig_signature_by_script_hash(
+        &self,
+        script_hash: &[u8],
+    ) -> Result<(), ProtocolError> {
+        if self.signature().is_empty() {
+            return Err(ProtocolError::StateTransitionIsNotSignedError(
+                StateTransitionIsNotSignedError::new(self.clone()),
+            ));
+        }
+        let data = self.signable_bytes()?;
+        multisig_signer::verify_data_signature(&data, self.signature().as_slice(), script_hash)
+            .map_err(|_| {
+                ProtocolError::from(ConsensusError::SignatureError(
+                    SignatureError::InvalidStateTransitionSignatureError(
+                        InvalidStateTransitionSignatureError::new(),
+                    ),
+                ))
+            })
+    }
+
     #[cfg(all(feature = "state-transition-validation"))]
     /// Verifies an ECDSA signature with the public key
     fn verify_ecdsa_signature_by_public_key(&self, public_key: &[u8]) -> Result<(), ProtocolError> {
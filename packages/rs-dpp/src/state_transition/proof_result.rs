@@ -1,6 +1,8 @@
 use crate::data_contract::DataContract;
 use crate::document::Document;
-use crate::identity::{Identity, PartialIdentity};
+use crate::identity::pending_transfer::PendingTransfer;
+use crate::identity::recovery_proposal::IdentityRecoveryProposal;
+use crate::identity::{Identity, PartialIdentity, TimestampMillis};
 use platform_value::Identifier;
 use std::collections::BTreeMap;
 
@@ -10,5 +12,9 @@ pub enum StateTransitionProofResult {
     VerifiedIdentity(Identity),
     VerifiedPartialIdentity(PartialIdentity),
     VerifiedBalanceTransfer(PartialIdentity, PartialIdentity), //from/to
+    VerifiedBalanceTransferToIdentities(PartialIdentity, Vec<PartialIdentity>), //from/to
+    VerifiedPendingTransfer(PartialIdentity, Identifier, Option<PendingTransfer>), //from/transfer
+    VerifiedIdentityRecoveryProposal(Identifier, Option<IdentityRecoveryProposal>), //identity/proposal
+    VerifiedIdentityRecoveryDelay(Identifier, Option<TimestampMillis>),             //identity/delay
     VerifiedDocuments(BTreeMap<Identifier, Option<Document>>),
 }
@@ -0,0 +1,58 @@
+use crate::fee::Credits;
+use crate::prelude::Revision;
+
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+
+use crate::state_transition::documents_batch_transition::document_transition::document_purchase_transition::DocumentPurchaseTransitionV0;
+
+pub trait DocumentPurchaseTransitionV0Methods {
+    /// Returns a reference to the `base` field of the `DocumentPurchaseTransitionV0`.
+    fn base(&self) -> &DocumentBaseTransition;
+    /// Returns a mut reference to the `base` field of the `DocumentPurchaseTransitionV0`.
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition;
+
+    /// Sets the value of the `base` field in the `DocumentPurchaseTransitionV0`.
+    fn set_base(&mut self, base: DocumentBaseTransition);
+
+    /// Returns the `revision` field of the `DocumentPurchaseTransitionV0`.
+    fn revision(&self) -> Revision;
+
+    /// Sets the value of the `revision` field in the `DocumentPurchaseTransitionV0`.
+    fn set_revision(&mut self, revision: Revision);
+
+    /// Returns the price in credits the buyer agrees to pay, it must match the listed price.
+    fn price(&self) -> Credits;
+
+    /// Sets the price in credits the buyer agrees to pay.
+    fn set_price(&mut self, price: Credits);
+}
+
+impl DocumentPurchaseTransitionV0Methods for DocumentPurchaseTransitionV0 {
+    fn base(&self) -> &DocumentBaseTransition {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition {
+        &mut self.base
+    }
+
+    fn set_base(&mut self, base: DocumentBaseTransition) {
+        self.base = base;
+    }
+
+    fn revision(&self) -> Revision {
+        self.revision
+    }
+
+    fn set_revision(&mut self, revision: Revision) {
+        self.revision = revision;
+    }
+
+    fn price(&self) -> Credits {
+        self.price
+    }
+
+    fn set_price(&mut self, price: Credits) {
+        self.price = price;
+    }
+}
@@ -1,6 +1,6 @@
 use platform_version::version::{FeatureVersion, PlatformVersion};
 use crate::data_contract::document_type::{DocumentTypeRef};
-use crate::document::{Document, DocumentV0Getters};
+use crate::document::{property_names, Document, DocumentV0Getters};
 use crate::document::errors::DocumentError;
 use crate::prelude::IdentityNonce;
 use crate::ProtocolError;
@@ -15,21 +15,27 @@ impl DocumentReplaceTransitionV0 {
         platform_version: &PlatformVersion,
         base_feature_version: Option<FeatureVersion>,
     ) -> Result<Self, ProtocolError> {
+        let base = DocumentBaseTransition::from_document(
+            &document,
+            document_type,
+            identity_contract_nonce,
+            platform_version,
+            base_feature_version,
+        )?;
+        let revision = document.revision().ok_or_else(|| {
+            ProtocolError::Document(Box::new(DocumentError::DocumentNoRevisionError {
+                document: Box::new(document.clone()),
+            }))
+        })?;
+        let updated_at = document.updated_at();
+        let mut data = document.properties_consumed();
+        // The price is a system property that can only be set by a price update
+        data.remove(property_names::PRICE);
         Ok(DocumentReplaceTransitionV0 {
-            base: DocumentBaseTransition::from_document(
-                &document,
-                document_type,
-                identity_contract_nonce,
-                platform_version,
-                base_feature_version,
-            )?,
-            revision: document.revision().ok_or_else(|| {
-                ProtocolError::Document(Box::new(DocumentError::DocumentNoRevisionError {
-                    document: Box::new(document.clone()),
-                }))
-            })?,
-            updated_at: document.updated_at(),
-            data: document.properties_consumed(),
+            base,
+            revision,
+            updated_at,
+            data,
         })
     }
 }
@@ -0,0 +1,59 @@
+use platform_value::Identifier;
+
+use crate::prelude::Revision;
+
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+
+use crate::state_transition::documents_batch_transition::document_transition::document_transfer_transition::DocumentTransferTransitionV0;
+
+pub trait DocumentTransferTransitionV0Methods {
+    /// Returns a reference to the `base` field of the `DocumentTransferTransitionV0`.
+    fn base(&self) -> &DocumentBaseTransition;
+    /// Returns a mut reference to the `base` field of the `DocumentTransferTransitionV0`.
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition;
+
+    /// Sets the value of the `base` field in the `DocumentTransferTransitionV0`.
+    fn set_base(&mut self, base: DocumentBaseTransition);
+
+    /// Returns the `revision` field of the `DocumentTransferTransitionV0`.
+    fn revision(&self) -> Revision;
+
+    /// Sets the value of the `revision` field in the `DocumentTransferTransitionV0`.
+    fn set_revision(&mut self, revision: Revision);
+
+    /// Returns the identity the document is transferred to.
+    fn recipient_owner_id(&self) -> Identifier;
+
+    /// Sets the identity the document is transferred to.
+    fn set_recipient_owner_id(&mut self, recipient_owner_id: Identifier);
+}
+
+impl DocumentTransferTransitionV0Methods for DocumentTransferTransitionV0 {
+    fn base(&self) -> &DocumentBaseTransition {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition {
+        &mut self.base
+    }
+
+    fn set_base(&mut self, base: DocumentBaseTransition) {
+        self.base = base;
+    }
+
+    fn revision(&self) -> Revision {
+        self.revision
+    }
+
+    fn set_revision(&mut self, revision: Revision) {
+        self.revision = revision;
+    }
+
+    fn recipient_owner_id(&self) -> Identifier {
+        self.recipient_owner_id
+    }
+
+    fn set_recipient_owner_id(&mut self, recipient_owner_id: Identifier) {
+        self.recipient_owner_id = recipient_owner_id;
+    }
+}
@@ -11,7 +11,10 @@ pub mod action_type;
 pub mod document_base_transition;
 pub mod document_create_transition;
 pub mod document_delete_transition;
+pub mod document_purchase_transition;
 pub mod document_replace_transition;
+pub mod document_transfer_transition;
+pub mod document_update_price_transition;
 
 use crate::identity::TimestampMillis;
 use crate::prelude::Revision;
@@ -19,12 +22,18 @@ use crate::state_transition::documents_batch_transition::document_base_transitio
 use derive_more::Display;
 pub use document_create_transition::DocumentCreateTransition;
 pub use document_delete_transition::DocumentDeleteTransition;
+pub use document_purchase_transition::DocumentPurchaseTransition;
 pub use document_replace_transition::DocumentReplaceTransition;
+pub use document_transfer_transition::DocumentTransferTransition;
+pub use document_update_price_transition::DocumentUpdatePriceTransition;
 use platform_value::Value;
 
 use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_create_transition::v0::v0_methods::DocumentCreateTransitionV0Methods;
 use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_replace_transition::v0::v0_methods::DocumentReplaceTransitionV0Methods;
 use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_delete_transition::v0::v0_methods::DocumentDeleteTransitionV0Methods;
+use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_transfer_transition::v0::v0_methods::DocumentTransferTransitionV0Methods;
+use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_update_price_transition::v0::v0_methods::DocumentUpdatePriceTransitionV0Methods;
+use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_purchase_transition::v0::v0_methods::DocumentPurchaseTransitionV0Methods;
 
 pub const PROPERTY_ACTION: &str = "$action";
 
@@ -83,6 +92,15 @@ pub enum DocumentTransition {
 
     #[display(fmt = "DeleteDocumentTransition({})", "_0")]
     Delete(DocumentDeleteTransition),
+
+    #[display(fmt = "TransferDocumentTransition({})", "_0")]
+    Transfer(DocumentTransferTransition),
+
+    #[display(fmt = "UpdatePriceDocumentTransition({})", "_0")]
+    UpdatePrice(DocumentUpdatePriceTransition),
+
+    #[display(fmt = "PurchaseDocumentTransition({})", "_0")]
+    Purchase(DocumentPurchaseTransition),
 }
 
 //
@@ -216,6 +234,30 @@ impl DocumentTransition {
             None
         }
     }
+
+    pub fn as_transition_transfer(&self) -> Option<&DocumentTransferTransition> {
+        if let Self::Transfer(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_transition_update_price(&self) -> Option<&DocumentUpdatePriceTransition> {
+        if let Self::UpdatePrice(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_transition_purchase(&self) -> Option<&DocumentPurchaseTransition> {
+        if let Self::Purchase(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
 }
 
 impl DocumentTransitionV0Methods for DocumentTransition {
@@ -224,6 +266,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.base(),
             DocumentTransition::Replace(t) => t.base(),
             DocumentTransition::Delete(t) => t.base(),
+            DocumentTransition::Transfer(t) => t.base(),
+            DocumentTransition::UpdatePrice(t) => t.base(),
+            DocumentTransition::Purchase(t) => t.base(),
         }
     }
 
@@ -232,6 +277,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.created_at(),
             DocumentTransition::Replace(_) => None,
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(_) => None,
+            DocumentTransition::UpdatePrice(_) => None,
+            DocumentTransition::Purchase(_) => None,
         }
     }
 
@@ -240,6 +288,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.updated_at(),
             DocumentTransition::Replace(t) => t.updated_at(),
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(_) => None,
+            DocumentTransition::UpdatePrice(_) => None,
+            DocumentTransition::Purchase(_) => None,
         }
     }
 
@@ -249,6 +300,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(ref mut t) => t.set_created_at(timestamp_millis),
             DocumentTransition::Replace(_) => {}
             DocumentTransition::Delete(_) => {}
+            DocumentTransition::Transfer(_) => {}
+            DocumentTransition::UpdatePrice(_) => {}
+            DocumentTransition::Purchase(_) => {}
         }
     }
 
@@ -257,6 +311,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(ref mut t) => t.set_updated_at(timestamp_millis),
             DocumentTransition::Replace(ref mut t) => t.set_updated_at(timestamp_millis),
             DocumentTransition::Delete(_) => {}
+            DocumentTransition::Transfer(_) => {}
+            DocumentTransition::UpdatePrice(_) => {}
+            DocumentTransition::Purchase(_) => {}
         }
     }
 
@@ -265,6 +322,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(_) => {}
             DocumentTransition::Replace(ref mut t) => t.set_revision(revision),
             DocumentTransition::Delete(_) => {}
+            DocumentTransition::Transfer(ref mut t) => t.set_revision(revision),
+            DocumentTransition::UpdatePrice(ref mut t) => t.set_revision(revision),
+            DocumentTransition::Purchase(ref mut t) => t.set_revision(revision),
         }
     }
 
@@ -273,6 +333,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.data().get(path),
             DocumentTransition::Replace(t) => t.data().get(path),
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(_) => None,
+            DocumentTransition::UpdatePrice(_) => None,
+            DocumentTransition::Purchase(_) => None,
         }
     }
 
@@ -293,6 +356,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => Some(t.data()),
             DocumentTransition::Replace(t) => Some(t.data()),
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(_) => None,
+            DocumentTransition::UpdatePrice(_) => None,
+            DocumentTransition::Purchase(_) => None,
         }
     }
 
@@ -301,6 +367,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(_) => Some(1),
             DocumentTransition::Replace(t) => Some(t.revision()),
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(t) => Some(t.revision()),
+            DocumentTransition::UpdatePrice(t) => Some(t.revision()),
+            DocumentTransition::Purchase(t) => Some(t.revision()),
         }
     }
 
@@ -318,6 +387,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
                     .insert(property_name, value);
             }
             DocumentTransition::Delete(_) => {}
+            DocumentTransition::Transfer(_) => {}
+            DocumentTransition::UpdatePrice(_) => {}
+            DocumentTransition::Purchase(_) => {}
         }
     }
 
@@ -330,6 +402,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.base_mut(),
             DocumentTransition::Replace(t) => t.base_mut(),
             DocumentTransition::Delete(t) => t.base_mut(),
+            DocumentTransition::Transfer(t) => t.base_mut(),
+            DocumentTransition::UpdatePrice(t) => t.base_mut(),
+            DocumentTransition::Purchase(t) => t.base_mut(),
         }
     }
 
@@ -338,6 +413,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => Some(t.data_mut()),
             DocumentTransition::Replace(t) => Some(t.data_mut()),
             DocumentTransition::Delete(_) => None,
+            DocumentTransition::Transfer(_) => None,
+            DocumentTransition::UpdatePrice(_) => None,
+            DocumentTransition::Purchase(_) => None,
         }
     }
 
@@ -346,6 +424,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.base().identity_contract_nonce(),
             DocumentTransition::Replace(t) => t.base().identity_contract_nonce(),
             DocumentTransition::Delete(t) => t.base().identity_contract_nonce(),
+            DocumentTransition::Transfer(t) => t.base().identity_contract_nonce(),
+            DocumentTransition::UpdatePrice(t) => t.base().identity_contract_nonce(),
+            DocumentTransition::Purchase(t) => t.base().identity_contract_nonce(),
         }
     }
 
@@ -354,6 +435,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Create(t) => t.base_mut().set_identity_contract_nonce(nonce),
             DocumentTransition::Replace(t) => t.base_mut().set_identity_contract_nonce(nonce),
             DocumentTransition::Delete(t) => t.base_mut().set_identity_contract_nonce(nonce),
+            DocumentTransition::Transfer(t) => t.base_mut().set_identity_contract_nonce(nonce),
+            DocumentTransition::UpdatePrice(t) => t.base_mut().set_identity_contract_nonce(nonce),
+            DocumentTransition::Purchase(t) => t.base_mut().set_identity_contract_nonce(nonce),
         }
     }
 }
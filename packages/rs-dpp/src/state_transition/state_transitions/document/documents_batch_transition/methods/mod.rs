@@ -1,8 +1,9 @@
 use crate::data_contract::document_type::DocumentTypeRef;
 use crate::document::Document;
+use crate::fee::Credits;
 use crate::identity::signer::Signer;
 use crate::identity::IdentityPublicKey;
-use crate::prelude::IdentityNonce;
+use crate::prelude::{Identifier, IdentityNonce};
 use crate::state_transition::documents_batch_transition::document_transition::DocumentTransition;
 use crate::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
 use crate::state_transition::documents_batch_transition::{
@@ -112,4 +113,136 @@ impl DocumentsBatchTransitionMethodsV0 for DocumentsBatchTransition {
             }),
         }
     }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_document_transfer_transition_from_document<S: Signer>(
+        document: Document,
+        document_type: DocumentTypeRef,
+        recipient_owner_id: Identifier,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        batch_feature_version: Option<FeatureVersion>,
+        transfer_feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match batch_feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .documents_batch_state_transition
+                .default_current_version,
+        ) {
+            0 => Ok(
+                DocumentsBatchTransitionV0::new_document_transfer_transition_from_document(
+                    document,
+                    document_type,
+                    recipient_owner_id,
+                    identity_public_key,
+                    identity_contract_nonce,
+                    signer,
+                    platform_version,
+                    batch_feature_version,
+                    transfer_feature_version,
+                    base_feature_version,
+                )?,
+            ),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "DocumentsBatchTransition::new_document_transfer_transition_from_document"
+                    .to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_document_update_price_transition_from_document<S: Signer>(
+        document: Document,
+        document_type: DocumentTypeRef,
+        price: Credits,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        batch_feature_version: Option<FeatureVersion>,
+        update_price_feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match batch_feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .documents_batch_state_transition
+                .default_current_version,
+        ) {
+            0 => Ok(
+                DocumentsBatchTransitionV0::new_document_update_price_transition_from_document(
+                    document,
+                    document_type,
+                    price,
+                    identity_public_key,
+                    identity_contract_nonce,
+                    signer,
+                    platform_version,
+                    batch_feature_version,
+                    update_price_feature_version,
+                    base_feature_version,
+                )?,
+            ),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method:
+                    "DocumentsBatchTransition::new_document_update_price_transition_from_document"
+                        .to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_document_purchase_transition_from_document<S: Signer>(
+        document: Document,
+        document_type: DocumentTypeRef,
+        new_owner_id: Identifier,
+        price: Credits,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        batch_feature_version: Option<FeatureVersion>,
+        purchase_feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match batch_feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .documents_batch_state_transition
+                .default_current_version,
+        ) {
+            0 => Ok(
+                DocumentsBatchTransitionV0::new_document_purchase_transition_from_document(
+                    document,
+                    document_type,
+                    new_owner_id,
+                    price,
+                    identity_public_key,
+                    identity_contract_nonce,
+                    signer,
+                    platform_version,
+                    batch_feature_version,
+                    purchase_feature_version,
+                    base_feature_version,
+                )?,
+            ),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "DocumentsBatchTransition::new_document_purchase_transition_from_document"
+                    .to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
 }
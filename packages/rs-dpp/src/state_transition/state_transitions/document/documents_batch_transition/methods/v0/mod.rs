@@ -1,5 +1,6 @@
 use crate::data_contract::document_type::DocumentTypeRef;
 use crate::document::Document;
+use crate::fee::Credits;
 use crate::identity::signer::Signer;
 use crate::identity::{IdentityPublicKey, SecurityLevel};
 use crate::prelude::IdentityNonce;
@@ -42,6 +43,55 @@ pub trait DocumentsBatchTransitionMethodsV0: DocumentsBatchTransitionAccessorsV0
         base_feature_version: Option<FeatureVersion>,
     ) -> Result<StateTransition, ProtocolError>;
 
+    /// Creates a signed batch transferring the document to the recipient identity.
+    /// The revision of the given document must already be bumped.
+    #[cfg(feature = "state-transition-signing")]
+    fn new_document_transfer_transition_from_document<S: Signer>(
+        document: Document,
+        document_type: DocumentTypeRef,
+        recipient_owner_id: Identifier,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        batch_feature_version: Option<FeatureVersion>,
+        transfer_feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Creates a signed batch setting the sale price of the document.
+    /// The revision of the given document must already be bumped.
+    #[cfg(feature = "state-transition-signing")]
+    fn new_document_update_price_transition_from_document<S: Signer>(
+        document: Document,
+        document_type: DocumentTypeRef,
+        price: Credits,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        batch_feature_version: Option<FeatureVersion>,
+        update_price_feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Creates a batch signed by the new owner purchasing the document at the given price.
+    /// The revision of the given document must already be bumped.
+    #[cfg(feature = "state-transition-signing")]
+    fn new_document_purchase_transition_from_document<S: Signer>(
+        document: Document,
+        document_type: DocumentTypeRef,
+        new_owner_id: Identifier,
+        price: Credits,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        batch_feature_version: Option<FeatureVersion>,
+        purchase_feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
     fn contract_based_security_level_requirement(
         &self,
         get_data_contract_security_level_requirement: impl Fn(
@@ -13,8 +13,11 @@ use crate::{identity::SecurityLevel, state_transition::StateTransitionFieldTypes
 pub use self::document_transition::{
     document_base_transition, document_create_transition,
     document_create_transition::DocumentCreateTransition, document_delete_transition,
-    document_delete_transition::DocumentDeleteTransition, document_replace_transition,
-    document_replace_transition::DocumentReplaceTransition,
+    document_delete_transition::DocumentDeleteTransition, document_purchase_transition,
+    document_purchase_transition::DocumentPurchaseTransition, document_replace_transition,
+    document_replace_transition::DocumentReplaceTransition, document_transfer_transition,
+    document_transfer_transition::DocumentTransferTransition, document_update_price_transition,
+    document_update_price_transition::DocumentUpdatePriceTransition,
 };
 
 use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
@@ -1,12 +1,14 @@
 use crate::data_contract::document_type::DocumentTypeRef;
 use crate::document::{Document, DocumentV0Getters};
+use crate::fee::Credits;
 use crate::identity::signer::Signer;
 use crate::identity::SecurityLevel;
-use crate::prelude::{IdentityNonce, IdentityPublicKey};
+use crate::prelude::{Identifier, IdentityNonce, IdentityPublicKey};
 use crate::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
 use crate::state_transition::documents_batch_transition::document_create_transition::DocumentCreateTransition;
 use crate::state_transition::documents_batch_transition::document_transition::{
-    DocumentReplaceTransition, DocumentTransition, DocumentTransitionV0Methods,
+    DocumentPurchaseTransition, DocumentReplaceTransition, DocumentTransferTransition,
+    DocumentTransition, DocumentTransitionV0Methods, DocumentUpdatePriceTransition,
 };
 use crate::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
 use crate::state_transition::documents_batch_transition::{
@@ -103,6 +105,124 @@ impl DocumentsBatchTransitionMethodsV0 for DocumentsBatchTransitionV0 {
         Ok(state_transition)
     }
 
+    #[cfg(feature = "state-transition-signing")]
+    fn new_document_transfer_transition_from_document<S: Signer>(
+        document: Document,
+        document_type: DocumentTypeRef,
+        recipient_owner_id: Identifier,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        _batch_feature_version: Option<FeatureVersion>,
+        transfer_feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let owner_id = document.owner_id();
+        let transfer_transition = DocumentTransferTransition::from_document(
+            document,
+            document_type,
+            identity_contract_nonce,
+            recipient_owner_id,
+            platform_version,
+            transfer_feature_version,
+            base_feature_version,
+        )?;
+        let documents_batch_transition: DocumentsBatchTransition = DocumentsBatchTransitionV0 {
+            owner_id,
+            transitions: vec![transfer_transition.into()],
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+        let mut state_transition: StateTransition = documents_batch_transition.into();
+        state_transition.sign_external(
+            identity_public_key,
+            signer,
+            Some(|_, _| Ok(SecurityLevel::HIGH)),
+        )?;
+        Ok(state_transition)
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_document_update_price_transition_from_document<S: Signer>(
+        document: Document,
+        document_type: DocumentTypeRef,
+        price: Credits,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        _batch_feature_version: Option<FeatureVersion>,
+        update_price_feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let owner_id = document.owner_id();
+        let update_price_transition = DocumentUpdatePriceTransition::from_document(
+            document,
+            document_type,
+            identity_contract_nonce,
+            price,
+            platform_version,
+            update_price_feature_version,
+            base_feature_version,
+        )?;
+        let documents_batch_transition: DocumentsBatchTransition = DocumentsBatchTransitionV0 {
+            owner_id,
+            transitions: vec![update_price_transition.into()],
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+        let mut state_transition: StateTransition = documents_batch_transition.into();
+        state_transition.sign_external(
+            identity_public_key,
+            signer,
+            Some(|_, _| Ok(SecurityLevel::HIGH)),
+        )?;
+        Ok(state_transition)
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_document_purchase_transition_from_document<S: Signer>(
+        document: Document,
+        document_type: DocumentTypeRef,
+        new_owner_id: Identifier,
+        price: Credits,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        _batch_feature_version: Option<FeatureVersion>,
+        purchase_feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let purchase_transition = DocumentPurchaseTransition::from_document(
+            document,
+            document_type,
+            identity_contract_nonce,
+            price,
+            platform_version,
+            purchase_feature_version,
+            base_feature_version,
+        )?;
+        // The purchase is made and signed by the buyer, not the current owner
+        let documents_batch_transition: DocumentsBatchTransition = DocumentsBatchTransitionV0 {
+            owner_id: new_owner_id,
+            transitions: vec![purchase_transition.into()],
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+        let mut state_transition: StateTransition = documents_batch_transition.into();
+        state_transition.sign_external(
+            identity_public_key,
+            signer,
+            Some(|_, _| Ok(SecurityLevel::HIGH)),
+        )?;
+        Ok(state_transition)
+    }
+
     fn set_transitions(&mut self, transitions: Vec<DocumentTransition>) {
         self.transitions = transitions;
     }
@@ -1,6 +1,7 @@
 use crate::consensus::basic::document::{
     DocumentTransitionsAreAbsentError, DuplicateDocumentTransitionsWithIdsError,
-    IdentityContractNonceOutOfBoundsError, MaxDocumentsTransitionsExceededError,
+    IdentityContractNonceOutOfBoundsError, InvalidDocumentTransitionActionError,
+    MaxDocumentsTransitionsExceededError,
 };
 use crate::consensus::basic::BasicError;
 
@@ -19,8 +20,6 @@ use platform_version::version::PlatformVersion;
 use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
 
-const MAX_TRANSITIONS_IN_BATCH: usize = 1;
-
 impl DocumentsBatchTransition {
     pub(super) fn validate_base_structure_v0<'d>(
         &self,
@@ -32,9 +31,37 @@ impl DocumentsBatchTransition {
             ));
         }
 
-        if self.transitions().len() > MAX_TRANSITIONS_IN_BATCH {
+        let max_transitions_in_documents_batch = platform_version
+            .dpp
+            .state_transitions
+            .documents
+            .documents_batch_transition
+            .validation
+            .max_transitions_in_documents_batch;
+
+        if self.transitions().len() > max_transitions_in_documents_batch as usize {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                MaxDocumentsTransitionsExceededError::new(
+                    max_transitions_in_documents_batch as u32,
+                )
+                .into(),
+            ));
+        }
+
+        // Documents can't be transferred nor traded yet
+        let unsupported_action =
+            self.transitions()
+                .iter()
+                .find_map(|document_transition| match document_transition {
+                    DocumentTransition::Transfer(_) => Some("transfer"),
+                    DocumentTransition::UpdatePrice(_) => Some("updatePrice"),
+                    DocumentTransition::Purchase(_) => Some("purchase"),
+                    _ => None,
+                });
+
+        if let Some(action) = unsupported_action {
             return Ok(SimpleConsensusValidationResult::new_with_error(
-                MaxDocumentsTransitionsExceededError::new(MAX_TRANSITIONS_IN_BATCH as u32).into(),
+                InvalidDocumentTransitionActionError::new(action.to_string()).into(),
             ));
         }
 
@@ -93,3 +120,201 @@ impl DocumentsBatchTransition {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::consensus::ConsensusError;
+    use crate::state_transition::documents_batch_transition::document_base_transition::v0::DocumentBaseTransitionV0;
+    use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+    use crate::state_transition::documents_batch_transition::document_create_transition::DocumentCreateTransitionV0;
+    use crate::state_transition::documents_batch_transition::document_purchase_transition::DocumentPurchaseTransitionV0;
+    use crate::state_transition::documents_batch_transition::document_transfer_transition::DocumentTransferTransitionV0;
+    use crate::state_transition::documents_batch_transition::document_transition::{
+        DocumentCreateTransition, DocumentPurchaseTransition, DocumentTransferTransition,
+        DocumentUpdatePriceTransition,
+    };
+    use crate::state_transition::documents_batch_transition::document_update_price_transition::DocumentUpdatePriceTransitionV0;
+    use crate::state_transition::documents_batch_transition::DocumentsBatchTransitionV0;
+
+    fn create_transition(
+        data_contract_id: Identifier,
+        identity_contract_nonce: u64,
+    ) -> DocumentTransition {
+        DocumentTransition::Create(DocumentCreateTransition::V0(DocumentCreateTransitionV0 {
+            base: DocumentBaseTransition::V0(DocumentBaseTransitionV0 {
+                id: Identifier::random(),
+                identity_contract_nonce,
+                document_type_name: "a".to_string(),
+                data_contract_id,
+            }),
+            entropy: Default::default(),
+            created_at: None,
+            updated_at: None,
+            data: Default::default(),
+        }))
+    }
+
+    fn transfer_transition(data_contract_id: Identifier) -> DocumentTransition {
+        DocumentTransition::Transfer(DocumentTransferTransition::V0(
+            DocumentTransferTransitionV0 {
+                base: DocumentBaseTransition::V0(DocumentBaseTransitionV0 {
+                    id: Identifier::random(),
+                    identity_contract_nonce: 1,
+                    document_type_name: "a".to_string(),
+                    data_contract_id,
+                }),
+                revision: 2,
+                recipient_owner_id: Identifier::random(),
+            },
+        ))
+    }
+
+    fn update_price_transition(data_contract_id: Identifier) -> DocumentTransition {
+        DocumentTransition::UpdatePrice(DocumentUpdatePriceTransition::V0(
+            DocumentUpdatePriceTransitionV0 {
+                base: DocumentBaseTransition::V0(DocumentBaseTransitionV0 {
+                    id: Identifier::random(),
+                    identity_contract_nonce: 1,
+                    document_type_name: "a".to_string(),
+                    data_contract_id,
+                }),
+                revision: 2,
+                price: 1000,
+            },
+        ))
+    }
+
+    fn purchase_transition(data_contract_id: Identifier) -> DocumentTransition {
+        DocumentTransition::Purchase(DocumentPurchaseTransition::V0(
+            DocumentPurchaseTransitionV0 {
+                base: DocumentBaseTransition::V0(DocumentBaseTransitionV0 {
+                    id: Identifier::random(),
+                    identity_contract_nonce: 1,
+                    document_type_name: "a".to_string(),
+                    data_contract_id,
+                }),
+                revision: 2,
+                price: 1000,
+            },
+        ))
+    }
+
+    #[test]
+    fn should_allow_a_batch_writing_to_multiple_contracts() {
+        let platform_version = PlatformVersion::latest();
+
+        let first_contract_id = Identifier::random();
+        let second_contract_id = Identifier::random();
+
+        let batch: DocumentsBatchTransition = DocumentsBatchTransitionV0 {
+            owner_id: Identifier::random(),
+            transitions: vec![
+                create_transition(first_contract_id, 1),
+                create_transition(first_contract_id, 2),
+                create_transition(second_contract_id, 1),
+            ],
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+
+        let result = batch
+            .validate_base_structure_v0(platform_version)
+            .expect("expected to validate base structure");
+
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn should_only_allow_a_single_transition_before_multiple_transitions_are_enabled() {
+        let platform_version = PlatformVersion::first();
+
+        let contract_id = Identifier::random();
+
+        let batch: DocumentsBatchTransition = DocumentsBatchTransitionV0 {
+            owner_id: Identifier::random(),
+            transitions: vec![
+                create_transition(contract_id, 1),
+                create_transition(contract_id, 2),
+            ],
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+
+        let result = batch
+            .validate_base_structure_v0(platform_version)
+            .expect("expected to validate base structure");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(
+                BasicError::MaxDocumentsTransitionsExceededError(_)
+            )]
+        ));
+    }
+
+    #[test]
+    fn should_only_allow_document_transfers_from_protocol_version_2() {
+        let batch: DocumentsBatchTransition = DocumentsBatchTransitionV0 {
+            owner_id: Identifier::random(),
+            transitions: vec![transfer_transition(Identifier::random())],
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+
+        let result = batch
+            .validate_base_structure(PlatformVersion::first())
+            .expect("expected to validate base structure");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(
+                BasicError::InvalidDocumentTransitionActionError(_)
+            )]
+        ));
+
+        let result = batch
+            .validate_base_structure(PlatformVersion::get(2).unwrap())
+            .expect("expected to validate base structure");
+
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn should_only_allow_document_trades_from_protocol_version_2() {
+        let contract_id = Identifier::random();
+
+        for transition in [
+            update_price_transition(contract_id),
+            purchase_transition(contract_id),
+        ] {
+            let batch: DocumentsBatchTransition = DocumentsBatchTransitionV0 {
+                owner_id: Identifier::random(),
+                transitions: vec![transition],
+                signature_public_key_id: 0,
+                signature: Default::default(),
+            }
+            .into();
+
+            let result = batch
+                .validate_base_structure(PlatformVersion::first())
+                .expect("expected to validate base structure");
+
+            assert!(matches!(
+                result.errors.as_slice(),
+                [ConsensusError::BasicError(
+                    BasicError::InvalidDocumentTransitionActionError(_)
+                )]
+            ));
+
+            let result = batch
+                .validate_base_structure(PlatformVersion::get(2).unwrap())
+                .expect("expected to validate base structure");
+
+            assert!(result.is_valid());
+        }
+    }
+}
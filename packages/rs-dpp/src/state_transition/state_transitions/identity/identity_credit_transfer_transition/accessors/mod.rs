@@ -1,6 +1,11 @@
 mod v0;
 
-use crate::prelude::{IdentityNonce, Revision};
+use std::collections::BTreeMap;
+
+use crate::fee::Credits;
+use crate::prelude::IdentityNonce;
+use crate::state_transition::identity_credit_transfer_transition::v1::IdentityCreditTransferTransitionV1;
+use crate::state_transition::identity_credit_transfer_transition::v2::PendingTransferOperation;
 use crate::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
 use platform_value::Identifier;
 pub use v0::*;
@@ -11,18 +16,41 @@ impl IdentityCreditTransferTransitionAccessorsV0 for IdentityCreditTransferTrans
             IdentityCreditTransferTransition::V0(transition) => {
                 transition.amount = amount;
             }
+            IdentityCreditTransferTransition::V1(transition) => {
+                let recipient_id = transition.first_recipient_id();
+                transition.recipients = BTreeMap::from([(recipient_id, amount)]);
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                if let PendingTransferOperation::Schedule {
+                    amount: scheduled_amount,
+                    ..
+                } = &mut transition.operation
+                {
+                    *scheduled_amount = amount;
+                }
+            }
         }
     }
 
     fn amount(&self) -> u64 {
         match self {
             IdentityCreditTransferTransition::V0(transition) => transition.amount,
+            IdentityCreditTransferTransition::V1(transition) => transition
+                .recipients
+                .values()
+                .fold(0, |total, amount| total.saturating_add(*amount)),
+            IdentityCreditTransferTransition::V2(transition) => match transition.operation {
+                PendingTransferOperation::Schedule { amount, .. } => amount,
+                PendingTransferOperation::Cancel { .. } => 0,
+            },
         }
     }
 
     fn identity_id(&self) -> Identifier {
         match self {
             IdentityCreditTransferTransition::V0(transition) => transition.identity_id,
+            IdentityCreditTransferTransition::V1(transition) => transition.identity_id,
+            IdentityCreditTransferTransition::V2(transition) => transition.identity_id,
         }
     }
 
@@ -31,32 +59,114 @@ impl IdentityCreditTransferTransitionAccessorsV0 for IdentityCreditTransferTrans
             IdentityCreditTransferTransition::V0(transition) => {
                 transition.identity_id = identity_id;
             }
+            IdentityCreditTransferTransition::V1(transition) => {
+                transition.identity_id = identity_id;
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                transition.identity_id = identity_id;
+            }
         }
     }
 
     fn recipient_id(&self) -> Identifier {
         match self {
             IdentityCreditTransferTransition::V0(transition) => transition.recipient_id,
+            IdentityCreditTransferTransition::V1(transition) => transition.first_recipient_id(),
+            IdentityCreditTransferTransition::V2(transition) => match transition.operation {
+                PendingTransferOperation::Schedule { recipient_id, .. } => recipient_id,
+                PendingTransferOperation::Cancel { .. } => Identifier::default(),
+            },
         }
     }
 
     fn set_recipient_id(&mut self, recipient_id: Identifier) {
+        let amount = self.amount();
         match self {
             IdentityCreditTransferTransition::V0(transition) => {
                 transition.recipient_id = recipient_id;
             }
+            IdentityCreditTransferTransition::V1(transition) => {
+                transition.recipients = BTreeMap::from([(recipient_id, amount)]);
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                if let PendingTransferOperation::Schedule {
+                    recipient_id: scheduled_recipient_id,
+                    ..
+                } = &mut transition.operation
+                {
+                    *scheduled_recipient_id = recipient_id;
+                }
+            }
+        }
+    }
+
+    fn recipients(&self) -> BTreeMap<Identifier, Credits> {
+        match self {
+            IdentityCreditTransferTransition::V0(transition) => {
+                BTreeMap::from([(transition.recipient_id, transition.amount)])
+            }
+            IdentityCreditTransferTransition::V1(transition) => transition.recipients.clone(),
+            IdentityCreditTransferTransition::V2(transition) => match transition.operation {
+                PendingTransferOperation::Schedule {
+                    recipient_id,
+                    amount,
+                    ..
+                } => BTreeMap::from([(recipient_id, amount)]),
+                PendingTransferOperation::Cancel { .. } => BTreeMap::new(),
+            },
+        }
+    }
+
+    fn set_recipients(&mut self, recipients: BTreeMap<Identifier, Credits>) {
+        match self {
+            IdentityCreditTransferTransition::V0(transition) => {
+                if recipients.len() > 1 {
+                    *self = IdentityCreditTransferTransitionV1 {
+                        identity_id: transition.identity_id,
+                        recipients,
+                        nonce: transition.nonce,
+                        signature_public_key_id: transition.signature_public_key_id,
+                        signature: transition.signature.clone(),
+                    }
+                    .into();
+                } else if let Some((recipient_id, amount)) = recipients.into_iter().next() {
+                    transition.recipient_id = recipient_id;
+                    transition.amount = amount;
+                }
+            }
+            IdentityCreditTransferTransition::V1(transition) => {
+                transition.recipients = recipients;
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                if let (
+                    PendingTransferOperation::Schedule {
+                        recipient_id,
+                        amount,
+                        ..
+                    },
+                    Some((first_recipient_id, first_amount)),
+                ) = (&mut transition.operation, recipients.into_iter().next())
+                {
+                    *recipient_id = first_recipient_id;
+                    *amount = first_amount;
+                }
+            }
         }
     }
 
     fn set_nonce(&mut self, nonce: IdentityNonce) {
         match self {
             IdentityCreditTransferTransition::V0(transition) => transition.nonce = nonce,
+            IdentityCreditTransferTransition::V1(transition) => transition.nonce = nonce,
+            IdentityCreditTransferTransition::V2(transition) => transition.nonce = nonce,
         }
     }
 
     fn nonce(&self) -> IdentityNonce {
         match self {
             IdentityCreditTransferTransition::V0(transition) => transition.nonce,
+            IdentityCreditTransferTransition::V1(transition) => transition.nonce,
+            IdentityCreditTransferTransition::V2(transition) => transition.nonce,
         }
     }
 }
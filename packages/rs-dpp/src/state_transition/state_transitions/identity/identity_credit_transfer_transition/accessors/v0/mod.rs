@@ -1,14 +1,27 @@
+use std::collections::BTreeMap;
+
+use crate::fee::Credits;
 use crate::prelude::IdentityNonce;
 
 use platform_value::Identifier;
 
 pub trait IdentityCreditTransferTransitionAccessorsV0 {
+    /// Sets the amount, a multi-recipient transfer becomes a transfer to its first recipient
     fn set_amount(&mut self, amount: u64);
+    /// The total amount transferred to all recipients, or the amount of a scheduled transfer
     fn amount(&self) -> u64;
     fn identity_id(&self) -> Identifier;
     fn set_identity_id(&mut self, identity_id: Identifier);
+    /// The recipient, the first recipient of a multi-recipient transfer
+    /// or the recipient of a scheduled transfer
     fn recipient_id(&self) -> Identifier;
+    /// Sets the recipient, a multi-recipient transfer becomes a transfer of its total amount
     fn set_recipient_id(&mut self, recipient_id: Identifier);
+    /// The amount transferred to each recipient
+    fn recipients(&self) -> BTreeMap<Identifier, Credits>;
+    /// Sets the amount transferred to each recipient, a transfer given more than one recipient
+    /// becomes a multi-recipient transfer, a scheduled transfer keeps only the first recipient
+    fn set_recipients(&mut self, recipients: BTreeMap<Identifier, Credits>);
     fn set_nonce(&mut self, nonce: IdentityNonce);
     fn nonce(&self) -> IdentityNonce;
 }
@@ -8,6 +8,12 @@ impl StateTransitionIdentitySigned for IdentityCreditTransferTransition {
             IdentityCreditTransferTransition::V0(transition) => {
                 transition.signature_public_key_id()
             }
+            IdentityCreditTransferTransition::V1(transition) => {
+                transition.signature_public_key_id()
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                transition.signature_public_key_id()
+            }
         }
     }
 
@@ -16,6 +22,12 @@ impl StateTransitionIdentitySigned for IdentityCreditTransferTransition {
             IdentityCreditTransferTransition::V0(transition) => {
                 transition.set_signature_public_key_id(key_id)
             }
+            IdentityCreditTransferTransition::V1(transition) => {
+                transition.set_signature_public_key_id(key_id)
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                transition.set_signature_public_key_id(key_id)
+            }
         }
     }
 
@@ -24,6 +36,12 @@ impl StateTransitionIdentitySigned for IdentityCreditTransferTransition {
             IdentityCreditTransferTransition::V0(transition) => {
                 transition.security_level_requirement()
             }
+            IdentityCreditTransferTransition::V1(transition) => {
+                transition.security_level_requirement()
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                transition.security_level_requirement()
+            }
         }
     }
 }
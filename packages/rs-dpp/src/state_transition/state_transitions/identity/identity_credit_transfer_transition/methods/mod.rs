@@ -3,5 +3,75 @@ mod v0;
 pub use v0::*;
 
 use crate::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+#[cfg(feature = "state-transition-signing")]
+use crate::{
+    fee::Credits,
+    identity::{signer::Signer, Identity},
+    prelude::{Identifier, IdentityNonce},
+    state_transition::{
+        identity_credit_transfer_transition::v1::IdentityCreditTransferTransitionV1,
+        identity_credit_transfer_transition::v2::{
+            IdentityCreditTransferTransitionV2, PendingTransferOperation,
+        },
+        StateTransition,
+    },
+    ProtocolError,
+};
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
+#[cfg(feature = "state-transition-signing")]
+use std::collections::BTreeMap;
 
-impl IdentityCreditTransferTransitionMethodsV0 for IdentityCreditTransferTransition {}
+impl IdentityCreditTransferTransitionMethodsV0 for IdentityCreditTransferTransition {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_to_recipients<S: Signer>(
+        identity: &Identity,
+        recipients: BTreeMap<Identifier, Credits>,
+        signer: S,
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        // only version 1 can transfer to more than one recipient
+        match version.unwrap_or(1) {
+            1 => Ok(IdentityCreditTransferTransitionV1::try_from_identity_to_recipients(
+                identity,
+                recipients,
+                signer,
+                nonce,
+                platform_version,
+                version,
+            )?),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown IdentityCreditTransferTransition version for try_from_identity_to_recipients {v}"
+            ))),
+        }
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_with_pending_transfer_operation<S: Signer>(
+        identity: &Identity,
+        operation: PendingTransferOperation,
+        signer: S,
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        // only version 2 has pending transfers
+        match version.unwrap_or(2) {
+            2 => Ok(
+                IdentityCreditTransferTransitionV2::try_from_identity_with_pending_transfer_operation(
+                    identity,
+                    operation,
+                    signer,
+                    nonce,
+                    platform_version,
+                    version,
+                )?,
+            ),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown IdentityCreditTransferTransition version for try_from_identity_with_pending_transfer_operation {v}"
+            ))),
+        }
+    }
+}
@@ -1,6 +1,47 @@
+#[cfg(feature = "state-transition-signing")]
+use std::collections::BTreeMap;
+
+#[cfg(feature = "state-transition-signing")]
+use crate::fee::Credits;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::Identity;
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{Identifier, IdentityNonce};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::identity_credit_transfer_transition::v2::PendingTransferOperation;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
 use crate::state_transition::StateTransitionType;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
 
 pub trait IdentityCreditTransferTransitionMethodsV0 {
+    /// Creates a signed transfer of credits from the identity to each of the recipients
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_to_recipients<S: Signer>(
+        identity: &Identity,
+        recipients: BTreeMap<Identifier, Credits>,
+        signer: S,
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Creates a signed transition scheduling or cancelling a pending transfer of the identity
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_with_pending_transfer_operation<S: Signer>(
+        identity: &Identity,
+        operation: PendingTransferOperation,
+        signer: S,
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
     /// Get State Transition Type
     fn get_type() -> StateTransitionType {
         StateTransitionType::IdentityCreditTransfer
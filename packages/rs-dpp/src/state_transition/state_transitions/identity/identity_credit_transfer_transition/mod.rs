@@ -6,6 +6,8 @@ mod json_conversion;
 pub mod methods;
 mod state_transition_like;
 pub mod v0;
+pub mod v1;
+pub mod v2;
 #[cfg(feature = "state-transition-value-conversion")]
 mod value_conversion;
 mod version;
@@ -13,6 +15,10 @@ mod version;
 use crate::state_transition::identity_credit_transfer_transition::fields::property_names::RECIPIENT_ID;
 use crate::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
 use crate::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0Signable;
+use crate::state_transition::identity_credit_transfer_transition::v1::IdentityCreditTransferTransitionV1;
+use crate::state_transition::identity_credit_transfer_transition::v1::IdentityCreditTransferTransitionV1Signable;
+use crate::state_transition::identity_credit_transfer_transition::v2::IdentityCreditTransferTransitionV2;
+use crate::state_transition::identity_credit_transfer_transition::v2::IdentityCreditTransferTransitionV2Signable;
 use crate::state_transition::StateTransitionFieldTypes;
 
 use crate::identity::state_transition::OptionallyAssetLockProved;
@@ -51,6 +57,10 @@ pub type IdentityCreditTransferTransitionLatest = IdentityCreditTransferTransiti
 pub enum IdentityCreditTransferTransition {
     #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "0"))]
     V0(IdentityCreditTransferTransitionV0),
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "1"))]
+    V1(IdentityCreditTransferTransitionV1),
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "2"))]
+    V2(IdentityCreditTransferTransitionV2),
 }
 
 impl IdentityCreditTransferTransition {
@@ -8,30 +8,41 @@ impl StateTransitionLike for IdentityCreditTransferTransition {
     fn modified_data_ids(&self) -> Vec<Identifier> {
         match self {
             IdentityCreditTransferTransition::V0(transition) => transition.modified_data_ids(),
+            IdentityCreditTransferTransition::V1(transition) => transition.modified_data_ids(),
+            IdentityCreditTransferTransition::V2(transition) => transition.modified_data_ids(),
         }
     }
 
     fn state_transition_protocol_version(&self) -> FeatureVersion {
         match self {
             IdentityCreditTransferTransition::V0(_) => 0,
+            IdentityCreditTransferTransition::V1(_) => 1,
+            IdentityCreditTransferTransition::V2(_) => 2,
+            IdentityCreditTransferTransition::V2(_) => 1,
         }
     }
     /// returns the type of State Transition
     fn state_transition_type(&self) -> StateTransitionType {
         match self {
             IdentityCreditTransferTransition::V0(transition) => transition.state_transition_type(),
+            IdentityCreditTransferTransition::V1(transition) => transition.state_transition_type(),
+            IdentityCreditTransferTransition::V2(transition) => transition.state_transition_type(),
         }
     }
     /// returns the signature as a byte-array
     fn signature(&self) -> &BinaryData {
         match self {
             IdentityCreditTransferTransition::V0(transition) => transition.signature(),
+            IdentityCreditTransferTransition::V1(transition) => transition.signature(),
+            IdentityCreditTransferTransition::V2(transition) => transition.signature(),
         }
     }
     /// set a new signature
     fn set_signature(&mut self, signature: BinaryData) {
         match self {
             IdentityCreditTransferTransition::V0(transition) => transition.set_signature(signature),
+            IdentityCreditTransferTransition::V1(transition) => transition.set_signature(signature),
+            IdentityCreditTransferTransition::V2(transition) => transition.set_signature(signature),
         }
     }
 
@@ -40,18 +51,28 @@ impl StateTransitionLike for IdentityCreditTransferTransition {
             IdentityCreditTransferTransition::V0(transition) => {
                 transition.set_signature_bytes(signature)
             }
+            IdentityCreditTransferTransition::V1(transition) => {
+                transition.set_signature_bytes(signature)
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                transition.set_signature_bytes(signature)
+            }
         }
     }
 
     fn owner_id(&self) -> Identifier {
         match self {
             IdentityCreditTransferTransition::V0(transition) => transition.owner_id(),
+            IdentityCreditTransferTransition::V1(transition) => transition.owner_id(),
+            IdentityCreditTransferTransition::V2(transition) => transition.owner_id(),
         }
     }
 
     fn unique_identifiers(&self) -> Vec<String> {
         match self {
             IdentityCreditTransferTransition::V0(transition) => transition.unique_identifiers(),
+            IdentityCreditTransferTransition::V1(transition) => transition.unique_identifiers(),
+            IdentityCreditTransferTransition::V2(transition) => transition.unique_identifiers(),
         }
     }
 }
@@ -1,4 +1,49 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::{
+    fee::Credits,
+    identity::{signer::Signer, Identity},
+    prelude::{Identifier, IdentityNonce},
+    state_transition::{
+        identity_credit_transfer_transition::v2::PendingTransferOperation, StateTransition,
+    },
+    ProtocolError,
+};
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
+#[cfg(feature = "state-transition-signing")]
+use std::collections::BTreeMap;
+
 use crate::state_transition::identity_credit_transfer_transition::methods::IdentityCreditTransferTransitionMethodsV0;
 use crate::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
 
-impl IdentityCreditTransferTransitionMethodsV0 for IdentityCreditTransferTransitionV0 {}
+impl IdentityCreditTransferTransitionMethodsV0 for IdentityCreditTransferTransitionV0 {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_to_recipients<S: Signer>(
+        _identity: &Identity,
+        _recipients: BTreeMap<Identifier, Credits>,
+        _signer: S,
+        _nonce: IdentityNonce,
+        _platform_version: &PlatformVersion,
+        _version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        Err(ProtocolError::UnknownVersionError(
+            "IdentityCreditTransferTransitionV0 can't transfer to multiple recipients, use version 1"
+                .to_string(),
+        ))
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_with_pending_transfer_operation<S: Signer>(
+        _identity: &Identity,
+        _operation: PendingTransferOperation,
+        _signer: S,
+        _nonce: IdentityNonce,
+        _platform_version: &PlatformVersion,
+        _version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        Err(ProtocolError::UnknownVersionError(
+            "IdentityCreditTransferTransitionV0 can't schedule transfers, use version 2"
+                .to_string(),
+        ))
+    }
+}
@@ -0,0 +1,4 @@
+use crate::state_transition::identity_credit_transfer_transition::v1::IdentityCreditTransferTransitionV1;
+use crate::state_transition::StateTransitionJsonConvert;
+
+impl<'a> StateTransitionJsonConvert<'a> for IdentityCreditTransferTransitionV1 {}
@@ -0,0 +1,92 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+pub(super) mod v1_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use std::collections::BTreeMap;
+
+use crate::fee::Credits;
+use crate::identity::KeyID;
+
+use crate::prelude::{Identifier, IdentityNonce};
+
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+use platform_value::BinaryData;
+use serde::{Deserialize, Serialize};
+
+/// Transfers credits from an identity to several recipients at once
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+    PlatformSignable,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[platform_serialize(unversioned)]
+#[derive(Default)]
+pub struct IdentityCreditTransferTransitionV1 {
+    // Own ST fields
+    pub identity_id: Identifier,
+    pub recipients: BTreeMap<Identifier, Credits>,
+    pub nonce: IdentityNonce,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
+
+impl IdentityCreditTransferTransitionV1 {
+    /// The recipient with the lowest identifier
+    pub(super) fn first_recipient_id(&self) -> Identifier {
+        self.recipients.keys().next().copied().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+
+    use crate::state_transition::identity_credit_transfer_transition::v1::IdentityCreditTransferTransitionV1;
+    use platform_value::Identifier;
+    use rand::Rng;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_identity_credit_transfer_transition_v1() {
+        let mut rng = rand::thread_rng();
+        let transition = IdentityCreditTransferTransitionV1 {
+            identity_id: Identifier::random(),
+            recipients: BTreeMap::from([
+                (Identifier::random(), rng.gen()),
+                (Identifier::random(), rng.gen()),
+            ]),
+            nonce: 1,
+            signature_public_key_id: rng.gen(),
+            signature: [0; 65].to_vec().into(),
+        };
+
+        let serialized = transition
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+        let deserialized =
+            IdentityCreditTransferTransitionV1::deserialize_from_bytes(serialized.as_slice())
+                .expect("expected to deserialize");
+        assert_eq!(transition, deserialized);
+    }
+}
@@ -0,0 +1,63 @@
+use platform_value::BinaryData;
+
+use crate::{
+    prelude::Identifier,
+    state_transition::{StateTransitionLike, StateTransitionType},
+};
+
+use crate::state_transition::identity_credit_transfer_transition::v1::IdentityCreditTransferTransitionV1;
+use crate::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+
+use crate::state_transition::StateTransition;
+use crate::state_transition::StateTransitionType::IdentityCreditTransfer;
+use crate::version::FeatureVersion;
+
+impl From<IdentityCreditTransferTransitionV1> for StateTransition {
+    fn from(value: IdentityCreditTransferTransitionV1) -> Self {
+        let identity_credit_transfer_transition: IdentityCreditTransferTransition = value.into();
+        identity_credit_transfer_transition.into()
+    }
+}
+
+impl StateTransitionLike for IdentityCreditTransferTransitionV1 {
+    fn state_transition_protocol_version(&self) -> FeatureVersion {
+        1
+    }
+
+    /// returns the type of State Transition
+    fn state_transition_type(&self) -> StateTransitionType {
+        IdentityCreditTransfer
+    }
+    /// returns the signature as a byte-array
+    fn signature(&self) -> &BinaryData {
+        &self.signature
+    }
+    /// set a new signature
+    fn set_signature(&mut self, signature: BinaryData) {
+        self.signature = signature
+    }
+    /// Returns IDs of the sender and all recipients
+    fn modified_data_ids(&self) -> Vec<Identifier> {
+        std::iter::once(self.identity_id)
+            .chain(self.recipients.keys().copied())
+            .collect()
+    }
+
+    fn set_signature_bytes(&mut self, signature: Vec<u8>) {
+        self.signature = BinaryData::new(signature)
+    }
+
+    /// Get owner ID
+    fn owner_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    /// We want things to be unique based on the nonce, so we don't add the transition type
+    fn unique_identifiers(&self) -> Vec<String> {
+        vec![format!(
+            "{}-{:x}",
+            base64::encode(self.identity_id),
+            self.nonce
+        )]
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::{
+    fee::Credits,
+    identity::{
+        accessors::IdentityGettersV0, signer::Signer, Identity, KeyType, Purpose, SecurityLevel,
+    },
+    prelude::{Identifier, IdentityNonce},
+    state_transition::{
+        identity_credit_transfer_transition::v2::PendingTransferOperation,
+        GetDataContractSecurityLevelRequirementFn, StateTransition,
+    },
+    ProtocolError,
+};
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
+#[cfg(feature = "state-transition-signing")]
+use std::collections::{BTreeMap, HashSet};
+
+use crate::state_transition::identity_credit_transfer_transition::methods::IdentityCreditTransferTransitionMethodsV0;
+use crate::state_transition::identity_credit_transfer_transition::v1::IdentityCreditTransferTransitionV1;
+
+impl IdentityCreditTransferTransitionMethodsV0 for IdentityCreditTransferTransitionV1 {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_to_recipients<S: Signer>(
+        identity: &Identity,
+        recipients: BTreeMap<Identifier, Credits>,
+        signer: S,
+        nonce: IdentityNonce,
+        _platform_version: &PlatformVersion,
+        _version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let mut transition: StateTransition = IdentityCreditTransferTransitionV1 {
+            identity_id: identity.id(),
+            recipients,
+            nonce,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+
+        let identity_public_key = identity
+            .get_first_public_key_matching(
+                Purpose::AUTHENTICATION,
+                HashSet::from([SecurityLevel::CRITICAL]),
+                KeyType::all_key_types().into(),
+            )
+            .ok_or(
+                ProtocolError::DesiredKeyWithTypePurposeSecurityLevelMissing(
+                    "no critical authentication public key".to_string(),
+                ),
+            )?;
+
+        transition.sign_external(
+            identity_public_key,
+            &signer,
+            None::<GetDataContractSecurityLevelRequirementFn>,
+        )?;
+
+        Ok(transition)
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_with_pending_transfer_operation<S: Signer>(
+        _identity: &Identity,
+        _operation: PendingTransferOperation,
+        _signer: S,
+        _nonce: IdentityNonce,
+        _platform_version: &PlatformVersion,
+        _version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        Err(ProtocolError::UnknownVersionError(
+            "IdentityCreditTransferTransitionV1 can't schedule transfers, use version 2"
+                .to_string(),
+        ))
+    }
+}
@@ -0,0 +1,4 @@
+use crate::state_transition::identity_credit_transfer_transition::v2::IdentityCreditTransferTransitionV2;
+use crate::state_transition::StateTransitionJsonConvert;
+
+impl<'a> StateTransitionJsonConvert<'a> for IdentityCreditTransferTransitionV2 {}
@@ -0,0 +1,114 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+pub(super) mod v2_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use crate::fee::Credits;
+use crate::identity::pending_transfer::TransferReleaseCondition;
+use crate::identity::KeyID;
+
+use crate::prelude::{Identifier, IdentityNonce};
+
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+use platform_value::BinaryData;
+use serde::{Deserialize, Serialize};
+
+/// Operation on a pending transfer of the identity
+#[derive(Debug, Clone, Encode, Decode, PartialEq)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase", rename_all_fields = "camelCase")
+)]
+pub enum PendingTransferOperation {
+    /// Removes the amount from the balance of the identity, the recipient receives it
+    /// once the release condition is reached
+    Schedule {
+        recipient_id: Identifier,
+        amount: Credits,
+        release_condition: TransferReleaseCondition,
+    },
+    /// Gives the amount of a transfer scheduled by the identity back to it,
+    /// as long as the transfer wasn't released
+    Cancel { transfer_id: Identifier },
+}
+
+/// Schedules or cancels a pending transfer of credits
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+    PlatformSignable,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[platform_serialize(unversioned)]
+pub struct IdentityCreditTransferTransitionV2 {
+    // Own ST fields
+    pub identity_id: Identifier,
+    pub operation: PendingTransferOperation,
+    pub nonce: IdentityNonce,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+
+    use crate::identity::pending_transfer::TransferReleaseCondition;
+    use crate::state_transition::identity_credit_transfer_transition::v2::{
+        IdentityCreditTransferTransitionV2, PendingTransferOperation,
+    };
+    use platform_value::Identifier;
+    use rand::Rng;
+
+    #[test]
+    fn test_identity_credit_transfer_transition_v2() {
+        let mut rng = rand::thread_rng();
+
+        for operation in [
+            PendingTransferOperation::Schedule {
+                recipient_id: Identifier::random(),
+                amount: rng.gen(),
+                release_condition: TransferReleaseCondition::BlockTime(rng.gen()),
+            },
+            PendingTransferOperation::Cancel {
+                transfer_id: Identifier::random(),
+            },
+        ] {
+            let transition = IdentityCreditTransferTransitionV2 {
+                identity_id: Identifier::random(),
+                operation,
+                nonce: 1,
+                signature_public_key_id: rng.gen(),
+                signature: [0; 65].to_vec().into(),
+            };
+
+            let serialized = transition
+                .serialize_to_bytes()
+                .expect("expected to serialize");
+            let deserialized =
+                IdentityCreditTransferTransitionV2::deserialize_from_bytes(serialized.as_slice())
+                    .expect("expected to deserialize");
+            assert_eq!(transition, deserialized);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use platform_value::BinaryData;
+
+use crate::{
+    prelude::Identifier,
+    state_transition::{StateTransitionLike, StateTransitionType},
+};
+
+use crate::state_transition::identity_credit_transfer_transition::v2::IdentityCreditTransferTransitionV2;
+use crate::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+
+use crate::state_transition::StateTransition;
+use crate::state_transition::StateTransitionType::IdentityCreditTransfer;
+use crate::version::FeatureVersion;
+
+impl From<IdentityCreditTransferTransitionV2> for StateTransition {
+    fn from(value: IdentityCreditTransferTransitionV2) -> Self {
+        let identity_credit_transfer_transition: IdentityCreditTransferTransition = value.into();
+        identity_credit_transfer_transition.into()
+    }
+}
+
+impl StateTransitionLike for IdentityCreditTransferTransitionV2 {
+    fn state_transition_protocol_version(&self) -> FeatureVersion {
+        2
+    }
+
+    /// returns the type of State Transition
+    fn state_transition_type(&self) -> StateTransitionType {
+        IdentityCreditTransfer
+    }
+    /// returns the signature as a byte-array
+    fn signature(&self) -> &BinaryData {
+        &self.signature
+    }
+    /// set a new signature
+    fn set_signature(&mut self, signature: BinaryData) {
+        self.signature = signature
+    }
+    /// Returns ID of the sender, recipients are only credited once the transfer is released
+    fn modified_data_ids(&self) -> Vec<Identifier> {
+        vec![self.identity_id]
+    }
+
+    fn set_signature_bytes(&mut self, signature: Vec<u8>) {
+        self.signature = BinaryData::new(signature)
+    }
+
+    /// Get owner ID
+    fn owner_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    /// We want things to be unique based on the nonce, so we don't add the transition type
+    fn unique_identifiers(&self) -> Vec<String> {
+        vec![format!(
+            "{}-{:x}",
+            base64::encode(self.identity_id),
+            self.nonce
+        )]
+    }
+}
@@ -0,0 +1,17 @@
+use crate::state_transition::identity_credit_transfer_transition::fields::*;
+use crate::state_transition::identity_credit_transfer_transition::v2::IdentityCreditTransferTransitionV2;
+use crate::state_transition::StateTransitionFieldTypes;
+
+impl StateTransitionFieldTypes for IdentityCreditTransferTransitionV2 {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![IDENTITY_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![]
+    }
+}
@@ -0,0 +1,9 @@
+use crate::state_transition::identity_credit_transfer_transition::v2::IdentityCreditTransferTransitionV2;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for IdentityCreditTransferTransitionV2 {
+    fn feature_version(&self) -> FeatureVersion {
+        2
+    }
+}
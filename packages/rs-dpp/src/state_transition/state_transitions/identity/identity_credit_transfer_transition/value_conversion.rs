@@ -5,6 +5,8 @@ use platform_value::Value;
 use crate::ProtocolError;
 
 use crate::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+use crate::state_transition::identity_credit_transfer_transition::v1::IdentityCreditTransferTransitionV1;
+use crate::state_transition::identity_credit_transfer_transition::v2::IdentityCreditTransferTransitionV2;
 use crate::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
 use crate::state_transition::state_transitions::identity_credit_transfer_transition::fields::*;
 use crate::state_transition::StateTransitionValueConvert;
@@ -23,6 +25,16 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityCreditTransferTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityCreditTransferTransition::V1(transition) => {
+                let mut value = transition.to_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                let mut value = transition.to_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(2))?;
+                Ok(value)
+            }
         }
     }
 
@@ -33,6 +45,16 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityCreditTransferTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityCreditTransferTransition::V1(transition) => {
+                let mut value = transition.to_canonical_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                let mut value = transition.to_canonical_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(2))?;
+                Ok(value)
+            }
         }
     }
 
@@ -43,6 +65,16 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityCreditTransferTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityCreditTransferTransition::V1(transition) => {
+                let mut value = transition.to_canonical_cleaned_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                let mut value = transition.to_canonical_cleaned_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(2))?;
+                Ok(value)
+            }
         }
     }
 
@@ -53,6 +85,16 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityCreditTransferTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityCreditTransferTransition::V1(transition) => {
+                let mut value = transition.to_cleaned_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
+            IdentityCreditTransferTransition::V2(transition) => {
+                let mut value = transition.to_cleaned_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(2))?;
+                Ok(value)
+            }
         }
     }
 
@@ -76,6 +118,14 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityCreditTransferTransition {
                 IdentityCreditTransferTransitionV0::from_object(raw_object, platform_version)?
                     .into(),
             ),
+            1 => Ok(
+                IdentityCreditTransferTransitionV1::from_object(raw_object, platform_version)?
+                    .into(),
+            ),
+            2 => Ok(
+                IdentityCreditTransferTransitionV2::from_object(raw_object, platform_version)?
+                    .into(),
+            ),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown IdentityCreditTransferTransition version {n}"
             ))),
@@ -103,6 +153,16 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityCreditTransferTransition {
                 platform_version,
             )?
             .into()),
+            1 => Ok(IdentityCreditTransferTransitionV1::from_value_map(
+                raw_value_map,
+                platform_version,
+            )?
+            .into()),
+            2 => Ok(IdentityCreditTransferTransitionV2::from_value_map(
+                raw_value_map,
+                platform_version,
+            )?
+            .into()),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown IdentityCreditTransferTransition version {n}"
             ))),
@@ -116,6 +176,8 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityCreditTransferTransition {
 
         match version {
             0 => IdentityCreditTransferTransitionV0::clean_value(value),
+            1 => IdentityCreditTransferTransitionV1::clean_value(value),
+            2 => IdentityCreditTransferTransitionV2::clean_value(value),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown IdentityCreditTransferTransition version {n}"
             ))),
@@ -6,6 +6,8 @@ impl FeatureVersioned for IdentityCreditTransferTransition {
     fn feature_version(&self) -> FeatureVersion {
         match self {
             IdentityCreditTransferTransition::V0(v0) => v0.feature_version(),
+            IdentityCreditTransferTransition::V1(v1) => v1.feature_version(),
+            IdentityCreditTransferTransition::V2(v2) => v2.feature_version(),
         }
     }
 }
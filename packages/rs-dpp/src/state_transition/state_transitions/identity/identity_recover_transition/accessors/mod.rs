@@ -0,0 +1,65 @@
+mod v0;
+
+use crate::prelude::{Identifier, IdentityNonce};
+use crate::state_transition::identity_recover_transition::v0::IdentityRecoverOperation;
+use crate::state_transition::identity_recover_transition::IdentityRecoverTransition;
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+pub use v0::*;
+
+impl IdentityRecoverTransitionAccessorsV0 for IdentityRecoverTransition {
+    fn set_identity_id(&mut self, id: Identifier) {
+        match self {
+            IdentityRecoverTransition::V0(transition) => transition.set_identity_id(id),
+        }
+    }
+
+    fn identity_id(&self) -> Identifier {
+        match self {
+            IdentityRecoverTransition::V0(transition) => transition.identity_id(),
+        }
+    }
+
+    fn set_operation(&mut self, operation: IdentityRecoverOperation) {
+        match self {
+            IdentityRecoverTransition::V0(transition) => transition.set_operation(operation),
+        }
+    }
+
+    fn operation(&self) -> IdentityRecoverOperation {
+        match self {
+            IdentityRecoverTransition::V0(transition) => transition.operation(),
+        }
+    }
+
+    fn set_new_master_keys(&mut self, new_master_keys: Vec<IdentityPublicKeyInCreation>) {
+        match self {
+            IdentityRecoverTransition::V0(transition) => {
+                transition.set_new_master_keys(new_master_keys)
+            }
+        }
+    }
+
+    fn new_master_keys(&self) -> &[IdentityPublicKeyInCreation] {
+        match self {
+            IdentityRecoverTransition::V0(transition) => transition.new_master_keys(),
+        }
+    }
+
+    fn new_master_keys_mut(&mut self) -> &mut [IdentityPublicKeyInCreation] {
+        match self {
+            IdentityRecoverTransition::V0(transition) => transition.new_master_keys_mut(),
+        }
+    }
+
+    fn set_nonce(&mut self, nonce: IdentityNonce) {
+        match self {
+            IdentityRecoverTransition::V0(transition) => transition.set_nonce(nonce),
+        }
+    }
+
+    fn nonce(&self) -> IdentityNonce {
+        match self {
+            IdentityRecoverTransition::V0(transition) => transition.nonce(),
+        }
+    }
+}
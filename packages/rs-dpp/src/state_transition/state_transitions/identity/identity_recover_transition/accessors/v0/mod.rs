@@ -0,0 +1,15 @@
+use crate::prelude::{Identifier, IdentityNonce};
+use crate::state_transition::identity_recover_transition::v0::IdentityRecoverOperation;
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+
+pub trait IdentityRecoverTransitionAccessorsV0 {
+    fn set_identity_id(&mut self, id: Identifier);
+    fn identity_id(&self) -> Identifier;
+    fn set_operation(&mut self, operation: IdentityRecoverOperation);
+    fn operation(&self) -> IdentityRecoverOperation;
+    fn set_new_master_keys(&mut self, new_master_keys: Vec<IdentityPublicKeyInCreation>);
+    fn new_master_keys(&self) -> &[IdentityPublicKeyInCreation];
+    fn new_master_keys_mut(&mut self) -> &mut [IdentityPublicKeyInCreation];
+    fn set_nonce(&mut self, nonce: IdentityNonce);
+    fn nonce(&self) -> IdentityNonce;
+}
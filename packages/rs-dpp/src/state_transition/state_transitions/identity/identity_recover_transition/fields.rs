@@ -0,0 +1,21 @@
+use crate::state_transition::state_transitions;
+
+use crate::state_transition::identity_recover_transition::fields::property_names::{
+    NEW_MASTER_KEYS_DATA, NEW_MASTER_KEYS_SIGNATURE,
+};
+pub use state_transitions::common_fields::property_names::{
+    IDENTITY_NONCE, SIGNATURE, SIGNATURE_PUBLIC_KEY_ID, STATE_TRANSITION_PROTOCOL_VERSION,
+    TRANSITION_TYPE,
+};
+pub use state_transitions::identity::common_fields::property_names::IDENTITY_ID;
+
+pub mod property_names {
+    pub const NEW_MASTER_KEYS_DATA: &str = "newMasterKeys[].data";
+    pub const NEW_MASTER_KEYS_SIGNATURE: &str = "newMasterKeys[].signature";
+    pub const NEW_MASTER_KEYS: &str = "newMasterKeys";
+    pub const OPERATION: &str = "operation";
+}
+
+pub const IDENTIFIER_FIELDS: [&str; 1] = [IDENTITY_ID];
+pub const BINARY_FIELDS: [&str; 3] = [NEW_MASTER_KEYS_DATA, NEW_MASTER_KEYS_SIGNATURE, SIGNATURE];
+pub const U32_FIELDS: [&str; 1] = [STATE_TRANSITION_PROTOCOL_VERSION];
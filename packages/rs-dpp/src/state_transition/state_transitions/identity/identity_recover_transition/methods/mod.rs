@@ -0,0 +1,52 @@
+mod v0;
+pub use v0::*;
+
+use crate::identity::signer::Signer;
+use crate::identity::IdentityPublicKey;
+
+use crate::state_transition::identity_recover_transition::v0::{
+    IdentityRecoverOperation, IdentityRecoverTransitionV0,
+};
+use crate::state_transition::identity_recover_transition::IdentityRecoverTransition;
+
+use crate::state_transition::StateTransition;
+use crate::version::FeatureVersion;
+use crate::ProtocolError;
+
+use crate::prelude::{Identifier, IdentityNonce};
+use platform_version::version::PlatformVersion;
+
+impl IdentityRecoverTransitionMethodsV0 for IdentityRecoverTransition {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_id_with_signer<S: Signer>(
+        identity_id: Identifier,
+        operation: IdentityRecoverOperation,
+        new_master_keys: Vec<IdentityPublicKey>,
+        signing_public_key: &IdentityPublicKey,
+        nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let default_current_version =
+            IdentityRecoverTransition::default_current_version(platform_version)?;
+
+        match version.unwrap_or(default_current_version) {
+            0 => Ok(
+                IdentityRecoverTransitionV0::try_from_identity_id_with_signer(
+                    identity_id,
+                    operation,
+                    new_master_keys,
+                    signing_public_key,
+                    nonce,
+                    signer,
+                    platform_version,
+                    version,
+                )?,
+            ),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown IdentityRecoverTransition version for try_from_identity_id_with_signer {v}"
+            ))),
+        }
+    }
+}
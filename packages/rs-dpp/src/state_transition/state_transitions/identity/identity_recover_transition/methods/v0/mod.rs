@@ -0,0 +1,29 @@
+use platform_version::version::PlatformVersion;
+
+use crate::identity::signer::Signer;
+use crate::identity::IdentityPublicKey;
+use crate::prelude::{Identifier, IdentityNonce};
+use crate::state_transition::identity_recover_transition::v0::IdentityRecoverOperation;
+use crate::state_transition::StateTransition;
+use crate::version::FeatureVersion;
+use crate::{state_transition::StateTransitionType, ProtocolError};
+
+pub trait IdentityRecoverTransitionMethodsV0 {
+    /// Creates a recover transition of the identity signed by the recovery key of a guardian,
+    /// or by a master key of the identity when vetoing
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_id_with_signer<S: Signer>(
+        identity_id: Identifier,
+        operation: IdentityRecoverOperation,
+        new_master_keys: Vec<IdentityPublicKey>,
+        signing_public_key: &IdentityPublicKey,
+        nonce: IdentityNonce,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+    /// Get State Transition Type
+    fn get_type() -> StateTransitionType {
+        StateTransitionType::IdentityRecover
+    }
+}
@@ -0,0 +1,119 @@
+pub mod accessors;
+pub mod fields;
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+pub mod methods;
+mod state_transition_like;
+pub mod v0;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use crate::state_transition::identity_recover_transition::fields::property_names::NEW_MASTER_KEYS_SIGNATURE;
+use crate::state_transition::identity_recover_transition::v0::IdentityRecoverTransitionV0;
+use crate::state_transition::identity_recover_transition::v0::IdentityRecoverTransitionV0Signable;
+use crate::state_transition::StateTransitionFieldTypes;
+use fields::*;
+
+use crate::identity::state_transition::OptionallyAssetLockProved;
+use crate::version::FeatureVersion;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+use platform_version::version::PlatformVersion;
+use platform_versioning::PlatformVersioned;
+use serde::{Deserialize, Serialize};
+
+/// Lets the guardians holding the recovery keys of an identity replace its master keys,
+/// unless a master key vetoes the recovery during the recovery delay
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformDeserialize,
+    PlatformSerialize,
+    PlatformSignable,
+    PlatformVersioned,
+    From,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(tag = "$version")
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+pub enum IdentityRecoverTransition {
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "0"))]
+    V0(IdentityRecoverTransitionV0),
+}
+
+impl IdentityRecoverTransition {
+    pub fn default_versioned(platform_version: &PlatformVersion) -> Result<Self, ProtocolError> {
+        match platform_version
+            .dpp
+            .identity_versions
+            .identity_structure_version
+        {
+            0 => Ok(IdentityRecoverTransition::V0(
+                IdentityRecoverTransitionV0::default(),
+            )),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "IdentityRecoverTransition::default_versioned".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+
+    /// The version the transition is serialized with by default, it isn't serializable before
+    /// the protocol version activating identity recovery
+    pub fn default_current_version(
+        platform_version: &PlatformVersion,
+    ) -> Result<FeatureVersion, ProtocolError> {
+        platform_version
+            .dpp
+            .state_transition_serialization_versions
+            .identity_recover_state_transition
+            .as_ref()
+            .map(|bounds| bounds.default_current_version)
+            .ok_or(ProtocolError::UnknownVersionError(format!(
+                "IdentityRecoverTransition is not active in protocol version {}",
+                platform_version.protocol_version
+            )))
+    }
+
+    /// Identity recovery is only active from the protocol version it's serializable in
+    pub fn verify_protocol_version(&self, protocol_version: u32) -> Result<bool, ProtocolError> {
+        let platform_version = PlatformVersion::get(protocol_version)?;
+        Ok(platform_version
+            .dpp
+            .state_transition_serialization_versions
+            .identity_recover_state_transition
+            .as_ref()
+            .is_some_and(|bounds| bounds.check_version(self.feature_version())))
+    }
+}
+
+impl OptionallyAssetLockProved for IdentityRecoverTransition {}
+
+impl StateTransitionFieldTypes for IdentityRecoverTransition {
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE, NEW_MASTER_KEYS_SIGNATURE]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![IDENTITY_ID]
+    }
+
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![
+            SIGNATURE,
+            SIGNATURE_PUBLIC_KEY_ID,
+            NEW_MASTER_KEYS_SIGNATURE,
+        ]
+    }
+}
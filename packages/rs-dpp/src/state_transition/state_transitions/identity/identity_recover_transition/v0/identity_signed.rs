@@ -0,0 +1,73 @@
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::consensus::signature::InvalidSignaturePublicKeySecurityLevelError;
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::identity::IdentityPublicKey;
+use crate::identity::{KeyID, SecurityLevel};
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::state_transition::errors::WrongPublicKeyPurposeError;
+use crate::state_transition::identity_recover_transition::v0::IdentityRecoverTransitionV0;
+use crate::state_transition::StateTransitionIdentitySigned;
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::ProtocolError;
+
+impl StateTransitionIdentitySigned for IdentityRecoverTransitionV0 {
+    fn signature_public_key_id(&self) -> KeyID {
+        self.signature_public_key_id
+    }
+
+    fn set_signature_public_key_id(&mut self, key_id: KeyID) {
+        self.signature_public_key_id = key_id
+    }
+
+    /// Guardians sign with their recovery keys, the identity vetoes with a master key
+    #[cfg(any(
+        feature = "state-transition-signing",
+        feature = "state-transition-validation"
+    ))]
+    fn verify_public_key_level_and_purpose(
+        &self,
+        public_key: &IdentityPublicKey,
+    ) -> Result<(), ProtocolError> {
+        if !self
+            .security_level_requirement()
+            .contains(&public_key.security_level())
+        {
+            return Err(ProtocolError::InvalidSignaturePublicKeySecurityLevelError(
+                InvalidSignaturePublicKeySecurityLevelError::new(
+                    public_key.security_level(),
+                    self.security_level_requirement(),
+                ),
+            ));
+        }
+
+        let purpose_requirement = self.operation.purpose_requirement();
+
+        if public_key.purpose() != purpose_requirement {
+            return Err(ProtocolError::WrongPublicKeyPurposeError(
+                WrongPublicKeyPurposeError::new(public_key.purpose(), purpose_requirement),
+            ));
+        }
+        Ok(())
+    }
+
+    fn security_level_requirement(&self) -> Vec<SecurityLevel> {
+        self.operation.security_level_requirement()
+    }
+}
@@ -0,0 +1,4 @@
+use crate::state_transition::identity_recover_transition::v0::IdentityRecoverTransitionV0;
+use crate::state_transition::StateTransitionJsonConvert;
+
+impl<'a> StateTransitionJsonConvert<'a> for IdentityRecoverTransitionV0 {}
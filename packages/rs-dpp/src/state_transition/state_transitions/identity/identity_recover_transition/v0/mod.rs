@@ -0,0 +1,172 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+pub(super) mod v0_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use bincode::{Decode, Encode};
+use platform_serialization_derive::PlatformSignable;
+use platform_value::BinaryData;
+use serde::{Deserialize, Serialize};
+
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreationSignable;
+
+use crate::identity::{KeyID, Purpose, SecurityLevel, TimestampMillis};
+use crate::prelude::{Identifier, IdentityNonce};
+
+/// The step of the social recovery of an identity
+#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum IdentityRecoverOperation {
+    /// Proposes to replace the master keys of the identity with new master keys,
+    /// signed by the recovery key of a guardian
+    #[default]
+    Propose,
+    /// Approves the recovery proposal of the identity, signed by the recovery key of a guardian
+    Approve,
+    /// Vetoes the recovery proposal of the identity, signed by a master key of the identity
+    Veto,
+    /// Replaces the master keys of the identity once a majority of the guardians approved
+    /// the proposal and the recovery delay passed, signed by the recovery key of a guardian
+    Execute,
+    /// Sets how long the master keys of the identity can veto a recovery proposal before it can
+    /// be executed, signed by a master key of the identity
+    #[cfg_attr(
+        feature = "state-transition-serde-conversion",
+        serde(rename_all = "camelCase")
+    )]
+    SetRecoveryDelay {
+        /// The recovery delay replacing the default recovery delay of the platform
+        recovery_delay_ms: TimestampMillis,
+    },
+}
+
+impl IdentityRecoverOperation {
+    /// The purpose of the key that must sign the transition
+    pub fn purpose_requirement(&self) -> Purpose {
+        match self {
+            IdentityRecoverOperation::Veto | IdentityRecoverOperation::SetRecoveryDelay { .. } => {
+                Purpose::AUTHENTICATION
+            }
+            _ => Purpose::RECOVERY,
+        }
+    }
+
+    /// The security levels of the key that must sign the transition
+    pub fn security_level_requirement(&self) -> Vec<SecurityLevel> {
+        match self {
+            IdentityRecoverOperation::Veto | IdentityRecoverOperation::SetRecoveryDelay { .. } => {
+                vec![SecurityLevel::MASTER]
+            }
+            _ => vec![SecurityLevel::CRITICAL],
+        }
+    }
+}
+
+#[derive(Encode, Decode, PlatformSignable, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+// There is a problem deriving bincode for a borrowed vector
+// Hence we set to do it somewhat manually inside the PlatformSignable proc macro
+// Instead of inside of bincode_derive
+#[platform_signable(derive_bincode_with_borrowed_vec)]
+#[derive(Default)]
+pub struct IdentityRecoverTransitionV0 {
+    /// Unique identifier of the identity to be recovered
+    pub identity_id: Identifier,
+
+    /// The step of the recovery
+    pub operation: IdentityRecoverOperation,
+
+    /// Master keys replacing the current master keys of the identity, only set when proposing
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    #[platform_signable(into = "Vec<IdentityPublicKeyInCreationSignable>")]
+    pub new_master_keys: Vec<IdentityPublicKeyInCreation>,
+
+    /// Identity nonce for this transition to prevent replay attacks
+    pub nonce: IdentityNonce,
+
+    /// The ID of the public key used to sing the State Transition
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    /// Cryptographic signature of the State Transition
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::identity::identity_public_key::v0::IdentityPublicKeyV0;
+    use crate::identity::{IdentityPublicKey, KeyType, Purpose, SecurityLevel};
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+    use crate::state_transition::identity_recover_transition::v0::{
+        IdentityRecoverOperation, IdentityRecoverTransitionV0,
+    };
+    use crate::state_transition::identity_recover_transition::IdentityRecoverTransition;
+    use platform_value::{BinaryData, Identifier};
+    use platform_version::version::PlatformVersion;
+
+    #[test]
+    fn test_identity_recover_transition_serialization() {
+        let transition: IdentityRecoverTransition = IdentityRecoverTransitionV0 {
+            identity_id: Identifier::random(),
+            operation: IdentityRecoverOperation::Propose,
+            new_master_keys: vec![IdentityPublicKey::from(IdentityPublicKeyV0 {
+                id: 5,
+                purpose: Purpose::AUTHENTICATION,
+                security_level: SecurityLevel::MASTER,
+                contract_bounds: None,
+                key_type: KeyType::ECDSA_SECP256K1,
+                read_only: false,
+                data: BinaryData::new(vec![2; 33]),
+                disabled_at: None,
+            })
+            .into()],
+            nonce: 1,
+            signature_public_key_id: 3,
+            signature: [0; 65].to_vec().into(),
+        }
+        .into();
+
+        let serialized = transition
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+        let deserialized = IdentityRecoverTransition::deserialize_from_bytes(serialized.as_slice())
+            .expect("expected to deserialize");
+        assert_eq!(transition, deserialized);
+    }
+
+    #[test]
+    fn should_only_be_active_from_protocol_version_2() {
+        let transition: IdentityRecoverTransition = IdentityRecoverTransitionV0 {
+            identity_id: Identifier::random(),
+            operation: IdentityRecoverOperation::Veto,
+            nonce: 1,
+            ..Default::default()
+        }
+        .into();
+
+        assert!(!transition
+            .verify_protocol_version(1)
+            .expect("expected to verify protocol version"));
+        assert!(
+            IdentityRecoverTransition::default_current_version(PlatformVersion::first()).is_err()
+        );
+
+        assert!(transition
+            .verify_protocol_version(2)
+            .expect("expected to verify protocol version"));
+    }
+}
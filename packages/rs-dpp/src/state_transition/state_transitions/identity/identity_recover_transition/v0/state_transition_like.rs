@@ -0,0 +1,61 @@
+use platform_value::BinaryData;
+
+use crate::{
+    prelude::Identifier,
+    state_transition::{StateTransitionLike, StateTransitionType},
+};
+
+use crate::state_transition::identity_recover_transition::v0::IdentityRecoverTransitionV0;
+use crate::state_transition::identity_recover_transition::IdentityRecoverTransition;
+
+use crate::state_transition::StateTransition;
+use crate::state_transition::StateTransitionType::IdentityRecover;
+use crate::version::FeatureVersion;
+
+impl From<IdentityRecoverTransitionV0> for StateTransition {
+    fn from(value: IdentityRecoverTransitionV0) -> Self {
+        let identity_recover_transition: IdentityRecoverTransition = value.into();
+        identity_recover_transition.into()
+    }
+}
+
+impl StateTransitionLike for IdentityRecoverTransitionV0 {
+    fn state_transition_protocol_version(&self) -> FeatureVersion {
+        0
+    }
+
+    /// returns the type of State Transition
+    fn state_transition_type(&self) -> StateTransitionType {
+        IdentityRecover
+    }
+    /// returns the signature as a byte-array
+    fn signature(&self) -> &BinaryData {
+        &self.signature
+    }
+    /// set a new signature
+    fn set_signature(&mut self, signature: BinaryData) {
+        self.signature = signature
+    }
+    /// Returns ID of the recovered identity
+    fn modified_data_ids(&self) -> Vec<Identifier> {
+        vec![self.identity_id]
+    }
+
+    fn set_signature_bytes(&mut self, signature: Vec<u8>) {
+        self.signature = BinaryData::new(signature)
+    }
+
+    /// Get owner ID
+    fn owner_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    /// We want things to be unique based on the nonce, so we don't add the transition type
+    fn unique_identifiers(&self) -> Vec<String> {
+        vec![format!(
+            "{}-{:x}",
+            base64::encode(self.identity_id),
+            self.nonce
+        )]
+    }
+}
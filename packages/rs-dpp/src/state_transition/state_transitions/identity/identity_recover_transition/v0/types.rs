@@ -0,0 +1,22 @@
+use crate::state_transition::identity_recover_transition::fields::property_names::*;
+use crate::state_transition::identity_recover_transition::fields::*;
+use crate::state_transition::identity_recover_transition::v0::IdentityRecoverTransitionV0;
+use crate::state_transition::StateTransitionFieldTypes;
+
+impl StateTransitionFieldTypes for IdentityRecoverTransitionV0 {
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE, NEW_MASTER_KEYS_SIGNATURE]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![IDENTITY_ID]
+    }
+
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![
+            SIGNATURE,
+            SIGNATURE_PUBLIC_KEY_ID,
+            NEW_MASTER_KEYS_SIGNATURE,
+        ]
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::serialization::Signable;
+
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::PlatformVersion;
+
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::IdentityPublicKey;
+
+use crate::prelude::{Identifier, IdentityNonce};
+use crate::state_transition::identity_recover_transition::accessors::IdentityRecoverTransitionAccessorsV0;
+use crate::state_transition::identity_recover_transition::methods::IdentityRecoverTransitionMethodsV0;
+use crate::state_transition::identity_recover_transition::v0::{
+    IdentityRecoverOperation, IdentityRecoverTransitionV0,
+};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::public_key_in_creation::accessors::IdentityPublicKeyInCreationV0Setters;
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::{GetDataContractSecurityLevelRequirementFn, StateTransition};
+#[cfg(feature = "state-transition-signing")]
+use crate::version::FeatureVersion;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+
+impl IdentityRecoverTransitionMethodsV0 for IdentityRecoverTransitionV0 {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_id_with_signer<S: Signer>(
+        identity_id: Identifier,
+        operation: IdentityRecoverOperation,
+        new_master_keys: Vec<IdentityPublicKey>,
+        signing_public_key: &IdentityPublicKey,
+        nonce: IdentityNonce,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        _version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let new_master_keys_in_creation = new_master_keys
+            .iter()
+            .map(|public_key| public_key.into())
+            .collect();
+
+        let mut identity_recover_transition = IdentityRecoverTransitionV0 {
+            identity_id,
+            operation,
+            new_master_keys: new_master_keys_in_creation,
+            nonce,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        };
+
+        let state_transition: StateTransition = identity_recover_transition.clone().into();
+
+        let key_signable_bytes = state_transition.signable_bytes()?;
+
+        // Sign all the new master keys
+        identity_recover_transition
+            .new_master_keys
+            .iter_mut()
+            .zip(new_master_keys.iter())
+            .try_for_each(|(public_key_with_witness, public_key)| {
+                if public_key.key_type().is_unique_key_type() {
+                    let signature = signer.sign(public_key, &key_signable_bytes)?;
+                    public_key_with_witness.set_signature(signature);
+                }
+
+                Ok::<(), ProtocolError>(())
+            })?;
+
+        let mut state_transition: StateTransition = identity_recover_transition.into();
+        state_transition.sign_external(
+            signing_public_key,
+            signer,
+            None::<GetDataContractSecurityLevelRequirementFn>,
+        )?;
+        Ok(state_transition)
+    }
+}
+
+impl IdentityRecoverTransitionAccessorsV0 for IdentityRecoverTransitionV0 {
+    fn set_identity_id(&mut self, id: Identifier) {
+        self.identity_id = id;
+    }
+
+    fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    fn set_operation(&mut self, operation: IdentityRecoverOperation) {
+        self.operation = operation;
+    }
+
+    fn operation(&self) -> IdentityRecoverOperation {
+        self.operation
+    }
+
+    fn set_new_master_keys(&mut self, new_master_keys: Vec<IdentityPublicKeyInCreation>) {
+        self.new_master_keys = new_master_keys;
+    }
+
+    fn new_master_keys(&self) -> &[IdentityPublicKeyInCreation] {
+        &self.new_master_keys
+    }
+
+    fn new_master_keys_mut(&mut self) -> &mut [IdentityPublicKeyInCreation] {
+        &mut self.new_master_keys
+    }
+
+    fn set_nonce(&mut self, nonce: IdentityNonce) {
+        self.nonce = nonce;
+    }
+
+    fn nonce(&self) -> IdentityNonce {
+        self.nonce
+    }
+}
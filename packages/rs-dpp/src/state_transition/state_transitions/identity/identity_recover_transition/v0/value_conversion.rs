@@ -0,0 +1,114 @@
+use platform_value::{IntegerReplacementType, ReplacementType, Value};
+
+use crate::{state_transition::StateTransitionFieldTypes, ProtocolError};
+
+use crate::state_transition::identity_recover_transition::fields::*;
+use crate::state_transition::identity_recover_transition::v0::IdentityRecoverTransitionV0;
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use crate::state_transition::StateTransitionValueConvert;
+
+use crate::state_transition::state_transitions::common_fields::property_names::NONCE;
+use platform_version::version::PlatformVersion;
+
+impl<'a> StateTransitionValueConvert<'a> for IdentityRecoverTransitionV0 {
+    fn from_object(
+        mut raw_object: Value,
+        platform_version: &PlatformVersion,
+    ) -> Result<Self, ProtocolError> {
+        let signature = raw_object
+            .get_binary_data(SIGNATURE)
+            .map_err(ProtocolError::ValueError)?;
+        let signature_public_key_id = raw_object
+            .get_integer(SIGNATURE_PUBLIC_KEY_ID)
+            .map_err(ProtocolError::ValueError)?;
+        let identity_id = raw_object
+            .get_identifier(IDENTITY_ID)
+            .map_err(ProtocolError::ValueError)?;
+        let operation = platform_value::from_value(
+            raw_object
+                .remove(property_names::OPERATION)
+                .map_err(ProtocolError::ValueError)?,
+        )
+        .map_err(ProtocolError::ValueError)?;
+        let nonce = raw_object
+            .get_integer(NONCE)
+            .map_err(ProtocolError::ValueError)?;
+        let new_master_keys = raw_object
+            .remove_optional_array(property_names::NEW_MASTER_KEYS)
+            .map_err(ProtocolError::ValueError)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|value| IdentityPublicKeyInCreation::from_object(value, platform_version))
+            .collect::<Result<Vec<_>, ProtocolError>>()?;
+
+        Ok(IdentityRecoverTransitionV0 {
+            identity_id,
+            operation,
+            new_master_keys,
+            nonce,
+            signature_public_key_id,
+            signature,
+        })
+    }
+
+    fn clean_value(value: &mut Value) -> Result<(), ProtocolError> {
+        value.replace_at_paths(IDENTIFIER_FIELDS, ReplacementType::Identifier)?;
+        value.replace_at_paths(BINARY_FIELDS, ReplacementType::BinaryBytes)?;
+        value.replace_integer_type_at_paths(U32_FIELDS, IntegerReplacementType::U32)?;
+        Ok(())
+    }
+
+    fn to_object(&self, skip_signature: bool) -> Result<Value, ProtocolError> {
+        let mut value: Value = platform_value::to_value(self)?;
+        if skip_signature {
+            value
+                .remove_values_matching_paths(Self::signature_property_paths())
+                .map_err(ProtocolError::ValueError)?;
+        }
+
+        let mut new_master_keys: Vec<Value> = vec![];
+        for key in self.new_master_keys.iter() {
+            new_master_keys.push(key.to_object(skip_signature)?);
+        }
+
+        if !new_master_keys.is_empty() {
+            value.insert_at_end(
+                property_names::NEW_MASTER_KEYS.to_owned(),
+                Value::Array(new_master_keys),
+            )?;
+        }
+
+        Ok(value)
+    }
+
+    fn to_cleaned_object(&self, skip_signature: bool) -> Result<Value, ProtocolError> {
+        let mut value: Value = platform_value::to_value(self)?;
+
+        if skip_signature {
+            value
+                .remove_values_matching_paths(Self::signature_property_paths())
+                .map_err(ProtocolError::ValueError)?;
+        }
+
+        if !self.new_master_keys.is_empty() {
+            let mut new_master_keys: Vec<Value> = vec![];
+            for key in self.new_master_keys.iter() {
+                new_master_keys.push(key.to_cleaned_object(skip_signature)?);
+            }
+
+            value.insert(
+                property_names::NEW_MASTER_KEYS.to_owned(),
+                Value::Array(new_master_keys),
+            )?;
+        }
+
+        value.remove_optional_value_if_empty_array(property_names::NEW_MASTER_KEYS)?;
+
+        Ok(value)
+    }
+
+    // Override to_canonical_cleaned_object to manage new_master_keys individually
+    fn to_canonical_cleaned_object(&self, skip_signature: bool) -> Result<Value, ProtocolError> {
+        self.to_cleaned_object(skip_signature)
+    }
+}
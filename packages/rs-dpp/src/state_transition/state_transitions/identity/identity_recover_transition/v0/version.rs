@@ -0,0 +1,9 @@
+use crate::state_transition::identity_recover_transition::v0::IdentityRecoverTransitionV0;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for IdentityRecoverTransitionV0 {
+    fn feature_version(&self) -> FeatureVersion {
+        0
+    }
+}
@@ -0,0 +1,11 @@
+use crate::state_transition::identity_recover_transition::IdentityRecoverTransition;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for IdentityRecoverTransition {
+    fn feature_version(&self) -> FeatureVersion {
+        match self {
+            IdentityRecoverTransition::V0(v0) => v0.feature_version(),
+        }
+    }
+}
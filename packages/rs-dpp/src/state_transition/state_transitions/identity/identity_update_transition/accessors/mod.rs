@@ -1,46 +1,56 @@
 mod v0;
+mod v1;
 
+use crate::fee::Credits;
 use crate::identity::{KeyID, TimestampMillis};
 use crate::prelude::{IdentityNonce, Revision};
+use crate::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
 use crate::state_transition::identity_update_transition::IdentityUpdateTransition;
 use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
 use platform_value::Identifier;
 pub use v0::*;
+pub use v1::*;
 
 impl IdentityUpdateTransitionAccessorsV0 for IdentityUpdateTransition {
     fn set_identity_id(&mut self, id: Identifier) {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.set_identity_id(id),
+            IdentityUpdateTransition::V1(transition) => transition.set_identity_id(id),
         }
     }
 
     fn identity_id(&self) -> Identifier {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.identity_id(),
+            IdentityUpdateTransition::V1(transition) => transition.identity_id(),
         }
     }
 
     fn set_revision(&mut self, revision: Revision) {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.set_revision(revision),
+            IdentityUpdateTransition::V1(transition) => transition.set_revision(revision),
         }
     }
 
     fn revision(&self) -> Revision {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.revision(),
+            IdentityUpdateTransition::V1(transition) => transition.revision(),
         }
     }
 
     fn set_nonce(&mut self, nonce: IdentityNonce) {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.set_nonce(nonce),
+            IdentityUpdateTransition::V1(transition) => transition.set_nonce(nonce),
         }
     }
 
     fn nonce(&self) -> IdentityNonce {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.nonce(),
+            IdentityUpdateTransition::V1(transition) => transition.nonce(),
         }
     }
 
@@ -49,18 +59,23 @@ impl IdentityUpdateTransitionAccessorsV0 for IdentityUpdateTransition {
             IdentityUpdateTransition::V0(transition) => {
                 transition.set_public_keys_to_add(add_public_keys)
             }
+            IdentityUpdateTransition::V1(transition) => {
+                transition.set_public_keys_to_add(add_public_keys)
+            }
         }
     }
 
     fn public_keys_to_add(&self) -> &[IdentityPublicKeyInCreation] {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.public_keys_to_add(),
+            IdentityUpdateTransition::V1(transition) => transition.public_keys_to_add(),
         }
     }
 
     fn public_keys_to_add_mut(&mut self) -> &mut [IdentityPublicKeyInCreation] {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.public_keys_to_add_mut(),
+            IdentityUpdateTransition::V1(transition) => transition.public_keys_to_add_mut(),
         }
     }
 
@@ -69,12 +84,16 @@ impl IdentityUpdateTransitionAccessorsV0 for IdentityUpdateTransition {
             IdentityUpdateTransition::V0(transition) => {
                 transition.set_public_key_ids_to_disable(disable_public_keys)
             }
+            IdentityUpdateTransition::V1(transition) => {
+                transition.set_public_key_ids_to_disable(disable_public_keys)
+            }
         }
     }
 
     fn public_key_ids_to_disable(&self) -> &[KeyID] {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.public_key_ids_to_disable(),
+            IdentityUpdateTransition::V1(transition) => transition.public_key_ids_to_disable(),
         }
     }
 
@@ -83,18 +102,96 @@ impl IdentityUpdateTransitionAccessorsV0 for IdentityUpdateTransition {
             IdentityUpdateTransition::V0(transition) => {
                 transition.set_public_keys_disabled_at(public_keys_disabled_at)
             }
+            IdentityUpdateTransition::V1(transition) => {
+                transition.set_public_keys_disabled_at(public_keys_disabled_at)
+            }
         }
     }
 
     fn public_keys_disabled_at(&self) -> Option<TimestampMillis> {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.public_keys_disabled_at(),
+            IdentityUpdateTransition::V1(transition) => transition.public_keys_disabled_at(),
         }
     }
 
     fn owner_id(&self) -> Identifier {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.owner_id(),
+            IdentityUpdateTransition::V1(transition) => transition.owner_id(),
+        }
+    }
+}
+
+impl IdentityUpdateTransitionAccessorsV1 for IdentityUpdateTransition {
+    fn set_public_keys_spending_limits(
+        &mut self,
+        public_keys_spending_limits: Vec<(KeyID, Option<Credits>)>,
+    ) {
+        match self {
+            IdentityUpdateTransition::V0(transition) => {
+                if !public_keys_spending_limits.is_empty() {
+                    *self = IdentityUpdateTransitionV1 {
+                        identity_id: transition.identity_id,
+                        revision: transition.revision,
+                        nonce: transition.nonce,
+                        add_public_keys: transition.add_public_keys.clone(),
+                        disable_public_keys: transition.disable_public_keys.clone(),
+                        public_keys_disabled_at: transition.public_keys_disabled_at,
+                        public_keys_disable_grace_period_ms: None,
+                        public_keys_spending_limits,
+                        signature_public_key_id: transition.signature_public_key_id,
+                        signature: transition.signature.clone(),
+                    }
+                    .into();
+                }
+            }
+            IdentityUpdateTransition::V1(transition) => {
+                transition.set_public_keys_spending_limits(public_keys_spending_limits)
+            }
+        }
+    }
+
+    fn public_keys_spending_limits(&self) -> &[(KeyID, Option<Credits>)] {
+        match self {
+            IdentityUpdateTransition::V0(_) => &[],
+            IdentityUpdateTransition::V1(transition) => transition.public_keys_spending_limits(),
+        }
+    }
+
+    fn set_public_keys_disable_grace_period_ms(
+        &mut self,
+        public_keys_disable_grace_period_ms: Option<u64>,
+    ) {
+        match self {
+            IdentityUpdateTransition::V0(transition) => {
+                if public_keys_disable_grace_period_ms.is_some() {
+                    *self = IdentityUpdateTransitionV1 {
+                        identity_id: transition.identity_id,
+                        revision: transition.revision,
+                        nonce: transition.nonce,
+                        add_public_keys: transition.add_public_keys.clone(),
+                        disable_public_keys: transition.disable_public_keys.clone(),
+                        public_keys_disabled_at: transition.public_keys_disabled_at,
+                        public_keys_disable_grace_period_ms,
+                        public_keys_spending_limits: vec![],
+                        signature_public_key_id: transition.signature_public_key_id,
+                        signature: transition.signature.clone(),
+                    }
+                    .into();
+                }
+            }
+            IdentityUpdateTransition::V1(transition) => transition
+                .set_public_keys_disable_grace_period_ms(public_keys_disable_grace_period_ms),
+        }
+    }
+
+    fn public_keys_disable_grace_period_ms(&self) -> Option<u64> {
+        match self {
+            IdentityUpdateTransition::V0(_) => None,
+            IdentityUpdateTransition::V1(transition) => {
+                transition.public_keys_disable_grace_period_ms()
+            }
         }
     }
 }
@@ -0,0 +1,20 @@
+use crate::fee::Credits;
+use crate::identity::KeyID;
+
+pub trait IdentityUpdateTransitionAccessorsV1 {
+    /// Sets the spending limits per epoch to set on existing keys, none removes the limit
+    fn set_public_keys_spending_limits(
+        &mut self,
+        public_keys_spending_limits: Vec<(KeyID, Option<Credits>)>,
+    );
+    /// The spending limits per epoch to set on existing keys, none removes the limit
+    fn public_keys_spending_limits(&self) -> &[(KeyID, Option<Credits>)];
+    /// Sets the delay in milliseconds before the disabled keys stop being valid
+    fn set_public_keys_disable_grace_period_ms(
+        &mut self,
+        public_keys_disable_grace_period_ms: Option<u64>,
+    );
+    /// The delay in milliseconds before the disabled keys stop being valid, so a replacement key
+    /// can be added while transitions signed with the rotated keys are still in flight
+    fn public_keys_disable_grace_period_ms(&self) -> Option<u64>;
+}
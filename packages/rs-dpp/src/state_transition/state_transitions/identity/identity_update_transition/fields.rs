@@ -15,6 +15,8 @@ pub mod property_names {
     pub const ADD_PUBLIC_KEYS: &str = "addPublicKeys";
     pub const DISABLE_PUBLIC_KEYS: &str = "disablePublicKeys";
     pub const PUBLIC_KEYS_DISABLED_AT: &str = "publicKeysDisabledAt";
+    pub const PUBLIC_KEYS_DISABLE_GRACE_PERIOD_MS: &str = "publicKeysDisableGracePeriodMs";
+    pub const PUBLIC_KEYS_SPENDING_LIMITS: &str = "publicKeysSpendingLimits";
 }
 
 pub const IDENTIFIER_FIELDS: [&str; 1] = [IDENTITY_ID];
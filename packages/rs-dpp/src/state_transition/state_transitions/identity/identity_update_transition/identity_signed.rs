@@ -6,6 +6,7 @@ impl StateTransitionIdentitySigned for IdentityUpdateTransition {
     fn signature_public_key_id(&self) -> KeyID {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.signature_public_key_id(),
+            IdentityUpdateTransition::V1(transition) => transition.signature_public_key_id(),
         }
     }
 
@@ -14,12 +15,16 @@ impl StateTransitionIdentitySigned for IdentityUpdateTransition {
             IdentityUpdateTransition::V0(transition) => {
                 transition.set_signature_public_key_id(key_id)
             }
+            IdentityUpdateTransition::V1(transition) => {
+                transition.set_signature_public_key_id(key_id)
+            }
         }
     }
 
     fn security_level_requirement(&self) -> Vec<SecurityLevel> {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.security_level_requirement(),
+            IdentityUpdateTransition::V1(transition) => transition.security_level_requirement(),
         }
     }
 }
@@ -5,6 +5,7 @@ use crate::identity::signer::Signer;
 use crate::identity::{Identity, IdentityPublicKey, KeyID};
 
 use crate::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
+use crate::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
 use crate::state_transition::identity_update_transition::IdentityUpdateTransition;
 
 use crate::state_transition::StateTransition;
@@ -45,6 +46,17 @@ impl IdentityUpdateTransitionMethodsV0 for IdentityUpdateTransition {
                 platform_version,
                 version,
             )?),
+            1 => Ok(IdentityUpdateTransitionV1::try_from_identity_with_signer(
+                identity,
+                master_public_key_id,
+                add_public_keys,
+                disable_public_keys,
+                public_keys_disabled_at,
+                nonce,
+                signer,
+                platform_version,
+                version,
+            )?),
             v => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown IdentityUpdateTransition version for try_from_identity_with_signer {v}"
             ))),
@@ -7,6 +7,7 @@ pub mod methods;
 mod state_transition_like;
 pub mod v0;
 mod v0_methods;
+pub mod v1;
 #[cfg(feature = "state-transition-value-conversion")]
 mod value_conversion;
 mod version;
@@ -14,6 +15,8 @@ mod version;
 use crate::state_transition::identity_update_transition::fields::property_names::ADD_PUBLIC_KEYS_SIGNATURE;
 use crate::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
 use crate::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0Signable;
+use crate::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
+use crate::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1Signable;
 use crate::state_transition::StateTransitionFieldTypes;
 use fields::*;
 
@@ -50,6 +53,8 @@ use serde::{Deserialize, Serialize};
 pub enum IdentityUpdateTransition {
     #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "0"))]
     V0(IdentityUpdateTransitionV0),
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "1"))]
+    V1(IdentityUpdateTransitionV1),
 }
 
 impl IdentityUpdateTransition {
@@ -69,7 +69,7 @@ pub struct IdentityUpdateTransitionV0 {
 
 /// if the property isn't present the empty list is returned. If property is defined, the function
 /// might return some serialization-related errors
-fn get_list<T: TryFrom<Value, Error = platform_value::Error>>(
+pub(super) fn get_list<T: TryFrom<Value, Error = platform_value::Error>>(
     value: &mut Value,
     property_name: &str,
 ) -> Result<Vec<T>, ProtocolError> {
@@ -84,7 +84,7 @@ fn get_list<T: TryFrom<Value, Error = platform_value::Error>>(
 
 /// if the property isn't present the empty list is returned. If property is defined, the function
 /// might return some serialization-related errors
-fn remove_integer_list_or_default<T>(
+pub(super) fn remove_integer_list_or_default<T>(
     value: &mut Value,
     property_name: &str,
 ) -> Result<Vec<T>, ProtocolError>
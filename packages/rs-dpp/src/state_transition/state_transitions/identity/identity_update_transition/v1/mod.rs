@@ -0,0 +1,134 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+pub(super) mod v1_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use bincode::{Decode, Encode};
+use platform_serialization_derive::PlatformSignable;
+use platform_value::BinaryData;
+use serde::{Deserialize, Serialize};
+
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreationSignable;
+
+use crate::fee::Credits;
+use crate::prelude::IdentityNonce;
+use crate::{
+    identity::KeyID,
+    prelude::{Identifier, Revision, TimestampMillis},
+};
+
+/// Updates the keys of an identity, and can also set or remove the spending limits of its
+/// existing keys
+#[derive(Encode, Decode, PlatformSignable, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+// There is a problem deriving bincode for a borrowed vector
+// Hence we set to do it somewhat manually inside the PlatformSignable proc macro
+// Instead of inside of bincode_derive
+#[platform_signable(derive_bincode_with_borrowed_vec)]
+#[derive(Default)]
+pub struct IdentityUpdateTransitionV1 {
+    /// Unique identifier of the identity to be updated
+    pub identity_id: Identifier,
+
+    /// The revision of the identity after update
+    pub revision: Revision,
+
+    /// Identity nonce for this transition to prevent replay attacks
+    pub nonce: IdentityNonce,
+
+    /// Public Keys to add to the Identity
+    /// we want to skip serialization of transitions, as we does it manually in `to_object()`  and `to_json()`
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    #[platform_signable(into = "Vec<IdentityPublicKeyInCreationSignable>")]
+    pub add_public_keys: Vec<IdentityPublicKeyInCreation>,
+
+    /// Identity Public Keys ID's to disable for the Identity
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub disable_public_keys: Vec<KeyID>,
+
+    /// Timestamp when keys were disabled
+    pub public_keys_disabled_at: Option<TimestampMillis>,
+
+    /// Delay in milliseconds before the disabled keys stop being valid, so a replacement key
+    /// can be added while transitions signed with the rotated keys are still in flight
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub public_keys_disable_grace_period_ms: Option<u64>,
+
+    /// Spending limits per epoch to set on existing keys of the Identity, none removes the limit
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub public_keys_spending_limits: Vec<(KeyID, Option<Credits>)>,
+
+    /// The ID of the public key used to sing the State Transition
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    /// Cryptographic signature of the State Transition
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+    use crate::state_transition::identity_update_transition::accessors::{
+        IdentityUpdateTransitionAccessorsV0, IdentityUpdateTransitionAccessorsV1,
+    };
+    use crate::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
+    use crate::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
+    use crate::state_transition::identity_update_transition::IdentityUpdateTransition;
+    use platform_value::Identifier;
+
+    #[test]
+    fn test_identity_update_transition_v1() {
+        let transition: IdentityUpdateTransition = IdentityUpdateTransitionV1 {
+            identity_id: Identifier::random(),
+            revision: 2,
+            nonce: 1,
+            disable_public_keys: vec![3],
+            public_keys_spending_limits: vec![(1, Some(50000)), (2, None)],
+            signature: [0; 65].to_vec().into(),
+            ..Default::default()
+        }
+        .into();
+
+        let serialized = transition
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+        let deserialized = IdentityUpdateTransition::deserialize_from_bytes(serialized.as_slice())
+            .expect("expected to deserialize");
+        assert_eq!(transition, deserialized);
+    }
+
+    #[test]
+    fn setting_spending_limits_should_upgrade_v0_to_v1() {
+        let identity_id = Identifier::random();
+        let mut transition: IdentityUpdateTransition = IdentityUpdateTransitionV0 {
+            identity_id,
+            revision: 2,
+            nonce: 1,
+            ..Default::default()
+        }
+        .into();
+
+        transition.set_public_keys_spending_limits(vec![]);
+        assert!(matches!(transition, IdentityUpdateTransition::V0(_)));
+
+        transition.set_public_keys_spending_limits(vec![(1, Some(50000))]);
+        assert!(matches!(transition, IdentityUpdateTransition::V1(_)));
+        assert_eq!(transition.identity_id(), identity_id);
+        assert_eq!(transition.nonce(), 1);
+        assert_eq!(
+            transition.public_keys_spending_limits(),
+            &[(1, Some(50000))]
+        );
+    }
+}
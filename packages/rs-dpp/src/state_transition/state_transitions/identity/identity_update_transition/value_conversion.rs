@@ -5,6 +5,7 @@ use platform_value::Value;
 use crate::ProtocolError;
 
 use crate::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
+use crate::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
 use crate::state_transition::identity_update_transition::IdentityUpdateTransition;
 use crate::state_transition::state_transitions::identity_update_transition::fields::*;
 use crate::state_transition::StateTransitionValueConvert;
@@ -22,6 +23,11 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityUpdateTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityUpdateTransition::V1(transition) => {
+                let mut value = transition.to_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -32,6 +38,11 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityUpdateTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityUpdateTransition::V1(transition) => {
+                let mut value = transition.to_canonical_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -42,6 +53,11 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityUpdateTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityUpdateTransition::V1(transition) => {
+                let mut value = transition.to_canonical_cleaned_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -52,6 +68,11 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityUpdateTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityUpdateTransition::V1(transition) => {
+                let mut value = transition.to_cleaned_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -72,6 +93,7 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityUpdateTransition {
 
         match version {
             0 => Ok(IdentityUpdateTransitionV0::from_object(raw_object, platform_version)?.into()),
+            1 => Ok(IdentityUpdateTransitionV1::from_object(raw_object, platform_version)?.into()),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown IdentityUpdateTransition version {n}"
             ))),
@@ -97,6 +119,9 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityUpdateTransition {
             0 => Ok(
                 IdentityUpdateTransitionV0::from_value_map(raw_value_map, platform_version)?.into(),
             ),
+            1 => Ok(
+                IdentityUpdateTransitionV1::from_value_map(raw_value_map, platform_version)?.into(),
+            ),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown IdentityUpdateTransition version {n}"
             ))),
@@ -110,6 +135,7 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityUpdateTransition {
 
         match version {
             0 => IdentityUpdateTransitionV0::clean_value(value),
+            1 => IdentityUpdateTransitionV1::clean_value(value),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown IdentityUpdateTransition version {n}"
             ))),
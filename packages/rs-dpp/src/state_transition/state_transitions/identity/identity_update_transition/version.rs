@@ -6,6 +6,7 @@ impl FeatureVersioned for IdentityUpdateTransition {
     fn feature_version(&self) -> FeatureVersion {
         match self {
             IdentityUpdateTransition::V0(v0) => v0.feature_version(),
+            IdentityUpdateTransition::V1(v1) => v1.feature_version(),
         }
     }
 }
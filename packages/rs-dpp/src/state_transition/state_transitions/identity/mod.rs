@@ -2,6 +2,7 @@ mod common_fields;
 pub mod identity_create_transition;
 pub mod identity_credit_transfer_transition;
 pub mod identity_credit_withdrawal_transition;
+pub mod identity_recover_transition;
 pub mod identity_topup_transition;
 pub mod identity_update_transition;
 pub mod public_key_in_creation;
@@ -0,0 +1,111 @@
+//! Signing and verification with `EDDSA_25519_HASH160` keys.
+//!
+//! Identity public keys of this type only hold the hash160 of the Ed25519 public key and,
+//! unlike ECDSA, the public key can't be recovered from an Ed25519 signature. Signatures
+//! are therefore made of the Ed25519 public key followed by the Ed25519 signature of the data.
+
+use crate::util::hash::ripemd160_sha256;
+use crate::ProtocolError;
+use anyhow::anyhow;
+use ed25519_dalek::{
+    Signature, Signer, SigningKey, VerifyingKey, PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH,
+    SIGNATURE_LENGTH,
+};
+
+/// The length of a signature made with an `EDDSA_25519_HASH160` key
+pub const SIGNATURE_WITH_PUBLIC_KEY_LENGTH: usize = PUBLIC_KEY_LENGTH + SIGNATURE_LENGTH;
+
+fn signing_key(private_key: &[u8]) -> Result<SigningKey, ProtocolError> {
+    let secret_key: &[u8; SECRET_KEY_LENGTH] = private_key.try_into().map_err(|_| {
+        ProtocolError::Error(anyhow!(
+            "eddsa 25519 private key must be {} bytes long",
+            SECRET_KEY_LENGTH
+        ))
+    })?;
+
+    Ok(SigningKey::from_bytes(secret_key))
+}
+
+/// Returns the hash160 of the Ed25519 public key of the private key,
+/// as it's stored in the `EDDSA_25519_HASH160` identity public key
+pub fn public_key_hash(private_key: &[u8]) -> Result<[u8; 20], ProtocolError> {
+    Ok(ripemd160_sha256(
+        signing_key(private_key)?.verifying_key().as_bytes(),
+    ))
+}
+
+/// Signs the data, the signature is prefixed with the Ed25519 public key of the private key
+pub fn sign(
+    data: &[u8],
+    private_key: &[u8],
+) -> Result<[u8; SIGNATURE_WITH_PUBLIC_KEY_LENGTH], ProtocolError> {
+    let signing_key = signing_key(private_key)?;
+
+    let mut signature = [0u8; SIGNATURE_WITH_PUBLIC_KEY_LENGTH];
+    signature[..PUBLIC_KEY_LENGTH].copy_from_slice(signing_key.verifying_key().as_bytes());
+    signature[PUBLIC_KEY_LENGTH..].copy_from_slice(&signing_key.sign(data).to_bytes());
+
+    Ok(signature)
+}
+
+/// Verifies the signature of the data was made with the Ed25519 key whose hash160 is `public_key_hash`
+pub fn verify_data_signature(
+    data: &[u8],
+    signature: &[u8],
+    public_key_hash: &[u8],
+) -> Result<(), ProtocolError> {
+    if signature.len() != SIGNATURE_WITH_PUBLIC_KEY_LENGTH {
+        return Err(ProtocolError::Error(anyhow!(
+            "eddsa 25519 signature must be {} bytes long, got {}",
+            SIGNATURE_WITH_PUBLIC_KEY_LENGTH,
+            signature.len()
+        )));
+    }
+
+    let (public_key_bytes, signature_bytes) = signature.split_at(PUBLIC_KEY_LENGTH);
+
+    if ripemd160_sha256(public_key_bytes).as_slice() != public_key_hash {
+        return Err(ProtocolError::Error(anyhow!(
+            "eddsa 25519 public key of the signature doesn't match the public key hash"
+        )));
+    }
+
+    let public_key = VerifyingKey::try_from(public_key_bytes)
+        .map_err(|e| ProtocolError::Error(anyhow!("invalid eddsa 25519 public key: {}", e)))?;
+
+    let signature = Signature::from_slice(signature_bytes)
+        .map_err(|e| ProtocolError::Error(anyhow!("invalid eddsa 25519 signature: {}", e)))?;
+
+    public_key
+        .verify_strict(data, &signature)
+        .map_err(|e| ProtocolError::Error(anyhow!("eddsa 25519 signature is incorrect: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_verify_signature_made_with_private_key() {
+        let private_key = [1u8; 32];
+        let public_key_hash = public_key_hash(&private_key).expect("expected public key hash");
+
+        let signature = sign(b"data", &private_key).expect("expected to sign");
+
+        verify_data_signature(b"data", &signature, &public_key_hash)
+            .expect("expected signature to be valid");
+
+        verify_data_signature(b"other data", &signature, &public_key_hash)
+            .expect_err("expected signature of other data to be invalid");
+    }
+
+    #[test]
+    fn should_not_verify_signature_of_other_key() {
+        let public_key_hash = public_key_hash(&[1u8; 32]).expect("expected public key hash");
+
+        let signature = sign(b"data", &[2u8; 32]).expect("expected to sign");
+
+        verify_data_signature(b"data", &signature, &public_key_hash)
+            .expect_err("expected signature of other key to be invalid");
+    }
+}
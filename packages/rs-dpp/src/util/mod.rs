@@ -4,12 +4,14 @@ pub mod cbor_serializer;
 pub mod cbor_value;
 
 pub mod deserializer;
+pub mod ed25519_signer;
 pub mod entropy_generator;
 pub mod hash;
 pub mod is_fibonacci_number;
 pub mod json_path;
 pub mod json_schema;
 pub mod json_value;
+pub mod multisig_signer;
 pub mod protocol_data;
 
 pub mod strings;
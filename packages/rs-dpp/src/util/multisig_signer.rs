@@ -0,0 +1,316 @@
+//! Signing and verification with `BIP13_SCRIPT_HASH` keys authorizing an M-of-N policy.
+//!
+//! Identity public keys of this type hold the hash160 of a standard multisig redeem script
+//! (`OP_M <public key 1> ... <public key N> OP_N OP_CHECKMULTISIG`) over compressed secp256k1
+//! public keys. Signatures are a [MultisigSignature] carrying the redeem script along with
+//! the ECDSA signatures of M of its public keys.
+
+use crate::util::hash::ripemd160_sha256;
+use crate::ProtocolError;
+use anyhow::anyhow;
+use bincode::{Decode, Encode};
+use dashcore::signer;
+
+const OP_PUSHNUM_1: u8 = 0x51;
+const OP_PUSHNUM_16: u8 = 0x60;
+const OP_PUSHBYTES_33: u8 = 0x21;
+const OP_CHECKMULTISIG: u8 = 0xae;
+
+/// The length of the compressed secp256k1 public keys of a multisig redeem script
+pub const MULTISIG_PUBLIC_KEY_LENGTH: usize = 33;
+
+/// The maximum number of public keys of a multisig redeem script
+pub const MAX_MULTISIG_PUBLIC_KEYS: usize = 16;
+
+/// A signature made with a `BIP13_SCRIPT_HASH` key
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct MultisigSignature {
+    /// The multisig redeem script the identity public key is the hash of
+    pub redeem_script: Vec<u8>,
+    /// The ECDSA signatures, in the same order as the public keys they were made with
+    /// appear in the redeem script
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl MultisigSignature {
+    /// Serializes the signature to be set as the signature of a state transition
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ProtocolError> {
+        let config = bincode::config::standard()
+            .with_big_endian()
+            .with_no_limit();
+        bincode::encode_to_vec(self, config).map_err(|e| {
+            ProtocolError::EncodingError(format!("unable to serialize multisig signature: {}", e))
+        })
+    }
+
+    /// Deserializes the signature of a state transition
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProtocolError> {
+        let config = bincode::config::standard()
+            .with_big_endian()
+            .with_no_limit();
+        let (multisig_signature, read_length) =
+            bincode::decode_from_slice(bytes, config).map_err(|e| {
+                ProtocolError::DecodingError(format!(
+                    "unable to deserialize multisig signature: {}",
+                    e
+                ))
+            })?;
+
+        if read_length != bytes.len() {
+            return Err(ProtocolError::DecodingError(
+                "multisig signature has trailing bytes".to_string(),
+            ));
+        }
+
+        Ok(multisig_signature)
+    }
+}
+
+fn pushnum_value(opcode: u8) -> Option<usize> {
+    (OP_PUSHNUM_1..=OP_PUSHNUM_16)
+        .contains(&opcode)
+        .then(|| (opcode - OP_PUSHNUM_1) as usize + 1)
+}
+
+/// Builds the standard multisig redeem script requiring `threshold` signatures of the public keys
+pub fn multisig_redeem_script<K: AsRef<[u8]>>(
+    threshold: usize,
+    public_keys: &[K],
+) -> Result<Vec<u8>, ProtocolError> {
+    if public_keys.is_empty() || public_keys.len() > MAX_MULTISIG_PUBLIC_KEYS {
+        return Err(ProtocolError::Error(anyhow!(
+            "multisig must have between 1 and {} public keys, got {}",
+            MAX_MULTISIG_PUBLIC_KEYS,
+            public_keys.len()
+        )));
+    }
+
+    if threshold == 0 || threshold > public_keys.len() {
+        return Err(ProtocolError::Error(anyhow!(
+            "multisig threshold must be between 1 and {}, got {}",
+            public_keys.len(),
+            threshold
+        )));
+    }
+
+    let mut redeem_script =
+        Vec::with_capacity(3 + public_keys.len() * (MULTISIG_PUBLIC_KEY_LENGTH + 1));
+
+    redeem_script.push(OP_PUSHNUM_1 + (threshold - 1) as u8);
+
+    for public_key in public_keys {
+        let public_key = public_key.as_ref();
+
+        if public_key.len() != MULTISIG_PUBLIC_KEY_LENGTH {
+            return Err(ProtocolError::Error(anyhow!(
+                "multisig public keys must be {} bytes long compressed keys, got {} bytes",
+                MULTISIG_PUBLIC_KEY_LENGTH,
+                public_key.len()
+            )));
+        }
+
+        redeem_script.push(OP_PUSHBYTES_33);
+        redeem_script.extend_from_slice(public_key);
+    }
+
+    redeem_script.push(OP_PUSHNUM_1 + (public_keys.len() - 1) as u8);
+    redeem_script.push(OP_CHECKMULTISIG);
+
+    Ok(redeem_script)
+}
+
+/// Parses a standard multisig redeem script into its threshold and public keys
+pub fn parse_multisig_redeem_script(
+    redeem_script: &[u8],
+) -> Result<(usize, Vec<&[u8]>), ProtocolError> {
+    let invalid_script =
+        |reason: &str| ProtocolError::Error(anyhow!("invalid multisig redeem script: {}", reason));
+
+    let Some((&threshold_opcode, rest)) = redeem_script.split_first() else {
+        return Err(invalid_script("script is empty"));
+    };
+
+    let Some((&OP_CHECKMULTISIG, rest)) = rest.split_last() else {
+        return Err(invalid_script("script must end with OP_CHECKMULTISIG"));
+    };
+
+    let Some((&public_keys_count_opcode, public_keys_bytes)) = rest.split_last() else {
+        return Err(invalid_script("missing number of public keys"));
+    };
+
+    let threshold =
+        pushnum_value(threshold_opcode).ok_or_else(|| invalid_script("invalid threshold"))?;
+
+    let public_keys_count = pushnum_value(public_keys_count_opcode)
+        .ok_or_else(|| invalid_script("invalid number of public keys"))?;
+
+    if threshold > public_keys_count {
+        return Err(invalid_script(
+            "threshold is greater than the number of public keys",
+        ));
+    }
+
+    if public_keys_bytes.len() != public_keys_count * (MULTISIG_PUBLIC_KEY_LENGTH + 1) {
+        return Err(invalid_script(
+            "public keys don't match the number of public keys",
+        ));
+    }
+
+    let public_keys = public_keys_bytes
+        .chunks(MULTISIG_PUBLIC_KEY_LENGTH + 1)
+        .map(|push| match push.split_first() {
+            Some((&OP_PUSHBYTES_33, public_key)) => Ok(public_key),
+            _ => Err(invalid_script("public keys must be compressed")),
+        })
+        .collect::<Result<Vec<_>, ProtocolError>>()?;
+
+    Ok((threshold, public_keys))
+}
+
+/// Returns the number of public keys of the redeem script carried by a multisig signature,
+/// every one of them may be checked when verifying the signature
+pub fn public_keys_count(signature: &[u8]) -> Result<usize, ProtocolError> {
+    let MultisigSignature { redeem_script, .. } = MultisigSignature::from_bytes(signature)?;
+
+    let (_, public_keys) = parse_multisig_redeem_script(&redeem_script)?;
+
+    Ok(public_keys.len())
+}
+
+/// Verifies the multisig signature of the data was made with the redeem script whose hash160
+/// is `script_hash`, with as many signatures as its threshold
+pub fn verify_data_signature(
+    data: &[u8],
+    signature: &[u8],
+    script_hash: &[u8],
+) -> Result<(), ProtocolError> {
+    let MultisigSignature {
+        redeem_script,
+        signatures,
+    } = MultisigSignature::from_bytes(signature)?;
+
+    if ripemd160_sha256(&redeem_script).as_slice() != script_hash {
+        return Err(ProtocolError::Error(anyhow!(
+            "redeem script of the multisig signature doesn't match the script hash"
+        )));
+    }
+
+    let (threshold, public_keys) = parse_multisig_redeem_script(&redeem_script)?;
+
+    if signatures.len() != threshold {
+        return Err(ProtocolError::Error(anyhow!(
+            "multisig requires {} signatures, got {}",
+            threshold,
+            signatures.len()
+        )));
+    }
+
+    // Like OP_CHECKMULTISIG, every signature must match one of the public keys following
+    // the public key matched by the previous signature
+    let mut public_keys = public_keys.into_iter();
+
+    for signature in signatures.iter() {
+        if !public_keys
+            .any(|public_key| signer::verify_data_signature(data, signature, public_key).is_ok())
+        {
+            return Err(ProtocolError::Error(anyhow!(
+                "multisig signatures are incorrect"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dashcore::secp256k1::{Secp256k1, SecretKey};
+
+    fn public_key(private_key: &[u8; 32]) -> [u8; 33] {
+        let secret_key = SecretKey::from_slice(private_key).expect("expected secret key");
+        secret_key.public_key(&Secp256k1::new()).serialize()
+    }
+
+    #[test]
+    fn should_parse_redeem_script() {
+        let public_keys = [public_key(&[1; 32]), public_key(&[2; 32])];
+
+        let redeem_script =
+            multisig_redeem_script(1, &public_keys).expect("expected redeem script");
+
+        let (threshold, parsed_public_keys) =
+            parse_multisig_redeem_script(&redeem_script).expect("expected to parse");
+
+        assert_eq!(threshold, 1);
+
+        let signature = MultisigSignature {
+            redeem_script: redeem_script.clone(),
+            signatures: vec![],
+        }
+        .to_bytes()
+        .expect("expected to serialize");
+
+        assert_eq!(public_keys_count(&signature).expect("expected a count"), 2);
+        assert_eq!(
+            parsed_public_keys,
+            public_keys
+                .iter()
+                .map(|key| key.as_slice())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_verify_threshold_signatures_in_public_keys_order() {
+        let private_keys = [[1; 32], [2; 32], [3; 32]];
+        let public_keys = private_keys.iter().map(public_key).collect::<Vec<_>>();
+
+        let redeem_script =
+            multisig_redeem_script(2, &public_keys).expect("expected redeem script");
+        let script_hash = ripemd160_sha256(&redeem_script);
+
+        let sign = |private_key: &[u8; 32]| {
+            signer::sign(b"data", private_key)
+                .expect("expected to sign")
+                .to_vec()
+        };
+
+        let signature = |signatures: Vec<Vec<u8>>| {
+            MultisigSignature {
+                redeem_script: redeem_script.clone(),
+                signatures,
+            }
+            .to_bytes()
+            .expect("expected to serialize")
+        };
+
+        verify_data_signature(
+            b"data",
+            &signature(vec![sign(&private_keys[0]), sign(&private_keys[2])]),
+            &script_hash,
+        )
+        .expect("expected signatures to be valid");
+
+        verify_data_signature(
+            b"data",
+            &signature(vec![sign(&private_keys[2]), sign(&private_keys[0])]),
+            &script_hash,
+        )
+        .expect_err("expected signatures out of order to be invalid");
+
+        verify_data_signature(
+            b"data",
+            &signature(vec![sign(&private_keys[1]), sign(&private_keys[1])]),
+            &script_hash,
+        )
+        .expect_err("expected the same signature twice to be invalid");
+
+        verify_data_signature(
+            b"data",
+            &signature(vec![sign(&private_keys[1])]),
+            &script_hash,
+        )
+        .expect_err("expected less signatures than the threshold to be invalid");
+    }
+}
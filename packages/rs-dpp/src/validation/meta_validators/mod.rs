@@ -39,6 +39,10 @@ lazy_static! {
         "../../../schema/meta_schemas/document/v0/document-meta.json"
     ))
     .unwrap();
+    static ref DATA_CONTRACT_V1: Value = serde_json::from_str::<Value>(include_str!(
+        "../../../schema/meta_schemas/document/v1/document-meta.json"
+    ))
+    .unwrap();
 
     pub static ref DRAFT_202012_META_SCHEMA: JSONSchema = JSONSchema::options()
         .with_draft(Draft::Draft202012)
@@ -133,4 +137,58 @@ lazy_static! {
         .to_owned()
         .compile(&DATA_CONTRACT_V0)
         .expect("Invalid data contract schema");
+
+    // Compiled version of data contract meta schema v1, used from protocol version 2
+    pub static ref DOCUMENT_META_SCHEMA_V1: JSONSchema = JSONSchema::options()
+        .add_keyword(
+                "byteArray",
+                KeywordDefinition::Schema(json!({
+                    "items": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "maximum": 255,
+                    },
+                })),
+            )
+        .should_validate_formats(true)
+        .with_draft(Draft::Draft202012)
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/applicator".to_string(),
+            DRAFT202012_APPLICATOR.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/core".to_string(),
+            DRAFT202012_CORE.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/applicator".to_string(),
+            DRAFT202012_APPLICATOR.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/unevaluated".to_string(),
+            DRAFT202012_UNEVALUATED.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/validation".to_string(),
+            DRAFT202012_VALIDATION.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/meta-data".to_string(),
+            DRAFT202012_META_DATA.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/format-annotation".to_string(),
+            DRAFT202012_FORMAT_ANNOTATION.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/content".to_string(),
+            DRAFT202012_CONTENT.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/schema".to_string(),
+            DRAFT202012.clone(),
+        )
+        .to_owned()
+        .compile(&DATA_CONTRACT_V1)
+        .expect("Invalid data contract schema");
 }
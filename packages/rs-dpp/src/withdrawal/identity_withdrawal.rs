@@ -0,0 +1,105 @@
+use crate::document::{Document, DocumentV0Getters};
+use crate::fee::Credits;
+use crate::identity::core_script::CoreScript;
+use crate::prelude::TimestampMillis;
+use crate::withdrawal::Pooling;
+use crate::ProtocolError;
+use dashcore::Txid;
+use data_contracts::withdrawals_contract::v1::document_types::withdrawal;
+use data_contracts::withdrawals_contract::WithdrawalStatus;
+use platform_value::btreemap_extensions::BTreeValueMapHelper;
+use platform_value::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// A withdrawal of credits requested by an identity, read from its document in the withdrawals
+/// system contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityWithdrawal {
+    /// The id of the withdrawal document
+    pub id: Identifier,
+    /// The identity that requested the withdrawal
+    pub identity_id: Identifier,
+    /// The withdrawn credits
+    pub amount: Credits,
+    /// The fee of the Core transaction in Duffs per byte
+    pub core_fee_per_byte: u32,
+    /// How the withdrawal can be pooled with other withdrawals
+    pub pooling: Pooling,
+    /// The script of the Core output receiving the withdrawal
+    pub output_script: CoreScript,
+    /// The progress of the withdrawal
+    pub status: WithdrawalStatus,
+    /// The index of the asset unlock transaction the withdrawal is part of, once pooled.
+    /// Core identifies asset unlock transactions by this index.
+    pub transaction_index: Option<u64>,
+    /// The Core height at which the asset unlock transaction was signed, once pooled
+    pub transaction_sign_height: Option<u32>,
+    /// The id of the asset unlock transaction the withdrawal is part of, once it is signed and
+    /// broadcasted. It is not part of the document, as the transaction is signed by the quorum
+    /// after the block building it, so it is only known from the block that follows.
+    pub transaction_id: Option<Txid>,
+    /// When the withdrawal was requested
+    pub created_at: TimestampMillis,
+    /// When the status of the withdrawal last changed
+    pub updated_at: TimestampMillis,
+}
+
+impl TryFrom<&Document> for IdentityWithdrawal {
+    type Error = ProtocolError;
+
+    fn try_from(document: &Document) -> Result<Self, Self::Error> {
+        let properties = document.properties();
+
+        let pooling = properties
+            .get_integer::<u8>(withdrawal::properties::POOLING)
+            .ok()
+            .and_then(|pooling| Pooling::try_from(pooling).ok())
+            .ok_or(ProtocolError::CorruptedSerialization(
+                "withdrawal document should have a valid pooling".to_string(),
+            ))?;
+
+        let status = properties
+            .get_integer::<u8>(withdrawal::properties::STATUS)
+            .ok()
+            .and_then(|status| WithdrawalStatus::try_from(status).ok())
+            .ok_or(ProtocolError::CorruptedSerialization(
+                "withdrawal document should have a valid status".to_string(),
+            ))?;
+
+        Ok(IdentityWithdrawal {
+            id: document.id(),
+            identity_id: document.owner_id(),
+            amount: properties.get_integer(withdrawal::properties::AMOUNT)?,
+            core_fee_per_byte: properties.get_integer(withdrawal::properties::CORE_FEE_PER_BYTE)?,
+            pooling,
+            output_script: CoreScript::from_bytes(
+                properties.get_bytes(withdrawal::properties::OUTPUT_SCRIPT)?,
+            ),
+            status,
+            transaction_index: properties
+                .get_optional_integer(withdrawal::properties::TRANSACTION_INDEX)?,
+            transaction_sign_height: properties
+                .get_optional_integer(withdrawal::properties::TRANSACTION_SIGN_HEIGHT)?,
+            transaction_id: None,
+            created_at: document
+                .created_at()
+                .ok_or(ProtocolError::CorruptedSerialization(
+                    "withdrawal document should have a creation time".to_string(),
+                ))?,
+            updated_at: document
+                .updated_at()
+                .ok_or(ProtocolError::CorruptedSerialization(
+                    "withdrawal document should have an update time".to_string(),
+                ))?,
+        })
+    }
+}
+
+impl TryFrom<Document> for IdentityWithdrawal {
+    type Error = ProtocolError;
+
+    fn try_from(document: Document) -> Result<Self, Self::Error> {
+        IdentityWithdrawal::try_from(&document)
+    }
+}
@@ -1,9 +1,23 @@
+pub mod identity_withdrawal;
+
 use bincode::{Decode, Encode};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 #[repr(u8)]
 #[derive(
-    Serialize_repr, Deserialize_repr, PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, Default,
+    Serialize_repr,
+    Deserialize_repr,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Debug,
+    Encode,
+    Decode,
+    Default,
+    TryFromPrimitive,
+    IntoPrimitive,
 )]
 pub enum Pooling {
     #[default]
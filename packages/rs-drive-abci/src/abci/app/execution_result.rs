@@ -15,7 +15,7 @@ impl TryIntoPlatformVersioned<ExecTxResult> for StateTransitionExecutionResult {
         platform_version: &PlatformVersion,
     ) -> Result<ExecTxResult, Self::Error> {
         let response = match self {
-            StateTransitionExecutionResult::SuccessfulExecution(estimated_fees, actual_fees) => {
+            StateTransitionExecutionResult::SuccessfulExecution(estimated_fees, actual_fees, _) => {
                 ExecTxResult {
                     code: 0,
                     gas_wanted: estimated_fees.total_base_fee() as SignedCredits,
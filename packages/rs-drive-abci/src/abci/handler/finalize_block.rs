@@ -44,6 +44,20 @@ where
 
     app.commit_transaction()?;
 
+    if let Some(document_changes) = block_finalization_outcome.document_changes {
+        let platform_state = app.platform().state.read();
+        let platform_version = platform_state.current_platform_version()?;
+        drop(platform_state);
+
+        // The block is already committed so we don't fail if subscribers can't be notified
+        if let Err(error) = app
+            .platform()
+            .publish_document_changes(document_changes, platform_version)
+        {
+            tracing::error!(?error, "unable to publish document changes");
+        }
+    }
+
     Ok(proto::ResponseFinalizeBlock {
         events: vec![],
         retain_height: 0,
@@ -48,8 +48,12 @@ where
             core_rpc: &self.core_rpc,
         };
 
-        let state_transition_execution_event =
-            process_state_transition(&platform_ref, state_transition, Some(transaction))?;
+        let state_transition_execution_event = process_state_transition(
+            &platform_ref,
+            state_read_guard.last_block_info(),
+            state_transition,
+            Some(transaction),
+        )?;
 
         if state_transition_execution_event.is_valid() {
             let execution_event = state_transition_execution_event.into_data()?;
@@ -664,7 +668,20 @@ mod tests {
         let validation_result = platform
             .execute_tx(documents_batch_create_serialized_transition, &transaction)
             .expect("expected to execute document_create tx");
-        assert!(matches!(validation_result, SuccessfulPaidExecution(..)));
+
+        let SuccessfulPaidExecution(_, _, fee_results_by_contract) = validation_result else {
+            panic!(
+                "expected a successful paid execution: {:?}",
+                validation_result
+            );
+        };
+
+        // The fees of the documents batch are attributed to the contract it writes to
+        assert_eq!(
+            fee_results_by_contract.keys().collect::<Vec<_>>(),
+            vec![&dashpay_contract.id()]
+        );
+        assert!(fee_results_by_contract[&dashpay_contract.id()].storage_fee > 0);
 
         platform
             .drive
@@ -31,6 +31,7 @@ use crate::platform_types::cleaned_abci_messages::cleaned_block::v0::CleanedBloc
 use crate::platform_types::cleaned_abci_messages::finalized_block_cleaned_request::v0::FinalizeBlockCleanedRequest;
 
 use crate::platform_types::commit::Commit;
+use crate::platform_types::document_changes::BlockDocumentChanges;
 use crate::platform_types::epoch_info::v0::EpochInfoV0Getters;
 use crate::platform_types::platform::Platform;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
@@ -223,6 +224,10 @@ where
             .unsigned_withdrawal_transactions_mut()
             .drain();
 
+        // Take document changes to publish them once the block is committed
+        let document_changes =
+            std::mem::take(mutable_block_execution_context.document_changes_mut());
+
         drop(mutable_block_execution_context_guard);
 
         // Drain signatures instead of cloning
@@ -248,6 +253,11 @@ where
             platform_version,
         )?;
 
+        let block_document_changes = BlockDocumentChanges {
+            block_info: to_commit_block_info.clone(),
+            changes: document_changes,
+        };
+
         // Update platform (drive abci) state
 
         let extended_block_info = ExtendedBlockInfoV0 {
@@ -269,6 +279,9 @@ where
         crate::metrics::abci_last_platform_height(height);
         crate::metrics::abci_last_finalized_round(round);
 
-        Ok(validation_result.into())
+        Ok(block_execution_outcome::v0::BlockFinalizationOutcome {
+            validation_result,
+            document_changes: Some(block_document_changes),
+        })
     }
 }
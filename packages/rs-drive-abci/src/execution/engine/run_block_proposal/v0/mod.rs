@@ -1,4 +1,5 @@
 use dpp::block::epoch::Epoch;
+use dpp::fee::fee_result::FeeResult;
 
 use dpp::validation::ValidationResult;
 use drive::error::Error::GroveDB;
@@ -114,6 +115,7 @@ where
             proposer_pro_tx_hash,
             validator_set_quorum_hash,
             raw_state_transitions,
+            last_commit_threshold_vote_extensions,
             ..
         } = block_proposal;
 
@@ -258,11 +260,26 @@ where
                 );
             }
 
+            let previous_protocol_version =
+                block_platform_state.current_protocol_version_in_consensus();
+
             // Set current protocol version to the version from upcoming epoch
             block_platform_state.set_current_protocol_version_in_consensus(
                 block_platform_state.next_epoch_protocol_version(),
             );
 
+            // Create the state structures introduced by the new protocol version
+            if previous_protocol_version != block_platform_state.next_epoch_protocol_version() {
+                let new_platform_version =
+                    PlatformVersion::get(block_platform_state.next_epoch_protocol_version())?;
+                self.perform_events_on_first_block_of_protocol_change(
+                    &block_info,
+                    transaction,
+                    previous_protocol_version,
+                    new_platform_version,
+                )?;
+            }
+
             // Determine new protocol version based on votes for the next epoch
             let maybe_new_protocol_version = self.check_for_desired_protocol_upgrade(
                 hpmn_list_len,
@@ -280,14 +297,14 @@ where
         }
 
         // Mark all previously broadcasted and chainlocked withdrawals as complete
-        // only when we are on a new core height
-        if block_state_info.core_chain_locked_height() != last_block_core_height {
-            self.update_broadcasted_withdrawal_statuses(
-                &block_info,
-                transaction,
-                platform_version,
-            )?;
-        }
+        // and refund the expired ones only when we are on a new core height
+        let withdrawal_refund_fees = if block_state_info.core_chain_locked_height()
+            != last_block_core_height
+        {
+            self.update_broadcasted_withdrawal_statuses(&block_info, transaction, platform_version)?
+        } else {
+            FeeResult::default()
+        };
 
         // Preparing withdrawal transactions for signing and broadcasting
         // To process withdrawals we need to dequeue untiled transactions from the withdrawal transactions queue
@@ -303,6 +320,16 @@ where
                 platform_version,
             )?;
 
+        // Store the Core transaction ids of the withdrawal transactions signed in the last commit
+        // and keep the unsigned withdrawal transactions of this block until they are signed
+        self.store_withdrawal_transaction_ids(
+            last_commit_threshold_vote_extensions,
+            &unsigned_withdrawal_transaction_bytes,
+            &block_info,
+            Some(transaction),
+            platform_version,
+        )?;
+
         // Process transactions
         let state_transitions_result = self.process_raw_state_transitions(
             raw_state_transitions,
@@ -322,6 +349,9 @@ where
             platform_version,
         )?;
 
+        // Credit recipients of pending transfers released by this block
+        self.release_pending_transfers(&block_info, Some(transaction), platform_version)?;
+
         // Create a new block execution context
 
         let mut block_execution_context: BlockExecutionContext =
@@ -332,11 +362,19 @@ where
                 unsigned_withdrawal_transactions: unsigned_withdrawal_transaction_bytes,
                 block_platform_state,
                 proposer_results: None,
+                document_changes: state_transitions_result.document_changes().to_vec(),
             }
             .into();
 
         // while we have the state transitions executed, we now need to process the block fees
-        let block_fees_v0: BlockFeesV0 = state_transitions_result.aggregated_fees().clone().into();
+        // including the fees paid by identities for the refunds of expired withdrawals
+        let mut block_fee_result = state_transitions_result.aggregated_fees().clone();
+
+        block_fee_result
+            .checked_add_assign(withdrawal_refund_fees)
+            .map_err(Error::Protocol)?;
+
+        let block_fees_v0: BlockFeesV0 = block_fee_result.into();
 
         // Process fees
         let processed_block_fees = self.process_block_fees(
@@ -5,3 +5,9 @@ pub(in crate::execution) mod validator_set_update;
 
 /// Updating the drive cache happens as the final part of block finalization
 pub(in crate::execution) mod update_drive_cache;
+
+/// Publishing document changes happens once the block is committed
+pub(in crate::execution) mod publish_document_changes;
+
+/// Releasing pending transfers happens after state transitions are processed
+pub(in crate::execution) mod release_pending_transfers;
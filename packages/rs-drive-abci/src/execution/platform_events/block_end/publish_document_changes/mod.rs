@@ -0,0 +1,53 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+
+use crate::platform_types::document_changes::BlockDocumentChanges;
+use crate::platform_types::platform::Platform;
+
+use crate::rpc::core::CoreRPCLike;
+
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Publishes the documents changed by a block to the document subscriptions once
+    /// the block is committed.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the publish_document_changes function.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_document_changes` - The documents changed by the committed block.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - If the changes are successfully published, or there is nobody
+    ///   subscribed, it returns `Ok(())`. If the method version is unknown, it returns an `Error`.
+    ///
+    pub fn publish_document_changes(
+        &self,
+        block_document_changes: BlockDocumentChanges,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .block_end
+            .publish_document_changes
+        {
+            0 => self.publish_document_changes_v0(block_document_changes),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "publish_document_changes".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
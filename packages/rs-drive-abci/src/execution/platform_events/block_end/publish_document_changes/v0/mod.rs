@@ -0,0 +1,33 @@
+use crate::error::Error;
+use crate::platform_types::document_changes::BlockDocumentChanges;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use std::sync::Arc;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Publishes the documents changed by a committed block to the document subscriptions.
+    ///
+    /// Changed documents were already taken from the executed state transitions, so nothing is
+    /// read from the state here. This is skipped entirely when nobody is subscribed.
+    ///
+    pub(super) fn publish_document_changes_v0(
+        &self,
+        block_document_changes: BlockDocumentChanges,
+    ) -> Result<(), Error> {
+        if block_document_changes.changes.is_empty()
+            || self.document_changes_sender.receiver_count() == 0
+        {
+            return Ok(());
+        }
+
+        // Sending only fails if all subscriptions were dropped in the meantime
+        let _ = self
+            .document_changes_sender
+            .send(Arc::new(block_document_changes));
+
+        Ok(())
+    }
+}
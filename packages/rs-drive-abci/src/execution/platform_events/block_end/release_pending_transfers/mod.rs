@@ -0,0 +1,59 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+
+use crate::platform_types::platform::Platform;
+
+use crate::rpc::core::CoreRPCLike;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Credits recipients with the pending transfers whose release condition is reached
+    /// by the block, and removes the released transfers.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the release_pending_transfers function. Pending transfers only exist from protocol
+    /// version 2, so nothing is released before.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_info` - Information about the current block, its time and core height
+    ///   are compared to the release condition of pending transfers.
+    /// * `transaction` - The transaction the released transfers are applied in.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - If the pending transfers are successfully released, it returns `Ok(())`.
+    ///   If there is a problem with the release, it returns an `Error`.
+    ///
+    pub fn release_pending_transfers(
+        &self,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .block_end
+            .release_pending_transfers
+        {
+            None => Ok(()),
+            Some(0) => self.release_pending_transfers_v0(block_info, transaction, platform_version),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "release_pending_transfers".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use drive::drive::batch::DriveOperation::{
+    IdentityOperation, PendingTransferOperation, SystemOperation,
+};
+use drive::drive::batch::{
+    IdentityOperationType, PendingTransferOperationType, SystemOperationType,
+};
+use drive::drive::config::DEFAULT_QUERY_LIMIT;
+use drive::grovedb::TransactionArg;
+
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Credits recipients with the pending transfers released by the block
+    pub(super) fn release_pending_transfers_v0(
+        &self,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        // Transfers above the limit are released in the following blocks
+        let pending_transfers = self.drive.fetch_released_pending_transfers(
+            block_info,
+            DEFAULT_QUERY_LIMIT,
+            transaction,
+            platform_version,
+        )?;
+
+        if pending_transfers.is_empty() {
+            return Ok(());
+        }
+
+        tracing::debug!(
+            "Releasing {} pending transfers at block time {} and core height {}",
+            pending_transfers.len(),
+            block_info.time_ms,
+            block_info.core_height,
+        );
+
+        let mut drive_operations = Vec::with_capacity(pending_transfers.len() * 3);
+
+        for pending_transfer in pending_transfers {
+            drive_operations.push(IdentityOperation(
+                IdentityOperationType::AddToIdentityBalance {
+                    identity_id: pending_transfer.recipient_id.to_buffer(),
+                    added_balance: pending_transfer.amount,
+                },
+            ));
+
+            // Credits of pending transfers were removed from the system when scheduled
+            drive_operations.push(SystemOperation(SystemOperationType::AddToSystemCredits {
+                amount: pending_transfer.amount,
+            }));
+
+            drive_operations.push(PendingTransferOperation(
+                PendingTransferOperationType::RemovePendingTransfer { pending_transfer },
+            ));
+        }
+
+        self.drive.apply_drive_operations(
+            drive_operations,
+            true,
+            block_info,
+            transaction,
+            platform_version,
+        )?;
+
+        Ok(())
+    }
+}
@@ -49,6 +49,7 @@ impl<C> Platform<C> {
                 primary_key_equal_clause: None,
                 in_clause: None,
                 range_clause: None,
+                residual_range_clauses: vec![],
                 equal_clauses: BTreeMap::from([(
                     "$ownerId".to_string(),
                     WhereClause {
@@ -57,6 +58,8 @@ impl<C> Platform<C> {
                         value: Value::Bytes(masternode_owner_id.to_vec()),
                     },
                 )]),
+                or_clauses: vec![],
+                contains_word_clause: None,
             },
             offset: None,
             limit: Some(1),
@@ -1 +1,2 @@
 mod check_for_desired_protocol_upgrade;
+mod perform_events_on_first_block_of_protocol_change;
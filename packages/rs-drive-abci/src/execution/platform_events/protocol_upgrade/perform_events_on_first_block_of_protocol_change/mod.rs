@@ -0,0 +1,56 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use dpp::block::block_info::BlockInfo;
+use dpp::util::deserializer::ProtocolVersion;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C> {
+    /// Performs the state changes required by a new protocol version, such as creating the
+    /// trees of features it introduces.
+    /// This method should only be called on the first block of an epoch changing the protocol
+    /// version.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_info` - Information about the current block.
+    /// * `transaction` - A transaction argument to interact with the underlying storage.
+    /// * `previous_protocol_version` - The protocol version in consensus before this block.
+    /// * `platform_version` - The platform version of the new protocol version, it dictates
+    ///   which version of the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - Returns `Ok(())` if the events were performed, or the
+    ///   corresponding Error if there is an issue interacting with the underlying storage.
+    pub fn perform_events_on_first_block_of_protocol_change(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        previous_protocol_version: ProtocolVersion,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .protocol_upgrade
+            .perform_events_on_first_block_of_protocol_change
+        {
+            None => Ok(()),
+            Some(0) => self.perform_events_on_first_block_of_protocol_change_v0(
+                block_info,
+                transaction,
+                previous_protocol_version,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "perform_events_on_first_block_of_protocol_change".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use dpp::block::block_info::BlockInfo;
+use dpp::util::deserializer::ProtocolVersion;
+use dpp::version::PlatformVersion;
+use drive::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
+use drive::drive::batch::GroveDbOpBatch;
+use drive::drive::identity::{
+    add_initial_pending_transfers_structure_operations,
+    add_initial_withdrawal_transaction_ids_structure_operations,
+};
+use drive::grovedb::Transaction;
+
+/// The protocol version introducing pending transfers
+const PENDING_TRANSFERS_PROTOCOL_VERSION: ProtocolVersion = 2;
+
+/// The protocol version introducing the Core transaction ids of withdrawal transactions
+const WITHDRAWAL_TRANSACTION_IDS_PROTOCOL_VERSION: ProtocolVersion = 2;
+
+impl<C> Platform<C> {
+    /// Creates the structures of the features introduced between the previous protocol version
+    /// and the new one, so that networks started on an older protocol version get the same
+    /// state structure as networks started on the new one.
+    pub(super) fn perform_events_on_first_block_of_protocol_change_v0(
+        &self,
+        _block_info: &BlockInfo,
+        transaction: &Transaction,
+        previous_protocol_version: ProtocolVersion,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        if previous_protocol_version < PENDING_TRANSFERS_PROTOCOL_VERSION
+            && platform_version.protocol_version >= PENDING_TRANSFERS_PROTOCOL_VERSION
+        {
+            let mut batch = GroveDbOpBatch::new();
+
+            add_initial_pending_transfers_structure_operations(&mut batch);
+
+            self.drive.grove_apply_batch(
+                batch,
+                false,
+                Some(transaction),
+                &platform_version.drive,
+            )?;
+        }
+
+        if previous_protocol_version < WITHDRAWAL_TRANSACTION_IDS_PROTOCOL_VERSION
+            && platform_version.protocol_version >= WITHDRAWAL_TRANSACTION_IDS_PROTOCOL_VERSION
+        {
+            let mut batch = GroveDbOpBatch::new();
+
+            add_initial_withdrawal_transaction_ids_structure_operations(&mut batch);
+
+            self.drive.grove_apply_batch(
+                batch,
+                false,
+                Some(transaction),
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -8,10 +8,12 @@ use crate::platform_types::event_execution_result::EventExecutionResult::{
 use crate::platform_types::platform::Platform;
 use crate::rpc::core::CoreRPCLike;
 use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
 use dpp::validation::SimpleConsensusValidationResult;
 use dpp::version::PlatformVersion;
 use drive::drive::identity::update::apply_balance_change_outcome::ApplyBalanceChangeOutcomeV0Methods;
 use drive::grovedb::Transaction;
+use std::collections::BTreeMap;
 
 impl<C> Platform<C>
 where
@@ -95,6 +97,63 @@ where
                     Ok(SuccessfulPaidExecution(
                         fee_validation_result.into_data()?,
                         outcome.actual_fee_paid_owned(),
+                        BTreeMap::new(),
+                    ))
+                } else {
+                    Ok(ConsensusExecutionError(
+                        SimpleConsensusValidationResult::new_with_errors(
+                            fee_validation_result.errors,
+                        ),
+                    ))
+                }
+            }
+            ExecutionEvent::PaidDocumentsBatchDriveEvent {
+                identity,
+                operations_by_contract,
+                execution_operations,
+                ..
+            } => {
+                if fee_validation_result.is_valid_with_data() {
+                    // The operations of every data contract are applied on their own, so the
+                    // fees they cost can be attributed to the contract
+                    let mut individual_fee_result = FeeResult::default();
+                    let mut fee_results_by_contract = BTreeMap::new();
+
+                    for (data_contract_id, operations) in operations_by_contract {
+                        let contract_fee_result = self
+                            .drive
+                            .apply_drive_operations(
+                                operations,
+                                true,
+                                block_info,
+                                Some(transaction),
+                                platform_version,
+                            )
+                            .map_err(Error::Drive)?;
+
+                        individual_fee_result.checked_add_assign(contract_fee_result.clone())?;
+                        fee_results_by_contract.insert(data_contract_id, contract_fee_result);
+                    }
+
+                    ValidationOperation::add_many_to_fee_result(
+                        &execution_operations,
+                        &mut individual_fee_result,
+                        &block_info.epoch,
+                        platform_version,
+                    )?;
+
+                    let balance_change = individual_fee_result.into_balance_change(identity.id);
+
+                    let outcome = self.drive.apply_balance_change_from_fee_to_identity(
+                        balance_change,
+                        Some(transaction),
+                        platform_version,
+                    )?;
+
+                    Ok(SuccessfulPaidExecution(
+                        fee_validation_result.into_data()?,
+                        outcome.actual_fee_paid_owned(),
+                        fee_results_by_contract,
                     ))
                 } else {
                     Ok(ConsensusExecutionError(
@@ -6,18 +6,23 @@ use crate::rpc::core::CoreRPCLike;
 use dpp::block::block_info::BlockInfo;
 use dpp::consensus::basic::decode::SerializedObjectParsingError;
 use dpp::dashcore::hashes::Hash;
+use dpp::document::{Document, DocumentV0Getters};
 use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
 use dpp::identity::state_transition::OptionallyAssetLockProved;
 use dpp::serialization::PlatformDeserializable;
+use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
 use dpp::state_transition::StateTransition;
 use dpp::{dashcore, ProtocolError};
 
+use crate::platform_types::document_changes::{DocumentChange, DocumentChangeType};
 use crate::platform_types::event_execution_result::EventExecutionResult;
 use crate::platform_types::state_transitions_processing_result::{
     StateTransitionExecutionResult, StateTransitionsProcessingResult,
 };
 use dpp::version::PlatformVersion;
 use drive::grovedb::Transaction;
+use std::collections::BTreeMap;
 
 struct StateTransitionAwareError {
     error: Error,
@@ -69,11 +74,14 @@ where
         let mut processing_result = StateTransitionsProcessingResult::default();
 
         for raw_state_transition in raw_state_transitions {
+            let mut document_changes = Vec::new();
+
             let execution_result = self
                 .process_raw_state_transition(
                     raw_state_transition,
                     block_info,
                     &platform_ref,
+                    &mut document_changes,
                     transaction,
                     platform_version,
                 )
@@ -98,6 +106,7 @@ where
                 });
 
             processing_result.add(execution_result)?;
+            processing_result.add_document_changes(document_changes);
         }
 
         Ok(processing_result)
@@ -108,6 +117,7 @@ where
         raw_state_transition: &[u8],
         block_info: &BlockInfo,
         platform_ref: &PlatformRef<C>,
+        document_changes: &mut Vec<DocumentChange>,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
     ) -> Result<StateTransitionExecutionResult, StateTransitionAwareError> {
@@ -169,14 +179,29 @@ where
         let state_transition_name = state_transition.name();
         let is_st_asset_lock_funded = state_transition.optional_asset_lock_proof().is_some();
 
+        // Documents are only reported as changed once the state transition is successfully executed
+        let state_transition_document_changes: Vec<DocumentChange> = match &state_transition {
+            StateTransition::DocumentsBatch(documents_batch_transition) => {
+                documents_batch_transition
+                    .transitions()
+                    .iter()
+                    .map(DocumentChange::from)
+                    .collect()
+            }
+            _ => vec![],
+        };
+
         // Validate state transition and produce an execution event
-        let mut st_validation_result =
-            process_state_transition(platform_ref, state_transition, Some(transaction)).map_err(
-                |error| StateTransitionAwareError {
-                    error,
-                    raw_state_transition: raw_state_transition.into(),
-                },
-            )?;
+        let mut st_validation_result = process_state_transition(
+            platform_ref,
+            block_info,
+            state_transition,
+            Some(transaction),
+        )
+        .map_err(|error| StateTransitionAwareError {
+            error,
+            raw_state_transition: raw_state_transition.into(),
+        })?;
 
         // State Transition is invalid
         if !st_validation_result.is_valid() {
@@ -213,7 +238,7 @@ where
                     })?;
 
                 match event_execution_result {
-                    EventExecutionResult::SuccessfulPaidExecution(_, actual_fees) => {
+                    EventExecutionResult::SuccessfulPaidExecution(_, actual_fees, _) => {
                         tracing::debug!(
                             "{} state transition ({}) not processed, but paid for processing",
                             state_transition_name,
@@ -270,6 +295,26 @@ where
                     raw_state_transition: raw_state_transition.into(),
                 })?;
 
+        // Changed documents are taken from the executed operations, so they don't have to be
+        // fetched again once the block is committed, and only when somebody can receive them
+        let mut state_transition_document_changes = state_transition_document_changes;
+        if !state_transition_document_changes.is_empty()
+            && self.document_changes_sender.receiver_count() > 0
+        {
+            let stored_documents: BTreeMap<Identifier, &Document> = execution_event
+                .stored_documents()
+                .map(|document| (document.id(), document))
+                .collect();
+
+            for change in state_transition_document_changes.iter_mut() {
+                if change.change_type != DocumentChangeType::Deleted {
+                    change.document = stored_documents
+                        .get(&change.document_id)
+                        .map(|document| (*document).clone());
+                }
+            }
+        }
+
         let event_execution_result = self
             .execute_event(execution_event, block_info, transaction, platform_version)
             .map_err(|error| StateTransitionAwareError {
@@ -278,14 +323,24 @@ where
             })?;
 
         let state_transition_execution_result = match event_execution_result {
-            EventExecutionResult::SuccessfulPaidExecution(estimated_fees, actual_fees) => {
+            EventExecutionResult::SuccessfulPaidExecution(
+                estimated_fees,
+                actual_fees,
+                fee_results_by_contract,
+            ) => {
                 tracing::debug!(
                     "{} state transition ({}) successfully processed",
                     state_transition_name,
                     st_hash,
                 );
 
-                StateTransitionExecutionResult::SuccessfulExecution(estimated_fees, actual_fees)
+                document_changes.extend(state_transition_document_changes);
+
+                StateTransitionExecutionResult::SuccessfulExecution(
+                    estimated_fees,
+                    actual_fees,
+                    fee_results_by_contract,
+                )
             }
             EventExecutionResult::SuccessfulFreeExecution => {
                 tracing::debug!(
@@ -294,9 +349,12 @@ where
                     st_hash,
                 );
 
+                document_changes.extend(state_transition_document_changes);
+
                 StateTransitionExecutionResult::SuccessfulExecution(
                     FeeResult::default(),
                     FeeResult::default(),
+                    BTreeMap::new(),
                 )
             }
             EventExecutionResult::ConsensusExecutionError(mut validation_result) => {
@@ -8,10 +8,13 @@ use dpp::block::block_info::BlockInfo;
 use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
 use dpp::consensus::state::state_error::StateError;
 use dpp::fee::fee_result::FeeResult;
+use dpp::fee::Credits;
+use dpp::identity::PartialIdentity;
 
 use dpp::prelude::ConsensusValidationResult;
 use dpp::version::PlatformVersion;
 
+use drive::drive::batch::DriveOperation;
 use drive::grovedb::TransactionArg;
 
 impl<C> Platform<C>
@@ -96,53 +99,81 @@ where
                 removed_balance,
                 operations,
                 execution_operations,
-            } => {
-                let balance = identity.balance.ok_or(Error::Execution(
-                    ExecutionError::CorruptedCodeExecution("partial identity info with no balance"),
-                ))?;
-                let balance_after_principal_operation =
-                    balance.saturating_sub(removed_balance.unwrap_or_default());
-                let mut estimated_fee_result = self
-                    .drive
-                    .apply_drive_operations(
-                        operations.clone(),
-                        false,
-                        block_info,
-                        transaction,
-                        platform_version,
-                    )
-                    .map_err(Error::Drive)?;
-
-                ValidationOperation::add_many_to_fee_result(
-                    execution_operations,
-                    &mut estimated_fee_result,
-                    &block_info.epoch,
-                    platform_version,
-                )?;
-
-                // TODO: Should take into account refunds as well
-                let required_balance = estimated_fee_result.total_base_fee();
-                if balance_after_principal_operation >= required_balance {
-                    Ok(ConsensusValidationResult::new_with_data(
-                        estimated_fee_result,
-                    ))
-                } else {
-                    Ok(ConsensusValidationResult::new_with_data_and_errors(
-                        estimated_fee_result,
-                        vec![StateError::IdentityInsufficientBalanceError(
-                            IdentityInsufficientBalanceError::new(
-                                identity.id,
-                                balance,
-                                required_balance,
-                            ),
-                        )
-                        .into()],
-                    ))
-                }
-            }
+            } => self.validate_fees_of_paid_drive_operations_v0(
+                identity,
+                *removed_balance,
+                operations.clone(),
+                execution_operations,
+                block_info,
+                transaction,
+                platform_version,
+            ),
+            ExecutionEvent::PaidDocumentsBatchDriveEvent {
+                identity,
+                removed_balance,
+                operations_by_contract,
+                execution_operations,
+            } => self.validate_fees_of_paid_drive_operations_v0(
+                identity,
+                *removed_balance,
+                operations_by_contract.values().flatten().cloned().collect(),
+                execution_operations,
+                block_info,
+                transaction,
+                platform_version,
+            ),
             ExecutionEvent::FreeDriveEvent { .. } => Ok(ConsensusValidationResult::new_with_data(
                 FeeResult::default(),
             )),
         }
     }
+
+    /// Validates that the identity paying for the given drive operations can afford their
+    /// estimated fees, on top of the balance removed by the principal operation
+    fn validate_fees_of_paid_drive_operations_v0(
+        &self,
+        identity: &PartialIdentity,
+        removed_balance: Option<Credits>,
+        operations: Vec<DriveOperation>,
+        execution_operations: &[ValidationOperation],
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<FeeResult>, Error> {
+        let balance =
+            identity
+                .balance
+                .ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "partial identity info with no balance",
+                )))?;
+        let balance_after_principal_operation =
+            balance.saturating_sub(removed_balance.unwrap_or_default());
+        let mut estimated_fee_result = self
+            .drive
+            .apply_drive_operations(operations, false, block_info, transaction, platform_version)
+            .map_err(Error::Drive)?;
+
+        ValidationOperation::add_many_to_fee_result(
+            execution_operations,
+            &mut estimated_fee_result,
+            &block_info.epoch,
+            platform_version,
+        )?;
+
+        // TODO: Should take into account refunds as well
+        let required_balance = estimated_fee_result.total_base_fee();
+        if balance_after_principal_operation >= required_balance {
+            Ok(ConsensusValidationResult::new_with_data(
+                estimated_fee_result,
+            ))
+        } else {
+            Ok(ConsensusValidationResult::new_with_data_and_errors(
+                estimated_fee_result,
+                vec![StateError::IdentityInsufficientBalanceError(
+                    IdentityInsufficientBalanceError::new(identity.id, balance, required_balance),
+                )
+                .into()],
+            ))
+        }
+    }
 }
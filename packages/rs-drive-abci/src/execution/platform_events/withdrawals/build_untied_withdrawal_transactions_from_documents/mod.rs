@@ -2,6 +2,7 @@ use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::platform_types::platform::Platform;
 use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
 use dpp::document::Document;
 use dpp::identifier::Identifier;
 use dpp::version::PlatformVersion;
@@ -11,6 +12,7 @@ use drive::drive::identity::withdrawals::{
 use std::collections::HashMap;
 
 mod v0;
+mod v1;
 
 impl<C> Platform<C>
 where
@@ -22,20 +24,29 @@ where
     /// # Arguments
     ///
     /// * `documents` - A slice of `Document`.
-    /// * `drive_operation_types` - A mutable reference to `Vec<DriveOperation>`.
-    /// * `transaction` - A `TransactionArg` reference.
+    /// * `start_index` - The index of the first built transaction.
+    /// * `block_info` - Information about the current block, used from v1 to tell how long withdrawals have been queued.
     /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
     ///   the method to call.
     ///
     /// # Returns
     ///
-    /// * `Result<HashMap<Identifier, WithdrawalTransactionIdAndBytes>, Error>` - Returns a HashMap containing withdrawal transactions if found, otherwise returns an `Error`.
+    /// * `Result<(Vec<WithdrawalTransactionIndexAndBytes>, HashMap<Identifier, WithdrawalTransactionIndex>), Error>` -
+    ///   Returns the built withdrawal transactions and the transaction index of every withdrawal document
+    ///   tied to one of them. From v1, pooled withdrawal documents share the index of their transaction.
     pub(in crate::execution::platform_events::withdrawals) fn build_untied_withdrawal_transactions_from_documents(
         &self,
         documents: &[Document],
         start_index: WithdrawalTransactionIndex,
+        block_info: &BlockInfo,
         platform_version: &PlatformVersion,
-    ) -> Result<HashMap<Identifier, WithdrawalTransactionIndexAndBytes>, Error> {
+    ) -> Result<
+        (
+            Vec<WithdrawalTransactionIndexAndBytes>,
+            HashMap<Identifier, WithdrawalTransactionIndex>,
+        ),
+        Error,
+    > {
         match platform_version
             .drive_abci
             .methods
@@ -45,9 +56,14 @@ where
             0 => {
                 self.build_untied_withdrawal_transactions_from_documents_v0(documents, start_index)
             }
+            1 => self.build_untied_withdrawal_transactions_from_documents_v1(
+                documents,
+                start_index,
+                block_info,
+            ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "build_untied_withdrawal_transactions_from_documents".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
@@ -28,14 +28,22 @@ impl<C> Platform<C>
 where
     C: CoreRPCLike,
 {
-    /// Build list of Core transactions from withdrawal documents
+    /// Build list of Core transactions from withdrawal documents, one transaction per document
     pub(super) fn build_untied_withdrawal_transactions_from_documents_v0(
         &self,
         documents: &[Document],
         start_index: WithdrawalTransactionIndex,
-    ) -> Result<HashMap<Identifier, WithdrawalTransactionIndexAndBytes>, Error> {
-        let mut withdrawals: HashMap<Identifier, WithdrawalTransactionIndexAndBytes> =
-            HashMap::new();
+    ) -> Result<
+        (
+            Vec<WithdrawalTransactionIndexAndBytes>,
+            HashMap<Identifier, WithdrawalTransactionIndex>,
+        ),
+        Error,
+    > {
+        let mut transactions: Vec<WithdrawalTransactionIndexAndBytes> =
+            Vec::with_capacity(documents.len());
+        let mut transaction_indices: HashMap<Identifier, WithdrawalTransactionIndex> =
+            HashMap::with_capacity(documents.len());
 
         for (i, document) in documents.iter().enumerate() {
             let output_script_bytes = document
@@ -95,10 +103,11 @@ where
                     ))
                 })?;
 
-            withdrawals.insert(document.id(), (transaction_index, transaction_buffer));
+            transaction_indices.insert(document.id(), transaction_index);
+            transactions.push((transaction_index, transaction_buffer));
         }
 
-        Ok(withdrawals)
+        Ok((transactions, transaction_indices))
     }
 }
 
@@ -198,14 +207,15 @@ mod tests {
 
             let documents = vec![document_1, document_2];
 
-            let transactions = platform
+            let (transactions, transaction_indices) = platform
                 .build_untied_withdrawal_transactions_from_documents_v0(&documents, 50)
                 .expect("to build transactions from documents");
 
+            assert_eq!(transaction_indices.len(), 2);
+
             assert_eq!(
                 transactions
-                    .values()
-                    .cloned()
+                    .into_iter()
                     .sorted()
                     .collect::<Vec<WithdrawalTransactionIndexAndBytes>>(),
                 vec![
@@ -0,0 +1,582 @@
+use std::collections::HashMap;
+
+use dashcore_rpc::dashcore::{
+    blockdata::transaction::special_transaction::asset_unlock::{
+        qualified_asset_unlock::ASSET_UNLOCK_TX_SIZE,
+        unqualified_asset_unlock::{AssetUnlockBasePayload, AssetUnlockBaseTransactionInfo},
+    },
+    consensus::Encodable,
+    ScriptBuf, TxOut,
+};
+use dpp::block::block_info::BlockInfo;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::fee::Credits;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::system_data_contracts::withdrawals_contract::v1::document_types::withdrawal;
+use dpp::withdrawal::Pooling;
+
+use drive::dpp::identifier::Identifier;
+use drive::dpp::identity::convert_credits_to_duffs;
+use drive::drive::identity::withdrawals::{
+    WithdrawalTransactionIndex, WithdrawalTransactionIndexAndBytes,
+};
+
+use crate::{
+    error::{execution::ExecutionError, Error},
+    platform_types::platform::Platform,
+    rpc::core::CoreRPCLike,
+};
+
+/// The maximum number of withdrawals pooled into a single transaction.
+///
+/// Documents of the 16 transactions dequeued for signing are fetched at once with
+/// `DEFAULT_QUERY_LIMIT` (100), so a transaction must not be tied to more than 6 documents.
+const MAX_WITHDRAWALS_PER_POOLED_TRANSACTION: usize = 6;
+
+/// How long a withdrawal with `Standard` pooling stays queued waiting for other withdrawals
+/// to be pooled with, before it's sent in a transaction of its own
+const STANDARD_POOLING_MAX_WAIT_TIME_MS: u64 = 60 * 60 * 1000;
+
+struct QueuedWithdrawal {
+    id: Identifier,
+    amount: Credits,
+    core_fee_per_byte: u32,
+    output_script_bytes: Vec<u8>,
+    pooling: Pooling,
+    created_at: u64,
+}
+
+impl TryFrom<&Document> for QueuedWithdrawal {
+    type Error = Error;
+
+    fn try_from(document: &Document) -> Result<Self, Self::Error> {
+        let output_script_bytes = document
+            .properties()
+            .get_bytes(withdrawal::properties::OUTPUT_SCRIPT)
+            .map_err(|_| {
+                Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "Can't get outputScript from withdrawal document",
+                ))
+            })?;
+
+        let amount = document
+            .properties()
+            .get_integer(withdrawal::properties::AMOUNT)
+            .map_err(|_| {
+                Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "Can't get amount from withdrawal document",
+                ))
+            })?;
+
+        let core_fee_per_byte: u32 = document
+            .properties()
+            .get_integer(withdrawal::properties::CORE_FEE_PER_BYTE)
+            .map_err(|_| {
+                Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "Can't get coreFeePerByte from withdrawal document",
+                ))
+            })?;
+
+        let pooling = document
+            .properties()
+            .get_integer::<u8>(withdrawal::properties::POOLING)
+            .ok()
+            .and_then(|pooling| Pooling::try_from(pooling).ok())
+            .ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "Can't get pooling from withdrawal document",
+            )))?;
+
+        let created_at = document.created_at().ok_or(Error::Execution(
+            ExecutionError::CorruptedCodeExecution("Can't get createdAt from withdrawal document"),
+        ))?;
+
+        Ok(QueuedWithdrawal {
+            id: document.id(),
+            amount,
+            core_fee_per_byte,
+            output_script_bytes,
+            pooling,
+            created_at,
+        })
+    }
+}
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Build list of Core transactions from withdrawal documents
+    ///
+    /// Withdrawals which allow pooling and share the same output script and fee per byte are
+    /// pooled into transactions with a single output paying their total amount. A pooled
+    /// transaction has the size of a transaction with one withdrawal and pays a single
+    /// `ASSET_UNLOCK_TX_SIZE * core_fee_per_byte` fee, so the credit pool pays one fee for all
+    /// its withdrawals instead of one fee each. Withdrawal amounts are not reduced by a share
+    /// of the fee.
+    ///
+    /// A withdrawal with `Standard` pooling that can't be pooled with others is left out until
+    /// it has been queued for `STANDARD_POOLING_MAX_WAIT_TIME_MS`.
+    ///
+    /// Returns the transactions, ordered by their oldest withdrawal, along with the index of the
+    /// transaction each pooled withdrawal document is tied to.
+    pub(super) fn build_untied_withdrawal_transactions_from_documents_v1(
+        &self,
+        documents: &[Document],
+        start_index: WithdrawalTransactionIndex,
+        block_info: &BlockInfo,
+    ) -> Result<
+        (
+            Vec<WithdrawalTransactionIndexAndBytes>,
+            HashMap<Identifier, WithdrawalTransactionIndex>,
+        ),
+        Error,
+    > {
+        let withdrawals = documents
+            .iter()
+            .map(QueuedWithdrawal::try_from)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut pools: HashMap<(&[u8], u32), Vec<&QueuedWithdrawal>> = HashMap::new();
+
+        for withdrawal in withdrawals
+            .iter()
+            .filter(|withdrawal| withdrawal.pooling != Pooling::Never)
+        {
+            pools
+                .entry((
+                    withdrawal.output_script_bytes.as_slice(),
+                    withdrawal.core_fee_per_byte,
+                ))
+                .or_default()
+                .push(withdrawal);
+        }
+
+        let mut transactions = Vec::new();
+        let mut transaction_indices = HashMap::new();
+        let mut transaction_index = start_index;
+
+        for withdrawal in withdrawals.iter() {
+            let transactions_withdrawals: Vec<Vec<&QueuedWithdrawal>> =
+                if withdrawal.pooling == Pooling::Never {
+                    vec![vec![withdrawal]]
+                } else if let Some(pool) = pools.remove(&(
+                    withdrawal.output_script_bytes.as_slice(),
+                    withdrawal.core_fee_per_byte,
+                )) {
+                    pool.chunks(MAX_WITHDRAWALS_PER_POOLED_TRANSACTION)
+                        .map(<[_]>::to_vec)
+                        .collect()
+                } else {
+                    // Already pooled with an older withdrawal
+                    continue;
+                };
+
+            for transaction_withdrawals in transactions_withdrawals {
+                if let [withdrawal] = transaction_withdrawals.as_slice() {
+                    if withdrawal.pooling == Pooling::Standard
+                        && block_info.time_ms.saturating_sub(withdrawal.created_at)
+                            < STANDARD_POOLING_MAX_WAIT_TIME_MS
+                    {
+                        continue;
+                    }
+                }
+
+                let transaction_bytes = build_untied_withdrawal_transaction(
+                    &transaction_withdrawals,
+                    transaction_index,
+                )?;
+
+                for withdrawal in transaction_withdrawals {
+                    transaction_indices.insert(withdrawal.id, transaction_index);
+                }
+
+                transactions.push((transaction_index, transaction_bytes));
+
+                transaction_index += 1;
+            }
+        }
+
+        Ok((transactions, transaction_indices))
+    }
+}
+
+fn build_untied_withdrawal_transaction(
+    withdrawals: &[&QueuedWithdrawal],
+    transaction_index: WithdrawalTransactionIndex,
+) -> Result<Vec<u8>, Error> {
+    let Some(first_withdrawal) = withdrawals.first() else {
+        return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+            "withdrawal transaction must pay at least one withdrawal",
+        )));
+    };
+
+    let amount = withdrawals
+        .iter()
+        .try_fold(0 as Credits, |amount, withdrawal| {
+            amount.checked_add(withdrawal.amount)
+        })
+        .ok_or(Error::Execution(ExecutionError::Overflow(
+            "pooled withdrawals amount overflow",
+        )))?;
+
+    let output_script = ScriptBuf::from_bytes(first_withdrawal.output_script_bytes.clone());
+
+    let tx_out = TxOut {
+        value: convert_credits_to_duffs(amount)?,
+        script_pubkey: output_script,
+    };
+
+    let withdrawal_transaction = AssetUnlockBaseTransactionInfo {
+        version: 1,
+        lock_time: 0,
+        output: vec![tx_out],
+        base_payload: AssetUnlockBasePayload {
+            version: 1,
+            index: transaction_index,
+            fee: ASSET_UNLOCK_TX_SIZE as u32 * first_withdrawal.core_fee_per_byte,
+        },
+    };
+
+    let mut transaction_buffer: Vec<u8> = vec![];
+
+    withdrawal_transaction
+        .consensus_encode(&mut transaction_buffer)
+        .map_err(|_| {
+            Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "Can't consensus encode a withdrawal transaction",
+            ))
+        })?;
+
+    Ok(transaction_buffer)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use dpp::withdrawal::Pooling;
+    use dpp::{
+        data_contracts::withdrawals_contract, tests::fixtures::get_withdrawal_document_fixture,
+    };
+    use drive::tests::helpers::setup::setup_document;
+
+    mod build_withdrawal_transactions_from_documents {
+        use dpp::block::block_info::BlockInfo;
+        use dpp::data_contract::accessors::v0::DataContractV0Getters;
+        use dpp::data_contracts::withdrawals_contract::v1::document_types::withdrawal;
+        use dpp::document::{DocumentV0Getters, DocumentV0Setters};
+        use dpp::identity::core_script::CoreScript;
+        use dpp::platform_value::platform_value;
+        use dpp::prelude::Identifier;
+        use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+        use dpp::version::PlatformVersion;
+        use drive::drive::identity::withdrawals::WithdrawalTransactionIndexAndBytes;
+        use drive::tests::helpers::setup::setup_system_data_contract;
+        use itertools::Itertools;
+
+        use crate::test::helpers::setup::TestPlatformBuilder;
+
+        use super::super::{
+            MAX_WITHDRAWALS_PER_POOLED_TRANSACTION, STANDARD_POOLING_MAX_WAIT_TIME_MS,
+        };
+        use super::*;
+
+        #[test]
+        fn test_build() {
+            let platform_version = PlatformVersion::latest();
+            let platform = TestPlatformBuilder::new()
+                .build_with_mock_rpc()
+                .set_initial_state_structure();
+
+            let transaction = platform.drive.grove.start_transaction();
+
+            let data_contract =
+                load_system_data_contract(SystemDataContract::Withdrawals, platform_version)
+                    .expect("to load system data contract");
+
+            setup_system_data_contract(&platform.drive, &data_contract, Some(&transaction));
+
+            let owner_id = Identifier::new([1u8; 32]);
+
+            let document_1 = get_withdrawal_document_fixture(
+                &data_contract,
+                owner_id,
+                platform_value!({
+                    "amount": 1000u64,
+                    "coreFeePerByte": 1u32,
+                    "pooling": Pooling::Never as u8,
+                    "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                    "status": withdrawals_contract::WithdrawalStatus::POOLED as u8,
+                    "transactionIndex": 1u64,
+                }),
+                None,
+                platform_version.protocol_version,
+            )
+            .expect("expected withdrawal document");
+
+            let document_type = data_contract
+                .document_type_for_name(withdrawal::NAME)
+                .expect("expected to get document type");
+
+            setup_document(
+                &platform.drive,
+                &document_1,
+                &data_contract,
+                document_type,
+                Some(&transaction),
+            );
+
+            let document_2 = get_withdrawal_document_fixture(
+                &data_contract,
+                owner_id,
+                platform_value!({
+                    "amount": 1000u64,
+                    "coreFeePerByte": 1u32,
+                    "pooling": Pooling::Never as u8,
+                    "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                    "status": withdrawals_contract::WithdrawalStatus::POOLED as u8,
+                    "transactionIndex": 2u64,
+                }),
+                None,
+                platform_version.protocol_version,
+            )
+            .expect("expected withdrawal document");
+
+            setup_document(
+                &platform.drive,
+                &document_2,
+                &data_contract,
+                document_type,
+                Some(&transaction),
+            );
+
+            let documents = vec![document_1, document_2];
+
+            let (transactions, transaction_indices) = platform
+                .build_untied_withdrawal_transactions_from_documents_v1(
+                    &documents,
+                    50,
+                    &BlockInfo::default(),
+                )
+                .expect("to build transactions from documents");
+
+            assert_eq!(transaction_indices.len(), 2);
+
+            assert_eq!(
+                transactions
+                    .into_iter()
+                    .sorted()
+                    .collect::<Vec<WithdrawalTransactionIndexAndBytes>>(),
+                vec![
+                    (
+                        50,
+                        vec![
+                            1, 0, 9, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 23, 0, 1, 2, 3, 4, 5, 6, 7,
+                            8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 0, 0, 0, 0,
+                            1, 50, 0, 0, 0, 0, 0, 0, 0, 190, 0, 0, 0
+                        ],
+                    ),
+                    (
+                        51,
+                        vec![
+                            1, 0, 9, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 23, 0, 1, 2, 3, 4, 5, 6, 7,
+                            8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 0, 0, 0, 0,
+                            1, 51, 0, 0, 0, 0, 0, 0, 0, 190, 0, 0, 0
+                        ],
+                    ),
+                ]
+                .into_iter()
+                .sorted()
+                .collect::<Vec<WithdrawalTransactionIndexAndBytes>>(),
+            );
+        }
+
+        #[test]
+        fn test_build_pooled() {
+            let platform_version = PlatformVersion::latest();
+            let platform = TestPlatformBuilder::new()
+                .build_with_mock_rpc()
+                .set_initial_state_structure();
+
+            let data_contract =
+                load_system_data_contract(SystemDataContract::Withdrawals, platform_version)
+                    .expect("to load system data contract");
+
+            let owner_id = Identifier::new([1u8; 32]);
+
+            let withdrawal_document = |pooling: Pooling, output_script: Vec<u8>| {
+                let mut document = get_withdrawal_document_fixture(
+                    &data_contract,
+                    owner_id,
+                    platform_value!({
+                        "amount": 1000u64,
+                        "coreFeePerByte": 1u32,
+                        "pooling": pooling as u8,
+                        "outputScript": CoreScript::from_bytes(output_script),
+                        "status": withdrawals_contract::WithdrawalStatus::QUEUED as u8,
+                    }),
+                    None,
+                    platform_version.protocol_version,
+                )
+                .expect("expected withdrawal document");
+
+                document.set_created_at(Some(1));
+
+                document
+            };
+
+            let shared_output_script = (0..23).collect::<Vec<u8>>();
+
+            let pooled_document_1 =
+                withdrawal_document(Pooling::IfAvailable, shared_output_script.clone());
+            let never_pooled_document =
+                withdrawal_document(Pooling::Never, shared_output_script.clone());
+            let pooled_document_2 =
+                withdrawal_document(Pooling::Standard, shared_output_script.clone());
+            let waiting_document = withdrawal_document(Pooling::Standard, (1..24).collect());
+
+            let documents = vec![
+                pooled_document_1.clone(),
+                never_pooled_document.clone(),
+                pooled_document_2.clone(),
+                waiting_document.clone(),
+            ];
+
+            let block_info = BlockInfo {
+                time_ms: 1000,
+                ..Default::default()
+            };
+
+            let (transactions, transaction_indices) = platform
+                .build_untied_withdrawal_transactions_from_documents_v1(&documents, 50, &block_info)
+                .expect("to build transactions from documents");
+
+            // The pooled withdrawals share a transaction paying both amounts in a single output
+            assert_eq!(
+                transactions,
+                vec![
+                    (
+                        50,
+                        vec![
+                            1, 0, 9, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 23, 0, 1, 2, 3, 4, 5, 6, 7,
+                            8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 0, 0, 0, 0,
+                            1, 50, 0, 0, 0, 0, 0, 0, 0, 190, 0, 0, 0
+                        ],
+                    ),
+                    (
+                        51,
+                        vec![
+                            1, 0, 9, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 23, 0, 1, 2, 3, 4, 5, 6, 7,
+                            8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 0, 0, 0, 0,
+                            1, 51, 0, 0, 0, 0, 0, 0, 0, 190, 0, 0, 0
+                        ],
+                    ),
+                ]
+            );
+
+            assert_eq!(transaction_indices.get(&pooled_document_1.id()), Some(&50));
+            assert_eq!(transaction_indices.get(&pooled_document_2.id()), Some(&50));
+            assert_eq!(
+                transaction_indices.get(&never_pooled_document.id()),
+                Some(&51)
+            );
+
+            // The standard pooled withdrawal without others to be pooled with keeps waiting
+            assert_eq!(transaction_indices.get(&waiting_document.id()), None);
+
+            let block_info = BlockInfo {
+                time_ms: STANDARD_POOLING_MAX_WAIT_TIME_MS + 1,
+                ..Default::default()
+            };
+
+            let (transactions, transaction_indices) = platform
+                .build_untied_withdrawal_transactions_from_documents_v1(
+                    &[waiting_document.clone()],
+                    52,
+                    &block_info,
+                )
+                .expect("to build transactions from documents");
+
+            assert_eq!(transactions.len(), 1);
+            assert_eq!(transaction_indices.get(&waiting_document.id()), Some(&52));
+        }
+
+        #[test]
+        fn test_build_pooled_limits() {
+            let platform_version = PlatformVersion::latest();
+            let platform = TestPlatformBuilder::new()
+                .build_with_mock_rpc()
+                .set_initial_state_structure();
+
+            let data_contract =
+                load_system_data_contract(SystemDataContract::Withdrawals, platform_version)
+                    .expect("to load system data contract");
+
+            let owner_id = Identifier::new([1u8; 32]);
+
+            let output_script = (0..23).collect::<Vec<u8>>();
+
+            let withdrawal_document = |core_fee_per_byte: u32| {
+                let mut document = get_withdrawal_document_fixture(
+                    &data_contract,
+                    owner_id,
+                    platform_value!({
+                        "amount": 1000u64,
+                        "coreFeePerByte": core_fee_per_byte,
+                        "pooling": Pooling::IfAvailable as u8,
+                        "outputScript": CoreScript::from_bytes(output_script.clone()),
+                        "status": withdrawals_contract::WithdrawalStatus::QUEUED as u8,
+                    }),
+                    None,
+                    platform_version.protocol_version,
+                )
+                .expect("expected withdrawal document");
+
+                document.set_created_at(Some(1));
+
+                document
+            };
+
+            let pooled_documents = (0..MAX_WITHDRAWALS_PER_POOLED_TRANSACTION + 1)
+                .map(|_| withdrawal_document(1))
+                .collect::<Vec<_>>();
+
+            let other_fee_document = withdrawal_document(2);
+
+            let documents = pooled_documents
+                .iter()
+                .cloned()
+                .chain([other_fee_document.clone()])
+                .collect::<Vec<_>>();
+
+            let (transactions, transaction_indices) = platform
+                .build_untied_withdrawal_transactions_from_documents_v1(
+                    &documents,
+                    50,
+                    &BlockInfo::default(),
+                )
+                .expect("to build transactions from documents");
+
+            // A full pool is followed by a transaction with the remaining withdrawal, then
+            // withdrawals with another fee per byte are never pooled with them
+            assert_eq!(
+                transactions
+                    .iter()
+                    .map(|(index, _)| *index)
+                    .collect::<Vec<_>>(),
+                vec![50, 51, 52]
+            );
+
+            for document in &pooled_documents[..MAX_WITHDRAWALS_PER_POOLED_TRANSACTION] {
+                assert_eq!(transaction_indices.get(&document.id()), Some(&50));
+            }
+
+            assert_eq!(
+                transaction_indices
+                    .get(&pooled_documents[MAX_WITHDRAWALS_PER_POOLED_TRANSACTION].id()),
+                Some(&51)
+            );
+
+            assert_eq!(transaction_indices.get(&other_fee_document.id()), Some(&52));
+        }
+    }
+}
@@ -5,7 +5,6 @@ use dpp::document::document_methods::DocumentMethodsV0;
 use dpp::document::{DocumentV0Getters, DocumentV0Setters};
 use dpp::version::PlatformVersion;
 
-use drive::drive::identity::withdrawals::WithdrawalTransactionIndexAndBytes;
 use drive::grovedb::TransactionArg;
 
 use dpp::system_data_contracts::withdrawals_contract;
@@ -44,16 +43,16 @@ where
             .drive
             .fetch_next_withdrawal_transaction_index(transaction, platform_version)?;
 
-        let untied_withdrawal_transactions = self
+        let (withdrawal_transactions, transaction_indices) = self
             .build_untied_withdrawal_transactions_from_documents(
                 &documents,
                 start_transaction_index,
+                block_info,
                 platform_version,
             )?;
 
         for document in documents.iter_mut() {
-            let Some((transaction_index, _)) = untied_withdrawal_transactions.get(&document.id())
-            else {
+            let Some(transaction_index) = transaction_indices.get(&document.id()) else {
                 return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
                     "transactions must contain a transaction",
                 )));
@@ -78,9 +77,6 @@ where
             })?;
         }
 
-        let withdrawal_transactions: Vec<WithdrawalTransactionIndexAndBytes> =
-            untied_withdrawal_transactions.into_values().collect();
-
         let withdrawal_transactions_count = withdrawal_transactions.len();
 
         let mut drive_operations = Vec::new();
@@ -158,7 +154,7 @@ mod tests {
 
     #[test]
     fn test_pooling() {
-        let platform_version = PlatformVersion::latest();
+        let platform_version = PlatformVersion::first();
         let platform = TestPlatformBuilder::new()
             .build_with_mock_rpc()
             .set_initial_state_structure();
@@ -0,0 +1,61 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::document::Document;
+use dpp::fee::fee_result::FeeResult;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+mod v0;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Refund expired withdrawals. This function is a version handler that
+    /// directs to specific version implementations of the `refund_expired_withdrawals` function.
+    ///
+    /// The withdrawn credits are added back to the balance of the identity that requested the withdrawal.
+    /// The identity pays the fees of the refund out of the refunded credits.
+    ///
+    /// # Arguments
+    ///
+    /// * `expired_withdrawal_documents` - Withdrawal documents which transactions were never included by Core.
+    /// * `block_info` - Information about the current block.
+    /// * `transaction` - A `Transaction` reference representing the current transaction.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FeeResult, Error>` - Returns the fees paid by identities for their refunds if successful,
+    ///   otherwise returns an `Error`.
+    pub(in crate::execution) fn refund_expired_withdrawals(
+        &self,
+        expired_withdrawal_documents: &[Document],
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .withdrawals
+            .refund_expired_withdrawals
+        {
+            0 => self.refund_expired_withdrawals_v0(
+                expired_withdrawal_documents,
+                block_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "refund_expired_withdrawals".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
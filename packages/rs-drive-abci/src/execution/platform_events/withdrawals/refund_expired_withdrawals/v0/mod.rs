@@ -0,0 +1,168 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::fee::fee_result::FeeResult;
+use dpp::fee::Credits;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::system_data_contracts::withdrawals_contract::v1::document_types::withdrawal;
+use dpp::version::PlatformVersion;
+use drive::drive::batch::DriveOperation::{IdentityOperation, SystemOperation};
+use drive::drive::batch::{IdentityOperationType, SystemOperationType};
+use drive::drive::identity::update::apply_balance_change_outcome::ApplyBalanceChangeOutcomeV0Methods;
+use drive::grovedb::Transaction;
+
+use crate::{
+    error::{execution::ExecutionError, Error},
+    platform_types::platform::Platform,
+    rpc::core::CoreRPCLike,
+};
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Refund expired withdrawals to their identities
+    pub(super) fn refund_expired_withdrawals_v0(
+        &self,
+        expired_withdrawal_documents: &[Document],
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let mut refund_fees = FeeResult::default();
+
+        for document in expired_withdrawal_documents {
+            let identity_id = document.owner_id();
+
+            let amount: Credits = document
+                .properties()
+                .get_integer(withdrawal::properties::AMOUNT)
+                .map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedDriveResponse(
+                        "Can't get amount from withdrawal document".to_string(),
+                    ))
+                })?;
+
+            tracing::debug!(
+                "Refunding {} credits of expired withdrawal {} to identity {}",
+                amount,
+                document.id(),
+                identity_id
+            );
+
+            let drive_operations = vec![
+                IdentityOperation(IdentityOperationType::AddToIdentityBalance {
+                    identity_id: identity_id.to_buffer(),
+                    added_balance: amount,
+                }),
+                // Withdrawn credits were removed from the system when the withdrawal was requested
+                SystemOperation(SystemOperationType::AddToSystemCredits { amount }),
+            ];
+
+            let fee_result = self.drive.apply_drive_operations(
+                drive_operations,
+                true,
+                block_info,
+                Some(transaction),
+                platform_version,
+            )?;
+
+            // Fees are paid by the identity out of the refunded credits
+            let outcome = self.drive.apply_balance_change_from_fee_to_identity(
+                fee_result.into_balance_change(identity_id),
+                Some(transaction),
+                platform_version,
+            )?;
+
+            refund_fees
+                .checked_add_assign(outcome.actual_fee_paid_owned())
+                .map_err(Error::Protocol)?;
+        }
+
+        Ok(refund_fees)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::data_contracts::withdrawals_contract::WithdrawalStatus;
+    use dpp::identity::core_script::CoreScript;
+    use dpp::identity::Identity;
+    use dpp::platform_value::platform_value;
+    use dpp::prelude::Identifier;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use dpp::tests::fixtures::get_withdrawal_document_fixture;
+    use dpp::withdrawal::Pooling;
+
+    #[test]
+    fn test_expired_withdrawals_are_refunded_minus_fees() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let block_info = BlockInfo {
+            time_ms: 0,
+            height: 1,
+            core_height: 96,
+            epoch: Default::default(),
+        };
+
+        let owner_id = Identifier::new([1u8; 32]);
+
+        let identity = Identity::create_basic_identity(owner_id.to_buffer(), platform_version)
+            .expect("expected to create an identity");
+
+        platform
+            .drive
+            .add_new_identity(
+                identity,
+                false,
+                &block_info,
+                true,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("expected to add an identity");
+
+        let data_contract =
+            load_system_data_contract(SystemDataContract::Withdrawals, platform_version)
+                .expect("to load system data contract");
+
+        let amount: Credits = 1_000_000_000;
+
+        let document = get_withdrawal_document_fixture(
+            &data_contract,
+            owner_id,
+            platform_value!({
+                "amount": amount,
+                "coreFeePerByte": 1u32,
+                "pooling": Pooling::Never as u8,
+                "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                "status": WithdrawalStatus::EXPIRED as u8,
+                "transactionIndex": 1u64,
+                "transactionSignHeight": 1,
+            }),
+            None,
+            platform_version.protocol_version,
+        )
+        .expect("expected withdrawal document");
+
+        let refund_fees = platform
+            .refund_expired_withdrawals_v0(&[document], &block_info, &transaction, platform_version)
+            .expect("expected to refund expired withdrawals");
+
+        assert!(refund_fees.processing_fee > 0);
+
+        let balance = platform
+            .drive
+            .fetch_identity_balance(owner_id.to_buffer(), Some(&transaction), platform_version)
+            .expect("expected to fetch balance")
+            .expect("expected a balance");
+
+        assert_eq!(balance, amount - refund_fees.total_base_fee());
+    }
+}
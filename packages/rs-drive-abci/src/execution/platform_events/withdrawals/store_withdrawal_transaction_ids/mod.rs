@@ -0,0 +1,69 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::withdrawal::unsigned_withdrawal_txs::v0::UnsignedWithdrawalTxs;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+use tenderdash_abci::proto::types::VoteExtension;
+
+mod v0;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Stores the Core transaction ids of the withdrawal transactions built in the previous
+    /// block, and keeps the unsigned withdrawal transactions of this block until their ids
+    /// can be stored in the next one.
+    ///
+    /// The id of a withdrawal transaction depends on its quorum signature, which is only
+    /// known once the block building it is committed, it is then computed from the threshold
+    /// vote extensions of the last commit. This function is a version handler that directs to
+    /// specific version implementations of the store_withdrawal_transaction_ids function.
+    /// Transaction ids are only stored from protocol version 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `last_commit_threshold_vote_extensions` - The threshold vote extensions of the last commit.
+    /// * `unsigned_withdrawal_transactions` - The withdrawal transactions built in this block.
+    /// * `block_info` - Information about the current block.
+    /// * `transaction` - A `Transaction` reference.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - If the operation is successful, it returns `Ok(())`. If there is a
+    ///   problem with the operation, it returns an `Error`.
+    pub(in crate::execution) fn store_withdrawal_transaction_ids(
+        &self,
+        last_commit_threshold_vote_extensions: &[VoteExtension],
+        unsigned_withdrawal_transactions: &UnsignedWithdrawalTxs,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .withdrawals
+            .store_withdrawal_transaction_ids
+        {
+            None => Ok(()),
+            Some(0) => self.store_withdrawal_transaction_ids_v0(
+                last_commit_threshold_vote_extensions,
+                unsigned_withdrawal_transactions,
+                block_info,
+                transaction,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "store_withdrawal_transaction_ids".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use crate::abci::AbciError;
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::withdrawal::unsigned_withdrawal_txs::v0::UnsignedWithdrawalTxs;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::dashcore::bls_sig_utils::BLSSignature;
+use dpp::dashcore::hashes::Hash;
+use dpp::dashcore::transaction::special_transaction::TransactionPayload::AssetUnlockPayloadType;
+use dpp::dashcore::{consensus, Transaction};
+use dpp::version::PlatformVersion;
+use drive::drive::batch::DriveOperation;
+use drive::drive::identity::withdrawals::{
+    WithdrawalTransactionIndexAndBytes, WithdrawalTransactionIndexAndId,
+};
+use drive::grovedb::TransactionArg;
+use tenderdash_abci::proto::types::VoteExtension;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn store_withdrawal_transaction_ids_v0(
+        &self,
+        last_commit_threshold_vote_extensions: &[VoteExtension],
+        unsigned_withdrawal_transactions: &UnsignedWithdrawalTxs,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let mut drive_operations: Vec<DriveOperation> = vec![];
+
+        let previous_unsigned_withdrawal_transactions = self
+            .drive
+            .dequeue_unsigned_withdrawal_transactions(
+                transaction,
+                &mut drive_operations,
+                platform_version,
+            )?
+            .into_iter()
+            .map(|(_, transaction_bytes)| {
+                consensus::deserialize::<Transaction>(&transaction_bytes).map_err(|e| {
+                    Error::Execution(ExecutionError::CorruptedDriveResponse(format!(
+                        "unsigned withdrawal transaction can not be deserialized: {}",
+                        e
+                    )))
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()
+            .map(UnsignedWithdrawalTxs::from_vec)?;
+
+        // Threshold signatures are unique, so the signatures of the last commit are the ones
+        // appended to the transactions broadcasted when the previous block was finalized
+        let transaction_ids = if previous_unsigned_withdrawal_transactions.is_empty() {
+            vec![]
+        } else if previous_unsigned_withdrawal_transactions
+            .are_matching_with_vote_extensions(last_commit_threshold_vote_extensions)
+        {
+            previous_unsigned_withdrawal_transactions
+                .into_iter()
+                .zip(last_commit_threshold_vote_extensions)
+                .map(|(transaction, vote_extension)| {
+                    signed_transaction_index_and_id(transaction, vote_extension)
+                })
+                .collect::<Result<Vec<_>, Error>>()?
+        } else {
+            tracing::warn!(
+                "Withdrawal transactions of the previous block don't match the vote extensions of the last commit, their transaction ids are not stored",
+            );
+
+            vec![]
+        };
+
+        self.drive
+            .add_insert_withdrawal_transaction_ids_operations(
+                transaction_ids,
+                &mut drive_operations,
+                platform_version,
+            )?;
+
+        let unsigned_withdrawal_transactions = unsigned_withdrawal_transactions
+            .iter()
+            .map(|transaction| {
+                let Some(AssetUnlockPayloadType(payload)) =
+                    &transaction.special_transaction_payload
+                else {
+                    return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "withdrawal transaction payload must be AssetUnlockPayloadType",
+                    )));
+                };
+
+                Ok((payload.base.index, consensus::serialize(transaction)))
+            })
+            .collect::<Result<Vec<WithdrawalTransactionIndexAndBytes>, Error>>()?;
+
+        self.drive
+            .add_enqueue_unsigned_withdrawal_transaction_operations(
+                unsigned_withdrawal_transactions,
+                &mut drive_operations,
+                platform_version,
+            )?;
+
+        self.drive.apply_drive_operations(
+            drive_operations,
+            true,
+            block_info,
+            transaction,
+            platform_version,
+        )?;
+
+        Ok(())
+    }
+}
+
+fn signed_transaction_index_and_id(
+    mut transaction: Transaction,
+    vote_extension: &VoteExtension,
+) -> Result<WithdrawalTransactionIndexAndId, Error> {
+    let signature_bytes: [u8; 96] = vote_extension.signature.clone().try_into().map_err(|e| {
+        AbciError::BadRequestDataSize(format!(
+            "invalid vote extension signature size: {}",
+            hex::encode(e)
+        ))
+    })?;
+
+    let Some(AssetUnlockPayloadType(mut payload)) = transaction.special_transaction_payload else {
+        return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+            "withdrawal transaction payload must be AssetUnlockPayloadType",
+        )));
+    };
+
+    payload.quorum_sig = BLSSignature::from(signature_bytes);
+
+    let index = payload.base.index;
+
+    transaction.special_transaction_payload = Some(AssetUnlockPayloadType(payload));
+
+    Ok((index, transaction.txid().to_byte_array()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform_types::withdrawal::unsigned_withdrawal_txs::v0::make_extend_vote_request_id;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dashcore_rpc::dashcore::blockdata::transaction::special_transaction::asset_unlock::request_info::AssetUnlockRequestInfo;
+    use dashcore_rpc::dashcore::blockdata::transaction::special_transaction::asset_unlock::unqualified_asset_unlock::{
+        AssetUnlockBasePayload, AssetUnlockBaseTransactionInfo,
+    };
+    use dashcore_rpc::dashcore::consensus::Encodable;
+    use dashcore_rpc::dashcore::{QuorumHash, ScriptBuf, TxOut};
+    use dpp::dashcore::transaction::special_transaction::asset_unlock::qualified_asset_unlock::build_asset_unlock_tx;
+    use dpp::dashcore::Txid;
+    use std::collections::BTreeMap;
+    use tenderdash_abci::proto::types::VoteExtensionType;
+
+    fn unsigned_withdrawal_transaction(index: u64) -> Transaction {
+        let untied_transaction = AssetUnlockBaseTransactionInfo {
+            version: 1,
+            lock_time: 0,
+            output: vec![TxOut {
+                value: 1000,
+                script_pubkey: ScriptBuf::from_bytes(vec![index as u8; 23]),
+            }],
+            base_payload: AssetUnlockBasePayload {
+                version: 1,
+                index,
+                fee: 1,
+            },
+        };
+
+        let mut untied_transaction_bytes = vec![];
+        untied_transaction
+            .consensus_encode(&mut untied_transaction_bytes)
+            .expect("expected to encode the untied transaction");
+
+        let request_info = AssetUnlockRequestInfo {
+            request_height: 1,
+            quorum_hash: QuorumHash::from_byte_array([1; 32]),
+        };
+
+        let mut unsigned_transaction_bytes = vec![];
+        request_info
+            .consensus_append_to_base_encode(
+                untied_transaction_bytes,
+                &mut unsigned_transaction_bytes,
+            )
+            .expect("expected to append the request info");
+
+        build_asset_unlock_tx(&unsigned_transaction_bytes)
+            .expect("expected to build the unsigned transaction")
+    }
+
+    fn vote_extension(transaction: &Transaction, signature: [u8; 96]) -> VoteExtension {
+        VoteExtension {
+            r#type: VoteExtensionType::ThresholdRecoverRaw as i32,
+            extension: transaction.txid().to_byte_array().to_vec(),
+            sign_request_id: Some(make_extend_vote_request_id(transaction)),
+            signature: signature.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_transaction_ids_are_stored_once_transactions_are_signed() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let block_info = BlockInfo::default();
+
+        let unsigned_transactions = vec![
+            unsigned_withdrawal_transaction(0),
+            unsigned_withdrawal_transaction(1),
+        ];
+
+        // The transactions are built in a first block
+        platform
+            .store_withdrawal_transaction_ids_v0(
+                &[],
+                &UnsignedWithdrawalTxs::from_vec(unsigned_transactions.clone()),
+                &block_info,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("expected to store unsigned transactions");
+
+        let transaction_ids = platform
+            .drive
+            .fetch_withdrawal_transaction_ids(&[0, 1], Some(&transaction), platform_version)
+            .expect("expected to fetch transaction ids");
+
+        assert!(transaction_ids.is_empty());
+
+        // Their signatures are in the commit of the first block
+        let vote_extensions = unsigned_transactions
+            .iter()
+            .zip([[1; 96], [2; 96]])
+            .map(|(transaction, signature)| vote_extension(transaction, signature))
+            .collect::<Vec<_>>();
+
+        platform
+            .store_withdrawal_transaction_ids_v0(
+                &vote_extensions,
+                &UnsignedWithdrawalTxs::default(),
+                &block_info,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("expected to store transaction ids");
+
+        let expected_transaction_ids = unsigned_transactions
+            .into_iter()
+            .zip(vote_extensions.iter())
+            .map(|(transaction, vote_extension)| {
+                signed_transaction_index_and_id(transaction, vote_extension)
+                    .expect("expected to sign the transaction")
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let transaction_ids = platform
+            .drive
+            .fetch_withdrawal_transaction_ids(&[0, 1], Some(&transaction), platform_version)
+            .expect("expected to fetch transaction ids");
+
+        assert_eq!(transaction_ids, expected_transaction_ids);
+
+        // The ids are the ids of the signed transactions, not of the unsigned ones
+        assert_ne!(
+            Txid::from_byte_array(transaction_ids[&0]),
+            unsigned_withdrawal_transaction(0).txid()
+        );
+
+        let mut drive_operations = vec![];
+
+        let unsigned_transactions = platform
+            .drive
+            .dequeue_unsigned_withdrawal_transactions(
+                Some(&transaction),
+                &mut drive_operations,
+                platform_version,
+            )
+            .expect("expected to dequeue unsigned transactions");
+
+        assert!(unsigned_transactions.is_empty());
+    }
+}
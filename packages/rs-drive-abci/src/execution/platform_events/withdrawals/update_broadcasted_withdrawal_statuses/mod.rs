@@ -3,10 +3,12 @@ use crate::error::Error;
 use crate::platform_types::platform::Platform;
 use crate::rpc::core::CoreRPCLike;
 use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
 use dpp::version::PlatformVersion;
 use drive::grovedb::Transaction;
 
 mod v0;
+mod v1;
 
 impl<C> Platform<C>
 where
@@ -16,7 +18,8 @@ where
     ///
     /// This method is responsible for updating the status of withdrawal transactions that have been broadcasted and reached finality.
     /// This is done based on the height of the last synced core block, which helps in determining whether the withdrawal
-    /// transaction has been completed or expired.
+    /// transaction has been completed or expired. From protocol version 2, credits of expired withdrawals
+    /// are refunded to their identities.
     ///
     /// # Arguments
     ///
@@ -25,8 +28,9 @@ where
     ///
     /// # Returns
     ///
-    /// * `Result<(), Error>` - Returns an Ok(()) if the statuses are successfully updated.
-    ///                          Otherwise, it returns an Error.
+    /// * `Result<FeeResult, Error>` - Returns the fees paid by identities for the refunds of expired withdrawals,
+    ///                                empty before protocol version 2, if the statuses are successfully updated.
+    ///                                Otherwise, it returns an Error.
     ///
     /// # Errors
     ///
@@ -40,21 +44,30 @@ where
         block_info: &BlockInfo,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
-    ) -> Result<(), Error> {
+    ) -> Result<FeeResult, Error> {
         match platform_version
             .drive_abci
             .methods
             .withdrawals
             .update_broadcasted_withdrawal_statuses
         {
-            0 => self.update_broadcasted_withdrawal_statuses_v0(
+            0 => {
+                self.update_broadcasted_withdrawal_statuses_v0(
+                    block_info,
+                    transaction,
+                    platform_version,
+                )?;
+
+                Ok(FeeResult::default())
+            }
+            1 => self.update_broadcasted_withdrawal_statuses_v1(
                 block_info,
                 transaction,
                 platform_version,
             ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "update_broadcasted_withdrawal_statuses".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
@@ -7,6 +7,7 @@ use crate::execution::types::block_execution_context::v0::{
     BlockExecutionContextV0OwnedGetters, BlockExecutionContextV0Setters,
 };
 use crate::execution::types::block_state_info::BlockStateInfo;
+use crate::platform_types::document_changes::DocumentChange;
 use crate::platform_types::epoch_info::EpochInfo;
 use crate::platform_types::platform_state::PlatformState;
 use crate::platform_types::withdrawal::unsigned_withdrawal_txs::v0::UnsignedWithdrawalTxs;
@@ -65,6 +66,12 @@ impl BlockExecutionContextV0Getters for BlockExecutionContext {
             BlockExecutionContext::V0(v0) => v0.proposer_results.as_ref(),
         }
     }
+
+    fn document_changes(&self) -> &[DocumentChange] {
+        match self {
+            BlockExecutionContext::V0(v0) => &v0.document_changes,
+        }
+    }
 }
 
 impl BlockExecutionContextV0Setters for BlockExecutionContext {
@@ -135,6 +142,12 @@ impl BlockExecutionContextV0MutableGetters for BlockExecutionContext {
             BlockExecutionContext::V0(v0) => v0.unsigned_withdrawal_transactions_mut(),
         }
     }
+
+    fn document_changes_mut(&mut self) -> &mut Vec<DocumentChange> {
+        match self {
+            BlockExecutionContext::V0(v0) => v0.document_changes_mut(),
+        }
+    }
 }
 
 impl BlockExecutionContextV0OwnedGetters for BlockExecutionContext {
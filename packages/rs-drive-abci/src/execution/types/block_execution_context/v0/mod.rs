@@ -29,6 +29,7 @@
 
 use crate::execution::types::block_state_info::BlockStateInfo;
 
+use crate::platform_types::document_changes::DocumentChange;
 use crate::platform_types::epoch_info::EpochInfo;
 use crate::platform_types::platform_state::PlatformState;
 use crate::platform_types::withdrawal::unsigned_withdrawal_txs::v0::UnsignedWithdrawalTxs;
@@ -50,6 +51,8 @@ pub struct BlockExecutionContextV0 {
     pub block_platform_state: PlatformState,
     /// The response prepare proposal if proposed by us
     pub proposer_results: Option<ResponsePrepareProposal>,
+    /// Documents changed by the state transitions of the block, published once it's committed
+    pub document_changes: Vec<DocumentChange>,
 }
 /// A trait defining getter methods for interacting with a BlockExecutionContextV0.
 pub trait BlockExecutionContextV0Getters {
@@ -70,6 +73,9 @@ pub trait BlockExecutionContextV0Getters {
 
     /// Returns a reference of the proposer_results field.
     fn proposer_results(&self) -> Option<&ResponsePrepareProposal>;
+
+    /// Returns a reference of the document_changes field.
+    fn document_changes(&self) -> &[DocumentChange];
 }
 
 /// A trait defining setter methods for interacting with a BlockExecutionContextV0.
@@ -109,6 +115,9 @@ pub trait BlockExecutionContextV0MutableGetters {
 
     /// Returns a mut reference of the withdrawal_transactions field.
     fn unsigned_withdrawal_transactions_mut(&mut self) -> &mut UnsignedWithdrawalTxs;
+
+    /// Returns a mut reference of the document_changes field.
+    fn document_changes_mut(&mut self) -> &mut Vec<DocumentChange>;
 }
 
 /// A trait defining methods for interacting with a BlockExecutionContextV0.
@@ -156,6 +165,11 @@ impl BlockExecutionContextV0Getters for BlockExecutionContextV0 {
     fn proposer_results(&self) -> Option<&ResponsePrepareProposal> {
         self.proposer_results.as_ref()
     }
+
+    /// Returns a reference to the document_changes field.
+    fn document_changes(&self) -> &[DocumentChange] {
+        &self.document_changes
+    }
 }
 
 impl BlockExecutionContextV0Setters for BlockExecutionContextV0 {
@@ -209,6 +223,10 @@ impl BlockExecutionContextV0MutableGetters for BlockExecutionContextV0 {
     fn unsigned_withdrawal_transactions_mut(&mut self) -> &mut UnsignedWithdrawalTxs {
         &mut self.unsigned_withdrawal_transactions
     }
+
+    fn document_changes_mut(&mut self) -> &mut Vec<DocumentChange> {
+        &mut self.document_changes
+    }
 }
 
 impl BlockExecutionContextV0OwnedGetters for BlockExecutionContextV0 {
@@ -3,12 +3,14 @@ mod v0;
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::execution::types::execution_event::ExecutionEvent::{
-    PaidDriveEvent, PaidFromAssetLockDriveEvent,
+    FreeDriveEvent, PaidDocumentsBatchDriveEvent, PaidDriveEvent, PaidFromAssetLockDriveEvent,
 };
 use dpp::block::epoch::Epoch;
+use dpp::document::Document;
 use dpp::fee::Credits;
 
 use dpp::identity::PartialIdentity;
+use dpp::prelude::Identifier;
 
 use dpp::version::PlatformVersion;
 use drive::state_transition_action::StateTransitionAction;
@@ -18,7 +20,9 @@ use crate::execution::types::state_transition_execution_context::{
     StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
 };
 use drive::drive::batch::transitions::DriveHighLevelOperationConverter;
-use drive::drive::batch::DriveOperation;
+use drive::drive::batch::{DocumentOperationType, DriveOperation};
+use drive::drive::object_size_info::DocumentInfoV0Methods;
+use std::collections::BTreeMap;
 
 /// An execution event
 #[derive(Clone)]
@@ -27,13 +31,24 @@ pub(in crate::execution) enum ExecutionEvent<'a> {
     PaidDriveEvent {
         /// The identity requesting the event
         identity: PartialIdentity,
-        /// The removed balance in the case of a transfer or withdrawal
+        /// The removed balance in the case of a transfer, a withdrawal or a document purchase
         removed_balance: Option<Credits>,
         /// the operations that the identity is requesting to perform
         operations: Vec<DriveOperation<'a>>,
         /// the execution operations that we must also pay for
         execution_operations: Vec<ValidationOperation>,
     },
+    /// A documents batch paid by an identity, applied and charged per data contract
+    PaidDocumentsBatchDriveEvent {
+        /// The identity requesting the event
+        identity: PartialIdentity,
+        /// The removed balance in the case of a document purchase
+        removed_balance: Option<Credits>,
+        /// the operations that the identity is requesting to perform for every data contract
+        operations_by_contract: BTreeMap<Identifier, Vec<DriveOperation<'a>>>,
+        /// the execution operations that we must also pay for
+        execution_operations: Vec<ValidationOperation>,
+    },
     /// A drive event that is paid from an asset lock
     PaidFromAssetLockDriveEvent {
         /// The identity requesting the event
@@ -123,6 +138,31 @@ impl<'a> ExecutionEvent<'a> {
                     )))
                 }
             }
+            StateTransitionAction::DocumentsBatchAction(document_batch_action) => {
+                // Purchased documents are paid for with the balance of the buyer
+                let removed_balance = document_batch_action.all_purchases_amount()?;
+                let StateTransitionAction::DocumentsBatchAction(document_batch_action) = action
+                else {
+                    return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "action should be a documents batch action",
+                    )));
+                };
+                // Every data contract written by the batch is applied and charged separately
+                let operations_by_contract = document_batch_action
+                    .into_high_level_drive_operations_by_contract(epoch, platform_version)?;
+                if let Some(identity) = identity {
+                    Ok(PaidDocumentsBatchDriveEvent {
+                        identity,
+                        removed_balance,
+                        operations_by_contract,
+                        execution_operations: execution_context.operations_consume(),
+                    })
+                } else {
+                    Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "partial identity should be present",
+                    )))
+                }
+            }
             _ => {
                 let operations =
                     action.into_high_level_drive_operations(epoch, platform_version)?;
@@ -141,4 +181,31 @@ impl<'a> ExecutionEvent<'a> {
             }
         }
     }
+
+    /// The documents added or updated by the event, as they are stored once it's executed
+    pub(in crate::execution) fn stored_documents(&self) -> impl Iterator<Item = &Document> {
+        let operations: Box<dyn Iterator<Item = &DriveOperation<'a>> + '_> = match self {
+            PaidDriveEvent { operations, .. }
+            | PaidFromAssetLockDriveEvent { operations, .. }
+            | FreeDriveEvent { operations } => Box::new(operations.iter()),
+            PaidDocumentsBatchDriveEvent {
+                operations_by_contract,
+                ..
+            } => Box::new(operations_by_contract.values().flatten()),
+        };
+
+        operations.filter_map(|operation| match operation {
+            DriveOperation::DocumentOperation(
+                DocumentOperationType::AddDocument {
+                    owned_document_info,
+                    ..
+                }
+                | DocumentOperationType::UpdateDocument {
+                    owned_document_info,
+                    ..
+                },
+            ) => owned_document_info.document_info.get_borrowed_document(),
+            _ => None,
+        })
+    }
 }
@@ -8,11 +8,25 @@ use dpp::version::PlatformVersion;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SignatureVerificationOperation {
     pub signature_type: KeyType,
+    /// The number of public keys the signature may be checked against
+    pub checked_public_keys: u16,
 }
 
 impl SignatureVerificationOperation {
     pub fn new(signature_type: KeyType) -> Self {
-        Self { signature_type }
+        Self {
+            signature_type,
+            checked_public_keys: 1,
+        }
+    }
+
+    /// A verification of a multisig signature checked against every public key of its
+    /// redeem script
+    pub fn new_multisig(checked_public_keys: u16) -> Self {
+        Self {
+            signature_type: KeyType::BIP13_SCRIPT_HASH,
+            checked_public_keys,
+        }
     }
 }
 
@@ -22,9 +36,11 @@ impl OperationLike for SignatureVerificationOperation {
         epoch: &Epoch,
         platform_version: &PlatformVersion,
     ) -> Result<Credits, Error> {
-        Ok(self
-            .signature_type
-            .signature_verify_cost(platform_version, epoch)?)
+        Ok(self.signature_type.signature_verify_cost(
+            self.checked_public_keys,
+            platform_version,
+            epoch,
+        )?)
     }
 
     fn storage_cost(&self, _platform_version: &PlatformVersion) -> Result<Credits, Error> {
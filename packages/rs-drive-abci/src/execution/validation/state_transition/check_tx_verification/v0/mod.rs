@@ -40,7 +40,13 @@ pub(super) fn state_transition_to_execution_event_for_check_tx_v0<'a, C: CoreRPC
     match check_tx_level {
         CheckTxLevel::FirstTimeCheck => {
             if state_transition.requires_check_tx_full_validation() {
-                Ok(process_state_transition(platform, state_transition, None)?.map(Some))
+                Ok(process_state_transition(
+                    platform,
+                    platform.state.last_block_info(),
+                    state_transition,
+                    None,
+                )?
+                .map(Some))
             } else {
                 // we need to validate the structure, the fees, and the signature
                 let mut state_transition_execution_context =
@@ -77,6 +83,7 @@ pub(super) fn state_transition_to_execution_event_for_check_tx_v0<'a, C: CoreRPC
                 let action = if state_transition.requires_advance_structure_validation() {
                     let state_transition_action_result = state_transition.transform_into_action(
                         platform,
+                        platform.state.last_block_info(),
                         true,
                         &mut state_transition_execution_context,
                         None,
@@ -120,6 +127,7 @@ pub(super) fn state_transition_to_execution_event_for_check_tx_v0<'a, C: CoreRPC
                         let state_transition_action_result = state_transition
                             .transform_into_action(
                                 platform,
+                                platform.state.last_block_info(),
                                 true,
                                 &mut state_transition_execution_context,
                                 None,
@@ -141,6 +149,7 @@ pub(super) fn state_transition_to_execution_event_for_check_tx_v0<'a, C: CoreRPC
                 let result = state_transition.validate_identity_and_signatures(
                     platform.drive,
                     action.as_ref(),
+                    platform.state.last_block_info(),
                     None,
                     &mut state_transition_execution_context,
                     platform_version,
@@ -176,6 +185,7 @@ pub(super) fn state_transition_to_execution_event_for_check_tx_v0<'a, C: CoreRPC
                 } else {
                     let state_transition_action_result = state_transition.transform_into_action(
                         platform,
+                        platform.state.last_block_info(),
                         true,
                         &mut state_transition_execution_context,
                         None,
@@ -230,6 +240,7 @@ pub(super) fn state_transition_to_execution_event_for_check_tx_v0<'a, C: CoreRPC
 
                 let state_transition_action_result = state_transition.transform_into_action(
                     platform,
+                    platform.state.last_block_info(),
                     true,
                     &mut state_transition_execution_context,
                     None,
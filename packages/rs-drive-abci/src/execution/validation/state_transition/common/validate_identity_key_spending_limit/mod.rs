@@ -0,0 +1,49 @@
+use dpp::block::epoch::EpochIndex;
+use dpp::fee::Credits;
+use dpp::identifier::Identifier;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
+use dpp::validation::ConsensusValidationResult;
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+use dpp::version::PlatformVersion;
+use crate::error::Error;
+use crate::error::execution::ExecutionError;
+use crate::execution::validation::state_transition::common::validate_identity_key_spending_limit::v0::validate_identity_key_spending_limit_v0;
+
+pub mod v0;
+
+/// Validates that the key signing a transfer or withdrawal can spend the amount during the epoch,
+/// returns the spending of the key to record if it has a spending limit
+pub(crate) fn validate_identity_key_spending_limit(
+    identity_id: Identifier,
+    key_id: KeyID,
+    amount: Credits,
+    epoch_index: EpochIndex,
+    drive: &Drive,
+    transaction: TransactionArg,
+    platform_version: &PlatformVersion,
+) -> Result<ConsensusValidationResult<Option<(KeyID, KeySpending)>>, Error> {
+    match platform_version
+        .drive_abci
+        .validation_and_processing
+        .state_transitions
+        .common_validation_methods
+        .validate_identity_key_spending_limit
+    {
+        0 => validate_identity_key_spending_limit_v0(
+            identity_id,
+            key_id,
+            amount,
+            epoch_index,
+            drive,
+            transaction,
+            platform_version,
+        ),
+        version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+            method: "validate_identity_key_spending_limit".to_string(),
+            known_versions: vec![0],
+            received: version,
+        })),
+    }
+}
@@ -0,0 +1,66 @@
+use crate::error::Error;
+
+use dpp::block::epoch::EpochIndex;
+use dpp::consensus::state::identity::identity_key_spending_limit_exceeded_error::IdentityKeySpendingLimitExceededError;
+use dpp::fee::Credits;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
+use dpp::platform_value::Identifier;
+use dpp::validation::ConsensusValidationResult;
+
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+
+use dpp::version::PlatformVersion;
+
+/// This will validate that the signing key stays within its spending limit for the epoch
+pub(super) fn validate_identity_key_spending_limit_v0(
+    identity_id: Identifier,
+    key_id: KeyID,
+    amount: Credits,
+    epoch_index: EpochIndex,
+    drive: &Drive,
+    transaction: TransactionArg,
+    platform_version: &PlatformVersion,
+) -> Result<ConsensusValidationResult<Option<(KeyID, KeySpending)>>, Error> {
+    if amount == 0 {
+        // nothing is spent when cancelling a pending transfer
+        return Ok(ConsensusValidationResult::new_with_data(None));
+    }
+
+    let Some(spending_limit) = drive.fetch_identity_key_spending_limit(
+        identity_id.to_buffer(),
+        key_id,
+        transaction,
+        platform_version,
+    )?
+    else {
+        return Ok(ConsensusValidationResult::new_with_data(None));
+    };
+
+    let key_spending = drive
+        .fetch_identity_key_spending(
+            identity_id.to_buffer(),
+            key_id,
+            transaction,
+            platform_version,
+        )?
+        .unwrap_or_default();
+
+    match key_spending.checked_spend(epoch_index, amount, spending_limit) {
+        Some(key_spending) => Ok(ConsensusValidationResult::new_with_data(Some((
+            key_id,
+            key_spending,
+        )))),
+        None => Ok(ConsensusValidationResult::new_with_error(
+            IdentityKeySpendingLimitExceededError::new(
+                identity_id,
+                key_id,
+                spending_limit,
+                key_spending.spent_in_epoch(epoch_index),
+                amount,
+            )
+            .into(),
+        )),
+    }
+}
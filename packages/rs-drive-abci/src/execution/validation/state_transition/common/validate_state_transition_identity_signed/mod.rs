@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use dpp::block::block_info::BlockInfo;
 use dpp::identifier::Identifier;
 use dpp::identity::PartialIdentity;
 use dpp::ProtocolError;
@@ -14,8 +15,10 @@ use crate::error::Error;
 use crate::error::execution::ExecutionError;
 use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
 use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::v0::ValidateStateTransitionIdentitySignatureV0;
+use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::v1::ValidateStateTransitionIdentitySignatureV1;
 
 pub mod v0;
+pub mod v1;
 
 pub type GetDataContractFn = fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>;
 
@@ -24,6 +27,7 @@ pub trait ValidateStateTransitionIdentitySignature {
         &self,
         drive: &Drive,
         action: Option<&StateTransitionAction>,
+        block_info: &BlockInfo,
         request_revision: bool,
         transaction: TransactionArg,
         execution_context: &mut StateTransitionExecutionContext,
@@ -36,6 +40,7 @@ impl ValidateStateTransitionIdentitySignature for StateTransition {
         &self,
         drive: &Drive,
         action: Option<&StateTransitionAction>,
+        block_info: &BlockInfo,
         request_revision: bool,
         transaction: TransactionArg,
         execution_context: &mut StateTransitionExecutionContext,
@@ -51,6 +56,16 @@ impl ValidateStateTransitionIdentitySignature for StateTransition {
             0 => self.validate_state_transition_identity_signed_v0(
                 drive,
                 action,
+                block_info,
+                request_revision,
+                transaction,
+                execution_context,
+                platform_version,
+            ),
+            1 => self.validate_state_transition_identity_signed_v1(
+                drive,
+                action,
+                block_info,
                 request_revision,
                 transaction,
                 execution_context,
@@ -58,9 +73,370 @@ impl ValidateStateTransitionIdentitySignature for StateTransition {
             ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "StateTransition::validate_state_transition_identity_signature".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::types::execution_operation::signature_verification_operation::SignatureVerificationOperation;
+    use crate::execution::types::execution_operation::ValidationOperation;
+    use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContextMethodsV0;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use dpp::consensus::signature::SignatureError;
+    use dpp::consensus::ConsensusError;
+    use dpp::dashcore::secp256k1::{Secp256k1, SecretKey};
+    use dpp::dashcore::signer;
+    use dpp::identity::identity_public_key::v0::IdentityPublicKeyV0;
+    use dpp::identity::v0::IdentityV0;
+    use dpp::identity::{
+        Identity, IdentityPublicKey, KeyType, Purpose, SecurityLevel, TimestampMillis,
+    };
+    use dpp::serialization::Signable;
+    use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+    use dpp::util::hash::ripemd160_sha256;
+    use dpp::util::multisig_signer::{multisig_redeem_script, MultisigSignature};
+    use dpp::version::DefaultForPlatformVersion;
+    use dpp::NativeBlsModule;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::BTreeMap;
+
+    const IDENTITY_ID: [u8; 32] = [2u8; 32];
+
+    /// Adds an identity with a single critical authentication key
+    fn setup_identity_with_key(
+        key_type: KeyType,
+        public_key_data: Vec<u8>,
+        disabled_at: Option<TimestampMillis>,
+        platform_version: &PlatformVersion,
+    ) -> (TempPlatform<MockCoreRPCLike>, IdentityPublicKey) {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let identity_public_key: IdentityPublicKey = IdentityPublicKeyV0 {
+            id: 0,
+            purpose: Purpose::AUTHENTICATION,
+            security_level: SecurityLevel::CRITICAL,
+            contract_bounds: None,
+            key_type,
+            read_only: false,
+            data: public_key_data.into(),
+            disabled_at,
+        }
+        .into();
+
+        let identity: Identity = IdentityV0 {
+            id: Identifier::new(IDENTITY_ID),
+            public_keys: BTreeMap::from([(0, identity_public_key.clone())]),
+            balance: 1000000000,
+            revision: 0,
+        }
+        .into();
+
+        platform
+            .drive
+            .add_new_identity(
+                identity,
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add an identity");
+
+        (platform, identity_public_key)
+    }
+
+    fn unsigned_transfer() -> StateTransition {
+        IdentityCreditTransferTransitionV0 {
+            identity_id: Identifier::new(IDENTITY_ID),
+            recipient_id: Identifier::new([3u8; 32]),
+            amount: 1000,
+            nonce: 1,
+            ..Default::default()
+        }
+        .into()
+    }
+
+    /// Adds an identity with a single key of the given type and returns a credit transfer
+    /// signed with this key
+    fn setup_identity_and_signed_transfer(
+        key_type: KeyType,
+        platform_version: &PlatformVersion,
+    ) -> (TempPlatform<MockCoreRPCLike>, StateTransition) {
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let (public_key_data, private_key) = key_type
+            .random_public_and_private_key_data(&mut rng, platform_version)
+            .expect("expected to generate a key");
+
+        let (platform, identity_public_key) =
+            setup_identity_with_key(key_type, public_key_data, None, platform_version);
+
+        let mut state_transition = unsigned_transfer();
+
+        state_transition
+            .sign(&identity_public_key, &private_key, &NativeBlsModule)
+            .expect("expected to sign the state transition");
+
+        (platform, state_transition)
+    }
+
+    /// Adds an identity with a 2 of 3 multisig key and returns a credit transfer signed by
+    /// the first and last keys of the multisig
+    fn setup_identity_and_multisig_signed_transfer(
+        platform_version: &PlatformVersion,
+    ) -> (TempPlatform<MockCoreRPCLike>, StateTransition) {
+        let private_keys = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let public_keys = private_keys
+            .iter()
+            .map(|private_key| {
+                SecretKey::from_slice(private_key)
+                    .expect("expected a secret key")
+                    .public_key(&Secp256k1::new())
+                    .serialize()
+            })
+            .collect::<Vec<_>>();
+
+        let redeem_script =
+            multisig_redeem_script(2, &public_keys).expect("expected a redeem script");
+
+        let (platform, _) = setup_identity_with_key(
+            KeyType::BIP13_SCRIPT_HASH,
+            ripemd160_sha256(&redeem_script).to_vec(),
+            None,
+            platform_version,
+        );
+
+        let mut state_transition = unsigned_transfer();
+
+        let data = state_transition
+            .signable_bytes()
+            .expect("expected signable bytes");
+
+        let signature = MultisigSignature {
+            redeem_script,
+            signatures: [&private_keys[0], &private_keys[2]]
+                .into_iter()
+                .map(|private_key| {
+                    signer::sign(&data, private_key)
+                        .expect("expected to sign")
+                        .to_vec()
+                })
+                .collect(),
+        }
+        .to_bytes()
+        .expect("expected to serialize the signature");
+
+        state_transition.set_signature(signature.into());
+        state_transition.set_signature_public_key_id(0);
+
+        (platform, state_transition)
+    }
+
+    fn validate_signature(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        state_transition: &StateTransition,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> ConsensusValidationResult<PartialIdentity> {
+        state_transition
+            .validate_state_transition_identity_signed(
+                &platform.drive,
+                None,
+                block_info,
+                false,
+                None,
+                execution_context,
+                platform_version,
+            )
+            .expect("expected to validate the signature")
+    }
+
+    fn new_execution_context(
+        platform_version: &PlatformVersion,
+    ) -> StateTransitionExecutionContext {
+        StateTransitionExecutionContext::default_for_platform_version(platform_version)
+            .expect("expected an execution context")
+    }
+
+    #[test]
+    fn should_not_accept_eddsa_signatures_before_they_are_supported() {
+        let platform_version = PlatformVersion::first();
+
+        let (platform, state_transition) =
+            setup_identity_and_signed_transfer(KeyType::EDDSA_25519_HASH160, platform_version);
+
+        let result = validate_signature(
+            &platform,
+            &state_transition,
+            &BlockInfo::default(),
+            &mut new_execution_context(platform_version),
+            platform_version,
+        );
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::SignatureError(
+                SignatureError::InvalidIdentityPublicKeyTypeError(_)
+            )]
+        ));
+    }
+
+    #[test]
+    fn should_accept_eddsa_signatures() {
+        let platform_version = PlatformVersion::latest();
+
+        let (platform, mut state_transition) =
+            setup_identity_and_signed_transfer(KeyType::EDDSA_25519_HASH160, platform_version);
+
+        let result = validate_signature(
+            &platform,
+            &state_transition,
+            &BlockInfo::default(),
+            &mut new_execution_context(platform_version),
+            platform_version,
+        );
+
+        assert!(result.is_valid());
+
+        let mut signature = state_transition.signature().to_vec();
+        *signature.last_mut().expect("expected a signature") ^= 1;
+        state_transition.set_signature(signature.into());
+
+        let result = validate_signature(
+            &platform,
+            &state_transition,
+            &BlockInfo::default(),
+            &mut new_execution_context(platform_version),
+            platform_version,
+        );
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::SignatureError(
+                SignatureError::InvalidStateTransitionSignatureError(_)
+            )]
+        ));
+    }
+
+    #[test]
+    fn should_not_accept_multisig_signatures_before_they_are_supported() {
+        let platform_version = PlatformVersion::first();
+
+        let (platform, state_transition) =
+            setup_identity_and_multisig_signed_transfer(platform_version);
+
+        let result = validate_signature(
+            &platform,
+            &state_transition,
+            &BlockInfo::default(),
+            &mut new_execution_context(platform_version),
+            platform_version,
+        );
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::SignatureError(
+                SignatureError::InvalidIdentityPublicKeyTypeError(_)
+            )]
+        ));
+    }
+
+    #[test]
+    fn should_charge_multisig_signatures_for_every_public_key() {
+        let platform_version = PlatformVersion::latest();
+
+        let (platform, state_transition) =
+            setup_identity_and_multisig_signed_transfer(platform_version);
+
+        let mut execution_context = new_execution_context(platform_version);
+
+        let result = validate_signature(
+            &platform,
+            &state_transition,
+            &BlockInfo::default(),
+            &mut execution_context,
+            platform_version,
+        );
+
+        assert!(result.is_valid());
+
+        assert_eq!(
+            execution_context.operations_slice(),
+            &[ValidationOperation::SignatureVerification(
+                SignatureVerificationOperation::new_multisig(3)
+            )]
+        );
+
+        let epoch = BlockInfo::default().epoch;
+
+        let multisig_cost = KeyType::BIP13_SCRIPT_HASH
+            .signature_verify_cost(3, platform_version, &epoch)
+            .expect("expected a cost");
+        let single_key_cost = KeyType::BIP13_SCRIPT_HASH
+            .signature_verify_cost(1, platform_version, &epoch)
+            .expect("expected a cost");
+
+        assert!(multisig_cost > single_key_cost);
+    }
+
+    #[test]
+    fn should_accept_signatures_of_keys_in_their_disable_grace_period() {
+        let platform_version = PlatformVersion::latest();
+
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let (public_key_data, private_key) = KeyType::ECDSA_SECP256K1
+            .random_public_and_private_key_data(&mut rng, platform_version)
+            .expect("expected to generate a key");
+
+        // The key was rotated with a grace period ending at 2000 ms
+        let (platform, identity_public_key) = setup_identity_with_key(
+            KeyType::ECDSA_SECP256K1,
+            public_key_data,
+            Some(2000),
+            platform_version,
+        );
+
+        let mut state_transition = unsigned_transfer();
+
+        state_transition
+            .sign(&identity_public_key, &private_key, &NativeBlsModule)
+            .expect("expected to sign the state transition");
+
+        let result = validate_signature(
+            &platform,
+            &state_transition,
+            &BlockInfo::default_with_time(1000),
+            &mut new_execution_context(platform_version),
+            platform_version,
+        );
+
+        assert!(result.is_valid());
+
+        let result = validate_signature(
+            &platform,
+            &state_transition,
+            &BlockInfo::default_with_time(2000),
+            &mut new_execution_context(platform_version),
+            platform_version,
+        );
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::SignatureError(
+                SignatureError::PublicKeyIsDisabledError(_)
+            )]
+        ));
+    }
+}
@@ -3,15 +3,17 @@ use crate::error::Error;
 use dpp::consensus::signature::{
     IdentityNotFoundError, InvalidSignaturePublicKeySecurityLevelError,
     InvalidStateTransitionSignatureError, PublicKeySecurityLevelNotMetError,
+    WrongPublicKeyPurposeError,
 };
 
-use dpp::identity::PartialIdentity;
+use dpp::identity::{PartialIdentity, Purpose};
 
 use crate::execution::types::execution_operation::signature_verification_operation::SignatureVerificationOperation;
 use crate::execution::types::execution_operation::ValidationOperation;
 use crate::execution::types::state_transition_execution_context::{
     StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
 };
+use dpp::block::block_info::BlockInfo;
 use dpp::consensus::ConsensusError;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
@@ -19,6 +21,7 @@ use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use crate::error::execution::ExecutionError;
 use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
 use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use dpp::state_transition::identity_recover_transition::accessors::IdentityRecoverTransitionAccessorsV0;
 use dpp::state_transition::StateTransition;
 use dpp::validation::ConsensusValidationResult;
 use dpp::version::PlatformVersion;
@@ -53,6 +56,7 @@ pub(super) trait ValidateStateTransitionIdentitySignatureV0<'a> {
         &self,
         drive: &Drive,
         action: Option<&StateTransitionAction>,
+        block_info: &BlockInfo,
         request_revision: bool,
         transaction: TransactionArg,
         execution_context: &mut StateTransitionExecutionContext,
@@ -65,6 +69,7 @@ impl<'a> ValidateStateTransitionIdentitySignatureV0<'a> for StateTransition {
         &self,
         drive: &Drive,
         action: Option<&StateTransitionAction>,
+        block_info: &BlockInfo,
         request_identity_revision: bool,
         transaction: TransactionArg,
         execution_context: &mut StateTransitionExecutionContext,
@@ -144,6 +149,24 @@ impl<'a> ValidateStateTransitionIdentitySignatureV0<'a> for StateTransition {
             return Ok(validation_result);
         }
 
+        // Recovery keys belong to guardians and can only sign the recovery of the identity
+        let purpose_requirement = match self {
+            StateTransition::IdentityRecover(st) => Some(st.operation().purpose_requirement()),
+            _ => None,
+        };
+
+        let wrong_purpose_requirement = match purpose_requirement {
+            Some(purpose) => (public_key.purpose() != purpose).then_some(purpose),
+            None => (public_key.purpose() == Purpose::RECOVERY).then_some(Purpose::AUTHENTICATION),
+        };
+
+        if let Some(purpose_requirement) = wrong_purpose_requirement {
+            validation_result.add_error(SignatureError::WrongPublicKeyPurposeError(
+                WrongPublicKeyPurposeError::new(public_key.purpose(), purpose_requirement),
+            ));
+            return Ok(validation_result);
+        }
+
         if !security_levels.contains(&public_key.security_level()) {
             validation_result.add_error(
                 SignatureError::InvalidSignaturePublicKeySecurityLevelError(
@@ -156,7 +179,8 @@ impl<'a> ValidateStateTransitionIdentitySignatureV0<'a> for StateTransition {
             return Ok(validation_result);
         }
 
-        if public_key.is_disabled() {
+        // Rotated keys stay usable until the end of their grace period
+        if public_key.is_disabled_at(block_info.time_ms) {
             validation_result.add_error(SignatureError::PublicKeyIsDisabledError(
                 PublicKeyIsDisabledError::new(public_key.id()),
             ));
@@ -166,7 +190,8 @@ impl<'a> ValidateStateTransitionIdentitySignatureV0<'a> for StateTransition {
         let operation = SignatureVerificationOperation::new(public_key.key_type());
         execution_context.add_operation(ValidationOperation::SignatureVerification(operation));
 
-        let signature_is_valid = self.verify_signature(public_key, &NativeBlsModule);
+        let signature_is_valid =
+            self.verify_signature_at_time(public_key, block_info.time_ms, &NativeBlsModule);
 
         if let Err(err) = signature_is_valid {
             let consensus_error = convert_to_consensus_signature_error(err)?;
@@ -0,0 +1,215 @@
+use crate::error::Error;
+
+use dpp::consensus::signature::{
+    IdentityNotFoundError, InvalidSignaturePublicKeySecurityLevelError, WrongPublicKeyPurposeError,
+};
+
+use dpp::identity::{PartialIdentity, Purpose};
+
+use crate::execution::types::execution_operation::signature_verification_operation::SignatureVerificationOperation;
+use crate::execution::types::execution_operation::ValidationOperation;
+use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::v0::convert_to_consensus_signature_error;
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+
+use crate::error::execution::ExecutionError;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use dpp::state_transition::identity_recover_transition::accessors::IdentityRecoverTransitionAccessorsV0;
+use dpp::state_transition::StateTransition;
+use dpp::util::multisig_signer;
+use dpp::validation::ConsensusValidationResult;
+use dpp::version::PlatformVersion;
+use dpp::ProtocolError;
+use dpp::{
+    consensus::signature::{
+        InvalidIdentityPublicKeyTypeError, MissingPublicKeyError, PublicKeyIsDisabledError,
+        SignatureError,
+    },
+    NativeBlsModule,
+};
+use drive::dpp::identity::KeyType;
+use drive::drive::identity::key::fetch::IdentityKeysRequest;
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::StateTransitionAction;
+use lazy_static::lazy_static;
+use std::collections::HashSet;
+
+lazy_static! {
+    /// Unlike v0, EdDSA and multisig signatures are verified
+    static ref SUPPORTED_KEY_TYPES: HashSet<KeyType> = {
+        let mut keys = HashSet::new();
+        keys.insert(KeyType::ECDSA_SECP256K1);
+        keys.insert(KeyType::BLS12_381);
+        keys.insert(KeyType::ECDSA_HASH160);
+        keys.insert(KeyType::EDDSA_25519_HASH160);
+        keys.insert(KeyType::BIP13_SCRIPT_HASH);
+        keys
+    };
+}
+
+pub(super) trait ValidateStateTransitionIdentitySignatureV1<'a> {
+    fn validate_state_transition_identity_signed_v1(
+        &self,
+        drive: &Drive,
+        action: Option<&StateTransitionAction>,
+        block_info: &BlockInfo,
+        request_revision: bool,
+        transaction: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error>;
+}
+
+impl<'a> ValidateStateTransitionIdentitySignatureV1<'a> for StateTransition {
+    fn validate_state_transition_identity_signed_v1(
+        &self,
+        drive: &Drive,
+        action: Option<&StateTransitionAction>,
+        block_info: &BlockInfo,
+        request_identity_revision: bool,
+        transaction: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error> {
+        let mut validation_result = ConsensusValidationResult::<PartialIdentity>::default();
+
+        let key_id =
+            self.signature_public_key_id()
+                .ok_or(ProtocolError::CorruptedCodeExecution(
+                    "state_transition does not have a public key Id to verify".to_string(),
+                ))?;
+
+        let owner_id = self.owner_id();
+
+        let security_levels = match self {
+            StateTransition::DocumentsBatch(_) => {
+                let action = action.ok_or(ProtocolError::CorruptedCodeExecution(
+                    "we expect a state transition action when validating the signature of the documents batch transition".to_string(),
+                ))?;
+                let StateTransitionAction::DocumentsBatchAction(documents_batch_action) = action
+                else {
+                    return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "we expect a documents batch state transition action when validating the signature of the documents batch transition",
+                    )));
+                };
+                documents_batch_action.contract_based_security_level_requirement()
+            }
+            _ => self
+                .security_level_requirement()
+                .ok_or(ProtocolError::CorruptedCodeExecution(
+                    "state_transition does not have a owner Id to verify".to_string(),
+                )),
+        }?;
+
+        let key_request = IdentityKeysRequest::new_specific_key_query(owner_id.as_bytes(), key_id);
+
+        let maybe_partial_identity = if request_identity_revision {
+            drive.fetch_identity_balance_with_keys_and_revision(
+                key_request,
+                transaction,
+                platform_version,
+            )?
+        } else {
+            drive.fetch_identity_balance_with_keys(key_request, transaction, platform_version)?
+        };
+
+        let partial_identity = match maybe_partial_identity {
+            None => {
+                // dbg!(bs58::encode(&state_transition.get_owner_id()).into_string());
+                validation_result.add_error(SignatureError::IdentityNotFoundError(
+                    IdentityNotFoundError::new(owner_id),
+                ));
+                return Ok(validation_result);
+            }
+            Some(partial_identity) => partial_identity,
+        };
+
+        if !partial_identity.not_found_public_keys.is_empty() {
+            validation_result.add_error(SignatureError::MissingPublicKeyError(
+                MissingPublicKeyError::new(key_id),
+            ));
+            return Ok(validation_result);
+        }
+
+        let Some(public_key) = partial_identity.loaded_public_keys.get(&key_id) else {
+            validation_result.add_error(SignatureError::MissingPublicKeyError(
+                MissingPublicKeyError::new(key_id),
+            ));
+            return Ok(validation_result);
+        };
+
+        if !SUPPORTED_KEY_TYPES.contains(&public_key.key_type()) {
+            validation_result.add_error(SignatureError::InvalidIdentityPublicKeyTypeError(
+                InvalidIdentityPublicKeyTypeError::new(public_key.key_type()),
+            ));
+            return Ok(validation_result);
+        }
+
+        // Recovery keys belong to guardians and can only sign the recovery of the identity
+        let purpose_requirement = match self {
+            StateTransition::IdentityRecover(st) => Some(st.operation().purpose_requirement()),
+            _ => None,
+        };
+
+        let wrong_purpose_requirement = match purpose_requirement {
+            Some(purpose) => (public_key.purpose() != purpose).then_some(purpose),
+            None => (public_key.purpose() == Purpose::RECOVERY).then_some(Purpose::AUTHENTICATION),
+        };
+
+        if let Some(purpose_requirement) = wrong_purpose_requirement {
+            validation_result.add_error(SignatureError::WrongPublicKeyPurposeError(
+                WrongPublicKeyPurposeError::new(public_key.purpose(), purpose_requirement),
+            ));
+            return Ok(validation_result);
+        }
+
+        if !security_levels.contains(&public_key.security_level()) {
+            validation_result.add_error(
+                SignatureError::InvalidSignaturePublicKeySecurityLevelError(
+                    InvalidSignaturePublicKeySecurityLevelError::new(
+                        public_key.security_level(),
+                        security_levels,
+                    ),
+                ),
+            );
+            return Ok(validation_result);
+        }
+
+        // Rotated keys stay usable until the end of their grace period
+        if public_key.is_disabled_at(block_info.time_ms) {
+            validation_result.add_error(SignatureError::PublicKeyIsDisabledError(
+                PublicKeyIsDisabledError::new(public_key.id()),
+            ));
+            return Ok(validation_result);
+        }
+
+        let operation = if public_key.key_type() == KeyType::BIP13_SCRIPT_HASH {
+            // A signature that can't be decoded is rejected before any public key is checked
+            let checked_public_keys =
+                multisig_signer::public_keys_count(self.signature().as_slice()).unwrap_or(0);
+            SignatureVerificationOperation::new_multisig(checked_public_keys as u16)
+        } else {
+            SignatureVerificationOperation::new(public_key.key_type())
+        };
+        execution_context.add_operation(ValidationOperation::SignatureVerification(operation));
+
+        let signature_is_valid =
+            self.verify_signature_at_time(public_key, block_info.time_ms, &NativeBlsModule);
+
+        if let Err(err) = signature_is_valid {
+            let consensus_error = convert_to_consensus_signature_error(err)?;
+            validation_result.add_error(consensus_error);
+            return Ok(validation_result);
+        }
+
+        validation_result.set_data(partial_identity);
+
+        Ok(validation_result)
+    }
+}
@@ -20,11 +20,13 @@ use drive::state_transition_action::StateTransitionAction;
 use crate::execution::types::state_transition_execution_context::{StateTransitionExecutionContext};
 use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::{ValidateStateTransitionIdentitySignature};
 use crate::execution::validation::state_transition::state_transitions::identity_update::identity_and_signatures::v0::IdentityUpdateStateTransitionIdentityAndSignaturesValidationV0;
+use crate::execution::validation::state_transition::state_transitions::identity_recover::identity_and_signatures::v0::IdentityRecoverStateTransitionIdentityAndSignaturesValidationV0;
 use crate::execution::validation::state_transition::state_transitions::identity_create::identity_and_signatures::v0::IdentityCreateStateTransitionIdentityAndSignaturesValidationV0;
 use crate::execution::validation::state_transition::state_transitions::identity_top_up::identity_retrieval::v0::IdentityTopUpStateTransitionIdentityRetrievalV0;
 
 pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
     platform: &'a PlatformRef<C>,
+    block_info: &BlockInfo,
     state_transition: StateTransition,
     transaction: TransactionArg,
     platform_version: &PlatformVersion,
@@ -45,6 +47,7 @@ pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
     let action = if state_transition.requires_advance_structure_validation() {
         let state_transition_action_result = state_transition.transform_into_action(
             platform,
+            block_info,
             true,
             &mut state_transition_execution_context,
             transaction,
@@ -77,6 +80,7 @@ pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
         } else {
             let state_transition_action_result = state_transition.transform_into_action(
                 platform,
+                block_info,
                 true,
                 &mut state_transition_execution_context,
                 transaction,
@@ -98,6 +102,7 @@ pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
     let result = state_transition.validate_identity_and_signatures(
         platform.drive,
         action.as_ref(),
+        block_info,
         transaction,
         &mut state_transition_execution_context,
         platform_version,
@@ -160,6 +165,7 @@ pub(crate) trait StateTransitionSignatureValidationV0 {
     /// # Arguments
     ///
     /// * `drive` - A reference to the drive containing the transaction data.
+    /// * `block_info` - The block info used to know if the signing key is disabled.
     /// * `tx` - The transaction argument to be authenticated.
     /// * `execution_context` - A mutable reference to the StateTransitionExecutionContext that provides the context for validation.
     /// * `platform_version` - A reference to the PlatformVersion to be used for validation.
@@ -174,6 +180,7 @@ pub(crate) trait StateTransitionSignatureValidationV0 {
         &self,
         drive: &Drive,
         action: Option<&StateTransitionAction>,
+        block_info: &BlockInfo,
         tx: TransactionArg,
         execution_context: &mut StateTransitionExecutionContext,
         platform_version: &PlatformVersion,
@@ -324,6 +331,7 @@ impl StateTransitionBasicStructureValidationV0 for StateTransition {
             StateTransition::IdentityCreditTransfer(st) => {
                 st.validate_basic_structure(platform_version)
             }
+            StateTransition::IdentityRecover(st) => st.validate_basic_structure(platform_version),
         }
     }
 }
@@ -355,6 +363,9 @@ impl StateTransitionNonceValidationV0 for StateTransition {
             StateTransition::IdentityCreditWithdrawal(st) => {
                 st.validate_nonces(platform, block_info, tx, platform_version)
             }
+            StateTransition::IdentityRecover(st) => {
+                st.validate_nonces(platform, block_info, tx, platform_version)
+            }
             _ => Ok(SimpleConsensusValidationResult::new()),
         }
     }
@@ -407,6 +418,7 @@ impl StateTransitionSignatureValidationV0 for StateTransition {
         &self,
         drive: &Drive,
         action: Option<&StateTransitionAction>,
+        block_info: &BlockInfo,
         tx: TransactionArg,
         execution_context: &mut StateTransitionExecutionContext,
         platform_version: &PlatformVersion,
@@ -422,6 +434,7 @@ impl StateTransitionSignatureValidationV0 for StateTransition {
                     .validate_state_transition_identity_signed(
                         drive,
                         action,
+                        block_info,
                         false,
                         tx,
                         execution_context,
@@ -429,6 +442,54 @@ impl StateTransitionSignatureValidationV0 for StateTransition {
                     )?
                     .map(Some))
             }
+            StateTransition::IdentityRecover(st) => {
+                match platform_version
+                    .drive_abci
+                    .validation_and_processing
+                    .state_transitions
+                    .identity_recover_state_transition
+                    .identity_signatures
+                {
+                    // The purpose of the signing key depends on the recover operation
+                    Some(0) => {
+                        let signable_bytes: Vec<u8> = self.signable_bytes()?;
+                        let mut validation_result = self
+                            .validate_state_transition_identity_signed(
+                                drive,
+                                action,
+                                block_info,
+                                false,
+                                tx,
+                                execution_context,
+                                platform_version,
+                            )?;
+                        if !validation_result.is_valid() {
+                            Ok(validation_result.map(Some))
+                        } else {
+                            let result = st
+                                .validate_identity_recover_state_transition_signatures_v0(
+                                    signable_bytes,
+                                    execution_context,
+                                )?;
+                            validation_result.merge(result);
+                            Ok(validation_result.map(Some))
+                        }
+                    }
+                    None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                        method: "identity recover transition: validate_identity_and_signatures"
+                            .to_string(),
+                        known_versions: vec![0],
+                    })),
+                    Some(version) => {
+                        Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                            method: "identity recover transition: validate_identity_and_signatures"
+                                .to_string(),
+                            known_versions: vec![0],
+                            received: version,
+                        }))
+                    }
+                }
+            }
             StateTransition::IdentityUpdate(st) => {
                 match platform_version
                     .drive_abci
@@ -443,6 +504,7 @@ impl StateTransitionSignatureValidationV0 for StateTransition {
                             .validate_state_transition_identity_signed(
                                 drive,
                                 action,
+                                block_info,
                                 true,
                                 tx,
                                 execution_context,
@@ -588,6 +650,10 @@ impl StateTransitionStateValidationV0 for StateTransition {
             StateTransition::IdentityCreditTransfer(st) => {
                 st.validate_state(action, platform, execution_context, tx)
             }
+            // The replay attack is prevented by identity nonce
+            StateTransition::IdentityRecover(st) => {
+                st.validate_state(action, platform, execution_context, tx)
+            }
         }
     }
 }
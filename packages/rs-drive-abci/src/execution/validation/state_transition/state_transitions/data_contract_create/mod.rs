@@ -2,6 +2,7 @@ mod identity_nonce;
 mod state;
 mod structure;
 
+use dpp::block::block_info::BlockInfo;
 use dpp::prelude::ConsensusValidationResult;
 use dpp::state_transition::data_contract_create_transition::DataContractCreateTransition;
 use dpp::validation::SimpleConsensusValidationResult;
@@ -30,6 +31,7 @@ impl StateTransitionActionTransformerV0 for DataContractCreateTransition {
     fn transform_into_action<C: CoreRPCLike>(
         &self,
         platform: &PlatformRef<C>,
+        _block_info: &BlockInfo,
         _validate: bool,
         _execution_context: &mut StateTransitionExecutionContext,
         _tx: TransactionArg,
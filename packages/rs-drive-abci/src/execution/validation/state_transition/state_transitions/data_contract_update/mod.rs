@@ -2,6 +2,7 @@ mod identity_contract_nonce;
 mod state;
 mod structure;
 
+use dpp::block::block_info::BlockInfo;
 use dpp::state_transition::data_contract_update_transition::DataContractUpdateTransition;
 use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
 
@@ -29,6 +30,7 @@ impl StateTransitionActionTransformerV0 for DataContractUpdateTransition {
     fn transform_into_action<C: CoreRPCLike>(
         &self,
         platform: &PlatformRef<C>,
+        _block_info: &BlockInfo,
         _validate: bool,
         _execution_context: &mut StateTransitionExecutionContext,
         _tx: TransactionArg,
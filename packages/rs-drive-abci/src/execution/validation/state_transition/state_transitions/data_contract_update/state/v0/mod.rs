@@ -174,6 +174,29 @@ impl DataContractUpdateStateTransitionStateValidationV0 for DataContractUpdateTr
                     ),
                 )
             }
+            // Words are only indexed when documents are stored, so the tokenized indices of an
+            // existing document type can not change
+            let old_token_indices = old_contract_document_type.token_indices();
+            let new_token_indices = new_contract_document_type.token_indices();
+            if let Some(changed_index) = old_token_indices
+                .iter()
+                .filter(|index| !new_token_indices.contains(index))
+                .chain(
+                    new_token_indices
+                        .iter()
+                        .filter(|index| !old_token_indices.contains(index)),
+                )
+                .next()
+            {
+                validation_result.add_error(
+                    BasicError::DataContractInvalidIndexDefinitionUpdateError(
+                        DataContractInvalidIndexDefinitionUpdateError::new(
+                            new_contract_document_type_name.clone(),
+                            changed_index.name.clone(),
+                        ),
+                    ),
+                )
+            }
         }
 
         if !validation_result.is_valid() {
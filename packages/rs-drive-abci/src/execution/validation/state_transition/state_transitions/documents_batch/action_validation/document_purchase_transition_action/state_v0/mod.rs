@@ -0,0 +1,60 @@
+use dpp::consensus::signature::IdentityNotFoundError;
+use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
+use dpp::fee::Credits;
+use dpp::identifier::Identifier;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::DocumentPurchaseTransitionAction;
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+use crate::error::Error;
+use crate::platform_types::platform::PlatformStateRef;
+
+pub(super) trait DocumentPurchaseTransitionActionStateValidationV0 {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        batch_purchases_price: Credits,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl DocumentPurchaseTransitionActionStateValidationV0 for DocumentPurchaseTransitionAction {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        batch_purchases_price: Credits,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        // The revision and the price of the original document were already checked
+        // when transforming the transition into an action, we only need the purchaser
+        // to be able to pay for the document, along with every purchase before it in the batch
+        // since they are all paid from the same balance
+        let maybe_purchaser_balance = platform.drive.fetch_identity_balance(
+            owner_id.to_buffer(),
+            transaction,
+            platform_version,
+        )?;
+
+        let Some(purchaser_balance) = maybe_purchaser_balance else {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                IdentityNotFoundError::new(owner_id).into(),
+            ));
+        };
+
+        if purchaser_balance < batch_purchases_price {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                IdentityInsufficientBalanceError::new(
+                    owner_id,
+                    purchaser_balance,
+                    batch_purchases_price,
+                )
+                .into(),
+            ));
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
@@ -0,0 +1,47 @@
+use dpp::consensus::signature::IdentityNotFoundError;
+use dpp::document::DocumentV0Getters;
+use dpp::identifier::Identifier;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::DocumentTransferTransitionAction;
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::v0::DocumentTransferTransitionActionAccessorsV0;
+use crate::error::Error;
+use crate::platform_types::platform::PlatformStateRef;
+
+pub(super) trait DocumentTransferTransitionActionStateValidationV0 {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl DocumentTransferTransitionActionStateValidationV0 for DocumentTransferTransitionAction {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        _owner_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        // The ownership and the revision of the original document were already checked
+        // when transforming the transition into an action, we only need the recipient to exist
+        let recipient_owner_id = self.document().owner_id();
+
+        let maybe_existing_recipient = platform.drive.fetch_identity_balance(
+            recipient_owner_id.to_buffer(),
+            transaction,
+            platform_version,
+        )?;
+
+        if maybe_existing_recipient.is_none() {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                IdentityNotFoundError::new(recipient_owner_id).into(),
+            ));
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
@@ -0,0 +1,50 @@
+use dpp::consensus::basic::document::InvalidDocumentTypeError;
+use dpp::consensus::state::document::document_not_transferable_error::DocumentNotTransferableError;
+use dpp::consensus::state::document::document_transfer_to_self_error::DocumentTransferToSelfError;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::DocumentV0Getters;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::DocumentTransferTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::v0::DocumentTransferTransitionActionAccessorsV0;
+
+use crate::error::Error;
+
+pub(super) trait DocumentTransferTransitionActionStructureValidationV0 {
+    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl DocumentTransferTransitionActionStructureValidationV0 for DocumentTransferTransitionAction {
+    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error> {
+        let contract_fetch_info = self.base().data_contract_fetch_info();
+        let data_contract = &contract_fetch_info.contract;
+        let document_type_name = self.base().document_type_name();
+
+        // Make sure that the document type is defined in the contract
+        let Some(document_type) = data_contract.document_type_optional_for_name(document_type_name)
+        else {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTypeError::new(document_type_name.clone(), data_contract.id())
+                    .into(),
+            ));
+        };
+
+        // Only documents of types marked as transferable in the contract can change owner
+        if !document_type.documents_transferable() {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                DocumentNotTransferableError::new(self.base().id(), document_type_name.clone())
+                    .into(),
+            ));
+        }
+
+        let recipient_owner_id = self.document().owner_id();
+
+        if recipient_owner_id == self.original_owner_id() {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                DocumentTransferToSelfError::new(self.base().id(), recipient_owner_id).into(),
+            ));
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
@@ -0,0 +1,41 @@
+use dpp::consensus::basic::document::InvalidDocumentTypeError;
+use dpp::consensus::state::document::document_not_tradeable_error::DocumentNotTradeableError;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::DocumentUpdatePriceTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::v0::DocumentUpdatePriceTransitionActionAccessorsV0;
+
+use crate::error::Error;
+
+pub(super) trait DocumentUpdatePriceTransitionActionStructureValidationV0 {
+    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl DocumentUpdatePriceTransitionActionStructureValidationV0
+    for DocumentUpdatePriceTransitionAction
+{
+    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error> {
+        let contract_fetch_info = self.base().data_contract_fetch_info();
+        let data_contract = &contract_fetch_info.contract;
+        let document_type_name = self.base().document_type_name();
+
+        // Make sure that the document type is defined in the contract
+        let Some(document_type) = data_contract.document_type_optional_for_name(document_type_name)
+        else {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTypeError::new(document_type_name.clone(), data_contract.id())
+                    .into(),
+            ));
+        };
+
+        // Only documents of types marked as tradeable in the contract can be put up for sale
+        if !document_type.documents_tradeable() {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                DocumentNotTradeableError::new(self.base().id(), document_type_name.clone()).into(),
+            ));
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
@@ -1,3 +1,6 @@
 pub(crate) mod document_create_transition_action;
 pub(crate) mod document_delete_transition_action;
+pub(crate) mod document_purchase_transition_action;
 pub(crate) mod document_replace_transition_action;
+pub(crate) mod document_transfer_transition_action;
+pub(crate) mod document_update_price_transition_action;
@@ -255,6 +255,7 @@ pub fn create_domain_data_trigger_v0(
                 primary_key_equal_clause: None,
                 in_clause: None,
                 range_clause: None,
+                residual_range_clauses: vec![],
                 equal_clauses: BTreeMap::from([
                     (
                         "normalizedParentDomainName".to_string(),
@@ -273,6 +274,8 @@ pub fn create_domain_data_trigger_v0(
                         },
                     ),
                 ]),
+                or_clauses: vec![],
+                contains_word_clause: None,
             },
             offset: None,
             limit: None,
@@ -370,6 +373,7 @@ pub fn create_domain_data_trigger_v0(
             primary_key_equal_clause: None,
             in_clause: None,
             range_clause: None,
+            residual_range_clauses: vec![],
             equal_clauses: BTreeMap::from([(
                 "saltedDomainHash".to_string(),
                 WhereClause {
@@ -378,6 +382,8 @@ pub fn create_domain_data_trigger_v0(
                     value: Value::Bytes32(salted_domain_hash),
                 },
             )]),
+            or_clauses: vec![],
+            contains_word_clause: None,
         },
         offset: None,
         limit: None,
@@ -75,7 +75,10 @@ pub fn delete_withdrawal_data_trigger_v0(
             }),
             in_clause: None,
             range_clause: None,
+            residual_range_clauses: vec![],
             equal_clauses: BTreeMap::default(),
+            or_clauses: vec![],
+            contains_word_clause: None,
         },
         offset: None,
         limit: Some(100),
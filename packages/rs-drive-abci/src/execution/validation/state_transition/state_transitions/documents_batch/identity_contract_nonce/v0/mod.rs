@@ -13,7 +13,10 @@ use dpp::state_transition::documents_batch_transition::document_transition::Docu
 
 use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
 use dpp::state_transition::StateTransitionLike;
+use std::collections::{BTreeMap, BTreeSet};
 
+use dpp::platform_value::Identifier;
+use dpp::prelude::IdentityNonce;
 use dpp::validation::SimpleConsensusValidationResult;
 
 use crate::platform_types::platform::PlatformStateRef;
@@ -39,26 +42,53 @@ impl DocumentsBatchStateTransitionIdentityContractNonceV0 for DocumentsBatchTran
         tx: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<SimpleConsensusValidationResult, Error> {
-        // We should validate that all newly created documents have valid ids
+        let identity_id = self.owner_id();
+
+        // A batch can use multiple data contracts, every contract has its own identity contract
+        // nonce, so we group the nonces by contract and only fetch the stored nonce once for each
+        let mut nonces_by_contract: BTreeMap<Identifier, Vec<IdentityNonce>> = BTreeMap::new();
         for transition in self.transitions() {
-            let revision_nonce = transition.identity_contract_nonce();
-            let identity_id = self.owner_id();
-            let (existing_nonce, fees) = platform.drive.fetch_identity_contract_nonce_with_fees(
+            nonces_by_contract
+                .entry(transition.data_contract_id())
+                .or_default()
+                .push(transition.identity_contract_nonce());
+        }
+
+        for (data_contract_id, revision_nonces) in nonces_by_contract {
+            let (existing_nonce, _fees) = platform.drive.fetch_identity_contract_nonce_with_fees(
                 identity_id.to_buffer(),
-                transition.data_contract_id().to_buffer(),
+                data_contract_id.to_buffer(),
                 block_info,
                 true,
                 tx,
                 platform_version,
             )?;
 
-            let result = if let Some(existing_nonce) = existing_nonce {
-                validate_identity_nonce_update(existing_nonce, revision_nonce, identity_id)
-            } else {
-                validate_new_identity_nonce(revision_nonce, identity_id)
-            };
-            if !result.is_valid() {
-                return Ok(result);
+            let mut used_nonces = BTreeSet::new();
+
+            for revision_nonce in revision_nonces {
+                // Two transitions for the same contract can not share a nonce
+                if !used_nonces.insert(revision_nonce) {
+                    return Ok(SimpleConsensusValidationResult::new_with_error(
+                        ConsensusError::StateError(StateError::InvalidIdentityNonceError(
+                            InvalidIdentityNonceError::new(
+                                identity_id,
+                                existing_nonce,
+                                revision_nonce,
+                                MergeIdentityNonceResult::NonceAlreadyPresentAtTip,
+                            ),
+                        )),
+                    ));
+                }
+
+                let result = if let Some(existing_nonce) = existing_nonce {
+                    validate_identity_nonce_update(existing_nonce, revision_nonce, identity_id)
+                } else {
+                    validate_new_identity_nonce(revision_nonce, identity_id)
+                };
+                if !result.is_valid() {
+                    return Ok(result);
+                }
             }
         }
 
@@ -36,6 +36,7 @@ impl StateTransitionActionTransformerV0 for DocumentsBatchTransition {
     fn transform_into_action<C: CoreRPCLike>(
         &self,
         platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
         validate: bool,
         _execution_context: &mut StateTransitionExecutionContext,
         tx: TransactionArg,
@@ -49,7 +50,7 @@ impl StateTransitionActionTransformerV0 for DocumentsBatchTransition {
             .documents_batch_state_transition
             .transform_into_action
         {
-            0 => self.transform_into_action_v0(&platform.into(), validate, tx),
+            0 => self.transform_into_action_v0(&platform.into(), block_info, validate, tx),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "documents batch transition: transform_into_action".to_string(),
                 known_versions: vec![0],
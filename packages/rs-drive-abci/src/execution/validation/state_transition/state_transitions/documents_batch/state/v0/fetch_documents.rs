@@ -146,7 +146,10 @@ pub(crate) fn fetch_documents_for_transitions_knowing_contract_and_document_type
             primary_key_equal_clause: None,
             in_clause: None,
             range_clause: None,
+            residual_range_clauses: vec![],
             equal_clauses: Default::default(),
+            or_clauses: vec![],
+            contains_word_clause: None,
         },
         offset: None,
         limit: Some(transitions.len() as u16),
@@ -190,7 +193,10 @@ pub(crate) fn fetch_document_with_id(
             }),
             in_clause: None,
             range_clause: None,
+            residual_range_clauses: vec![],
             equal_clauses: Default::default(),
+            or_clauses: vec![],
+            contains_word_clause: None,
         },
         offset: None,
         limit: Some(1),
@@ -1,4 +1,6 @@
+use dpp::block::block_info::BlockInfo;
 use dpp::consensus::ConsensusError;
+use dpp::fee::Credits;
 use dpp::consensus::state::state_error::StateError;
 use dpp::prelude::ConsensusValidationResult;
 use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
@@ -9,6 +11,7 @@ use drive::state_transition_action::StateTransitionAction;
 use dpp::version::{DefaultForPlatformVersion, PlatformVersion};
 use drive::grovedb::TransactionArg;
 use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::v0::DocumentPurchaseTransitionActionAccessorsV0;
 use drive::state_transition_action::document::documents_batch::DocumentsBatchTransitionAction;
 use drive::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceAction;
 use crate::error::Error;
@@ -17,6 +20,9 @@ use crate::execution::types::state_transition_execution_context::StateTransition
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_create_transition_action::DocumentCreateTransitionActionValidation;
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_delete_transition_action::DocumentDeleteTransitionActionValidation;
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_replace_transition_action::DocumentReplaceTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_transfer_transition_action::DocumentTransferTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_update_price_transition_action::DocumentUpdatePriceTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_purchase_transition_action::DocumentPurchaseTransitionActionValidation;
 use crate::execution::validation::state_transition::documents_batch::data_triggers::{data_trigger_bindings_list, DataTriggerExecutionContext, DataTriggerExecutor};
 use crate::execution::validation::state_transition::documents_batch::state::v0::data_triggers::execute_data_triggers;
 use crate::platform_types::platform::{PlatformStateRef};
@@ -38,6 +44,7 @@ pub(in crate::execution::validation::state_transition::state_transitions::docume
     fn transform_into_action_v0(
         &self,
         platform: &PlatformStateRef,
+        block_info: &BlockInfo,
         validate: bool,
         tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
@@ -60,6 +67,8 @@ impl DocumentsBatchStateTransitionStateValidationV0 for DocumentsBatchTransition
 
         let mut validated_transitions = vec![];
 
+        let mut batch_purchases_price: Credits = 0;
+
         let data_trigger_bindings = if platform.config.execution.use_document_triggers {
             data_trigger_bindings_list(platform_version)?
         } else {
@@ -75,6 +84,28 @@ impl DocumentsBatchStateTransitionStateValidationV0 for DocumentsBatchTransition
                     .validate_state(platform, owner_id, transaction, platform_version)?,
                 DocumentTransitionAction::DeleteAction(delete_action) => delete_action
                     .validate_state(platform, owner_id, transaction, platform_version)?,
+                DocumentTransitionAction::TransferAction(transfer_action) => transfer_action
+                    .validate_state(platform, owner_id, transaction, platform_version)?,
+                DocumentTransitionAction::UpdatePriceAction(update_price_action) => {
+                    update_price_action.validate_state(
+                        platform,
+                        owner_id,
+                        transaction,
+                        platform_version,
+                    )?
+                }
+                DocumentTransitionAction::PurchaseAction(purchase_action) => {
+                    // All purchases of the batch are paid from the balance of the owner
+                    batch_purchases_price =
+                        batch_purchases_price.saturating_add(purchase_action.price());
+                    purchase_action.validate_state(
+                        platform,
+                        owner_id,
+                        batch_purchases_price,
+                        transaction,
+                        platform_version,
+                    )?
+                }
                 DocumentTransitionAction::BumpIdentityDataContractNonce(..) => {
                     return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
                         "we should never start with a bump identity data contract nonce",
@@ -147,6 +178,28 @@ impl DocumentsBatchStateTransitionStateValidationV0 for DocumentsBatchTransition
             }
         }
 
+        if !validation_result.is_valid() {
+            // A batch is applied atomically, even when it writes to multiple data contracts.
+            // If any of its transitions is invalid none of them are applied, however we still need
+            // to bump the identity data contract nonce of every contract the batch is using
+            validated_transitions = validated_transitions
+                .into_iter()
+                .map(|transition| match transition {
+                    DocumentTransitionAction::BumpIdentityDataContractNonce(_) => Ok(transition),
+                    transition => Ok(DocumentTransitionAction::BumpIdentityDataContractNonce(
+                        BumpIdentityDataContractNonceAction::from_document_base_transition_action(
+                            transition.base_owned().ok_or(Error::Execution(
+                                ExecutionError::CorruptedCodeExecution(
+                                    "base should always exist on transition",
+                                ),
+                            ))?,
+                            owner_id,
+                        )?,
+                    )),
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+        }
+
         state_transition_action.set_transitions(validated_transitions);
 
         validation_result.set_data(state_transition_action.into());
@@ -157,6 +210,7 @@ impl DocumentsBatchStateTransitionStateValidationV0 for DocumentsBatchTransition
     fn transform_into_action_v0(
         &self,
         platform: &PlatformStateRef,
+        block_info: &BlockInfo,
         validate: bool,
         tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
@@ -166,7 +220,7 @@ impl DocumentsBatchStateTransitionStateValidationV0 for DocumentsBatchTransition
             StateTransitionExecutionContext::default_for_platform_version(platform_version)?;
 
         let validation_result =
-            self.try_into_action_v0(platform, validate, tx, &mut execution_context)?;
+            self.try_into_action_v0(platform, block_info, validate, tx, &mut execution_context)?;
 
         Ok(validation_result.map(Into::into))
     }
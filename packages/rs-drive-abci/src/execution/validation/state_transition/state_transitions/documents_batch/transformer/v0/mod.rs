@@ -7,6 +7,8 @@ use crate::platform_types::platform::PlatformStateRef;
 use dpp::consensus::basic::document::{DataContractNotPresentError, InvalidDocumentTypeError};
 use dpp::consensus::basic::BasicError;
 
+use dpp::consensus::state::document::document_incorrect_purchase_price_error::DocumentIncorrectPurchasePriceError;
+use dpp::consensus::state::document::document_not_for_sale_error::DocumentNotForSaleError;
 use dpp::consensus::state::document::document_not_found_error::DocumentNotFoundError;
 use dpp::consensus::state::document::document_owner_id_mismatch_error::DocumentOwnerIdMismatchError;
 
@@ -14,35 +16,42 @@ use dpp::consensus::state::document::invalid_document_revision_error::InvalidDoc
 use dpp::consensus::state::state_error::StateError;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
 
-use dpp::document::{Document, DocumentV0Getters};
+use dpp::document::{property_names, Document, DocumentV0Getters};
+use dpp::fee::Credits;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
 use dpp::validation::SimpleConsensusValidationResult;
 use dpp::{consensus::ConsensusError, prelude::Identifier, validation::ConsensusValidationResult};
 
 use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
 use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
 use dpp::state_transition::documents_batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
-use dpp::state_transition::documents_batch_transition::document_transition::{DocumentTransition, DocumentReplaceTransition, DocumentTransitionV0Methods};
+use dpp::state_transition::documents_batch_transition::document_transition::{DocumentPurchaseTransition, DocumentTransition, DocumentTransitionV0Methods};
+use dpp::state_transition::documents_batch_transition::document_transition::document_purchase_transition::v0::v0_methods::DocumentPurchaseTransitionV0Methods;
 use dpp::state_transition::StateTransitionLike;
 use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentCreateTransitionAction;
 use drive::state_transition_action::document::documents_batch::document_transition::document_delete_transition_action::DocumentDeleteTransitionAction;
 use drive::state_transition_action::document::documents_batch::document_transition::document_replace_transition_action::DocumentReplaceTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::DocumentTransferTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::DocumentUpdatePriceTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::DocumentPurchaseTransitionAction;
 use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionAction;
 use drive::state_transition_action::document::documents_batch::DocumentsBatchTransitionAction;
 use drive::state_transition_action::document::documents_batch::v0::DocumentsBatchTransitionActionV0;
 
 use crate::execution::validation::state_transition::documents_batch::state::v0::fetch_documents::fetch_documents_for_transitions_knowing_contract_and_document_type;
+use dpp::block::block_info::BlockInfo;
 use dpp::version::PlatformVersion;
 use drive::grovedb::TransactionArg;
 
-use dpp::state_transition::documents_batch_transition::document_transition::document_replace_transition::v0::v0_methods::DocumentReplaceTransitionV0Methods;
-use drive::drive::contract::DataContractFetchInfo;
 use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use drive::drive::contract::DataContractFetchInfo;
 
 pub(in crate::execution::validation::state_transition::state_transitions::documents_batch) trait DocumentsBatchTransitionTransformerV0
 {
     fn try_into_action_v0(
         &self,
         platform: &PlatformStateRef,
+        block_info: &BlockInfo,
         validate: bool,
         transaction: TransactionArg,
         execution_context: &mut StateTransitionExecutionContext,
@@ -52,6 +61,7 @@ pub(in crate::execution::validation::state_transition::state_transitions::docume
 trait DocumentsBatchTransitionInternalTransformerV0 {
     fn transform_document_transitions_within_contract_v0(
         platform: &PlatformStateRef,
+        block_info: &BlockInfo,
         validate: bool,
         data_contract_id: &Identifier,
         owner_id: Identifier,
@@ -62,6 +72,7 @@ trait DocumentsBatchTransitionInternalTransformerV0 {
     ) -> Result<ConsensusValidationResult<Vec<DocumentTransitionAction>>, Error>;
     fn transform_document_transitions_within_document_type_v0(
         platform: &PlatformStateRef,
+        block_info: &BlockInfo,
         validate: bool,
         data_contract_fetch_info: Arc<DataContractFetchInfo>,
         document_type_name: &str,
@@ -73,6 +84,7 @@ trait DocumentsBatchTransitionInternalTransformerV0 {
     ) -> Result<ConsensusValidationResult<Vec<DocumentTransitionAction>>, Error>;
     /// The data contract can be of multiple difference versions
     fn transform_transition_v0(
+        block_info: &BlockInfo,
         validate: bool,
         data_contract_fetch_info: Arc<DataContractFetchInfo>,
         transition: &DocumentTransition,
@@ -84,20 +96,26 @@ trait DocumentsBatchTransitionInternalTransformerV0 {
         fetched_documents: &'a [Document],
     ) -> ConsensusValidationResult<&'a Document>;
     fn check_ownership_of_old_replaced_document_v0(
-        document_transition: &DocumentReplaceTransition,
+        document_transition: &DocumentTransition,
         fetched_document: &Document,
         owner_id: &Identifier,
     ) -> SimpleConsensusValidationResult;
     fn check_revision_is_bumped_by_one_during_replace_v0(
-        document_transition: &DocumentReplaceTransition,
+        document_transition: &DocumentTransition,
         original_document: &Document,
     ) -> SimpleConsensusValidationResult;
+
+    fn check_purchase_price_v0(
+        document_purchase_transition: &DocumentPurchaseTransition,
+        original_document: &Document,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
 }
 
 impl DocumentsBatchTransitionTransformerV0 for DocumentsBatchTransition {
     fn try_into_action_v0(
         &self,
         platform: &PlatformStateRef,
+        block_info: &BlockInfo,
         validate: bool,
         transaction: TransactionArg,
         execution_context: &mut StateTransitionExecutionContext,
@@ -138,6 +156,7 @@ impl DocumentsBatchTransitionTransformerV0 for DocumentsBatchTransition {
                 |(data_contract_id, document_transitions_by_document_type)| {
                     Self::transform_document_transitions_within_contract_v0(
                         platform,
+                        block_info,
                         validate,
                         data_contract_id,
                         owner_id,
@@ -171,6 +190,7 @@ impl DocumentsBatchTransitionTransformerV0 for DocumentsBatchTransition {
 impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition {
     fn transform_document_transitions_within_contract_v0(
         platform: &PlatformStateRef,
+        block_info: &BlockInfo,
         validate: bool,
         data_contract_id: &Identifier,
         owner_id: Identifier,
@@ -204,6 +224,7 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
         .map(|(document_type_name, document_transitions)| {
             Self::transform_document_transitions_within_document_type_v0(
                 platform,
+                block_info,
                 validate,
                 data_contract_fetch_info.clone(),
                 document_type_name,
@@ -221,6 +242,7 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
 
     fn transform_document_transitions_within_document_type_v0(
         platform: &PlatformStateRef,
+        block_info: &BlockInfo,
         validate: bool,
         data_contract_fetch_info: Arc<DataContractFetchInfo>,
         document_type_name: &str,
@@ -251,13 +273,21 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
 
         let replace_transitions = document_transitions
             .iter()
-            .filter(|transition| matches!(transition, DocumentTransition::Replace(_)))
+            .filter(|transition| {
+                matches!(
+                    transition,
+                    DocumentTransition::Replace(_)
+                        | DocumentTransition::Transfer(_)
+                        | DocumentTransition::UpdatePrice(_)
+                        | DocumentTransition::Purchase(_)
+                )
+            })
             .copied()
             .collect::<Vec<_>>();
 
-        // We fetch documents only for replace transitions
+        // We fetch documents only for replace, transfer, update price and purchase transitions
         // since we need them to create transition actions
-        // Below we also perform state validation for replace transitions only
+        // Below we also perform state validation for these transitions only
         // other transitions are validated in their validate_state functions
         // TODO: Think more about this architecture
         let fetched_documents_validation_result =
@@ -284,6 +314,7 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
                 .map(|transition| {
                     // we validate every transition in this document type
                     Self::transform_transition_v0(
+                        block_info,
                         validate,
                         data_contract_fetch_info.clone(),
                         transition,
@@ -319,6 +350,7 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
 
     /// The data contract can be of multiple difference versions
     fn transform_transition_v0<'a>(
+        block_info: &BlockInfo,
         validate: bool,
         data_contract_fetch_info: Arc<DataContractFetchInfo>,
         transition: &DocumentTransition,
@@ -357,7 +389,7 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
                 let original_document_created_at = original_document.created_at();
 
                 let validation_result = Self::check_ownership_of_old_replaced_document_v0(
-                    document_replace_transition,
+                    transition,
                     original_document,
                     &owner_id,
                 );
@@ -372,7 +404,7 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
                     // for example when we already applied the state transition action
                     // and we are just validating it happened
                     let validation_result = Self::check_revision_is_bumped_by_one_during_replace_v0(
-                        document_replace_transition,
+                        transition,
                         original_document,
                     );
 
@@ -395,6 +427,148 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
                     Ok(result)
                 }
             }
+            DocumentTransition::Transfer(document_transfer_transition) => {
+                let mut result = ConsensusValidationResult::<DocumentTransitionAction>::new();
+
+                let validation_result =
+                    Self::find_replaced_document_v0(transition, replaced_documents);
+
+                if !validation_result.is_valid_with_data() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                let original_document = validation_result.into_data()?;
+
+                // Only the current owner can transfer the document
+                let validation_result = Self::check_ownership_of_old_replaced_document_v0(
+                    transition,
+                    original_document,
+                    &owner_id,
+                );
+
+                if !validation_result.is_valid() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                if validate {
+                    let validation_result = Self::check_revision_is_bumped_by_one_during_replace_v0(
+                        transition,
+                        original_document,
+                    );
+
+                    if !validation_result.is_valid() {
+                        result.merge(validation_result);
+                        return Ok(result);
+                    }
+                }
+
+                let document_transfer_action =
+                    DocumentTransferTransitionAction::try_from_borrowed_document_transfer_transition(
+                        document_transfer_transition,
+                        original_document.clone(),
+                        block_info,
+                        |_identifier| Ok(data_contract_fetch_info.clone()),
+                    )?;
+
+                Ok(DocumentTransitionAction::TransferAction(document_transfer_action).into())
+            }
+            DocumentTransition::UpdatePrice(document_update_price_transition) => {
+                let mut result = ConsensusValidationResult::<DocumentTransitionAction>::new();
+
+                let validation_result =
+                    Self::find_replaced_document_v0(transition, replaced_documents);
+
+                if !validation_result.is_valid_with_data() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                let original_document = validation_result.into_data()?;
+
+                // Only the current owner can set the price of the document
+                let validation_result = Self::check_ownership_of_old_replaced_document_v0(
+                    transition,
+                    original_document,
+                    &owner_id,
+                );
+
+                if !validation_result.is_valid() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                if validate {
+                    let validation_result = Self::check_revision_is_bumped_by_one_during_replace_v0(
+                        transition,
+                        original_document,
+                    );
+
+                    if !validation_result.is_valid() {
+                        result.merge(validation_result);
+                        return Ok(result);
+                    }
+                }
+
+                let document_update_price_action =
+                    DocumentUpdatePriceTransitionAction::try_from_borrowed_document_update_price_transition(
+                        document_update_price_transition,
+                        original_document.clone(),
+                        |_identifier| Ok(data_contract_fetch_info.clone()),
+                    )?;
+
+                Ok(
+                    DocumentTransitionAction::UpdatePriceAction(document_update_price_action)
+                        .into(),
+                )
+            }
+            DocumentTransition::Purchase(document_purchase_transition) => {
+                let mut result = ConsensusValidationResult::<DocumentTransitionAction>::new();
+
+                let validation_result =
+                    Self::find_replaced_document_v0(transition, replaced_documents);
+
+                if !validation_result.is_valid_with_data() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                let original_document = validation_result.into_data()?;
+
+                // The purchaser is not the owner of the document, so ownership is not checked
+                if validate {
+                    let validation_result = Self::check_revision_is_bumped_by_one_during_replace_v0(
+                        transition,
+                        original_document,
+                    );
+
+                    if !validation_result.is_valid() {
+                        result.merge(validation_result);
+                        return Ok(result);
+                    }
+
+                    let validation_result = Self::check_purchase_price_v0(
+                        document_purchase_transition,
+                        original_document,
+                    )?;
+
+                    if !validation_result.is_valid() {
+                        result.merge(validation_result);
+                        return Ok(result);
+                    }
+                }
+
+                let document_purchase_action =
+                    DocumentPurchaseTransitionAction::try_from_borrowed_document_purchase_transition(
+                        document_purchase_transition,
+                        original_document.clone(),
+                        owner_id,
+                        |_identifier| Ok(data_contract_fetch_info.clone()),
+                    )?;
+
+                Ok(DocumentTransitionAction::PurchaseAction(document_purchase_action).into())
+            }
             DocumentTransition::Delete(document_delete_transition) => {
                 let action = DocumentDeleteTransitionAction::from_document_borrowed_create_transition_with_contract_lookup(document_delete_transition,                      |_identifier| {
                 Ok(data_contract_fetch_info.clone())
@@ -424,7 +598,7 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
     }
 
     fn check_ownership_of_old_replaced_document_v0(
-        document_transition: &DocumentReplaceTransition,
+        document_transition: &DocumentTransition,
         fetched_document: &Document,
         owner_id: &Identifier,
     ) -> SimpleConsensusValidationResult {
@@ -441,12 +615,13 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
         result
     }
     fn check_revision_is_bumped_by_one_during_replace_v0(
-        document_transition: &DocumentReplaceTransition,
+        document_transition: &DocumentTransition,
         original_document: &Document,
     ) -> SimpleConsensusValidationResult {
         let mut result = SimpleConsensusValidationResult::default();
 
-        let revision = document_transition.revision();
+        // Only transitions on existing documents reach this check, they always carry a revision
+        let revision = document_transition.revision().unwrap_or_default();
 
         // If there was no previous revision this means that the document_type is not update-able
         // However this should have been caught earlier
@@ -473,4 +648,35 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
         }
         result
     }
+
+    fn check_purchase_price_v0(
+        document_purchase_transition: &DocumentPurchaseTransition,
+        original_document: &Document,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let document_id = document_purchase_transition.base().id();
+
+        let Some(price) = original_document
+            .properties()
+            .get_optional_integer::<Credits>(property_names::PRICE)?
+        else {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                DocumentNotForSaleError::new(document_id).into(),
+            ));
+        };
+
+        // The purchaser must agree to the exact listed price, this protects them against
+        // the owner raising the price while the purchase is in flight
+        if price != document_purchase_transition.price() {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                DocumentIncorrectPurchasePriceError::new(
+                    document_id,
+                    document_purchase_transition.price(),
+                    price,
+                )
+                .into(),
+            ));
+        }
+
+        Ok(SimpleConsensusValidationResult::default())
+    }
 }
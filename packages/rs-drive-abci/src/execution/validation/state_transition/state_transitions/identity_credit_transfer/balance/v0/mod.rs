@@ -71,7 +71,13 @@ impl IdentityCreditTransferTransitionBalanceValidationV0 for IdentityCreditTrans
             existing_identity_balance
         };
 
-        if balance < self.amount() + MIN_LEFTOVER_CREDITS_BEFORE_PROCESSING {
+        // the amount of a multi-recipient transfer saturates instead of overflowing,
+        // which can never be covered by the balance
+        if balance
+            < self
+                .amount()
+                .saturating_add(MIN_LEFTOVER_CREDITS_BEFORE_PROCESSING)
+        {
             return Ok(SimpleConsensusValidationResult::new_with_error(
                 IdentityInsufficientBalanceError::new(self.identity_id(), balance, self.amount())
                     .into(),
@@ -3,6 +3,7 @@ mod nonce;
 mod state;
 mod structure;
 
+use dpp::block::block_info::BlockInfo;
 use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
 use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
 use dpp::version::PlatformVersion;
@@ -29,9 +30,10 @@ impl StateTransitionActionTransformerV0 for IdentityCreditTransferTransition {
     fn transform_into_action<C: CoreRPCLike>(
         &self,
         platform: &PlatformRef<C>,
+        _block_info: &BlockInfo,
         _validate: bool,
         _execution_context: &mut StateTransitionExecutionContext,
-        _tx: TransactionArg,
+        tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
         let platform_version = platform.state.current_platform_version()?;
 
@@ -42,7 +44,7 @@ impl StateTransitionActionTransformerV0 for IdentityCreditTransferTransition {
             .identity_credit_transfer_state_transition
             .transform_into_action
         {
-            0 => self.transform_into_action_v0(),
+            0 => self.transform_into_action_v0(platform, tx, platform_version),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "identity credit transfer transition: transform_into_action".to_string(),
                 known_versions: vec![0],
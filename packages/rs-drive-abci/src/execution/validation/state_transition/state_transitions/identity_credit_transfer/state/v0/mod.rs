@@ -1,14 +1,19 @@
 use crate::error::Error;
+use crate::execution::validation::state_transition::common::validate_identity_key_spending_limit::validate_identity_key_spending_limit;
 use crate::platform_types::platform::PlatformRef;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::rpc::core::CoreRPCLike;
 
 use dpp::consensus::signature::IdentityNotFoundError;
 
+use dpp::consensus::state::identity::pending_transfer_not_found_error::PendingTransferNotFoundError;
 use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
 
 use dpp::prelude::ConsensusValidationResult;
 use dpp::state_transition::identity_credit_transfer_transition::accessors::IdentityCreditTransferTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_transfer_transition::v2::PendingTransferOperation;
 use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+use dpp::state_transition::StateTransitionIdentitySigned;
 use drive::state_transition_action::identity::identity_credit_transfer::IdentityCreditTransferTransitionAction;
 
 use dpp::version::PlatformVersion;
@@ -23,8 +28,11 @@ pub(in crate::execution::validation::state_transition::state_transitions::identi
         platform_version: &PlatformVersion,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
 
-    fn transform_into_action_v0(
+    fn transform_into_action_v0<C: CoreRPCLike>(
         &self,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
 }
 
@@ -58,26 +66,77 @@ impl IdentityCreditTransferStateTransitionStateValidationV0 for IdentityCreditTr
             ));
         }
 
-        let maybe_existing_recipient = platform.drive.fetch_identity_balance(
-            self.recipient_id().to_buffer(),
-            tx,
-            platform_version,
-        )?;
+        for recipient_id in self.recipients().into_keys() {
+            let maybe_existing_recipient = platform.drive.fetch_identity_balance(
+                recipient_id.to_buffer(),
+                tx,
+                platform_version,
+            )?;
 
-        if maybe_existing_recipient.is_none() {
-            return Ok(ConsensusValidationResult::new_with_error(
-                IdentityNotFoundError::new(self.recipient_id()).into(),
-            ));
+            if maybe_existing_recipient.is_none() {
+                return Ok(ConsensusValidationResult::new_with_error(
+                    IdentityNotFoundError::new(recipient_id).into(),
+                ));
+            }
         }
 
-        self.transform_into_action_v0()
+        self.transform_into_action_v0(platform, tx, platform_version)
     }
 
-    fn transform_into_action_v0(
+    fn transform_into_action_v0<C: CoreRPCLike>(
         &self,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let mut cancelled_pending_transfer = None;
+
+        if let IdentityCreditTransferTransition::V2(transition) = self {
+            if let PendingTransferOperation::Cancel { transfer_id } = &transition.operation {
+                // released transfers are removed at the end of the block reaching their
+                // release condition, so a transfer still pending can be cancelled
+                let maybe_pending_transfer = platform.drive.fetch_pending_transfer(
+                    transfer_id.to_buffer(),
+                    tx,
+                    platform_version,
+                )?;
+
+                let Some(pending_transfer) = maybe_pending_transfer.filter(|pending_transfer| {
+                    pending_transfer.sender_id == transition.identity_id
+                }) else {
+                    return Ok(ConsensusValidationResult::new_with_error(
+                        PendingTransferNotFoundError::new(transition.identity_id, *transfer_id)
+                            .into(),
+                    ));
+                };
+
+                cancelled_pending_transfer = Some(pending_transfer);
+            }
+        }
+
+        let key_spending_result = validate_identity_key_spending_limit(
+            self.identity_id(),
+            self.signature_public_key_id(),
+            self.amount(),
+            platform.state.last_committed_block_epoch_ref().index,
+            platform.drive,
+            tx,
+            platform_version,
+        )?;
+
+        if !key_spending_result.is_valid() {
+            return Ok(ConsensusValidationResult::new_with_errors(
+                key_spending_result.errors,
+            ));
+        }
+
         Ok(ConsensusValidationResult::new_with_data(
-            IdentityCreditTransferTransitionAction::from(self).into(),
+            IdentityCreditTransferTransitionAction::try_from_borrowed_transition(
+                self,
+                cancelled_pending_transfer,
+                key_spending_result.into_data()?,
+            )?
+            .into(),
         ))
     }
 }
@@ -1,15 +1,20 @@
 use dpp::consensus::basic::identity::{
     IdentityCreditTransferToSelfError, InvalidIdentityCreditTransferAmountError,
+    InvalidIdentityCreditTransferRecipientsCountError,
 };
 
 // use dpp::platform_value::
 use crate::error::Error;
 use dpp::state_transition::identity_credit_transfer_transition::accessors::IdentityCreditTransferTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_transfer_transition::v2::PendingTransferOperation;
 use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
 use dpp::validation::SimpleConsensusValidationResult;
 
 const MIN_TRANSFER_AMOUNT: u64 = 1000;
 
+/// The maximum number of recipients of a single credit transfer
+const MAX_TRANSFER_RECIPIENTS: u16 = 100;
+
 pub(in crate::execution::validation::state_transition::state_transitions::identity_credit_transfer) trait IdentityCreditTransferStateTransitionStructureValidationV0 {
     fn validate_base_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error>;
 }
@@ -20,19 +25,60 @@ impl IdentityCreditTransferStateTransitionStructureValidationV0
     fn validate_base_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error> {
         let result = SimpleConsensusValidationResult::new();
 
-        if self.identity_id() == self.recipient_id() {
+        if let IdentityCreditTransferTransition::V2(transition) = self {
+            // cancelling a pending transfer has nothing to validate before knowing the state
+            let PendingTransferOperation::Schedule {
+                recipient_id,
+                amount,
+                ..
+            } = &transition.operation
+            else {
+                return Ok(result);
+            };
+
+            if recipient_id == &transition.identity_id {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    IdentityCreditTransferToSelfError::default().into(),
+                ));
+            }
+
+            if *amount < MIN_TRANSFER_AMOUNT {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    InvalidIdentityCreditTransferAmountError::new(*amount, MIN_TRANSFER_AMOUNT)
+                        .into(),
+                ));
+            }
+
+            return Ok(result);
+        }
+
+        let recipients = self.recipients();
+
+        if recipients.is_empty() || recipients.len() > MAX_TRANSFER_RECIPIENTS as usize {
             return Ok(SimpleConsensusValidationResult::new_with_error(
-                IdentityCreditTransferToSelfError::default().into(),
+                InvalidIdentityCreditTransferRecipientsCountError::new(
+                    recipients.len().try_into().unwrap_or(u16::MAX),
+                    MAX_TRANSFER_RECIPIENTS,
+                )
+                .into(),
             ));
         }
 
-        if self.amount() < MIN_TRANSFER_AMOUNT {
+        if recipients.contains_key(&self.identity_id()) {
             return Ok(SimpleConsensusValidationResult::new_with_error(
-                InvalidIdentityCreditTransferAmountError::new(self.amount(), MIN_TRANSFER_AMOUNT)
-                    .into(),
+                IdentityCreditTransferToSelfError::default().into(),
             ));
         }
 
+        for amount in recipients.into_values() {
+            if amount < MIN_TRANSFER_AMOUNT {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    InvalidIdentityCreditTransferAmountError::new(amount, MIN_TRANSFER_AMOUNT)
+                        .into(),
+                ));
+            }
+        }
+
         Ok(result)
     }
 }
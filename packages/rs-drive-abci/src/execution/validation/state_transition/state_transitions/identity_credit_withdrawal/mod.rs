@@ -3,6 +3,7 @@ mod nonce;
 mod state;
 mod structure;
 
+use dpp::block::block_info::BlockInfo;
 use dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
 use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
 use dpp::version::PlatformVersion;
@@ -18,6 +19,7 @@ use crate::rpc::core::CoreRPCLike;
 
 use crate::execution::validation::state_transition::identity_credit_withdrawal::state::v0::IdentityCreditWithdrawalStateTransitionStateValidationV0;
 use crate::execution::validation::state_transition::identity_credit_withdrawal::structure::v0::IdentityCreditWithdrawalStateTransitionStructureValidationV0;
+use crate::execution::validation::state_transition::identity_credit_withdrawal::structure::v1::IdentityCreditWithdrawalStateTransitionStructureValidationV1;
 
 use crate::execution::validation::state_transition::processor::v0::{
     StateTransitionBasicStructureValidationV0, StateTransitionStateValidationV0,
@@ -30,9 +32,10 @@ impl StateTransitionActionTransformerV0 for IdentityCreditWithdrawalTransition {
     fn transform_into_action<C: CoreRPCLike>(
         &self,
         platform: &PlatformRef<C>,
+        _block_info: &BlockInfo,
         _validate: bool,
         _execution_context: &mut StateTransitionExecutionContext,
-        _tx: TransactionArg,
+        tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
         let platform_version = platform.state.current_platform_version()?;
 
@@ -43,7 +46,7 @@ impl StateTransitionActionTransformerV0 for IdentityCreditWithdrawalTransition {
             .identity_credit_withdrawal_state_transition
             .transform_into_action
         {
-            0 => self.transform_into_action_v0(platform),
+            0 => self.transform_into_action_v0(platform, tx),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "identity credit withdrawal transition: transform_into_action".to_string(),
                 known_versions: vec![0],
@@ -66,10 +69,11 @@ impl StateTransitionBasicStructureValidationV0 for IdentityCreditWithdrawalTrans
             .base_structure
         {
             0 => self.validate_base_structure_v0(),
+            1 => self.validate_base_structure_v1(),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "identity credit withdrawal transition: validate_basic_structure"
                     .to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
@@ -102,3 +106,67 @@ impl StateTransitionStateValidationV0 for IdentityCreditWithdrawalTransition {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::execution::validation::state_transition::processor::v0::StateTransitionBasicStructureValidationV0;
+    use dpp::consensus::basic::BasicError;
+    use dpp::consensus::ConsensusError;
+    use dpp::identity::core_script::CoreScript;
+    use dpp::state_transition::identity_credit_withdrawal_transition::v0::{
+        IdentityCreditWithdrawalTransitionV0, MIN_WITHDRAWAL_AMOUNT,
+    };
+    use dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
+    use dpp::version::PlatformVersion;
+    use dpp::withdrawal::Pooling;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn withdrawal_transition(pooling: Pooling) -> IdentityCreditWithdrawalTransition {
+        let mut rng = StdRng::seed_from_u64(5);
+
+        IdentityCreditWithdrawalTransitionV0 {
+            amount: MIN_WITHDRAWAL_AMOUNT,
+            core_fee_per_byte: 1,
+            pooling,
+            output_script: CoreScript::random_p2pkh(&mut rng),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    #[test]
+    fn should_reject_pooling_before_withdrawals_are_pooled() {
+        let platform_version = PlatformVersion::first();
+
+        let result = withdrawal_transition(Pooling::Standard)
+            .validate_basic_structure(platform_version)
+            .expect("expected to validate structure");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(
+                BasicError::NotImplementedIdentityCreditWithdrawalTransitionPoolingError(_)
+            )]
+        ));
+
+        let result = withdrawal_transition(Pooling::Never)
+            .validate_basic_structure(platform_version)
+            .expect("expected to validate structure");
+
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn should_accept_pooling_once_withdrawals_are_pooled() {
+        let platform_version = PlatformVersion::latest();
+
+        for pooling in [Pooling::Never, Pooling::IfAvailable, Pooling::Standard] {
+            let result = withdrawal_transition(pooling)
+                .validate_basic_structure(platform_version)
+                .expect("expected to validate structure");
+
+            assert!(result.is_valid());
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::execution::validation::state_transition::common::validate_identity_key_spending_limit::validate_identity_key_spending_limit;
 use crate::platform_types::platform::PlatformRef;
 use crate::rpc::core::CoreRPCLike;
 
@@ -9,6 +10,7 @@ use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
 use dpp::prelude::ConsensusValidationResult;
 use dpp::state_transition::identity_credit_withdrawal_transition::accessors::IdentityCreditWithdrawalTransitionAccessorsV0;
 use dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
+use dpp::state_transition::StateTransitionIdentitySigned;
 
 use drive::grovedb::TransactionArg;
 use drive::state_transition_action::identity::identity_credit_withdrawal::IdentityCreditWithdrawalTransitionAction;
@@ -24,6 +26,7 @@ pub(in crate::execution::validation::state_transition::state_transitions::identi
     fn transform_into_action_v0<C: CoreRPCLike>(
         &self,
         platform: &PlatformRef<C>,
+        tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
 }
 
@@ -59,13 +62,16 @@ impl IdentityCreditWithdrawalStateTransitionStateValidationV0
             ));
         }
 
-        self.transform_into_action_v0(platform)
+        self.transform_into_action_v0(platform, tx)
     }
 
     fn transform_into_action_v0<C: CoreRPCLike>(
         &self,
         platform: &PlatformRef<C>,
+        tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
         let last_block_time =
             platform
                 .state
@@ -74,10 +80,27 @@ impl IdentityCreditWithdrawalStateTransitionStateValidationV0
                     "expected a last platform block during identity update validation",
                 )))?;
 
+        let key_spending_result = validate_identity_key_spending_limit(
+            self.identity_id(),
+            self.signature_public_key_id(),
+            self.amount(),
+            platform.state.last_committed_block_epoch_ref().index,
+            platform.drive,
+            tx,
+            platform_version,
+        )?;
+
+        if !key_spending_result.is_valid() {
+            return Ok(ConsensusValidationResult::new_with_errors(
+                key_spending_result.errors,
+            ));
+        }
+
         Ok(ConsensusValidationResult::new_with_data(
             IdentityCreditWithdrawalTransitionAction::from_identity_credit_withdrawal(
                 self,
                 last_block_time,
+                key_spending_result.into_data()?,
             )
             .into(),
         ))
@@ -1 +1,2 @@
 pub(crate) mod v0;
+pub(crate) mod v1;
@@ -0,0 +1,49 @@
+use crate::error::Error;
+
+use crate::execution::types::execution_operation::signature_verification_operation::SignatureVerificationOperation;
+use crate::execution::types::execution_operation::ValidationOperation;
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+use dpp::serialization::PlatformMessageSignable;
+use dpp::state_transition::identity_recover_transition::accessors::IdentityRecoverTransitionAccessorsV0;
+use dpp::state_transition::identity_recover_transition::IdentityRecoverTransition;
+use dpp::state_transition::public_key_in_creation::accessors::IdentityPublicKeyInCreationV0Getters;
+use dpp::validation::SimpleConsensusValidationResult;
+
+pub(in crate::execution::validation::state_transition) trait IdentityRecoverStateTransitionIdentityAndSignaturesValidationV0
+{
+    fn validate_identity_recover_state_transition_signatures_v0(
+        &self,
+        signable_bytes: Vec<u8>,
+        execution_context: &mut StateTransitionExecutionContext,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+
+impl IdentityRecoverStateTransitionIdentityAndSignaturesValidationV0 for IdentityRecoverTransition {
+    fn validate_identity_recover_state_transition_signatures_v0(
+        &self,
+        signable_bytes: Vec<u8>,
+        execution_context: &mut StateTransitionExecutionContext,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let mut result = SimpleConsensusValidationResult::default();
+
+        // The proposed master keys must prove that their private keys are held,
+        // otherwise guardians could recover an identity to keys nobody controls
+        for key in self.new_master_keys().iter() {
+            let validation_result = signable_bytes.as_slice().verify_signature(
+                key.key_type(),
+                key.data().as_slice(),
+                key.signature().as_slice(),
+            )?;
+            execution_context.add_operation(ValidationOperation::SignatureVerification(
+                SignatureVerificationOperation::new(key.key_type()),
+            ));
+            if !validation_result.is_valid() {
+                result.add_errors(validation_result.errors);
+            }
+        }
+
+        Ok(result)
+    }
+}
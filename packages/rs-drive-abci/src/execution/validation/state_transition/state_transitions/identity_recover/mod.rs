@@ -0,0 +1,154 @@
+pub(crate) mod identity_and_signatures;
+mod nonce;
+mod state;
+mod structure;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::state_transition::identity_recover_transition::IdentityRecoverTransition;
+use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
+use dpp::version::PlatformVersion;
+use drive::state_transition_action::StateTransitionAction;
+
+use drive::grovedb::TransactionArg;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::platform_types::platform::PlatformRef;
+use crate::rpc::core::CoreRPCLike;
+
+use crate::execution::validation::state_transition::identity_recover::state::v0::IdentityRecoverStateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::identity_recover::structure::v0::IdentityRecoverStateTransitionStructureValidationV0;
+use crate::execution::validation::state_transition::processor::v0::{
+    StateTransitionBasicStructureValidationV0, StateTransitionStateValidationV0,
+};
+use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+
+impl StateTransitionActionTransformerV0 for IdentityRecoverTransition {
+    fn transform_into_action<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        _block_info: &BlockInfo,
+        _validate: bool,
+        _execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_recover_state_transition
+            .transform_into_action
+        {
+            0 => self.transform_into_action_v0(platform, tx, platform_version),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "identity recover transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+impl StateTransitionBasicStructureValidationV0 for IdentityRecoverTransition {
+    fn validate_basic_structure(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_recover_state_transition
+            .base_structure
+        {
+            0 => self.validate_base_structure_v0(platform_version),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "identity recover transition: validate_basic_structure".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+impl StateTransitionStateValidationV0 for IdentityRecoverTransition {
+    fn validate_state<C: CoreRPCLike>(
+        &self,
+        _action: Option<StateTransitionAction>,
+        platform: &PlatformRef<C>,
+        _execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_recover_state_transition
+            .state
+        {
+            0 => self.validate_state_v0(platform, tx, platform_version),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "identity recover transition: validate_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::execution::ExecutionError;
+    use crate::error::Error;
+    use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+    use crate::execution::validation::state_transition::processor::v0::StateTransitionSignatureValidationV0;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::prelude::Identifier;
+    use dpp::state_transition::identity_recover_transition::v0::{
+        IdentityRecoverOperation, IdentityRecoverTransitionV0,
+    };
+    use dpp::state_transition::StateTransition;
+    use dpp::version::{DefaultForPlatformVersion, PlatformVersion};
+
+    #[test]
+    fn should_reject_identity_recovery_before_protocol_version_2() {
+        let platform_version = PlatformVersion::first();
+
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let state_transition: StateTransition = IdentityRecoverTransitionV0 {
+            identity_id: Identifier::random(),
+            operation: IdentityRecoverOperation::Veto,
+            nonce: 1,
+            ..Default::default()
+        }
+        .into();
+
+        let mut execution_context =
+            StateTransitionExecutionContext::default_for_platform_version(platform_version)
+                .expect("expected an execution context");
+
+        let result = state_transition.validate_identity_and_signatures(
+            &platform.drive,
+            None,
+            &BlockInfo::default(),
+            None,
+            &mut execution_context,
+            platform_version,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::Execution(ExecutionError::VersionNotActive { .. }))
+        ));
+    }
+}
@@ -0,0 +1,295 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::execution::validation::state_transition::common::validate_identity_public_key_ids_dont_exist_in_state::validate_identity_public_key_ids_dont_exist_in_state;
+use crate::execution::validation::state_transition::common::validate_unique_identity_public_key_hashes_in_state::validate_unique_identity_public_key_hashes_in_state;
+use crate::platform_types::platform::PlatformRef;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::rpc::core::CoreRPCLike;
+
+use dpp::consensus::state::identity::identity_recovery_proposal_already_exists_error::IdentityRecoveryProposalAlreadyExistsError;
+use dpp::consensus::state::identity::identity_recovery_proposal_not_executable_error::IdentityRecoveryProposalNotExecutableError;
+use dpp::consensus::state::identity::identity_recovery_proposal_not_found_error::IdentityRecoveryProposalNotFoundError;
+use dpp::consensus::ConsensusError;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::recovery_proposal::IdentityRecoveryProposal;
+use dpp::identity::{KeyID, Purpose, SecurityLevel};
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::identity_recover_transition::accessors::IdentityRecoverTransitionAccessorsV0;
+use dpp::state_transition::identity_recover_transition::v0::IdentityRecoverOperation;
+use dpp::state_transition::identity_recover_transition::IdentityRecoverTransition;
+use dpp::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use dpp::state_transition::StateTransitionIdentitySigned;
+use dpp::version::{DefaultForPlatformVersion, PlatformVersion};
+use drive::drive::identity::key::fetch::{IdentityKeysRequest, KeyIDIdentityPublicKeyPairBTreeMap};
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::identity::identity_recover::v0::{
+    IdentityRecoverActionOperation, IdentityRecoverTransitionActionV0,
+};
+use drive::state_transition_action::identity::identity_recover::IdentityRecoverTransitionAction;
+use drive::state_transition_action::system::bump_identity_nonce_action::BumpIdentityNonceAction;
+use drive::state_transition_action::StateTransitionAction;
+use std::collections::BTreeSet;
+
+pub(in crate::execution::validation::state_transition::state_transitions::identity_recover) trait IdentityRecoverStateTransitionStateValidationV0
+{
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+
+    fn transform_into_action_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+}
+
+impl IdentityRecoverStateTransitionStateValidationV0 for IdentityRecoverTransition {
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        // The signing key was already checked to be a recovery key of the identity,
+        // or one of its master keys when vetoing, everything else depends on the proposal
+        self.transform_into_action_v0(platform, tx, platform_version)
+    }
+
+    fn transform_into_action_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let mut state_transition_execution_context =
+            StateTransitionExecutionContext::default_for_platform_version(platform_version)?;
+        let drive = platform.drive;
+        let identity_id = self.identity_id();
+
+        let last_block_time_ms =
+            platform
+                .state
+                .last_committed_block_time_ms()
+                .ok_or(Error::Execution(ExecutionError::StateNotInitialized(
+                    "expected a last platform block during identity recover validation",
+                )))?;
+
+        let maybe_recovery_proposal = drive.fetch_identity_recovery_proposal(
+            identity_id.to_buffer(),
+            tx,
+            platform_version,
+        )?;
+
+        let operation = match (self.operation(), maybe_recovery_proposal) {
+            // The recovery delay can be changed whether a recovery is pending or not
+            (IdentityRecoverOperation::SetRecoveryDelay { recovery_delay_ms }, _) => {
+                IdentityRecoverActionOperation::SetRecoveryDelay(recovery_delay_ms)
+            }
+            (IdentityRecoverOperation::Propose, Some(_)) => {
+                return self.bump_nonce_with_errors(vec![
+                    IdentityRecoveryProposalAlreadyExistsError::new(identity_id).into(),
+                ]);
+            }
+            (IdentityRecoverOperation::Propose, None) => {
+                let errors = validate_new_master_keys_in_state(
+                    self,
+                    self.new_master_keys(),
+                    platform,
+                    &mut state_transition_execution_context,
+                    tx,
+                    platform_version,
+                )?;
+
+                if !errors.is_empty() {
+                    return self.bump_nonce_with_errors(errors);
+                }
+
+                IdentityRecoverActionOperation::SetRecoveryProposal(IdentityRecoveryProposal {
+                    new_master_keys: self
+                        .new_master_keys()
+                        .iter()
+                        .map(|key| key.clone().into())
+                        .collect(),
+                    approving_key_ids: BTreeSet::from([self.signature_public_key_id()]),
+                    proposed_at: last_block_time_ms,
+                })
+            }
+            (_, None) => {
+                return self.bump_nonce_with_errors(vec![
+                    IdentityRecoveryProposalNotFoundError::new(identity_id).into(),
+                ]);
+            }
+            (IdentityRecoverOperation::Approve, Some(mut recovery_proposal)) => {
+                recovery_proposal
+                    .approving_key_ids
+                    .insert(self.signature_public_key_id());
+                IdentityRecoverActionOperation::SetRecoveryProposal(recovery_proposal)
+            }
+            (IdentityRecoverOperation::Veto, Some(_)) => {
+                IdentityRecoverActionOperation::RemoveRecoveryProposal
+            }
+            (IdentityRecoverOperation::Execute, Some(recovery_proposal)) => {
+                let identity_keys = drive
+                    .fetch_identity_keys::<KeyIDIdentityPublicKeyPairBTreeMap>(
+                        IdentityKeysRequest::new_all_keys_query(&identity_id.to_buffer(), None),
+                        tx,
+                        platform_version,
+                    )?;
+
+                let enabled_key_ids = |purpose: Purpose, security_level: SecurityLevel| {
+                    identity_keys
+                        .values()
+                        .filter(|key| {
+                            !key.is_disabled()
+                                && key.purpose() == purpose
+                                && key.security_level() == security_level
+                        })
+                        .map(|key| key.id())
+                        .collect::<BTreeSet<KeyID>>()
+                };
+
+                // approvals of recovery keys disabled since the proposal are not counted
+                let recovery_key_ids = enabled_key_ids(Purpose::RECOVERY, SecurityLevel::CRITICAL);
+
+                // the identity can choose its own recovery delay, otherwise the default applies
+                let recovery_delay_ms = drive
+                    .fetch_identity_recovery_delay(identity_id.to_buffer(), tx, platform_version)?
+                    .unwrap_or(
+                        platform_version
+                            .drive_abci
+                            .validation_and_processing
+                            .state_transitions
+                            .identity_recovery_delay_ms,
+                    );
+
+                let executable_at = recovery_proposal.executable_at(recovery_delay_ms);
+
+                if !recovery_proposal.is_approved(&recovery_key_ids)
+                    || last_block_time_ms < executable_at
+                {
+                    return self.bump_nonce_with_errors(vec![
+                        IdentityRecoveryProposalNotExecutableError::new(
+                            identity_id,
+                            recovery_proposal
+                                .approvals(&recovery_key_ids)
+                                .try_into()
+                                .unwrap_or(u16::MAX),
+                            IdentityRecoveryProposal::required_approvals(recovery_key_ids.len())
+                                .try_into()
+                                .unwrap_or(u16::MAX),
+                            executable_at,
+                        )
+                        .into(),
+                    ]);
+                }
+
+                // keys could have been added to the identity since the proposal was made
+                let new_master_keys = recovery_proposal
+                    .new_master_keys
+                    .iter()
+                    .map(IdentityPublicKeyInCreation::from)
+                    .collect::<Vec<_>>();
+
+                let errors = validate_new_master_keys_in_state(
+                    self,
+                    &new_master_keys,
+                    platform,
+                    &mut state_transition_execution_context,
+                    tx,
+                    platform_version,
+                )?;
+
+                if !errors.is_empty() {
+                    return self.bump_nonce_with_errors(errors);
+                }
+
+                let revision = drive
+                    .fetch_identity_revision(identity_id.to_buffer(), true, tx, platform_version)?
+                    .ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "revision should exist for an identity with a recovery proposal",
+                    )))?;
+
+                IdentityRecoverActionOperation::ReplaceMasterKeys {
+                    master_keys_to_disable: enabled_key_ids(
+                        Purpose::AUTHENTICATION,
+                        SecurityLevel::MASTER,
+                    )
+                    .into_iter()
+                    .collect(),
+                    new_master_keys: recovery_proposal.new_master_keys,
+                    disabled_at: last_block_time_ms,
+                    revision: revision + 1,
+                }
+            }
+        };
+
+        let action: IdentityRecoverTransitionAction = IdentityRecoverTransitionActionV0 {
+            identity_id,
+            operation,
+            nonce: self.nonce(),
+        }
+        .into();
+
+        Ok(ConsensusValidationResult::new_with_data(action.into()))
+    }
+}
+
+trait IdentityRecoverBumpNonceV0 {
+    fn bump_nonce_with_errors(
+        &self,
+        errors: Vec<ConsensusError>,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+}
+
+impl IdentityRecoverBumpNonceV0 for IdentityRecoverTransition {
+    fn bump_nonce_with_errors(
+        &self,
+        errors: Vec<ConsensusError>,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let bump_action = StateTransitionAction::BumpIdentityNonceAction(
+            BumpIdentityNonceAction::from_borrowed_identity_recover_transition(self)?,
+        );
+
+        Ok(ConsensusValidationResult::new_with_data_and_errors(
+            bump_action,
+            errors,
+        ))
+    }
+}
+
+/// The new master keys must not be used by another identity and must not reuse a key id
+fn validate_new_master_keys_in_state<C: CoreRPCLike>(
+    transition: &IdentityRecoverTransition,
+    new_master_keys: &[IdentityPublicKeyInCreation],
+    platform: &PlatformRef<C>,
+    execution_context: &mut StateTransitionExecutionContext,
+    tx: TransactionArg,
+    platform_version: &PlatformVersion,
+) -> Result<Vec<ConsensusError>, Error> {
+    let result = validate_unique_identity_public_key_hashes_in_state(
+        new_master_keys,
+        platform.drive,
+        execution_context,
+        tx,
+        platform_version,
+    )?;
+
+    if !result.is_valid() {
+        return Ok(result.errors);
+    }
+
+    Ok(validate_identity_public_key_ids_dont_exist_in_state(
+        transition.identity_id(),
+        new_master_keys,
+        platform.drive,
+        tx,
+        execution_context,
+        platform_version,
+    )?
+    .errors)
+}
@@ -0,0 +1,72 @@
+use crate::error::Error;
+use dpp::consensus::basic::identity::{
+    InvalidIdentityRecoverTransitionMasterKeysError, InvalidIdentityRecoveryDelayError,
+};
+use dpp::identity::{Purpose, SecurityLevel};
+use dpp::state_transition::identity_recover_transition::accessors::IdentityRecoverTransitionAccessorsV0;
+use dpp::state_transition::identity_recover_transition::v0::IdentityRecoverOperation;
+use dpp::state_transition::identity_recover_transition::IdentityRecoverTransition;
+use dpp::state_transition::public_key_in_creation::accessors::IdentityPublicKeyInCreationV0Getters;
+use dpp::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use dpp::validation::SimpleConsensusValidationResult;
+use dpp::version::PlatformVersion;
+
+pub(in crate::execution::validation::state_transition::state_transitions::identity_recover) trait IdentityRecoverStateTransitionStructureValidationV0
+{
+    fn validate_base_structure_v0(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+
+impl IdentityRecoverStateTransitionStructureValidationV0 for IdentityRecoverTransition {
+    fn validate_base_structure_v0(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let new_master_keys = self.new_master_keys();
+
+        // Only a proposal carries the master keys replacing the current ones
+        let has_valid_master_keys = match self.operation() {
+            IdentityRecoverOperation::Propose => {
+                !new_master_keys.is_empty()
+                    && new_master_keys.iter().all(|key| {
+                        key.purpose() == Purpose::AUTHENTICATION
+                            && key.security_level() == SecurityLevel::MASTER
+                    })
+            }
+            _ => new_master_keys.is_empty(),
+        };
+
+        if !has_valid_master_keys {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidIdentityRecoverTransitionMasterKeysError::new().into(),
+            ));
+        }
+
+        // A short recovery delay would not leave the master keys the time to veto a proposal
+        if let IdentityRecoverOperation::SetRecoveryDelay { recovery_delay_ms } = self.operation() {
+            let min_recovery_delay_ms = platform_version
+                .drive_abci
+                .validation_and_processing
+                .state_transitions
+                .min_identity_recovery_delay_ms;
+
+            if recovery_delay_ms < min_recovery_delay_ms {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    InvalidIdentityRecoveryDelayError::new(
+                        recovery_delay_ms,
+                        min_recovery_delay_ms,
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        IdentityPublicKeyInCreation::validate_identity_public_keys_structure(
+            new_master_keys,
+            platform_version,
+        )
+        .map_err(Error::Protocol)
+    }
+}
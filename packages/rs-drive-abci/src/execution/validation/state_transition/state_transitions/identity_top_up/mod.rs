@@ -2,6 +2,7 @@ pub(crate) mod identity_retrieval;
 mod state;
 mod structure;
 
+use dpp::block::block_info::BlockInfo;
 use dpp::state_transition::identity_topup_transition::IdentityTopUpTransition;
 use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
 use dpp::version::PlatformVersion;
@@ -30,6 +31,7 @@ impl StateTransitionActionTransformerV0 for IdentityTopUpTransition {
     fn transform_into_action<C: CoreRPCLike>(
         &self,
         platform: &PlatformRef<C>,
+        _block_info: &BlockInfo,
         _validate: bool,
         execution_context: &mut StateTransitionExecutionContext,
         _tx: TransactionArg,
@@ -9,7 +9,9 @@ use dpp::consensus::state::state_error::StateError;
 
 use dpp::prelude::ConsensusValidationResult;
 
-use dpp::state_transition::identity_update_transition::accessors::IdentityUpdateTransitionAccessorsV0;
+use dpp::state_transition::identity_update_transition::accessors::{
+    IdentityUpdateTransitionAccessorsV0, IdentityUpdateTransitionAccessorsV1,
+};
 use dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
 use dpp::validation::block_time_window::validate_time_in_block_time_window::validate_time_in_block_time_window;
 use dpp::version::PlatformVersion;
@@ -189,6 +191,39 @@ impl IdentityUpdateStateTransitionStateValidationV0 for IdentityUpdateTransition
                 }
             }
         }
+
+        if !self.public_keys_spending_limits().is_empty() {
+            // Spending limits can only be set on keys of the identity
+            let key_ids = self
+                .public_keys_spending_limits()
+                .iter()
+                .map(|(key_id, _)| *key_id)
+                .collect::<Vec<_>>();
+
+            validation_result.add_errors(
+                validate_identity_public_key_ids_exist_in_state(
+                    self.identity_id(),
+                    &key_ids,
+                    drive,
+                    &mut state_transition_execution_context,
+                    tx,
+                    platform_version,
+                )?
+                .errors,
+            );
+
+            if !validation_result.is_valid() {
+                let bump_action = StateTransitionAction::BumpIdentityNonceAction(
+                    BumpIdentityNonceAction::from_borrowed_identity_update_transition(self)?,
+                );
+
+                return Ok(ConsensusValidationResult::new_with_data_and_errors(
+                    bump_action,
+                    validation_result.errors,
+                ));
+            }
+        }
+
         self.transform_into_action_v0()
     }
 
@@ -1,11 +1,13 @@
 use crate::error::Error;
 use dpp::consensus::basic::identity::{
     DuplicatedIdentityPublicKeyIdBasicError, InvalidIdentityUpdateTransitionDisableKeysError,
-    InvalidIdentityUpdateTransitionEmptyError,
+    InvalidIdentityUpdateTransitionEmptyError, InvalidIdentityUpdateTransitionKeyRotationError,
 };
 use dpp::consensus::state::identity::max_identity_public_key_limit_reached_error::MaxIdentityPublicKeyLimitReachedError;
 use dpp::consensus::ConsensusError;
-use dpp::state_transition::identity_update_transition::accessors::IdentityUpdateTransitionAccessorsV0;
+use dpp::state_transition::identity_update_transition::accessors::{
+    IdentityUpdateTransitionAccessorsV0, IdentityUpdateTransitionAccessorsV1,
+};
 use dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
 use dpp::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
 use dpp::validation::SimpleConsensusValidationResult;
@@ -14,6 +16,10 @@ use std::collections::HashSet;
 
 const MAX_KEYS_TO_DISABLE: usize = 10;
 
+const MAX_KEYS_TO_UPDATE_SPENDING_LIMIT: usize = 10;
+
+const MAX_KEY_ROTATION_GRACE_PERIOD_MS: u64 = 24 * 60 * 60 * 1000;
+
 pub(in crate::execution::validation::state_transition::state_transitions::identity_update) trait IdentityUpdateStateTransitionStructureValidationV0
 {
     fn validate_base_structure_v0(
@@ -29,8 +35,11 @@ impl IdentityUpdateStateTransitionStructureValidationV0 for IdentityUpdateTransi
     ) -> Result<SimpleConsensusValidationResult, Error> {
         let mut result = SimpleConsensusValidationResult::default();
 
-        // Ensure that either disablePublicKeys or addPublicKeys is present
-        if self.public_key_ids_to_disable().is_empty() && self.public_keys_to_add().is_empty() {
+        // Ensure that either disablePublicKeys, addPublicKeys or publicKeysSpendingLimits is present
+        if self.public_key_ids_to_disable().is_empty()
+            && self.public_keys_to_add().is_empty()
+            && self.public_keys_spending_limits().is_empty()
+        {
             result.add_error(ConsensusError::from(
                 InvalidIdentityUpdateTransitionEmptyError::new(),
             ));
@@ -75,6 +84,39 @@ impl IdentityUpdateStateTransitionStructureValidationV0 for IdentityUpdateTransi
             ))
         }
 
+        // Keys can only be disabled with a grace period when they are rotated to replacement keys
+        if let Some(grace_period_ms) = self.public_keys_disable_grace_period_ms() {
+            if self.public_key_ids_to_disable().is_empty()
+                || self.public_keys_to_add().is_empty()
+                || grace_period_ms == 0
+                || grace_period_ms > MAX_KEY_ROTATION_GRACE_PERIOD_MS
+            {
+                result.add_error(ConsensusError::from(
+                    InvalidIdentityUpdateTransitionKeyRotationError::new(
+                        grace_period_ms,
+                        MAX_KEY_ROTATION_GRACE_PERIOD_MS,
+                    ),
+                ));
+            }
+        }
+
+        // Validate spending limits of public keys
+        if self.public_keys_spending_limits().len() > MAX_KEYS_TO_UPDATE_SPENDING_LIMIT {
+            result.add_error(ConsensusError::from(
+                MaxIdentityPublicKeyLimitReachedError::new(MAX_KEYS_TO_UPDATE_SPENDING_LIMIT),
+            ));
+        }
+
+        let mut ids = HashSet::new();
+        for (key_id, _) in self.public_keys_spending_limits() {
+            if !ids.insert(key_id) {
+                result.add_error(ConsensusError::from(
+                    DuplicatedIdentityPublicKeyIdBasicError::new(vec![*key_id]),
+                ));
+                break;
+            }
+        }
+
         if !result.is_valid() {
             return Ok(result);
         }
@@ -10,6 +10,9 @@ pub mod identity_credit_transfer;
 /// Module for managing withdrawals of credit from an identity entity.
 pub mod identity_credit_withdrawal;
 
+/// Module for recovering an identity through the guardians holding its recovery keys.
+pub mod identity_recover;
+
 /// Module for topping up credit in an identity entity.
 pub mod identity_top_up;
 
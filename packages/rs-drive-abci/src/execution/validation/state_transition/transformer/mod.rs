@@ -2,6 +2,7 @@ use crate::error::Error;
 use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
 use crate::platform_types::platform::PlatformRef;
 use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
 use dpp::prelude::ConsensusValidationResult;
 use dpp::state_transition::StateTransition;
 use drive::grovedb::TransactionArg;
@@ -21,6 +22,7 @@ pub trait StateTransitionActionTransformerV0 {
     ///
     /// # Arguments
     /// * `platform`: A reference to a platform implementing CoreRPCLike.
+    /// * `block_info`: The block the state transition is executed in.
     /// * `tx`: The `TransactionArg` to be transformed into a `StateTransitionAction`.
     ///
     /// # Returns
@@ -28,6 +30,7 @@ pub trait StateTransitionActionTransformerV0 {
     fn transform_into_action<C: CoreRPCLike>(
         &self,
         platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
         validate: bool,
         execution_context: &mut StateTransitionExecutionContext,
         tx: TransactionArg,
@@ -38,34 +41,38 @@ impl StateTransitionActionTransformerV0 for StateTransition {
     fn transform_into_action<C: CoreRPCLike>(
         &self,
         platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
         validate: bool,
         execution_context: &mut StateTransitionExecutionContext,
         tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
         match self {
             StateTransition::DataContractCreate(st) => {
-                st.transform_into_action(platform, validate, execution_context, tx)
+                st.transform_into_action(platform, block_info, validate, execution_context, tx)
             }
             StateTransition::DataContractUpdate(st) => {
-                st.transform_into_action(platform, validate, execution_context, tx)
+                st.transform_into_action(platform, block_info, validate, execution_context, tx)
             }
             StateTransition::IdentityCreate(st) => {
-                st.transform_into_action(platform, validate, execution_context, tx)
+                st.transform_into_action(platform, block_info, validate, execution_context, tx)
             }
             StateTransition::IdentityUpdate(st) => {
-                st.transform_into_action(platform, validate, execution_context, tx)
+                st.transform_into_action(platform, block_info, validate, execution_context, tx)
             }
             StateTransition::IdentityTopUp(st) => {
-                st.transform_into_action(platform, validate, execution_context, tx)
+                st.transform_into_action(platform, block_info, validate, execution_context, tx)
             }
             StateTransition::IdentityCreditWithdrawal(st) => {
-                st.transform_into_action(platform, validate, execution_context, tx)
+                st.transform_into_action(platform, block_info, validate, execution_context, tx)
             }
             StateTransition::DocumentsBatch(st) => {
-                st.transform_into_action(platform, validate, execution_context, tx)
+                st.transform_into_action(platform, block_info, validate, execution_context, tx)
             }
             StateTransition::IdentityCreditTransfer(st) => {
-                st.transform_into_action(platform, validate, execution_context, tx)
+                st.transform_into_action(platform, block_info, validate, execution_context, tx)
+            }
+            StateTransition::IdentityRecover(st) => {
+                st.transform_into_action(platform, block_info, validate, execution_context, tx)
             }
         }
     }
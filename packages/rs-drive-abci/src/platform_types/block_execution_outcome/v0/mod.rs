@@ -1,4 +1,5 @@
 use crate::abci::AbciError;
+use crate::platform_types::document_changes::BlockDocumentChanges;
 use crate::platform_types::state_transitions_processing_result::StateTransitionsProcessingResult;
 use dpp::util::deserializer::ProtocolVersion;
 use dpp::validation::SimpleValidationResult;
@@ -25,10 +26,15 @@ pub struct BlockFinalizationOutcome {
     /// Errors here can happen if the block that we receive to be finalized isn't actually
     /// the one we expect, this could be a replay attack or some other kind of attack.
     pub validation_result: SimpleValidationResult<AbciError>,
+    /// The documents changed by the finalized block, to be published once it's committed
+    pub document_changes: Option<BlockDocumentChanges>,
 }
 
 impl From<SimpleValidationResult<AbciError>> for BlockFinalizationOutcome {
     fn from(validation_result: SimpleValidationResult<AbciError>) -> Self {
-        BlockFinalizationOutcome { validation_result }
+        BlockFinalizationOutcome {
+            validation_result,
+            document_changes: None,
+        }
     }
 }
@@ -7,7 +7,7 @@ use dpp::platform_value::Bytes32;
 use std::fmt;
 use tenderdash_abci::proto::abci::{RequestPrepareProposal, RequestProcessProposal};
 use tenderdash_abci::proto::serializers::timestamp::ToMilis;
-use tenderdash_abci::proto::types::CoreChainLock;
+use tenderdash_abci::proto::types::{CoreChainLock, VoteExtension};
 use tenderdash_abci::proto::version::Consensus;
 
 /// The block proposal is the combination of information that a proposer will propose,
@@ -35,6 +35,9 @@ pub struct BlockProposal<'a> {
     pub validator_set_quorum_hash: [u8; 32],
     /// The raw state transitions inside a block proposal
     pub raw_state_transitions: &'a Vec<Vec<u8>>,
+    /// The threshold vote extensions of the last commit, they hold the quorum signatures of
+    /// the withdrawal transactions of the previous block
+    pub last_commit_threshold_vote_extensions: &'a [VoteExtension],
 }
 
 impl<'a> fmt::Debug for BlockProposal<'a> {
@@ -84,7 +87,7 @@ impl<'a> TryFrom<&'a RequestPrepareProposal> for BlockProposal<'a> {
         let RequestPrepareProposal {
             max_tx_bytes: _,
             txs,
-            local_last_commit: _,
+            local_last_commit,
             misbehavior: _,
             height,
             time,
@@ -147,6 +150,10 @@ impl<'a> TryFrom<&'a RequestPrepareProposal> for BlockProposal<'a> {
 
             block_time_ms,
             raw_state_transitions: txs,
+            last_commit_threshold_vote_extensions: local_last_commit
+                .as_ref()
+                .map(|commit| commit.threshold_vote_extensions.as_slice())
+                .unwrap_or_default(),
         })
     }
 }
@@ -157,7 +164,7 @@ impl<'a> TryFrom<&'a RequestProcessProposal> for BlockProposal<'a> {
     fn try_from(value: &'a RequestProcessProposal) -> Result<Self, Self::Error> {
         let RequestProcessProposal {
             txs,
-            proposed_last_commit: _,
+            proposed_last_commit,
             misbehavior: _,
             hash,
             height,
@@ -250,6 +257,10 @@ impl<'a> TryFrom<&'a RequestProcessProposal> for BlockProposal<'a> {
             validator_set_quorum_hash,
             block_time_ms,
             raw_state_transitions: txs,
+            last_commit_threshold_vote_extensions: proposed_last_commit
+                .as_ref()
+                .map(|commit| commit.threshold_vote_extensions.as_slice())
+                .unwrap_or_default(),
         })
     }
 }
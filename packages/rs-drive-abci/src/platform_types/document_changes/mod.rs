@@ -0,0 +1,63 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::document::Document;
+use dpp::identifier::Identifier;
+use dpp::state_transition::documents_batch_transition::document_transition::{
+    DocumentTransition, DocumentTransitionV0Methods,
+};
+
+/// The way a document was changed by a state transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentChangeType {
+    /// The document was created
+    Created,
+    /// The document was replaced, transferred, repriced or purchased
+    Replaced,
+    /// The document was deleted
+    Deleted,
+}
+
+/// A document changed by a successfully executed documents batch
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentChange {
+    /// The data contract of the document
+    pub data_contract_id: Identifier,
+    /// The document type of the document
+    pub document_type_name: String,
+    /// The id of the document
+    pub document_id: Identifier,
+    /// How the document was changed
+    pub change_type: DocumentChangeType,
+    /// The document as stored by the state transition, never set for deleted documents.
+    /// It's only kept when somebody is subscribed to document changes while the block is executed.
+    pub document: Option<Document>,
+}
+
+impl From<&DocumentTransition> for DocumentChange {
+    fn from(transition: &DocumentTransition) -> Self {
+        let change_type = match transition {
+            DocumentTransition::Create(_) => DocumentChangeType::Created,
+            DocumentTransition::Delete(_) => DocumentChangeType::Deleted,
+            DocumentTransition::Replace(_)
+            | DocumentTransition::Transfer(_)
+            | DocumentTransition::UpdatePrice(_)
+            | DocumentTransition::Purchase(_) => DocumentChangeType::Replaced,
+        };
+
+        DocumentChange {
+            data_contract_id: transition.data_contract_id(),
+            document_type_name: transition.document_type_name().clone(),
+            document_id: transition.get_id(),
+            change_type,
+            document: None,
+        }
+    }
+}
+
+/// The documents changed by a committed block
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockDocumentChanges {
+    /// The committed block
+    pub block_info: BlockInfo,
+    /// The changes in the order they were executed
+    pub changes: Vec<DocumentChange>,
+}
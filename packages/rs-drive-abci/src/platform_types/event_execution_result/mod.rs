@@ -1,15 +1,21 @@
 use dpp::validation::SimpleConsensusValidationResult;
 
 use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
+use std::collections::BTreeMap;
 
 /// The Fee Result for a Dry Run (without state)
 pub type EstimatedFeeResult = FeeResult;
 
+/// The part of the actual Fee Result of a documents batch spent on each data contract
+pub type FeeResultsByContract = BTreeMap<Identifier, FeeResult>;
+
 /// An execution result
 #[derive(Debug, Clone)]
 pub enum EventExecutionResult {
-    /// Successfully executed a paid event
-    SuccessfulPaidExecution(EstimatedFeeResult, FeeResult),
+    /// Successfully executed a paid event, with the fees of every data contract written by
+    /// a documents batch
+    SuccessfulPaidExecution(EstimatedFeeResult, FeeResult, FeeResultsByContract),
     /// Successfully executed a free event
     SuccessfulFreeExecution,
     /// There were consensus errors when trying to execute an event
@@ -35,6 +35,8 @@ pub mod block_proposal;
 pub mod cleaned_abci_messages;
 /// The commit
 pub mod commit;
+/// Documents changed by a block
+pub mod document_changes;
 /// Epoch
 pub mod epoch_info;
 /// The execution event result
@@ -10,16 +10,21 @@ use crate::rpc::core::MockCoreRPCLike;
 use drive::drive::defaults::INITIAL_PROTOCOL_VERSION;
 use std::path::Path;
 use std::str::FromStr;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
+use tokio::sync::broadcast;
 
 use dashcore_rpc::dashcore::BlockHash;
 
 use crate::execution::types::block_execution_context::BlockExecutionContext;
+use crate::platform_types::document_changes::BlockDocumentChanges;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
 use dpp::version::{PlatformVersion, PlatformVersionCurrentVersion};
 use serde_json::json;
 
+/// The number of committed blocks kept for document subscriptions that didn't receive them yet
+const DOCUMENT_CHANGES_CHANNEL_CAPACITY: usize = 64;
+
 /// Platform is not versioned as it holds the main logic, we could not switch from one structure
 /// configuration of the Platform struct to another without a software upgrade
 
@@ -40,6 +45,8 @@ pub struct Platform<C> {
     pub block_execution_context: RwLock<Option<BlockExecutionContext>>,
     /// Core RPC Client
     pub core_rpc: C,
+    /// Publishes the documents changed by each committed block to document subscriptions
+    pub document_changes_sender: broadcast::Sender<Arc<BlockDocumentChanges>>,
 }
 
 // @append_only
@@ -228,6 +235,7 @@ impl<C> Platform<C> {
             config,
             block_execution_context: RwLock::new(None),
             core_rpc,
+            document_changes_sender: broadcast::channel(DOCUMENT_CHANGES_CHANNEL_CAPACITY).0,
         };
 
         Ok(platform)
@@ -257,6 +265,7 @@ impl<C> Platform<C> {
             config,
             block_execution_context: RwLock::new(None),
             core_rpc,
+            document_changes_sender: broadcast::channel(DOCUMENT_CHANGES_CHANNEL_CAPACITY).0,
         })
     }
 }
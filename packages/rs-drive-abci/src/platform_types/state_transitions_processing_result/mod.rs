@@ -1,7 +1,8 @@
 use dpp::consensus::ConsensusError;
 
 use crate::error::Error;
-use crate::platform_types::event_execution_result::EstimatedFeeResult;
+use crate::platform_types::document_changes::DocumentChange;
+use crate::platform_types::event_execution_result::{EstimatedFeeResult, FeeResultsByContract};
 use dpp::fee::fee_result::FeeResult;
 
 /// State Transition Execution Result represents a result of the single state transition execution.
@@ -19,8 +20,9 @@ pub enum StateTransitionExecutionResult {
     UnpaidConsensusError(ConsensusError),
     /// State Transition execution failed due to the internal drive-abci error
     DriveAbciError(String),
-    /// State Transition was successfully executed
-    SuccessfulExecution(EstimatedFeeResult, FeeResult),
+    /// State Transition was successfully executed, the fees of a documents batch are also
+    /// given for every data contract it writes to
+    SuccessfulExecution(EstimatedFeeResult, FeeResult, FeeResultsByContract),
 }
 
 /// State Transitions Processing Result produced by [process_raw_state_transitions] and represents
@@ -34,6 +36,7 @@ pub struct StateTransitionsProcessingResult {
     valid_count: usize,
     failed_count: usize,
     fees: FeeResult,
+    document_changes: Vec<DocumentChange>,
 }
 
 impl StateTransitionsProcessingResult {
@@ -50,7 +53,7 @@ impl StateTransitionsProcessingResult {
             StateTransitionExecutionResult::UnpaidConsensusError(_) => {
                 self.invalid_unpaid_count += 1;
             }
-            StateTransitionExecutionResult::SuccessfulExecution(_, actual_fees) => {
+            StateTransitionExecutionResult::SuccessfulExecution(_, actual_fees, _) => {
                 self.valid_count += 1;
 
                 self.fees.checked_add_assign(actual_fees.clone())?;
@@ -62,6 +65,11 @@ impl StateTransitionsProcessingResult {
         Ok(())
     }
 
+    /// Add the documents changed by a successfully executed state transition
+    pub fn add_document_changes(&mut self, document_changes: Vec<DocumentChange>) {
+        self.document_changes.extend(document_changes);
+    }
+
     /// Returns the number of paid invalid state transitions
     pub fn invalid_paid_count(&self) -> usize {
         self.invalid_paid_count
@@ -87,6 +95,11 @@ impl StateTransitionsProcessingResult {
         &self.fees
     }
 
+    /// Returns the documents changed by the successfully executed state transitions
+    pub fn document_changes(&self) -> &[DocumentChange] {
+        &self.document_changes
+    }
+
     /// Transform into the state transition execution results
     pub fn into_execution_results(self) -> Vec<StateTransitionExecutionResult> {
         self.execution_results
@@ -49,7 +49,7 @@ impl UnsignedWithdrawalTxs {
 
     /// Verifies that the collection of unsigned withdrawal transactions matches the given vote extensions
     /// created based on these transactions
-    pub fn are_matching_with_vote_extensions(&self, other: &Vec<VoteExtension>) -> bool {
+    pub fn are_matching_with_vote_extensions(&self, other: &[VoteExtension]) -> bool {
         if self.0.len() != other.len() {
             return false;
         };
@@ -0,0 +1,336 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_documents_aggregate_request::GetDocumentsAggregateRequestV0;
+use dapi_grpc::platform::v0::get_documents_aggregate_response::{
+    get_documents_aggregate_response_v0, GetDocumentsAggregateResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::identifier::Identifier;
+use dpp::platform_value::Value;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+use drive::query::{AggregateFunction, DriveQuery};
+
+impl<C> Platform<C> {
+    pub(super) fn query_documents_aggregate_v0(
+        &self,
+        GetDocumentsAggregateRequestV0 {
+            data_contract_id,
+            document_type: document_type_name,
+            r#where,
+            order_by,
+            limit,
+            sum_property,
+            prove,
+        }: GetDocumentsAggregateRequestV0,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetDocumentsAggregateResponseV0>, Error> {
+        let contract_id: Identifier = check_validation_result_with_data!(data_contract_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let (_, contract) = self.drive.get_contract_with_fetch_info_and_fee(
+            contract_id.to_buffer(),
+            None,
+            true,
+            None,
+            platform_version,
+        )?;
+
+        let contract = check_validation_result_with_data!(contract.ok_or(QueryError::Query(
+            QuerySyntaxError::DataContractNotFound(
+                "contract not found when querying from value with contract info",
+            )
+        )));
+
+        let contract_ref = &contract.contract;
+
+        let document_type = check_validation_result_with_data!(contract_ref
+            .document_type_for_name(document_type_name.as_str())
+            .map_err(|_| QueryError::InvalidArgument(format!(
+                "document type {} not found for contract {}",
+                document_type_name, contract_id
+            ))));
+
+        let where_clause = if r#where.is_empty() {
+            Value::Null
+        } else {
+            check_validation_result_with_data!(ciborium::de::from_reader(r#where.as_slice())
+                .map_err(|_| {
+                    QueryError::Query(QuerySyntaxError::DeserializationError(
+                        "unable to decode 'where' query from cbor".to_string(),
+                    ))
+                }))
+        };
+
+        let order_by = if !order_by.is_empty() {
+            check_validation_result_with_data!(ciborium::de::from_reader(order_by.as_slice())
+                .map_err(|_| {
+                    QueryError::Query(QuerySyntaxError::DeserializationError(
+                        "unable to decode 'order_by' query from cbor".to_string(),
+                    ))
+                }))
+        } else {
+            None
+        };
+
+        // aggregates cover every matched document
+        if limit != 0 {
+            return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                QuerySyntaxError::InvalidLimit(
+                    "aggregate queries can not have a limit".to_string(),
+                ),
+            )));
+        }
+
+        let mut drive_query =
+            check_validation_result_with_data!(DriveQuery::from_decomposed_values(
+                where_clause,
+                order_by,
+                None,
+                None,
+                true,
+                None,
+                contract_ref,
+                document_type,
+                &self.config.drive,
+                platform_version,
+            ));
+        drive_query.limit = None;
+
+        let function = if sum_property.is_empty() {
+            AggregateFunction::Count
+        } else {
+            AggregateFunction::Sum(sum_property)
+        };
+
+        let response = if prove {
+            let proof = match drive_query.execute_aggregate_with_proof(
+                &function,
+                &self.drive,
+                None,
+                None,
+                platform_version,
+            ) {
+                Ok(result) => result.0,
+                Err(drive::error::Error::Query(query_error)) => {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        query_error,
+                    )));
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            let (metadata, proof) = self.response_metadata_and_proof_v0(proof);
+
+            GetDocumentsAggregateResponseV0 {
+                result: Some(get_documents_aggregate_response_v0::Result::Proof(proof)),
+                metadata: Some(metadata),
+            }
+        } else {
+            let value = match drive_query.execute_aggregate_no_proof(
+                &function,
+                &self.drive,
+                None,
+                None,
+                platform_version,
+            ) {
+                Ok(result) => result.0,
+                Err(drive::error::Error::Query(query_error)) => {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        query_error,
+                    )));
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            GetDocumentsAggregateResponseV0 {
+                result: Some(get_documents_aggregate_response_v0::Result::Value(value)),
+                metadata: Some(self.response_metadata_v0()),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::{assert_invalid_identifier, setup_platform, store_data_contract};
+    use dpp::tests::fixtures::get_data_contract_fixture;
+
+    #[test]
+    fn test_invalid_document_id() {
+        let (platform, version) = setup_platform();
+
+        let request = GetDocumentsAggregateRequestV0 {
+            data_contract_id: vec![0; 8],
+            document_type: "niceDocument".to_string(),
+            r#where: vec![],
+            order_by: vec![],
+            limit: 0,
+            sum_property: "".to_string(),
+            prove: false,
+        };
+
+        let result = platform
+            .query_documents_aggregate_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert_invalid_identifier(result);
+    }
+
+    #[test]
+    fn test_data_contract_not_found_in_documents_aggregate_request() {
+        let (platform, version) = setup_platform();
+
+        let request = GetDocumentsAggregateRequestV0 {
+            data_contract_id: vec![0; 32],
+            document_type: "niceDocument".to_string(),
+            r#where: vec![],
+            order_by: vec![],
+            limit: 0,
+            sum_property: "".to_string(),
+            prove: false,
+        };
+
+        let result = platform
+            .query_documents_aggregate_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::Query(QuerySyntaxError::DataContractNotFound(msg))] if msg == &"contract not found when querying from value with contract info"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_limit() {
+        let (platform, version) = setup_platform();
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let data_contract_id = created_data_contract.data_contract().id();
+        let limit = 10;
+
+        let request = GetDocumentsAggregateRequestV0 {
+            data_contract_id: data_contract_id.to_vec(),
+            document_type: "niceDocument".to_string(),
+            r#where: vec![],
+            order_by: vec![],
+            limit,
+            sum_property: "".to_string(),
+            prove: false,
+        };
+
+        let result = platform
+            .query_documents_aggregate_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::Query(QuerySyntaxError::InvalidLimit(msg))] if msg == "aggregate queries can not have a limit"
+        ))
+    }
+
+    #[test]
+    fn test_sum_without_sum_index() {
+        let (platform, version) = setup_platform();
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let data_contract_id = created_data_contract.data_contract().id();
+
+        let request = GetDocumentsAggregateRequestV0 {
+            data_contract_id: data_contract_id.to_vec(),
+            document_type: "niceDocument".to_string(),
+            r#where: vec![],
+            order_by: vec![],
+            limit: 0,
+            sum_property: "name".to_string(),
+            prove: false,
+        };
+
+        let result = platform
+            .query_documents_aggregate_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::Query(QuerySyntaxError::NoSumIndexForQuery(_))]
+        ));
+    }
+
+    #[test]
+    fn test_count_documents_not_found() {
+        let (platform, version) = setup_platform();
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let data_contract_id = created_data_contract.data_contract().id();
+
+        let request = GetDocumentsAggregateRequestV0 {
+            data_contract_id: data_contract_id.to_vec(),
+            document_type: "niceDocument".to_string(),
+            r#where: vec![],
+            order_by: vec![],
+            limit: 0,
+            sum_property: "".to_string(),
+            prove: false,
+        };
+
+        let result = platform
+            .query_documents_aggregate_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.data,
+            Some(GetDocumentsAggregateResponseV0 {
+                result: Some(get_documents_aggregate_response_v0::Result::Value(0)),
+                metadata: Some(_),
+            })
+        ));
+    }
+
+    #[test]
+    fn test_count_absence_proof() {
+        let (platform, version) = setup_platform();
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let data_contract_id = created_data_contract.data_contract().id();
+
+        let request = GetDocumentsAggregateRequestV0 {
+            data_contract_id: data_contract_id.to_vec(),
+            document_type: "niceDocument".to_string(),
+            r#where: vec![],
+            order_by: vec![],
+            limit: 0,
+            sum_property: "".to_string(),
+            prove: true,
+        };
+
+        let result = platform
+            .query_documents_aggregate_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.data,
+            Some(GetDocumentsAggregateResponseV0 {
+                result: Some(get_documents_aggregate_response_v0::Result::Proof(_)),
+                metadata: Some(_),
+            })
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::document_changes::BlockDocumentChanges;
+use crate::platform_types::platform::Platform;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::subscribe_to_documents_request::Version as RequestVersion;
+use dapi_grpc::platform::v0::subscribe_to_documents_response::Version as ResponseVersion;
+use dapi_grpc::platform::v0::{SubscribeToDocumentsRequest, SubscribeToDocumentsResponse};
+use derive_more::From;
+use dpp::version::PlatformVersion;
+
+mod v0;
+
+pub use v0::DocumentsSubscriptionV0;
+
+/// A validated subscription to the documents changed by each committed block
+#[derive(Debug, Clone, From)]
+pub enum DocumentsSubscription {
+    /// Version 0
+    V0(DocumentsSubscriptionV0),
+}
+
+impl<C> Platform<C> {
+    /// Validates a subscription to document changes
+    pub fn subscribe_to_documents(
+        &self,
+        SubscribeToDocumentsRequest { version }: SubscribeToDocumentsRequest,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<DocumentsSubscription>, Error> {
+        let Some(version) = version else {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::DecodingError("could not decode documents subscription".to_string()),
+            ));
+        };
+
+        let feature_version_bounds = &platform_version.drive_abci.query.document_subscription;
+
+        let feature_version = match &version {
+            RequestVersion::V0(_) => 0,
+        };
+        if !feature_version_bounds.check_version(feature_version) {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::UnsupportedQueryVersion(
+                    "document_subscription".to_string(),
+                    feature_version_bounds.min_version,
+                    feature_version_bounds.max_version,
+                    platform_version.protocol_version,
+                    feature_version,
+                ),
+            ));
+        }
+        match version {
+            RequestVersion::V0(request_v0) => {
+                let result = self.subscribe_to_documents_v0(request_v0, platform_version)?;
+
+                Ok(result.map(DocumentsSubscription::V0))
+            }
+        }
+    }
+
+    /// The response to a subscription for the documents changed by a committed block,
+    /// `None` if none of the changed documents match the subscription
+    pub fn documents_subscription_response(
+        &self,
+        subscription: &DocumentsSubscription,
+        block_document_changes: &BlockDocumentChanges,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<SubscribeToDocumentsResponse>, Error> {
+        match subscription {
+            DocumentsSubscription::V0(subscription_v0) => {
+                let response_v0 = self.documents_subscription_response_v0(
+                    subscription_v0,
+                    block_document_changes,
+                    platform_version,
+                )?;
+
+                Ok(response_v0.map(|response_v0| SubscribeToDocumentsResponse {
+                    version: Some(ResponseVersion::V0(response_v0)),
+                }))
+            }
+        }
+    }
+}
@@ -0,0 +1,403 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::document_changes::{BlockDocumentChanges, DocumentChangeType};
+use crate::platform_types::platform::Platform;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::subscribe_to_documents_request::SubscribeToDocumentsRequestV0;
+use dapi_grpc::platform::v0::subscribe_to_documents_response::subscribe_to_documents_response_v0::{
+    ChangeType, DocumentChange,
+};
+use dapi_grpc::platform::v0::subscribe_to_documents_response::SubscribeToDocumentsResponseV0;
+use dpp::check_validation_result_with_data;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::identifier::Identifier;
+use dpp::platform_value::Value;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+use drive::query::{DriveQuery, SingleDocumentDriveQuery};
+
+/// A validated subscription to the documents of a document type matching a where clause
+#[derive(Debug, Clone)]
+pub struct DocumentsSubscriptionV0 {
+    /// The data contract of the documents
+    pub data_contract_id: Identifier,
+    /// The document type of the documents
+    pub document_type_name: String,
+    /// The where clause changed documents must match
+    pub where_clause: Value,
+    /// Should the changed documents be proved
+    pub prove: bool,
+}
+
+impl<C> Platform<C> {
+    pub(super) fn subscribe_to_documents_v0(
+        &self,
+        SubscribeToDocumentsRequestV0 {
+            data_contract_id,
+            document_type: document_type_name,
+            r#where,
+            prove,
+        }: SubscribeToDocumentsRequestV0,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<DocumentsSubscriptionV0>, Error> {
+        let contract_id: Identifier = check_validation_result_with_data!(data_contract_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let (_, contract) = self.drive.get_contract_with_fetch_info_and_fee(
+            contract_id.to_buffer(),
+            None,
+            true,
+            None,
+            platform_version,
+        )?;
+
+        let contract = check_validation_result_with_data!(contract.ok_or(QueryError::Query(
+            QuerySyntaxError::DataContractNotFound(
+                "contract not found when querying from value with contract info",
+            )
+        )));
+
+        let contract_ref = &contract.contract;
+
+        let document_type = check_validation_result_with_data!(contract_ref
+            .document_type_for_name(document_type_name.as_str())
+            .map_err(|_| QueryError::InvalidArgument(format!(
+                "document type {} not found for contract {}",
+                document_type_name, contract_id
+            ))));
+
+        let where_clause = if r#where.is_empty() {
+            Value::Null
+        } else {
+            check_validation_result_with_data!(ciborium::de::from_reader(r#where.as_slice())
+                .map_err(|_| {
+                    QueryError::Query(QuerySyntaxError::DeserializationError(
+                        "unable to decode 'where' query from cbor".to_string(),
+                    ))
+                }))
+        };
+
+        // Make sure the where clause is a valid query for the document type
+        check_validation_result_with_data!(DriveQuery::from_decomposed_values(
+            where_clause.clone(),
+            None,
+            None,
+            None,
+            true,
+            None,
+            contract_ref,
+            document_type,
+            &self.config.drive,
+            platform_version,
+        ));
+
+        Ok(QueryValidationResult::new_with_data(
+            DocumentsSubscriptionV0 {
+                data_contract_id: contract_id,
+                document_type_name,
+                where_clause,
+                prove,
+            },
+        ))
+    }
+
+    /// Deleted documents can't be matched against the where clause anymore, so every deletion
+    /// of the subscribed document type is sent.
+    ///
+    /// When requested, the proof is of the changed documents in the latest committed state and
+    /// the metadata is of that state, not of the block of the changes.
+    pub(super) fn documents_subscription_response_v0(
+        &self,
+        subscription: &DocumentsSubscriptionV0,
+        block_document_changes: &BlockDocumentChanges,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<SubscribeToDocumentsResponseV0>, Error> {
+        let mut subscribed_changes = block_document_changes
+            .changes
+            .iter()
+            .filter(|change| {
+                change.data_contract_id == subscription.data_contract_id
+                    && change.document_type_name == subscription.document_type_name
+            })
+            .peekable();
+
+        if subscribed_changes.peek().is_none() {
+            return Ok(None);
+        }
+
+        let Some(contract) = self.drive.get_contract_with_fetch_info(
+            subscription.data_contract_id.to_buffer(),
+            false,
+            None,
+            platform_version,
+        )?
+        else {
+            return Ok(None);
+        };
+
+        let document_type = contract
+            .contract
+            .document_type_for_name(subscription.document_type_name.as_str())?;
+
+        let drive_query = DriveQuery::from_decomposed_values(
+            subscription.where_clause.clone(),
+            None,
+            None,
+            None,
+            true,
+            None,
+            &contract.contract,
+            document_type,
+            &self.config.drive,
+            platform_version,
+        )?;
+
+        let mut changes = Vec::new();
+        let mut document_queries = Vec::new();
+
+        for change in subscribed_changes {
+            let (change_type, document) = match change.change_type {
+                DocumentChangeType::Deleted => (ChangeType::Deleted, vec![]),
+                DocumentChangeType::Created | DocumentChangeType::Replaced => {
+                    // Nobody was subscribed yet when the document was changed
+                    let Some(document) = &change.document else {
+                        continue;
+                    };
+
+                    if !drive_query.matches_document(document, platform_version)? {
+                        continue;
+                    }
+
+                    let change_type = if change.change_type == DocumentChangeType::Created {
+                        ChangeType::Created
+                    } else {
+                        ChangeType::Replaced
+                    };
+
+                    (
+                        change_type,
+                        document.serialize(document_type, platform_version)?,
+                    )
+                }
+            };
+
+            changes.push(DocumentChange {
+                change_type: change_type as i32,
+                document_id: change.document_id.to_vec(),
+                document,
+            });
+
+            document_queries.push(SingleDocumentDriveQuery {
+                contract_id: subscription.data_contract_id.to_buffer(),
+                document_type_name: subscription.document_type_name.clone(),
+                document_type_keeps_history: document_type.documents_keep_history(),
+                document_id: change.document_id.to_buffer(),
+                block_time_ms: None, //None because we want latest
+            });
+        }
+
+        if changes.is_empty() {
+            return Ok(None);
+        }
+
+        let response = if subscription.prove {
+            // Past states can't be proved, so this proves the changed documents in the latest
+            // committed state, which might already be newer than the block of the changes
+            let proof = self.drive.prove_multiple(
+                &vec![],
+                &[],
+                &document_queries,
+                None,
+                platform_version,
+            )?;
+
+            let (metadata, proof) = self.response_metadata_and_proof_v0(proof);
+
+            SubscribeToDocumentsResponseV0 {
+                changes,
+                proof: Some(proof),
+                metadata: Some(metadata),
+            }
+        } else {
+            SubscribeToDocumentsResponseV0 {
+                changes,
+                proof: None,
+                metadata: Some(self.response_metadata_v0()),
+            }
+        };
+
+        Ok(Some(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform_types::document_changes::DocumentChange as PlatformDocumentChange;
+    use crate::query::tests::{assert_invalid_identifier, setup_platform, store_data_contract};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::document::{DocumentV0Getters, DocumentV0Setters};
+    use dpp::tests::fixtures::get_data_contract_fixture;
+
+    #[test]
+    fn test_invalid_data_contract_id() {
+        let (platform, version) = setup_platform();
+
+        let request = SubscribeToDocumentsRequestV0 {
+            data_contract_id: vec![0; 8],
+            document_type: "niceDocument".to_string(),
+            r#where: vec![],
+            prove: false,
+        };
+
+        let result = platform
+            .subscribe_to_documents_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert_invalid_identifier(result);
+    }
+
+    #[test]
+    fn test_absent_document_type() {
+        let (platform, version) = setup_platform();
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let request = SubscribeToDocumentsRequestV0 {
+            data_contract_id: created_data_contract.data_contract().id().to_vec(),
+            document_type: "fakeDocument".to_string(),
+            r#where: vec![],
+            prove: false,
+        };
+
+        let result = platform
+            .subscribe_to_documents_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg.contains("document type fakeDocument not found for contract")
+        ));
+    }
+
+    #[test]
+    fn test_only_matching_document_changes_are_sent() {
+        let (platform, version) = setup_platform();
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        let data_contract = created_data_contract.data_contract();
+        store_data_contract(&platform, data_contract, version);
+
+        let document_type = data_contract
+            .document_type_for_name("niceDocument")
+            .expect("expected document type");
+
+        let mut where_clause = vec![];
+        ciborium::ser::into_writer(
+            &Value::Array(vec![Value::Array(vec![
+                Value::Text("name".to_string()),
+                Value::Text("==".to_string()),
+                Value::Text("Alice".to_string()),
+            ])]),
+            &mut where_clause,
+        )
+        .expect("expected to serialize where clause");
+
+        let request = SubscribeToDocumentsRequestV0 {
+            data_contract_id: data_contract.id().to_vec(),
+            document_type: "niceDocument".to_string(),
+            r#where: where_clause,
+            prove: false,
+        };
+
+        let subscription = platform
+            .subscribe_to_documents_v0(request, version)
+            .expect("expected query to succeed")
+            .into_data()
+            .expect("expected a valid subscription");
+
+        let mut matching_document = document_type
+            .random_document(Some(1), version)
+            .expect("expected a random document");
+        matching_document.set("name", Value::Text("Alice".to_string()));
+
+        let mut other_document = document_type
+            .random_document(Some(2), version)
+            .expect("expected a random document");
+        other_document.set("name", Value::Text("Bob".to_string()));
+
+        let deleted_document_id = Identifier::random();
+
+        let change = |document_id, change_type, document| PlatformDocumentChange {
+            data_contract_id: data_contract.id(),
+            document_type_name: "niceDocument".to_string(),
+            document_id,
+            change_type,
+            document,
+        };
+
+        let block_document_changes = BlockDocumentChanges {
+            block_info: BlockInfo::genesis(),
+            changes: vec![
+                change(
+                    matching_document.id(),
+                    DocumentChangeType::Created,
+                    Some(matching_document.clone()),
+                ),
+                change(
+                    other_document.id(),
+                    DocumentChangeType::Replaced,
+                    Some(other_document.clone()),
+                ),
+                change(deleted_document_id, DocumentChangeType::Deleted, None),
+            ],
+        };
+
+        let response = platform
+            .documents_subscription_response_v0(&subscription, &block_document_changes, version)
+            .expect("expected response to succeed")
+            .expect("expected matching changes");
+
+        assert_eq!(
+            response.changes,
+            vec![
+                DocumentChange {
+                    change_type: ChangeType::Created as i32,
+                    document_id: matching_document.id().to_vec(),
+                    document: matching_document
+                        .serialize(document_type, version)
+                        .expect("expected to serialize document"),
+                },
+                DocumentChange {
+                    change_type: ChangeType::Deleted as i32,
+                    document_id: deleted_document_id.to_vec(),
+                    document: vec![],
+                },
+            ]
+        );
+        assert!(response.proof.is_none());
+
+        let unrelated_changes = BlockDocumentChanges {
+            block_info: BlockInfo::genesis(),
+            changes: vec![change(
+                other_document.id(),
+                DocumentChangeType::Created,
+                Some(other_document),
+            )],
+        };
+
+        let response = platform
+            .documents_subscription_response_v0(&subscription, &unrelated_changes, version)
+            .expect("expected response to succeed");
+
+        assert!(response.is_none());
+    }
+}
@@ -7,3 +7,5 @@ mod identity_by_public_key_hash;
 mod identity_contract_nonce;
 mod identity_nonce;
 mod keys;
+mod pending_transfers;
+mod withdrawals;
@@ -0,0 +1,55 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_pending_transfers_request::Version as RequestVersion;
+use dapi_grpc::platform::v0::get_pending_transfers_response::Version as ResponseVersion;
+use dapi_grpc::platform::v0::{GetPendingTransfersRequest, GetPendingTransfersResponse};
+use dpp::version::PlatformVersion;
+
+mod v0;
+
+impl<C> Platform<C> {
+    /// Querying of the credit transfers pending for an identity
+    pub fn query_pending_transfers(
+        &self,
+        GetPendingTransfersRequest { version }: GetPendingTransfersRequest,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetPendingTransfersResponse>, Error> {
+        let Some(version) = version else {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::DecodingError("could not decode pending transfers query".to_string()),
+            ));
+        };
+
+        let feature_version_bounds = &platform_version
+            .drive_abci
+            .query
+            .identity_based_queries
+            .pending_transfers;
+
+        let feature_version = match &version {
+            RequestVersion::V0(_) => 0,
+        };
+        if !feature_version_bounds.check_version(feature_version) {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::UnsupportedQueryVersion(
+                    "pending_transfers".to_string(),
+                    feature_version_bounds.min_version,
+                    feature_version_bounds.max_version,
+                    platform_version.protocol_version,
+                    feature_version,
+                ),
+            ));
+        }
+        match version {
+            RequestVersion::V0(request_v0) => {
+                let result = self.query_pending_transfers_v0(request_v0, platform_version)?;
+
+                Ok(result.map(|response_v0| GetPendingTransfersResponse {
+                    version: Some(ResponseVersion::V0(response_v0)),
+                }))
+            }
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_pending_transfers_request::GetPendingTransfersRequestV0;
+use dapi_grpc::platform::v0::get_pending_transfers_response::{
+    get_pending_transfers_response_v0, GetPendingTransfersResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::identifier::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+
+impl<C> Platform<C> {
+    pub(super) fn query_pending_transfers_v0(
+        &self,
+        GetPendingTransfersRequestV0 {
+            identity_id,
+            outgoing,
+            limit,
+            prove,
+        }: GetPendingTransfersRequestV0,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetPendingTransfersResponseV0>, Error> {
+        let identity_id: Identifier = check_validation_result_with_data!(identity_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        if let Some(limit) = limit {
+            if limit > u16::MAX as u32 {
+                return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                    QuerySyntaxError::InvalidParameter("limit out of bounds".to_string()),
+                )));
+            }
+            if limit as u16 > self.config.drive.max_query_limit {
+                return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                    QuerySyntaxError::InvalidLimit(format!(
+                        "limit greater than max limit {}",
+                        self.config.drive.max_query_limit
+                    )),
+                )));
+            }
+        }
+
+        let limit = limit.map(|l| l as u16);
+
+        let response = if prove {
+            let proof = self.drive.prove_pending_transfers_for_identity(
+                identity_id.into_buffer(),
+                outgoing,
+                limit,
+                None,
+                &platform_version.drive,
+            )?;
+
+            let (metadata, proof) = self.response_metadata_and_proof_v0(proof);
+
+            GetPendingTransfersResponseV0 {
+                result: Some(get_pending_transfers_response_v0::Result::Proof(proof)),
+                metadata: Some(metadata),
+            }
+        } else {
+            let pending_transfers = self
+                .drive
+                .fetch_pending_transfers_for_identity(
+                    identity_id.into_buffer(),
+                    outgoing,
+                    limit,
+                    None,
+                    platform_version,
+                )?
+                .iter()
+                .map(|pending_transfer| pending_transfer.serialize_to_bytes())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            GetPendingTransfersResponseV0 {
+                result: Some(get_pending_transfers_response_v0::Result::PendingTransfers(
+                    get_pending_transfers_response_v0::PendingTransfers { pending_transfers },
+                )),
+                metadata: Some(self.response_metadata_v0()),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::{assert_invalid_identifier, setup_platform};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::identity::pending_transfer::{PendingTransfer, TransferReleaseCondition};
+    use dpp::serialization::PlatformDeserializable;
+    use drive::drive::batch::{DriveOperation, PendingTransferOperationType};
+
+    #[test]
+    fn test_invalid_identity_id() {
+        let (platform, version) = setup_platform();
+
+        let request = GetPendingTransfersRequestV0 {
+            identity_id: vec![0; 8],
+            outgoing: false,
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_pending_transfers_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert_invalid_identifier(result);
+    }
+
+    #[test]
+    fn test_invalid_limit_u16_overflow() {
+        let (platform, version) = setup_platform();
+
+        let request = GetPendingTransfersRequestV0 {
+            identity_id: vec![0; 32],
+            outgoing: false,
+            limit: Some(u32::MAX),
+            prove: false,
+        };
+
+        let result = platform
+            .query_pending_transfers_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::Query(QuerySyntaxError::InvalidParameter(msg))] if msg == "limit out of bounds"
+        ));
+    }
+
+    #[test]
+    fn test_pending_transfers() {
+        let (platform, version) = setup_platform();
+
+        let sender_id = Identifier::random();
+        let recipient_id = Identifier::random();
+
+        let pending_transfer = PendingTransfer {
+            id: PendingTransfer::id_from_sender_and_nonce(sender_id, 1),
+            sender_id,
+            recipient_id,
+            amount: 100000,
+            release_condition: TransferReleaseCondition::BlockTime(1000),
+        };
+
+        platform
+            .drive
+            .apply_drive_operations(
+                vec![DriveOperation::PendingTransferOperation(
+                    PendingTransferOperationType::InsertPendingTransfer {
+                        pending_transfer: pending_transfer.clone(),
+                    },
+                )],
+                true,
+                &BlockInfo::default(),
+                None,
+                version,
+            )
+            .expect("expected to insert a pending transfer");
+
+        for (identity_id, outgoing) in [(recipient_id, false), (sender_id, true)] {
+            let request = GetPendingTransfersRequestV0 {
+                identity_id: identity_id.to_vec(),
+                outgoing,
+                limit: None,
+                prove: false,
+            };
+
+            let result = platform
+                .query_pending_transfers_v0(request, version)
+                .expect("expected query to succeed");
+
+            let Some(GetPendingTransfersResponseV0 {
+                result:
+                    Some(get_pending_transfers_response_v0::Result::PendingTransfers(
+                        get_pending_transfers_response_v0::PendingTransfers { pending_transfers },
+                    )),
+                ..
+            }) = result.data
+            else {
+                panic!("expected pending transfers");
+            };
+
+            let pending_transfers = pending_transfers
+                .iter()
+                .map(|bytes| {
+                    PendingTransfer::deserialize_from_bytes(bytes)
+                        .expect("expected to deserialize a pending transfer")
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(pending_transfers, vec![pending_transfer.clone()]);
+        }
+
+        // the recipient does not send any transfer
+        let request = GetPendingTransfersRequestV0 {
+            identity_id: recipient_id.to_vec(),
+            outgoing: true,
+            limit: None,
+            prove: true,
+        };
+
+        let result = platform
+            .query_pending_transfers_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.data,
+            Some(GetPendingTransfersResponseV0 {
+                result: Some(get_pending_transfers_response_v0::Result::Proof(_)),
+                metadata: Some(_)
+            })
+        ));
+    }
+}
@@ -0,0 +1,339 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_identity_withdrawals_request::GetIdentityWithdrawalsRequestV0;
+use dapi_grpc::platform::v0::get_identity_withdrawals_response::{
+    get_identity_withdrawals_response_v0, GetIdentityWithdrawalsResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::Document;
+use dpp::identifier::Identifier;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use dpp::withdrawal::identity_withdrawal::IdentityWithdrawal;
+use dpp::ProtocolError;
+use drive::drive::config::DEFAULT_QUERY_LIMIT;
+use drive::drive::Drive;
+use drive::error::query::QuerySyntaxError;
+use std::collections::BTreeMap;
+
+impl<C> Platform<C> {
+    pub(super) fn query_identity_withdrawals_v0(
+        &self,
+        GetIdentityWithdrawalsRequestV0 {
+            identity_id,
+            limit,
+            prove,
+        }: GetIdentityWithdrawalsRequestV0,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetIdentityWithdrawalsResponseV0>, Error> {
+        let identity_id: Identifier = check_validation_result_with_data!(identity_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        // The default limit doesn't depend on the node config so the proof can be verified
+        // with the limit of the request
+        let limit = match limit {
+            Some(limit) if limit > u16::MAX as u32 => {
+                return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                    QuerySyntaxError::InvalidParameter("limit out of bounds".to_string()),
+                )));
+            }
+            Some(limit) if limit as u16 > self.config.drive.max_query_limit => {
+                return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                    QuerySyntaxError::InvalidLimit(format!(
+                        "limit greater than max limit {}",
+                        self.config.drive.max_query_limit
+                    )),
+                )));
+            }
+            Some(limit) => limit as u16,
+            None => DEFAULT_QUERY_LIMIT,
+        };
+
+        let withdrawals_contract = self.drive.cache.system_data_contracts.read_withdrawals();
+
+        let response = if prove {
+            let proof = self.drive.prove_withdrawals_for_identity_id(
+                &withdrawals_contract,
+                identity_id.into_buffer(),
+                limit,
+                None,
+                platform_version,
+            )?;
+
+            let (metadata, proof) = self.response_metadata_and_proof_v0(proof);
+
+            GetIdentityWithdrawalsResponseV0 {
+                result: Some(get_identity_withdrawals_response_v0::Result::Proof(proof)),
+                metadata: Some(metadata),
+            }
+        } else {
+            let drive_query = Drive::withdrawals_for_identity_query(
+                &withdrawals_contract,
+                identity_id.into_buffer(),
+                limit,
+            )?;
+
+            let (serialized_documents, _, _) = drive_query.execute_raw_results_no_proof(
+                &self.drive,
+                None,
+                None,
+                platform_version,
+            )?;
+
+            let transaction_indexes = serialized_documents
+                .iter()
+                .map(|serialized_document| {
+                    let document = Document::from_bytes(
+                        serialized_document,
+                        drive_query.document_type,
+                        platform_version,
+                    )?;
+
+                    Ok(IdentityWithdrawal::try_from(document)?.transaction_index)
+                })
+                .collect::<Result<Vec<_>, ProtocolError>>()?;
+
+            // Transaction ids are only stored from protocol version 2
+            let transaction_ids = if platform_version
+                .drive_abci
+                .methods
+                .withdrawals
+                .store_withdrawal_transaction_ids
+                .is_some()
+            {
+                let mut unique_transaction_indexes = transaction_indexes
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .collect::<Vec<_>>();
+                unique_transaction_indexes.sort_unstable();
+                unique_transaction_indexes.dedup();
+
+                self.drive.fetch_withdrawal_transaction_ids(
+                    &unique_transaction_indexes,
+                    None,
+                    platform_version,
+                )?
+            } else {
+                BTreeMap::new()
+            };
+
+            let withdrawals = serialized_documents
+                .into_iter()
+                .zip(transaction_indexes)
+                .map(|(document, transaction_index)| {
+                    get_identity_withdrawals_response_v0::Withdrawal {
+                        document,
+                        transaction_id: transaction_index
+                            .and_then(|index| transaction_ids.get(&index))
+                            .map(|transaction_id| transaction_id.to_vec())
+                            .unwrap_or_default(),
+                    }
+                })
+                .collect();
+
+            GetIdentityWithdrawalsResponseV0 {
+                result: Some(get_identity_withdrawals_response_v0::Result::Withdrawals(
+                    get_identity_withdrawals_response_v0::Withdrawals { withdrawals },
+                )),
+                metadata: Some(self.response_metadata_v0()),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::{assert_invalid_identifier, setup_platform};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::dashcore::hashes::Hash;
+    use dpp::dashcore::Txid;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contracts::withdrawals_contract::WithdrawalStatus;
+    use dpp::identity::core_script::CoreScript;
+    use dpp::platform_value::platform_value;
+    use dpp::system_data_contracts::withdrawals_contract::v1::document_types::withdrawal;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use dpp::tests::fixtures::get_withdrawal_document_fixture;
+    use dpp::withdrawal::Pooling;
+    use drive::tests::helpers::setup::{setup_document, setup_system_data_contract};
+
+    #[test]
+    fn test_invalid_identity_id() {
+        let (platform, version) = setup_platform();
+
+        let request = GetIdentityWithdrawalsRequestV0 {
+            identity_id: vec![0; 8],
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_identity_withdrawals_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert_invalid_identifier(result);
+    }
+
+    #[test]
+    fn test_invalid_limit_u16_overflow() {
+        let (platform, version) = setup_platform();
+
+        let request = GetIdentityWithdrawalsRequestV0 {
+            identity_id: vec![0; 32],
+            limit: Some(u32::MAX),
+            prove: false,
+        };
+
+        let result = platform
+            .query_identity_withdrawals_v0(request, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::Query(QuerySyntaxError::InvalidParameter(msg))] if msg == "limit out of bounds"
+        ));
+    }
+
+    #[test]
+    fn test_identity_withdrawals() {
+        let (platform, version) = setup_platform();
+
+        let data_contract = load_system_data_contract(SystemDataContract::Withdrawals, version)
+            .expect("expected to load the withdrawals contract");
+
+        setup_system_data_contract(&platform.drive, &data_contract, None);
+
+        let document_type = data_contract
+            .document_type_for_name(withdrawal::NAME)
+            .expect("expected to get document type");
+
+        let identity_id = Identifier::random();
+
+        let document = get_withdrawal_document_fixture(
+            &data_contract,
+            identity_id,
+            platform_value!({
+                "amount": 1000u64,
+                "coreFeePerByte": 1u32,
+                "pooling": Pooling::Never as u8,
+                "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                "status": WithdrawalStatus::BROADCASTED as u8,
+                "transactionIndex": 1u64,
+                "transactionSignHeight": 93u32,
+            }),
+            None,
+            version.protocol_version,
+        )
+        .expect("expected withdrawal document");
+
+        setup_document(
+            &platform.drive,
+            &document,
+            &data_contract,
+            document_type,
+            None,
+        );
+
+        let transaction_id = [7; 32];
+
+        let mut drive_operations = vec![];
+
+        platform
+            .drive
+            .add_insert_withdrawal_transaction_ids_operations(
+                vec![(1, transaction_id)],
+                &mut drive_operations,
+                version,
+            )
+            .expect("expected to add transaction id operations");
+
+        platform
+            .drive
+            .apply_drive_operations(drive_operations, true, &BlockInfo::default(), None, version)
+            .expect("expected to store the transaction id");
+
+        let mut expected_withdrawal =
+            IdentityWithdrawal::try_from(&document).expect("expected a withdrawal");
+
+        assert_eq!(expected_withdrawal.status, WithdrawalStatus::BROADCASTED);
+        assert_eq!(expected_withdrawal.transaction_index, Some(1));
+        assert_eq!(expected_withdrawal.transaction_id, None);
+
+        expected_withdrawal.transaction_id = Some(Txid::from_byte_array(transaction_id));
+
+        let request = GetIdentityWithdrawalsRequestV0 {
+            identity_id: identity_id.to_vec(),
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_identity_withdrawals_v0(request, version)
+            .expect("expected query to succeed");
+
+        let Some(GetIdentityWithdrawalsResponseV0 {
+            result:
+                Some(get_identity_withdrawals_response_v0::Result::Withdrawals(
+                    get_identity_withdrawals_response_v0::Withdrawals { withdrawals },
+                )),
+            ..
+        }) = result.data
+        else {
+            panic!("expected withdrawals");
+        };
+
+        let withdrawals = withdrawals
+            .iter()
+            .map(|withdrawal| {
+                let document = Document::from_bytes(&withdrawal.document, document_type, version)
+                    .expect("expected to deserialize a withdrawal document");
+
+                let mut identity_withdrawal =
+                    IdentityWithdrawal::try_from(document).expect("expected a withdrawal");
+                identity_withdrawal.transaction_id =
+                    Txid::from_slice(&withdrawal.transaction_id).ok();
+                identity_withdrawal
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(withdrawals, vec![expected_withdrawal.clone()]);
+
+        let request = GetIdentityWithdrawalsRequestV0 {
+            identity_id: identity_id.to_vec(),
+            limit: None,
+            prove: true,
+        };
+
+        let result = platform
+            .query_identity_withdrawals_v0(request, version)
+            .expect("expected query to succeed");
+
+        let Some(GetIdentityWithdrawalsResponseV0 {
+            result: Some(get_identity_withdrawals_response_v0::Result::Proof(proof)),
+            metadata: Some(_),
+        }) = result.data
+        else {
+            panic!("expected a proof");
+        };
+
+        let (_, proved_withdrawals) = Drive::verify_withdrawals_for_identity_id(
+            &proof.grovedb_proof,
+            identity_id.into_buffer(),
+            DEFAULT_QUERY_LIMIT,
+            version,
+        )
+        .expect("expected to verify the proof");
+
+        assert_eq!(proved_withdrawals, vec![expected_withdrawal]);
+    }
+}
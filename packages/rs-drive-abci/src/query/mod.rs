@@ -1,5 +1,7 @@
 mod data_contract_based_queries;
+mod document_aggregate_query;
 mod document_query;
+mod document_subscription;
 mod identity_based_queries;
 mod proofs;
 mod response_metadata;
@@ -1,6 +1,8 @@
 use crate::error::query::QueryError;
 use crate::error::Error;
+use crate::platform_types::document_changes::BlockDocumentChanges;
 use crate::platform_types::platform::Platform;
+use crate::query::document_subscription::DocumentsSubscription;
 use crate::query::QueryValidationResult;
 use crate::rpc::core::DefaultCoreRPC;
 use crate::utils::spawn_blocking_task_with_name_if_supported;
@@ -10,28 +12,40 @@ use dapi_grpc::platform::v0::{
     BroadcastStateTransitionRequest, BroadcastStateTransitionResponse, GetConsensusParamsRequest,
     GetConsensusParamsResponse, GetDataContractHistoryRequest, GetDataContractHistoryResponse,
     GetDataContractRequest, GetDataContractResponse, GetDataContractsRequest,
-    GetDataContractsResponse, GetDocumentsRequest, GetDocumentsResponse, GetEpochsInfoRequest,
-    GetEpochsInfoResponse, GetIdentitiesByPublicKeyHashesRequest,
-    GetIdentitiesByPublicKeyHashesResponse, GetIdentitiesRequest, GetIdentitiesResponse,
-    GetIdentityBalanceAndRevisionRequest, GetIdentityBalanceAndRevisionResponse,
-    GetIdentityBalanceRequest, GetIdentityBalanceResponse, GetIdentityByPublicKeyHashRequest,
-    GetIdentityByPublicKeyHashResponse, GetIdentityContractNonceRequest,
-    GetIdentityContractNonceResponse, GetIdentityKeysRequest, GetIdentityKeysResponse,
-    GetIdentityNonceRequest, GetIdentityNonceResponse, GetIdentityRequest, GetIdentityResponse,
-    GetProofsRequest, GetProofsResponse, GetProtocolVersionUpgradeStateRequest,
-    GetProtocolVersionUpgradeStateResponse, GetProtocolVersionUpgradeVoteStatusRequest,
-    GetProtocolVersionUpgradeVoteStatusResponse, WaitForStateTransitionResultRequest,
+    GetDataContractsResponse, GetDocumentsAggregateRequest, GetDocumentsAggregateResponse,
+    GetDocumentsRequest, GetDocumentsResponse, GetEpochsInfoRequest, GetEpochsInfoResponse,
+    GetIdentitiesByPublicKeyHashesRequest, GetIdentitiesByPublicKeyHashesResponse,
+    GetIdentitiesRequest, GetIdentitiesResponse, GetIdentityBalanceAndRevisionRequest,
+    GetIdentityBalanceAndRevisionResponse, GetIdentityBalanceRequest, GetIdentityBalanceResponse,
+    GetIdentityByPublicKeyHashRequest, GetIdentityByPublicKeyHashResponse,
+    GetIdentityContractNonceRequest, GetIdentityContractNonceResponse, GetIdentityKeysRequest,
+    GetIdentityKeysResponse, GetIdentityNonceRequest, GetIdentityNonceResponse, GetIdentityRequest,
+    GetIdentityResponse, GetIdentityWithdrawalsRequest, GetIdentityWithdrawalsResponse,
+    GetPendingTransfersRequest, GetPendingTransfersResponse, GetProofsRequest, GetProofsResponse,
+    GetProtocolVersionUpgradeStateRequest, GetProtocolVersionUpgradeStateResponse,
+    GetProtocolVersionUpgradeVoteStatusRequest, GetProtocolVersionUpgradeVoteStatusResponse,
+    SubscribeToDocumentsRequest, SubscribeToDocumentsResponse, WaitForStateTransitionResultRequest,
     WaitForStateTransitionResultResponse,
 };
 use dapi_grpc::tonic::{Request, Response, Status};
 use dpp::version::PlatformVersion;
 use dpp::version::PlatformVersionCurrentVersion;
 use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{mpsc, Semaphore};
+use tokio_stream::wrappers::ReceiverStream;
 use tracing::Instrument;
 
+/// The number of responses buffered for a document subscription while the client is reading them
+const DOCUMENT_SUBSCRIPTION_BUFFER_SIZE: usize = 16;
+
+/// The maximum number of document subscriptions served at the same time
+const MAX_DOCUMENT_SUBSCRIPTIONS: usize = 1024;
+
 /// Service to handle platform queries
 pub struct QueryService {
     platform: Arc<Platform<DefaultCoreRPC>>,
+    document_subscription_permits: Arc<Semaphore>,
 }
 
 type QueryMethod<RQ, RS> =
@@ -40,7 +54,10 @@ type QueryMethod<RQ, RS> =
 impl QueryService {
     /// Creates new QueryService
     pub fn new(platform: Arc<Platform<DefaultCoreRPC>>) -> Self {
-        Self { platform }
+        Self {
+            platform,
+            document_subscription_permits: Arc::new(Semaphore::new(MAX_DOCUMENT_SUBSCRIPTIONS)),
+        }
     }
 
     async fn handle_blocking_query<RQ, RS>(
@@ -81,6 +98,29 @@ impl QueryService {
     }
 }
 
+async fn documents_subscription_response(
+    platform: Arc<Platform<DefaultCoreRPC>>,
+    subscription: DocumentsSubscription,
+    block_document_changes: Arc<BlockDocumentChanges>,
+) -> Result<Option<SubscribeToDocumentsResponse>, Status> {
+    spawn_blocking_task_with_name_if_supported("document_subscription", move || {
+        let Some(platform_version) = PlatformVersion::get_maybe_current() else {
+            return Err(Status::unavailable("platform is not initialized"));
+        };
+
+        platform
+            .documents_subscription_response(
+                &subscription,
+                &block_document_changes,
+                platform_version,
+            )
+            .map_err(error_into_status)
+    })?
+    .instrument(tracing::trace_span!("document_subscription"))
+    .await
+    .map_err(|error| Status::internal(format!("join error: {}", error)))?
+}
+
 fn respond_with_unimplemented<RS>(name: &str) -> Result<Response<RS>, Status> {
     tracing::error!("{} endpoint is called but it's not supported", name);
 
@@ -180,6 +220,30 @@ impl PlatformService for QueryService {
         .await
     }
 
+    async fn get_pending_transfers(
+        &self,
+        request: Request<GetPendingTransfersRequest>,
+    ) -> Result<Response<GetPendingTransfersResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_pending_transfers,
+            "get_pending_transfers",
+        )
+        .await
+    }
+
+    async fn get_identity_withdrawals(
+        &self,
+        request: Request<GetIdentityWithdrawalsRequest>,
+    ) -> Result<Response<GetIdentityWithdrawalsResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_identity_withdrawals,
+            "get_identity_withdrawals",
+        )
+        .await
+    }
+
     async fn get_proofs(
         &self,
         request: Request<GetProofsRequest>,
@@ -240,6 +304,18 @@ impl PlatformService for QueryService {
         .await
     }
 
+    async fn get_documents_aggregate(
+        &self,
+        request: Request<GetDocumentsAggregateRequest>,
+    ) -> Result<Response<GetDocumentsAggregateResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_documents_aggregate,
+            "get_documents_aggregate",
+        )
+        .await
+    }
+
     async fn get_identities_by_public_key_hashes(
         &self,
         request: Request<GetIdentitiesByPublicKeyHashesRequest>,
@@ -313,6 +389,83 @@ impl PlatformService for QueryService {
         )
         .await
     }
+
+    type subscribeToDocumentsStream = ReceiverStream<Result<SubscribeToDocumentsResponse, Status>>;
+
+    async fn subscribe_to_documents(
+        &self,
+        request: Request<SubscribeToDocumentsRequest>,
+    ) -> Result<Response<Self::subscribeToDocumentsStream>, Status> {
+        // Every subscription holds a permit until its task ends
+        let Ok(permit) = Arc::clone(&self.document_subscription_permits).try_acquire_owned() else {
+            return Err(Status::resource_exhausted(
+                "too many document subscriptions, try again later",
+            ));
+        };
+
+        // Subscribe before validating the request so no block committed meanwhile is missed
+        let mut document_changes_receiver = self.platform.document_changes_sender.subscribe();
+
+        let subscription = self
+            .handle_blocking_query(
+                request,
+                Platform::<DefaultCoreRPC>::subscribe_to_documents,
+                "subscribe_to_documents",
+            )
+            .await?
+            .into_inner();
+
+        let (sender, receiver) = mpsc::channel(DOCUMENT_SUBSCRIPTION_BUFFER_SIZE);
+
+        let platform = Arc::clone(&self.platform);
+
+        tokio::spawn(async move {
+            let _permit = permit;
+
+            loop {
+                let block_document_changes = match document_changes_receiver.recv().await {
+                    Ok(block_document_changes) => block_document_changes,
+                    Err(RecvError::Lagged(skipped_blocks)) => {
+                        let _ = sender
+                            .send(Err(Status::data_loss(format!(
+                                "document subscription is too slow, changes of {} blocks were skipped",
+                                skipped_blocks
+                            ))))
+                            .await;
+                        break;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+
+                // The client is gone
+                if sender.is_closed() {
+                    break;
+                }
+
+                let response = documents_subscription_response(
+                    Arc::clone(&platform),
+                    subscription.clone(),
+                    block_document_changes,
+                )
+                .await;
+
+                match response {
+                    Ok(Some(response)) => {
+                        if sender.send(Ok(response)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(status) => {
+                        let _ = sender.send(Err(status)).await;
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
 }
 
 fn query_error_into_status(error: QueryError) -> Status {
@@ -94,9 +94,13 @@ mod tests {
     use dpp::data_contract::document_type::random_document::{
         DocumentFieldFillSize, DocumentFieldFillType,
     };
+    use dpp::document::DocumentV0Getters;
+    use dpp::fee::Credits;
     use dpp::identity::accessors::IdentityGettersV0;
     use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+    use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
     use dpp::system_data_contracts::withdrawals_contract;
+    use dpp::system_data_contracts::withdrawals_contract::v1::document_types::withdrawal;
     use dpp::tests::json_document::json_document_to_created_contract;
     use dpp::util::hash::hash_to_hex_string;
     use dpp::version::PlatformVersion;
@@ -3105,6 +3109,305 @@ mod tests {
         };
     }
 
+    #[test]
+    fn run_chain_refunds_withdrawals_never_included_by_core() {
+        let platform_version = PlatformVersion::latest();
+        let strategy = NetworkStrategy {
+            strategy: Strategy {
+                contracts_with_updates: vec![],
+                operations: vec![
+                    Operation {
+                        op_type: OperationType::IdentityTopUp,
+                        frequency: Frequency {
+                            times_per_block_range: 1..4,
+                            chance_per_block: None,
+                        },
+                    },
+                    Operation {
+                        op_type: OperationType::IdentityWithdrawal,
+                        frequency: Frequency {
+                            times_per_block_range: 1..4,
+                            chance_per_block: None,
+                        },
+                    },
+                ],
+                start_identities: vec![],
+                identities_inserts: Frequency {
+                    times_per_block_range: 1..2,
+                    chance_per_block: None,
+                },
+                identity_contract_nonce_gaps: None,
+                signer: None,
+            },
+            total_hpmns: 100,
+            extra_normal_mns: 0,
+            validator_quorum_count: 24,
+            chain_lock_quorum_count: 24,
+            upgrading_info: None,
+
+            proposer_strategy: Default::default(),
+            rotate_quorums: false,
+            failure_testing: None,
+            query_testing: None,
+            // because we can add an identity and withdraw from it in the same block
+            // the result would be different then expected
+            verify_state_transition_results: false,
+            ..Default::default()
+        };
+
+        // Once withdrawals are requested the balances of identities should only change by refunds
+        let strategy_without_operations = NetworkStrategy {
+            strategy: Strategy {
+                contracts_with_updates: vec![],
+                operations: vec![],
+                start_identities: vec![],
+                identities_inserts: Frequency {
+                    times_per_block_range: Default::default(),
+                    chance_per_block: None,
+                },
+                identity_contract_nonce_gaps: None,
+                signer: None,
+            },
+            ..strategy.clone()
+        };
+
+        let config = PlatformConfig {
+            validator_set_quorum_size: 100,
+            validator_set_quorum_type: "llmq_100_67".to_string(),
+            chain_lock_quorum_type: "llmq_100_67".to_string(),
+            execution: ExecutionConfig {
+                verify_sum_trees: true,
+                validator_set_rotation_block_count: 25,
+                ..Default::default()
+            },
+            block_spacing_ms: 3000,
+            testing_configs: PlatformTestConfig::default_with_no_block_signing(),
+            ..Default::default()
+        };
+
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(config.clone())
+            .build_with_mock_rpc();
+
+        platform
+            .core_rpc
+            .expect_send_raw_transaction()
+            .returning(move |_| Ok(Txid::all_zeros()));
+
+        // Core never includes the broadcasted asset unlock transactions
+        platform
+            .core_rpc
+            .expect_get_asset_unlock_statuses()
+            .returning(move |indices, _| {
+                Ok(indices
+                    .iter()
+                    .map(|index| AssetUnlockStatusResult {
+                        index: *index,
+                        status: AssetUnlockStatus::Unknown,
+                    })
+                    .collect())
+            });
+
+        // We need to change the chain lock along the way but we can't mutate `platform.core_rpc`
+        // later because platform reference is moved into the AbciApplication.
+        let shared_chain_lock = Arc::new(Mutex::new(ChainLock {
+            block_height: 1,
+            block_hash: BlockHash::from_byte_array([1; 32]),
+            signature: BLSSignature::from([2; 96]),
+        }));
+
+        {
+            let chain_lock = shared_chain_lock.clone();
+
+            platform
+                .core_rpc
+                .expect_get_best_chain_lock()
+                .returning(move || Ok(chain_lock.lock().unwrap().clone()));
+        }
+
+        // Run first two blocks:
+        // - Block 1: creates identities
+        // - Block 2: tops up identities and initiates withdrawals
+        let ChainExecutionOutcome {
+            abci_app,
+            proposers,
+            quorums,
+            current_quorum_hash,
+            current_proposer_versions,
+            end_time_ms,
+            identity_nonce_counter,
+            identity_contract_nonce_counter,
+            identities,
+            ..
+        } = run_chain_for_strategy(&mut platform, 2, strategy, config.clone(), 1);
+
+        // Run block 3
+        // Should broadcast previously pooled withdrawals to core
+        let (
+            ChainExecutionOutcome {
+                abci_app,
+                proposers,
+                quorums,
+                current_quorum_hash,
+                current_proposer_versions,
+                end_time_ms,
+                identity_nonce_counter,
+                identity_contract_nonce_counter,
+                ..
+            },
+            broadcasted_withdrawals_amount,
+        ) = {
+            let outcome = continue_chain_for_strategy(
+                abci_app,
+                ChainExecutionParameters {
+                    block_start: 3,
+                    core_height_start: 1,
+                    block_count: 1,
+                    proposers,
+                    quorums,
+                    current_quorum_hash,
+                    current_proposer_versions: Some(current_proposer_versions),
+                    current_identity_nonce_counter: identity_nonce_counter,
+                    current_identity_contract_nonce_counter: identity_contract_nonce_counter,
+                    start_time_ms: GENESIS_TIME_MS,
+                    current_time_ms: end_time_ms,
+                },
+                strategy_without_operations.clone(),
+                config.clone(),
+                StrategyRandomness::SeedEntropy(2),
+            );
+
+            let withdrawal_documents_broadcasted = outcome
+                .abci_app
+                .platform
+                .drive
+                .fetch_oldest_withdrawal_documents_by_status(
+                    withdrawals_contract::WithdrawalStatus::BROADCASTED.into(),
+                    DEFAULT_QUERY_LIMIT,
+                    None,
+                    platform_version,
+                )
+                .unwrap();
+
+            assert!(!withdrawal_documents_broadcasted.is_empty());
+            assert_eq!(
+                outcome.withdrawals.len(),
+                withdrawal_documents_broadcasted.len()
+            );
+
+            let broadcasted_withdrawals_amount = withdrawal_documents_broadcasted.len();
+
+            (outcome, broadcasted_withdrawals_amount)
+        };
+
+        let identity_ids = identities
+            .iter()
+            .map(|identity| identity.id().to_buffer())
+            .collect();
+
+        let balances_before_expiration = abci_app
+            .platform
+            .drive
+            .fetch_identities_balances(&identity_ids, None)
+            .expect("expected to fetch balances");
+
+        // Core reaches the expiration height without including the transactions
+        {
+            let number_of_blocks_before_expiration: u32 = 48;
+
+            shared_chain_lock.lock().unwrap().block_height +=
+                number_of_blocks_before_expiration + 1;
+        }
+
+        // Run block 4
+        // Withdrawals should expire and their credits should be refunded to identities
+        let outcome = continue_chain_for_strategy(
+            abci_app,
+            ChainExecutionParameters {
+                block_start: 4,
+                core_height_start: 1,
+                block_count: 1,
+                proposers,
+                quorums,
+                current_quorum_hash,
+                current_proposer_versions: Some(current_proposer_versions),
+                current_identity_nonce_counter: identity_nonce_counter,
+                current_identity_contract_nonce_counter: identity_contract_nonce_counter,
+                start_time_ms: GENESIS_TIME_MS,
+                current_time_ms: end_time_ms + 1000,
+            },
+            strategy_without_operations,
+            config,
+            StrategyRandomness::SeedEntropy(3),
+        );
+
+        let withdrawal_documents_broadcasted = outcome
+            .abci_app
+            .platform
+            .drive
+            .fetch_oldest_withdrawal_documents_by_status(
+                withdrawals_contract::WithdrawalStatus::BROADCASTED.into(),
+                DEFAULT_QUERY_LIMIT,
+                None,
+                platform_version,
+            )
+            .unwrap();
+
+        let withdrawal_documents_expired = outcome
+            .abci_app
+            .platform
+            .drive
+            .fetch_oldest_withdrawal_documents_by_status(
+                withdrawals_contract::WithdrawalStatus::EXPIRED.into(),
+                DEFAULT_QUERY_LIMIT,
+                None,
+                platform_version,
+            )
+            .unwrap();
+
+        assert!(withdrawal_documents_broadcasted.is_empty());
+        assert_eq!(
+            withdrawal_documents_expired.len(),
+            broadcasted_withdrawals_amount
+        );
+
+        let mut refunded_amounts: BTreeMap<[u8; 32], Credits> = BTreeMap::new();
+
+        for document in withdrawal_documents_expired {
+            let amount: Credits = document
+                .properties()
+                .get_integer(withdrawal::properties::AMOUNT)
+                .expect("expected withdrawal amount");
+
+            *refunded_amounts
+                .entry(document.owner_id().to_buffer())
+                .or_default() += amount;
+        }
+
+        let balances_after_expiration = outcome
+            .abci_app
+            .platform
+            .drive
+            .fetch_identities_balances(&identity_ids, None)
+            .expect("expected to fetch balances");
+
+        for (identity_id, balance_before) in balances_before_expiration {
+            let balance_after = balances_after_expiration
+                .get(&identity_id)
+                .copied()
+                .expect("expected a balance");
+
+            match refunded_amounts.get(&identity_id) {
+                // Identities get their withdrawn credits back minus the fees of the refund
+                Some(refunded_amount) => {
+                    assert!(balance_after > balance_before);
+                    assert!(balance_after < balance_before + refunded_amount);
+                }
+                None => assert_eq!(balance_after, balance_before),
+            }
+        }
+    }
+
     #[test]
     fn run_chain_rotation_is_deterministic_1_block() {
         let strategy = NetworkStrategy {
@@ -23,6 +23,9 @@ use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatch
 use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
 use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentFromCreateTransitionAction;
 use drive::state_transition_action::document::documents_batch::document_transition::document_replace_transition_action::DocumentFromReplaceTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::v0::DocumentTransferTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::v0::DocumentUpdatePriceTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::v0::DocumentPurchaseTransitionActionAccessorsV0;
 use drive_abci::abci::app::FullAbciApplication;
 use drive_abci::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
 use drive_abci::platform_types::platform_state::v0::PlatformStateV0Methods;
@@ -60,6 +63,7 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
 
             let consensus_validation_result = match state_transition.transform_into_action(
                 &platform,
+                state.last_block_info(),
                 false,
                 &mut execution_context,
                 None,
@@ -373,6 +377,28 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                                 // we expect no document
                                 assert!(document.is_none());
                             }
+                            DocumentTransitionAction::TransferAction(transfer_action) => {
+                                if *was_executed {
+                                    // it's also possible we deleted something we transferred
+                                    if let Some(document) = document {
+                                        assert_eq!(&document, transfer_action.document());
+                                    }
+                                }
+                            }
+                            DocumentTransitionAction::UpdatePriceAction(update_price_action) => {
+                                if *was_executed {
+                                    if let Some(document) = document {
+                                        assert_eq!(&document, update_price_action.document());
+                                    }
+                                }
+                            }
+                            DocumentTransitionAction::PurchaseAction(purchase_action) => {
+                                if *was_executed {
+                                    if let Some(document) = document {
+                                        assert_eq!(&document, purchase_action.document());
+                                    }
+                                }
+                            }
                             DocumentTransitionAction::BumpIdentityDataContractNonce(_) => {
                                 panic!("we should not have a bump identity data contract nonce");
                             }
@@ -581,13 +607,17 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                                 .into(),
                         });
 
-                    proofs_request
-                        .identities
-                        .push(get_proofs_request_v0::IdentityRequest {
-                            identity_id: identity_credit_transfer_action.recipient_id().to_vec(),
-                            request_type: get_proofs_request_v0::identity_request::Type::Balance
-                                .into(),
-                        });
+                    let recipients = identity_credit_transfer_action.recipients();
+
+                    for recipient_id in recipients.keys() {
+                        proofs_request
+                            .identities
+                            .push(get_proofs_request_v0::IdentityRequest {
+                                identity_id: recipient_id.to_vec(),
+                                request_type:
+                                    get_proofs_request_v0::identity_request::Type::Balance.into(),
+                            });
+                    }
 
                     let versioned_request = GetProofsRequest {
                         version: Some(get_proofs_request::Version::V0(proofs_request)),
@@ -618,30 +648,32 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                         platform.state.last_committed_block_info()
                     );
 
-                    let (root_hash_recipient, balance_recipient) =
-                        Drive::verify_identity_balance_for_identity_id(
-                            &response_proof.grovedb_proof,
-                            identity_credit_transfer_action.recipient_id().into_buffer(),
-                            true,
-                            platform_version,
-                        )
-                        .expect("expected to verify balance recipient");
+                    for (recipient_id, amount) in recipients {
+                        let (root_hash_recipient, balance_recipient) =
+                            Drive::verify_identity_balance_for_identity_id(
+                                &response_proof.grovedb_proof,
+                                recipient_id.into_buffer(),
+                                true,
+                                platform_version,
+                            )
+                            .expect("expected to verify balance recipient");
 
-                    assert_eq!(
-                        &root_hash_recipient,
-                        expected_root_hash,
-                        "state last block info {:?}",
-                        platform.state.last_committed_block_info()
-                    );
+                        assert_eq!(
+                            &root_hash_recipient,
+                            expected_root_hash,
+                            "state last block info {:?}",
+                            platform.state.last_committed_block_info()
+                        );
 
-                    if *was_executed {
-                        let balance_recipient = balance_recipient.expect("expected a balance");
+                        if *was_executed {
+                            let balance_recipient = balance_recipient.expect("expected a balance");
 
-                        assert!(
-                            balance_recipient >= identity_credit_transfer_action.transfer_amount()
-                        );
+                            assert!(balance_recipient >= amount);
+                        }
                     }
                 }
+                // strategies do not recover identities
+                StateTransitionAction::IdentityRecoverAction(_) => {}
                 StateTransitionAction::BumpIdentityNonceAction(_) => {}
                 StateTransitionAction::BumpIdentityDataContractNonceAction(_) => {}
             }
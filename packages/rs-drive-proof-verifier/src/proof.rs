@@ -5,12 +5,14 @@ use dapi_grpc::platform::v0::get_protocol_version_upgrade_vote_status_request::{
 use dapi_grpc::platform::v0::security_level_map::KeyKindRequestType as GrpcKeyKind;
 use dapi_grpc::platform::v0::{
     get_data_contract_history_request, get_data_contract_request, get_data_contracts_request,
-    get_epochs_info_request, get_identity_balance_and_revision_request,
-    get_identity_balance_request, get_identity_by_public_key_hash_request,
-    get_identity_contract_nonce_request, get_identity_keys_request, get_identity_nonce_request,
-    get_identity_request, GetProtocolVersionUpgradeStateRequest,
-    GetProtocolVersionUpgradeStateResponse, GetProtocolVersionUpgradeVoteStatusRequest,
-    GetProtocolVersionUpgradeVoteStatusResponse, ResponseMetadata,
+    get_epochs_info_request, get_identities_by_public_key_hashes_request, get_identities_request,
+    get_identity_balance_and_revision_request, get_identity_balance_request,
+    get_identity_by_public_key_hash_request, get_identity_contract_nonce_request,
+    get_identity_keys_request, get_identity_nonce_request, get_identity_request,
+    get_identity_withdrawals_request, get_pending_transfers_request,
+    GetProtocolVersionUpgradeStateRequest, GetProtocolVersionUpgradeStateResponse,
+    GetProtocolVersionUpgradeVoteStatusRequest, GetProtocolVersionUpgradeVoteStatusResponse,
+    ResponseMetadata,
 };
 use dapi_grpc::platform::{
     v0::{self as platform, key_request_type, KeyRequestType as GrpcKeyType},
@@ -30,9 +32,10 @@ use drive::drive::identity::key::fetch::{
     IdentityKeysRequest, KeyKindRequestType, KeyRequestType, PurposeU8, SecurityLevelU8,
 };
 
+use drive::drive::config::DEFAULT_QUERY_LIMIT;
 use drive::drive::Drive;
 use drive::error::proof::ProofError;
-use drive::query::DriveQuery;
+use drive::query::{AggregateFunction, DriveQuery};
 use std::array::TryFromSliceError;
 use std::collections::BTreeMap;
 use std::num::TryFromIntError;
@@ -237,6 +240,273 @@ impl FromProof<platform::GetIdentityByPublicKeyHashRequest> for Identity {
     }
 }
 
+impl FromProof<platform::GetIdentitiesRequest> for Identities {
+    type Request = platform::GetIdentitiesRequest;
+    type Response = platform::GetIdentitiesResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata), Error>
+    where
+        Identities: 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        // Parse response to read proof and metadata
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        let ids = match request.version.ok_or(Error::EmptyVersion)? {
+            get_identities_request::Version::V0(v0) => v0.ids,
+        };
+
+        let ids = ids
+            .iter()
+            .map(|id| {
+                id.clone()
+                    .try_into()
+                    .map_err(|_e| Error::RequestDecodeError {
+                        error: format!("wrong id size: expected: {}, got: {}", 32, id.len()),
+                    })
+            })
+            .collect::<Result<Vec<[u8; 32]>, Error>>()?;
+
+        // Extract content from proof and verify Drive/GroveDB proofs
+        let (root_hash, identities) = Drive::verify_full_identities_by_identity_ids::<
+            Vec<([u8; 32], Option<Identity>)>,
+        >(
+            &proof.grovedb_proof, ids.as_slice(), platform_version
+        )
+        .map_err(|e| Error::DriveError {
+            error: e.to_string(),
+        })?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        let maybe_identities = if !identities.is_empty() {
+            let identities: Identities = identities
+                .into_iter()
+                .map(|(id, maybe_identity)| (Identifier::from(id), maybe_identity))
+                .collect();
+
+            Some(identities)
+        } else {
+            None
+        };
+
+        Ok((maybe_identities, mtd.clone()))
+    }
+}
+
+impl FromProof<platform::GetIdentitiesByPublicKeyHashesRequest> for IdentitiesByPublicKeyHashes {
+    type Request = platform::GetIdentitiesByPublicKeyHashesRequest;
+    type Response = platform::GetIdentitiesByPublicKeyHashesResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata), Error>
+    where
+        IdentitiesByPublicKeyHashes: 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        // Parse response to read proof and metadata
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        let public_key_hashes = match request.version.ok_or(Error::EmptyVersion)? {
+            get_identities_by_public_key_hashes_request::Version::V0(v0) => v0.public_key_hashes,
+        };
+
+        let public_key_hashes = public_key_hashes
+            .iter()
+            .map(|public_key_hash| {
+                public_key_hash
+                    .clone()
+                    .try_into()
+                    .map_err(|_e| Error::RequestDecodeError {
+                        error: format!(
+                            "wrong public key hash size: expected: {}, got: {}",
+                            20,
+                            public_key_hash.len()
+                        ),
+                    })
+            })
+            .collect::<Result<Vec<[u8; 20]>, Error>>()?;
+
+        // Extract content from proof and verify Drive/GroveDB proofs
+        let (root_hash, identities) =
+            Drive::verify_full_identities_by_public_key_hashes::<IdentitiesByPublicKeyHashes>(
+                &proof.grovedb_proof,
+                public_key_hashes.as_slice(),
+                platform_version,
+            )
+            .map_err(|e| Error::DriveError {
+                error: e.to_string(),
+            })?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        let maybe_identities = if !identities.is_empty() {
+            Some(identities)
+        } else {
+            None
+        };
+
+        Ok((maybe_identities, mtd.clone()))
+    }
+}
+
+impl FromProof<platform::GetPendingTransfersRequest> for PendingTransfers {
+    type Request = platform::GetPendingTransfersRequest;
+    type Response = platform::GetPendingTransfersResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata), Error>
+    where
+        PendingTransfers: 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        // Parse response to read proof and metadata
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        let (identity_id, outgoing, limit) = match request.version.ok_or(Error::EmptyVersion)? {
+            get_pending_transfers_request::Version::V0(v0) => {
+                let identity_id = Identifier::from_bytes(&v0.identity_id)
+                    .map_err(|e| Error::ProtocolError {
+                        error: e.to_string(),
+                    })?
+                    .into_buffer();
+                let limit = v0
+                    .limit
+                    .map(|i| {
+                        u16::try_from(i).map_err(|e| Error::RequestDecodeError {
+                            error: format!("limit out of range: {}", e),
+                        })
+                    })
+                    .transpose()?;
+                (identity_id, v0.outgoing, limit)
+            }
+        };
+
+        // Extract content from proof and verify Drive/GroveDB proofs
+        let (root_hash, pending_transfers) = Drive::verify_pending_transfers_for_identity_id(
+            &proof.grovedb_proof,
+            identity_id,
+            outgoing,
+            limit,
+            false,
+            platform_version,
+        )
+        .map_err(|e| Error::DriveError {
+            error: e.to_string(),
+        })?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        let pending_transfers = pending_transfers
+            .into_iter()
+            .map(|pending_transfer| (pending_transfer.id, Some(pending_transfer)))
+            .collect::<PendingTransfers>();
+
+        if pending_transfers.is_empty() {
+            Ok((None, mtd.clone()))
+        } else {
+            Ok((Some(pending_transfers), mtd.clone()))
+        }
+    }
+}
+
+impl FromProof<platform::GetIdentityWithdrawalsRequest> for IdentityWithdrawals {
+    type Request = platform::GetIdentityWithdrawalsRequest;
+    type Response = platform::GetIdentityWithdrawalsResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata), Error>
+    where
+        IdentityWithdrawals: 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        // Parse response to read proof and metadata
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        let (identity_id, limit) = match request.version.ok_or(Error::EmptyVersion)? {
+            get_identity_withdrawals_request::Version::V0(v0) => {
+                let identity_id = Identifier::from_bytes(&v0.identity_id)
+                    .map_err(|e| Error::ProtocolError {
+                        error: e.to_string(),
+                    })?
+                    .into_buffer();
+                // withdrawals are proved with the default query limit when the request has none
+                let limit = v0
+                    .limit
+                    .map(|i| {
+                        u16::try_from(i).map_err(|e| Error::RequestDecodeError {
+                            error: format!("limit out of range: {}", e),
+                        })
+                    })
+                    .transpose()?
+                    .unwrap_or(DEFAULT_QUERY_LIMIT);
+                (identity_id, limit)
+            }
+        };
+
+        // Extract content from proof and verify Drive/GroveDB proofs
+        let (root_hash, withdrawals) = Drive::verify_withdrawals_for_identity_id(
+            &proof.grovedb_proof,
+            identity_id,
+            limit,
+            platform_version,
+        )
+        .map_err(|e| Error::DriveError {
+            error: e.to_string(),
+        })?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        let withdrawals = withdrawals
+            .into_iter()
+            .map(|withdrawal| (withdrawal.id, Some(withdrawal)))
+            .collect::<IdentityWithdrawals>();
+
+        if withdrawals.is_empty() {
+            Ok((None, mtd.clone()))
+        } else {
+            Ok((Some(withdrawals), mtd.clone()))
+        }
+    }
+}
+
 impl FromProof<platform::GetIdentityKeysRequest> for IdentityPublicKeys {
     type Request = platform::GetIdentityKeysRequest;
     type Response = platform::GetIdentityKeysResponse;
@@ -1093,6 +1363,51 @@ where
     }
 }
 
+impl<'dq, Q> FromProof<Q> for DocumentsAggregate
+where
+    Q: TryInto<(DriveQuery<'dq>, AggregateFunction)> + Clone + 'dq,
+    Q::Error: std::fmt::Display,
+{
+    type Request = Q;
+    type Response = platform::GetDocumentsAggregateResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        platform_version: &PlatformVersion,
+
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata), Error>
+    where
+        Self: 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let (drive_query, function): (DriveQuery<'dq>, AggregateFunction) = request
+            .clone()
+            .try_into()
+            .map_err(|e: Q::Error| Error::RequestDecodeError {
+                error: e.to_string(),
+            })?;
+
+        // Parse response to read proof and metadata
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        let (root_hash, value) = drive_query
+            .verify_aggregate_proof(&function, &proof.grovedb_proof, platform_version)
+            .map_err(|e| Error::DriveError {
+                error: e.to_string(),
+            })?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        Ok((Some(DocumentsAggregate(value)), mtd.clone()))
+    }
+}
+
 /// Convert u32, if 0 return None, otherwise return Some(u16).
 /// Errors when value is out of range.
 fn u32_to_u16_opt(i: u32) -> Result<Option<u16>, Error> {
@@ -13,9 +13,10 @@ use dpp::{
     block::{epoch::EpochIndex, extended_epoch_info::ExtendedEpochInfo},
     dashcore::ProTxHash,
     document::Document,
-    identity::KeyID,
-    prelude::{DataContract, Identifier, IdentityPublicKey, Revision},
+    identity::{pending_transfer::PendingTransfer, KeyID},
+    prelude::{DataContract, Identifier, Identity, IdentityPublicKey, Revision},
     util::deserializer::ProtocolVersion,
+    withdrawal::identity_withdrawal::IdentityWithdrawal,
 };
 
 /// A data structure that holds a set of objects of a generic type `O`, indexed by a key of type `K`.
@@ -44,6 +45,18 @@ pub type DataContractHistory = BTreeMap<u64, DataContract>;
 /// If data contract is not found, it is represented as `None`.
 pub type DataContracts = RetrievedObjects<Identifier, DataContract>;
 
+/// Multiple identities.
+///
+/// Mapping between identity IDs and identities.
+/// If identity is not found, it is represented as `None`.
+pub type Identities = RetrievedObjects<Identifier, Identity>;
+
+/// Multiple identities found by their unique public key hashes.
+///
+/// Mapping between public key hashes and identities.
+/// If no identity has the public key, it is represented as `None`.
+pub type IdentitiesByPublicKeyHashes = RetrievedObjects<[u8; 20], Identity>;
+
 /// Identity balance.
 pub type IdentityBalance = u64;
 /// Identity balance and revision of the identity.
@@ -65,6 +78,16 @@ pub type IdentityPublicKeys = RetrievedObjects<KeyID, IdentityPublicKey>;
 /// Collection of documents.
 pub type Documents = RetrievedObjects<Identifier, Document>;
 
+/// Credit transfers pending for an identity, by transfer id.
+pub type PendingTransfers = RetrievedObjects<Identifier, PendingTransfer>;
+
+/// Withdrawals requested by an identity, by withdrawal document id.
+pub type IdentityWithdrawals = RetrievedObjects<Identifier, IdentityWithdrawal>;
+
+/// Count of documents, or sum of an integer property of documents, matched by a document query.
+#[derive(Debug)]
+pub struct DocumentsAggregate(pub i64);
+
 /// Collection of epoch information
 pub type ExtendedEpochInfos = RetrievedObjects<EpochIndex, ExtendedEpochInfo>;
 
@@ -3,6 +3,9 @@ use crate::drive::Drive;
 use crate::error::Error;
 use crate::fee::op::LowLevelDriveOperation;
 use dpp::block::block_info::BlockInfo;
+use dpp::fee::Credits;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::recovery_proposal::IdentityRecoveryProposal;
 use dpp::identity::{Identity, IdentityPublicKey, KeyID, TimestampMillis};
 use dpp::prelude::{IdentityNonce, Revision};
 
@@ -60,6 +63,14 @@ pub enum IdentityOperationType {
         disable_at: TimestampMillis,
     },
 
+    /// Sets or removes the spending limits of identity keys
+    UpdateIdentityKeySpendingLimits {
+        /// The identity id of the identity
+        identity_id: [u8; 32],
+        /// The keys along with their new spending limit per epoch, none removes the limit
+        key_spending_limits: Vec<(KeyID, Option<Credits>)>,
+    },
+
     /// Re-Enable Identity Keys
     /// This should only be used internally in Drive (for masternode identities)
     ReEnableIdentityKeys {
@@ -94,6 +105,38 @@ pub enum IdentityOperationType {
         /// The nonce we are updating to
         nonce: IdentityNonce,
     },
+
+    /// Inserts or replaces the recovery proposal of an identity
+    SetIdentityRecoveryProposal {
+        /// The identity id of the identity
+        identity_id: [u8; 32],
+        /// The recovery proposal with its approvals
+        recovery_proposal: IdentityRecoveryProposal,
+    },
+
+    /// Removes the recovery proposal of an identity
+    RemoveIdentityRecoveryProposal {
+        /// The identity id of the identity
+        identity_id: [u8; 32],
+    },
+
+    /// Inserts or replaces the recovery delay chosen by an identity
+    SetIdentityRecoveryDelay {
+        /// The identity id of the identity
+        identity_id: [u8; 32],
+        /// How long the master keys of the identity can veto a recovery proposal
+        recovery_delay_ms: TimestampMillis,
+    },
+
+    /// Records the credits spent during the epoch by an identity key with a spending limit
+    SetIdentityKeySpending {
+        /// The identity id of the identity
+        identity_id: [u8; 32],
+        /// The key that signed the transfer or withdrawal
+        key_id: KeyID,
+        /// The spending of the key once the credits were spent
+        key_spending: KeySpending,
+    },
 }
 
 impl DriveLowLevelOperationConverter for IdentityOperationType {
@@ -166,6 +209,16 @@ impl DriveLowLevelOperationConverter for IdentityOperationType {
                 transaction,
                 platform_version,
             ),
+            IdentityOperationType::UpdateIdentityKeySpendingLimits {
+                identity_id,
+                key_spending_limits,
+            } => drive.update_identity_key_spending_limits_operations(
+                identity_id,
+                key_spending_limits,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
             IdentityOperationType::ReEnableIdentityKeys {
                 identity_id,
                 keys_ids,
@@ -214,6 +267,42 @@ impl DriveLowLevelOperationConverter for IdentityOperationType {
                 result.to_result()?;
                 Ok(operations)
             }
+            IdentityOperationType::SetIdentityRecoveryProposal {
+                identity_id,
+                recovery_proposal,
+            } => drive.set_identity_recovery_proposal_operations(
+                identity_id,
+                &recovery_proposal,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            ),
+            IdentityOperationType::RemoveIdentityRecoveryProposal { identity_id } => drive
+                .remove_identity_recovery_proposal_operations(
+                    identity_id,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                ),
+            IdentityOperationType::SetIdentityRecoveryDelay {
+                identity_id,
+                recovery_delay_ms,
+            } => drive.set_identity_recovery_delay_operations(
+                identity_id,
+                recovery_delay_ms,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            ),
+            IdentityOperationType::SetIdentityKeySpending {
+                identity_id,
+                key_id,
+                key_spending,
+            } => drive.set_identity_key_spending_operations(
+                identity_id,
+                key_id,
+                &key_spending,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            ),
         }
     }
 }
@@ -3,6 +3,7 @@ mod document;
 mod drive_methods;
 mod finalize_task;
 mod identity;
+mod pending_transfers;
 mod system;
 mod withdrawals;
 
@@ -19,6 +20,7 @@ pub use document::DocumentOperationType;
 pub use document::DocumentOperationsForContractDocumentType;
 pub use document::UpdateOperationInfo;
 pub use identity::IdentityOperationType;
+pub use pending_transfers::PendingTransferOperationType;
 pub use system::SystemOperationType;
 pub use withdrawals::WithdrawalOperationType;
 
@@ -66,6 +68,8 @@ pub enum DriveOperation<'a> {
     DocumentOperation(DocumentOperationType<'a>),
     /// Withdrawal operation
     WithdrawalOperation(WithdrawalOperationType),
+    /// Pending transfer operation
+    PendingTransferOperation(PendingTransferOperationType),
     /// An identity operation
     IdentityOperation(IdentityOperationType),
     /// A system operation
@@ -114,6 +118,15 @@ impl DriveLowLevelOperationConverter for DriveOperation<'_> {
                     platform_version,
                 )
             }
+            DriveOperation::PendingTransferOperation(pending_transfer_operation_type) => {
+                pending_transfer_operation_type.into_low_level_drive_operations(
+                    drive,
+                    estimated_costs_only_with_layer_info,
+                    block_info,
+                    transaction,
+                    platform_version,
+                )
+            }
             DriveOperation::IdentityOperation(identity_operation_type) => identity_operation_type
                 .into_low_level_drive_operations(
                     drive,
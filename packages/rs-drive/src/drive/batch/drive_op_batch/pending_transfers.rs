@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::identity::pending_transfer::{PendingTransfer, TransferReleaseCondition};
+use dpp::serialization::PlatformSerializable;
+use dpp::version::PlatformVersion;
+use grovedb::reference_path::ReferencePathType::UpstreamRootHeightReference;
+use grovedb::Element;
+use grovedb::{batch::KeyInfoPath, EstimatedLayerInformation, TransactionArg};
+
+use crate::drive::grove_operations::BatchDeleteApplyType;
+use crate::drive::identity::pending_transfers::paths::{
+    get_pending_transfers_path, pending_transfer_index_key, PENDING_TRANSFERS_BY_ID_KEY,
+    PENDING_TRANSFERS_BY_RECIPIENT_KEY, PENDING_TRANSFERS_BY_RELEASE_CORE_HEIGHT_KEY,
+    PENDING_TRANSFERS_BY_RELEASE_TIME_KEY, PENDING_TRANSFERS_BY_SENDER_KEY,
+};
+use crate::drive::identity::pending_transfers::{
+    AVERAGE_PENDING_TRANSFER_SIZE, PENDING_TRANSFER_REFERENCE_SIZE,
+};
+use crate::drive::object_size_info::PathKeyElementInfo::PathFixedSizeKeyRefElement;
+use crate::{drive::Drive, error::Error, fee::op::LowLevelDriveOperation};
+
+use super::DriveLowLevelOperationConverter;
+
+/// Operations for pending transfers
+#[derive(Clone, Debug)]
+pub enum PendingTransferOperationType {
+    /// Inserts a pending transfer along with its references by recipient, sender and release
+    InsertPendingTransfer {
+        /// the scheduled transfer
+        pending_transfer: PendingTransfer,
+    },
+    /// Removes a released or cancelled pending transfer along with its references
+    RemovePendingTransfer {
+        /// the released or cancelled transfer
+        pending_transfer: PendingTransfer,
+    },
+}
+
+/// The subtrees referencing the pending transfer, with the key of the reference in each of them
+fn pending_transfer_references(
+    pending_transfer: &PendingTransfer,
+) -> [(&'static [u8; 1], Vec<u8>); 3] {
+    let transfer_id = pending_transfer.id.as_bytes();
+
+    let release_reference = match pending_transfer.release_condition {
+        TransferReleaseCondition::BlockTime(time_ms) => (
+            &PENDING_TRANSFERS_BY_RELEASE_TIME_KEY,
+            pending_transfer_index_key(&time_ms.to_be_bytes(), transfer_id),
+        ),
+        TransferReleaseCondition::CoreBlockHeight(core_height) => (
+            &PENDING_TRANSFERS_BY_RELEASE_CORE_HEIGHT_KEY,
+            pending_transfer_index_key(&core_height.to_be_bytes(), transfer_id),
+        ),
+    };
+
+    [
+        (
+            &PENDING_TRANSFERS_BY_RECIPIENT_KEY,
+            pending_transfer_index_key(pending_transfer.recipient_id.as_slice(), transfer_id),
+        ),
+        (
+            &PENDING_TRANSFERS_BY_SENDER_KEY,
+            pending_transfer_index_key(pending_transfer.sender_id.as_slice(), transfer_id),
+        ),
+        release_reference,
+    ]
+}
+
+impl DriveLowLevelOperationConverter for PendingTransferOperationType {
+    fn into_low_level_drive_operations(
+        self,
+        drive: &Drive,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        _block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Drive::add_estimation_costs_for_pending_transfers(
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        match self {
+            PendingTransferOperationType::InsertPendingTransfer { pending_transfer } => {
+                let mut drive_operations = vec![];
+
+                let transfer_id = pending_transfer.id.to_buffer();
+
+                drive.batch_insert(
+                    PathFixedSizeKeyRefElement((
+                        get_pending_transfers_path(&PENDING_TRANSFERS_BY_ID_KEY),
+                        transfer_id.as_slice(),
+                        Element::Item(pending_transfer.serialize_to_bytes()?, None),
+                    )),
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?;
+
+                for (subtree_key, key) in pending_transfer_references(&pending_transfer) {
+                    // the reference keeps the misc and pending transfers keys of its path
+                    // and points to the transfer in the subtree of transfers by id
+                    drive.batch_insert(
+                        PathFixedSizeKeyRefElement((
+                            get_pending_transfers_path(subtree_key),
+                            key.as_slice(),
+                            Element::new_reference_with_flags(
+                                UpstreamRootHeightReference(
+                                    2,
+                                    vec![
+                                        PENDING_TRANSFERS_BY_ID_KEY.to_vec(),
+                                        transfer_id.to_vec(),
+                                    ],
+                                ),
+                                None,
+                            ),
+                        )),
+                        &mut drive_operations,
+                        &platform_version.drive,
+                    )?;
+                }
+
+                Ok(drive_operations)
+            }
+            PendingTransferOperationType::RemovePendingTransfer { pending_transfer } => {
+                let mut drive_operations = vec![];
+
+                let apply_type = |estimated_value_size| {
+                    if estimated_costs_only_with_layer_info.is_some() {
+                        BatchDeleteApplyType::StatelessBatchDelete {
+                            is_sum_tree: false,
+                            estimated_value_size,
+                        }
+                    } else {
+                        // we know that we are not deleting a subtree
+                        BatchDeleteApplyType::StatefulBatchDelete {
+                            is_known_to_be_subtree_with_sum: Some((false, false)),
+                        }
+                    }
+                };
+
+                let path = get_pending_transfers_path(&PENDING_TRANSFERS_BY_ID_KEY);
+
+                drive.batch_delete(
+                    (&path).into(),
+                    pending_transfer.id.as_slice(),
+                    apply_type(AVERAGE_PENDING_TRANSFER_SIZE),
+                    transaction,
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?;
+
+                for (subtree_key, key) in pending_transfer_references(&pending_transfer) {
+                    let path = get_pending_transfers_path(subtree_key);
+
+                    drive.batch_delete(
+                        (&path).into(),
+                        key.as_slice(),
+                        apply_type(PENDING_TRANSFER_REFERENCE_SIZE),
+                        transaction,
+                        &mut drive_operations,
+                        &platform_version.drive,
+                    )?;
+                }
+
+                Ok(drive_operations)
+            }
+        }
+    }
+}
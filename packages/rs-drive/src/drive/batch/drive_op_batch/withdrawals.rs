@@ -7,11 +7,13 @@ use grovedb::{batch::KeyInfoPath, EstimatedLayerInformation, TransactionArg};
 
 use crate::drive::grove_operations::BatchDeleteApplyType;
 use crate::drive::identity::withdrawals::paths::{
-    get_withdrawal_root_path_vec, get_withdrawal_transactions_queue_path,
-    get_withdrawal_transactions_queue_path_vec, WITHDRAWAL_TRANSACTIONS_NEXT_INDEX_KEY,
+    get_withdrawal_root_path_vec, get_withdrawal_transaction_ids_path_vec,
+    get_withdrawal_transactions_queue_path, get_withdrawal_transactions_queue_path_vec,
+    get_withdrawal_transactions_unsigned_path, get_withdrawal_transactions_unsigned_path_vec,
+    WITHDRAWAL_TRANSACTIONS_NEXT_INDEX_KEY,
 };
 use crate::drive::identity::withdrawals::{
-    WithdrawalTransactionIndex, WithdrawalTransactionIndexAndBytes,
+    WithdrawalTransactionIndex, WithdrawalTransactionIndexAndBytes, WithdrawalTransactionIndexAndId,
 };
 use crate::drive::object_size_info::PathKeyElementInfo;
 use crate::{drive::Drive, error::Error, fee::op::LowLevelDriveOperation};
@@ -36,6 +38,21 @@ pub enum WithdrawalOperationType {
         /// withdrawal transaction tuple with id and bytes
         index: WithdrawalTransactionIndex,
     },
+    /// Insert unsigned Core Transactions waiting for their quorum signatures
+    InsertUnsignedTransactions {
+        /// withdrawal transaction tuples with index and unsigned transaction bytes
+        withdrawal_transactions: Vec<WithdrawalTransactionIndexAndBytes>,
+    },
+    /// Delete an unsigned Core Transaction once its quorum signature is known
+    DeleteUnsignedTransaction {
+        /// index of the withdrawal transaction
+        index: WithdrawalTransactionIndex,
+    },
+    /// Insert the Core Transaction ids of signed withdrawal transactions
+    InsertTransactionIds {
+        /// withdrawal transaction tuples with index and Core transaction id
+        transaction_ids: Vec<WithdrawalTransactionIndexAndId>,
+    },
 }
 
 impl DriveLowLevelOperationConverter for WithdrawalOperationType {
@@ -105,6 +122,65 @@ impl DriveLowLevelOperationConverter for WithdrawalOperationType {
                     &platform_version.drive,
                 )?;
 
+                Ok(drive_operations)
+            }
+            WithdrawalOperationType::InsertUnsignedTransactions {
+                withdrawal_transactions,
+            } => {
+                let mut drive_operations = vec![];
+
+                let path = get_withdrawal_transactions_unsigned_path_vec();
+
+                for (index, bytes) in withdrawal_transactions {
+                    drive.batch_insert(
+                        PathKeyElementInfo::PathKeyElement::<'_, 1>((
+                            path.clone(),
+                            index.to_be_bytes().to_vec(),
+                            Element::Item(bytes, None),
+                        )),
+                        &mut drive_operations,
+                        &platform_version.drive,
+                    )?;
+                }
+
+                Ok(drive_operations)
+            }
+            WithdrawalOperationType::DeleteUnsignedTransaction { index } => {
+                let mut drive_operations = vec![];
+
+                let path = get_withdrawal_transactions_unsigned_path();
+
+                drive.batch_delete(
+                    (&path).into(),
+                    &index.to_be_bytes(),
+                    // we know that we are not deleting a subtree
+                    BatchDeleteApplyType::StatefulBatchDelete {
+                        is_known_to_be_subtree_with_sum: Some((false, false)),
+                    },
+                    transaction,
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?;
+
+                Ok(drive_operations)
+            }
+            WithdrawalOperationType::InsertTransactionIds { transaction_ids } => {
+                let mut drive_operations = vec![];
+
+                let path = get_withdrawal_transaction_ids_path_vec();
+
+                for (index, transaction_id) in transaction_ids {
+                    drive.batch_insert(
+                        PathKeyElementInfo::PathKeyElement::<'_, 1>((
+                            path.clone(),
+                            index.to_be_bytes().to_vec(),
+                            Element::Item(transaction_id.to_vec(), None),
+                        )),
+                        &mut drive_operations,
+                        &platform_version.drive,
+                    )?;
+                }
+
                 Ok(drive_operations)
             }
         }
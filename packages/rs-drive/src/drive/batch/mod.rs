@@ -13,5 +13,6 @@ pub use drive_op_batch::DataContractOperationType;
 pub use drive_op_batch::DocumentOperationType;
 pub use drive_op_batch::DriveOperation;
 pub use drive_op_batch::IdentityOperationType;
+pub use drive_op_batch::PendingTransferOperationType;
 pub use drive_op_batch::SystemOperationType;
 pub use grovedb_op_batch::GroveDbOpBatch;
@@ -0,0 +1,53 @@
+use crate::drive::batch::transitions::document::DriveHighLevelDocumentOperationConverter;
+use crate::drive::batch::DriveOperation::{DocumentOperation, IdentityOperation};
+use crate::drive::batch::{DocumentOperationType, DriveOperation, IdentityOperationType};
+use crate::drive::flags::StorageFlags;
+use crate::drive::object_size_info::DocumentInfo::DocumentOwnedInfo;
+use crate::drive::object_size_info::OwnedDocumentInfo;
+use crate::error::Error;
+use dpp::block::epoch::Epoch;
+
+use dpp::prelude::Identifier;
+use std::borrow::Cow;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::DocumentTransferTransitionAction;
+use crate::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::v0::DocumentTransferTransitionActionAccessorsV0;
+use dpp::version::PlatformVersion;
+
+impl DriveHighLevelDocumentOperationConverter for DocumentTransferTransitionAction {
+    fn into_high_level_document_drive_operations<'b>(
+        self,
+        epoch: &Epoch,
+        owner_id: Identifier,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'b>>, Error> {
+        let data_contract_id = self.base().data_contract_id();
+        let document_type_name = self.base().document_type_name().clone();
+        let identity_contract_nonce = self.base().identity_contract_nonce();
+        let document = self.document_owned();
+
+        // the sender pays for the update of the document
+        let storage_flags = StorageFlags::new_single_epoch(epoch.index, Some(owner_id.to_buffer()));
+
+        let mut drive_operations = vec![];
+        drive_operations.push(IdentityOperation(
+            IdentityOperationType::UpdateIdentityContractNonce {
+                identity_id: owner_id.into_buffer(),
+                contract_id: data_contract_id.into_buffer(),
+                nonce: identity_contract_nonce,
+            },
+        ));
+        // No owner id is given so that the old and the new document each use their own
+        // owner for index keys, this re-keys every index that contains $ownerId
+        drive_operations.push(DocumentOperation(DocumentOperationType::UpdateDocument {
+            owned_document_info: OwnedDocumentInfo {
+                document_info: DocumentOwnedInfo((document, Some(Cow::Owned(storage_flags)))),
+                owner_id: None,
+            },
+            contract_id: data_contract_id,
+            document_type_name: Cow::Owned(document_type_name),
+        }));
+
+        Ok(drive_operations)
+    }
+}
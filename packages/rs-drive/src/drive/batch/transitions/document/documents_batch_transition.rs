@@ -1,10 +1,13 @@
 use crate::drive::batch::transitions::document::DriveHighLevelDocumentOperationConverter;
 use crate::drive::batch::transitions::DriveHighLevelOperationConverter;
-use crate::drive::batch::DriveOperation;
+use crate::drive::batch::DriveOperation::IdentityOperation;
+use crate::drive::batch::{DriveOperation, IdentityOperationType};
 use crate::error::Error;
 use crate::state_transition_action::document::documents_batch::DocumentsBatchTransitionAction;
 use dpp::block::epoch::Epoch;
+use dpp::prelude::{Identifier, IdentityNonce};
 use dpp::version::PlatformVersion;
+use std::collections::BTreeMap;
 
 impl DriveHighLevelOperationConverter for DocumentsBatchTransitionAction {
     fn into_high_level_drive_operations<'b>(
@@ -12,20 +15,72 @@ impl DriveHighLevelOperationConverter for DocumentsBatchTransitionAction {
         epoch: &Epoch,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<DriveOperation<'b>>, Error> {
-        let owner_id = self.owner_id();
-        let transitions = self.transitions_owned();
-        Ok(transitions
-            .into_iter()
-            .map(|transition| {
-                transition.into_high_level_document_drive_operations(
-                    epoch,
-                    owner_id,
-                    platform_version,
-                )
-            })
-            .collect::<Result<Vec<Vec<DriveOperation>>, Error>>()?
-            .into_iter()
+        Ok(self
+            .into_high_level_drive_operations_by_contract(epoch, platform_version)?
+            .into_values()
             .flatten()
             .collect())
     }
 }
+
+impl DocumentsBatchTransitionAction {
+    /// The high level drive operations of the batch grouped by the data contract they are for.
+    ///
+    /// A batch can write to multiple contracts, but the identity contract nonce must only be
+    /// updated once per contract, as every nonce update of a batch is merged against the same
+    /// stored nonce. Because of this the nonce operations of the transitions are replaced by a
+    /// single operation per contract setting the highest nonce used for that contract.
+    pub fn into_high_level_drive_operations_by_contract<'b>(
+        self,
+        epoch: &Epoch,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Identifier, Vec<DriveOperation<'b>>>, Error> {
+        let owner_id = self.owner_id();
+        let transitions = self.transitions_owned();
+
+        let mut identity_contract_nonces: BTreeMap<Identifier, IdentityNonce> = BTreeMap::new();
+        let mut operations_by_contract: BTreeMap<Identifier, Vec<DriveOperation>> = BTreeMap::new();
+
+        for transition in transitions {
+            let data_contract_id = transition.data_contract_id();
+            let operations = transition.into_high_level_document_drive_operations(
+                epoch,
+                owner_id,
+                platform_version,
+            )?;
+            let contract_operations = operations_by_contract.entry(data_contract_id).or_default();
+            for operation in operations {
+                if let IdentityOperation(IdentityOperationType::UpdateIdentityContractNonce {
+                    nonce,
+                    ..
+                }) = operation
+                {
+                    let highest_nonce = identity_contract_nonces
+                        .entry(data_contract_id)
+                        .or_insert(nonce);
+                    if nonce > *highest_nonce {
+                        *highest_nonce = nonce;
+                    }
+                } else {
+                    contract_operations.push(operation);
+                }
+            }
+        }
+
+        for (data_contract_id, nonce) in identity_contract_nonces {
+            operations_by_contract
+                .entry(data_contract_id)
+                .or_default()
+                .insert(
+                    0,
+                    IdentityOperation(IdentityOperationType::UpdateIdentityContractNonce {
+                        identity_id: owner_id.into_buffer(),
+                        contract_id: data_contract_id.into_buffer(),
+                        nonce,
+                    }),
+                );
+        }
+
+        Ok(operations_by_contract)
+    }
+}
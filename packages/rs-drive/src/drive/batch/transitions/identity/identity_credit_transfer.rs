@@ -1,8 +1,13 @@
 use crate::drive::batch::transitions::DriveHighLevelOperationConverter;
-use crate::drive::batch::DriveOperation::IdentityOperation;
-use crate::drive::batch::{DriveOperation, IdentityOperationType};
+use crate::drive::batch::DriveOperation::{
+    IdentityOperation, PendingTransferOperation, SystemOperation,
+};
+use crate::drive::batch::{
+    DriveOperation, IdentityOperationType, PendingTransferOperationType, SystemOperationType,
+};
 
 use crate::error::Error;
+use crate::state_transition_action::identity::identity_credit_transfer::v2::PendingTransferActionOperation;
 use crate::state_transition_action::identity::identity_credit_transfer::IdentityCreditTransferTransitionAction;
 use dpp::block::epoch::Epoch;
 use dpp::version::PlatformVersion;
@@ -13,12 +18,72 @@ impl DriveHighLevelOperationConverter for IdentityCreditTransferTransitionAction
         _epoch: &Epoch,
         _platform_version: &PlatformVersion,
     ) -> Result<Vec<DriveOperation<'a>>, Error> {
-        let recipient_id = self.recipient_id();
+        let signing_key_spending_operation =
+            self.signing_key_spending().map(|(key_id, key_spending)| {
+                IdentityOperation(IdentityOperationType::SetIdentityKeySpending {
+                    identity_id: self.identity_id().to_buffer(),
+                    key_id,
+                    key_spending,
+                })
+            });
+
+        if let IdentityCreditTransferTransitionAction::V2(transition) = self {
+            let identity_id = transition.identity_id;
+
+            let mut drive_operations = vec![IdentityOperation(
+                IdentityOperationType::UpdateIdentityNonce {
+                    identity_id: identity_id.into_buffer(),
+                    nonce: transition.nonce,
+                },
+            )];
+
+            drive_operations.extend(signing_key_spending_operation);
+
+            // credits of a pending transfer are out of the system until released or cancelled
+            match transition.operation {
+                PendingTransferActionOperation::Schedule(pending_transfer) => {
+                    drive_operations.extend([
+                        IdentityOperation(IdentityOperationType::RemoveFromIdentityBalance {
+                            identity_id: identity_id.to_buffer(),
+                            balance_to_remove: pending_transfer.amount,
+                        }),
+                        SystemOperation(SystemOperationType::RemoveFromSystemCredits {
+                            amount: pending_transfer.amount,
+                        }),
+                        PendingTransferOperation(
+                            PendingTransferOperationType::InsertPendingTransfer {
+                                pending_transfer,
+                            },
+                        ),
+                    ]);
+                }
+                PendingTransferActionOperation::Cancel(pending_transfer) => {
+                    drive_operations.extend([
+                        IdentityOperation(IdentityOperationType::AddToIdentityBalance {
+                            identity_id: identity_id.to_buffer(),
+                            added_balance: pending_transfer.amount,
+                        }),
+                        SystemOperation(SystemOperationType::AddToSystemCredits {
+                            amount: pending_transfer.amount,
+                        }),
+                        PendingTransferOperation(
+                            PendingTransferOperationType::RemovePendingTransfer {
+                                pending_transfer,
+                            },
+                        ),
+                    ]);
+                }
+            }
+
+            return Ok(drive_operations);
+        }
+
+        let recipients = self.recipients();
         let identity_id = self.identity_id();
         let transfer_amount = self.transfer_amount();
         let nonce = self.nonce();
 
-        let drive_operations = vec![
+        let mut drive_operations = vec![
             IdentityOperation(IdentityOperationType::UpdateIdentityNonce {
                 identity_id: identity_id.into_buffer(),
                 nonce,
@@ -27,11 +92,18 @@ impl DriveHighLevelOperationConverter for IdentityCreditTransferTransitionAction
                 identity_id: identity_id.to_buffer(),
                 balance_to_remove: transfer_amount,
             }),
+        ];
+
+        drive_operations.extend(signing_key_spending_operation);
+
+        // all recipients are credited in the same batch as the sender is debited
+        drive_operations.extend(recipients.into_iter().map(|(recipient_id, amount)| {
             IdentityOperation(IdentityOperationType::AddToIdentityBalance {
                 identity_id: recipient_id.to_buffer(),
-                added_balance: transfer_amount,
-            }),
-        ];
+                added_balance: amount,
+            })
+        }));
+
         Ok(drive_operations)
     }
 }
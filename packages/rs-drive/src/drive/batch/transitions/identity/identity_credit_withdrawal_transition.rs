@@ -19,9 +19,10 @@ impl DriveHighLevelOperationConverter for IdentityCreditWithdrawalTransitionActi
         let identity_id = self.identity_id();
         let nonce = self.nonce();
         let balance_to_remove = self.amount();
+        let signing_key_spending = self.signing_key_spending();
         let prepared_withdrawal_document = self.prepared_withdrawal_document_owned();
 
-        let drive_operations = vec![
+        let mut drive_operations = vec![
             IdentityOperation(IdentityOperationType::RemoveFromIdentityBalance {
                 identity_id: identity_id.to_buffer(),
                 balance_to_remove,
@@ -44,6 +45,16 @@ impl DriveHighLevelOperationConverter for IdentityCreditWithdrawalTransitionActi
             }),
         ];
 
+        if let Some((key_id, key_spending)) = signing_key_spending {
+            drive_operations.push(IdentityOperation(
+                IdentityOperationType::SetIdentityKeySpending {
+                    identity_id: identity_id.to_buffer(),
+                    key_id,
+                    key_spending,
+                },
+            ));
+        }
+
         Ok(drive_operations)
     }
 }
@@ -0,0 +1,99 @@
+use crate::drive::batch::transitions::DriveHighLevelOperationConverter;
+use crate::drive::batch::DriveOperation::IdentityOperation;
+use crate::drive::batch::{DriveOperation, IdentityOperationType};
+
+use crate::error::Error;
+use dpp::block::epoch::Epoch;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::IdentityPublicKey;
+
+use crate::state_transition_action::identity::identity_recover::v0::IdentityRecoverActionOperation;
+use crate::state_transition_action::identity::identity_recover::IdentityRecoverTransitionAction;
+use dpp::version::PlatformVersion;
+
+impl DriveHighLevelOperationConverter for IdentityRecoverTransitionAction {
+    fn into_high_level_drive_operations<'a>(
+        self,
+        _epoch: &Epoch,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'a>>, Error> {
+        let IdentityRecoverTransitionAction::V0(action) = self;
+
+        let identity_id = action.identity_id.to_buffer();
+
+        let mut drive_operations = vec![IdentityOperation(
+            IdentityOperationType::UpdateIdentityNonce {
+                identity_id,
+                nonce: action.nonce,
+            },
+        )];
+
+        match action.operation {
+            IdentityRecoverActionOperation::SetRecoveryProposal(recovery_proposal) => {
+                drive_operations.push(IdentityOperation(
+                    IdentityOperationType::SetIdentityRecoveryProposal {
+                        identity_id,
+                        recovery_proposal,
+                    },
+                ));
+            }
+            IdentityRecoverActionOperation::RemoveRecoveryProposal => {
+                drive_operations.push(IdentityOperation(
+                    IdentityOperationType::RemoveIdentityRecoveryProposal { identity_id },
+                ));
+            }
+            IdentityRecoverActionOperation::SetRecoveryDelay(recovery_delay_ms) => {
+                drive_operations.push(IdentityOperation(
+                    IdentityOperationType::SetIdentityRecoveryDelay {
+                        identity_id,
+                        recovery_delay_ms,
+                    },
+                ));
+            }
+            IdentityRecoverActionOperation::ReplaceMasterKeys {
+                master_keys_to_disable,
+                new_master_keys,
+                disabled_at,
+                revision,
+            } => {
+                let (unique_keys, non_unique_keys): (
+                    Vec<IdentityPublicKey>,
+                    Vec<IdentityPublicKey>,
+                ) = new_master_keys
+                    .into_iter()
+                    .partition(|key| key.key_type().is_unique_key_type());
+
+                drive_operations.push(IdentityOperation(
+                    IdentityOperationType::RemoveIdentityRecoveryProposal { identity_id },
+                ));
+
+                drive_operations.push(IdentityOperation(
+                    IdentityOperationType::UpdateIdentityRevision {
+                        identity_id,
+                        revision,
+                    },
+                ));
+
+                if !master_keys_to_disable.is_empty() {
+                    drive_operations.push(IdentityOperation(
+                        IdentityOperationType::DisableIdentityKeys {
+                            identity_id,
+                            keys_ids: master_keys_to_disable,
+                            disable_at: disabled_at,
+                        },
+                    ));
+                }
+
+                drive_operations.push(IdentityOperation(
+                    IdentityOperationType::AddNewKeysToIdentity {
+                        identity_id,
+                        unique_keys_to_add: unique_keys,
+                        non_unique_keys_to_add: non_unique_keys,
+                    },
+                ));
+            }
+        }
+
+        Ok(drive_operations)
+    }
+}
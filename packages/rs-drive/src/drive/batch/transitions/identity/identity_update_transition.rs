@@ -19,6 +19,7 @@ impl DriveHighLevelOperationConverter for IdentityUpdateTransitionAction {
         let identity_id = self.identity_id();
         let revision = self.revision();
         let public_keys_disabled_at = self.public_keys_disabled_at();
+        let public_keys_spending_limits = self.public_keys_spending_limits().clone();
         let (add_public_keys, disable_public_keys) = self.public_keys_to_add_and_disable_owned();
 
         let (unique_keys, non_unique_keys): (Vec<IdentityPublicKey>, Vec<IdentityPublicKey>) =
@@ -56,6 +57,15 @@ impl DriveHighLevelOperationConverter for IdentityUpdateTransitionAction {
             }
         }
 
+        if !public_keys_spending_limits.is_empty() {
+            drive_operations.push(IdentityOperation(
+                IdentityOperationType::UpdateIdentityKeySpendingLimits {
+                    identity_id: identity_id.to_buffer(),
+                    key_spending_limits: public_keys_spending_limits,
+                },
+            ));
+        }
+
         Ok(drive_operations)
     }
 }
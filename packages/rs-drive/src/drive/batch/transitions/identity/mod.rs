@@ -1,5 +1,6 @@
 mod identity_create_transition;
 mod identity_credit_transfer;
 mod identity_credit_withdrawal_transition;
+mod identity_recover_transition;
 mod identity_top_up_transition;
 mod identity_update_transition;
@@ -60,6 +60,10 @@ impl<'s> DriveHighLevelOperationConverter for StateTransitionAction {
                 identity_credit_transfer_transition,
             ) => identity_credit_transfer_transition
                 .into_high_level_drive_operations(epoch, platform_version),
+            StateTransitionAction::IdentityRecoverAction(identity_recover_transition) => {
+                identity_recover_transition
+                    .into_high_level_drive_operations(epoch, platform_version)
+            }
             StateTransitionAction::BumpIdentityNonceAction(bump_identity_nonce_transition) => {
                 bump_identity_nonce_transition
                     .into_high_level_drive_operations(epoch, platform_version)
@@ -1,5 +1,6 @@
 use crate::drive::contract::paths;
 
+use crate::drive::document::TOKEN_INDICES_TREE_KEY;
 use crate::drive::flags::StorageFlags;
 use crate::drive::object_size_info::DriveKeyInfo::{Key, KeyRef};
 use crate::drive::{contract_documents_path, Drive, RootTree};
@@ -12,6 +13,7 @@ use dpp::data_contract::config::v0::DataContractConfigGettersV0;
 use dpp::data_contract::DataContract;
 use dpp::fee::fee_result::FeeResult;
 
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
 use dpp::serialization::PlatformSerializableWithPlatformVersion;
 
@@ -217,6 +219,37 @@ impl Drive {
                     index_cache.insert(index_bytes);
                 }
             }
+
+            // tokenized indices are kept in their own tree, with a tree for each property
+            if !document_type.as_ref().token_indices().is_empty() {
+                self.batch_insert_empty_tree(
+                    type_path,
+                    KeyRef(TOKEN_INDICES_TREE_KEY.as_slice()),
+                    storage_flags.as_ref(),
+                    &mut batch_operations,
+                    &platform_version.drive,
+                )?;
+
+                let token_indices_path = [
+                    type_path[0],
+                    type_path[1],
+                    type_path[2],
+                    type_path[3],
+                    TOKEN_INDICES_TREE_KEY.as_slice(),
+                ];
+
+                for index in document_type.as_ref().token_indices() {
+                    for index_property in index.properties.iter() {
+                        self.batch_insert_empty_tree(
+                            token_indices_path,
+                            KeyRef(index_property.name.as_bytes()),
+                            storage_flags.as_ref(),
+                            &mut batch_operations,
+                            &platform_version.drive,
+                        )?;
+                    }
+                }
+            }
         }
 
         if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
@@ -1,3 +1,4 @@
+use crate::drive::document::TOKEN_INDICES_TREE_KEY;
 use crate::drive::flags::StorageFlags;
 use crate::drive::grove_operations::BatchInsertTreeApplyType;
 use crate::drive::object_size_info::DriveKeyInfo::KeyRef;
@@ -322,6 +323,43 @@ impl Drive {
                         index_cache.insert(index_bytes);
                     }
                 }
+
+                if !document_type.as_ref().token_indices().is_empty() {
+                    self.batch_insert_empty_tree_if_not_exists(
+                        PathFixedSizeKeyRef((type_path, TOKEN_INDICES_TREE_KEY.as_slice())),
+                        storage_flags.as_ref().map(|flags| flags.as_ref()),
+                        apply_type,
+                        transaction,
+                        &mut None,
+                        &mut batch_operations,
+                        drive_version,
+                    )?;
+
+                    let token_indices_path = [
+                        type_path[0],
+                        type_path[1],
+                        type_path[2],
+                        type_path[3],
+                        TOKEN_INDICES_TREE_KEY.as_slice(),
+                    ];
+
+                    for index in document_type.as_ref().token_indices() {
+                        for index_property in index.properties.iter() {
+                            self.batch_insert_empty_tree_if_not_exists(
+                                PathFixedSizeKeyRef((
+                                    token_indices_path,
+                                    index_property.name.as_bytes(),
+                                )),
+                                storage_flags.as_ref().map(|flags| flags.as_ref()),
+                                apply_type,
+                                transaction,
+                                &mut None,
+                                &mut batch_operations,
+                                drive_version,
+                            )?;
+                        }
+                    }
+                }
             } else {
                 // We can just insert this directly because the original document type already exists
                 self.batch_insert_empty_tree(
@@ -364,6 +402,37 @@ impl Drive {
                         index_cache.insert(index_bytes);
                     }
                 }
+
+                // tokenized indices are kept in their own tree, with a tree for each property
+                if !document_type.as_ref().token_indices().is_empty() {
+                    self.batch_insert_empty_tree(
+                        type_path,
+                        KeyRef(TOKEN_INDICES_TREE_KEY.as_slice()),
+                        storage_flags.as_ref().map(|flags| flags.as_ref()),
+                        &mut batch_operations,
+                        drive_version,
+                    )?;
+
+                    let token_indices_path = [
+                        type_path[0],
+                        type_path[1],
+                        type_path[2],
+                        type_path[3],
+                        TOKEN_INDICES_TREE_KEY.as_slice(),
+                    ];
+
+                    for index in document_type.as_ref().token_indices() {
+                        for index_property in index.properties.iter() {
+                            self.batch_insert_empty_tree(
+                                token_indices_path,
+                                KeyRef(index_property.name.as_bytes()),
+                                storage_flags.as_ref().map(|flags| flags.as_ref()),
+                                &mut batch_operations,
+                                drive_version,
+                            )?;
+                        }
+                    }
+                }
             }
         }
         Ok(batch_operations)
@@ -141,6 +141,15 @@ impl Drive {
             document_type,
         };
 
+        self.remove_index_sums_for_document_operations(
+            &document_and_contract_info,
+            &previous_batch_operations,
+            estimated_costs_only_with_layer_info,
+            transaction,
+            &mut batch_operations,
+            platform_version,
+        )?;
+
         self.remove_indices_for_top_index_level_for_contract_operations(
             &document_and_contract_info,
             &previous_batch_operations,
@@ -149,6 +158,15 @@ impl Drive {
             &mut batch_operations,
             platform_version,
         )?;
+
+        self.remove_token_indices_for_document_operations(
+            &document_and_contract_info,
+            &previous_batch_operations,
+            estimated_costs_only_with_layer_info,
+            transaction,
+            &mut batch_operations,
+            platform_version,
+        )?;
         Ok(batch_operations)
     }
 }
@@ -0,0 +1,53 @@
+mod v0;
+
+use crate::drive::object_size_info::DocumentAndContractInfo;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::version::PlatformVersion;
+
+use grovedb::batch::KeyInfoPath;
+
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Removes the value of the document from the sum tree of every index declaring a sum property.
+    ///
+    /// This must happen before the references of the document are removed so that the index
+    /// trees left empty are deleted up the tree.
+    pub(crate) fn remove_index_sums_for_document_operations(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        previous_batch_operations: &Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .delete
+            .remove_index_sums_for_document_operations
+        {
+            0 => self.remove_index_sums_for_document_operations_v0(
+                document_and_contract_info,
+                previous_batch_operations,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                batch_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "remove_index_sums_for_document_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
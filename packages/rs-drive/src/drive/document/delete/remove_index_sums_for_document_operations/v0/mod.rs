@@ -0,0 +1,139 @@
+use crate::drive::defaults::{
+    AVERAGE_BALANCE_SIZE, CONTRACT_DOCUMENTS_PATH_HEIGHT, DEFAULT_HASH_SIZE_U8,
+};
+use crate::drive::document::{
+    document_index_sum_tree_estimated_path, document_index_sum_tree_path,
+};
+use crate::drive::grove_operations::BatchDeleteUpTreeApplyType;
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::DocumentV0Getters;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerSizes::AllItems;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Removes the value of the document from the sum tree of every index declaring a sum property.
+    pub(super) fn remove_index_sums_for_document_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        previous_batch_operations: &Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let document_type = document_and_contract_info.document_type;
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_index_sums(
+                document_and_contract_info,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            )?;
+            return self.remove_estimated_index_sums_for_document_operations(
+                document_and_contract_info,
+                previous_batch_operations,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                batch_operations,
+                platform_version,
+            );
+        }
+
+        let Some((document, _)) = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_borrowed_document_and_storage_flags()
+        else {
+            return Ok(());
+        };
+
+        for index in document_type
+            .indices()
+            .iter()
+            .filter(|index| index.sum_property.is_some())
+        {
+            let sum_tree_path = document_index_sum_tree_path(
+                document_and_contract_info.contract.id_ref().as_bytes(),
+                document_type,
+                index,
+                document,
+                document_and_contract_info.owned_document_info.owner_id,
+                platform_version,
+            )?;
+
+            // the sum tree is removed with its last item, the index levels above it are removed
+            // with the references of the document
+            self.batch_delete_up_tree_while_empty(
+                KeyInfoPath::from_known_owned_path(sum_tree_path),
+                document.id().as_slice(),
+                Some(CONTRACT_DOCUMENTS_PATH_HEIGHT),
+                BatchDeleteUpTreeApplyType::StatefulBatchDelete {
+                    is_known_to_be_subtree_with_sum: Some((false, false)),
+                },
+                transaction,
+                previous_batch_operations,
+                batch_operations,
+                &platform_version.drive,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Adds the operations of removing a document from the sum tree of every index declaring a
+    /// sum property when only estimating costs, the index values are then not known.
+    fn remove_estimated_index_sums_for_document_operations(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        previous_batch_operations: &Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let flags_size = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_storage_flags_ref()
+            .map(|s| s.serialized_size());
+        for index in document_and_contract_info
+            .document_type
+            .indices()
+            .iter()
+            .filter(|index| index.sum_property.is_some())
+        {
+            let sum_tree_path =
+                document_index_sum_tree_estimated_path(document_and_contract_info, index)?;
+            let delete_apply_type = Self::stateless_delete_of_non_tree_for_costs(
+                AllItems(DEFAULT_HASH_SIZE_U8, AVERAGE_BALANCE_SIZE, flags_size),
+                &sum_tree_path,
+                Some((false, false)),
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            )?;
+            // only the size of the key matters for the costs
+            self.batch_delete_up_tree_while_empty(
+                sum_tree_path,
+                &[0; 32],
+                Some(CONTRACT_DOCUMENTS_PATH_HEIGHT),
+                delete_apply_type,
+                transaction,
+                previous_batch_operations,
+                batch_operations,
+                &platform_version.drive,
+            )?;
+        }
+        Ok(())
+    }
+}
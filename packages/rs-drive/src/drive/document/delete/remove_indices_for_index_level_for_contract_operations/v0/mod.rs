@@ -83,17 +83,17 @@ impl Drive {
             let mut sub_level_index_path_info = index_path_info.clone();
             let index_property_key = KeyRef(name.as_bytes());
 
-            let document_index_field = document_and_contract_info
+            // an array has a key for each of its items
+            let document_index_fields = document_and_contract_info
                 .owned_document_info
                 .document_info
-                .get_raw_for_document_type(
+                .get_raw_index_keys_for_document_type(
                     name,
                     document_type,
                     document_and_contract_info.owned_document_info.owner_id,
                     Some((sub_level, event_id)),
                     platform_version,
-                )?
-                .unwrap_or_default();
+                )?;
 
             sub_level_index_path_info.push(index_property_key)?;
 
@@ -127,25 +127,28 @@ impl Drive {
             // Iteration 1. the index path is now something likeDataContracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId
             // Iteration 2. the index path is now something likeDataContracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/accountReference
 
-            any_fields_null |= document_index_field.is_empty();
-
-            // we push the actual value of the index path
-            sub_level_index_path_info.push(document_index_field)?;
-            // Iteration 1. the index path is now something likeDataContracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/
-            // Iteration 2. the index path is now something likeDataContracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/accountReference/<accountReference>
-            self.remove_indices_for_index_level_for_contract_operations(
-                document_and_contract_info,
-                sub_level_index_path_info,
-                sub_level,
-                any_fields_null,
-                storage_flags,
-                previous_batch_operations,
-                estimated_costs_only_with_layer_info,
-                event_id,
-                transaction,
-                batch_operations,
-                platform_version,
-            )?;
+            for document_index_field in document_index_fields {
+                any_fields_null |= document_index_field.is_empty();
+
+                let mut document_index_path_info = sub_level_index_path_info.clone();
+                // we push the actual value of the index path
+                document_index_path_info.push(document_index_field)?;
+                // Iteration 1. the index path is now something likeDataContracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/
+                // Iteration 2. the index path is now something likeDataContracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/accountReference/<accountReference>
+                self.remove_indices_for_index_level_for_contract_operations(
+                    document_and_contract_info,
+                    document_index_path_info,
+                    sub_level,
+                    any_fields_null,
+                    storage_flags,
+                    previous_batch_operations,
+                    estimated_costs_only_with_layer_info,
+                    event_id,
+                    transaction,
+                    batch_operations,
+                    platform_version,
+                )?;
+            }
         }
         Ok(())
     }
@@ -0,0 +1,126 @@
+use crate::drive::defaults::{CONTRACT_DOCUMENTS_PATH_HEIGHT, DEFAULT_HASH_SIZE_U8};
+use crate::drive::document::{
+    contract_document_type_token_index_path_vec, document_index_tokens, document_reference_size,
+    unique_event_id,
+};
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::config::v0::DataContractConfigGettersV0;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::MAX_INDEXED_TOKEN_LENGTH;
+use dpp::document::DocumentV0Getters;
+use dpp::version::PlatformVersion;
+use grovedb::batch::key_info::KeyInfo;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerSizes::AllReference;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Removes the references to the document under each of its words for every tokenized index.
+    pub(super) fn remove_token_indices_for_document_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        previous_batch_operations: &Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let contract = document_and_contract_info.contract;
+        let document_type = document_and_contract_info.document_type;
+        if document_type.token_indices().is_empty() {
+            return Ok(());
+        }
+        let storage_flags =
+            if document_type.documents_mutable() || contract.config().can_be_deleted() {
+                document_and_contract_info
+                    .owned_document_info
+                    .document_info
+                    .get_storage_flags_ref()
+            } else {
+                None //there are no need for storage flags if documents are not mutable and contract can not be deleted
+            };
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_token_indices(
+                document_and_contract_info,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            )?;
+        }
+
+        let document = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_borrowed_document();
+        let event_id = unique_event_id();
+
+        for index in document_type.token_indices() {
+            let Some(index_property) = index.properties.first() else {
+                return Err(Error::Drive(DriveError::CorruptedContractIndexes(
+                    "tokenized index should have a property",
+                )));
+            };
+            let token_index_path =
+                KeyInfoPath::from_known_owned_path(contract_document_type_token_index_path_vec(
+                    contract.id_ref().as_bytes(),
+                    document_type.name(),
+                    &index_property.name,
+                ));
+
+            // the words are not known when estimating, so we account for a single word of the
+            // maximum length
+            let token_keys = match document {
+                Some(document) => document_index_tokens(document, index)?
+                    .into_iter()
+                    .map(|token| KeyInfo::KnownKey(token.into_bytes()))
+                    .collect(),
+                None => vec![KeyInfo::MaxKeySize {
+                    unique_id: document_type.unique_id_for_storage().to_vec(),
+                    max_size: MAX_INDEXED_TOKEN_LENGTH as u8,
+                }],
+            };
+
+            for token_key in token_keys {
+                let mut token_path = token_index_path.clone();
+                token_path.push(token_key);
+
+                let delete_apply_type = Self::stateless_delete_of_non_tree_for_costs(
+                    AllReference(
+                        DEFAULT_HASH_SIZE_U8,
+                        document_reference_size(document_type),
+                        storage_flags.map(|s| s.serialized_size()),
+                    ),
+                    &token_path,
+                    // we know we are not deleting a tree
+                    Some((false, false)),
+                    estimated_costs_only_with_layer_info,
+                    platform_version,
+                )?;
+
+                // the word tree is removed with its last reference, the tree of the tokenized
+                // property is kept
+                self.batch_delete_up_tree_while_empty(
+                    token_path,
+                    document
+                        .map(|document| document.id_ref().as_slice())
+                        .unwrap_or(event_id.as_slice()),
+                    Some(CONTRACT_DOCUMENTS_PATH_HEIGHT + 1),
+                    delete_apply_type,
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                    &platform_version.drive,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use crate::drive::object_size_info::DocumentAndContractInfo;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerInformation;
+use std::collections::HashMap;
+
+mod v0;
+
+impl Drive {
+    /// Adds estimated storage costs for adding or removing a document in the sum trees of the
+    /// indices declaring a sum property.
+    ///
+    /// This function uses the platform version to determine the appropriate method to estimate these
+    /// costs. Currently, it supports version `0` and delegates the estimation to
+    /// `add_estimation_costs_for_index_sums_v0`.
+    ///
+    /// # Arguments
+    /// * `document_and_contract_info`: Information about the document and its associated contract.
+    /// * `estimated_costs_only_with_layer_info`: A mutable reference to a hashmap where the estimated layer
+    ///   information will be stored for the index sum paths.
+    /// * `platform_version`: Version of the platform being used, which determines the estimation method.
+    ///
+    /// # Errors
+    /// * `DriveError::UnknownVersionMismatch`: Returned if the platform version method specified is unsupported.
+    pub(crate) fn add_estimation_costs_for_index_sums(
+        document_and_contract_info: &DocumentAndContractInfo,
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .estimation_costs
+            .add_estimation_costs_for_index_sums
+        {
+            0 => Self::add_estimation_costs_for_index_sums_v0(
+                document_and_contract_info,
+                estimated_costs_only_with_layer_info,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "Drive::add_estimation_costs_for_index_sums".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::drive::defaults::{AVERAGE_BALANCE_SIZE, DEFAULT_HASH_SIZE_U8};
+use crate::drive::document::document_index_sum_tree_estimated_path;
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods};
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use grovedb::batch::key_info::KeyInfo;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, PotentiallyAtMaxElements};
+use grovedb::EstimatedLayerInformation;
+use grovedb::EstimatedLayerSizes::{AllItems, AllSubtrees};
+use grovedb::EstimatedSumTrees::{NoSumTrees, SomeSumTrees};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds estimated storage costs for adding or removing a document in the sum trees of the
+    /// indices declaring a sum property.
+    ///
+    /// Every index property is a tree holding a tree per value, the last value holds the
+    /// references of the index under key 0 next to the sum tree, which in turn holds a sum item
+    /// per document.
+    pub(super) fn add_estimation_costs_for_index_sums_v0(
+        document_and_contract_info: &DocumentAndContractInfo,
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+    ) -> Result<(), Error> {
+        let document_type = document_and_contract_info.document_type;
+        let flags_size = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_storage_flags_ref()
+            .map(|s| s.serialized_size());
+
+        for index in document_type
+            .indices()
+            .iter()
+            .filter(|index| index.sum_property.is_some())
+        {
+            let sum_tree_path =
+                document_index_sum_tree_estimated_path(document_and_contract_info, index)?;
+            let base_path_len = sum_tree_path.0.len() - 2 * index.properties.len() - 1;
+
+            for (position, _) in index.properties.iter().enumerate() {
+                let property_path_len = base_path_len + 2 * position + 1;
+                let KeyInfo::MaxKeySize { max_size, .. } = &sum_tree_path.0[property_path_len]
+                else {
+                    continue;
+                };
+
+                // On this level we have a tree for every value of the property
+                estimated_costs_only_with_layer_info.insert(
+                    KeyInfoPath::from_vec(sum_tree_path.0[..property_path_len].to_vec()),
+                    EstimatedLayerInformation {
+                        is_sum_tree: false,
+                        estimated_layer_count: PotentiallyAtMaxElements,
+                        estimated_layer_sizes: AllSubtrees(*max_size, NoSumTrees, flags_size),
+                    },
+                );
+
+                let is_last_property = position + 1 == index.properties.len();
+                // On this level we have the next index property, or the references and the sum
+                // tree for the last one
+                estimated_costs_only_with_layer_info.insert(
+                    KeyInfoPath::from_vec(sum_tree_path.0[..property_path_len + 1].to_vec()),
+                    EstimatedLayerInformation {
+                        is_sum_tree: false,
+                        estimated_layer_count: ApproximateElements(2),
+                        estimated_layer_sizes: if is_last_property {
+                            AllSubtrees(
+                                1,
+                                SomeSumTrees {
+                                    sum_trees_weight: 1,
+                                    non_sum_trees_weight: 1,
+                                },
+                                flags_size,
+                            )
+                        } else {
+                            AllSubtrees(DEFAULT_HASH_SIZE_U8, NoSumTrees, flags_size)
+                        },
+                    },
+                );
+            }
+
+            // On this level we have a sum item for every document
+            estimated_costs_only_with_layer_info.insert(
+                sum_tree_path,
+                EstimatedLayerInformation {
+                    is_sum_tree: true,
+                    estimated_layer_count: PotentiallyAtMaxElements,
+                    estimated_layer_sizes: AllItems(
+                        DEFAULT_HASH_SIZE_U8,
+                        AVERAGE_BALANCE_SIZE,
+                        flags_size,
+                    ),
+                },
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use crate::drive::defaults::DEFAULT_HASH_SIZE_U8;
+use crate::drive::document::{
+    contract_document_type_path_vec, contract_document_type_token_index_path_vec,
+    document_reference_size, TOKEN_INDICES_TREE_KEY,
+};
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods};
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::config::v0::DataContractConfigGettersV0;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::MAX_INDEXED_TOKEN_LENGTH;
+use grovedb::batch::key_info::KeyInfo;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, PotentiallyAtMaxElements};
+use grovedb::EstimatedLayerInformation;
+use grovedb::EstimatedLayerSizes::{AllReference, AllSubtrees};
+use grovedb::EstimatedSumTrees::NoSumTrees;
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds estimated storage costs for adding a document under the words of its tokenized indices.
+    ///
+    /// Below the tokenized indices tree there is a tree per tokenized property, holding a tree per
+    /// word, which in turn holds a reference per document having the word.
+    pub(super) fn add_estimation_costs_for_token_indices_v0(
+        document_and_contract_info: &DocumentAndContractInfo,
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+    ) -> Result<(), Error> {
+        let contract = document_and_contract_info.contract;
+        let document_type = document_and_contract_info.document_type;
+        let token_indices = document_type.token_indices();
+        if token_indices.is_empty() {
+            return Ok(());
+        }
+        let storage_flags =
+            if document_type.documents_mutable() || contract.config().can_be_deleted() {
+                document_and_contract_info
+                    .owned_document_info
+                    .document_info
+                    .get_storage_flags_ref()
+            } else {
+                None
+            };
+        let flags_size = storage_flags.map(|s| s.serialized_size());
+
+        let mut token_indices_path =
+            contract_document_type_path_vec(contract.id_ref().as_bytes(), document_type.name());
+        token_indices_path.push(TOKEN_INDICES_TREE_KEY.to_vec());
+
+        // On this level we have a tree for every tokenized property
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(token_indices_path),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: ApproximateElements(token_indices.len() as u32),
+                estimated_layer_sizes: AllSubtrees(DEFAULT_HASH_SIZE_U8, NoSumTrees, flags_size),
+            },
+        );
+
+        for index in token_indices {
+            let Some(index_property) = index.properties.first() else {
+                continue;
+            };
+            let token_index_path = contract_document_type_token_index_path_vec(
+                contract.id_ref().as_bytes(),
+                document_type.name(),
+                &index_property.name,
+            );
+
+            // On this level we have a tree for every word
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(token_index_path.clone()),
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: PotentiallyAtMaxElements,
+                    estimated_layer_sizes: AllSubtrees(
+                        MAX_INDEXED_TOKEN_LENGTH as u8,
+                        NoSumTrees,
+                        flags_size,
+                    ),
+                },
+            );
+
+            let mut token_path = KeyInfoPath::from_known_owned_path(token_index_path);
+            token_path.push(KeyInfo::MaxKeySize {
+                unique_id: document_type.unique_id_for_storage().to_vec(),
+                max_size: MAX_INDEXED_TOKEN_LENGTH as u8,
+            });
+
+            // On this level we have a reference for every document having the word
+            estimated_costs_only_with_layer_info.insert(
+                token_path,
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: PotentiallyAtMaxElements,
+                    estimated_layer_sizes: AllReference(
+                        DEFAULT_HASH_SIZE_U8,
+                        document_reference_size(document_type),
+                        flags_size,
+                    ),
+                },
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,7 @@
 mod stateless_delete_of_non_tree_for_costs;
 
 mod add_estimation_costs_for_add_document_to_primary_storage;
+
+mod add_estimation_costs_for_token_indices;
+
+mod add_estimation_costs_for_index_sums;
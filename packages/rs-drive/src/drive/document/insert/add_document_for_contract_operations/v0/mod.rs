@@ -120,6 +120,24 @@ impl Drive {
             platform_version,
         )?;
 
+        self.add_index_sums_for_document_operations(
+            &document_and_contract_info,
+            previous_batch_operations,
+            estimated_costs_only_with_layer_info,
+            transaction,
+            &mut batch_operations,
+            platform_version,
+        )?;
+
+        self.add_token_indices_for_document_operations(
+            &document_and_contract_info,
+            previous_batch_operations,
+            estimated_costs_only_with_layer_info,
+            transaction,
+            &mut batch_operations,
+            platform_version,
+        )?;
+
         Ok(batch_operations)
     }
 }
@@ -0,0 +1,230 @@
+use crate::drive::defaults::{AVERAGE_BALANCE_SIZE, DEFAULT_HASH_SIZE_U8, STORAGE_FLAGS_SIZE};
+use crate::drive::document::{
+    document_index_sum_tree_estimated_path, document_index_sum_tree_path, document_index_sum_value,
+};
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::BatchInsertTreeApplyType;
+use crate::drive::grove_operations::DirectQueryType::StatefulDirectQuery;
+use crate::drive::object_size_info::PathKeyElementInfo::{
+    PathKeyElement, PathKeyUnknownElementSize,
+};
+use crate::drive::object_size_info::PathKeyInfo::PathKeySize;
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use crate::fee::op::LowLevelDriveOperation::GroveOperation;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::document::DocumentV0Getters;
+use dpp::version::PlatformVersion;
+use grovedb::batch::key_info::KeyInfo;
+use grovedb::batch::{KeyInfoPath, Op};
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds the value of the document to the sum tree of every index declaring a sum property.
+    pub(super) fn add_index_sums_for_document_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let document_type = document_and_contract_info.document_type;
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_index_sums(
+                document_and_contract_info,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            )?;
+            return self.add_estimated_index_sums_for_document_operations(
+                document_and_contract_info,
+                previous_batch_operations,
+                transaction,
+                batch_operations,
+                platform_version,
+            );
+        }
+
+        let Some((document, storage_flags)) = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_borrowed_document_and_storage_flags()
+        else {
+            return Ok(());
+        };
+
+        for index in document_type
+            .indices()
+            .iter()
+            .filter(|index| index.sum_property.is_some())
+        {
+            let mut sum_tree_path = document_index_sum_tree_path(
+                document_and_contract_info.contract.id_ref().as_bytes(),
+                document_type,
+                index,
+                document,
+                document_and_contract_info.owned_document_info.owner_id,
+                platform_version,
+            )?;
+            let sum_tree_key = sum_tree_path.pop().expect("sum tree path has a key");
+
+            self.batch_insert_empty_sum_tree_for_index_if_not_exists(
+                sum_tree_path.clone(),
+                sum_tree_key.clone(),
+                storage_flags,
+                previous_batch_operations,
+                transaction,
+                batch_operations,
+                platform_version,
+            )?;
+
+            sum_tree_path.push(sum_tree_key);
+
+            self.batch_insert(
+                PathKeyElement::<0>((
+                    sum_tree_path,
+                    document.id().to_vec(),
+                    Element::SumItem(
+                        document_index_sum_value(document, index)?,
+                        StorageFlags::map_to_some_element_flags(storage_flags),
+                    ),
+                )),
+                batch_operations,
+                &platform_version.drive,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Adds the operations of inserting a document in the sum tree of every index declaring a sum
+    /// property when only estimating costs, the index values are then not known.
+    fn add_estimated_index_sums_for_document_operations(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let drive_version = &platform_version.drive;
+        let document_type = document_and_contract_info.document_type;
+        let storage_flags = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_storage_flags_ref();
+
+        for index in document_type
+            .indices()
+            .iter()
+            .filter(|index| index.sum_property.is_some())
+        {
+            let mut sum_tree_path =
+                document_index_sum_tree_estimated_path(document_and_contract_info, index)?;
+            let sum_tree_key = sum_tree_path.0.pop().expect("sum tree path has a key");
+
+            self.batch_insert_empty_tree_if_not_exists(
+                PathKeySize::<0>(sum_tree_path.clone(), sum_tree_key.clone()),
+                storage_flags,
+                BatchInsertTreeApplyType::StatelessBatchInsertTree {
+                    in_tree_using_sums: false,
+                    is_sum_tree: true,
+                    flags_len: storage_flags
+                        .map(|s| s.serialized_size())
+                        .unwrap_or_default(),
+                },
+                transaction,
+                previous_batch_operations,
+                batch_operations,
+                drive_version,
+            )?;
+
+            sum_tree_path.push(sum_tree_key);
+            self.batch_insert(
+                PathKeyUnknownElementSize::<0>((
+                    sum_tree_path,
+                    KeyInfo::MaxKeySize {
+                        unique_id: document_type.unique_id_for_storage().to_vec(),
+                        max_size: DEFAULT_HASH_SIZE_U8,
+                    },
+                    Element::required_item_space(AVERAGE_BALANCE_SIZE, STORAGE_FLAGS_SIZE),
+                )),
+                batch_operations,
+                drive_version,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Inserts the sum tree of an index unless it exists or is already inserted in the batch.
+    /// A pending deletion of the sum tree is dropped instead.
+    pub(in crate::drive::document) fn batch_insert_empty_sum_tree_for_index_if_not_exists(
+        &self,
+        path: Vec<Vec<u8>>,
+        key: Vec<u8>,
+        storage_flags: Option<&StorageFlags>,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let sum_tree = match storage_flags {
+            Some(storage_flags) => {
+                Element::empty_sum_tree_with_flags(storage_flags.to_some_element_flags())
+            }
+            None => Element::empty_sum_tree(),
+        };
+        let drive_operation = LowLevelDriveOperation::insert_for_known_path_key_element(
+            path.clone(),
+            key.clone(),
+            sum_tree,
+        );
+
+        // we only add the operation if it doesn't already exist in the current batch
+        // if we had a delete then we need to remove the delete
+        for existing_operations in previous_batch_operations
+            .iter_mut()
+            .map(|operations| &mut **operations)
+            .chain(std::iter::once(&mut *batch_operations))
+        {
+            let position = existing_operations.iter().position(|operation| {
+                if operation == &drive_operation {
+                    return true;
+                }
+                let GroveOperation(grove_op) = operation else {
+                    return false;
+                };
+                grove_op.key == key
+                    && grove_op.path == path
+                    && matches!(grove_op.op, Op::DeleteTree | Op::DeleteSumTree)
+            });
+            if let Some(position) = position {
+                if existing_operations[position] != drive_operation {
+                    existing_operations.remove(position);
+                }
+                return Ok(());
+            }
+        }
+
+        let exists = self.grove_has_raw(
+            path.as_slice().into(),
+            key.as_slice(),
+            StatefulDirectQuery,
+            transaction,
+            batch_operations,
+            &platform_version.drive,
+        )?;
+        if !exists {
+            batch_operations.push(drive_operation);
+        }
+        Ok(())
+    }
+}
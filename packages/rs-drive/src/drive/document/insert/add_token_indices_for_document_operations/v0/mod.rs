@@ -0,0 +1,163 @@
+use crate::drive::defaults::{DEFAULT_HASH_SIZE_U8, STORAGE_FLAGS_SIZE};
+use crate::drive::document::{
+    contract_document_type_token_index_path_vec, document_index_tokens, make_document_reference,
+};
+use crate::drive::grove_operations::BatchInsertTreeApplyType;
+use crate::drive::object_size_info::DocumentInfo::DocumentEstimatedAverageSize;
+use crate::drive::object_size_info::PathKeyElementInfo::{
+    PathKeyElement, PathKeyUnknownElementSize,
+};
+use crate::drive::object_size_info::PathKeyInfo::{PathKey, PathKeySize};
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::config::v0::DataContractConfigGettersV0;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::MAX_INDEXED_TOKEN_LENGTH;
+use dpp::document::DocumentV0Getters;
+use dpp::version::PlatformVersion;
+use grovedb::batch::key_info::KeyInfo;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds a reference to the document under each of its words for every tokenized index.
+    pub(super) fn add_token_indices_for_document_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let drive_version = &platform_version.drive;
+        let contract = document_and_contract_info.contract;
+        let document_type = document_and_contract_info.document_type;
+        if document_type.token_indices().is_empty() {
+            return Ok(());
+        }
+        let storage_flags =
+            if document_type.documents_mutable() || contract.config().can_be_deleted() {
+                document_and_contract_info
+                    .owned_document_info
+                    .document_info
+                    .get_storage_flags_ref()
+            } else {
+                None //there are no need for storage flags if documents are not mutable and contract can not be deleted
+            };
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_token_indices(
+                document_and_contract_info,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            )?;
+        }
+
+        let apply_type = if estimated_costs_only_with_layer_info.is_none() {
+            BatchInsertTreeApplyType::StatefulBatchInsertTree
+        } else {
+            BatchInsertTreeApplyType::StatelessBatchInsertTree {
+                in_tree_using_sums: false,
+                is_sum_tree: false,
+                flags_len: storage_flags
+                    .map(|s| s.serialized_size())
+                    .unwrap_or_default(),
+            }
+        };
+
+        for index in document_type.token_indices() {
+            let Some(index_property) = index.properties.first() else {
+                return Err(Error::Drive(DriveError::CorruptedContractIndexes(
+                    "tokenized index should have a property",
+                )));
+            };
+            let token_index_path = contract_document_type_token_index_path_vec(
+                contract.id_ref().as_bytes(),
+                document_type.name(),
+                &index_property.name,
+            );
+
+            if let DocumentEstimatedAverageSize(max_size) =
+                &document_and_contract_info.owned_document_info.document_info
+            {
+                // the words are not known, so we account for a single word of the maximum length
+                let token_key_info = KeyInfo::MaxKeySize {
+                    unique_id: document_type.unique_id_for_storage().to_vec(),
+                    max_size: MAX_INDEXED_TOKEN_LENGTH as u8,
+                };
+                self.batch_insert_empty_tree_if_not_exists(
+                    PathKeySize::<0>(
+                        KeyInfoPath::from_known_owned_path(token_index_path.clone()),
+                        token_key_info.clone(),
+                    ),
+                    storage_flags,
+                    apply_type,
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                    drive_version,
+                )?;
+
+                let mut token_path = KeyInfoPath::from_known_owned_path(token_index_path);
+                token_path.push(token_key_info);
+                self.batch_insert(
+                    PathKeyUnknownElementSize::<0>((
+                        token_path,
+                        KeyInfo::MaxKeySize {
+                            unique_id: document_type.unique_id_for_storage().to_vec(),
+                            max_size: DEFAULT_HASH_SIZE_U8,
+                        },
+                        Element::required_item_space(*max_size, STORAGE_FLAGS_SIZE),
+                    )),
+                    batch_operations,
+                    drive_version,
+                )?;
+                continue;
+            }
+
+            let Some((document, document_storage_flags)) = document_and_contract_info
+                .owned_document_info
+                .document_info
+                .get_borrowed_document_and_storage_flags()
+            else {
+                return Ok(());
+            };
+
+            for token in document_index_tokens(document, index)? {
+                let token = token.into_bytes();
+
+                // here we are inserting an empty tree that will have the references of the documents having this word
+                self.batch_insert_empty_tree_if_not_exists(
+                    PathKey::<0>((token_index_path.clone(), token.clone())),
+                    storage_flags,
+                    apply_type,
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                    drive_version,
+                )?;
+
+                let mut token_path = token_index_path.clone();
+                token_path.push(token);
+                self.batch_insert(
+                    PathKeyElement::<0>((
+                        token_path,
+                        document.id().to_vec(),
+                        make_document_reference(document, document_type, document_storage_flags),
+                    )),
+                    batch_operations,
+                    drive_version,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
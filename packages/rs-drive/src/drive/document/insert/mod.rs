@@ -64,6 +64,14 @@ mod add_indices_for_top_index_level_for_contract_operations;
 // This module contains functionality for adding a reference for an index level for contract operations
 mod add_reference_for_index_level_for_contract_operations;
 
+// Module: add_index_sums_for_document_operations
+// This module contains functionality for adding the values of a document to the sum trees of its indices
+mod add_index_sums_for_document_operations;
+
+// Module: add_token_indices_for_document_operations
+// This module contains functionality for adding a document under the words of its tokenized indices
+mod add_token_indices_for_document_operations;
+
 #[cfg(all(
     feature = "fixtures-and-mocks",
     feature = "data-contract-cbor-conversion"
@@ -966,4 +974,67 @@ mod tests {
             )
             .expect("should create random tld");
     }
+
+    #[test]
+    fn test_add_family_document_with_index_sums_average_case_cost_fee() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let db_transaction = drive.grove.start_transaction();
+
+        let platform_version = PlatformVersion::latest();
+
+        let estimated_storage_fee = |contract_path: &str, contract_id: [u8; 32]| {
+            let contract = setup_contract(
+                &drive,
+                contract_path,
+                Some(contract_id),
+                Some(&db_transaction),
+            );
+
+            let document_type = contract
+                .document_type_for_name("person")
+                .expect("expected to get document type");
+
+            let person_document = json_document_to_document(
+                "tests/supporting_files/contract/family/person0.json",
+                None,
+                document_type,
+                platform_version,
+            )
+            .expect("expected to get document");
+
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((
+                                &person_document,
+                                StorageFlags::optional_default_as_cow(),
+                            )),
+                            owner_id: None,
+                        },
+                        contract: &contract,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    false,
+                    Some(&db_transaction),
+                    platform_version,
+                )
+                .expect("expected to estimate the document insertion")
+                .storage_fee
+        };
+
+        // the sum trees of the indices are part of the estimated costs
+        assert!(
+            estimated_storage_fee(
+                "tests/supporting_files/contract/family/family-contract-with-sums.json",
+                [1; 32]
+            ) > estimated_storage_fee(
+                "tests/supporting_files/contract/family/family-contract.json",
+                [2; 32]
+            )
+        );
+    }
 }
@@ -37,16 +37,32 @@
 use crate::drive::defaults::DEFAULT_HASH_SIZE_U8;
 #[cfg(feature = "full")]
 use crate::drive::flags::StorageFlags;
+#[cfg(feature = "full")]
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods};
 #[cfg(any(feature = "full", feature = "verify"))]
 use crate::drive::{defaults, RootTree};
+#[cfg(feature = "full")]
+use crate::error::drive::DriveError;
+#[cfg(feature = "full")]
+use crate::error::fee::FeeError;
+#[cfg(feature = "full")]
+use crate::error::Error;
+#[cfg(feature = "full")]
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
 #[cfg(any(feature = "full", feature = "verify"))]
-use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::data_contract::document_type::{DocumentPropertyType, DocumentTypeRef, Index};
+#[cfg(feature = "full")]
+use dpp::document::document_methods::DocumentMethodsV0;
 #[cfg(feature = "full")]
 use dpp::document::Document;
 use dpp::document::DocumentV0Getters;
 #[cfg(feature = "full")]
+use dpp::platform_value::btreemap_extensions::BTreeValueMapPathHelper;
+#[cfg(feature = "full")]
+use dpp::version::PlatformVersion;
+#[cfg(feature = "full")]
 use grovedb::batch::key_info::KeyInfo;
 #[cfg(feature = "full")]
 use grovedb::batch::KeyInfoPath;
@@ -54,6 +70,8 @@ use grovedb::batch::KeyInfoPath;
 use grovedb::reference_path::ReferencePathType::UpstreamRootHeightReference;
 #[cfg(feature = "full")]
 use grovedb::Element;
+#[cfg(feature = "full")]
+use std::collections::BTreeSet;
 
 #[cfg(feature = "full")]
 mod delete;
@@ -224,6 +242,199 @@ fn document_reference_size(document_type: DocumentTypeRef) -> u32 {
     6 + reference_path_size
 }
 
+#[cfg(any(feature = "full", feature = "verify"))]
+/// The key of the sum tree of an index, it sits next to the references stored under key 0
+pub(crate) const INDEX_SUM_TREE_KEY: [u8; 1] = [1];
+
+#[cfg(feature = "full")]
+/// Returns the path to the sum tree of an index for the index values of a document.
+fn document_index_sum_tree_path(
+    contract_id: &[u8],
+    document_type: DocumentTypeRef,
+    index: &Index,
+    document: &Document,
+    owner_id: Option<[u8; 32]>,
+    platform_version: &PlatformVersion,
+) -> Result<Vec<Vec<u8>>, Error> {
+    let mut sum_tree_path = contract_document_type_path_vec(contract_id, document_type.name());
+    for index_property in index.properties.iter() {
+        sum_tree_path.push(index_property.name.as_bytes().to_vec());
+        // a document without a value for the property is stored under an empty key
+        sum_tree_path.push(
+            document
+                .get_raw_for_document_type(
+                    &index_property.name,
+                    document_type,
+                    owner_id,
+                    platform_version,
+                )?
+                .unwrap_or_default(),
+        );
+    }
+    sum_tree_path.push(INDEX_SUM_TREE_KEY.to_vec());
+    Ok(sum_tree_path)
+}
+
+#[cfg(feature = "full")]
+/// Returns the path to the sum tree of an index when the index values are not known, each value
+/// is then a key of the estimated size of its property.
+fn document_index_sum_tree_estimated_path(
+    document_and_contract_info: &DocumentAndContractInfo,
+    index: &Index,
+) -> Result<KeyInfoPath, Error> {
+    let document_type = document_and_contract_info.document_type;
+    let mut sum_tree_path = KeyInfoPath::from_known_owned_path(contract_document_type_path_vec(
+        document_and_contract_info.contract.id_ref().as_bytes(),
+        document_type.name(),
+    ));
+    for index_property in index.properties.iter() {
+        sum_tree_path.push(KeyInfo::KnownKey(index_property.name.as_bytes().to_vec()));
+        let estimated_size = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_estimated_size_for_document_type(&index_property.name, document_type)?;
+        if estimated_size > u8::MAX as u16 {
+            return Err(Error::Fee(FeeError::Overflow(
+                "document field is too big for being an index key",
+            )));
+        }
+        // the unique id must be the same every time the path is built for this index property
+        sum_tree_path.push(KeyInfo::MaxKeySize {
+            unique_id: [
+                document_type.name().as_bytes(),
+                index.name.as_bytes(),
+                index_property.name.as_bytes(),
+            ]
+            .concat(),
+            max_size: estimated_size as u8,
+        });
+    }
+    sum_tree_path.push(KeyInfo::KnownKey(INDEX_SUM_TREE_KEY.to_vec()));
+    Ok(sum_tree_path)
+}
+
+#[cfg(feature = "full")]
+/// Returns the value a document adds to the sum tree of an index, a missing value adds 0.
+fn document_index_sum_value(document: &Document, index: &Index) -> Result<i64, Error> {
+    let Some(sum_property) = index.sum_property.as_ref() else {
+        return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+            "index does not declare a sum property",
+        )));
+    };
+    Ok(document
+        .properties()
+        .get_optional_at_path(sum_property)?
+        .map(|value| value.to_integer::<i64>())
+        .transpose()?
+        .unwrap_or_default())
+}
+
+#[cfg(any(feature = "full", feature = "verify"))]
+/// The key of the tree holding the tokenized indices of a document type, it sits next to the
+/// primary storage under key 0
+pub(crate) const TOKEN_INDICES_TREE_KEY: [u8; 1] = [1];
+
+#[cfg(any(feature = "full", feature = "verify"))]
+/// Returns the path to the words of a tokenized index on a property of a document type.
+pub(crate) fn contract_document_type_token_index_path_vec(
+    contract_id: &[u8],
+    document_type_name: &str,
+    property_name: &str,
+) -> Vec<Vec<u8>> {
+    let mut path = contract_document_type_path_vec(contract_id, document_type_name);
+    path.push(TOKEN_INDICES_TREE_KEY.to_vec());
+    path.push(property_name.as_bytes().to_vec());
+    path
+}
+
+#[cfg(feature = "full")]
+/// Returns the words of a document for a tokenized index, a document without a value for the
+/// property has none.
+fn document_index_tokens(document: &Document, index: &Index) -> Result<BTreeSet<String>, Error> {
+    let Some(index_property) = index.properties.first() else {
+        return Err(Error::Drive(DriveError::CorruptedContractIndexes(
+            "tokenized index should have a property",
+        )));
+    };
+    Ok(document
+        .properties()
+        .get_optional_at_path(&index_property.name)?
+        .map(|value| value.to_str().map(Index::tokens))
+        .transpose()?
+        .unwrap_or_default())
+}
+
+#[cfg(any(feature = "full", feature = "verify"))]
+/// Returns true if a property of an index is an array, such a multikey index has an entry for
+/// every item of the array.
+pub(crate) fn is_multikey_index(document_type: DocumentTypeRef, index: &Index) -> bool {
+    index.properties.iter().any(|index_property| {
+        document_type
+            .flattened_properties()
+            .get(&index_property.name)
+            .is_some_and(|property| {
+                matches!(property.property_type, DocumentPropertyType::Array(_))
+            })
+    })
+}
+
+#[cfg(feature = "full")]
+/// Returns the paths of the entries of a document in a multikey index, one for every distinct
+/// item of its array property. A document without items or without a value for a property is
+/// stored under an empty key.
+fn document_multikey_index_paths(
+    contract_id: &[u8],
+    document_type: DocumentTypeRef,
+    index: &Index,
+    document: &Document,
+    owner_id: Option<[u8; 32]>,
+    platform_version: &PlatformVersion,
+) -> Result<BTreeSet<Vec<Vec<u8>>>, Error> {
+    let mut index_paths = vec![contract_document_type_path_vec(
+        contract_id,
+        document_type.name(),
+    )];
+    for index_property in index.properties.iter() {
+        let is_array = document_type
+            .flattened_properties()
+            .get(&index_property.name)
+            .is_some_and(|property| {
+                matches!(property.property_type, DocumentPropertyType::Array(_))
+            });
+        let keys = if is_array {
+            document
+                .get_raw_array_elements_for_document_type(
+                    &index_property.name,
+                    document_type,
+                    platform_version,
+                )?
+                .filter(|elements| !elements.is_empty())
+                .unwrap_or(vec![vec![]])
+        } else {
+            vec![document
+                .get_raw_for_document_type(
+                    &index_property.name,
+                    document_type,
+                    owner_id,
+                    platform_version,
+                )?
+                .unwrap_or_default()]
+        };
+        index_paths = index_paths
+            .into_iter()
+            .flat_map(|index_path| {
+                keys.iter().map(move |key| {
+                    let mut index_path = index_path.clone();
+                    index_path.push(index_property.name.as_bytes().to_vec());
+                    index_path.push(key.clone());
+                    index_path
+                })
+            })
+            .collect();
+    }
+    Ok(index_paths.into_iter().collect())
+}
+
 #[cfg(feature = "full")]
 fn unique_event_id() -> [u8; 32] {
     rand::random::<[u8; 32]>()
@@ -274,7 +274,13 @@ impl Drive {
         protocol_version: Option<u32>,
     ) -> Result<Vec<u8>, Error> {
         let platform_version = PlatformVersion::get_version_or_current_or_latest(protocol_version)?;
-        let query = DriveQuery::from_cbor(query_cbor, contract, document_type, &self.config)?;
+        let query = DriveQuery::from_cbor(
+            query_cbor,
+            contract,
+            document_type,
+            &self.config,
+            platform_version,
+        )?;
 
         query.execute_with_proof_internal(self, transaction, drive_operations, platform_version)
     }
@@ -329,7 +335,13 @@ impl Drive {
         protocol_version: Option<u32>,
     ) -> Result<([u8; 32], Vec<Vec<u8>>), Error> {
         let platform_version = PlatformVersion::get_version_or_current_or_latest(protocol_version)?;
-        let query = DriveQuery::from_cbor(query_cbor, contract, document_type, &self.config)?;
+        let query = DriveQuery::from_cbor(
+            query_cbor,
+            contract,
+            document_type,
+            &self.config,
+            platform_version,
+        )?;
 
         query.execute_with_proof_only_get_elements_internal(
             self,
@@ -404,8 +416,13 @@ impl Drive {
             .contract
             .document_type_for_name(document_type_name)?;
 
-        let query =
-            DriveQuery::from_cbor(query_cbor, &contract.contract, document_type, &self.config)?;
+        let query = DriveQuery::from_cbor(
+            query_cbor,
+            &contract.contract,
+            document_type,
+            &self.config,
+            platform_version,
+        )?;
 
         self.query_serialized_documents(query, epoch, transaction, platform_version)
     }
@@ -422,7 +439,13 @@ impl Drive {
         protocol_version: Option<u32>,
     ) -> Result<(Vec<Vec<u8>>, u16), Error> {
         let platform_version = PlatformVersion::get_version_or_current_or_latest(protocol_version)?;
-        let query = DriveQuery::from_cbor(query_cbor, contract, document_type, &self.config)?;
+        let query = DriveQuery::from_cbor(
+            query_cbor,
+            contract,
+            document_type,
+            &self.config,
+            platform_version,
+        )?;
 
         query.execute_raw_results_no_proof_internal(
             self,
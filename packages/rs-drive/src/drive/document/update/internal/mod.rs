@@ -4,3 +4,12 @@ pub mod update_document_for_contract_apply_and_add_to_operations;
 // Module: update_document_for_contract_operations
 // This module contains functionality for updating a document for contract operations
 pub mod update_document_for_contract_operations;
+// Module: update_index_sums_for_document_operations
+// This module contains functionality for moving the values of an updated document in the sum trees of its indices
+pub mod update_index_sums_for_document_operations;
+// Module: update_multikey_indices_for_document_operations
+// This module contains functionality for moving the references of an updated document between the items of its multikey indices
+pub mod update_multikey_indices_for_document_operations;
+// Module: update_token_indices_for_document_operations
+// This module contains functionality for moving the references of an updated document between the words of its tokenized indices
+pub mod update_token_indices_for_document_operations;
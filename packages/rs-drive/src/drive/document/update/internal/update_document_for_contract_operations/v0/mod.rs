@@ -2,7 +2,7 @@ use crate::drive::defaults::CONTRACT_DOCUMENTS_PATH_HEIGHT;
 use crate::drive::document::{
     contract_document_type_path,
     contract_documents_keeping_history_primary_key_path_for_document_id,
-    contract_documents_primary_key_path, make_document_reference,
+    contract_documents_primary_key_path, is_multikey_index, make_document_reference,
 };
 
 use crate::drive::flags::StorageFlags;
@@ -165,9 +165,37 @@ impl Drive {
             )));
         };
 
+        let DocumentOwnedInfo((old_document, _)) = &old_document_info else {
+            return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                "old document must be owned",
+            )));
+        };
+
+        self.update_index_sums_for_document_operations(
+            &document_and_contract_info,
+            old_document,
+            previous_batch_operations,
+            transaction,
+            &mut batch_operations,
+            platform_version,
+        )?;
+
+        self.update_token_indices_for_document_operations(
+            &document_and_contract_info,
+            old_document,
+            previous_batch_operations,
+            transaction,
+            &mut batch_operations,
+            platform_version,
+        )?;
+
         let mut batch_insertion_cache: HashSet<Vec<Vec<u8>>> = HashSet::new();
         // fourth we need to store a reference to the document for each index
         for index in document_type.indices() {
+            // multikey indices have an entry for every item of an array, they are updated below
+            if is_multikey_index(document_type, index) {
+                continue;
+            }
             // at this point the contract path is to the contract documents
             // for each index the top index component will already have been added
             // when the contract itself was created
@@ -469,6 +497,17 @@ impl Drive {
                 }
             }
         }
+
+        self.update_multikey_indices_for_document_operations(
+            &document_and_contract_info,
+            old_document,
+            previous_batch_operations,
+            &mut batch_insertion_cache,
+            transaction,
+            &mut batch_operations,
+            platform_version,
+        )?;
+
         Ok(batch_operations)
     }
 }
@@ -0,0 +1,59 @@
+mod v0;
+
+use crate::drive::object_size_info::DocumentAndContractInfo;
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::document::Document;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Moves the value of an updated document in the sum trees of the indices declaring a sum property.
+    ///
+    /// # Parameters
+    /// * `document_and_contract_info`: The updated document and its contract info.
+    /// * `old_document`: The document as it is currently stored.
+    /// * `previous_batch_operations`: Previous batch operations to include.
+    /// * `transaction`: The transaction argument.
+    /// * `batch_operations`: The batch operations to include.
+    /// * `platform_version`: The platform version to select the correct function version to run.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the operation was successful.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub(in crate::drive::document::update) fn update_index_sums_for_document_operations(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        old_document: &Document,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .update
+            .update_index_sums_for_document_operations
+        {
+            0 => self.update_index_sums_for_document_operations_v0(
+                document_and_contract_info,
+                old_document,
+                previous_batch_operations,
+                transaction,
+                batch_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "update_index_sums_for_document_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
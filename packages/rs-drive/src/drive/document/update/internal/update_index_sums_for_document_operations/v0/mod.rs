@@ -0,0 +1,114 @@
+use crate::drive::defaults::CONTRACT_DOCUMENTS_PATH_HEIGHT;
+use crate::drive::document::{document_index_sum_tree_path, document_index_sum_value};
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::BatchDeleteUpTreeApplyType;
+use crate::drive::object_size_info::PathKeyElementInfo::PathKeyElement;
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, TransactionArg};
+
+impl Drive {
+    /// Moves the value of an updated document in the sum trees of the indices declaring a sum property.
+    pub(super) fn update_index_sums_for_document_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        old_document: &Document,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let Some((document, storage_flags)) = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_borrowed_document_and_storage_flags()
+        else {
+            return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                "must have document and storage flags",
+            )));
+        };
+        let contract_id = document_and_contract_info.contract.id_ref().as_bytes();
+        let document_type = document_and_contract_info.document_type;
+        let owner_id = document_and_contract_info.owned_document_info.owner_id;
+
+        for index in document_type
+            .indices()
+            .iter()
+            .filter(|index| index.sum_property.is_some())
+        {
+            let old_sum_tree_path = document_index_sum_tree_path(
+                contract_id,
+                document_type,
+                index,
+                old_document,
+                owner_id,
+                platform_version,
+            )?;
+            let mut sum_tree_path = document_index_sum_tree_path(
+                contract_id,
+                document_type,
+                index,
+                document,
+                owner_id,
+                platform_version,
+            )?;
+            let value = document_index_sum_value(document, index)?;
+
+            if old_sum_tree_path == sum_tree_path {
+                // the index values did not change, we only need to replace a changed value
+                if document_index_sum_value(old_document, index)? == value {
+                    continue;
+                }
+            } else {
+                // this must happen before the old references are removed so that the index
+                // trees left empty are deleted up the tree
+                self.batch_delete_up_tree_while_empty(
+                    KeyInfoPath::from_known_owned_path(old_sum_tree_path),
+                    document.id().as_slice(),
+                    Some(CONTRACT_DOCUMENTS_PATH_HEIGHT),
+                    BatchDeleteUpTreeApplyType::StatefulBatchDelete {
+                        is_known_to_be_subtree_with_sum: Some((false, false)),
+                    },
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                    &platform_version.drive,
+                )?;
+
+                let sum_tree_key = sum_tree_path.pop().expect("sum tree path has a key");
+                self.batch_insert_empty_sum_tree_for_index_if_not_exists(
+                    sum_tree_path.clone(),
+                    sum_tree_key.clone(),
+                    storage_flags,
+                    previous_batch_operations,
+                    transaction,
+                    batch_operations,
+                    platform_version,
+                )?;
+                sum_tree_path.push(sum_tree_key);
+            }
+
+            self.batch_insert(
+                PathKeyElement::<0>((
+                    sum_tree_path,
+                    document.id().to_vec(),
+                    Element::SumItem(
+                        value,
+                        StorageFlags::map_to_some_element_flags(storage_flags),
+                    ),
+                )),
+                batch_operations,
+                &platform_version.drive,
+            )?;
+        }
+        Ok(())
+    }
+}
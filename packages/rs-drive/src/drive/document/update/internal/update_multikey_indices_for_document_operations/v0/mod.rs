@@ -0,0 +1,159 @@
+use crate::drive::defaults::CONTRACT_DOCUMENTS_PATH_HEIGHT;
+use crate::drive::document::{
+    contract_document_type_path_vec, document_multikey_index_paths, is_multikey_index,
+    make_document_reference,
+};
+use crate::drive::grove_operations::{BatchDeleteUpTreeApplyType, BatchInsertTreeApplyType};
+use crate::drive::object_size_info::PathKeyElementInfo::PathKeyElement;
+use crate::drive::object_size_info::PathKeyInfo::PathKey;
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::TransactionArg;
+use std::collections::HashSet;
+
+impl Drive {
+    /// Moves the references of an updated document between the items of its multikey indices.
+    pub(super) fn update_multikey_indices_for_document_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        old_document: &Document,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        batch_insertion_cache: &mut HashSet<Vec<Vec<u8>>>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let drive_version = &platform_version.drive;
+        let Some((document, storage_flags)) = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_borrowed_document_and_storage_flags()
+        else {
+            return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                "must have document and storage flags",
+            )));
+        };
+        let contract_id = document_and_contract_info.contract.id_ref().as_bytes();
+        let document_type = document_and_contract_info.document_type;
+        let owner_id = document_and_contract_info.owned_document_info.owner_id;
+        let contract_document_type_path_len =
+            contract_document_type_path_vec(contract_id, document_type.name()).len();
+
+        let document_reference = make_document_reference(document, document_type, storage_flags);
+        // We can only trust the reference content has not changed if there are no storage flags
+        let trust_refresh_reference = storage_flags.is_none();
+
+        for index in document_type
+            .indices()
+            .iter()
+            .filter(|index| is_multikey_index(document_type, index))
+        {
+            let old_index_paths = document_multikey_index_paths(
+                contract_id,
+                document_type,
+                index,
+                old_document,
+                owner_id,
+                platform_version,
+            )?;
+            let index_paths = document_multikey_index_paths(
+                contract_id,
+                document_type,
+                index,
+                document,
+                owner_id,
+                platform_version,
+            )?;
+
+            // first the trees of the new entries are inserted, the top index property tree was
+            // added when the contract was created
+            for index_path in index_paths.difference(&old_index_paths) {
+                for depth in contract_document_type_path_len + 1..index_path.len() {
+                    let tree_path = index_path[..=depth].to_vec();
+                    if batch_insertion_cache.contains(&tree_path) {
+                        continue;
+                    }
+                    let inserted = self.batch_insert_empty_tree_if_not_exists(
+                        PathKey::<0>((index_path[..depth].to_vec(), index_path[depth].clone())),
+                        storage_flags,
+                        BatchInsertTreeApplyType::StatefulBatchInsertTree,
+                        transaction,
+                        previous_batch_operations,
+                        batch_operations,
+                        drive_version,
+                    )?;
+                    if inserted {
+                        batch_insertion_cache.insert(tree_path);
+                    }
+                }
+            }
+
+            // then the references of the removed entries are deleted with the trees they leave empty
+            for old_index_path in old_index_paths.difference(&index_paths) {
+                let mut references_path = old_index_path.clone();
+                references_path.push(vec![0]);
+                self.batch_delete_up_tree_while_empty(
+                    KeyInfoPath::from_known_owned_path(references_path),
+                    document.id().as_slice(),
+                    Some(CONTRACT_DOCUMENTS_PATH_HEIGHT),
+                    BatchDeleteUpTreeApplyType::StatefulBatchDelete {
+                        is_known_to_be_subtree_with_sum: Some((false, false)),
+                    },
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                    drive_version,
+                )?;
+            }
+
+            for index_path in index_paths.iter() {
+                let mut references_path = index_path.clone();
+
+                if old_index_paths.contains(index_path) {
+                    // the document already has this entry, we need to refresh the reference
+                    references_path.push(vec![0]);
+                    self.batch_refresh_reference(
+                        references_path,
+                        document.id().to_vec(),
+                        document_reference.clone(),
+                        trust_refresh_reference,
+                        batch_operations,
+                        drive_version,
+                    )?;
+                    continue;
+                }
+
+                // multikey indices are never unique, the references are stored in a tree at key 0
+                self.batch_insert_empty_tree_if_not_exists(
+                    PathKey::<0>((references_path.clone(), vec![0])),
+                    storage_flags,
+                    BatchInsertTreeApplyType::StatefulBatchInsertTree,
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                    drive_version,
+                )?;
+
+                references_path.push(vec![0]);
+                self.batch_insert(
+                    PathKeyElement::<0>((
+                        references_path,
+                        document.id().to_vec(),
+                        document_reference.clone(),
+                    )),
+                    batch_operations,
+                    drive_version,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
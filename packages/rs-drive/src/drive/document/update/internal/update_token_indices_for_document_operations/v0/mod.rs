@@ -0,0 +1,127 @@
+use crate::drive::defaults::CONTRACT_DOCUMENTS_PATH_HEIGHT;
+use crate::drive::document::{
+    contract_document_type_token_index_path_vec, document_index_tokens, make_document_reference,
+};
+use crate::drive::grove_operations::{BatchDeleteUpTreeApplyType, BatchInsertTreeApplyType};
+use crate::drive::object_size_info::PathKeyElementInfo::PathKeyElement;
+use crate::drive::object_size_info::PathKeyInfo::PathKey;
+use crate::drive::object_size_info::{DocumentAndContractInfo, DocumentInfoV0Methods};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Moves the references of an updated document between the words of its tokenized indices.
+    pub(super) fn update_token_indices_for_document_operations_v0(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        old_document: &Document,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let drive_version = &platform_version.drive;
+        let Some((document, storage_flags)) = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_borrowed_document_and_storage_flags()
+        else {
+            return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                "must have document and storage flags",
+            )));
+        };
+        let contract_id = document_and_contract_info.contract.id_ref().as_bytes();
+        let document_type = document_and_contract_info.document_type;
+
+        for index in document_type.token_indices() {
+            let Some(index_property) = index.properties.first() else {
+                return Err(Error::Drive(DriveError::CorruptedContractIndexes(
+                    "tokenized index should have a property",
+                )));
+            };
+            let token_index_path = contract_document_type_token_index_path_vec(
+                contract_id,
+                document_type.name(),
+                &index_property.name,
+            );
+            let old_tokens = document_index_tokens(old_document, index)?;
+            let tokens = document_index_tokens(document, index)?;
+
+            for old_token in old_tokens.difference(&tokens) {
+                let mut token_path = token_index_path.clone();
+                token_path.push(old_token.as_bytes().to_vec());
+
+                // the word tree is removed with its last reference, the tree of the tokenized
+                // property is kept
+                self.batch_delete_up_tree_while_empty(
+                    KeyInfoPath::from_known_owned_path(token_path),
+                    document.id().as_slice(),
+                    Some(CONTRACT_DOCUMENTS_PATH_HEIGHT + 1),
+                    BatchDeleteUpTreeApplyType::StatefulBatchDelete {
+                        is_known_to_be_subtree_with_sum: Some((false, false)),
+                    },
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                    drive_version,
+                )?;
+            }
+
+            let document_reference =
+                make_document_reference(document, document_type, storage_flags);
+            // We can only trust the reference content has not changed if there are no storage flags
+            let trust_refresh_reference = storage_flags.is_none();
+
+            for token in tokens.iter() {
+                let is_kept = old_tokens.contains(token);
+                let token = token.as_bytes().to_vec();
+                let mut token_path = token_index_path.clone();
+
+                if is_kept {
+                    // the document already has this word, we need to refresh the reference
+                    token_path.push(token);
+                    self.batch_refresh_reference(
+                        token_path,
+                        document.id().to_vec(),
+                        document_reference.clone(),
+                        trust_refresh_reference,
+                        batch_operations,
+                        drive_version,
+                    )?;
+                    continue;
+                }
+
+                // here we are inserting an empty tree that will have the references of the documents having this word
+                self.batch_insert_empty_tree_if_not_exists(
+                    PathKey::<0>((token_path.clone(), token.clone())),
+                    storage_flags,
+                    BatchInsertTreeApplyType::StatefulBatchInsertTree,
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                    drive_version,
+                )?;
+
+                token_path.push(token);
+                self.batch_insert(
+                    PathKeyElement::<0>((
+                        token_path,
+                        document.id().to_vec(),
+                        document_reference.clone(),
+                    )),
+                    batch_operations,
+                    drive_version,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -219,8 +219,13 @@ mod tests {
         // Check Alice profile
 
         let sql_string = "select * from profile";
-        let query = DriveQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
-            .expect("should build query");
+        let query = DriveQuery::from_sql_expr(
+            sql_string,
+            &contract,
+            Some(&DriveConfig::default()),
+            platform_version,
+        )
+        .expect("should build query");
 
         let (results_no_transaction, _, _) = query
             .execute_raw_results_no_proof(&drive, None, None, platform_version)
@@ -319,8 +324,13 @@ mod tests {
         // Check Alice profile
 
         let sql_string = "select * from profile";
-        let query = DriveQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
-            .expect("should build query");
+        let query = DriveQuery::from_sql_expr(
+            sql_string,
+            &contract,
+            Some(&DriveConfig::default()),
+            platform_version,
+        )
+        .expect("should build query");
 
         let (results_no_transaction, _, _) = query
             .execute_raw_results_no_proof(&drive, None, None, platform_version)
@@ -427,8 +437,13 @@ mod tests {
         // Check Alice profile
 
         let sql_string = "select * from profile";
-        let query = DriveQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
-            .expect("should build query");
+        let query = DriveQuery::from_sql_expr(
+            sql_string,
+            &contract,
+            Some(&DriveConfig::default()),
+            platform_version,
+        )
+        .expect("should build query");
 
         let (results_no_transaction, _, _) = query
             .execute_raw_results_no_proof(&drive, None, None, platform_version)
@@ -832,6 +847,95 @@ mod tests {
             .expect("expected to update a document with history successfully");
     }
 
+    #[test]
+    fn test_update_document_owner_rekeys_owner_indexes() {
+        let (drive, contract) = setup_dashpay("", true);
+
+        let platform_version = PlatformVersion::latest();
+
+        let document_type = contract
+            .document_type_for_name("profile")
+            .expect("profile document exists");
+
+        let original_owner_id = Identifier::random();
+        let recipient_owner_id = Identifier::random();
+
+        let mut document = document_type
+            .create_document_from_data(
+                platform_value!({"displayName": "Alice"}),
+                original_owner_id,
+                random(),
+                platform_version,
+            )
+            .expect("should create document");
+
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("should create alice profile");
+
+        // Transfer the profile to another identity
+
+        document.set_owner_id(recipient_owner_id);
+        document.set_revision(Some(2));
+
+        drive
+            .update_document_for_contract(
+                &document,
+                &contract,
+                document_type,
+                None,
+                BlockInfo::default(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+                platform_version,
+            )
+            .expect("should transfer alice profile");
+
+        let count_profiles_owned_by = |owner_id: Identifier| {
+            let query_value = platform_value!({
+                "where": [["$ownerId", "==", owner_id]],
+            })
+            .into_btree_string_map()
+            .expect("expected a map");
+
+            let query = DriveQuery::from_btree_map_value(
+                query_value,
+                &contract,
+                document_type,
+                &DriveConfig::default(),
+                platform_version,
+            )
+            .expect("should build query");
+
+            let (results, _, _) = query
+                .execute_raw_results_no_proof(&drive, None, None, platform_version)
+                .expect("expected to execute query");
+
+            results.len()
+        };
+
+        assert_eq!(count_profiles_owned_by(original_owner_id), 0);
+        assert_eq!(count_profiles_owned_by(recipient_owner_id), 1);
+    }
+
     fn test_fees_for_update_document(using_history: bool, using_transaction: bool) {
         let config = DriveConfig {
             batching_consistency_verification: true,
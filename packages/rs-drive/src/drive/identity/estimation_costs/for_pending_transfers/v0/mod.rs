@@ -0,0 +1,114 @@
+use crate::drive::defaults::DEFAULT_HASH_SIZE_U8;
+
+use crate::drive::Drive;
+
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, EstimatedLevel, PotentiallyAtMaxElements};
+use grovedb::EstimatedLayerInformation;
+use grovedb::EstimatedLayerSizes::{AllItems, AllReference, AllSubtrees, Mix};
+
+use crate::drive::identity::pending_transfers::paths::{
+    get_pending_transfers_path_vec, get_pending_transfers_root_path_vec,
+    PENDING_TRANSFERS_BY_ID_KEY, PENDING_TRANSFERS_BY_RECIPIENT_KEY,
+    PENDING_TRANSFERS_BY_RELEASE_CORE_HEIGHT_KEY, PENDING_TRANSFERS_BY_RELEASE_TIME_KEY,
+    PENDING_TRANSFERS_BY_SENDER_KEY,
+};
+use crate::drive::identity::pending_transfers::{
+    AVERAGE_PENDING_TRANSFER_SIZE, PENDING_TRANSFER_REFERENCE_SIZE,
+};
+use crate::drive::RootTree;
+
+use grovedb::EstimatedSumTrees::{NoSumTrees, SomeSumTrees};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds estimation costs for inserting or removing a pending transfer in Drive for version 0.
+    ///
+    /// # Notes
+    ///
+    /// The function estimates costs for the layers down to the pending transfers:
+    ///
+    /// 1. The top layer, where the misc tree is.
+    /// 2. The misc tree, containing the total system credits and protocol versions items
+    ///    along with the pending transfers tree.
+    /// 3. The pending transfers tree, containing a subtree for the transfers by id
+    ///    and a subtree of references for each way of looking them up.
+    /// 4. The subtrees themselves, whose keys are the transfer id prefixed by the
+    ///    recipient id, the sender id, the release time or the release core height.
+    pub(super) fn add_estimation_costs_for_pending_transfers_v0(
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+    ) {
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path([]),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: EstimatedLevel(1, false),
+                estimated_layer_sizes: AllSubtrees(
+                    1,
+                    SomeSumTrees {
+                        sum_trees_weight: 1,
+                        non_sum_trees_weight: 1,
+                    },
+                    None,
+                ),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(vec![vec![RootTree::Misc as u8]]),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: ApproximateElements(4),
+                estimated_layer_sizes: Mix {
+                    subtrees_size: Some((1, NoSumTrees, None, 1)),
+                    items_size: Some((1, 8, None, 3)),
+                    references_size: None,
+                },
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(get_pending_transfers_root_path_vec()),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: ApproximateElements(5),
+                estimated_layer_sizes: AllSubtrees(1, NoSumTrees, None),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(get_pending_transfers_path_vec(
+                PENDING_TRANSFERS_BY_ID_KEY,
+            )),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: PotentiallyAtMaxElements,
+                estimated_layer_sizes: AllItems(
+                    DEFAULT_HASH_SIZE_U8,
+                    AVERAGE_PENDING_TRANSFER_SIZE,
+                    None,
+                ),
+            },
+        );
+
+        for (subtree_key, prefix_size) in [
+            (PENDING_TRANSFERS_BY_RECIPIENT_KEY, DEFAULT_HASH_SIZE_U8),
+            (PENDING_TRANSFERS_BY_SENDER_KEY, DEFAULT_HASH_SIZE_U8),
+            (PENDING_TRANSFERS_BY_RELEASE_TIME_KEY, 8),
+            (PENDING_TRANSFERS_BY_RELEASE_CORE_HEIGHT_KEY, 4),
+        ] {
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(get_pending_transfers_path_vec(subtree_key)),
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: PotentiallyAtMaxElements,
+                    estimated_layer_sizes: AllReference(
+                        prefix_size + DEFAULT_HASH_SIZE_U8,
+                        PENDING_TRANSFER_REFERENCE_SIZE,
+                        None,
+                    ),
+                },
+            );
+        }
+    }
+}
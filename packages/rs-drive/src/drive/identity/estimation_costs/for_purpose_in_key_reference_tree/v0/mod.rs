@@ -45,6 +45,7 @@ impl Drive {
             Purpose::WITHDRAW => ApproximateElements(1),
             Purpose::SYSTEM => ApproximateElements(1),
             Purpose::VOTING => ApproximateElements(1),
+            Purpose::RECOVERY => ApproximateElements(1),
         };
 
         let estimated_layer_sizes = match purpose {
@@ -58,6 +59,7 @@ impl Drive {
             Purpose::WITHDRAW => AllReference(1, KEY_REFERENCE_SIZE, None),
             Purpose::SYSTEM => AllReference(1, KEY_REFERENCE_SIZE, None),
             Purpose::VOTING => AllReference(1, KEY_REFERENCE_SIZE, None),
+            Purpose::RECOVERY => AllReference(1, KEY_REFERENCE_SIZE, None),
         };
         // we then need to insert the identity keys layer
         estimated_costs_only_with_layer_info.insert(
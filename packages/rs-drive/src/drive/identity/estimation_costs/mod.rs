@@ -6,6 +6,7 @@ mod for_identity_contract_info_group_key_purpose;
 mod for_identity_contract_info_group_keys;
 mod for_keys_for_identity_id;
 mod for_negative_credit;
+mod for_pending_transfers;
 mod for_purpose_in_key_reference_tree;
 mod for_root_key_reference_tree;
 mod for_update_nonce;
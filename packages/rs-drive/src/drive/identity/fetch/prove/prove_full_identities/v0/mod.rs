@@ -275,4 +275,45 @@ mod tests {
         // We want to get a proof on the balance, the revision and 5 keys
         assert_eq!(proof.len(), 70);
     }
+
+    #[test]
+    fn should_verify_full_identities_by_identity_ids_with_missing_identity() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let mut identities: BTreeMap<[u8; 32], Option<Identity>> =
+            Identity::random_identities(2, 5, Some(14), platform_version)
+                .expect("expect to get random identities")
+                .into_iter()
+                .map(|identity| (identity.id().to_buffer(), Some(identity)))
+                .collect();
+
+        for identity in identities.values() {
+            drive
+                .add_new_identity(
+                    identity.as_ref().unwrap().clone(),
+                    false,
+                    &BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to add an identity");
+        }
+
+        identities.insert([0; 32], None);
+
+        let identity_ids = identities.keys().copied().collect::<Vec<[u8; 32]>>();
+
+        let proof = drive
+            .prove_full_identities_v0(&identity_ids, None, &platform_version.drive)
+            .expect("should prove identities");
+
+        let (_, proved_identities) = Drive::verify_full_identities_by_identity_ids::<
+            BTreeMap<[u8; 32], Option<Identity>>,
+        >(&proof, &identity_ids, platform_version)
+        .expect("expected to verify the proof");
+
+        assert_eq!(proved_identities, identities);
+    }
 }
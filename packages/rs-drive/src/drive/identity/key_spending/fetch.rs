@@ -0,0 +1,117 @@
+use crate::drive::grove_operations::DirectQueryType;
+use crate::drive::identity::identity_path;
+use crate::drive::identity::key_spending::{
+    identity_key_spending_key, identity_key_spending_limit_key,
+};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::fee::Credits;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
+use dpp::serialization::PlatformDeserializable;
+use dpp::version::PlatformVersion;
+use grovedb::{Element, TransactionArg};
+
+impl Drive {
+    /// Fetches the last recorded spending of an identity key with a spending limit
+    pub fn fetch_identity_key_spending(
+        &self,
+        identity_id: [u8; 32],
+        key_id: KeyID,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<KeySpending>, Error> {
+        self.fetch_identity_key_spending_operations(
+            identity_id,
+            key_id,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    /// Fetches the last recorded spending of an identity key with a spending limit and adds
+    /// the cost of the fetch to `drive_operations`
+    pub fn fetch_identity_key_spending_operations(
+        &self,
+        identity_id: [u8; 32],
+        key_id: KeyID,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<KeySpending>, Error> {
+        let identity_path = identity_path(identity_id.as_slice());
+
+        let element = self.grove_get_raw_optional(
+            (&identity_path).into(),
+            identity_key_spending_key(key_id).as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        )?;
+
+        match element {
+            Some(Element::Item(bytes, _)) => Ok(Some(KeySpending::deserialize_from_bytes(&bytes)?)),
+            Some(_) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "identity key spending was present but was not identified as an item",
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches the spending limit per epoch of an identity key, `None` if the key is not limited
+    pub fn fetch_identity_key_spending_limit(
+        &self,
+        identity_id: [u8; 32],
+        key_id: KeyID,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Credits>, Error> {
+        self.fetch_identity_key_spending_limit_operations(
+            identity_id,
+            key_id,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    /// Fetches the spending limit per epoch of an identity key and adds the cost of the fetch
+    /// to `drive_operations`
+    pub fn fetch_identity_key_spending_limit_operations(
+        &self,
+        identity_id: [u8; 32],
+        key_id: KeyID,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Credits>, Error> {
+        let identity_path = identity_path(identity_id.as_slice());
+
+        let element = self.grove_get_raw_optional(
+            (&identity_path).into(),
+            identity_key_spending_limit_key(key_id).as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        )?;
+
+        match element {
+            Some(Element::Item(encoded_spending_limit, _)) => Ok(Some(Credits::from_be_bytes(
+                encoded_spending_limit.try_into().map_err(|_| {
+                    Error::Drive(DriveError::CorruptedElementType(
+                        "identity key spending limit was not 8 bytes as expected",
+                    ))
+                })?,
+            ))),
+            Some(_) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "identity key spending limit was present but was not identified as an item",
+            ))),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! The spending limits of identity keys and the credits they spent during the current epoch.
+//!
+//! The spending limit and the spending of each key are stored as items in the identity subtree,
+//! their keys are the key spending limit or key spending prefix followed by the big endian id of
+//! the identity key. The spending is only written when a key with a spending limit transfers or
+//! withdraws credits.
+
+mod fetch;
+mod operations;
+
+use crate::drive::identity::IdentityRootStructure;
+use dpp::identity::KeyID;
+
+/// The key of the spending of an identity key in the identity subtree
+pub(crate) fn identity_key_spending_key(key_id: KeyID) -> Vec<u8> {
+    let mut key = vec![IdentityRootStructure::IdentityTreeKeySpending as u8];
+    key.extend_from_slice(&key_id.to_be_bytes());
+    key
+}
+
+/// The key of the spending limit of an identity key in the identity subtree
+pub(crate) fn identity_key_spending_limit_key(key_id: KeyID) -> Vec<u8> {
+    let mut key = vec![IdentityRootStructure::IdentityTreeKeySpendingLimit as u8];
+    key.extend_from_slice(&key_id.to_be_bytes());
+    key
+}
@@ -0,0 +1,43 @@
+use crate::drive::identity::identity_path_vec;
+use crate::drive::identity::key_spending::identity_key_spending_key;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
+use dpp::serialization::PlatformSerializable;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, EstimatedLayerInformation};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Inserts or replaces the spending of an identity key during the current epoch
+    pub(crate) fn set_identity_key_spending_operations(
+        &self,
+        identity_id: [u8; 32],
+        key_id: KeyID,
+        key_spending: &KeySpending,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            // the spending is an item of the identity subtree, like the revision
+            Self::add_estimation_costs_for_update_revision(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(vec![
+            LowLevelDriveOperation::insert_for_known_path_key_element(
+                identity_path_vec(identity_id.as_slice()),
+                identity_key_spending_key(key_id),
+                Element::new_item(key_spending.serialize_to_bytes()?),
+            ),
+        ])
+    }
+}
@@ -40,7 +40,7 @@ use crate::drive::RootTree;
 #[cfg(any(feature = "full", feature = "verify"))]
 use dpp::identity::{KeyID, Purpose, SecurityLevel};
 
-#[cfg(feature = "full")]
+#[cfg(any(feature = "full", feature = "verify"))]
 /// Everything related to withdrawals
 pub mod withdrawals;
 
@@ -62,12 +62,25 @@ mod insert;
 #[cfg(any(feature = "full", feature = "verify"))]
 /// Module related to Identity Keys
 pub mod key;
+/// Module related to the spending of identity keys with a spending limit
+#[cfg(feature = "full")]
+pub mod key_spending;
+/// Module related to pending transfers
+#[cfg(any(feature = "full", feature = "verify"))]
+pub mod pending_transfers;
+/// Module related to identity recovery proposals
+#[cfg(any(feature = "full", feature = "verify"))]
+pub mod recovery_proposal;
 /// Module related to updating of identity
 #[cfg(feature = "full")]
 pub mod update;
 
+#[cfg(feature = "full")]
+pub use pending_transfers::paths::add_initial_pending_transfers_structure_operations;
 #[cfg(feature = "full")]
 pub use withdrawals::paths::add_initial_withdrawal_state_structure_operations;
+#[cfg(feature = "full")]
+pub use withdrawals::paths::add_initial_withdrawal_transaction_ids_structure_operations;
 
 use crate::drive::identity::contract_info::ContractInfoStructure;
 #[cfg(any(feature = "full", feature = "verify"))]
@@ -334,6 +347,14 @@ pub enum IdentityRootStructure {
     IdentityTreeNegativeCredit = 96,
     /// Identity contract information
     IdentityContractInfo = 32,
+    /// The pending recovery proposal of the identity, replacing its master keys
+    IdentityTreeRecoveryProposal = 224,
+    /// The delay during which the master keys of the identity can veto a recovery proposal
+    IdentityTreeRecoveryDelay = 240,
+    /// The spending during the current epoch of keys with a spending limit
+    IdentityTreeKeySpending = 16,
+    /// The spending limit per epoch of identity keys
+    IdentityTreeKeySpendingLimit = 48,
 }
 
 #[cfg(feature = "full")]
@@ -367,6 +388,10 @@ impl From<IdentityRootStructure> for &'static [u8; 1] {
             IdentityRootStructure::IdentityTreeKeyReferences => &[160],
             IdentityRootStructure::IdentityTreeNegativeCredit => &[96],
             IdentityRootStructure::IdentityContractInfo => &[32],
+            IdentityRootStructure::IdentityTreeRecoveryProposal => &[224],
+            IdentityRootStructure::IdentityTreeRecoveryDelay => &[240],
+            IdentityRootStructure::IdentityTreeKeySpending => &[16],
+            IdentityRootStructure::IdentityTreeKeySpendingLimit => &[48],
         }
     }
 }
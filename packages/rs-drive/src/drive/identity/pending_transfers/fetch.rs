@@ -0,0 +1,107 @@
+use crate::drive::grove_operations::DirectQueryType;
+use crate::drive::identity::pending_transfers::paths::{
+    get_pending_transfers_path, PENDING_TRANSFERS_BY_ID_KEY,
+};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::block::block_info::BlockInfo;
+use dpp::identity::pending_transfer::PendingTransfer;
+use dpp::serialization::PlatformDeserializable;
+use dpp::version::PlatformVersion;
+use grovedb::{Element, PathQuery, TransactionArg};
+
+impl Drive {
+    /// Fetches a pending transfer by its id
+    pub fn fetch_pending_transfer(
+        &self,
+        transfer_id: [u8; 32],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<PendingTransfer>, Error> {
+        let path = get_pending_transfers_path(&PENDING_TRANSFERS_BY_ID_KEY);
+
+        let element = self.grove_get_raw_optional(
+            (&path).into(),
+            &transfer_id,
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )?;
+
+        match element {
+            Some(Element::Item(bytes, _)) => {
+                Ok(Some(PendingTransfer::deserialize_from_bytes(&bytes)?))
+            }
+            Some(_) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "pending transfer was present but was not identified as an item",
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches the pending transfers to the identity,
+    /// or the pending transfers sent by the identity if `outgoing` is true
+    pub fn fetch_pending_transfers_for_identity(
+        &self,
+        identity_id: [u8; 32],
+        outgoing: bool,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<PendingTransfer>, Error> {
+        let path_query = Self::pending_transfers_for_identity_query(identity_id, outgoing, limit);
+
+        self.fetch_pending_transfers_by_path_query(&path_query, transaction, platform_version)
+    }
+
+    /// Fetches up to `limit` pending transfers released at the end of the block
+    pub fn fetch_released_pending_transfers(
+        &self,
+        block_info: &BlockInfo,
+        limit: u16,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<PendingTransfer>, Error> {
+        let mut pending_transfers = self.fetch_pending_transfers_by_path_query(
+            &Self::pending_transfers_released_by_block_time_query(block_info.time_ms, limit),
+            transaction,
+            platform_version,
+        )?;
+
+        let remaining_limit = limit.saturating_sub(pending_transfers.len() as u16);
+
+        if remaining_limit > 0 {
+            pending_transfers.extend(self.fetch_pending_transfers_by_path_query(
+                &Self::pending_transfers_released_by_core_height_query(
+                    block_info.core_height,
+                    remaining_limit,
+                ),
+                transaction,
+                platform_version,
+            )?);
+        }
+
+        Ok(pending_transfers)
+    }
+
+    fn fetch_pending_transfers_by_path_query(
+        &self,
+        path_query: &PathQuery,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<PendingTransfer>, Error> {
+        let (serialized_pending_transfers, _) = self.grove_get_path_query_serialized_results(
+            path_query,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )?;
+
+        serialized_pending_transfers
+            .iter()
+            .map(|bytes| PendingTransfer::deserialize_from_bytes(bytes).map_err(Error::Protocol))
+            .collect()
+    }
+}
@@ -0,0 +1,22 @@
+//! Credit transfers scheduled by an identity that its recipient only receives once
+//! a block time or a core block height is reached.
+//!
+//! Pending transfers are stored by id in the pending transfers tree of the misc tree, and
+//! referenced by recipient, by sender and by release condition so they can be queried by
+//! identity and released at the end of the block reaching their release condition.
+
+#[cfg(feature = "full")]
+mod fetch;
+/// Functions and constants related to GroveDB paths
+pub mod paths;
+#[cfg(feature = "full")]
+mod prove;
+mod queries;
+
+/// The average size of a serialized pending transfer
+#[cfg(feature = "full")]
+pub const AVERAGE_PENDING_TRANSFER_SIZE: u32 = 115;
+
+/// The size of a reference to a pending transfer
+#[cfg(feature = "full")]
+pub const PENDING_TRANSFER_REFERENCE_SIZE: u32 = 40;
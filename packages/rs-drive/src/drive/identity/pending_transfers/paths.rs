@@ -0,0 +1,72 @@
+#[cfg(feature = "full")]
+use grovedb::Element;
+
+#[cfg(feature = "full")]
+use crate::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
+#[cfg(feature = "full")]
+use crate::drive::batch::GroveDbOpBatch;
+use crate::drive::RootTree;
+
+/// constant key in the misc tree for the subtree containing pending transfers
+pub const PENDING_TRANSFERS_KEY: &[u8; 1] = b"p";
+/// constant key for the subtree of pending transfers by their id
+pub const PENDING_TRANSFERS_BY_ID_KEY: [u8; 1] = [0];
+/// constant key for the subtree of references to pending transfers by recipient
+pub const PENDING_TRANSFERS_BY_RECIPIENT_KEY: [u8; 1] = [1];
+/// constant key for the subtree of references to pending transfers by sender
+pub const PENDING_TRANSFERS_BY_SENDER_KEY: [u8; 1] = [2];
+/// constant key for the subtree of references to pending transfers by release block time
+pub const PENDING_TRANSFERS_BY_RELEASE_TIME_KEY: [u8; 1] = [3];
+/// constant key for the subtree of references to pending transfers by release core block height
+pub const PENDING_TRANSFERS_BY_RELEASE_CORE_HEIGHT_KEY: [u8; 1] = [4];
+
+/// Add operations for creating initial pending transfers state structure
+#[cfg(feature = "full")]
+pub fn add_initial_pending_transfers_structure_operations(batch: &mut GroveDbOpBatch) {
+    batch.add_insert_empty_tree(
+        vec![vec![RootTree::Misc as u8]],
+        PENDING_TRANSFERS_KEY.to_vec(),
+    );
+
+    for key in [
+        PENDING_TRANSFERS_BY_ID_KEY,
+        PENDING_TRANSFERS_BY_RECIPIENT_KEY,
+        PENDING_TRANSFERS_BY_SENDER_KEY,
+        PENDING_TRANSFERS_BY_RELEASE_TIME_KEY,
+        PENDING_TRANSFERS_BY_RELEASE_CORE_HEIGHT_KEY,
+    ] {
+        batch.add_insert_empty_tree(get_pending_transfers_root_path_vec(), key.to_vec());
+    }
+}
+
+/// Helper function to get pending transfers root path as Vec
+pub fn get_pending_transfers_root_path_vec() -> Vec<Vec<u8>> {
+    vec![vec![RootTree::Misc as u8], PENDING_TRANSFERS_KEY.to_vec()]
+}
+
+/// Helper function to get the path of one of the pending transfers subtrees as [u8]
+pub fn get_pending_transfers_path(subtree_key: &'static [u8; 1]) -> [&'static [u8]; 3] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Misc),
+        PENDING_TRANSFERS_KEY,
+        subtree_key,
+    ]
+}
+
+/// Helper function to get the path of one of the pending transfers subtrees as Vec
+pub fn get_pending_transfers_path_vec(subtree_key: [u8; 1]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        PENDING_TRANSFERS_KEY.to_vec(),
+        subtree_key.to_vec(),
+    ]
+}
+
+/// The key of a pending transfer in the recipient, sender and release subtrees,
+/// the transfer id follows the prefix so transfers sharing a prefix can be queried by range
+pub fn pending_transfer_index_key(prefix: &[u8], transfer_id: &[u8; 32]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(prefix.len() + 32);
+    key.extend_from_slice(prefix);
+    key.extend_from_slice(transfer_id);
+    key
+}
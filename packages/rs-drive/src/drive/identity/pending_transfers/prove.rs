@@ -0,0 +1,221 @@
+use crate::drive::Drive;
+use crate::error::Error;
+
+use dpp::version::drive_versions::DriveVersion;
+
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves a pending transfer by its id
+    pub fn prove_pending_transfer(
+        &self,
+        transfer_id: [u8; 32],
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let pending_transfer_query = Self::pending_transfer_query(transfer_id);
+        self.grove_get_proved_path_query(
+            &pending_transfer_query,
+            false,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )
+    }
+
+    /// Proves the pending transfers to the identity,
+    /// or the pending transfers sent by the identity if `outgoing` is true
+    pub fn prove_pending_transfers_for_identity(
+        &self,
+        identity_id: [u8; 32],
+        outgoing: bool,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let pending_transfers_query =
+            Self::pending_transfers_for_identity_query(identity_id, outgoing, limit);
+        self.grove_get_proved_path_query(
+            &pending_transfers_query,
+            false,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::batch::{DriveOperation, PendingTransferOperationType};
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::identity::pending_transfer::{PendingTransfer, TransferReleaseCondition};
+    use dpp::prelude::Identifier;
+    use dpp::version::PlatformVersion;
+
+    fn insert_pending_transfer(drive: &Drive, pending_transfer: &PendingTransfer) {
+        let platform_version = PlatformVersion::latest();
+
+        drive
+            .apply_drive_operations(
+                vec![DriveOperation::PendingTransferOperation(
+                    PendingTransferOperationType::InsertPendingTransfer {
+                        pending_transfer: pending_transfer.clone(),
+                    },
+                )],
+                true,
+                &BlockInfo::default(),
+                None,
+                platform_version,
+            )
+            .expect("expected to insert a pending transfer");
+    }
+
+    #[test]
+    fn should_prove_a_pending_transfer_and_its_absence_once_removed() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let sender_id = Identifier::random();
+
+        let pending_transfer = PendingTransfer {
+            id: PendingTransfer::id_from_sender_and_nonce(sender_id, 1),
+            sender_id,
+            recipient_id: Identifier::random(),
+            amount: 100000,
+            release_condition: TransferReleaseCondition::BlockTime(1000),
+        };
+
+        insert_pending_transfer(&drive, &pending_transfer);
+
+        let proof = drive
+            .prove_pending_transfer(
+                pending_transfer.id.to_buffer(),
+                None,
+                &platform_version.drive,
+            )
+            .expect("should not error when proving a pending transfer");
+
+        let (_, proved_pending_transfer) = Drive::verify_pending_transfer(
+            proof.as_slice(),
+            pending_transfer.id.to_buffer(),
+            false,
+            platform_version,
+        )
+        .expect("expect that this be verified");
+
+        assert_eq!(proved_pending_transfer, Some(pending_transfer.clone()));
+
+        drive
+            .apply_drive_operations(
+                vec![DriveOperation::PendingTransferOperation(
+                    PendingTransferOperationType::RemovePendingTransfer {
+                        pending_transfer: pending_transfer.clone(),
+                    },
+                )],
+                true,
+                &BlockInfo::default(),
+                None,
+                platform_version,
+            )
+            .expect("expected to remove a pending transfer");
+
+        let proof = drive
+            .prove_pending_transfer(
+                pending_transfer.id.to_buffer(),
+                None,
+                &platform_version.drive,
+            )
+            .expect("should not error when proving a pending transfer");
+
+        let (_, proved_pending_transfer) = Drive::verify_pending_transfer(
+            proof.as_slice(),
+            pending_transfer.id.to_buffer(),
+            false,
+            platform_version,
+        )
+        .expect("expect that this be verified");
+
+        assert_eq!(proved_pending_transfer, None);
+    }
+
+    #[test]
+    fn should_prove_pending_transfers_of_an_identity() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let sender_id = Identifier::random();
+        let recipient_id = Identifier::random();
+
+        let pending_transfers: Vec<PendingTransfer> = (1..=3)
+            .map(|nonce| PendingTransfer {
+                id: PendingTransfer::id_from_sender_and_nonce(sender_id, nonce),
+                sender_id,
+                recipient_id,
+                amount: 100000 * nonce,
+                release_condition: TransferReleaseCondition::CoreBlockHeight(nonce as u32),
+            })
+            .collect();
+
+        for pending_transfer in &pending_transfers {
+            insert_pending_transfer(&drive, pending_transfer);
+        }
+
+        // a transfer between other identities should not be returned
+        let other_sender_id = Identifier::random();
+        insert_pending_transfer(
+            &drive,
+            &PendingTransfer {
+                id: PendingTransfer::id_from_sender_and_nonce(other_sender_id, 1),
+                sender_id: other_sender_id,
+                recipient_id: Identifier::random(),
+                amount: 100000,
+                release_condition: TransferReleaseCondition::CoreBlockHeight(1),
+            },
+        );
+
+        let mut expected_pending_transfers = pending_transfers.clone();
+        expected_pending_transfers.sort_by_key(|pending_transfer| pending_transfer.id);
+
+        for (identity_id, outgoing) in [(recipient_id, false), (sender_id, true)] {
+            let proof = drive
+                .prove_pending_transfers_for_identity(
+                    identity_id.to_buffer(),
+                    outgoing,
+                    None,
+                    None,
+                    &platform_version.drive,
+                )
+                .expect("should not error when proving pending transfers");
+
+            let (_, proved_pending_transfers) = Drive::verify_pending_transfers_for_identity_id(
+                proof.as_slice(),
+                identity_id.to_buffer(),
+                outgoing,
+                None,
+                false,
+                platform_version,
+            )
+            .expect("expect that this be verified");
+
+            assert_eq!(proved_pending_transfers, expected_pending_transfers);
+        }
+
+        let released_pending_transfers = drive
+            .fetch_released_pending_transfers(
+                &BlockInfo {
+                    core_height: 2,
+                    ..Default::default()
+                },
+                100,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch released pending transfers");
+
+        // the transfer of the other sender is released as well
+        assert_eq!(released_pending_transfers.len(), 3);
+    }
+}
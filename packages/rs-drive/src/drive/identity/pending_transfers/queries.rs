@@ -0,0 +1,83 @@
+use crate::drive::identity::pending_transfers::paths::{
+    get_pending_transfers_path_vec, pending_transfer_index_key, PENDING_TRANSFERS_BY_ID_KEY,
+    PENDING_TRANSFERS_BY_RECIPIENT_KEY, PENDING_TRANSFERS_BY_RELEASE_CORE_HEIGHT_KEY,
+    PENDING_TRANSFERS_BY_RELEASE_TIME_KEY, PENDING_TRANSFERS_BY_SENDER_KEY,
+};
+use crate::drive::Drive;
+use grovedb::{PathQuery, Query, SizedQuery};
+
+impl Drive {
+    /// The query for proving a pending transfer by its id
+    pub fn pending_transfer_query(transfer_id: [u8; 32]) -> PathQuery {
+        PathQuery::new_single_key(
+            get_pending_transfers_path_vec(PENDING_TRANSFERS_BY_ID_KEY),
+            transfer_id.to_vec(),
+        )
+    }
+
+    /// The query for the pending transfers to the identity,
+    /// or for the pending transfers sent by the identity if `outgoing` is true
+    pub fn pending_transfers_for_identity_query(
+        identity_id: [u8; 32],
+        outgoing: bool,
+        limit: Option<u16>,
+    ) -> PathQuery {
+        let subtree_key = if outgoing {
+            PENDING_TRANSFERS_BY_SENDER_KEY
+        } else {
+            PENDING_TRANSFERS_BY_RECIPIENT_KEY
+        };
+
+        let mut query = Query::new();
+        query.insert_range_inclusive(
+            pending_transfer_index_key(&identity_id, &[0; 32])
+                ..=pending_transfer_index_key(&identity_id, &[u8::MAX; 32]),
+        );
+
+        PathQuery {
+            path: get_pending_transfers_path_vec(subtree_key),
+            query: SizedQuery {
+                query,
+                limit,
+                offset: None,
+            },
+        }
+    }
+
+    /// The query for the pending transfers released by a block time of `time_ms`
+    pub fn pending_transfers_released_by_block_time_query(time_ms: u64, limit: u16) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_range_to_inclusive(
+            ..=pending_transfer_index_key(&time_ms.to_be_bytes(), &[u8::MAX; 32]),
+        );
+
+        PathQuery {
+            path: get_pending_transfers_path_vec(PENDING_TRANSFERS_BY_RELEASE_TIME_KEY),
+            query: SizedQuery {
+                query,
+                limit: Some(limit),
+                offset: None,
+            },
+        }
+    }
+
+    /// The query for the pending transfers released by a core block height of `core_height`
+    pub fn pending_transfers_released_by_core_height_query(
+        core_height: u32,
+        limit: u16,
+    ) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_range_to_inclusive(
+            ..=pending_transfer_index_key(&core_height.to_be_bytes(), &[u8::MAX; 32]),
+        );
+
+        PathQuery {
+            path: get_pending_transfers_path_vec(PENDING_TRANSFERS_BY_RELEASE_CORE_HEIGHT_KEY),
+            query: SizedQuery {
+                query,
+                limit: Some(limit),
+                offset: None,
+            },
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::drive::grove_operations::DirectQueryType;
+use crate::drive::identity::{identity_path, IdentityRootStructure};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::identity::recovery_proposal::IdentityRecoveryProposal;
+use dpp::identity::TimestampMillis;
+use dpp::serialization::PlatformDeserializable;
+use dpp::version::PlatformVersion;
+use grovedb::{Element, TransactionArg};
+
+impl Drive {
+    /// Fetches the pending recovery proposal of an identity
+    pub fn fetch_identity_recovery_proposal(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<IdentityRecoveryProposal>, Error> {
+        self.fetch_identity_recovery_proposal_operations(
+            identity_id,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    /// Fetches the pending recovery proposal of an identity and adds the cost of the fetch
+    /// to `drive_operations`
+    pub fn fetch_identity_recovery_proposal_operations(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<IdentityRecoveryProposal>, Error> {
+        let identity_path = identity_path(identity_id.as_slice());
+
+        let element = self.grove_get_raw_optional(
+            (&identity_path).into(),
+            Into::<&[u8; 1]>::into(IdentityRootStructure::IdentityTreeRecoveryProposal),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        )?;
+
+        match element {
+            Some(Element::Item(bytes, _)) => Ok(Some(
+                IdentityRecoveryProposal::deserialize_from_bytes(&bytes)?,
+            )),
+            Some(_) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "identity recovery proposal was present but was not identified as an item",
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches the recovery delay chosen by an identity, `None` if it never set one
+    pub fn fetch_identity_recovery_delay(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TimestampMillis>, Error> {
+        let identity_path = identity_path(identity_id.as_slice());
+
+        let element = self.grove_get_raw_optional(
+            (&identity_path).into(),
+            Into::<&[u8; 1]>::into(IdentityRootStructure::IdentityTreeRecoveryDelay),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )?;
+
+        match element {
+            Some(Element::Item(encoded_recovery_delay, _)) => {
+                Ok(Some(TimestampMillis::from_be_bytes(
+                    encoded_recovery_delay.try_into().map_err(|_| {
+                        Error::Drive(DriveError::CorruptedElementType(
+                            "identity recovery delay was not 8 bytes as expected",
+                        ))
+                    })?,
+                )))
+            }
+            Some(_) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "identity recovery delay was present but was not identified as an item",
+            ))),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! The recovery proposal of an identity, made by the guardians holding its recovery keys.
+//!
+//! An identity has at most one pending recovery proposal, stored as an item in the identity
+//! subtree. It is removed when it is vetoed by a master key of the identity or executed.
+//!
+//! The delay during which the master keys can veto a proposal is set by the identity and stored
+//! as another item of the identity subtree, the platform default is used when it was never set.
+
+#[cfg(feature = "full")]
+mod fetch;
+#[cfg(feature = "full")]
+mod operations;
+#[cfg(feature = "full")]
+mod prove;
+mod queries;
+
+/// The average size of a serialized recovery proposal
+#[cfg(feature = "full")]
+pub const AVERAGE_RECOVERY_PROPOSAL_SIZE: u32 = 120;
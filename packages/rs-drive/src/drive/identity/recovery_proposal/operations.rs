@@ -0,0 +1,116 @@
+use crate::drive::grove_operations::BatchDeleteApplyType;
+use crate::drive::identity::recovery_proposal::AVERAGE_RECOVERY_PROPOSAL_SIZE;
+use crate::drive::identity::{identity_path, identity_path_vec, IdentityRootStructure};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::identity::recovery_proposal::IdentityRecoveryProposal;
+use dpp::identity::TimestampMillis;
+use dpp::serialization::PlatformSerializable;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Inserts or replaces the recovery proposal of the identity
+    pub(crate) fn set_identity_recovery_proposal_operations(
+        &self,
+        identity_id: [u8; 32],
+        recovery_proposal: &IdentityRecoveryProposal,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            // the proposal is an item of the identity subtree, like the revision
+            Self::add_estimation_costs_for_update_revision(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(vec![
+            LowLevelDriveOperation::insert_for_known_path_key_element(
+                identity_path_vec(identity_id.as_slice()),
+                vec![IdentityRootStructure::IdentityTreeRecoveryProposal as u8],
+                Element::new_item(recovery_proposal.serialize_to_bytes()?),
+            ),
+        ])
+    }
+
+    /// Removes the recovery proposal of the identity once it was vetoed or executed
+    pub(crate) fn remove_identity_recovery_proposal_operations(
+        &self,
+        identity_id: [u8; 32],
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations = vec![];
+
+        let apply_type = if let Some(estimated_costs_only_with_layer_info) =
+            estimated_costs_only_with_layer_info
+        {
+            Self::add_estimation_costs_for_update_revision(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+            BatchDeleteApplyType::StatelessBatchDelete {
+                is_sum_tree: false,
+                estimated_value_size: AVERAGE_RECOVERY_PROPOSAL_SIZE,
+            }
+        } else {
+            // we know that we are not deleting a subtree
+            BatchDeleteApplyType::StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some((false, false)),
+            }
+        };
+
+        let identity_path = identity_path(identity_id.as_slice());
+
+        self.batch_delete(
+            (&identity_path).into(),
+            Into::<&[u8; 1]>::into(IdentityRootStructure::IdentityTreeRecoveryProposal),
+            apply_type,
+            transaction,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Ok(drive_operations)
+    }
+
+    /// Inserts or replaces the recovery delay chosen by the identity
+    pub(crate) fn set_identity_recovery_delay_operations(
+        &self,
+        identity_id: [u8; 32],
+        recovery_delay_ms: TimestampMillis,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            // the delay is an item of the identity subtree, like the revision
+            Self::add_estimation_costs_for_update_revision(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(vec![
+            LowLevelDriveOperation::insert_for_known_path_key_element(
+                identity_path_vec(identity_id.as_slice()),
+                vec![IdentityRootStructure::IdentityTreeRecoveryDelay as u8],
+                Element::new_item(recovery_delay_ms.to_be_bytes().to_vec()),
+            ),
+        ])
+    }
+}
@@ -0,0 +1,199 @@
+use crate::drive::Drive;
+use crate::error::Error;
+
+use dpp::version::drive_versions::DriveVersion;
+
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the recovery proposal of an identity, or its absence
+    pub fn prove_identity_recovery_proposal(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let recovery_proposal_query = Self::identity_recovery_proposal_query(&identity_id);
+        self.grove_get_proved_path_query(
+            &recovery_proposal_query,
+            false,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )
+    }
+
+    /// Proves the recovery delay of an identity, or its absence
+    pub fn prove_identity_recovery_delay(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let recovery_delay_query = Self::identity_recovery_delay_query(&identity_id);
+        self.grove_get_proved_path_query(
+            &recovery_delay_query,
+            false,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::batch::{DriveOperation, IdentityOperationType};
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::recovery_proposal::IdentityRecoveryProposal;
+    use dpp::identity::Identity;
+    use dpp::version::PlatformVersion;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn should_prove_a_recovery_proposal_and_its_absence_once_removed() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let identity = Identity::random_identity(3, Some(14), platform_version)
+            .expect("expected a random identity");
+
+        drive
+            .add_new_identity(
+                identity.clone(),
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to insert identity");
+
+        let identity_id = identity.id().to_buffer();
+
+        let recovery_proposal = IdentityRecoveryProposal {
+            new_master_keys: vec![identity
+                .public_keys()
+                .values()
+                .next()
+                .expect("expected a key")
+                .clone()],
+            approving_key_ids: BTreeSet::from([1]),
+            proposed_at: 1000,
+        };
+
+        drive
+            .apply_drive_operations(
+                vec![DriveOperation::IdentityOperation(
+                    IdentityOperationType::SetIdentityRecoveryProposal {
+                        identity_id,
+                        recovery_proposal: recovery_proposal.clone(),
+                    },
+                )],
+                true,
+                &BlockInfo::default(),
+                None,
+                platform_version,
+            )
+            .expect("expected to set the recovery proposal");
+
+        let proof = drive
+            .prove_identity_recovery_proposal(identity_id, None, &platform_version.drive)
+            .expect("expected to prove the recovery proposal");
+
+        let (_, proved_recovery_proposal) =
+            Drive::verify_identity_recovery_proposal(&proof, identity_id, false, platform_version)
+                .expect("expected to verify the recovery proposal");
+
+        assert_eq!(proved_recovery_proposal, Some(recovery_proposal));
+
+        drive
+            .apply_drive_operations(
+                vec![DriveOperation::IdentityOperation(
+                    IdentityOperationType::RemoveIdentityRecoveryProposal { identity_id },
+                )],
+                true,
+                &BlockInfo::default(),
+                None,
+                platform_version,
+            )
+            .expect("expected to remove the recovery proposal");
+
+        let proof = drive
+            .prove_identity_recovery_proposal(identity_id, None, &platform_version.drive)
+            .expect("expected to prove the absence of the recovery proposal");
+
+        let (_, proved_recovery_proposal) =
+            Drive::verify_identity_recovery_proposal(&proof, identity_id, false, platform_version)
+                .expect("expected to verify the absence of the recovery proposal");
+
+        assert_eq!(proved_recovery_proposal, None);
+    }
+
+    #[test]
+    fn should_prove_a_recovery_delay_and_its_absence() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let identity = Identity::random_identity(3, Some(15), platform_version)
+            .expect("expected a random identity");
+
+        drive
+            .add_new_identity(
+                identity.clone(),
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to insert identity");
+
+        let identity_id = identity.id().to_buffer();
+
+        let proof = drive
+            .prove_identity_recovery_delay(identity_id, None, &platform_version.drive)
+            .expect("expected to prove the absence of the recovery delay");
+
+        let (_, proved_recovery_delay) =
+            Drive::verify_identity_recovery_delay(&proof, identity_id, false, platform_version)
+                .expect("expected to verify the absence of the recovery delay");
+
+        assert_eq!(proved_recovery_delay, None);
+
+        drive
+            .apply_drive_operations(
+                vec![DriveOperation::IdentityOperation(
+                    IdentityOperationType::SetIdentityRecoveryDelay {
+                        identity_id,
+                        recovery_delay_ms: 86_400_000,
+                    },
+                )],
+                true,
+                &BlockInfo::default(),
+                None,
+                platform_version,
+            )
+            .expect("expected to set the recovery delay");
+
+        assert_eq!(
+            drive
+                .fetch_identity_recovery_delay(identity_id, None, platform_version)
+                .expect("expected to fetch the recovery delay"),
+            Some(86_400_000)
+        );
+
+        let proof = drive
+            .prove_identity_recovery_delay(identity_id, None, &platform_version.drive)
+            .expect("expected to prove the recovery delay");
+
+        let (_, proved_recovery_delay) =
+            Drive::verify_identity_recovery_delay(&proof, identity_id, false, platform_version)
+                .expect("expected to verify the recovery delay");
+
+        assert_eq!(proved_recovery_delay, Some(86_400_000));
+    }
+}
@@ -0,0 +1,21 @@
+use crate::drive::identity::{identity_path_vec, IdentityRootStructure};
+use crate::drive::Drive;
+use grovedb::PathQuery;
+
+impl Drive {
+    /// The query for proving the recovery proposal of an identity
+    pub fn identity_recovery_proposal_query(identity_id: &[u8; 32]) -> PathQuery {
+        PathQuery::new_single_key(
+            identity_path_vec(identity_id),
+            vec![IdentityRootStructure::IdentityTreeRecoveryProposal as u8],
+        )
+    }
+
+    /// The query for proving the recovery delay of an identity
+    pub fn identity_recovery_delay_query(identity_id: &[u8; 32]) -> PathQuery {
+        PathQuery::new_single_key(
+            identity_path_vec(identity_id),
+            vec![IdentityRootStructure::IdentityTreeRecoveryDelay as u8],
+        )
+    }
+}
@@ -8,4 +8,5 @@ mod disable_identity_keys;
 pub(crate) mod merge_identity_nonce;
 mod re_enable_identity_keys;
 mod remove_from_identity_balance;
+mod update_identity_key_spending_limits;
 mod update_identity_revision;
@@ -0,0 +1,112 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::fee::Credits;
+use dpp::identity::KeyID;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+
+use std::collections::HashMap;
+
+impl Drive {
+    /// Sets or removes the spending limits of identity keys. This function is version controlled.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The ID of the Identity whose keys are updated.
+    /// * `key_spending_limits` - The IDs of the keys along with their new spending limit per epoch,
+    ///   none removes the limit.
+    /// * `block_info` - The current block information.
+    /// * `apply` - Whether to apply the change.
+    /// * `transaction` - The current transaction.
+    /// * `platform_version` - The platform version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FeeResult, Error>` - The resulting fee if successful, or an error.
+    pub fn update_identity_key_spending_limits(
+        &self,
+        identity_id: [u8; 32],
+        key_spending_limits: Vec<(KeyID, Option<Credits>)>,
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .update
+            .update_identity_key_spending_limits
+        {
+            0 => self.update_identity_key_spending_limits_v0(
+                identity_id,
+                key_spending_limits,
+                block_info,
+                apply,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "update_identity_key_spending_limits".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Operations for setting or removing the spending limits of identity keys.
+    /// This function is version controlled.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The ID of the Identity whose keys are updated.
+    /// * `key_spending_limits` - The IDs of the keys along with their new spending limit per epoch,
+    ///   none removes the limit.
+    /// * `estimated_costs_only_with_layer_info` - The estimated costs with layer information.
+    /// * `transaction` - The current transaction.
+    /// * `platform_version` - The platform version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<LowLevelDriveOperation>, Error>` - The resulting low level drive operations if successful, or an error.
+    pub fn update_identity_key_spending_limits_operations(
+        &self,
+        identity_id: [u8; 32],
+        key_spending_limits: Vec<(KeyID, Option<Credits>)>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .update
+            .update_identity_key_spending_limits
+        {
+            0 => self.update_identity_key_spending_limits_operations_v0(
+                identity_id,
+                key_spending_limits,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "update_identity_key_spending_limits_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
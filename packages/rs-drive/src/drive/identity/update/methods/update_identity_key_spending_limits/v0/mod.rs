@@ -0,0 +1,173 @@
+use dpp::block::block_info::BlockInfo;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use grovedb::batch::KeyInfoPath;
+
+use crate::drive::identity::key::fetch::{
+    IdentityKeysRequest, KeyIDIdentityPublicKeyPairBTreeMap, KeyRequestType,
+};
+use dpp::fee::fee_result::FeeResult;
+use dpp::fee::Credits;
+use dpp::identity::KeyID;
+
+use crate::drive::grove_operations::BatchDeleteApplyType;
+use crate::drive::identity::key_spending::identity_key_spending_limit_key;
+use crate::drive::identity::{identity_path, identity_path_vec};
+use dpp::version::PlatformVersion;
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Update the spending limits of identity keys
+    pub(super) fn update_identity_key_spending_limits_v0(
+        &self,
+        identity_id: [u8; 32],
+        key_spending_limits: Vec<(KeyID, Option<Credits>)>,
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let mut estimated_costs_only_with_layer_info = if apply {
+            None::<HashMap<KeyInfoPath, EstimatedLayerInformation>>
+        } else {
+            Some(HashMap::new())
+        };
+
+        let batch_operations = self.update_identity_key_spending_limits_operations_v0(
+            identity_id,
+            key_spending_limits,
+            &mut estimated_costs_only_with_layer_info,
+            transaction,
+            platform_version,
+        )?;
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        let fees = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+        )?;
+
+        Ok(fees)
+    }
+
+    /// Sets or removes the spending limit per epoch of a set of identity keys in version 0.
+    ///
+    /// The limits are stored next to the keys in the identity subtree, so the keys themselves
+    /// are left untouched, and the credits they already spent during the current epoch are kept.
+    pub(super) fn update_identity_key_spending_limits_operations_v0(
+        &self,
+        identity_id: [u8; 32],
+        key_spending_limits: Vec<(KeyID, Option<Credits>)>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations = vec![];
+
+        let drive_version = &platform_version.drive;
+
+        let identity_path = identity_path(identity_id.as_slice());
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            // the limits are items of the identity subtree, like the revision
+            Self::add_estimation_costs_for_update_revision(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+                drive_version,
+            )?;
+        } else {
+            let key_ids: Vec<KeyID> = key_spending_limits
+                .iter()
+                .map(|(key_id, _)| *key_id)
+                .collect();
+
+            let key_ids_len = key_ids.len();
+
+            let key_request = IdentityKeysRequest {
+                identity_id,
+                request_type: KeyRequestType::SpecificKeys(key_ids),
+                limit: Some(key_ids_len as u16),
+                offset: None,
+            };
+
+            let keys: KeyIDIdentityPublicKeyPairBTreeMap = self.fetch_identity_keys_operations(
+                key_request,
+                transaction,
+                &mut drive_operations,
+                platform_version,
+            )?;
+
+            if keys.len() != key_ids_len {
+                return Err(Error::Drive(DriveError::UpdatingDocumentThatDoesNotExist(
+                    "key to update the spending limit of with specified ID is not found",
+                )));
+            }
+        }
+
+        for (key_id, spending_limit) in key_spending_limits {
+            let spending_limit_key = identity_key_spending_limit_key(key_id);
+
+            if let Some(spending_limit) = spending_limit {
+                drive_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+                    identity_path_vec(identity_id.as_slice()),
+                    spending_limit_key,
+                    Element::new_item(spending_limit.to_be_bytes().to_vec()),
+                ));
+                continue;
+            }
+
+            let apply_type = if estimated_costs_only_with_layer_info.is_some() {
+                BatchDeleteApplyType::StatelessBatchDelete {
+                    is_sum_tree: false,
+                    estimated_value_size: 8,
+                }
+            } else if self
+                .fetch_identity_key_spending_limit_operations(
+                    identity_id,
+                    key_id,
+                    transaction,
+                    &mut drive_operations,
+                    platform_version,
+                )?
+                .is_some()
+            {
+                // we know that we are not deleting a subtree
+                BatchDeleteApplyType::StatefulBatchDelete {
+                    is_known_to_be_subtree_with_sum: Some((false, false)),
+                }
+            } else {
+                // the key was not limited
+                continue;
+            };
+
+            self.batch_delete(
+                (&identity_path).into(),
+                spending_limit_key.as_slice(),
+                apply_type,
+                transaction,
+                &mut drive_operations,
+                drive_version,
+            )?;
+        }
+
+        Ok(drive_operations)
+    }
+}
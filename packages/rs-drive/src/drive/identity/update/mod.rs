@@ -378,6 +378,81 @@ mod tests {
         }
     }
 
+    mod update_identity_key_spending_limits {
+        use super::*;
+        use dpp::block::block_info::BlockInfo;
+        use dpp::block::epoch::Epoch;
+        use dpp::version::PlatformVersion;
+
+        #[test]
+        fn should_set_and_remove_spending_limits() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let platform_version = PlatformVersion::first();
+
+            let identity = Identity::random_identity(5, Some(12345), platform_version)
+                .expect("expected a random identity");
+
+            let block_info = BlockInfo::default_with_epoch(Epoch::new(0).unwrap());
+
+            drive
+                .add_new_identity(
+                    identity.clone(),
+                    false,
+                    &block_info,
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to insert identity");
+
+            drive
+                .update_identity_key_spending_limits(
+                    identity.id().to_buffer(),
+                    vec![(1, Some(50000)), (2, Some(100000))],
+                    &block_info,
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to set spending limits");
+
+            drive
+                .update_identity_key_spending_limits(
+                    identity.id().to_buffer(),
+                    vec![(2, None)],
+                    &block_info,
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to remove spending limit");
+
+            let identity_keys = drive
+                .fetch_all_identity_keys(identity.id().to_buffer(), None, platform_version)
+                .expect("expected to get keys");
+
+            assert_eq!(identity_keys, identity.public_keys().clone());
+
+            for key_id in identity_keys.keys() {
+                let spending_limit = drive
+                    .fetch_identity_key_spending_limit(
+                        identity.id().to_buffer(),
+                        *key_id,
+                        None,
+                        platform_version,
+                    )
+                    .expect("expected to fetch the spending limit");
+
+                if *key_id == 1 {
+                    assert_eq!(spending_limit, Some(50000));
+                } else {
+                    assert_eq!(spending_limit, None);
+                }
+            }
+        }
+    }
+
     mod update_identity_revision {
         use super::*;
         use dpp::block::block_info::BlockInfo;
@@ -72,7 +72,10 @@ impl Drive {
                 primary_key_equal_clause: None,
                 in_clause: None,
                 range_clause: None,
+                residual_range_clauses: vec![],
                 equal_clauses: where_clauses,
+                or_clauses: vec![],
+                contains_word_clause: None,
             },
             offset: None,
             limit: Some(limit),
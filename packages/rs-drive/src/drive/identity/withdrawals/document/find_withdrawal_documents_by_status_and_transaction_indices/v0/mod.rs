@@ -15,7 +15,8 @@ use std::collections::BTreeMap;
 
 impl Drive {
     // TODO(withdrawals): Currently it queries only up to 100 documents.
-    //  It works while we don't have pooling
+    //  It works while the number of withdrawals pooled into a transaction is limited
+    //  so the documents of all dequeued transactions fit into the limit
 
     pub(super) fn find_withdrawal_documents_by_status_and_transaction_indices_v0(
         &self,
@@ -72,7 +73,10 @@ impl Drive {
                 primary_key_equal_clause: None,
                 in_clause: None,
                 range_clause: None,
+                residual_range_clauses: vec![],
                 equal_clauses: where_clauses,
+                or_clauses: vec![],
+                contains_word_clause: None,
             },
             offset: None,
             limit: Some(limit),
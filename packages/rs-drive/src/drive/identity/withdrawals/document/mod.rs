@@ -2,3 +2,5 @@
 pub mod fetch_oldest_withdrawal_documents_by_status;
 /// This module dedicated for a versioned find_up_to_100_withdrawal_documents_by_status_and_transaction_indices
 pub mod find_withdrawal_documents_by_status_and_transaction_indices;
+/// This module dedicated for a versioned prove_withdrawals_for_identity_id
+pub mod prove_withdrawals_for_identity_id;
@@ -0,0 +1,53 @@
+mod v0;
+mod v1;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::DataContract;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    /// Proves the withdrawals requested by an identity, most recently updated first.
+    ///
+    /// From protocol version 2 the proof of the withdrawal documents is followed by the proof of
+    /// the Core transaction ids of the transactions they are part of.
+    pub fn prove_withdrawals_for_identity_id(
+        &self,
+        withdrawals_contract: &DataContract,
+        identity_id: [u8; 32],
+        limit: u16,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .withdrawals
+            .document
+            .prove_withdrawals_for_identity_id
+        {
+            0 => self.prove_withdrawals_for_identity_id_v0(
+                withdrawals_contract,
+                identity_id,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            1 => self.prove_withdrawals_for_identity_id_v1(
+                withdrawals_contract,
+                identity_id,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_withdrawals_for_identity_id".to_string(),
+                known_versions: vec![0, 1],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::data_contract::DataContract;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn prove_withdrawals_for_identity_id_v0(
+        &self,
+        withdrawals_contract: &DataContract,
+        identity_id: [u8; 32],
+        limit: u16,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let query = Self::withdrawals_for_identity_query(withdrawals_contract, identity_id, limit)?;
+
+        let (proof, _) = query.execute_with_proof(self, None, transaction, platform_version)?;
+
+        Ok(proof)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::drive::identity::withdrawals::WithdrawalTransactionIndex;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::query::DriveQuery;
+use dpp::data_contract::DataContract;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::Document;
+use dpp::version::PlatformVersion;
+use dpp::withdrawal::identity_withdrawal::IdentityWithdrawal;
+use grovedb::TransactionArg;
+use std::collections::BTreeSet;
+
+impl Drive {
+    pub(super) fn prove_withdrawals_for_identity_id_v1(
+        &self,
+        withdrawals_contract: &DataContract,
+        identity_id: [u8; 32],
+        limit: u16,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let query = Self::withdrawals_for_identity_query(withdrawals_contract, identity_id, limit)?;
+
+        let (serialized_documents, _, _) =
+            query.execute_raw_results_no_proof(self, None, transaction, platform_version)?;
+
+        let transaction_indexes = serialized_documents
+            .iter()
+            .map(|serialized_document| {
+                let document = Document::from_bytes(
+                    serialized_document,
+                    query.document_type,
+                    platform_version,
+                )?;
+
+                Ok(IdentityWithdrawal::try_from(document)?.transaction_index)
+            })
+            .collect::<Result<Vec<_>, Error>>()?
+            .into_iter()
+            .flatten()
+            .collect::<BTreeSet<WithdrawalTransactionIndex>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        // Without pooled withdrawals there are no transaction ids to prove
+        let transaction_ids_proof = if transaction_indexes.is_empty() {
+            vec![]
+        } else {
+            self.prove_withdrawal_transaction_ids(
+                &transaction_indexes,
+                transaction,
+                platform_version,
+            )?
+        };
+
+        let (documents_proof, _) =
+            query.execute_with_proof(self, None, transaction, platform_version)?;
+
+        Ok(DriveQuery::encode_proof_sequence(vec![
+            documents_proof,
+            transaction_ids_proof,
+        ]))
+    }
+}
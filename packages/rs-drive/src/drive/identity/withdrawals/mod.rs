@@ -1,12 +1,18 @@
 /// Functions related to withdrawal documents
+#[cfg(feature = "full")]
 pub mod document;
 
 /// Functions and constants related to GroveDB paths
 pub mod paths;
+/// Queries of the withdrawal documents of an identity and of withdrawal transaction ids
+mod queries;
 /// Functions related to withdrawal transactions
+#[cfg(feature = "full")]
 pub mod transaction;
 
 /// Simple type alias for withdrawal transaction with it's index
 pub type WithdrawalTransactionIndexAndBytes = (WithdrawalTransactionIndex, Vec<u8>);
 /// Transaction index type
 pub type WithdrawalTransactionIndex = u64;
+/// Simple type alias for the Core transaction id of a withdrawal transaction with it's index
+pub type WithdrawalTransactionIndexAndId = (WithdrawalTransactionIndex, [u8; 32]);
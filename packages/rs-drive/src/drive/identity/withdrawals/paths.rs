@@ -1,14 +1,24 @@
+#[cfg(feature = "full")]
 use grovedb::Element;
 
+#[cfg(feature = "full")]
 use crate::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
-use crate::drive::{batch::GroveDbOpBatch, RootTree};
+#[cfg(feature = "full")]
+use crate::drive::batch::GroveDbOpBatch;
+use crate::drive::RootTree;
 
 /// constant key for transaction counter
 pub const WITHDRAWAL_TRANSACTIONS_NEXT_INDEX_KEY: [u8; 1] = [0];
 /// constant id for subtree containing transactions queue
 pub const WITHDRAWAL_TRANSACTIONS_QUEUE_KEY: [u8; 1] = [1];
+/// constant id for subtree containing the transactions built in the last block, until their
+/// quorum signatures are known
+pub const WITHDRAWAL_TRANSACTIONS_UNSIGNED_KEY: [u8; 1] = [2];
+/// constant id for subtree containing the Core transaction ids of signed transactions by index
+pub const WITHDRAWAL_TRANSACTIONS_IDS_KEY: [u8; 1] = [3];
 
 /// Add operations for creating initial withdrawal state structure
+#[cfg(feature = "full")]
 pub fn add_initial_withdrawal_state_structure_operations(batch: &mut GroveDbOpBatch) {
     batch.add_insert(
         vec![vec![RootTree::WithdrawalTransactions as u8]],
@@ -22,6 +32,21 @@ pub fn add_initial_withdrawal_state_structure_operations(batch: &mut GroveDbOpBa
     );
 }
 
+/// Add operations for creating the trees keeping the Core transaction ids of withdrawal
+/// transactions, they exist from protocol version 2
+#[cfg(feature = "full")]
+pub fn add_initial_withdrawal_transaction_ids_structure_operations(batch: &mut GroveDbOpBatch) {
+    batch.add_insert_empty_tree(
+        vec![vec![RootTree::WithdrawalTransactions as u8]],
+        WITHDRAWAL_TRANSACTIONS_UNSIGNED_KEY.to_vec(),
+    );
+
+    batch.add_insert_empty_tree(
+        vec![vec![RootTree::WithdrawalTransactions as u8]],
+        WITHDRAWAL_TRANSACTIONS_IDS_KEY.to_vec(),
+    );
+}
+
 /// Helper function to get root path
 pub fn get_withdrawal_root_path_vec() -> Vec<Vec<u8>> {
     vec![vec![RootTree::WithdrawalTransactions as u8]]
@@ -47,3 +72,27 @@ pub fn get_withdrawal_transactions_queue_path() -> [&'static [u8]; 2] {
         &WITHDRAWAL_TRANSACTIONS_QUEUE_KEY,
     ]
 }
+
+/// Helper function to get unsigned transactions path as Vec
+pub fn get_withdrawal_transactions_unsigned_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::WithdrawalTransactions as u8],
+        WITHDRAWAL_TRANSACTIONS_UNSIGNED_KEY.to_vec(),
+    ]
+}
+
+/// Helper function to get unsigned transactions path as [u8]
+pub fn get_withdrawal_transactions_unsigned_path() -> [&'static [u8]; 2] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::WithdrawalTransactions),
+        &WITHDRAWAL_TRANSACTIONS_UNSIGNED_KEY,
+    ]
+}
+
+/// Helper function to get transaction ids path as Vec
+pub fn get_withdrawal_transaction_ids_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::WithdrawalTransactions as u8],
+        WITHDRAWAL_TRANSACTIONS_IDS_KEY.to_vec(),
+    ]
+}
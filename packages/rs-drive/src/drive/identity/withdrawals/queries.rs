@@ -0,0 +1,76 @@
+use crate::drive::identity::withdrawals::paths::get_withdrawal_transaction_ids_path_vec;
+use crate::drive::identity::withdrawals::WithdrawalTransactionIndex;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::query::{DriveQuery, InternalClauses, OrderClause, Query, WhereClause, WhereOperator};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::data_contracts::withdrawals_contract::v1::document_types::withdrawal;
+use dpp::platform_value::Value;
+use grovedb::{PathQuery, SizedQuery};
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// The query for the withdrawal documents of an identity, most recently updated first
+    pub fn withdrawals_for_identity_query(
+        withdrawals_contract: &DataContract,
+        identity_id: [u8; 32],
+        limit: u16,
+    ) -> Result<DriveQuery, Error> {
+        let document_type = withdrawals_contract.document_type_for_name(withdrawal::NAME)?;
+
+        let mut equal_clauses = BTreeMap::new();
+
+        equal_clauses.insert(
+            withdrawal::properties::OWNER_ID.to_string(),
+            WhereClause {
+                field: withdrawal::properties::OWNER_ID.to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(identity_id),
+            },
+        );
+
+        let mut order_by = IndexMap::new();
+
+        order_by.insert(
+            withdrawal::properties::UPDATED_AT.to_string(),
+            OrderClause {
+                field: withdrawal::properties::UPDATED_AT.to_string(),
+                ascending: false,
+            },
+        );
+
+        Ok(DriveQuery {
+            contract: withdrawals_contract,
+            document_type,
+            internal_clauses: InternalClauses {
+                equal_clauses,
+                ..Default::default()
+            },
+            offset: None,
+            limit: Some(limit),
+            order_by,
+            start_at: None,
+            start_at_included: false,
+            block_time_ms: None,
+        })
+    }
+
+    /// The query for the Core transaction ids of withdrawal transactions by their indexes
+    pub fn withdrawal_transaction_ids_query(
+        transaction_indexes: &[WithdrawalTransactionIndex],
+    ) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_keys(
+            transaction_indexes
+                .iter()
+                .map(|index| index.to_be_bytes().to_vec())
+                .collect(),
+        );
+        PathQuery::new(
+            get_withdrawal_transaction_ids_path_vec(),
+            SizedQuery::new(query, Some(transaction_indexes.len() as u16), None),
+        )
+    }
+}
@@ -0,0 +1,43 @@
+use crate::drive::batch::DriveOperation;
+use crate::drive::identity::withdrawals::WithdrawalTransactionIndexAndId;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use platform_version::version::PlatformVersion;
+
+mod v0;
+
+impl Drive {
+    /// Add insert operations for the Core transaction ids of signed withdrawal transactions
+    /// to the batch
+    pub fn add_insert_withdrawal_transaction_ids_operations(
+        &self,
+        transaction_ids: Vec<WithdrawalTransactionIndexAndId>,
+        drive_operation_types: &mut Vec<DriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .withdrawals
+            .transaction
+            .ids
+            .add_insert_withdrawal_transaction_ids_operations
+        {
+            0 => {
+                self.add_insert_withdrawal_transaction_ids_operations_v0(
+                    transaction_ids,
+                    drive_operation_types,
+                );
+
+                Ok(())
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "add_insert_withdrawal_transaction_ids_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::drive::batch::drive_op_batch::WithdrawalOperationType;
+use crate::drive::batch::DriveOperation;
+use crate::drive::identity::withdrawals::WithdrawalTransactionIndexAndId;
+use crate::drive::Drive;
+
+impl Drive {
+    pub(super) fn add_insert_withdrawal_transaction_ids_operations_v0(
+        &self,
+        transaction_ids: Vec<WithdrawalTransactionIndexAndId>,
+        drive_operation_types: &mut Vec<DriveOperation>,
+    ) {
+        if !transaction_ids.is_empty() {
+            drive_operation_types.push(DriveOperation::WithdrawalOperation(
+                WithdrawalOperationType::InsertTransactionIds { transaction_ids },
+            ));
+        }
+    }
+}
@@ -0,0 +1,39 @@
+mod v0;
+
+use crate::drive::identity::withdrawals::WithdrawalTransactionIndex;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Fetches the Core transaction ids of withdrawal transactions by their indexes.
+    ///
+    /// Transactions that are not signed yet, or that were signed before protocol version 2,
+    /// have no id and are absent from the result.
+    pub fn fetch_withdrawal_transaction_ids(
+        &self,
+        transaction_indexes: &[WithdrawalTransactionIndex],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<WithdrawalTransactionIndex, [u8; 32]>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .withdrawals
+            .transaction
+            .ids
+            .fetch_withdrawal_transaction_ids
+        {
+            0 => self.fetch_withdrawal_transaction_ids_v0(transaction_indexes, transaction),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_withdrawal_transaction_ids".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
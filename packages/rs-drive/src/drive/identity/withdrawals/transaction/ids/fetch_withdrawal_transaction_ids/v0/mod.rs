@@ -0,0 +1,62 @@
+use crate::drive::identity::withdrawals::WithdrawalTransactionIndex;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{Element, TransactionArg};
+use std::collections::BTreeMap;
+
+impl Drive {
+    pub(super) fn fetch_withdrawal_transaction_ids_v0(
+        &self,
+        transaction_indexes: &[WithdrawalTransactionIndex],
+        transaction: TransactionArg,
+    ) -> Result<BTreeMap<WithdrawalTransactionIndex, [u8; 32]>, Error> {
+        if transaction_indexes.is_empty() {
+            return Ok(BTreeMap::new());
+        }
+
+        let path_query = Self::withdrawal_transaction_ids_query(transaction_indexes);
+
+        let result_items = self
+            .grove
+            .query_raw(
+                &path_query,
+                transaction.is_some(),
+                true,
+                QueryResultType::QueryKeyElementPairResultType,
+                transaction,
+            )
+            .unwrap()
+            .map_err(Error::GroveDB)?
+            .0
+            .to_key_elements();
+
+        result_items
+            .into_iter()
+            .map(|(index_bytes, element)| {
+                let index = WithdrawalTransactionIndex::from_be_bytes(
+                    index_bytes.try_into().map_err(|_| {
+                        Error::Drive(DriveError::CorruptedSerialization(String::from(
+                            "withdrawal index must be u64",
+                        )))
+                    })?,
+                );
+
+                let Element::Item(transaction_id, _) = element else {
+                    return Err(Error::Drive(DriveError::CorruptedWithdrawalNotItem(
+                        "withdrawal transaction id is not an item",
+                    )));
+                };
+
+                let transaction_id = transaction_id.try_into().map_err(|_| {
+                    Error::Drive(DriveError::CorruptedSerialization(String::from(
+                        "withdrawal transaction id must be 32 bytes",
+                    )))
+                })?;
+
+                Ok((index, transaction_id))
+            })
+            .collect()
+    }
+}
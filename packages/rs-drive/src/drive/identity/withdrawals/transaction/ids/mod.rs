@@ -0,0 +1,6 @@
+/// This module dedicated for a versioned add_insert_withdrawal_transaction_ids_operations
+pub mod add_insert_withdrawal_transaction_ids_operations;
+/// This module dedicated for a versioned fetch_withdrawal_transaction_ids
+pub mod fetch_withdrawal_transaction_ids;
+/// This module dedicated for a versioned prove_withdrawal_transaction_ids
+pub mod prove_withdrawal_transaction_ids;
@@ -0,0 +1,40 @@
+mod v0;
+
+use crate::drive::identity::withdrawals::WithdrawalTransactionIndex;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    /// Proves the Core transaction ids of withdrawal transactions by their indexes, transactions
+    /// without id are proved absent.
+    pub fn prove_withdrawal_transaction_ids(
+        &self,
+        transaction_indexes: &[WithdrawalTransactionIndex],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .withdrawals
+            .transaction
+            .ids
+            .prove_withdrawal_transaction_ids
+        {
+            0 => self.prove_withdrawal_transaction_ids_v0(
+                transaction_indexes,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_withdrawal_transaction_ids".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
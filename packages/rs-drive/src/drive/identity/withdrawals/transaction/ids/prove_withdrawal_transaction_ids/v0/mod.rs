@@ -0,0 +1,23 @@
+use crate::drive::identity::withdrawals::WithdrawalTransactionIndex;
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn prove_withdrawal_transaction_ids_v0(
+        &self,
+        transaction_indexes: &[WithdrawalTransactionIndex],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query = Self::withdrawal_transaction_ids_query(transaction_indexes);
+        self.grove_get_proved_path_query(
+            &path_query,
+            false,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )
+    }
+}
@@ -3,3 +3,6 @@ pub mod queue;
 
 /// Functions related to transaction index counter
 pub mod index;
+
+/// Functions related to Core transaction ids of signed transactions
+pub mod ids;
@@ -0,0 +1,20 @@
+use crate::drive::batch::drive_op_batch::WithdrawalOperationType;
+use crate::drive::batch::DriveOperation;
+use crate::drive::identity::withdrawals::WithdrawalTransactionIndexAndBytes;
+use crate::drive::Drive;
+
+impl Drive {
+    pub(super) fn add_enqueue_unsigned_withdrawal_transaction_operations_v0(
+        &self,
+        withdrawal_transactions: Vec<WithdrawalTransactionIndexAndBytes>,
+        drive_operation_types: &mut Vec<DriveOperation>,
+    ) {
+        if !withdrawal_transactions.is_empty() {
+            drive_operation_types.push(DriveOperation::WithdrawalOperation(
+                WithdrawalOperationType::InsertUnsignedTransactions {
+                    withdrawal_transactions,
+                },
+            ));
+        }
+    }
+}
@@ -0,0 +1,39 @@
+mod v0;
+
+use crate::drive::batch::DriveOperation;
+use crate::drive::identity::withdrawals::WithdrawalTransactionIndexAndBytes;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    /// Get all unsigned withdrawal transactions waiting for their quorum signatures from the DB,
+    /// ordered by index
+    pub fn dequeue_unsigned_withdrawal_transactions(
+        &self,
+        transaction: TransactionArg,
+        drive_operation_types: &mut Vec<DriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<WithdrawalTransactionIndexAndBytes>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .withdrawals
+            .transaction
+            .queue
+            .dequeue_unsigned_withdrawal_transactions
+        {
+            0 => {
+                self.dequeue_unsigned_withdrawal_transactions_v0(transaction, drive_operation_types)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "dequeue_unsigned_withdrawal_transactions".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -1,5 +1,9 @@
+/// This module dedicated for a versioned add_enqueue_unsigned_withdrawal_transaction_operations
+pub mod add_enqueue_unsigned_withdrawal_transaction_operations;
 /// This module dedicated for a versioned add_enqueue_untied_withdrawal_transaction_operations
 pub mod add_enqueue_untied_withdrawal_transaction_operations;
+/// This module dedicated for a versioned dequeue_unsigned_withdrawal_transactions
+pub mod dequeue_unsigned_withdrawal_transactions;
 /// This module dedicated for a versioned dequeue_untied_withdrawal_transactions
 pub mod dequeue_untied_withdrawal_transactions;
 
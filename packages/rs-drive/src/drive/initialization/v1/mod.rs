@@ -0,0 +1,108 @@
+//! Drive Initialization
+
+use crate::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
+use crate::drive::batch::GroveDbOpBatch;
+use crate::drive::identity::{
+    add_initial_pending_transfers_structure_operations,
+    add_initial_withdrawal_transaction_ids_structure_operations,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Creates the initial state structure, with the pending transfers and withdrawal transaction
+    /// ids trees that were added in protocol version 2.
+    pub(super) fn create_initial_state_structure_1(
+        &self,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        self.create_initial_state_structure_0(transaction, platform_version)?;
+
+        let mut batch = GroveDbOpBatch::new();
+
+        // In Misc: pending transfers
+        add_initial_pending_transfers_structure_operations(&mut batch);
+
+        // In WithdrawalTransactions: unsigned transactions and transaction ids
+        add_initial_withdrawal_transaction_ids_structure_operations(&mut batch);
+
+        self.grove_apply_batch(batch, false, transaction, &platform_version.drive)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use crate::drive::identity::pending_transfers::paths::PENDING_TRANSFERS_KEY;
+    use crate::drive::identity::withdrawals::paths::{
+        get_withdrawal_root_path, WITHDRAWAL_TRANSACTIONS_IDS_KEY,
+        WITHDRAWAL_TRANSACTIONS_UNSIGNED_KEY,
+    };
+    use crate::drive::system::misc_path;
+    use crate::drive::Drive;
+    use crate::tests::helpers::setup::setup_drive;
+    use dpp::version::PlatformVersion;
+
+    fn has_pending_transfers_tree(drive: &Drive) -> bool {
+        drive
+            .grove
+            .get(&misc_path(), PENDING_TRANSFERS_KEY, None)
+            .unwrap()
+            .is_ok()
+    }
+
+    fn has_withdrawal_transaction_ids_trees(drive: &Drive) -> bool {
+        [
+            WITHDRAWAL_TRANSACTIONS_UNSIGNED_KEY,
+            WITHDRAWAL_TRANSACTIONS_IDS_KEY,
+        ]
+        .iter()
+        .all(|key| {
+            drive
+                .grove
+                .get(&get_withdrawal_root_path(), key, None)
+                .unwrap()
+                .is_ok()
+        })
+    }
+
+    #[test]
+    fn test_pending_transfers_tree_is_only_created_from_protocol_version_2() {
+        let first_platform_version = PlatformVersion::first();
+        let drive = setup_drive(None);
+        drive
+            .create_initial_state_structure(None, first_platform_version)
+            .expect("expected to create initial state structure");
+        assert!(!has_pending_transfers_tree(&drive));
+
+        let latest_platform_version = PlatformVersion::latest();
+        let drive = setup_drive(None);
+        drive
+            .create_initial_state_structure(None, latest_platform_version)
+            .expect("expected to create initial state structure");
+        assert!(has_pending_transfers_tree(&drive));
+    }
+
+    #[test]
+    fn test_withdrawal_transaction_ids_trees_are_only_created_from_protocol_version_2() {
+        let first_platform_version = PlatformVersion::first();
+        let drive = setup_drive(None);
+        drive
+            .create_initial_state_structure(None, first_platform_version)
+            .expect("expected to create initial state structure");
+        assert!(!has_withdrawal_transaction_ids_trees(&drive));
+
+        let latest_platform_version = PlatformVersion::latest();
+        let drive = setup_drive(None);
+        drive
+            .create_initial_state_structure(None, latest_platform_version)
+            .expect("expected to create initial state structure");
+        assert!(has_withdrawal_transaction_ids_trees(&drive));
+    }
+}
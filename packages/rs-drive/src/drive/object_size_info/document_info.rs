@@ -10,7 +10,7 @@ use crate::error::fee::FeeError;
 use crate::error::Error;
 use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
-use dpp::data_contract::document_type::{DocumentTypeRef, IndexLevel};
+use dpp::data_contract::document_type::{DocumentPropertyType, DocumentTypeRef, IndexLevel};
 use dpp::document::document_methods::DocumentMethodsV0;
 use dpp::document::{Document, DocumentV0Getters};
 use dpp::version::PlatformVersion;
@@ -57,6 +57,16 @@ pub trait DocumentInfoV0Methods {
         size_info_with_base_event: Option<(&IndexLevel, [u8; 32])>,
         platform_version: &PlatformVersion,
     ) -> Result<Option<DriveKeyInfo>, Error>;
+    /// Gets the raw index keys for the given document type, one for every distinct item when the
+    /// key path is an array and a single key otherwise, empty keys stand for missing values
+    fn get_raw_index_keys_for_document_type(
+        &self,
+        key_path: &str,
+        document_type: DocumentTypeRef,
+        owner_id: Option<[u8; 32]>,
+        size_info_with_base_event: Option<(&IndexLevel, [u8; 32])>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveKeyInfo>, Error>;
     /// Gets the borrowed document
     fn get_borrowed_document_and_storage_flags(&self)
         -> Option<(&Document, Option<&StorageFlags>)>;
@@ -120,7 +130,7 @@ impl<'a> DocumentInfoV0Methods for DocumentInfo<'a> {
                         "incorrect key path for document type for estimated sizes",
                     ))
                 })?;
-                let estimated_size = property.property_type.middle_byte_size_ceil().ok_or({
+                let estimated_size = estimated_index_key_size(&property.property_type).ok_or({
                     Error::Drive(DriveError::CorruptedCodeExecution(
                         "document type must have a max size",
                     ))
@@ -192,7 +202,7 @@ impl<'a> DocumentInfoV0Methods for DocumentInfo<'a> {
                             })?;
 
                         let estimated_middle_size =
-                            property.property_type.middle_byte_size_ceil().ok_or({
+                            estimated_index_key_size(&property.property_type).ok_or({
                                 Error::Drive(DriveError::CorruptedCodeExecution(
                                     "document type must have a max size",
                                 ))
@@ -215,6 +225,50 @@ impl<'a> DocumentInfoV0Methods for DocumentInfo<'a> {
         }
     }
 
+    /// Gets the raw index keys for the given document type, one for every distinct item when the
+    /// key path is an array and a single key otherwise, empty keys stand for missing values
+    fn get_raw_index_keys_for_document_type(
+        &self,
+        key_path: &str,
+        document_type: DocumentTypeRef,
+        owner_id: Option<[u8; 32]>,
+        size_info_with_base_event: Option<(&IndexLevel, [u8; 32])>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveKeyInfo>, Error> {
+        let is_array = document_type
+            .flattened_properties()
+            .get(key_path)
+            .is_some_and(|property| {
+                matches!(property.property_type, DocumentPropertyType::Array(_))
+            });
+        match self.get_borrowed_document() {
+            Some(document) if is_array => {
+                let elements = document
+                    .get_raw_array_elements_for_document_type(
+                        key_path,
+                        document_type,
+                        platform_version,
+                    )?
+                    .unwrap_or_default();
+                if elements.is_empty() {
+                    Ok(vec![Key(vec![])])
+                } else {
+                    Ok(elements.into_iter().map(Key).collect())
+                }
+            }
+            // estimations are made for a single item
+            _ => Ok(vec![self
+                .get_raw_for_document_type(
+                    key_path,
+                    document_type,
+                    owner_id,
+                    size_info_with_base_event,
+                    platform_version,
+                )?
+                .unwrap_or_default()]),
+        }
+    }
+
     /// Gets the borrowed document
     fn get_borrowed_document_and_storage_flags(
         &self,
@@ -260,3 +314,13 @@ impl<'a> DocumentInfoV0Methods for DocumentInfo<'a> {
         }
     }
 }
+
+/// The estimated size of an index key for a property, the items of arrays being indexed one by one
+fn estimated_index_key_size(property_type: &DocumentPropertyType) -> Option<u16> {
+    match property_type {
+        DocumentPropertyType::Array(item_type) => item_type
+            .as_document_property_type()
+            .middle_byte_size_ceil(),
+        property_type => property_type.middle_byte_size_ceil(),
+    }
+}
@@ -0,0 +1,52 @@
+mod v0;
+
+use crate::drive::verify::RootHash;
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+use crate::query::{AggregateFunction, DriveQuery};
+
+use dpp::version::PlatformVersion;
+
+impl<'a> DriveQuery<'a> {
+    /// Verifies a proof for an aggregate of the documents matched by the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The aggregate function the proof was made for.
+    /// * `proof` - A byte slice representing the proof to be verified.
+    /// * `platform_version` - The platform version against which to verify the proof.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing:
+    /// * A tuple with the root hash and the count or sum if the proof is valid.
+    /// * An `Error` variant, in case the proof verification fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` variant if:
+    /// 1. The proof verification fails.
+    /// 2. No index declares the requested sum for the where clauses of the query.
+    pub fn verify_aggregate_proof(
+        &self,
+        function: &AggregateFunction,
+        proof: &[u8],
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, i64), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .document
+            .verify_aggregate_proof
+        {
+            0 => self.verify_aggregate_proof_v0(function, proof, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_aggregate_proof".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
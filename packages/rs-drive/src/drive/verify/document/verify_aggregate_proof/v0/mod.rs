@@ -0,0 +1,46 @@
+use crate::drive::verify::RootHash;
+
+use crate::error::Error;
+use crate::query::{AggregateFunction, DriveQuery};
+use grovedb::GroveDb;
+
+use dpp::version::PlatformVersion;
+
+impl<'a> DriveQuery<'a> {
+    /// Verifies a proof for an aggregate of the documents matched by the query.
+    ///
+    /// The query must not have a limit, see `validate_aggregate_query`.
+    ///
+    /// A count is verified by counting the proved documents, a count above the maximum count of
+    /// the platform version is rejected. A sum is verified by adding up the proved sum trees of
+    /// the index declaring the summed property.
+    pub(super) fn verify_aggregate_proof_v0(
+        &self,
+        function: &AggregateFunction,
+        proof: &[u8],
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, i64), Error> {
+        self.validate_aggregate_query()?;
+        match function {
+            AggregateFunction::Count => {
+                let (root_hash, documents) = self
+                    .count_query(platform_version)
+                    .verify_proof_keep_serialized(proof, platform_version)?;
+                Ok((
+                    root_hash,
+                    Self::checked_count(documents.len(), platform_version)?,
+                ))
+            }
+            AggregateFunction::Sum(sum_property) => {
+                let path_query = self.construct_sum_path_query(sum_property, platform_version)?;
+                let (root_hash, proved_key_values) = GroveDb::verify_query(proof, &path_query)?;
+                let sum = Self::total_of_sum_trees(
+                    proved_key_values
+                        .into_iter()
+                        .filter_map(|(_, _, element)| element),
+                )?;
+                Ok((root_hash, sum))
+            }
+        }
+    }
+}
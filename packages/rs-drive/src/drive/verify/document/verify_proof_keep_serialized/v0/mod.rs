@@ -18,6 +18,10 @@ impl<'a> DriveQuery<'a> {
     /// * On success, returns a tuple containing the root hash of the GroveDB tree and a vector of serialized documents.
     /// * On failure, returns an Error.
     ///
+    /// Queries with or clauses are verified branch by branch, see
+    /// `verify_disjunctive_proof_keep_serialized`, and queries with range clauses on several
+    /// fields page by page, see `verify_residual_proof_keep_serialized`.
+    ///
     /// # Errors
     /// This function will return an Error if:
     /// * The start at document is not present in proof and it is expected to be.
@@ -28,6 +32,12 @@ impl<'a> DriveQuery<'a> {
         proof: &[u8],
         platform_version: &PlatformVersion,
     ) -> Result<(RootHash, Vec<Vec<u8>>), Error> {
+        if self.is_disjunctive() {
+            return self.verify_disjunctive_proof_keep_serialized(proof, platform_version);
+        }
+        if self.has_residual_range_clauses() {
+            return self.verify_residual_proof_keep_serialized(proof, platform_version);
+        }
         let path_query = if let Some(start_at) = &self.start_at {
             let (_, start_document) =
                 self.verify_start_at_document_in_proof(proof, true, *start_at, platform_version)?;
@@ -1,3 +1,4 @@
+mod verify_full_identities_by_identity_ids;
 mod verify_full_identities_by_public_key_hashes;
 mod verify_full_identity_by_identity_id;
 mod verify_full_identity_by_public_key_hash;
@@ -9,4 +10,9 @@ mod verify_identity_id_by_public_key_hash;
 mod verify_identity_ids_by_public_key_hashes;
 mod verify_identity_keys_by_identity_id;
 mod verify_identity_nonce;
+mod verify_identity_recovery_delay;
+mod verify_identity_recovery_proposal;
 mod verify_identity_revision_for_identity_id;
+mod verify_pending_transfer;
+mod verify_pending_transfers_for_identity_id;
+mod verify_withdrawals_for_identity_id;
@@ -0,0 +1,68 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+pub use dpp::prelude::Identity;
+
+use dpp::version::PlatformVersion;
+
+use std::iter::FromIterator;
+
+impl Drive {
+    /// Verifies the full identities of multiple users by their identity IDs.
+    ///
+    /// This function takes a byte slice representing the serialized proof and a list of identity IDs.
+    /// It verifies the full identities and returns a collection of the identity ID and associated identity for each user.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the users.
+    /// - `identity_ids`: A reference to a slice of 32-byte arrays, each representing an identity ID.
+    /// - `platform_version`: The platform version against which to verify the identities.
+    ///
+    /// # Generic Parameters
+    ///
+    /// - `T`: The type of the collection to hold the results.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and `T`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `Error` variant if:
+    /// - The proof of authentication is not valid.
+    /// - Any of the identities is not proved against the same root hash.
+    /// - An unknown or unsupported platform version is provided.
+    ///
+    pub fn verify_full_identities_by_identity_ids<T: FromIterator<([u8; 32], Option<Identity>)>>(
+        proof: &[u8],
+        identity_ids: &[[u8; 32]],
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .identity
+            .verify_full_identities_by_identity_ids
+        {
+            0 => Self::verify_full_identities_by_identity_ids_v0(
+                proof,
+                identity_ids,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_full_identities_by_identity_ids".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+pub use dpp::prelude::Identity;
+
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the full identities of multiple users by their identity IDs.
+    ///
+    /// This function is a generalization of `verify_full_identity_by_identity_id`,
+    /// which works with a slice of identity IDs instead of a single ID.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the users.
+    /// - `identity_ids`: A reference to a slice of 32-byte arrays, each representing an identity ID.
+    ///
+    /// # Generic Parameters
+    ///
+    /// - `T`: The type of the collection to hold the results, which must be constructible
+    ///    from an iterator of tuples of a 32-byte array and an optional `Identity`.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and `T`.
+    /// The `RootHash` represents the root hash of GroveDB, and `T` represents
+    /// the collection of the identity ID and associated identity (if it exists) for each user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    /// - No identity IDs are given.
+    /// - Any of the identities is not proved against the same root hash.
+    ///
+    pub(super) fn verify_full_identities_by_identity_ids_v0<
+        T: FromIterator<([u8; 32], Option<Identity>)>,
+    >(
+        proof: &[u8],
+        identity_ids: &[[u8; 32]],
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        let mut root_hash: Option<RootHash> = None;
+
+        let identities = identity_ids
+            .iter()
+            .map(|identity_id| {
+                let (identity_root_hash, maybe_identity) =
+                    Self::verify_full_identity_by_identity_id(
+                        proof,
+                        true,
+                        *identity_id,
+                        platform_version,
+                    )?;
+
+                if *root_hash.get_or_insert(identity_root_hash) != identity_root_hash {
+                    return Err(Error::Proof(ProofError::CorruptedProof(
+                        "identities are proved against different root hashes".to_string(),
+                    )));
+                }
+
+                Ok((*identity_id, maybe_identity))
+            })
+            .collect::<Result<T, Error>>()?;
+
+        let root_hash = root_hash.ok_or(Error::Proof(ProofError::IncorrectProof(
+            "no identity ids were given to verify the proof".to_string(),
+        )))?;
+
+        Ok((root_hash, identities))
+    }
+}
@@ -0,0 +1,60 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use dpp::identity::TimestampMillis;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the recovery delay chosen by an identity.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the recovery delay.
+    /// - `identity_id`: A 32-byte array representing the id of the identity.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    /// - `platform_version`: The platform version against which to verify the recovery delay.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<TimestampMillis>`. The `RootHash` represents the root hash of GroveDB, and the
+    /// `Option<TimestampMillis>` is `None` if the identity never set a recovery delay.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - The proved element is not an 8 bytes item.
+    ///
+    pub fn verify_identity_recovery_delay(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<TimestampMillis>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .identity
+            .verify_identity_recovery_delay
+        {
+            0 => {
+                Self::verify_identity_recovery_delay_v0(proof, identity_id, verify_subset_of_proof)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_identity_recovery_delay".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use crate::drive::identity::{identity_path_vec, IdentityRootStructure};
+use dpp::identity::TimestampMillis;
+use grovedb::GroveDb;
+
+impl Drive {
+    /// Verifies the recovery delay chosen by an identity.
+    ///
+    /// `verify_subset_of_proof` is used to indicate if we want to verify a subset of a bigger proof.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the recovery delay.
+    /// - `identity_id`: A 32-byte array representing the id of the identity.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<TimestampMillis>`. The `RootHash` represents the root hash of GroveDB, and the
+    /// `Option<TimestampMillis>` is `None` if the identity never set a recovery delay.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - The proved element is not an 8 bytes item.
+    ///
+    pub(crate) fn verify_identity_recovery_delay_v0(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Option<TimestampMillis>), Error> {
+        let mut path_query = Self::identity_recovery_delay_query(&identity_id);
+        path_query.query.limit = Some(1);
+        let (root_hash, mut proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query_with_absence_proof(proof, &path_query)?
+        } else {
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = proved_key_values.remove(0);
+            if path != identity_path_vec(identity_id.as_slice()) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path for the recovery delay"
+                        .to_string(),
+                )));
+            }
+            if key != vec![IdentityRootStructure::IdentityTreeRecoveryDelay as u8] {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key for the recovery delay"
+                        .to_string(),
+                )));
+            }
+
+            let recovery_delay = maybe_element
+                .map(|element| {
+                    let bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+
+                    Ok::<TimestampMillis, Error>(TimestampMillis::from_be_bytes(
+                        bytes.try_into().map_err(|_| {
+                            Error::Proof(ProofError::IncorrectValueSize(
+                                "recovery delay should be 8 bytes",
+                            ))
+                        })?,
+                    ))
+                })
+                .transpose()?;
+            Ok((root_hash, recovery_delay))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one recovery delay",
+            )))
+        }
+    }
+}
@@ -0,0 +1,62 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use dpp::identity::recovery_proposal::IdentityRecoveryProposal;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the recovery proposal of an identity.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the recovery proposal.
+    /// - `identity_id`: A 32-byte array representing the id of the identity.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    /// - `platform_version`: The platform version against which to verify the recovery proposal.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<IdentityRecoveryProposal>`. The `RootHash` represents the root hash of GroveDB, and the
+    /// `Option<IdentityRecoveryProposal>` is `None` if no recovery of the identity is pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - The proved element can not be deserialized into a recovery proposal.
+    ///
+    pub fn verify_identity_recovery_proposal(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<IdentityRecoveryProposal>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .identity
+            .verify_identity_recovery_proposal
+        {
+            0 => Self::verify_identity_recovery_proposal_v0(
+                proof,
+                identity_id,
+                verify_subset_of_proof,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_identity_recovery_proposal".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use crate::drive::identity::{identity_path_vec, IdentityRootStructure};
+use dpp::identity::recovery_proposal::IdentityRecoveryProposal;
+use dpp::serialization::PlatformDeserializable;
+use grovedb::GroveDb;
+
+impl Drive {
+    /// Verifies the recovery proposal of an identity.
+    ///
+    /// `verify_subset_of_proof` is used to indicate if we want to verify a subset of a bigger proof.
+    /// For example, if the proof can prove the keys of the identity and its recovery proposal,
+    /// but here we are only interested in verifying the recovery proposal.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the recovery proposal.
+    /// - `identity_id`: A 32-byte array representing the id of the identity.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<IdentityRecoveryProposal>`. The `RootHash` represents the root hash of GroveDB, and the
+    /// `Option<IdentityRecoveryProposal>` is `None` if no recovery of the identity is pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - The proved element can not be deserialized into a recovery proposal.
+    ///
+    pub(crate) fn verify_identity_recovery_proposal_v0(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Option<IdentityRecoveryProposal>), Error> {
+        let mut path_query = Self::identity_recovery_proposal_query(&identity_id);
+        path_query.query.limit = Some(1);
+        let (root_hash, mut proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query_with_absence_proof(proof, &path_query)?
+        } else {
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = proved_key_values.remove(0);
+            if path != identity_path_vec(identity_id.as_slice()) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path for the recovery proposal"
+                        .to_string(),
+                )));
+            }
+            if key != vec![IdentityRootStructure::IdentityTreeRecoveryProposal as u8] {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key for the recovery proposal"
+                        .to_string(),
+                )));
+            }
+
+            let recovery_proposal = maybe_element
+                .map(|element| {
+                    let bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+
+                    IdentityRecoveryProposal::deserialize_from_bytes(&bytes)
+                        .map_err(Error::Protocol)
+                })
+                .transpose()?;
+            Ok((root_hash, recovery_proposal))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one recovery proposal",
+            )))
+        }
+    }
+}
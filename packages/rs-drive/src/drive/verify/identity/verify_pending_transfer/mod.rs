@@ -0,0 +1,58 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use dpp::identity::pending_transfer::PendingTransfer;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies a pending transfer by its id.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the pending transfer.
+    /// - `transfer_id`: A 32-byte array representing the id of the pending transfer.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    /// - `platform_version`: The platform version against which to verify the pending transfer.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<PendingTransfer>`. The `RootHash` represents the root hash of GroveDB, and the
+    /// `Option<PendingTransfer>` is `None` if the transfer was released, cancelled or never scheduled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - The proved element can not be deserialized into a pending transfer.
+    ///
+    pub fn verify_pending_transfer(
+        proof: &[u8],
+        transfer_id: [u8; 32],
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<PendingTransfer>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .identity
+            .verify_pending_transfer
+        {
+            0 => Self::verify_pending_transfer_v0(proof, transfer_id, verify_subset_of_proof),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_pending_transfer".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use crate::drive::identity::pending_transfers::paths::{
+    get_pending_transfers_path_vec, PENDING_TRANSFERS_BY_ID_KEY,
+};
+use dpp::identity::pending_transfer::PendingTransfer;
+use dpp::serialization::PlatformDeserializable;
+use grovedb::GroveDb;
+
+impl Drive {
+    /// Verifies a pending transfer by its id.
+    ///
+    /// `verify_subset_of_proof` is used to indicate if we want to verify a subset of a bigger proof.
+    /// For example, if the proof can prove the balance of the sender and the pending transfer,
+    /// but here we are only interested in verifying the pending transfer.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the pending transfer.
+    /// - `transfer_id`: A 32-byte array representing the id of the pending transfer.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<PendingTransfer>`. The `RootHash` represents the root hash of GroveDB, and the
+    /// `Option<PendingTransfer>` is `None` if the transfer is not pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - The proved element can not be deserialized into a pending transfer.
+    ///
+    pub(crate) fn verify_pending_transfer_v0(
+        proof: &[u8],
+        transfer_id: [u8; 32],
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Option<PendingTransfer>), Error> {
+        let mut path_query = Self::pending_transfer_query(transfer_id);
+        path_query.query.limit = Some(1);
+        let (root_hash, mut proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query_with_absence_proof(proof, &path_query)?
+        } else {
+            GroveDb::verify_query_with_absence_proof(proof, &path_query)?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = proved_key_values.remove(0);
+            if path != get_pending_transfers_path_vec(PENDING_TRANSFERS_BY_ID_KEY) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path for the pending transfer"
+                        .to_string(),
+                )));
+            }
+            if key != transfer_id.to_vec() {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key for the pending transfer"
+                        .to_string(),
+                )));
+            }
+
+            let pending_transfer = maybe_element
+                .map(|element| {
+                    let bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+
+                    PendingTransfer::deserialize_from_bytes(&bytes).map_err(Error::Protocol)
+                })
+                .transpose()?;
+            Ok((root_hash, pending_transfer))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one pending transfer",
+            )))
+        }
+    }
+}
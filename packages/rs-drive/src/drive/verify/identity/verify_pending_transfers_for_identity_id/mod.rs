@@ -0,0 +1,68 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use dpp::identity::pending_transfer::PendingTransfer;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the pending transfers to an identity, or sent by the identity if `outgoing` is true.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the pending transfers.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the recipient or sender.
+    /// - `outgoing`: A boolean indicating whether the pending transfers were sent by the identity.
+    /// - `limit`: The maximum number of pending transfers that were requested.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    /// - `platform_version`: The platform version against which to verify the pending transfers.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// the pending transfers ordered by their id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - A proved element can not be deserialized into a pending transfer.
+    /// - A proved pending transfer was not sent or received by the identity.
+    ///
+    pub fn verify_pending_transfers_for_identity_id(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        outgoing: bool,
+        limit: Option<u16>,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<PendingTransfer>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .identity
+            .verify_pending_transfers_for_identity_id
+        {
+            0 => Self::verify_pending_transfers_for_identity_id_v0(
+                proof,
+                identity_id,
+                outgoing,
+                limit,
+                verify_subset_of_proof,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_pending_transfers_for_identity_id".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
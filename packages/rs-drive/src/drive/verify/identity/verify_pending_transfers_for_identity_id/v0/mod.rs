@@ -0,0 +1,79 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use dpp::identity::pending_transfer::PendingTransfer;
+use dpp::serialization::PlatformDeserializable;
+use grovedb::GroveDb;
+
+impl Drive {
+    /// Verifies the pending transfers to an identity, or sent by the identity if `outgoing` is true.
+    ///
+    /// `verify_subset_of_proof` is used to indicate if we want to verify a subset of a bigger proof.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the pending transfers.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the recipient or sender.
+    /// - `outgoing`: A boolean indicating whether the pending transfers were sent by the identity.
+    /// - `limit`: The maximum number of pending transfers that were requested.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// the pending transfers ordered by their id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - A proved element can not be deserialized into a pending transfer.
+    /// - A proved pending transfer was not sent or received by the identity.
+    ///
+    pub(crate) fn verify_pending_transfers_for_identity_id_v0(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        outgoing: bool,
+        limit: Option<u16>,
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Vec<PendingTransfer>), Error> {
+        let path_query = Self::pending_transfers_for_identity_query(identity_id, outgoing, limit);
+        let (root_hash, proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query(proof, &path_query)?
+        } else {
+            GroveDb::verify_query(proof, &path_query)?
+        };
+
+        let pending_transfers = proved_key_values
+            .into_iter()
+            .filter_map(|(_, _, maybe_element)| maybe_element)
+            .map(|element| {
+                let bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+
+                let pending_transfer =
+                    PendingTransfer::deserialize_from_bytes(&bytes).map_err(Error::Protocol)?;
+
+                let transfer_identity_id = if outgoing {
+                    pending_transfer.sender_id
+                } else {
+                    pending_transfer.recipient_id
+                };
+
+                if transfer_identity_id.to_buffer() != identity_id {
+                    return Err(Error::Proof(ProofError::CorruptedProof(
+                        "we got back a pending transfer of another identity".to_string(),
+                    )));
+                }
+
+                Ok(pending_transfer)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok((root_hash, pending_transfers))
+    }
+}
@@ -0,0 +1,71 @@
+mod v0;
+mod v1;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use dpp::version::PlatformVersion;
+use dpp::withdrawal::identity_withdrawal::IdentityWithdrawal;
+
+impl Drive {
+    /// Verifies the withdrawals requested by an identity.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the withdrawal documents, followed from
+    ///   protocol version 2 by the proof of the Core transaction ids of their transactions.
+    /// - `identity_id`: A 32-byte array representing the identity ID that requested the withdrawals.
+    /// - `limit`: The maximum number of withdrawals that were requested.
+    /// - `platform_version`: The platform version against which to verify the withdrawals.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// the withdrawals, most recently updated first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - A proved document can not be read as a withdrawal.
+    /// - A proved withdrawal was not requested by the identity.
+    ///
+    pub fn verify_withdrawals_for_identity_id(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        limit: u16,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<IdentityWithdrawal>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .identity
+            .verify_withdrawals_for_identity_id
+        {
+            0 => Self::verify_withdrawals_for_identity_id_v0(
+                proof,
+                identity_id,
+                limit,
+                platform_version,
+            ),
+            1 => Self::verify_withdrawals_for_identity_id_v1(
+                proof,
+                identity_id,
+                limit,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_withdrawals_for_identity_id".to_string(),
+                known_versions: vec![0, 1],
+                received: version,
+            })),
+        }
+    }
+}
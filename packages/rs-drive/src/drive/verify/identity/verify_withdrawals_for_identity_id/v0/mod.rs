@@ -0,0 +1,65 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use dpp::document::DocumentV0Getters;
+use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+use dpp::version::PlatformVersion;
+use dpp::withdrawal::identity_withdrawal::IdentityWithdrawal;
+
+impl Drive {
+    /// Verifies the withdrawals requested by an identity.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the withdrawal documents.
+    /// - `identity_id`: A 32-byte array representing the identity ID that requested the withdrawals.
+    /// - `limit`: The maximum number of withdrawals that were requested.
+    /// - `platform_version`: The platform version against which to verify the withdrawals.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// the withdrawals, most recently updated first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - A proved document can not be read as a withdrawal.
+    /// - A proved withdrawal was not requested by the identity.
+    ///
+    pub(crate) fn verify_withdrawals_for_identity_id_v0(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        limit: u16,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<IdentityWithdrawal>), Error> {
+        let withdrawals_contract =
+            load_system_data_contract(SystemDataContract::Withdrawals, platform_version)?;
+
+        let query =
+            Self::withdrawals_for_identity_query(&withdrawals_contract, identity_id, limit)?;
+
+        let (root_hash, documents) = query.verify_proof(proof, platform_version)?;
+
+        let withdrawals = documents
+            .iter()
+            .map(|document| {
+                if document.owner_id().to_buffer() != identity_id {
+                    return Err(Error::Proof(ProofError::CorruptedProof(
+                        "we got back a withdrawal of another identity".to_string(),
+                    )));
+                }
+
+                IdentityWithdrawal::try_from(document).map_err(Error::Protocol)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok((root_hash, withdrawals))
+    }
+}
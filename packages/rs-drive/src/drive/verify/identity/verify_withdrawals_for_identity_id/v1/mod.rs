@@ -0,0 +1,148 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use crate::drive::identity::withdrawals::WithdrawalTransactionIndex;
+use crate::query::DriveQuery;
+use dpp::dashcore::hashes::Hash;
+use dpp::dashcore::Txid;
+use dpp::document::DocumentV0Getters;
+use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+use dpp::version::PlatformVersion;
+use dpp::withdrawal::identity_withdrawal::IdentityWithdrawal;
+use grovedb::GroveDb;
+use std::collections::{BTreeMap, BTreeSet};
+
+impl Drive {
+    /// Verifies the withdrawals requested by an identity and the Core transaction ids of their
+    /// transactions.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the withdrawal documents followed by
+    ///   the proof of the transaction ids of the transactions they are part of.
+    /// - `identity_id`: A 32-byte array representing the identity ID that requested the withdrawals.
+    /// - `limit`: The maximum number of withdrawals that were requested.
+    /// - `platform_version`: The platform version against which to verify the withdrawals.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// the withdrawals, most recently updated first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    /// - A proved document can not be read as a withdrawal.
+    /// - A proved withdrawal was not requested by the identity.
+    /// - The proofs of the documents and of the transaction ids have different root hashes.
+    ///
+    pub(crate) fn verify_withdrawals_for_identity_id_v1(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        limit: u16,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<IdentityWithdrawal>), Error> {
+        let proofs = DriveQuery::decode_proof_sequence(proof)?;
+
+        let [documents_proof, transaction_ids_proof] = proofs[..] else {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "withdrawals proof must prove documents and transaction ids".to_string(),
+            )));
+        };
+
+        let withdrawals_contract =
+            load_system_data_contract(SystemDataContract::Withdrawals, platform_version)?;
+
+        let query =
+            Self::withdrawals_for_identity_query(&withdrawals_contract, identity_id, limit)?;
+
+        let (root_hash, documents) = query.verify_proof(documents_proof, platform_version)?;
+
+        let mut withdrawals = documents
+            .iter()
+            .map(|document| {
+                if document.owner_id().to_buffer() != identity_id {
+                    return Err(Error::Proof(ProofError::CorruptedProof(
+                        "we got back a withdrawal of another identity".to_string(),
+                    )));
+                }
+
+                IdentityWithdrawal::try_from(document).map_err(Error::Protocol)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let transaction_indexes = withdrawals
+            .iter()
+            .filter_map(|withdrawal| withdrawal.transaction_index)
+            .collect::<BTreeSet<WithdrawalTransactionIndex>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        // Without pooled withdrawals there are no transaction ids to prove
+        if transaction_indexes.is_empty() {
+            if !transaction_ids_proof.is_empty() {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "transaction ids are proved without pooled withdrawals".to_string(),
+                )));
+            }
+
+            return Ok((root_hash, withdrawals));
+        }
+
+        let path_query = Self::withdrawal_transaction_ids_query(&transaction_indexes);
+
+        let (transaction_ids_root_hash, proved_key_values) =
+            GroveDb::verify_query_with_absence_proof(transaction_ids_proof, &path_query)?;
+
+        if transaction_ids_root_hash != root_hash {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "withdrawal documents and transaction ids proofs have different root hashes"
+                    .to_string(),
+            )));
+        }
+
+        if proved_key_values.len() != transaction_indexes.len() {
+            return Err(Error::Proof(ProofError::WrongElementCount {
+                expected: transaction_indexes.len(),
+                got: proved_key_values.len(),
+            }));
+        }
+
+        let transaction_ids = proved_key_values
+            .into_iter()
+            .filter_map(|(_, key, maybe_element)| Some((key, maybe_element?)))
+            .map(|(key, element)| {
+                let index = WithdrawalTransactionIndex::from_be_bytes(
+                    key.try_into()
+                        .map_err(|_| Error::Proof(ProofError::IncorrectValueSize("key size")))?,
+                );
+
+                let transaction_id: [u8; 32] = element
+                    .into_item_bytes()
+                    .map_err(Error::GroveDB)?
+                    .try_into()
+                    .map_err(|_| {
+                        Error::Proof(ProofError::IncorrectValueSize(
+                            "transaction id size is incorrect",
+                        ))
+                    })?;
+
+                Ok((index, Txid::from_byte_array(transaction_id)))
+            })
+            .collect::<Result<BTreeMap<_, _>, Error>>()?;
+
+        for withdrawal in withdrawals.iter_mut() {
+            withdrawal.transaction_id = withdrawal
+                .transaction_index
+                .and_then(|index| transaction_ids.get(&index).copied());
+        }
+
+        Ok((root_hash, withdrawals))
+    }
+}
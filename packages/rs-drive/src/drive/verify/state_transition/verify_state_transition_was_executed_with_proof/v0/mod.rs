@@ -3,8 +3,11 @@ use std::sync::Arc;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::data_contract::serialized_version::DataContractInSerializationFormat;
-use dpp::document::{Document, DocumentV0Getters};
+use dpp::document::{property_names, Document, DocumentV0Getters};
+use dpp::fee::Credits;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
 use dpp::identity::PartialIdentity;
+use dpp::identity::pending_transfer::PendingTransfer;
 use dpp::prelude::{DataContract, Identifier};
 use dpp::state_transition::data_contract_create_transition::accessors::DataContractCreateTransitionAccessorsV0;
 use dpp::state_transition::data_contract_update_transition::accessors::DataContractUpdateTransitionAccessorsV0;
@@ -14,7 +17,11 @@ use dpp::state_transition::documents_batch_transition::document_create_transitio
 use dpp::state_transition::documents_batch_transition::document_transition::{DocumentTransition, DocumentTransitionV0Methods};
 use dpp::state_transition::identity_create_transition::accessors::IdentityCreateTransitionAccessorsV0;
 use dpp::state_transition::identity_credit_transfer_transition::accessors::IdentityCreditTransferTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+use dpp::state_transition::identity_credit_transfer_transition::v2::PendingTransferOperation;
 use dpp::state_transition::identity_credit_withdrawal_transition::accessors::IdentityCreditWithdrawalTransitionAccessorsV0;
+use dpp::state_transition::identity_recover_transition::accessors::IdentityRecoverTransitionAccessorsV0;
+use dpp::state_transition::identity_recover_transition::v0::IdentityRecoverOperation;
 use dpp::state_transition::identity_topup_transition::accessors::IdentityTopUpTransitionAccessorsV0;
 use dpp::state_transition::identity_update_transition::accessors::IdentityUpdateTransitionAccessorsV0;
 use dpp::state_transition::{StateTransition, StateTransitionLike};
@@ -22,8 +29,11 @@ use dpp::state_transition::documents_batch_transition::document_create_transitio
 use dpp::state_transition::documents_batch_transition::document_delete_transition::v0::v0_methods::DocumentDeleteTransitionV0Methods;
 use dpp::state_transition::documents_batch_transition::document_replace_transition::DocumentFromReplaceTransition;
 use dpp::state_transition::documents_batch_transition::document_replace_transition::v0::v0_methods::DocumentReplaceTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transfer_transition::v0::v0_methods::DocumentTransferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_update_price_transition::v0::v0_methods::DocumentUpdatePriceTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_purchase_transition::v0::v0_methods::DocumentPurchaseTransitionV0Methods;
 use dpp::state_transition::proof_result::StateTransitionProofResult;
-use dpp::state_transition::proof_result::StateTransitionProofResult::{VerifiedBalanceTransfer, VerifiedDataContract, VerifiedDocuments, VerifiedIdentity, VerifiedPartialIdentity};
+use dpp::state_transition::proof_result::StateTransitionProofResult::{VerifiedBalanceTransfer, VerifiedBalanceTransferToIdentities, VerifiedDataContract, VerifiedDocuments, VerifiedIdentity, VerifiedIdentityRecoveryDelay, VerifiedIdentityRecoveryProposal, VerifiedPartialIdentity, VerifiedPendingTransfer};
 use platform_version::TryIntoPlatformVersioned;
 use platform_version::version::PlatformVersion;
 use crate::drive::Drive;
@@ -153,6 +163,49 @@ impl Drive {
                             VerifiedDocuments(BTreeMap::from([(document.id(), Some(document))])),
                         ))
                     }
+                    DocumentTransition::Transfer(transfer_transition) => {
+                        let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (transfer)", transfer_transition.base().id()))))?;
+
+                        if document.owner_id() != transfer_transition.recipient_owner_id()
+                            || document.revision() != Some(transfer_transition.revision())
+                        {
+                            return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution did not contain expected document owner and revision after transfer with id {}", transfer_transition.base().id()))));
+                        }
+                        Ok((
+                            root_hash,
+                            VerifiedDocuments(BTreeMap::from([(document.id(), Some(document))])),
+                        ))
+                    }
+                    DocumentTransition::UpdatePrice(update_price_transition) => {
+                        let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (update price)", update_price_transition.base().id()))))?;
+
+                        let price = document
+                            .properties()
+                            .get_optional_integer::<Credits>(property_names::PRICE)?;
+
+                        if price != Some(update_price_transition.price())
+                            || document.revision() != Some(update_price_transition.revision())
+                        {
+                            return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution did not contain expected document price and revision after price update with id {}", update_price_transition.base().id()))));
+                        }
+                        Ok((
+                            root_hash,
+                            VerifiedDocuments(BTreeMap::from([(document.id(), Some(document))])),
+                        ))
+                    }
+                    DocumentTransition::Purchase(purchase_transition) => {
+                        let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (purchase)", purchase_transition.base().id()))))?;
+
+                        if document.owner_id() != documents_batch_transition.owner_id()
+                            || document.revision() != Some(purchase_transition.revision())
+                        {
+                            return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution did not contain expected document owner and revision after purchase with id {}", purchase_transition.base().id()))));
+                        }
+                        Ok((
+                            root_hash,
+                            VerifiedDocuments(BTreeMap::from([(document.id(), Some(document))])),
+                        ))
+                    }
                     DocumentTransition::Delete(delete_transition) => {
                         if document.is_some() {
                             return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution contained document after delete with id {}", delete_transition.base().id()))));
@@ -242,7 +295,164 @@ impl Drive {
                 let identity = identity.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain update for identity {} expected to exist because of state transition (update)", identity_update_transition.identity_id()))))?;
                 Ok((root_hash, VerifiedPartialIdentity(identity)))
             }
+            StateTransition::IdentityRecover(identity_recover_transition) => {
+                let identity_id = identity_recover_transition.identity_id();
+                if identity_recover_transition.operation() == IdentityRecoverOperation::Execute {
+                    // we expect to get the keys of the identity, including its new master keys
+                    let (root_hash, identity) = Drive::verify_identity_keys_by_identity_id(
+                        proof,
+                        IdentityKeysRequest::new_all_keys_query(&identity_id.into_buffer(), None),
+                        true,
+                        true,
+                        false,
+                        platform_version,
+                    )?;
+                    let identity = identity.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain keys for identity {} expected to exist because of state transition (recover)", identity_id))))?;
+                    return Ok((root_hash, VerifiedPartialIdentity(identity)));
+                }
+
+                if let IdentityRecoverOperation::SetRecoveryDelay { recovery_delay_ms } =
+                    identity_recover_transition.operation()
+                {
+                    // we expect to get the recovery delay that was set
+                    let (root_hash, recovery_delay) = Drive::verify_identity_recovery_delay(
+                        proof,
+                        identity_id.into_buffer(),
+                        false,
+                        platform_version,
+                    )?;
+                    if recovery_delay != Some(recovery_delay_ms) {
+                        return Err(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain recovery delay {} ms of identity {} expected because of state transition (set recovery delay)", recovery_delay_ms, identity_id))));
+                    }
+                    return Ok((
+                        root_hash,
+                        VerifiedIdentityRecoveryDelay(identity_id, recovery_delay),
+                    ));
+                }
+
+                // we expect to get the recovery proposal, or its absence once vetoed
+                let (root_hash, recovery_proposal) = Drive::verify_identity_recovery_proposal(
+                    proof,
+                    identity_id.into_buffer(),
+                    false,
+                    platform_version,
+                )?;
+
+                match (identity_recover_transition.operation(), &recovery_proposal) {
+                    (IdentityRecoverOperation::Veto, Some(_)) => {
+                        return Err(Error::Proof(ProofError::IncorrectProof(format!("proof contained recovery proposal of identity {} expected to be removed because of state transition (veto recovery)", identity_id))));
+                    }
+                    (
+                        IdentityRecoverOperation::Propose | IdentityRecoverOperation::Approve,
+                        None,
+                    ) => {
+                        return Err(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain recovery proposal of identity {} expected to exist because of state transition (propose or approve recovery)", identity_id))));
+                    }
+                    _ => {}
+                }
+
+                Ok((
+                    root_hash,
+                    VerifiedIdentityRecoveryProposal(identity_id, recovery_proposal),
+                ))
+            }
             StateTransition::IdentityCreditTransfer(identity_credit_transfer) => {
+                if let IdentityCreditTransferTransition::V1(_) = identity_credit_transfer {
+                    // we expect to get the balances of the sender and of all recipients
+                    let mut identity_ids = vec![identity_credit_transfer.identity_id().to_buffer()];
+                    identity_ids.extend(
+                        identity_credit_transfer
+                            .recipients()
+                            .keys()
+                            .map(|recipient_id| recipient_id.to_buffer()),
+                    );
+
+                    let (root_hash, balances): (RootHash, BTreeMap<[u8; 32], Option<Credits>>) =
+                        Drive::verify_identity_balances_for_identity_ids(
+                            proof,
+                            true,
+                            &identity_ids,
+                            platform_version,
+                        )?;
+
+                    let mut partial_identities = identity_ids.into_iter().map(|identity_id| {
+                        let balance = balances.get(&identity_id).copied().flatten().ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain balance for identity {} expected to exist because of state transition (transfer to identities)", Identifier::new(identity_id)))))?;
+                        Ok(PartialIdentity {
+                            id: Identifier::new(identity_id),
+                            loaded_public_keys: Default::default(),
+                            balance: Some(balance),
+                            revision: None,
+
+                            not_found_public_keys: Default::default(),
+                        })
+                    }).collect::<Result<Vec<PartialIdentity>, Error>>()?;
+
+                    let sender = partial_identities.remove(0);
+
+                    return Ok((
+                        root_hash,
+                        VerifiedBalanceTransferToIdentities(sender, partial_identities),
+                    ));
+                }
+
+                if let IdentityCreditTransferTransition::V2(v2) = identity_credit_transfer {
+                    // we expect to get the balance of the sender and the scheduled transfer,
+                    // or its absence once cancelled
+                    let transfer_id = match &v2.operation {
+                        PendingTransferOperation::Schedule { .. } => {
+                            PendingTransfer::id_from_sender_and_nonce(v2.identity_id, v2.nonce)
+                        }
+                        PendingTransferOperation::Cancel { transfer_id } => *transfer_id,
+                    };
+
+                    let (root_hash_identity, balance_identity) =
+                        Drive::verify_identity_balance_for_identity_id(
+                            proof,
+                            v2.identity_id.into_buffer(),
+                            true,
+                            platform_version,
+                        )?;
+
+                    let (root_hash_transfer, pending_transfer) = Drive::verify_pending_transfer(
+                        proof,
+                        transfer_id.into_buffer(),
+                        true,
+                        platform_version,
+                    )?;
+
+                    if root_hash_identity != root_hash_transfer {
+                        return Err(Error::Proof(ProofError::CorruptedProof("proof is expected to have same root hash for all subsets (pending transfer)".to_string())));
+                    }
+
+                    let balance_identity = balance_identity.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain balance for identity sender {} expected to exist because of state transition (pending transfer)", v2.identity_id))))?;
+
+                    match (&v2.operation, &pending_transfer) {
+                        (PendingTransferOperation::Schedule { .. }, None) => {
+                            return Err(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain pending transfer {} expected to exist because of state transition (schedule transfer)", transfer_id))));
+                        }
+                        (PendingTransferOperation::Cancel { .. }, Some(_)) => {
+                            return Err(Error::Proof(ProofError::IncorrectProof(format!("proof contained pending transfer {} expected to be removed because of state transition (cancel transfer)", transfer_id))));
+                        }
+                        _ => {}
+                    }
+
+                    return Ok((
+                        root_hash_identity,
+                        VerifiedPendingTransfer(
+                            PartialIdentity {
+                                id: v2.identity_id,
+                                loaded_public_keys: Default::default(),
+                                balance: Some(balance_identity),
+                                revision: None,
+
+                                not_found_public_keys: Default::default(),
+                            },
+                            transfer_id,
+                            pending_transfer,
+                        ),
+                    ));
+                }
+
                 // we expect to get an identity that matches the state transition
                 let (root_hash_identity, balance_identity) =
                     Drive::verify_identity_balance_for_identity_id(
@@ -48,6 +48,15 @@ pub enum QuerySyntaxError {
     /// Invalid starts with clause error
     #[error("invalid STARTSWITH clause error: {0}")]
     InvalidStartsWithClause(&'static str),
+    /// Invalid contains word clause error
+    #[error("invalid CONTAINSWORD clause error: {0}")]
+    InvalidContainsWordClause(&'static str),
+    /// Invalid clause on an array property error
+    #[error("invalid clause on array property error: {0}")]
+    InvalidArrayClause(&'static str),
+    /// Invalid or clause error
+    #[error("invalid OR clause error: {0}")]
+    InvalidOrClause(&'static str),
 
     /// Invalid where clause order error
     #[error("invalid where clause order error: {0}")]
@@ -132,4 +141,16 @@ pub enum QuerySyntaxError {
     /// Invalid identity prove request error
     #[error("invalid identity prove request error: {0}")]
     InvalidIdentityProveRequest(&'static str),
+
+    /// No index declares the requested sum for the where clauses
+    #[error("no sum index for query error: {0}")]
+    NoSumIndexForQuery(&'static str),
+
+    /// Range clauses on several fields filter out too many documents of the index query
+    #[error("residual range filter pages exceeded error: {0}")]
+    ResidualRangeFilterPagesExceeded(&'static str),
+
+    /// A count aggregate query matches more documents than it can count
+    #[error("aggregate count limit exceeded error: {0}")]
+    AggregateCountLimitExceeded(String),
 }
@@ -0,0 +1,339 @@
+use crate::drive::document::{contract_document_type_path_vec, INDEX_SUM_TREE_KEY};
+#[cfg(feature = "full")]
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+#[cfg(feature = "full")]
+use crate::fee::op::LowLevelDriveOperation;
+use crate::query::{DriveQuery, WhereClause};
+#[cfg(feature = "full")]
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::Index;
+use dpp::version::PlatformVersion;
+use dpp::ProtocolError;
+#[cfg(feature = "full")]
+use grovedb::query_result_type::QueryResultType;
+#[cfg(feature = "full")]
+use grovedb::TransactionArg;
+use grovedb::{Element, PathQuery, Query, SizedQuery};
+
+/// The function an aggregate query computes over the documents matched by a drive query
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AggregateFunction {
+    /// The number of documents matched by the query
+    Count,
+    /// The sum of an integer property over the documents matched by the query, read from the
+    /// sum trees of the index declaring this property as its sum
+    Sum(String),
+}
+
+impl<'a> DriveQuery<'a> {
+    /// Checks that the query can be aggregated.
+    ///
+    /// Aggregates are computed over every document matched by the where clauses, a limit would
+    /// silently truncate them so it is rejected.
+    pub(crate) fn validate_aggregate_query(&self) -> Result<(), Error> {
+        if self.limit.is_some() {
+            return Err(Error::Query(QuerySyntaxError::InvalidLimit(
+                "aggregate queries can not have a limit".to_string(),
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the query reading the documents of a count.
+    ///
+    /// Counts are computed from the matched documents, so they are read up to one document more
+    /// than the maximum count of the platform version, which is enough to tell that a count is
+    /// above this maximum without reading every document.
+    pub(crate) fn count_query(&self, platform_version: &PlatformVersion) -> DriveQuery<'a> {
+        let max_aggregate_count = platform_version
+            .drive
+            .methods
+            .document
+            .query
+            .max_aggregate_count;
+        DriveQuery {
+            limit: Some(max_aggregate_count.saturating_add(1)),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the number of documents read by a count query, or an error if it is above the
+    /// maximum count of the platform version.
+    pub(crate) fn checked_count(
+        document_count: usize,
+        platform_version: &PlatformVersion,
+    ) -> Result<i64, Error> {
+        let max_aggregate_count = platform_version
+            .drive
+            .methods
+            .document
+            .query
+            .max_aggregate_count;
+        if document_count > max_aggregate_count as usize {
+            return Err(Error::Query(QuerySyntaxError::AggregateCountLimitExceeded(
+                format!(
+                    "the query matches more than {} documents, it should be narrowed",
+                    max_aggregate_count
+                ),
+            )));
+        }
+        Ok(document_count as i64)
+    }
+
+    /// Returns the index holding the sums of a property for the where clauses of the query.
+    ///
+    /// The equality and in clauses must be on the first properties of the index and a range
+    /// clause, if any, must be on the property right after them.
+    pub fn sum_index(&self, sum_property: &str) -> Result<&'a Index, Error> {
+        let clauses = &self.internal_clauses;
+        if clauses.is_disjunctive()
+            || clauses.primary_key_in_clause.is_some()
+            || clauses.primary_key_equal_clause.is_some()
+            || !clauses.residual_range_clauses.is_empty()
+            || clauses.contains_word_clause.is_some()
+        {
+            return Err(Error::Query(QuerySyntaxError::NoSumIndexForQuery(
+                "sums can only be queried with equality, in and range clauses on indexed properties",
+            )));
+        }
+        if self.offset.is_some() || self.start_at.is_some() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "sums can not be queried with an offset or a start".to_string(),
+            )));
+        }
+
+        let prefix_fields: Vec<&str> = clauses
+            .equal_clauses
+            .keys()
+            .map(|field| field.as_str())
+            .chain(
+                clauses
+                    .in_clause
+                    .as_ref()
+                    .map(|in_clause| in_clause.field.as_str()),
+            )
+            .collect();
+
+        self.document_type
+            .indices()
+            .iter()
+            .find(|index| {
+                index.sum_property.as_deref() == Some(sum_property)
+                    && index.properties.len() >= prefix_fields.len()
+                    && index.properties[..prefix_fields.len()]
+                        .iter()
+                        .all(|property| prefix_fields.contains(&property.name.as_str()))
+                    && clauses.range_clause.as_ref().map_or(true, |range_clause| {
+                        index
+                            .properties
+                            .get(prefix_fields.len())
+                            .is_some_and(|property| property.name == range_clause.field)
+                    })
+            })
+            .ok_or(Error::Query(QuerySyntaxError::NoSumIndexForQuery(
+                "no index declares this sum for the properties of the where clauses",
+            )))
+    }
+
+    /// Returns the path query to the sum trees of the index holding the sums of a property.
+    ///
+    /// Index properties without a clause match every value, so the result has one sum tree for
+    /// every combination of index values matched by the where clauses.
+    pub fn construct_sum_path_query(
+        &self,
+        sum_property: &str,
+        platform_version: &PlatformVersion,
+    ) -> Result<PathQuery, Error> {
+        let index = self.sum_index(sum_property)?;
+        let clauses = &self.internal_clauses;
+
+        let mut subquery: Option<(Vec<u8>, Query)> = None;
+        for index_property in index.properties.iter().rev() {
+            let clause: Option<&WhereClause> = clauses
+                .equal_clauses
+                .get(index_property.name.as_str())
+                .or(clauses
+                    .in_clause
+                    .as_ref()
+                    .filter(|in_clause| in_clause.field == index_property.name))
+                .or(clauses
+                    .range_clause
+                    .as_ref()
+                    .filter(|range_clause| range_clause.field == index_property.name));
+
+            let mut query = match clause {
+                Some(clause) => {
+                    clause.to_path_query(self.document_type, &None, true, platform_version)?
+                }
+                None => {
+                    let mut query = Query::new();
+                    query.insert_all();
+                    query
+                }
+            };
+
+            match subquery.take() {
+                Some((subquery_key, level_subquery)) => {
+                    query.set_subquery(level_subquery);
+                    query.set_subquery_key(subquery_key);
+                }
+                None => query.set_subquery_key(INDEX_SUM_TREE_KEY.to_vec()),
+            }
+
+            subquery = Some((index_property.name.as_bytes().to_vec(), query));
+        }
+
+        let (first_property_name, query) = subquery.ok_or(Error::Query(
+            QuerySyntaxError::NoSumIndexForQuery("sum index must have properties"),
+        ))?;
+
+        let mut path = contract_document_type_path_vec(
+            self.contract.id_ref().as_bytes(),
+            self.document_type.name(),
+        );
+        path.push(first_property_name);
+
+        Ok(PathQuery::new(path, SizedQuery::new(query, None, None)))
+    }
+
+    /// Adds up the values of the sum trees returned for a sum path query.
+    pub(crate) fn total_of_sum_trees<I: IntoIterator<Item = Element>>(
+        elements: I,
+    ) -> Result<i64, Error> {
+        elements.into_iter().try_fold(0i64, |total, element| {
+            let Element::SumTree(_, sum, _) = element else {
+                return Err(Error::Drive(DriveError::CorruptedElementType(
+                    "index sums should be sum trees",
+                )));
+            };
+            total
+                .checked_add(sum)
+                .ok_or(Error::Protocol(ProtocolError::Overflow(
+                    "index sums overflow",
+                )))
+        })
+    }
+
+    #[cfg(feature = "full")]
+    /// Executes an aggregate query without a proof and returns the aggregate and its cost.
+    pub fn execute_aggregate_no_proof(
+        &self,
+        function: &AggregateFunction,
+        drive: &Drive,
+        block_info: Option<BlockInfo>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(i64, u64), Error> {
+        let mut drive_operations = vec![];
+        let value = self.execute_aggregate_no_proof_internal(
+            function,
+            drive,
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )?;
+        let cost = if let Some(block_info) = block_info {
+            let fee_result = Drive::calculate_fee(
+                None,
+                Some(drive_operations),
+                &block_info.epoch,
+                drive.config.epochs_per_era,
+                platform_version,
+            )?;
+            fee_result.processing_fee
+        } else {
+            0
+        };
+        Ok((value, cost))
+    }
+
+    #[cfg(feature = "full")]
+    /// Executes an aggregate query without a proof.
+    pub(crate) fn execute_aggregate_no_proof_internal(
+        &self,
+        function: &AggregateFunction,
+        drive: &Drive,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<i64, Error> {
+        self.validate_aggregate_query()?;
+        match function {
+            AggregateFunction::Count => {
+                let (documents, _) = self
+                    .count_query(platform_version)
+                    .execute_raw_results_no_proof_internal(
+                        drive,
+                        transaction,
+                        drive_operations,
+                        platform_version,
+                    )?;
+                Self::checked_count(documents.len(), platform_version)
+            }
+            AggregateFunction::Sum(sum_property) => {
+                let path_query = self.construct_sum_path_query(sum_property, platform_version)?;
+                let (elements, _) = drive.grove_get_raw_path_query(
+                    &path_query,
+                    transaction,
+                    QueryResultType::QueryElementResultType,
+                    drive_operations,
+                    &platform_version.drive,
+                )?;
+                Self::total_of_sum_trees(elements.to_elements())
+            }
+        }
+    }
+
+    #[cfg(feature = "full")]
+    /// Executes an aggregate query with a proof and returns the proof and its cost.
+    ///
+    /// A count is proved by the documents it counts, up to one document more than the maximum
+    /// count so that the verifier can reject counts above it, a sum by the sum trees it adds up.
+    pub fn execute_aggregate_with_proof(
+        self,
+        function: &AggregateFunction,
+        drive: &Drive,
+        block_info: Option<BlockInfo>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<u8>, u64), Error> {
+        self.validate_aggregate_query()?;
+        match function {
+            AggregateFunction::Count => self.count_query(platform_version).execute_with_proof(
+                drive,
+                block_info,
+                transaction,
+                platform_version,
+            ),
+            AggregateFunction::Sum(sum_property) => {
+                let mut drive_operations = vec![];
+                let path_query = self.construct_sum_path_query(sum_property, platform_version)?;
+                let proof = drive.grove_get_proved_path_query(
+                    &path_query,
+                    false,
+                    transaction,
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?;
+                let cost = if let Some(block_info) = block_info {
+                    let fee_result = Drive::calculate_fee(
+                        None,
+                        Some(drive_operations),
+                        &block_info.epoch,
+                        drive.config.epochs_per_era,
+                        platform_version,
+                    )?;
+                    fee_result.processing_fee
+                } else {
+                    0
+                };
+                Ok((proof, cost))
+            }
+        }
+    }
+}
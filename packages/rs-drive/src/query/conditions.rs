@@ -35,21 +35,112 @@ use grovedb::Query;
 use sqlparser::ast;
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Bound;
 
 use WhereOperator::{
-    Between, BetweenExcludeBounds, BetweenExcludeLeft, BetweenExcludeRight, Equal, GreaterThan,
-    GreaterThanOrEquals, In, LessThan, LessThanOrEquals, StartsWith,
+    Between, BetweenExcludeBounds, BetweenExcludeLeft, BetweenExcludeRight, ContainsWord, Equal,
+    GreaterThan, GreaterThanOrEquals, In, LessThan, LessThanOrEquals, NotEqual, NotIn,
+    NotStartsWith, StartsWith,
 };
 
 use crate::error::query::QuerySyntaxError;
 use crate::error::Error;
 use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
-use dpp::data_contract::document_type::{DocumentPropertyType, DocumentType, DocumentTypeRef};
+use dpp::data_contract::document_type::{
+    DocumentPropertyType, DocumentType, DocumentTypeRef, Index,
+};
 use dpp::document::document_methods::DocumentMethodsV0;
-use dpp::document::Document;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::platform_value::btreemap_extensions::BTreeValueMapPathHelper;
 use dpp::platform_value::Value;
 use dpp::version::PlatformVersion;
 
+/// The key of the map holding the branches of a disjunctive where clause,
+/// i.e. `{"or": [[clauses of branch 1], [clauses of branch 2]]}`
+pub const OR_CLAUSE_KEY: &str = "or";
+
+/// Returns the key ranges that are not in the given sorted and disjoint `[start, end)` key
+/// ranges, some of them can be empty.
+fn complement_key_ranges(
+    excluded_key_ranges: Vec<(Vec<u8>, Vec<u8>)>,
+) -> Vec<(Bound<Vec<u8>>, Bound<Vec<u8>>)> {
+    let mut key_ranges = vec![];
+    let mut lower_bound = Bound::Unbounded;
+    for (start, end) in excluded_key_ranges {
+        key_ranges.push((lower_bound, Bound::Excluded(start)));
+        lower_bound = Bound::Included(end);
+    }
+    key_ranges.push((lower_bound, Bound::Unbounded));
+    key_ranges
+}
+
+/// Returns the most restrictive of two lower bounds.
+fn tightest_lower_bound(a: Bound<Vec<u8>>, b: Bound<Vec<u8>>) -> Bound<Vec<u8>> {
+    let a_is_tighter = match (&a, &b) {
+        (Bound::Unbounded, _) => false,
+        (_, Bound::Unbounded) => true,
+        (
+            Bound::Included(a_key) | Bound::Excluded(a_key),
+            Bound::Included(b_key) | Bound::Excluded(b_key),
+        ) => a_key > b_key || (a_key == b_key && matches!(a, Bound::Excluded(_))),
+    };
+    if a_is_tighter {
+        a
+    } else {
+        b
+    }
+}
+
+/// Returns the most restrictive of two upper bounds.
+fn tightest_upper_bound(a: Bound<Vec<u8>>, b: Bound<Vec<u8>>) -> Bound<Vec<u8>> {
+    let a_is_tighter = match (&a, &b) {
+        (Bound::Unbounded, _) => false,
+        (_, Bound::Unbounded) => true,
+        (
+            Bound::Included(a_key) | Bound::Excluded(a_key),
+            Bound::Included(b_key) | Bound::Excluded(b_key),
+        ) => a_key < b_key || (a_key == b_key && matches!(a, Bound::Excluded(_))),
+    };
+    if a_is_tighter {
+        a
+    } else {
+        b
+    }
+}
+
+/// Inserts the key range between the two bounds in the query, empty ranges are not inserted.
+fn insert_key_range(query: &mut Query, lower_bound: Bound<Vec<u8>>, upper_bound: Bound<Vec<u8>>) {
+    match (lower_bound, upper_bound) {
+        (Bound::Unbounded, Bound::Unbounded) => query.insert_all(),
+        (Bound::Unbounded, Bound::Included(upper)) => query.insert_range_to_inclusive(..=upper),
+        (Bound::Unbounded, Bound::Excluded(upper)) => query.insert_range_to(..upper),
+        (Bound::Included(lower), Bound::Unbounded) => query.insert_range_from(lower..),
+        (Bound::Excluded(lower), Bound::Unbounded) => query.insert_range_after(lower..),
+        (Bound::Included(lower), Bound::Included(upper)) => {
+            if lower == upper {
+                query.insert_key(lower)
+            } else if lower < upper {
+                query.insert_range_inclusive(lower..=upper)
+            }
+        }
+        (Bound::Included(lower), Bound::Excluded(upper)) => {
+            if lower < upper {
+                query.insert_range(lower..upper)
+            }
+        }
+        (Bound::Excluded(lower), Bound::Included(upper)) => {
+            if lower < upper {
+                query.insert_range_after_to_inclusive(lower..=upper)
+            }
+        }
+        (Bound::Excluded(lower), Bound::Excluded(upper)) => {
+            if lower < upper {
+                query.insert_range_after_to(lower..upper)
+            }
+        }
+    }
+}
+
 /// Converts SQL values to CBOR.
 fn sql_value_to_platform_value(sql_value: ast::Value) -> Option<Value> {
     match sql_value {
@@ -99,6 +190,14 @@ pub enum WhereOperator {
     In,
     /// Starts with
     StartsWith,
+    /// Not equal
+    NotEqual,
+    /// Not in
+    NotIn,
+    /// Not starts with
+    NotStartsWith,
+    /// Contains word, only on properties with a tokenized index
+    ContainsWord,
 }
 
 impl WhereOperator {
@@ -116,6 +215,10 @@ impl WhereOperator {
             BetweenExcludeRight => false,
             In => false,
             StartsWith => false,
+            NotEqual => true,
+            NotIn => false,
+            NotStartsWith => false,
+            ContainsWord => false,
         }
     }
 
@@ -145,6 +248,16 @@ impl WhereOperator {
             StartsWith => Err(Error::Query(QuerySyntaxError::InvalidWhereClauseOrder(
                 "Startswith clause order invalid",
             ))),
+            NotEqual => Ok(NotEqual),
+            NotIn => Err(Error::Query(QuerySyntaxError::InvalidWhereClauseOrder(
+                "NotIn clause order invalid",
+            ))),
+            NotStartsWith => Err(Error::Query(QuerySyntaxError::InvalidWhereClauseOrder(
+                "NotStartsWith clause order invalid",
+            ))),
+            ContainsWord => Err(Error::Query(QuerySyntaxError::InvalidWhereClauseOrder(
+                "ContainsWord clause order invalid",
+            ))),
         }
     }
 }
@@ -153,11 +266,10 @@ impl WhereOperator {
     /// Returns true if the where operator result is a range
     pub const fn is_range(self) -> bool {
         match self {
-            Equal => false,
+            Equal | ContainsWord => false,
             GreaterThan | GreaterThanOrEquals | LessThan | LessThanOrEquals | Between
-            | BetweenExcludeBounds | BetweenExcludeLeft | BetweenExcludeRight | In | StartsWith => {
-                true
-            }
+            | BetweenExcludeBounds | BetweenExcludeLeft | BetweenExcludeRight | In | StartsWith
+            | NotEqual | NotIn | NotStartsWith => true,
         }
     }
 
@@ -184,6 +296,14 @@ impl WhereOperator {
             | "between_exclude_right" => Some(BetweenExcludeRight),
             "In" | "in" => Some(In),
             "StartsWith" | "startsWith" | "startswith" | "starts_with" => Some(StartsWith),
+            "!=" | "<>" | "NotEqual" | "notEqual" | "notequal" | "not_equal" => Some(NotEqual),
+            "NotIn" | "notIn" | "notin" | "not_in" => Some(NotIn),
+            "NotStartsWith" | "notStartsWith" | "notstartswith" | "not_starts_with" => {
+                Some(NotStartsWith)
+            }
+            "ContainsWord" | "containsWord" | "containsword" | "contains_word" => {
+                Some(ContainsWord)
+            }
             &_ => None,
         }
     }
@@ -196,6 +316,7 @@ impl WhereOperator {
             ast::BinaryOperator::GtEq => Some(WhereOperator::GreaterThanOrEquals),
             ast::BinaryOperator::Lt => Some(WhereOperator::LessThan),
             ast::BinaryOperator::LtEq => Some(WhereOperator::LessThanOrEquals),
+            ast::BinaryOperator::NotEq => Some(WhereOperator::NotEqual),
             _ => None,
         }
     }
@@ -215,6 +336,10 @@ impl ToString for WhereOperator {
             Self::BetweenExcludeRight => "BetweenExcludeRight",
             Self::In => "In",
             Self::StartsWith => "StartsWith",
+            Self::NotEqual => "!=",
+            Self::NotIn => "NotIn",
+            Self::NotStartsWith => "NotStartsWith",
+            Self::ContainsWord => "ContainsWord",
         };
 
         s.to_string()
@@ -278,6 +403,26 @@ impl<'a> WhereClause {
         Ok(in_values)
     }
 
+    /// Returns the contains word clause with its word normalized like the words of tokenized
+    /// indices.
+    pub(crate) fn into_contains_word_clause(self) -> Result<Self, Error> {
+        let Value::Text(text) = &self.value else {
+            return Err(Error::Query(QuerySyntaxError::InvalidContainsWordClause(
+                "contains word clause value must be a string",
+            )));
+        };
+        let mut words = Index::tokens(text).into_iter();
+        match (words.next(), words.next()) {
+            (Some(word), None) => Ok(WhereClause {
+                value: Value::Text(word),
+                ..self
+            }),
+            _ => Err(Error::Query(QuerySyntaxError::InvalidContainsWordClause(
+                "contains word clause value must be a single word",
+            ))),
+        }
+    }
+
     /// Returns true if the less than where clause is true
     pub fn less_than(&self, other: &Self, allow_eq: bool) -> Result<bool, Error> {
         match (&self.value, &other.value) {
@@ -462,9 +607,9 @@ impl<'a> WhereClause {
     /// Given a list of where clauses, returns them in groups of equal, range, and in clauses
     pub(crate) fn group_clauses(
         where_clauses: &'a [WhereClause],
-    ) -> Result<(BTreeMap<String, Self>, Option<Self>, Option<Self>), Error> {
+    ) -> Result<(BTreeMap<String, Self>, Vec<Self>, Option<Self>), Error> {
         if where_clauses.is_empty() {
-            return Ok((BTreeMap::new(), None, None));
+            return Ok((BTreeMap::new(), vec![], None));
         }
         let equal_clauses_array =
             where_clauses
@@ -524,9 +669,47 @@ impl<'a> WhereClause {
             ))),
         }?;
 
+        // Range clauses are grouped per field, fields are kept in the order they first appear
+        let mut range_fields: Vec<&str> = vec![];
+        for where_clause in where_clauses {
+            if where_clause.operator != Equal
+                && where_clause.operator != In
+                && !range_fields.contains(&where_clause.field.as_str())
+            {
+                range_fields.push(where_clause.field.as_str());
+            }
+        }
+
+        let range_clauses = range_fields
+            .into_iter()
+            .map(|field| {
+                let field_range_clauses = where_clauses
+                    .iter()
+                    .filter(|where_clause| {
+                        where_clause.field == field
+                            && where_clause.operator != Equal
+                            && where_clause.operator != In
+                    })
+                    .collect::<Vec<&WhereClause>>();
+                Self::group_range_clauses_for_field(field_range_clauses, &known_fields)
+            })
+            .collect::<Result<Vec<Option<Self>>, Error>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Ok((equal_clauses, range_clauses, in_clause))
+    }
+
+    /// Groups the range clauses of a single field into one range clause
+    fn group_range_clauses_for_field(
+        where_clauses: Vec<&WhereClause>,
+        known_fields: &BTreeSet<String>,
+    ) -> Result<Option<Self>, Error> {
         // In order to group range clauses
         let groupable_range_clauses: Vec<&WhereClause> = where_clauses
             .iter()
+            .copied()
             .filter(|where_clause| match where_clause.operator {
                 Equal => false,
                 In => false,
@@ -539,11 +722,16 @@ impl<'a> WhereClause {
                 BetweenExcludeBounds => false,
                 BetweenExcludeRight => false,
                 BetweenExcludeLeft => false,
+                NotEqual => false,
+                NotIn => false,
+                NotStartsWith => false,
+                ContainsWord => false,
             })
             .collect();
 
         let non_groupable_range_clauses: Vec<&WhereClause> = where_clauses
             .iter()
+            .copied()
             .filter(|where_clause| match where_clause.operator {
                 Equal => false,
                 In => false,
@@ -556,116 +744,112 @@ impl<'a> WhereClause {
                 BetweenExcludeBounds => true,
                 BetweenExcludeRight => true,
                 BetweenExcludeLeft => true,
+                NotEqual => true,
+                NotIn => true,
+                NotStartsWith => true,
+                ContainsWord => false,
             })
             .collect();
 
-        let range_clause =
-            if non_groupable_range_clauses.is_empty() {
-                if groupable_range_clauses.is_empty() {
-                    Ok(None)
-                } else if groupable_range_clauses.len() == 1 {
-                    let clause = *groupable_range_clauses.first().unwrap();
-                    if known_fields.contains(clause.field.as_str()) {
-                        Err(Error::Query(
-                            QuerySyntaxError::InvalidWhereClauseComponents(
-                                "in clause has same field as an equality clause",
-                            ),
-                        ))
-                    } else {
-                        Ok(Some(clause.clone()))
-                    }
-                } else if groupable_range_clauses.len() > 2 {
-                    Err(Error::Query(QuerySyntaxError::MultipleRangeClauses(
-                        "there can only be at most 2 range clauses that must be on the same field",
-                    )))
+        if non_groupable_range_clauses.is_empty() {
+            if groupable_range_clauses.is_empty() {
+                Ok(None)
+            } else if groupable_range_clauses.len() == 1 {
+                let clause = *groupable_range_clauses.first().unwrap();
+                if known_fields.contains(clause.field.as_str()) {
+                    Err(Error::Query(
+                        QuerySyntaxError::InvalidWhereClauseComponents(
+                            "in clause has same field as an equality clause",
+                        ),
+                    ))
+                } else {
+                    Ok(Some(clause.clone()))
+                }
+            } else if groupable_range_clauses.len() > 2 {
+                Err(Error::Query(QuerySyntaxError::MultipleRangeClauses(
+                    "there can only be at most 2 range clauses that must be on the same field",
+                )))
+            } else {
+                let first_field = groupable_range_clauses.first().unwrap().field.as_str();
+                if known_fields.contains(first_field) {
+                    Err(Error::Query(
+                        QuerySyntaxError::InvalidWhereClauseComponents(
+                            "a range clause has same field as an equality or in clause",
+                        ),
+                    ))
                 } else {
-                    let first_field = groupable_range_clauses.first().unwrap().field.as_str();
-                    if known_fields.contains(first_field) {
-                        Err(Error::Query(
-                            QuerySyntaxError::InvalidWhereClauseComponents(
-                                "a range clause has same field as an equality or in clause",
-                            ),
+                    let lower_upper_error = || {
+                        Error::Query(QuerySyntaxError::RangeClausesNotGroupable(
+                            "lower and upper bounds must be passed if providing 2 ranges",
                         ))
-                    } else if groupable_range_clauses
-                        .iter()
-                        .any(|&z| z.field.as_str() != first_field)
-                    {
-                        Err(Error::Query(QuerySyntaxError::MultipleRangeClauses(
-                            "all ranges must be on same field",
-                        )))
-                    } else {
-                        let lower_upper_error = || {
-                            Error::Query(QuerySyntaxError::RangeClausesNotGroupable(
-                                "lower and upper bounds must be passed if providing 2 ranges",
-                            ))
-                        };
+                    };
 
-                        // we need to find the bounds of the clauses
-                        let lower_bounds_clause =
-                            WhereClause::lower_bound_clause(groupable_range_clauses.as_slice())?
-                                .ok_or_else(lower_upper_error)?;
-                        let upper_bounds_clause =
-                            WhereClause::upper_bound_clause(groupable_range_clauses.as_slice())?
-                                .ok_or_else(lower_upper_error)?;
-
-                        let operator =
-                            match (lower_bounds_clause.operator, upper_bounds_clause.operator) {
-                                (GreaterThanOrEquals, LessThanOrEquals) => Some(Between),
-                                (GreaterThanOrEquals, LessThan) => Some(BetweenExcludeRight),
-                                (GreaterThan, LessThanOrEquals) => Some(BetweenExcludeLeft),
-                                (GreaterThan, LessThan) => Some(BetweenExcludeBounds),
-                                _ => None,
-                            }
+                    // we need to find the bounds of the clauses
+                    let lower_bounds_clause =
+                        WhereClause::lower_bound_clause(groupable_range_clauses.as_slice())?
+                            .ok_or_else(lower_upper_error)?;
+                    let upper_bounds_clause =
+                        WhereClause::upper_bound_clause(groupable_range_clauses.as_slice())?
                             .ok_or_else(lower_upper_error)?;
 
-                        if upper_bounds_clause
-                            .less_than(lower_bounds_clause, operator == BetweenExcludeBounds)?
-                        {
-                            return Err(Error::Query(QuerySyntaxError::MultipleRangeClauses(
-                                "lower bounds must be under upper bounds",
-                            )));
+                    let operator =
+                        match (lower_bounds_clause.operator, upper_bounds_clause.operator) {
+                            (GreaterThanOrEquals, LessThanOrEquals) => Some(Between),
+                            (GreaterThanOrEquals, LessThan) => Some(BetweenExcludeRight),
+                            (GreaterThan, LessThanOrEquals) => Some(BetweenExcludeLeft),
+                            (GreaterThan, LessThan) => Some(BetweenExcludeBounds),
+                            _ => None,
                         }
+                        .ok_or_else(lower_upper_error)?;
 
-                        Ok(Some(WhereClause {
-                            field: groupable_range_clauses.first().unwrap().field.clone(),
-                            operator,
-                            value: Value::Array(vec![
-                                lower_bounds_clause.value.clone(),
-                                upper_bounds_clause.value.clone(),
-                            ]),
-                        }))
+                    if upper_bounds_clause
+                        .less_than(lower_bounds_clause, operator == BetweenExcludeBounds)?
+                    {
+                        return Err(Error::Query(QuerySyntaxError::MultipleRangeClauses(
+                            "lower bounds must be under upper bounds",
+                        )));
                     }
+
+                    Ok(Some(WhereClause {
+                        field: groupable_range_clauses.first().unwrap().field.clone(),
+                        operator,
+                        value: Value::Array(vec![
+                            lower_bounds_clause.value.clone(),
+                            upper_bounds_clause.value.clone(),
+                        ]),
+                    }))
                 }
-            } else if non_groupable_range_clauses.len() == 1 && groupable_range_clauses.is_empty() {
-                let where_clause = *non_groupable_range_clauses.first().unwrap();
-                if where_clause.operator == StartsWith {
-                    // Starts with must null be against an empty string
-                    if let Value::Text(text) = &where_clause.value {
-                        if text.is_empty() {
-                            return Err(Error::Query(QuerySyntaxError::StartsWithIllegalString(
-                                "starts with can not start with an empty string",
-                            )));
-                        }
+            }
+        } else if non_groupable_range_clauses.len() == 1 && groupable_range_clauses.is_empty() {
+            let where_clause = *non_groupable_range_clauses.first().unwrap();
+            if matches!(where_clause.operator, StartsWith | NotStartsWith) {
+                // Starts with must null be against an empty string
+                if let Value::Text(text) = &where_clause.value {
+                    if text.is_empty() {
+                        return Err(Error::Query(QuerySyntaxError::StartsWithIllegalString(
+                            "starts with can not start with an empty string",
+                        )));
                     }
                 }
-                if known_fields.contains(where_clause.field.as_str()) {
-                    Err(Error::Query(QuerySyntaxError::DuplicateNonGroupableClauseSameField(
-                    "a non groupable range clause has same field as an equality or in clause",
-                )))
-                } else {
-                    Ok(Some(where_clause.clone()))
-                }
-            } else if groupable_range_clauses.is_empty() {
-                Err(Error::Query(QuerySyntaxError::MultipleRangeClauses(
-                    "there can not be more than 1 non groupable range clause",
-                )))
+            }
+            if known_fields.contains(where_clause.field.as_str()) {
+                Err(Error::Query(
+                    QuerySyntaxError::DuplicateNonGroupableClauseSameField(
+                        "a non groupable range clause has same field as an equality or in clause",
+                    ),
+                ))
             } else {
-                Err(Error::Query(QuerySyntaxError::RangeClausesNotGroupable(
-                    "clauses are not groupable",
-                )))
-            }?;
-
-        Ok((equal_clauses, range_clause, in_clause))
+                Ok(Some(where_clause.clone()))
+            }
+        } else if groupable_range_clauses.is_empty() {
+            Err(Error::Query(QuerySyntaxError::MultipleRangeClauses(
+                "there can not be more than 1 non groupable range clause",
+            )))
+        } else {
+            Err(Error::Query(QuerySyntaxError::RangeClausesNotGroupable(
+                "clauses are not groupable",
+            )))
+        }
     }
 
     fn split_value_for_between(
@@ -700,6 +884,149 @@ impl<'a> WhereClause {
         Ok((left_key, right_key))
     }
 
+    /// Returns true if the document matches the where clause.
+    ///
+    /// Values are compared by their serialized index keys, which preserve ordering, so a
+    /// document matches exactly when it would be returned by the index query of the clause.
+    /// A document without a value for the field has the empty key, like in indexes. A contains
+    /// word clause matches documents having the word in the text of the field.
+    pub(crate) fn matches_document(
+        &self,
+        document: &Document,
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+    ) -> Result<bool, Error> {
+        let document_key = document
+            .get_raw_for_document_type(self.field.as_str(), document_type, None, platform_version)?
+            .unwrap_or_default();
+        let serialize_value = |value: &Value| {
+            document_type
+                .serialize_value_for_key(self.field.as_str(), value, platform_version)
+                .map_err(Error::Protocol)
+        };
+        let matches = match self.operator {
+            Equal => document_key == serialize_value(&self.value)?,
+            In => {
+                let mut matches = false;
+                for value in self.in_values()?.iter() {
+                    if document_key == serialize_value(value)? {
+                        matches = true;
+                        break;
+                    }
+                }
+                matches
+            }
+            GreaterThan => document_key > serialize_value(&self.value)?,
+            GreaterThanOrEquals => document_key >= serialize_value(&self.value)?,
+            LessThan => document_key < serialize_value(&self.value)?,
+            LessThanOrEquals => document_key <= serialize_value(&self.value)?,
+            Between => {
+                let (left_key, right_key) =
+                    self.split_value_for_between(document_type, platform_version)?;
+                document_key >= left_key && document_key <= right_key
+            }
+            BetweenExcludeBounds => {
+                let (left_key, right_key) =
+                    self.split_value_for_between(document_type, platform_version)?;
+                document_key > left_key && document_key < right_key
+            }
+            BetweenExcludeLeft => {
+                let (left_key, right_key) =
+                    self.split_value_for_between(document_type, platform_version)?;
+                document_key > left_key && document_key <= right_key
+            }
+            BetweenExcludeRight => {
+                let (left_key, right_key) =
+                    self.split_value_for_between(document_type, platform_version)?;
+                document_key >= left_key && document_key < right_key
+            }
+            StartsWith => {
+                let prefix = serialize_value(&self.value)?;
+                if prefix.is_empty() {
+                    return Err(Error::Query(QuerySyntaxError::InvalidStartsWithClause(
+                        "starts with must have at least one character",
+                    )));
+                }
+                document_key.starts_with(prefix.as_slice())
+            }
+            NotEqual | NotIn | NotStartsWith => {
+                let excluded_key_ranges =
+                    self.excluded_key_ranges(document_type, platform_version)?;
+                !excluded_key_ranges
+                    .iter()
+                    .any(|(start, end)| document_key >= *start && document_key < *end)
+            }
+            ContainsWord => {
+                let word = self.value.to_str()?;
+                document
+                    .properties()
+                    .get_optional_at_path(self.field.as_str())?
+                    .and_then(|value| value.as_text())
+                    .is_some_and(|text| Index::tokens(text).contains(word))
+            }
+        };
+        Ok(matches)
+    }
+
+    /// Returns the sorted and disjoint `[start, end)` key ranges excluded by a negated where
+    /// clause.
+    fn excluded_key_ranges(
+        &self,
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>, Error> {
+        // The key right after a key is the key followed by a zero byte
+        let single_key_range = |key: Vec<u8>| {
+            let mut next_key = key.clone();
+            next_key.push(0);
+            (key, next_key)
+        };
+        match self.operator {
+            NotEqual => {
+                let key = document_type.serialize_value_for_key(
+                    self.field.as_str(),
+                    &self.value,
+                    platform_version,
+                )?;
+                Ok(vec![single_key_range(key)])
+            }
+            NotIn => {
+                let keys = self
+                    .in_values()?
+                    .iter()
+                    .map(|value| {
+                        document_type.serialize_value_for_key(
+                            self.field.as_str(),
+                            value,
+                            platform_version,
+                        )
+                    })
+                    .collect::<Result<BTreeSet<Vec<u8>>, _>>()?;
+                Ok(keys.into_iter().map(single_key_range).collect())
+            }
+            NotStartsWith => {
+                let left_key = document_type.serialize_value_for_key(
+                    self.field.as_str(),
+                    &self.value,
+                    platform_version,
+                )?;
+                let mut right_key = left_key.clone();
+                let last_char = right_key.last_mut().ok_or({
+                    Error::Query(QuerySyntaxError::InvalidStartsWithClause(
+                        "not starts with must have at least one character",
+                    ))
+                })?;
+                *last_char += 1;
+                Ok(vec![(left_key, right_key)])
+            }
+            _ => Err(Error::Query(
+                QuerySyntaxError::InvalidWhereClauseComponents(
+                    "only negated operators exclude key ranges",
+                ),
+            )),
+        }
+    }
+
     /// Returns a path query given the parameters
     // The start at document fields are:
     // document: The Document that we should start at
@@ -1071,10 +1398,220 @@ impl<'a> WhereClause {
                     }
                 }
             }
+            NotEqual | NotIn | NotStartsWith => {
+                let excluded_key_ranges =
+                    self.excluded_key_ranges(document_type, platform_version)?;
+                for (lower_bound, upper_bound) in complement_key_ranges(excluded_key_ranges) {
+                    // The start at key can only shrink the ranges in the query direction
+                    let (lower_bound, upper_bound) = match &starts_at_key_option {
+                        None => (lower_bound, upper_bound),
+                        Some((starts_at_key, included)) => {
+                            let starts_at_bound = if *included {
+                                Bound::Included(starts_at_key.clone())
+                            } else {
+                                Bound::Excluded(starts_at_key.clone())
+                            };
+                            if left_to_right {
+                                (
+                                    tightest_lower_bound(lower_bound, starts_at_bound),
+                                    upper_bound,
+                                )
+                            } else {
+                                (
+                                    lower_bound,
+                                    tightest_upper_bound(upper_bound, starts_at_bound),
+                                )
+                            }
+                        }
+                    };
+                    insert_key_range(&mut query, lower_bound, upper_bound);
+                }
+            }
+            ContainsWord => {
+                return Err(Error::Query(QuerySyntaxError::InvalidContainsWordClause(
+                    "contains word clauses are queried on the words of tokenized indices",
+                )))
+            }
         }
         Ok(query)
     }
 
+    /// Returns the branches of a disjunctive where clause given as `{"or": [branch, ...]}`.
+    ///
+    /// A branch is either a single where clause (`[field, operator, value]`) or a list of where
+    /// clauses that must all match.
+    pub fn disjunctive_branches_from_value(
+        value: &Value,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<Vec<Self>>, Error> {
+        let map =
+            value
+                .as_map()
+                .ok_or(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                    "where clause must be an array",
+                )))?;
+
+        let [(key, branches)] = map.as_slice() else {
+            return Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                "or clause must be a map with a single or key",
+            )));
+        };
+
+        if key.as_text() != Some(OR_CLAUSE_KEY) {
+            return Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                "or clause must be a map with a single or key",
+            )));
+        }
+
+        let Value::Array(branches) = branches else {
+            return Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                "or clause branches must be an array",
+            )));
+        };
+
+        if branches.len() < 2 {
+            return Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                "or clause must have at least 2 branches",
+            )));
+        }
+
+        let max_disjunctive_branches = platform_version
+            .drive
+            .methods
+            .document
+            .query
+            .max_disjunctive_branches as usize;
+        if branches.len() > max_disjunctive_branches {
+            return Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                "or clause has too many branches",
+            )));
+        }
+
+        branches
+            .iter()
+            .map(|branch| match branch {
+                Value::Array(components)
+                    if components.first().map(Value::is_text).unwrap_or_default() =>
+                {
+                    Ok(vec![WhereClause::from_components(components)?])
+                }
+                Value::Array(clauses) if !clauses.is_empty() => clauses
+                    .iter()
+                    .map(|clause| match clause {
+                        Value::Array(components) => WhereClause::from_components(components),
+                        _ => Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                            "where clause must be an array",
+                        ))),
+                    })
+                    .collect(),
+                _ => Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                    "or clause branch must be a where clause or a non empty array of where clauses",
+                ))),
+            })
+            .collect()
+    }
+
+    /// Combines two sets of disjunctive branches that must both hold, distributing the
+    /// conjunction over every pair of branches.
+    pub(crate) fn combine_disjunctive_branches(
+        left_branches: Vec<Vec<Self>>,
+        right_branches: Vec<Vec<Self>>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<Vec<Self>>, Error> {
+        let max_disjunctive_branches = platform_version
+            .drive
+            .methods
+            .document
+            .query
+            .max_disjunctive_branches as usize;
+        if left_branches.len() * right_branches.len() > max_disjunctive_branches {
+            return Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                "or clauses expand to too many branches",
+            )));
+        }
+
+        let mut branches = Vec::with_capacity(left_branches.len() * right_branches.len());
+        for left_branch in left_branches.iter() {
+            for right_branch in right_branches.iter() {
+                let mut branch = left_branch.clone();
+                branch.extend(right_branch.iter().cloned());
+                branches.push(branch);
+            }
+        }
+        Ok(branches)
+    }
+
+    /// Build where clauses from operations, returning them as disjunctive branches where each
+    /// branch is a list of where clauses that must all match
+    pub(crate) fn build_disjunctive_where_clauses_from_operations(
+        binary_operation: &ast::Expr,
+        document_type: &DocumentType,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<Vec<WhereClause>>, Error> {
+        match binary_operation {
+            ast::Expr::Nested(expr) => Self::build_disjunctive_where_clauses_from_operations(
+                expr,
+                document_type,
+                platform_version,
+            ),
+            ast::Expr::BinaryOp {
+                left,
+                op: ast::BinaryOperator::Or,
+                right,
+            } => {
+                let mut branches = Self::build_disjunctive_where_clauses_from_operations(
+                    left,
+                    document_type,
+                    platform_version,
+                )?;
+                branches.extend(Self::build_disjunctive_where_clauses_from_operations(
+                    right,
+                    document_type,
+                    platform_version,
+                )?);
+                let max_disjunctive_branches = platform_version
+                    .drive
+                    .methods
+                    .document
+                    .query
+                    .max_disjunctive_branches
+                    as usize;
+                if branches.len() > max_disjunctive_branches {
+                    return Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                        "or clause has too many branches",
+                    )));
+                }
+                Ok(branches)
+            }
+            ast::Expr::BinaryOp {
+                left,
+                op: ast::BinaryOperator::And,
+                right,
+            } => Self::combine_disjunctive_branches(
+                Self::build_disjunctive_where_clauses_from_operations(
+                    left,
+                    document_type,
+                    platform_version,
+                )?,
+                Self::build_disjunctive_where_clauses_from_operations(
+                    right,
+                    document_type,
+                    platform_version,
+                )?,
+                platform_version,
+            ),
+            _ => {
+                let mut where_clauses = Vec::new();
+                Self::build_where_clauses_from_operations(
+                    binary_operation,
+                    document_type,
+                    &mut where_clauses,
+                )?;
+                Ok(vec![where_clauses])
+            }
+        }
+    }
+
     /// Build where clauses from operations
     pub(crate) fn build_where_clauses_from_operations(
         binary_operation: &ast::Expr,
@@ -1087,12 +1624,6 @@ impl<'a> WhereClause {
                 list,
                 negated,
             } => {
-                if *negated {
-                    return Err(Error::Query(QuerySyntaxError::Unsupported(
-                        "Invalid query: negated in clause not supported".to_string(),
-                    )));
-                }
-
                 let field_name: String = if let ast::Expr::Identifier(ident) = &**expr {
                     ident.value.clone()
                 } else {
@@ -1141,7 +1672,11 @@ impl<'a> WhereClause {
 
                 where_clauses.push(WhereClause {
                     field: field_name,
-                    operator: WhereOperator::In,
+                    operator: if *negated {
+                        WhereOperator::NotIn
+                    } else {
+                        WhereOperator::In
+                    },
                     value: Value::Array(in_values),
                 });
 
@@ -1153,12 +1688,11 @@ impl<'a> WhereClause {
                 pattern,
                 escape_char,
             } => {
-                let where_operator = WhereOperator::StartsWith;
-                if *negated {
-                    return Err(Error::Query(QuerySyntaxError::Unsupported(
-                        "Negated Like not supported".to_string(),
-                    )));
-                }
+                let where_operator = if *negated {
+                    WhereOperator::NotStartsWith
+                } else {
+                    WhereOperator::StartsWith
+                };
 
                 let field_name: String = if let ast::Expr::Identifier(ident) = &**expr {
                     ident.value.clone()
@@ -1292,10 +1826,11 @@ impl<'a> From<WhereClause> for Value {
 #[cfg(feature = "full")]
 #[cfg(test)]
 mod tests {
-    use crate::query::conditions::WhereClause;
     use crate::query::conditions::WhereOperator::{
-        Equal, GreaterThan, GreaterThanOrEquals, In, LessThan, LessThanOrEquals,
+        Equal, GreaterThan, GreaterThanOrEquals, In, LessThan, LessThanOrEquals, NotEqual, NotIn,
+        NotStartsWith,
     };
+    use crate::query::conditions::{WhereClause, WhereOperator};
     use dpp::platform_value::Value;
 
     #[test]
@@ -1318,9 +1853,13 @@ mod tests {
                     value: Value::Float(1.0),
                 },
             ];
-            let (_, range_clause, _) = WhereClause::group_clauses(&where_clauses)
+            let (_, range_clauses, _) = WhereClause::group_clauses(&where_clauses)
                 .expect("expected to have groupable pair");
-            range_clause.expect("expected to have range clause returned");
+            assert_eq!(
+                range_clauses.len(),
+                1,
+                "expected to have one range clause returned"
+            );
         }
     }
 
@@ -1344,9 +1883,13 @@ mod tests {
                     value: Value::Float(0.0),
                 },
             ];
-            let (_, range_clause, _) = WhereClause::group_clauses(&where_clauses)
+            let (_, range_clauses, _) = WhereClause::group_clauses(&where_clauses)
                 .expect("expected to have groupable pair");
-            range_clause.expect("expected to have range clause returned");
+            assert_eq!(
+                range_clauses.len(),
+                1,
+                "expected to have one range clause returned"
+            );
         }
     }
 
@@ -1372,7 +1915,7 @@ mod tests {
     }
 
     #[test]
-    fn test_different_fields_grouping_causes_error() {
+    fn test_different_fields_grouping_into_separate_range_clauses() {
         let where_clauses = vec![
             WhereClause {
                 field: "a".to_string(),
@@ -1385,8 +1928,55 @@ mod tests {
                 value: Value::Float(1.0),
             },
         ];
+        let (_, range_clauses, _) = WhereClause::group_clauses(&where_clauses)
+            .expect("ranges on different fields should be grouped per field");
+        let fields = range_clauses
+            .iter()
+            .map(|where_clause| where_clause.field.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(fields, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_negated_operators_string_round_trip() {
+        for operator in [NotEqual, NotIn, NotStartsWith] {
+            assert_eq!(
+                WhereOperator::from_string(operator.to_string().as_str()),
+                Some(operator)
+            );
+        }
+
+        for (name, operator) in [
+            ("NotEqual", NotEqual),
+            ("notEqual", NotEqual),
+            ("not_equal", NotEqual),
+            ("NotIn", NotIn),
+            ("notIn", NotIn),
+            ("not_in", NotIn),
+            ("NotStartsWith", NotStartsWith),
+            ("notStartsWith", NotStartsWith),
+            ("not_starts_with", NotStartsWith),
+        ] {
+            assert_eq!(WhereOperator::from_string(name), Some(operator));
+        }
+    }
+
+    #[test]
+    fn test_negated_range_clause_with_other_range_causes_error() {
+        let where_clauses = vec![
+            WhereClause {
+                field: "a".to_string(),
+                operator: NotEqual,
+                value: Value::Float(0.0),
+            },
+            WhereClause {
+                field: "a".to_string(),
+                operator: LessThan,
+                value: Value::Float(1.0),
+            },
+        ];
         WhereClause::group_clauses(&where_clauses)
-            .expect_err("different fields should not be groupable");
+            .expect_err("a negated clause should not be groupable with another range");
     }
 
     #[test]
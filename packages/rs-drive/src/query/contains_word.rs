@@ -0,0 +1,92 @@
+use crate::drive::document::TOKEN_INDICES_TREE_KEY;
+use crate::error::drive::DriveError;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use crate::query::DriveQuery;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::Index;
+use dpp::document::{Document, DocumentV0Getters};
+use grovedb::{PathQuery, Query, SizedQuery};
+
+impl<'a> DriveQuery<'a> {
+    /// Returns the tokenized index holding the words of the field of the contains word clause.
+    pub fn contains_word_index(&self) -> Result<&'a Index, Error> {
+        let Some(contains_word_clause) = &self.internal_clauses.contains_word_clause else {
+            return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                "query should have a contains word clause",
+            )));
+        };
+        self.document_type
+            .token_indices()
+            .iter()
+            .find(|index| {
+                index
+                    .properties
+                    .first()
+                    .is_some_and(|property| property.name == contains_word_clause.field)
+            })
+            .ok_or(Error::Query(
+                QuerySyntaxError::WhereClauseOnNonIndexedProperty(
+                    "contains word clauses can only be on properties with a tokenized index",
+                ),
+            ))
+    }
+
+    /// Returns the path query to the documents having the word of the contains word clause.
+    ///
+    /// The references of these documents are stored by document id under the word, so results
+    /// can only be ordered by `$id`.
+    pub fn get_contains_word_path_query(
+        &self,
+        document_type_path: Vec<Vec<u8>>,
+        starts_at_document: Option<(Document, bool)>,
+    ) -> Result<PathQuery, Error> {
+        let index = self.contains_word_index()?;
+        let Some(contains_word_clause) = &self.internal_clauses.contains_word_clause else {
+            return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                "query should have a contains word clause",
+            )));
+        };
+
+        let left_to_right = match self.order_by.len() {
+            0 => true,
+            1 => match self.order_by.get("$id") {
+                Some(order_clause) => order_clause.ascending,
+                None => {
+                    return Err(Error::Query(QuerySyntaxError::InvalidOrderByProperties(
+                        "documents having a word can only be ordered by $id",
+                    )))
+                }
+            },
+            _ => {
+                return Err(Error::Query(QuerySyntaxError::InvalidOrderByProperties(
+                    "documents having a word can only be ordered by $id",
+                )))
+            }
+        };
+
+        let mut path = document_type_path;
+        path.push(TOKEN_INDICES_TREE_KEY.to_vec());
+        path.push(contains_word_clause.field.as_bytes().to_vec());
+        path.push(contains_word_clause.value.to_str()?.as_bytes().to_vec());
+
+        let mut query = Query::new_with_direction(left_to_right);
+        match starts_at_document {
+            None => query.insert_all(),
+            Some((document, included)) => {
+                let document_id = document.id().to_vec();
+                match (left_to_right, included) {
+                    (true, true) => query.insert_range_from(document_id..),
+                    (true, false) => query.insert_range_after(document_id..),
+                    (false, true) => query.insert_range_to_inclusive(..=document_id),
+                    (false, false) => query.insert_range_to(..document_id),
+                }
+            }
+        }
+
+        Ok(PathQuery::new(
+            path,
+            SizedQuery::new(query, self.limit, self.offset),
+        ))
+    }
+}
@@ -1,2 +1,4 @@
 /// Max index difference constant
 pub(crate) const MAX_INDEX_DIFFERENCE: u16 = 2;
+/// Max number of pages of the index query that are filtered by residual range clauses
+pub(crate) const MAX_RESIDUAL_FILTER_PAGES: usize = 10;
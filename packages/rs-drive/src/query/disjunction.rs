@@ -0,0 +1,306 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+use integer_encoding::VarInt;
+
+use crate::drive::config::DEFAULT_MAX_QUERY_LIMIT;
+#[cfg(feature = "verify")]
+use crate::drive::verify::RootHash;
+#[cfg(feature = "full")]
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+#[cfg(feature = "full")]
+use crate::fee::op::LowLevelDriveOperation;
+use crate::query::DriveQuery;
+use dpp::document::document_methods::DocumentMethodsV0;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::version::PlatformVersion;
+#[cfg(feature = "full")]
+use grovedb::TransactionArg;
+
+impl<'a> DriveQuery<'a> {
+    /// Returns true if the query is the union of several conjunctive queries.
+    pub fn is_disjunctive(&self) -> bool {
+        self.internal_clauses.is_disjunctive()
+    }
+
+    /// Splits a disjunctive query into one conjunctive query per branch.
+    ///
+    /// Every branch keeps the limit, ordering and start of the disjunctive query, so that the
+    /// first `limit` documents of the union are always contained in the union of the first
+    /// `limit` documents of each branch.
+    ///
+    /// Without order by clauses the union is ordered by id, which is not the order in which the
+    /// branches walk their indexes. Each branch then returns at most the limit of the query from
+    /// its first document and the union of these documents is ordered by id. Paging through such
+    /// a union would require every branch to be read entirely, so these queries can not have a
+    /// start.
+    ///
+    /// Every branch is executed on its own, so the number of branches is limited by the platform
+    /// version.
+    pub fn disjunctive_branch_queries(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveQuery<'a>>, Error> {
+        if self.offset.is_some() {
+            return Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                "offset is not supported for queries with or clauses",
+            )));
+        }
+
+        let max_disjunctive_branches = platform_version
+            .drive
+            .methods
+            .document
+            .query
+            .max_disjunctive_branches as usize;
+        if self.internal_clauses.or_clauses.len() > max_disjunctive_branches {
+            return Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                "query is split into too many disjunctive branches",
+            )));
+        }
+
+        if self.order_by.is_empty() && self.start_at.is_some() {
+            return Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                "start is not supported for queries with or clauses without order by clauses",
+            )));
+        }
+
+        // Queries built from a request always have a limit, at most the max query limit
+        let limit = Some(self.limit.unwrap_or(DEFAULT_MAX_QUERY_LIMIT));
+
+        Ok(self
+            .internal_clauses
+            .or_clauses
+            .iter()
+            .map(|branch_clauses| DriveQuery {
+                internal_clauses: branch_clauses.clone(),
+                limit,
+                ..self.clone()
+            })
+            .collect())
+    }
+
+    /// Merges the serialized documents returned by each branch of a disjunctive query.
+    ///
+    /// Documents matching several branches are only returned once, documents are ordered by the
+    /// query order by clauses then by id, and the limit of the query is applied to the union.
+    /// Branches walk the ids of documents with equal index values in the direction of the last
+    /// order by clause, so ties are broken in this direction too, otherwise the union could keep
+    /// documents a branch cut off by its limit instead of the ones it returned.
+    /// Without order by clauses documents are only ordered by id.
+    pub(crate) fn merge_disjunctive_results(
+        &self,
+        branch_results: Vec<Vec<Vec<u8>>>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        let mut seen_ids = BTreeSet::new();
+        let mut sortable_results = vec![];
+        for serialized in branch_results.into_iter().flatten() {
+            let document =
+                Document::from_bytes(serialized.as_slice(), self.document_type, platform_version)?;
+            if !seen_ids.insert(document.id()) {
+                continue;
+            }
+            // Index keys are serialized in an order preserving way, so comparing them is the same
+            // as comparing the values they were made from
+            let sort_keys = self
+                .order_by
+                .values()
+                .map(|order_clause| {
+                    document.get_raw_for_document_type(
+                        order_clause.field.as_str(),
+                        self.document_type,
+                        None,
+                        platform_version,
+                    )
+                })
+                .collect::<Result<Vec<Option<Vec<u8>>>, _>>()?;
+            sortable_results.push((sort_keys, document.id(), serialized));
+        }
+
+        let ids_ascending = self
+            .order_by
+            .values()
+            .last()
+            .map(|order_clause| order_clause.ascending)
+            .unwrap_or(true);
+
+        sortable_results.sort_by(|(keys_a, id_a, _), (keys_b, id_b, _)| {
+            keys_a
+                .iter()
+                .zip(keys_b.iter())
+                .zip(self.order_by.values())
+                .map(|((key_a, key_b), order_clause)| {
+                    if order_clause.ascending {
+                        key_a.cmp(key_b)
+                    } else {
+                        key_b.cmp(key_a)
+                    }
+                })
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or_else(|| {
+                    if ids_ascending {
+                        id_a.cmp(id_b)
+                    } else {
+                        id_b.cmp(id_a)
+                    }
+                })
+        });
+
+        let limit = self
+            .limit
+            .map(|limit| limit as usize)
+            .unwrap_or(sortable_results.len());
+
+        Ok(sortable_results
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, serialized)| serialized)
+            .collect())
+    }
+
+    /// Encodes a sequence of proofs into a single proof, it is used for the branches of
+    /// disjunctive queries and the pages of queries with residual range clauses.
+    ///
+    /// The encoding is the number of proofs followed by each length prefixed proof, all lengths
+    /// are varints.
+    pub(crate) fn encode_proof_sequence(proofs: Vec<Vec<u8>>) -> Vec<u8> {
+        let mut encoded = proofs.len().encode_var_vec();
+        for proof in proofs {
+            encoded.extend(proof.len().encode_var_vec());
+            encoded.extend(proof);
+        }
+        encoded
+    }
+
+    /// Decodes a proof made by `encode_proof_sequence` into the sequence of proofs.
+    pub(crate) fn decode_proof_sequence(proof: &[u8]) -> Result<Vec<&[u8]>, Error> {
+        let corrupted_proof_error = || {
+            Error::Proof(ProofError::CorruptedProof(
+                "invalid proof sequence".to_string(),
+            ))
+        };
+
+        let (proof_count, mut offset) =
+            usize::decode_var(proof).ok_or_else(corrupted_proof_error)?;
+        let mut proofs = Vec::with_capacity(proof_count.min(proof.len()));
+        for _ in 0..proof_count {
+            let (length, length_size) =
+                usize::decode_var(proof.get(offset..).ok_or_else(corrupted_proof_error)?)
+                    .ok_or_else(corrupted_proof_error)?;
+            offset += length_size;
+            let end = offset
+                .checked_add(length)
+                .ok_or_else(corrupted_proof_error)?;
+            proofs.push(proof.get(offset..end).ok_or_else(corrupted_proof_error)?);
+            offset = end;
+        }
+        if offset != proof.len() {
+            return Err(corrupted_proof_error());
+        }
+        Ok(proofs)
+    }
+
+    #[cfg(feature = "verify")]
+    /// Verifies the proof of a disjunctive query and returns the root hash and the merged
+    /// serialized documents of all branches.
+    pub(crate) fn verify_disjunctive_proof_keep_serialized(
+        &self,
+        proof: &[u8],
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<Vec<u8>>), Error> {
+        let branch_queries = self.disjunctive_branch_queries(platform_version)?;
+        let branch_proofs = Self::decode_proof_sequence(proof)?;
+        if branch_queries.len() != branch_proofs.len() {
+            return Err(Error::Proof(ProofError::WrongElementCount {
+                expected: branch_queries.len(),
+                got: branch_proofs.len(),
+            }));
+        }
+
+        let mut root_hash: Option<RootHash> = None;
+        let mut branch_results = Vec::with_capacity(branch_queries.len());
+        for (branch_query, branch_proof) in branch_queries.iter().zip(branch_proofs) {
+            let (branch_root_hash, documents) =
+                branch_query.verify_proof_keep_serialized(branch_proof, platform_version)?;
+            match root_hash {
+                None => root_hash = Some(branch_root_hash),
+                Some(root_hash) if root_hash != branch_root_hash => {
+                    return Err(Error::Proof(ProofError::CorruptedProof(
+                        "disjunctive proof branches have different root hashes".to_string(),
+                    )));
+                }
+                _ => {}
+            }
+            branch_results.push(documents);
+        }
+
+        let root_hash = root_hash.ok_or(Error::Proof(ProofError::IncompleteProof(
+            "disjunctive proof must have at least one branch",
+        )))?;
+
+        Ok((
+            root_hash,
+            self.merge_disjunctive_results(branch_results, platform_version)?,
+        ))
+    }
+
+    #[cfg(feature = "full")]
+    /// Executes every branch of a disjunctive query with a proof and returns the encoded proof.
+    pub(crate) fn execute_disjunctive_with_proof_internal(
+        &self,
+        drive: &Drive,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let branch_proofs = self
+            .disjunctive_branch_queries(platform_version)?
+            .into_iter()
+            .map(|branch_query| {
+                branch_query.execute_with_proof_internal(
+                    drive,
+                    transaction,
+                    drive_operations,
+                    platform_version,
+                )
+            })
+            .collect::<Result<Vec<Vec<u8>>, Error>>()?;
+        Ok(Self::encode_proof_sequence(branch_proofs))
+    }
+
+    #[cfg(feature = "full")]
+    /// Executes every branch of a disjunctive query without a proof and returns the merged
+    /// serialized documents and the number of skipped items.
+    pub(crate) fn execute_disjunctive_raw_results_no_proof_internal(
+        &self,
+        drive: &Drive,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<Vec<u8>>, u16), Error> {
+        let mut skipped: u16 = 0;
+        let branch_results = self
+            .disjunctive_branch_queries(platform_version)?
+            .iter()
+            .map(|branch_query| {
+                let (items, branch_skipped) = branch_query.execute_raw_results_no_proof_internal(
+                    drive,
+                    transaction,
+                    drive_operations,
+                    platform_version,
+                )?;
+                skipped = skipped.saturating_add(branch_skipped);
+                Ok(items)
+            })
+            .collect::<Result<Vec<Vec<Vec<u8>>>, Error>>()?;
+        Ok((
+            self.merge_disjunctive_results(branch_results, platform_version)?,
+            skipped,
+        ))
+    }
+}
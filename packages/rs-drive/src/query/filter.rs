@@ -0,0 +1,57 @@
+use crate::error::Error;
+use crate::query::{DriveQuery, InternalClauses};
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::Document;
+use dpp::version::PlatformVersion;
+
+impl InternalClauses {
+    /// Returns true if the document matches all the clauses, or one of the or clauses.
+    fn matches_document(
+        &self,
+        document: &Document,
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+    ) -> Result<bool, Error> {
+        if !self.or_clauses.is_empty() {
+            for branch in &self.or_clauses {
+                if branch.matches_document(document, document_type, platform_version)? {
+                    return Ok(true);
+                }
+            }
+            return Ok(false);
+        }
+
+        let clauses = self
+            .primary_key_in_clause
+            .iter()
+            .chain(self.primary_key_equal_clause.iter())
+            .chain(self.in_clause.iter())
+            .chain(self.range_clause.iter())
+            .chain(self.residual_range_clauses.iter())
+            .chain(self.equal_clauses.values())
+            .chain(self.contains_word_clause.iter());
+
+        for clause in clauses {
+            if !clause.matches_document(document, document_type, platform_version)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl<'a> DriveQuery<'a> {
+    /// Returns true if the document would be returned by the query, ignoring its limit, offset
+    /// and start.
+    ///
+    /// This is used to filter documents that were changed, without querying the index.
+    pub fn matches_document(
+        &self,
+        document: &Document,
+        platform_version: &PlatformVersion,
+    ) -> Result<bool, Error> {
+        self.internal_clauses
+            .matches_document(document, self.document_type, platform_version)
+    }
+}
@@ -113,17 +113,31 @@ use dpp::version::PlatformVersion;
 #[cfg(any(feature = "full", feature = "verify"))]
 use dpp::ProtocolError;
 
+#[cfg(any(feature = "full", feature = "verify"))]
+mod aggregate;
 #[cfg(any(feature = "full", feature = "verify"))]
 pub mod conditions;
 #[cfg(any(feature = "full", feature = "verify"))]
+mod contains_word;
+#[cfg(any(feature = "full", feature = "verify"))]
 mod defaults;
 #[cfg(any(feature = "full", feature = "verify"))]
+mod disjunction;
+#[cfg(any(feature = "full", feature = "verify"))]
+mod filter;
+#[cfg(any(feature = "full", feature = "verify"))]
+mod multikey;
+#[cfg(any(feature = "full", feature = "verify"))]
 pub mod ordering;
 #[cfg(any(feature = "full", feature = "verify"))]
+mod residual;
+#[cfg(any(feature = "full", feature = "verify"))]
 mod single_document_drive_query;
 #[cfg(feature = "full")]
 mod test_index;
 
+#[cfg(any(feature = "full", feature = "verify"))]
+pub use aggregate::AggregateFunction;
 #[cfg(any(feature = "full", feature = "verify"))]
 pub use single_document_drive_query::SingleDocumentDriveQuery;
 
@@ -143,14 +157,47 @@ pub struct InternalClauses {
     pub in_clause: Option<WhereClause>,
     /// Range clause
     pub range_clause: Option<WhereClause>,
+    /// Range clauses on other fields than the range clause, they are not part of the index query
+    /// and are instead used to filter its results
+    pub residual_range_clauses: Vec<WhereClause>,
     /// Equal clause
     pub equal_clauses: BTreeMap<String, WhereClause>,
+    /// Or clauses, when set the query is the union of these conjunctive branches and all other
+    /// clauses must be empty
+    pub or_clauses: Vec<InternalClauses>,
+    /// Contains word clause, when set the query is run on the tokenized index of its field and
+    /// all other clauses must be empty
+    pub contains_word_clause: Option<WhereClause>,
 }
 
 impl InternalClauses {
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Returns true if the clause is a valid format.
     pub fn verify(&self) -> bool {
+        if self.is_disjunctive() {
+            // All clauses have been distributed into the branches
+            return self.primary_key_in_clause.is_none()
+                && self.primary_key_equal_clause.is_none()
+                && self.in_clause.is_none()
+                && self.range_clause.is_none()
+                && self.residual_range_clauses.is_empty()
+                && self.equal_clauses.is_empty()
+                && self.contains_word_clause.is_none()
+                && self.or_clauses.iter().all(|branch| {
+                    !branch.is_disjunctive()
+                        && branch.contains_word_clause.is_none()
+                        && branch.verify()
+                });
+        }
+        if self.contains_word_clause.is_some() {
+            // The words of a tokenized index can not be combined with other index queries
+            return self.primary_key_in_clause.is_none()
+                && self.primary_key_equal_clause.is_none()
+                && self.in_clause.is_none()
+                && self.range_clause.is_none()
+                && self.residual_range_clauses.is_empty()
+                && self.equal_clauses.is_empty();
+        }
         // There can only be 1 primary key clause, or many other clauses
         if self
             .primary_key_in_clause
@@ -160,7 +207,11 @@ impl InternalClauses {
             // One is set, all rest must be empty
             !(self.in_clause.is_some()
                 || self.range_clause.is_some()
+                || !self.residual_range_clauses.is_empty()
                 || !self.equal_clauses.is_empty())
+        } else if !self.residual_range_clauses.is_empty() && self.range_clause.is_none() {
+            // Residual range clauses only filter the results of the range clause
+            false
         } else {
             !(self.primary_key_in_clause.is_some() && self.primary_key_equal_clause.is_some())
         }
@@ -172,19 +223,53 @@ impl InternalClauses {
         self.primary_key_in_clause.is_some() || self.primary_key_equal_clause.is_some()
     }
 
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns true if the query clause is for the words of a tokenized index.
+    pub fn is_for_contains_word(&self) -> bool {
+        self.contains_word_clause.is_some()
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns true if the query clause is a union of conjunctive branches.
+    pub fn is_disjunctive(&self) -> bool {
+        !self.or_clauses.is_empty()
+    }
+
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Returns true if self is empty.
     pub fn is_empty(&self) -> bool {
         self.in_clause.is_none()
             && self.range_clause.is_none()
+            && self.residual_range_clauses.is_empty()
             && self.equal_clauses.is_empty()
             && self.primary_key_in_clause.is_none()
             && self.primary_key_equal_clause.is_none()
+            && self.or_clauses.is_empty()
+            && self.contains_word_clause.is_none()
     }
 
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Extracts the `WhereClause`s and returns them as type `InternalClauses`.
     pub fn extract_from_clauses(all_where_clauses: Vec<WhereClause>) -> Result<Self, Error> {
+        let (contains_word_clauses, all_where_clauses): (Vec<WhereClause>, Vec<WhereClause>) =
+            all_where_clauses
+                .into_iter()
+                .partition(|where_clause| where_clause.operator == WhereOperator::ContainsWord);
+
+        let contains_word_clause = match contains_word_clauses.len() {
+            0 => Ok(None),
+            1 => Ok(Some(
+                contains_word_clauses
+                    .into_iter()
+                    .next()
+                    .expect("there must be a value")
+                    .into_contains_word_clause()?,
+            )),
+            _ => Err(Error::Query(QuerySyntaxError::InvalidContainsWordClause(
+                "There should only be one contains word clause",
+            ))),
+        }?;
+
         let primary_key_equal_clauses_array = all_where_clauses
             .iter()
             .filter_map(|where_clause| match where_clause.operator {
@@ -207,9 +292,14 @@ impl InternalClauses {
             })
             .collect::<Vec<WhereClause>>();
 
-        let (equal_clauses, range_clause, in_clause) =
+        let (equal_clauses, range_clauses, in_clause) =
             WhereClause::group_clauses(&all_where_clauses)?;
 
+        // The first range clause is used to choose the index, the others filter its results
+        let mut range_clauses = range_clauses.into_iter();
+        let range_clause = range_clauses.next();
+        let residual_range_clauses = range_clauses.collect();
+
         let primary_key_equal_clause = match primary_key_equal_clauses_array.len() {
             0 => Ok(None),
             1 => Ok(Some(
@@ -245,16 +335,130 @@ impl InternalClauses {
             primary_key_in_clause,
             in_clause,
             range_clause,
+            residual_range_clauses,
             equal_clauses,
+            or_clauses: vec![],
+            contains_word_clause,
         };
 
         match internal_clauses.verify() {
             true => Ok(internal_clauses),
+            false if internal_clauses.is_for_contains_word() => {
+                Err(Error::Query(QuerySyntaxError::InvalidContainsWordClause(
+                    "a contains word clause can not be combined with other where clauses",
+                )))
+            }
             false => Err(Error::Query(
                 QuerySyntaxError::InvalidWhereClauseComponents("Query has invalid where clauses"),
             )),
         }
     }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Extracts the `WhereClause`s that must all match and the disjunctive branches of which at
+    /// least one must match, and returns them as type `InternalClauses`.
+    ///
+    /// The common where clauses are distributed into every branch so that each branch can be
+    /// executed on its own index.
+    pub fn extract_from_disjunctive_clauses(
+        all_where_clauses: Vec<WhereClause>,
+        disjunctive_branches: Vec<Vec<WhereClause>>,
+    ) -> Result<Self, Error> {
+        match disjunctive_branches.len() {
+            0 => Self::extract_from_clauses(all_where_clauses),
+            1 => {
+                let mut where_clauses = all_where_clauses;
+                where_clauses.extend(disjunctive_branches.into_iter().flatten());
+                Self::extract_from_clauses(where_clauses)
+            }
+            _ => {
+                let or_clauses = disjunctive_branches
+                    .into_iter()
+                    .map(|branch| {
+                        let mut where_clauses = all_where_clauses.clone();
+                        where_clauses.extend(branch);
+                        Self::extract_from_clauses(where_clauses)
+                    })
+                    .collect::<Result<Vec<InternalClauses>, Error>>()?;
+
+                let internal_clauses = InternalClauses {
+                    or_clauses,
+                    ..Default::default()
+                };
+
+                match internal_clauses.verify() {
+                    true => Ok(internal_clauses),
+                    false => Err(Error::Query(QuerySyntaxError::InvalidOrClause(
+                        "Query has invalid or clauses",
+                    ))),
+                }
+            }
+        }
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Splits where clause values into the where clauses that must all match and the disjunctive
+    /// branches given as `{"or": [...]}` maps.
+    ///
+    /// Several or maps are combined by distributing them over each other.
+    pub fn where_clauses_from_values(
+        where_clause_values: &[Value],
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<WhereClause>, Vec<Vec<WhereClause>>), Error> {
+        let mut where_clauses = vec![];
+        let mut disjunctive_branches: Vec<Vec<WhereClause>> = vec![];
+        for where_clause in where_clause_values {
+            match where_clause {
+                Value::Array(clauses_components) => {
+                    where_clauses.push(WhereClause::from_components(clauses_components)?)
+                }
+                Value::Map(_) => {
+                    let branches = WhereClause::disjunctive_branches_from_value(
+                        where_clause,
+                        platform_version,
+                    )?;
+                    disjunctive_branches = if disjunctive_branches.is_empty() {
+                        branches
+                    } else {
+                        WhereClause::combine_disjunctive_branches(
+                            disjunctive_branches,
+                            branches,
+                            platform_version,
+                        )?
+                    };
+                }
+                _ => {
+                    return Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                        "where clause must be an array",
+                    )))
+                }
+            }
+        }
+        Ok((where_clauses, disjunctive_branches))
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns the where clauses as values, disjunctive branches are returned as a single
+    /// `{"or": [...]}` map.
+    pub fn to_where_clause_values(&self) -> Vec<Value> {
+        if self.is_disjunctive() {
+            let branches = self
+                .or_clauses
+                .iter()
+                .map(|branch| {
+                    let where_clauses: Vec<WhereClause> = branch.clone().into();
+                    Value::Array(where_clauses.into_iter().map(|v| v.into()).collect())
+                })
+                .collect();
+            vec![Value::Map(vec![(
+                Value::Text(conditions::OR_CLAUSE_KEY.to_string()),
+                Value::Array(branches),
+            )])]
+        } else {
+            let where_clauses: Vec<WhereClause> = self.clone().into();
+            where_clauses.into_iter().map(|v| v.into()).collect()
+        }
+    }
 }
 
 impl From<InternalClauses> for Vec<WhereClause> {
@@ -273,6 +477,10 @@ impl From<InternalClauses> for Vec<WhereClause> {
         if let Some(clause) = clauses.range_clause {
             result.push(clause);
         };
+        result.extend(clauses.residual_range_clauses);
+        if let Some(clause) = clauses.contains_word_clause {
+            result.push(clause);
+        };
 
         result
     }
@@ -347,6 +555,12 @@ impl<'a> DriveQuery<'a> {
         }
     }
 
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns true if the query is for the documents having a word.
+    pub fn is_for_contains_word(&self) -> bool {
+        self.internal_clauses.is_for_contains_word()
+    }
+
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Returns true if the query clause if for primary keys.
     pub fn is_for_primary_key(&self) -> bool {
@@ -371,13 +585,20 @@ impl<'a> DriveQuery<'a> {
         contract: &'a DataContract,
         document_type: DocumentTypeRef<'a>,
         config: &DriveConfig,
+        platform_version: &PlatformVersion,
     ) -> Result<Self, Error> {
         let query_document_value: Value = ciborium::de::from_reader(query_cbor).map_err(|_| {
             Error::Query(QuerySyntaxError::DeserializationError(
                 "unable to decode query from cbor".to_string(),
             ))
         })?;
-        Self::from_value(query_document_value, contract, document_type, config)
+        Self::from_value(
+            query_document_value,
+            contract,
+            document_type,
+            config,
+            platform_version,
+        )
     }
 
     #[cfg(any(feature = "full", feature = "verify"))]
@@ -387,9 +608,16 @@ impl<'a> DriveQuery<'a> {
         contract: &'a DataContract,
         document_type: DocumentTypeRef<'a>,
         config: &DriveConfig,
+        platform_version: &PlatformVersion,
     ) -> Result<Self, Error> {
         let query_document: BTreeMap<String, Value> = query_value.into_btree_string_map()?;
-        Self::from_btree_map_value(query_document, contract, document_type, config)
+        Self::from_btree_map_value(
+            query_document,
+            contract,
+            document_type,
+            config,
+            platform_version,
+        )
     }
 
     #[cfg(any(feature = "full", feature = "verify"))]
@@ -399,6 +627,7 @@ impl<'a> DriveQuery<'a> {
         contract: &'a DataContract,
         document_type: DocumentTypeRef<'a>,
         config: &DriveConfig,
+        platform_version: &PlatformVersion,
     ) -> Result<Self, Error> {
         if let Some(contract_id) = query_document
             .remove_optional_identifier("contract_id")
@@ -453,23 +682,15 @@ impl<'a> DriveQuery<'a> {
             .remove_optional_integer("blockTime")
             .map_err(|e| Error::Protocol(ProtocolError::ValueError(e)))?;
 
-        let all_where_clauses: Vec<WhereClause> =
+        let (all_where_clauses, disjunctive_branches) =
             query_document
                 .remove("where")
-                .map_or(Ok(vec![]), |id_cbor| {
+                .map_or(Ok((vec![], vec![])), |id_cbor| {
                     if let Value::Array(clauses) = id_cbor {
-                        clauses
-                            .iter()
-                            .map(|where_clause| {
-                                if let Value::Array(clauses_components) = where_clause {
-                                    WhereClause::from_components(clauses_components)
-                                } else {
-                                    Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
-                                        "where clause must be an array",
-                                    )))
-                                }
-                            })
-                            .collect::<Result<Vec<WhereClause>, Error>>()
+                        InternalClauses::where_clauses_from_values(
+                            clauses.as_slice(),
+                            platform_version,
+                        )
                     } else {
                         Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
                             "where clause must be an array",
@@ -477,7 +698,11 @@ impl<'a> DriveQuery<'a> {
                     }
                 })?;
 
-        let internal_clauses = InternalClauses::extract_from_clauses(all_where_clauses)?;
+        let internal_clauses = InternalClauses::extract_from_disjunctive_clauses(
+            all_where_clauses,
+            disjunctive_branches,
+        )?
+        .split_array_in_clauses(document_type, platform_version)?;
 
         let start_at_option = query_document.remove("startAt");
         let start_after_option = query_document.remove("startAfter");
@@ -569,6 +794,7 @@ impl<'a> DriveQuery<'a> {
         contract: &'a DataContract,
         document_type: DocumentTypeRef<'a>,
         config: &DriveConfig,
+        platform_version: &PlatformVersion,
     ) -> Result<Self, Error> {
         let limit = maybe_limit
             .map_or(Some(config.default_query_limit), |limit_value| {
@@ -583,26 +809,21 @@ impl<'a> DriveQuery<'a> {
                 config.max_query_limit
             ))))?;
 
-        let all_where_clauses: Vec<WhereClause> = match where_clause {
-            Value::Null => Ok(vec![]),
-            Value::Array(clauses) => clauses
-                .iter()
-                .map(|where_clause| {
-                    if let Value::Array(clauses_components) = where_clause {
-                        WhereClause::from_components(clauses_components)
-                    } else {
-                        Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
-                            "where clause must be an array",
-                        )))
-                    }
-                })
-                .collect::<Result<Vec<WhereClause>, Error>>(),
+        let (all_where_clauses, disjunctive_branches) = match where_clause {
+            Value::Null => Ok((vec![], vec![])),
+            Value::Array(clauses) => {
+                InternalClauses::where_clauses_from_values(clauses.as_slice(), platform_version)
+            }
             _ => Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
                 "where clause must be an array",
             ))),
         }?;
 
-        let internal_clauses = InternalClauses::extract_from_clauses(all_where_clauses)?;
+        let internal_clauses = InternalClauses::extract_from_disjunctive_clauses(
+            all_where_clauses,
+            disjunctive_branches,
+        )?
+        .split_array_in_clauses(document_type, platform_version)?;
 
         let order_by: IndexMap<String, OrderClause> = order_by
             .map_or(vec![], |id_cbor| {
@@ -644,6 +865,7 @@ impl<'a> DriveQuery<'a> {
         sql_string: &str,
         contract: &'a DataContract,
         config: Option<&DriveConfig>,
+        platform_version: &PlatformVersion,
     ) -> Result<Self, Error> {
         let dialect: MySqlDialect = MySqlDialect {};
         let statements: Vec<Statement> = Parser::parse_sql(&dialect, sql_string)
@@ -752,19 +974,23 @@ impl<'a> DriveQuery<'a> {
         // i.e other where clauses
         // e.g. firstname = wisdom and lastname = ogwu
         // if op is not [and] then [left] or [right] must not be a binary operation or list description
-        let mut all_where_clauses: Vec<WhereClause> = Vec::new();
+        // [or] splits the where clauses into branches, [and] is distributed over them
+        // e.g. a = 1 and (b = 2 or c = 3) becomes (a = 1 and b = 2) or (a = 1 and c = 3)
+        let mut disjunctive_branches: Vec<Vec<WhereClause>> = Vec::new();
         let selection_tree = select.selection.as_ref();
 
         // Where clauses are optional
         if let Some(selection_tree) = selection_tree {
-            WhereClause::build_where_clauses_from_operations(
+            disjunctive_branches = WhereClause::build_disjunctive_where_clauses_from_operations(
                 selection_tree,
                 document_type,
-                &mut all_where_clauses,
+                platform_version,
             )?;
         }
 
-        let internal_clauses = InternalClauses::extract_from_clauses(all_where_clauses)?;
+        let internal_clauses =
+            InternalClauses::extract_from_disjunctive_clauses(vec![], disjunctive_branches)?
+                .split_array_in_clauses(document_type, platform_version)?;
 
         let start_at_option = None; //todo
         let start_after_option = None; //todo
@@ -854,6 +1080,17 @@ impl<'a> DriveQuery<'a> {
         drive_operations: &mut Vec<LowLevelDriveOperation>,
         platform_version: &PlatformVersion,
     ) -> Result<PathQuery, Error> {
+        if self.is_disjunctive() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "queries with or clauses are executed as one path query per branch".to_string(),
+            )));
+        }
+        if self.has_residual_range_clauses() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "queries with range clauses on several fields are executed as one path query per page"
+                    .to_string(),
+            )));
+        }
         let drive_version = &platform_version.drive;
         // First we should get the overall document_type_path
         let document_type_path = self
@@ -921,6 +1158,8 @@ impl<'a> DriveQuery<'a> {
                 starts_at_document,
                 platform_version,
             )
+        } else if self.is_for_contains_word() {
+            self.get_contains_word_path_query(document_type_path, starts_at_document)
         } else {
             self.get_non_primary_key_path_query(
                 document_type_path,
@@ -950,6 +1189,17 @@ impl<'a> DriveQuery<'a> {
         starts_at_document: Option<Document>,
         platform_version: &PlatformVersion,
     ) -> Result<PathQuery, Error> {
+        if self.is_disjunctive() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "queries with or clauses are executed as one path query per branch".to_string(),
+            )));
+        }
+        if self.has_residual_range_clauses() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "queries with range clauses on several fields are executed as one path query per page"
+                    .to_string(),
+            )));
+        }
         // First we should get the overall document_type_path
         let document_type_path = self
             .contract
@@ -965,6 +1215,8 @@ impl<'a> DriveQuery<'a> {
                 starts_at_document,
                 platform_version,
             )
+        } else if self.is_for_contains_word() {
+            self.get_contains_word_path_query(document_type_path, starts_at_document)
         } else {
             self.get_non_primary_key_path_query(
                 document_type_path,
@@ -1200,6 +1452,18 @@ impl<'a> DriveQuery<'a> {
                 "query must better match an existing index",
             )));
         }
+        // walking the items of an array would return a document once for each of its items
+        if index.properties.iter().any(|index_property| {
+            multikey::is_array_field(self.document_type, &index_property.name)
+                && !self
+                    .internal_clauses
+                    .equal_clauses
+                    .contains_key(&index_property.name)
+        }) {
+            return Err(Error::Query(QuerySyntaxError::InvalidArrayClause(
+                "the array property of a multikey index must be queried with an equal clause",
+            )));
+        }
         Ok(index)
     }
 
@@ -1691,6 +1955,27 @@ impl<'a> DriveQuery<'a> {
         drive_operations: &mut Vec<LowLevelDriveOperation>,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<u8>, Error> {
+        if self.is_disjunctive() {
+            return self.execute_disjunctive_with_proof_internal(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            );
+        }
+        if self.has_residual_range_clauses() {
+            #[cfg(feature = "verify")]
+            return self.execute_residual_with_proof_internal(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            );
+            #[cfg(not(feature = "verify"))]
+            return Err(Error::Query(QuerySyntaxError::MultipleRangeClauses(
+                "proving queries with range clauses on several fields requires the verify feature",
+            )));
+        }
         let path_query = self.construct_path_query_operations(
             drive,
             true,
@@ -1747,6 +2032,24 @@ impl<'a> DriveQuery<'a> {
         drive_operations: &mut Vec<LowLevelDriveOperation>,
         platform_version: &PlatformVersion,
     ) -> Result<([u8; 32], Vec<Vec<u8>>), Error> {
+        if self.is_disjunctive() {
+            let proof = self.execute_disjunctive_with_proof_internal(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            )?;
+            return self.verify_proof_keep_serialized(proof.as_slice(), platform_version);
+        }
+        if self.has_residual_range_clauses() {
+            let proof = self.execute_residual_with_proof_internal(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            )?;
+            return self.verify_proof_keep_serialized(proof.as_slice(), platform_version);
+        }
         let path_query = self.construct_path_query_operations(
             drive,
             true,
@@ -1830,6 +2133,22 @@ impl<'a> DriveQuery<'a> {
         drive_operations: &mut Vec<LowLevelDriveOperation>,
         platform_version: &PlatformVersion,
     ) -> Result<(Vec<Vec<u8>>, u16), Error> {
+        if self.is_disjunctive() {
+            return self.execute_disjunctive_raw_results_no_proof_internal(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            );
+        }
+        if self.has_residual_range_clauses() {
+            return self.execute_residual_raw_results_no_proof_internal(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            );
+        }
         let path_query = self.construct_path_query_operations(
             drive,
             false,
@@ -1866,6 +2185,17 @@ impl<'a> DriveQuery<'a> {
         drive_operations: &mut Vec<LowLevelDriveOperation>,
         platform_version: &PlatformVersion,
     ) -> Result<(QueryResultElements, u16), Error> {
+        if self.is_disjunctive() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "queries with or clauses can only return documents".to_string(),
+            )));
+        }
+        if self.has_residual_range_clauses() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "queries with range clauses on several fields can only return documents"
+                    .to_string(),
+            )));
+        }
         let path_query = self.construct_path_query_operations(
             drive,
             false,
@@ -1916,10 +2246,9 @@ impl<'a> From<&DriveQuery<'a>> for BTreeMap<String, Value> {
         );
 
         // Internal clauses
-        let all_where_clauses: Vec<WhereClause> = query.internal_clauses.clone().into();
         response.insert(
             "where".to_string(),
-            Value::Array(all_where_clauses.into_iter().map(|v| v.into()).collect()),
+            Value::Array(query.internal_clauses.to_where_clause_values()),
         );
 
         // Offset
@@ -2046,6 +2375,7 @@ mod tests {
 
     #[test]
     fn test_drive_query_from_to_cbor() {
+        let platform_version = PlatformVersion::latest();
         let config = DriveConfig::default();
         let contract = get_data_contract_fixture(None, 0, 1).data_contract_owned();
         let document_type = contract
@@ -2072,13 +2402,20 @@ mod tests {
 
         let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
             .expect("expected to serialize to cbor");
-        let query = DriveQuery::from_cbor(where_cbor.as_slice(), &contract, document_type, &config)
-            .expect("deserialize cbor shouldn't fail");
+        let query = DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            document_type,
+            &config,
+            platform_version,
+        )
+        .expect("deserialize cbor shouldn't fail");
 
         let cbor = query.to_cbor().expect("should serialize cbor");
 
-        let deserialized = DriveQuery::from_cbor(&cbor, &contract, document_type, &config)
-            .expect("should deserialize cbor");
+        let deserialized =
+            DriveQuery::from_cbor(&cbor, &contract, document_type, &config, platform_version)
+                .expect("should deserialize cbor");
 
         assert_eq!(query, deserialized);
 
@@ -2088,7 +2425,8 @@ mod tests {
     }
 
     #[test]
-    fn test_invalid_query_ranges_different_fields() {
+    fn test_query_ranges_different_fields_filter_on_residual_clauses() {
+        let platform_version = PlatformVersion::latest();
         let query_value = json!({
             "where": [
                 ["firstName", "<", "Gilligan"],
@@ -2107,17 +2445,33 @@ mod tests {
 
         let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
             .expect("expected to serialize to cbor");
-        DriveQuery::from_cbor(
+        let query = DriveQuery::from_cbor(
             where_cbor.as_slice(),
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
-        .expect_err("all ranges must be on same field");
+        .expect("ranges on different fields should be allowed");
+
+        let range_clause = query
+            .internal_clauses
+            .range_clause
+            .as_ref()
+            .expect("expected a range clause");
+        assert_eq!(range_clause.field, "firstName");
+        let residual_fields = query
+            .internal_clauses
+            .residual_range_clauses
+            .iter()
+            .map(|where_clause| where_clause.field.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(residual_fields, vec!["lastName"]);
     }
 
     #[test]
     fn test_invalid_query_extra_invalid_field() {
+        let platform_version = PlatformVersion::latest();
         let query_value = json!({
             "where": [
                 ["firstName", "<", "Gilligan"],
@@ -2141,12 +2495,14 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect_err("fields of queries must of defined supported types (where, limit, orderBy...)");
     }
 
     #[test]
     fn test_invalid_query_conflicting_clauses() {
+        let platform_version = PlatformVersion::latest();
         let query_value = json!({
             "where": [
                 ["firstName", "<", "Gilligan"],
@@ -2171,12 +2527,14 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect_err("the query should not be created");
     }
 
     #[test]
     fn test_valid_query_groupable_meeting_clauses() {
+        let platform_version = PlatformVersion::latest();
         let query_value = json!({
             "where": [
                 ["firstName", "<=", "Gilligan"],
@@ -2201,12 +2559,14 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect("the query should be created");
     }
 
     #[test]
     fn test_valid_query_query_field_at_max_length() {
+        let platform_version = PlatformVersion::latest();
         let long_string = "t".repeat(255);
         let query_value = json!({
             "where": [
@@ -2230,6 +2590,7 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect("query should be fine for a 255 byte long string");
     }
@@ -2263,6 +2624,7 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect("fields of queries length must be under 256 bytes long");
         query
@@ -2290,7 +2652,7 @@ mod tests {
     //     });
     //
     //     let where_cbor = serializer::value_to_cbor(query_value, None).expect("expected to serialize to cbor");
-    //     let query = DriveQuery::from_cbor(where_cbor.as_slice(), &contract, document_type, &DriveConfig::default())
+    //     let query = DriveQuery::from_cbor(where_cbor.as_slice(), &contract, document_type, &DriveConfig::default(), platform_version)
     //         .expect("The query itself should be valid for a null type");
     //     query
     //         .execute_no_proof(&drive, None, None)
@@ -2318,7 +2680,7 @@ mod tests {
     //     });
     //
     //     let where_cbor = serializer::value_to_cbor(query_value, None).expect("expected to serialize to cbor");
-    //     let query = DriveQuery::from_cbor(where_cbor.as_slice(), &contract, document_type, &DriveConfig::default())
+    //     let query = DriveQuery::from_cbor(where_cbor.as_slice(), &contract, document_type, &DriveConfig::default(), platform_version)
     //         .expect("The query itself should be valid for a null type");
     //     query
     //         .execute_no_proof(&drive, None, None)
@@ -2352,6 +2714,7 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect("The query itself should be valid for a null type");
         query
@@ -2386,6 +2749,7 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect("query should be valid for empty array");
 
@@ -2425,6 +2789,7 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect("query is valid for too many elements");
 
@@ -2464,6 +2829,7 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect("the query should be created");
 
@@ -2474,6 +2840,7 @@ mod tests {
 
     #[test]
     fn test_invalid_query_starts_with_empty_string() {
+        let platform_version = PlatformVersion::latest();
         let query_value = json!({
             "where": [
                 ["firstName", "startsWith", ""],
@@ -2496,12 +2863,14 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect_err("starts with can not start with an empty string");
     }
 
     #[test]
     fn test_invalid_query_limit_too_high() {
+        let platform_version = PlatformVersion::latest();
         let query_value = json!({
             "where": [
                 ["firstName", "startsWith", "a"],
@@ -2524,12 +2893,14 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect_err("starts with can not start with an empty string");
     }
 
     #[test]
     fn test_invalid_query_limit_too_low() {
+        let platform_version = PlatformVersion::latest();
         let query_value = json!({
             "where": [
                 ["firstName", "startsWith", "a"],
@@ -2552,12 +2923,14 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect_err("starts with can not start with an empty string");
     }
 
     #[test]
     fn test_invalid_query_limit_zero() {
+        let platform_version = PlatformVersion::latest();
         let query_value = json!({
             "where": [
                 ["firstName", "startsWith", "a"],
@@ -2580,6 +2953,7 @@ mod tests {
             &contract,
             document_type,
             &DriveConfig::default(),
+            platform_version,
         )
         .expect_err("starts with can not start with an empty string");
     }
@@ -0,0 +1,116 @@
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use crate::query::{InternalClauses, WhereClause, WhereOperator};
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::{DocumentPropertyType, DocumentTypeRef};
+use dpp::version::PlatformVersion;
+
+/// Returns true if the field is an array property of the document type, such a property is
+/// indexed by its items.
+pub(crate) fn is_array_field(document_type: DocumentTypeRef, field: &str) -> bool {
+    document_type
+        .flattened_properties()
+        .get(field)
+        .is_some_and(|property| matches!(property.property_type, DocumentPropertyType::Array(_)))
+}
+
+fn too_many_branches_error() -> Error {
+    Error::Query(QuerySyntaxError::InvalidOrClause(
+        "in clauses on array properties expand to too many branches",
+    ))
+}
+
+impl InternalClauses {
+    /// Rewrites an in clause on an array property as a union of equal clauses.
+    ///
+    /// A document is stored in a multikey index once for every item of its array, so an in
+    /// clause walking this index would return a document once for every value it contains. Each
+    /// value instead gets its own disjunctive branch and the results of the branches are merged.
+    ///
+    /// The expansion is rejected as soon as it would produce more than the versioned maximum of
+    /// disjunctive branches, so that a query can never be turned into an unbounded number of
+    /// branches.
+    pub fn split_array_in_clauses(
+        self,
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+    ) -> Result<Self, Error> {
+        let max_disjunctive_branches = platform_version
+            .drive
+            .methods
+            .document
+            .query
+            .max_disjunctive_branches as usize;
+        if self.is_disjunctive() {
+            let mut or_clauses = vec![];
+            for branch in self.or_clauses {
+                let branch = branch.split_array_in_clauses(document_type, platform_version)?;
+                if branch.is_disjunctive() {
+                    or_clauses.extend(branch.or_clauses);
+                } else {
+                    or_clauses.push(branch);
+                }
+                if or_clauses.len() > max_disjunctive_branches {
+                    return Err(too_many_branches_error());
+                }
+            }
+            return Ok(InternalClauses {
+                or_clauses,
+                ..Default::default()
+            });
+        }
+
+        if self
+            .range_clause
+            .iter()
+            .chain(self.residual_range_clauses.iter())
+            .any(|range_clause| is_array_field(document_type, &range_clause.field))
+        {
+            return Err(Error::Query(QuerySyntaxError::InvalidArrayClause(
+                "array properties can only be queried with equal and in clauses",
+            )));
+        }
+
+        let Some(in_clause) = self
+            .in_clause
+            .as_ref()
+            .filter(|in_clause| is_array_field(document_type, &in_clause.field))
+        else {
+            return Ok(self);
+        };
+
+        let in_values = in_clause.in_values()?;
+        if in_values.len() > max_disjunctive_branches {
+            return Err(too_many_branches_error());
+        }
+
+        let mut branches = in_values
+            .iter()
+            .map(|value| {
+                let mut equal_clauses = self.equal_clauses.clone();
+                equal_clauses.insert(
+                    in_clause.field.clone(),
+                    WhereClause {
+                        field: in_clause.field.clone(),
+                        operator: WhereOperator::Equal,
+                        value: value.clone(),
+                    },
+                );
+                InternalClauses {
+                    in_clause: None,
+                    equal_clauses,
+                    ..self.clone()
+                }
+            })
+            .collect::<Vec<InternalClauses>>();
+
+        if branches.len() == 1 {
+            return Ok(branches.remove(0));
+        }
+
+        Ok(InternalClauses {
+            or_clauses: branches,
+            ..Default::default()
+        })
+    }
+}
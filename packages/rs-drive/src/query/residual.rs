@@ -0,0 +1,257 @@
+#[cfg(feature = "verify")]
+use crate::drive::verify::RootHash;
+#[cfg(feature = "full")]
+use crate::drive::Drive;
+#[cfg(feature = "verify")]
+use crate::error::proof::ProofError;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+#[cfg(feature = "full")]
+use crate::fee::op::LowLevelDriveOperation;
+use crate::query::defaults::MAX_RESIDUAL_FILTER_PAGES;
+use crate::query::{DriveQuery, InternalClauses};
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::version::PlatformVersion;
+#[cfg(feature = "full")]
+use grovedb::TransactionArg;
+
+/// The documents of a page of the index query that match the residual range clauses, and
+/// where the next page starts if there is one.
+struct FilteredPage {
+    documents: Vec<Vec<u8>>,
+    next_start_at: Option<[u8; 32]>,
+}
+
+impl<'a> DriveQuery<'a> {
+    /// Returns true if some range clauses are not part of the index query and instead filter
+    /// its results.
+    pub fn has_residual_range_clauses(&self) -> bool {
+        !self.internal_clauses.residual_range_clauses.is_empty()
+    }
+
+    /// Returns the query on the index of the range clause, without the residual range clauses.
+    ///
+    /// The first page starts where the query starts, the following pages start after the last
+    /// document of the previous page.
+    fn residual_page_query(&self, start_after: Option<[u8; 32]>) -> Result<DriveQuery<'a>, Error> {
+        if self.offset.is_some() {
+            return Err(Error::Query(QuerySyntaxError::MultipleRangeClauses(
+                "offset is not supported for queries with range clauses on several fields",
+            )));
+        }
+        let mut page_query = DriveQuery {
+            internal_clauses: InternalClauses {
+                residual_range_clauses: vec![],
+                ..self.internal_clauses.clone()
+            },
+            ..self.clone()
+        };
+        if let Some(start_after) = start_after {
+            page_query.start_at = Some(start_after);
+            page_query.start_at_included = false;
+        }
+        Ok(page_query)
+    }
+
+    /// Filters the serialized documents of a page by the residual range clauses.
+    ///
+    /// A full page means the index may hold more documents, the next page then starts after the
+    /// last document of this one.
+    fn filter_residual_page(
+        &self,
+        serialized_documents: Vec<Vec<u8>>,
+        platform_version: &PlatformVersion,
+    ) -> Result<FilteredPage, Error> {
+        let is_full_page = self
+            .limit
+            .map(|limit| limit > 0 && serialized_documents.len() >= limit as usize)
+            .unwrap_or(false);
+        let mut last_id = None;
+        let mut documents = vec![];
+        for serialized in serialized_documents {
+            let document =
+                Document::from_bytes(serialized.as_slice(), self.document_type, platform_version)?;
+            last_id = Some(document.id().to_buffer());
+            let mut matches = true;
+            for where_clause in &self.internal_clauses.residual_range_clauses {
+                if !where_clause.matches_document(
+                    &document,
+                    self.document_type,
+                    platform_version,
+                )? {
+                    matches = false;
+                    break;
+                }
+            }
+            if matches {
+                documents.push(serialized);
+            }
+        }
+        Ok(FilteredPage {
+            documents,
+            next_start_at: if is_full_page { last_id } else { None },
+        })
+    }
+
+    /// Returns true if no more pages are needed after having gathered `result_count` documents.
+    fn residual_results_are_complete(&self, result_count: usize) -> bool {
+        self.limit
+            .map(|limit| result_count >= limit as usize)
+            .unwrap_or(false)
+    }
+
+    /// The error returned when the index query still has documents after the maximum number of
+    /// pages without enough of them matching the residual range clauses.
+    ///
+    /// Returning the documents found so far would look like a complete result, so the query is
+    /// rejected instead.
+    fn residual_filter_pages_exceeded_error() -> Error {
+        Error::Query(QuerySyntaxError::ResidualRangeFilterPagesExceeded(
+            "too many documents are filtered out by range clauses on several fields, the query should be narrowed",
+        ))
+    }
+
+    /// Truncates the filtered documents to the limit of the query.
+    fn truncate_residual_results(&self, mut documents: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+        if let Some(limit) = self.limit {
+            documents.truncate(limit as usize);
+        }
+        documents
+    }
+
+    #[cfg(feature = "verify")]
+    /// Verifies the proof of a query with residual range clauses and returns the root hash and
+    /// the serialized documents matching all range clauses.
+    ///
+    /// The proof is a sequence of proofs of the pages of the index query, pages are replayed
+    /// the same way they were executed so a proof with missing or extra pages is rejected.
+    pub(crate) fn verify_residual_proof_keep_serialized(
+        &self,
+        proof: &[u8],
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<Vec<u8>>), Error> {
+        let page_proofs = Self::decode_proof_sequence(proof)?;
+        if page_proofs.len() > MAX_RESIDUAL_FILTER_PAGES {
+            return Err(Error::Proof(ProofError::TooManyElements(
+                "proof has more pages than allowed for residual range clauses",
+            )));
+        }
+
+        let mut root_hash: Option<RootHash> = None;
+        let mut documents = vec![];
+        let mut start_after = None;
+        let page_count = page_proofs.len();
+        for (page, page_proof) in page_proofs.into_iter().enumerate() {
+            let page_query = self.residual_page_query(start_after)?;
+            let (page_root_hash, page_documents) =
+                page_query.verify_proof_keep_serialized(page_proof, platform_version)?;
+            match root_hash {
+                None => root_hash = Some(page_root_hash),
+                Some(root_hash) if root_hash != page_root_hash => {
+                    return Err(Error::Proof(ProofError::CorruptedProof(
+                        "residual range proof pages have different root hashes".to_string(),
+                    )));
+                }
+                _ => {}
+            }
+            let filtered_page = self.filter_residual_page(page_documents, platform_version)?;
+            documents.extend(filtered_page.documents);
+
+            // Execution fails rather than returning a proof whose last allowed page still needs
+            // a next page, so such a proof is incomplete
+            let is_last_page = page + 1 == page_count;
+            let needs_next_page = filtered_page.next_start_at.is_some()
+                && !self.residual_results_are_complete(documents.len());
+            if needs_next_page && is_last_page {
+                return Err(Error::Proof(ProofError::IncompleteProof(
+                    "residual range proof is missing pages",
+                )));
+            } else if !needs_next_page && !is_last_page {
+                return Err(Error::Proof(ProofError::TooManyElements(
+                    "residual range proof has extra pages",
+                )));
+            }
+            start_after = filtered_page.next_start_at;
+        }
+
+        let root_hash = root_hash.ok_or(Error::Proof(ProofError::IncompleteProof(
+            "residual range proof must have at least one page",
+        )))?;
+
+        Ok((root_hash, self.truncate_residual_results(documents)))
+    }
+
+    #[cfg(all(feature = "full", feature = "verify"))]
+    /// Executes the pages of the index query with proofs until enough documents match the
+    /// residual range clauses, and returns the encoded proof.
+    ///
+    /// The documents of each page are read from its proof, so pages are only fetched once.
+    pub(crate) fn execute_residual_with_proof_internal(
+        &self,
+        drive: &Drive,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let mut page_proofs = vec![];
+        let mut result_count = 0;
+        let mut start_after = None;
+        for _ in 0..MAX_RESIDUAL_FILTER_PAGES {
+            let page_query = self.residual_page_query(start_after)?;
+            let page_proof = page_query.clone().execute_with_proof_internal(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            )?;
+            let (_, page_documents) =
+                page_query.verify_proof_keep_serialized(page_proof.as_slice(), platform_version)?;
+            page_proofs.push(page_proof);
+            let filtered_page = self.filter_residual_page(page_documents, platform_version)?;
+            result_count += filtered_page.documents.len();
+            if filtered_page.next_start_at.is_none()
+                || self.residual_results_are_complete(result_count)
+            {
+                return Ok(Self::encode_proof_sequence(page_proofs));
+            }
+            start_after = filtered_page.next_start_at;
+        }
+        Err(Self::residual_filter_pages_exceeded_error())
+    }
+
+    #[cfg(feature = "full")]
+    /// Executes the pages of the index query without proofs until enough documents match the
+    /// residual range clauses, and returns the serialized documents and the number of skipped
+    /// items.
+    pub(crate) fn execute_residual_raw_results_no_proof_internal(
+        &self,
+        drive: &Drive,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<Vec<u8>>, u16), Error> {
+        let mut documents = vec![];
+        let mut skipped: u16 = 0;
+        let mut start_after = None;
+        for _ in 0..MAX_RESIDUAL_FILTER_PAGES {
+            let page_query = self.residual_page_query(start_after)?;
+            let (page_documents, page_skipped) = page_query.execute_raw_results_no_proof_internal(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            )?;
+            skipped = skipped.saturating_add(page_skipped);
+            let filtered_page = self.filter_residual_page(page_documents, platform_version)?;
+            documents.extend(filtered_page.documents);
+            if filtered_page.next_start_at.is_none()
+                || self.residual_results_are_complete(documents.len())
+            {
+                return Ok((self.truncate_residual_results(documents), skipped));
+            }
+            start_after = filtered_page.next_start_at;
+        }
+        Err(Self::residual_filter_pages_exceeded_error())
+    }
+}
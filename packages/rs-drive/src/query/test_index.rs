@@ -110,6 +110,7 @@ mod tests {
             &contract,
             document_type.as_ref(),
             &DriveConfig::default(),
+            platform_version,
         )
         .expect("query should be valid");
         let index = query
@@ -129,6 +130,7 @@ mod tests {
             &contract,
             document_type.as_ref(),
             &DriveConfig::default(),
+            platform_version,
         )
         .expect("query should be valid");
         let index = query
@@ -156,6 +158,7 @@ mod tests {
             &contract,
             document_type.as_ref(),
             &DriveConfig::default(),
+            platform_version,
         )
         .expect("query should be valid");
         let error = query
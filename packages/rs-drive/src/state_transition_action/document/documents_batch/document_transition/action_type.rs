@@ -9,6 +9,11 @@ impl TransitionActionTypeGetter for DocumentTransitionAction {
             DocumentTransitionAction::CreateAction(_) => DocumentTransitionActionType::Create,
             DocumentTransitionAction::DeleteAction(_) => DocumentTransitionActionType::Delete,
             DocumentTransitionAction::ReplaceAction(_) => DocumentTransitionActionType::Replace,
+            DocumentTransitionAction::TransferAction(_) => DocumentTransitionActionType::Transfer,
+            DocumentTransitionAction::UpdatePriceAction(_) => {
+                DocumentTransitionActionType::UpdatePrice
+            }
+            DocumentTransitionAction::PurchaseAction(_) => DocumentTransitionActionType::Purchase,
             DocumentTransitionAction::BumpIdentityDataContractNonce(_) => {
                 DocumentTransitionActionType::IgnoreWhileBumpingRevision
             }
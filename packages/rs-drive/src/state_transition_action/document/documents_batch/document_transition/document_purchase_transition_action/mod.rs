@@ -0,0 +1,58 @@
+use derive_more::From;
+
+use dpp::document::Document;
+use dpp::fee::Credits;
+use dpp::platform_value::Identifier;
+
+/// transformer
+pub mod transformer;
+/// v0
+pub mod v0;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+use crate::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::v0::{DocumentPurchaseTransitionActionAccessorsV0, DocumentPurchaseTransitionActionV0};
+
+/// document purchase transition action
+#[derive(Debug, Clone, From)]
+pub enum DocumentPurchaseTransitionAction {
+    /// v0
+    V0(DocumentPurchaseTransitionActionV0),
+}
+
+impl DocumentPurchaseTransitionActionAccessorsV0 for DocumentPurchaseTransitionAction {
+    fn base(&self) -> &DocumentBaseTransitionAction {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> DocumentBaseTransitionAction {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn document(&self) -> &Document {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => &v0.document,
+        }
+    }
+
+    fn document_owned(self) -> Document {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => v0.document,
+        }
+    }
+
+    fn original_owner_id(&self) -> Identifier {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => v0.original_owner_id,
+        }
+    }
+
+    fn price(&self) -> Credits {
+        match self {
+            DocumentPurchaseTransitionAction::V0(v0) => v0.price,
+        }
+    }
+}
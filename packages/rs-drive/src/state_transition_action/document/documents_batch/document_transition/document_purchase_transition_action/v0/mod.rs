@@ -0,0 +1,37 @@
+/// transformer
+pub mod transformer;
+
+use dpp::document::Document;
+use dpp::fee::Credits;
+use dpp::platform_value::Identifier;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+#[derive(Debug, Clone)]
+/// document purchase transition action v0
+pub struct DocumentPurchaseTransitionActionV0 {
+    /// base
+    pub base: DocumentBaseTransitionAction,
+    /// The document as it will be stored after the purchase, owned by the buyer
+    pub document: Document,
+    /// The identity that owned the document before the purchase and receives the price
+    pub original_owner_id: Identifier,
+    /// The price paid by the buyer
+    pub price: Credits,
+}
+
+/// document purchase transition action accessors v0
+pub trait DocumentPurchaseTransitionActionAccessorsV0 {
+    /// base
+    fn base(&self) -> &DocumentBaseTransitionAction;
+    /// base owned
+    fn base_owned(self) -> DocumentBaseTransitionAction;
+    /// the purchased document, with the buyer as owner
+    fn document(&self) -> &Document;
+    /// the purchased document owned
+    fn document_owned(self) -> Document;
+    /// the owner of the document before the purchase
+    fn original_owner_id(&self) -> Identifier;
+    /// the price paid by the buyer
+    fn price(&self) -> Credits;
+}
@@ -0,0 +1,40 @@
+use dpp::document::{property_names, Document, DocumentV0Getters, DocumentV0Setters};
+use dpp::platform_value::Identifier;
+use std::sync::Arc;
+
+use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::document_purchase_transition::DocumentPurchaseTransitionV0;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+use crate::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::v0::DocumentPurchaseTransitionActionV0;
+
+impl DocumentPurchaseTransitionActionV0 {
+    /// try from borrowed
+    pub fn try_from_borrowed_document_purchase_transition(
+        document_purchase_transition: &DocumentPurchaseTransitionV0,
+        original_document: Document,
+        purchaser_id: Identifier,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        let DocumentPurchaseTransitionV0 {
+            base,
+            revision,
+            price,
+        } = document_purchase_transition;
+        let original_owner_id = original_document.owner_id();
+        let mut document = original_document;
+        document.set_owner_id(purchaser_id);
+        // Once bought the document is no longer for sale
+        document.properties_mut().remove(property_names::PRICE);
+        document.set_revision(Some(*revision));
+        Ok(DocumentPurchaseTransitionActionV0 {
+            base: DocumentBaseTransitionAction::from_borrowed_base_transition_with_contract_lookup(
+                base,
+                get_data_contract,
+            )?,
+            document,
+            original_owner_id,
+            price: *price,
+        })
+    }
+}
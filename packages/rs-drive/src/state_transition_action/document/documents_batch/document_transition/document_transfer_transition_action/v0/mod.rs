@@ -0,0 +1,33 @@
+/// transformer
+pub mod transformer;
+
+use dpp::document::Document;
+use dpp::platform_value::Identifier;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+#[derive(Debug, Clone)]
+/// document transfer transition action v0
+pub struct DocumentTransferTransitionActionV0 {
+    /// base
+    pub base: DocumentBaseTransitionAction,
+    /// The document as it will be stored after the transfer, owned by the recipient and updated
+    /// at the time of the block
+    pub document: Document,
+    /// The identity that owned the document before the transfer
+    pub original_owner_id: Identifier,
+}
+
+/// document transfer transition action accessors v0
+pub trait DocumentTransferTransitionActionAccessorsV0 {
+    /// base
+    fn base(&self) -> &DocumentBaseTransitionAction;
+    /// base owned
+    fn base_owned(self) -> DocumentBaseTransitionAction;
+    /// the transferred document, with the recipient as owner
+    fn document(&self) -> &Document;
+    /// the transferred document owned
+    fn document_owned(self) -> Document;
+    /// the owner of the document before the transfer
+    fn original_owner_id(&self) -> Identifier;
+}
@@ -0,0 +1,41 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::document::{property_names, Document, DocumentV0Getters, DocumentV0Setters};
+use dpp::platform_value::Identifier;
+use std::sync::Arc;
+
+use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::document_transfer_transition::DocumentTransferTransitionV0;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+use crate::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::v0::DocumentTransferTransitionActionV0;
+
+impl DocumentTransferTransitionActionV0 {
+    /// try from borrowed
+    pub fn try_from_borrowed_document_transfer_transition(
+        document_transfer_transition: &DocumentTransferTransitionV0,
+        original_document: Document,
+        block_info: &BlockInfo,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        let DocumentTransferTransitionV0 {
+            base,
+            revision,
+            recipient_owner_id,
+        } = document_transfer_transition;
+        let original_owner_id = original_document.owner_id();
+        let mut document = original_document;
+        document.set_owner_id(*recipient_owner_id);
+        // A transferred document is no longer for sale
+        document.properties_mut().remove(property_names::PRICE);
+        document.set_revision(Some(*revision));
+        document.set_updated_at(Some(block_info.time_ms));
+        Ok(DocumentTransferTransitionActionV0 {
+            base: DocumentBaseTransitionAction::from_borrowed_base_transition_with_contract_lookup(
+                base,
+                get_data_contract,
+            )?,
+            document,
+            original_owner_id,
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use derive_more::From;
+
+use dpp::document::Document;
+use dpp::fee::Credits;
+
+/// transformer
+pub mod transformer;
+/// v0
+pub mod v0;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+use crate::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::v0::{DocumentUpdatePriceTransitionActionAccessorsV0, DocumentUpdatePriceTransitionActionV0};
+
+/// document update price transition action
+#[derive(Debug, Clone, From)]
+pub enum DocumentUpdatePriceTransitionAction {
+    /// v0
+    V0(DocumentUpdatePriceTransitionActionV0),
+}
+
+impl DocumentUpdatePriceTransitionActionAccessorsV0 for DocumentUpdatePriceTransitionAction {
+    fn base(&self) -> &DocumentBaseTransitionAction {
+        match self {
+            DocumentUpdatePriceTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> DocumentBaseTransitionAction {
+        match self {
+            DocumentUpdatePriceTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn document(&self) -> &Document {
+        match self {
+            DocumentUpdatePriceTransitionAction::V0(v0) => &v0.document,
+        }
+    }
+
+    fn document_owned(self) -> Document {
+        match self {
+            DocumentUpdatePriceTransitionAction::V0(v0) => v0.document,
+        }
+    }
+
+    fn price(&self) -> Credits {
+        match self {
+            DocumentUpdatePriceTransitionAction::V0(v0) => v0.price,
+        }
+    }
+}
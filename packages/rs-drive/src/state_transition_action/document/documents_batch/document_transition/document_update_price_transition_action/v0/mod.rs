@@ -0,0 +1,32 @@
+/// transformer
+pub mod transformer;
+
+use dpp::document::Document;
+use dpp::fee::Credits;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+#[derive(Debug, Clone)]
+/// document update price transition action v0
+pub struct DocumentUpdatePriceTransitionActionV0 {
+    /// base
+    pub base: DocumentBaseTransitionAction,
+    /// The document as it will be stored after the update, carrying its new price
+    pub document: Document,
+    /// The price the document is put up for sale at
+    pub price: Credits,
+}
+
+/// document update price transition action accessors v0
+pub trait DocumentUpdatePriceTransitionActionAccessorsV0 {
+    /// base
+    fn base(&self) -> &DocumentBaseTransitionAction;
+    /// base owned
+    fn base_owned(self) -> DocumentBaseTransitionAction;
+    /// the document with its updated price
+    fn document(&self) -> &Document;
+    /// the document with its updated price owned
+    fn document_owned(self) -> Document;
+    /// the new price of the document
+    fn price(&self) -> Credits;
+}
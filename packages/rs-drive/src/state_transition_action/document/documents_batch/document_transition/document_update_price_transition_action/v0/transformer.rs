@@ -0,0 +1,35 @@
+use dpp::document::{property_names, Document, DocumentV0Setters};
+use dpp::platform_value::Identifier;
+use std::sync::Arc;
+
+use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::document_update_price_transition::DocumentUpdatePriceTransitionV0;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+use crate::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::v0::DocumentUpdatePriceTransitionActionV0;
+
+impl DocumentUpdatePriceTransitionActionV0 {
+    /// try from borrowed
+    pub fn try_from_borrowed_document_update_price_transition(
+        document_update_price_transition: &DocumentUpdatePriceTransitionV0,
+        original_document: Document,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        let DocumentUpdatePriceTransitionV0 {
+            base,
+            revision,
+            price,
+        } = document_update_price_transition;
+        let mut document = original_document;
+        document.set_u64(property_names::PRICE, *price);
+        document.set_revision(Some(*revision));
+        Ok(DocumentUpdatePriceTransitionActionV0 {
+            base: DocumentBaseTransitionAction::from_borrowed_base_transition_with_contract_lookup(
+                base,
+                get_data_contract,
+            )?,
+            document,
+            price: *price,
+        })
+    }
+}
@@ -5,8 +5,14 @@ pub mod document_base_transition_action;
 pub mod document_create_transition_action;
 /// document_delete_transition_action
 pub mod document_delete_transition_action;
+/// document_purchase_transition_action
+pub mod document_purchase_transition_action;
 /// document_replace_transition_action
 pub mod document_replace_transition_action;
+/// document_transfer_transition_action
+pub mod document_transfer_transition_action;
+/// document_update_price_transition_action
+pub mod document_update_price_transition_action;
 
 pub use dpp::state_transition::documents_batch_transition::document_transition::action_type::DocumentTransitionActionType;
 
@@ -17,7 +23,15 @@ use crate::state_transition_action::document::documents_batch::document_transiti
 use crate::state_transition_action::document::documents_batch::document_transition::document_delete_transition_action::DocumentDeleteTransitionAction;
 use crate::state_transition_action::document::documents_batch::document_transition::document_replace_transition_action::{DocumentReplaceTransitionAction, DocumentReplaceTransitionActionAccessorsV0};
 use crate::state_transition_action::document::documents_batch::document_transition::document_delete_transition_action::v0::DocumentDeleteTransitionActionAccessorsV0;
-use crate::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceAction;
+use crate::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::DocumentTransferTransitionAction;
+use crate::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::v0::DocumentTransferTransitionActionAccessorsV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::DocumentUpdatePriceTransitionAction;
+use crate::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::v0::DocumentUpdatePriceTransitionActionAccessorsV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::DocumentPurchaseTransitionAction;
+use crate::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::v0::DocumentPurchaseTransitionActionAccessorsV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use crate::state_transition_action::system::bump_identity_data_contract_nonce_action::{BumpIdentityDataContractNonceAction, BumpIdentityDataContractNonceActionAccessorsV0};
+use dpp::platform_value::Identifier;
 
 /// version
 pub const DOCUMENT_TRANSITION_ACTION_VERSION: u32 = 0;
@@ -31,6 +45,12 @@ pub enum DocumentTransitionAction {
     ReplaceAction(DocumentReplaceTransitionAction),
     /// delete
     DeleteAction(DocumentDeleteTransitionAction),
+    /// transfer
+    TransferAction(DocumentTransferTransitionAction),
+    /// update price
+    UpdatePriceAction(DocumentUpdatePriceTransitionAction),
+    /// purchase
+    PurchaseAction(DocumentPurchaseTransitionAction),
     /// bump identity data contract nonce
     BumpIdentityDataContractNonce(BumpIdentityDataContractNonceAction),
 }
@@ -42,16 +62,35 @@ impl DocumentTransitionAction {
             DocumentTransitionAction::CreateAction(d) => Some(d.base()),
             DocumentTransitionAction::DeleteAction(d) => Some(d.base()),
             DocumentTransitionAction::ReplaceAction(d) => Some(d.base()),
+            DocumentTransitionAction::TransferAction(d) => Some(d.base()),
+            DocumentTransitionAction::UpdatePriceAction(d) => Some(d.base()),
+            DocumentTransitionAction::PurchaseAction(d) => Some(d.base()),
             DocumentTransitionAction::BumpIdentityDataContractNonce(d) => None,
         }
     }
 
+    /// data contract id
+    pub fn data_contract_id(&self) -> Identifier {
+        match self {
+            DocumentTransitionAction::CreateAction(d) => d.base().data_contract_id(),
+            DocumentTransitionAction::DeleteAction(d) => d.base().data_contract_id(),
+            DocumentTransitionAction::ReplaceAction(d) => d.base().data_contract_id(),
+            DocumentTransitionAction::TransferAction(d) => d.base().data_contract_id(),
+            DocumentTransitionAction::UpdatePriceAction(d) => d.base().data_contract_id(),
+            DocumentTransitionAction::PurchaseAction(d) => d.base().data_contract_id(),
+            DocumentTransitionAction::BumpIdentityDataContractNonce(d) => d.data_contract_id(),
+        }
+    }
+
     /// base owned
     pub fn base_owned(self) -> Option<DocumentBaseTransitionAction> {
         match self {
             DocumentTransitionAction::CreateAction(d) => Some(d.base_owned()),
             DocumentTransitionAction::DeleteAction(d) => Some(d.base_owned()),
             DocumentTransitionAction::ReplaceAction(d) => Some(d.base_owned()),
+            DocumentTransitionAction::TransferAction(d) => Some(d.base_owned()),
+            DocumentTransitionAction::UpdatePriceAction(d) => Some(d.base_owned()),
+            DocumentTransitionAction::PurchaseAction(d) => Some(d.base_owned()),
             DocumentTransitionAction::BumpIdentityDataContractNonce(d) => None,
         }
     }
@@ -3,10 +3,12 @@ use crate::state_transition_action::document::documents_batch::v0::DocumentsBatc
 use derive_more::From;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::fee::Credits;
 use dpp::identity::SecurityLevel;
 use dpp::platform_value::Identifier;
 use dpp::ProtocolError;
 use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::v0::DocumentPurchaseTransitionActionAccessorsV0;
 
 /// document transition
 pub mod document_transition;
@@ -62,6 +64,22 @@ impl DocumentsBatchTransitionAction {
             DocumentsBatchTransitionAction::V0(v0) => v0.transitions = transitions,
         }
     }
+
+    /// The sum of the prices of all documents purchased in the batch, none if nothing is purchased
+    pub fn all_purchases_amount(&self) -> Result<Option<Credits>, ProtocolError> {
+        let mut total: Option<Credits> = None;
+        for transition in self.transitions() {
+            if let DocumentTransitionAction::PurchaseAction(purchase) = transition {
+                total = Some(
+                    total
+                        .unwrap_or_default()
+                        .checked_add(purchase.price())
+                        .ok_or(ProtocolError::Overflow("overflow in all purchases amount"))?,
+                );
+            }
+        }
+        Ok(total)
+    }
 }
 
 impl DocumentsBatchTransitionAction {
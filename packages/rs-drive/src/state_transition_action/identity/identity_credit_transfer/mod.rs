@@ -2,18 +2,33 @@
 pub mod transformer;
 /// v0
 pub mod v0;
+/// v1
+pub mod v1;
+/// v2
+pub mod v2;
 
 use crate::state_transition_action::identity::identity_credit_transfer::v0::IdentityCreditTransferTransitionActionV0;
+use crate::state_transition_action::identity::identity_credit_transfer::v1::IdentityCreditTransferTransitionActionV1;
+use crate::state_transition_action::identity::identity_credit_transfer::v2::{
+    IdentityCreditTransferTransitionActionV2, PendingTransferActionOperation,
+};
 use derive_more::From;
 use dpp::fee::Credits;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
 use dpp::platform_value::Identifier;
-use dpp::prelude::{IdentityNonce, Revision};
+use dpp::prelude::IdentityNonce;
+use std::collections::BTreeMap;
 
 /// action
 #[derive(Debug, Clone, From)]
 pub enum IdentityCreditTransferTransitionAction {
     /// v0
     V0(IdentityCreditTransferTransitionActionV0),
+    /// v1, transfer to multiple recipients
+    V1(IdentityCreditTransferTransitionActionV1),
+    /// v2, schedule or cancel a pending transfer
+    V2(IdentityCreditTransferTransitionActionV2),
 }
 
 impl IdentityCreditTransferTransitionAction {
@@ -21,13 +36,27 @@ impl IdentityCreditTransferTransitionAction {
     pub fn nonce(&self) -> IdentityNonce {
         match self {
             IdentityCreditTransferTransitionAction::V0(transition) => transition.nonce,
+            IdentityCreditTransferTransitionAction::V1(transition) => transition.nonce,
+            IdentityCreditTransferTransitionAction::V2(transition) => transition.nonce,
         }
     }
 
-    /// Transfer amount
+    /// Total transfer amount
     pub fn transfer_amount(&self) -> Credits {
         match self {
             IdentityCreditTransferTransitionAction::V0(transition) => transition.transfer_amount,
+            // the total was checked to be covered by the identity balance during validation
+            IdentityCreditTransferTransitionAction::V1(transition) => transition
+                .recipients
+                .values()
+                .fold(0, |total, amount| total.saturating_add(*amount)),
+            // cancelling gives the amount back instead of removing it from the identity balance
+            IdentityCreditTransferTransitionAction::V2(transition) => match &transition.operation {
+                PendingTransferActionOperation::Schedule(pending_transfer) => {
+                    pending_transfer.amount
+                }
+                PendingTransferActionOperation::Cancel(_) => 0,
+            },
         }
     }
 
@@ -35,13 +64,34 @@ impl IdentityCreditTransferTransitionAction {
     pub fn identity_id(&self) -> Identifier {
         match self {
             IdentityCreditTransferTransitionAction::V0(transition) => transition.identity_id,
+            IdentityCreditTransferTransitionAction::V1(transition) => transition.identity_id,
+            IdentityCreditTransferTransitionAction::V2(transition) => transition.identity_id,
         }
     }
 
-    /// Recipient Id
-    pub fn recipient_id(&self) -> Identifier {
+    /// The spending of the signing key during the epoch, if it has a spending limit
+    pub fn signing_key_spending(&self) -> Option<(KeyID, KeySpending)> {
         match self {
-            IdentityCreditTransferTransitionAction::V0(transition) => transition.recipient_id,
+            IdentityCreditTransferTransitionAction::V0(transition) => {
+                transition.signing_key_spending
+            }
+            IdentityCreditTransferTransitionAction::V1(transition) => {
+                transition.signing_key_spending
+            }
+            IdentityCreditTransferTransitionAction::V2(transition) => {
+                transition.signing_key_spending
+            }
+        }
+    }
+
+    /// Amount transferred to each recipient, pending transfers are only received at their release
+    pub fn recipients(&self) -> BTreeMap<Identifier, Credits> {
+        match self {
+            IdentityCreditTransferTransitionAction::V0(transition) => {
+                BTreeMap::from([(transition.recipient_id, transition.transfer_amount)])
+            }
+            IdentityCreditTransferTransitionAction::V1(transition) => transition.recipients.clone(),
+            IdentityCreditTransferTransitionAction::V2(_) => BTreeMap::new(),
         }
     }
 }
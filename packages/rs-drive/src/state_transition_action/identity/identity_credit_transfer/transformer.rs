@@ -1,23 +1,44 @@
 use crate::state_transition_action::identity::identity_credit_transfer::v0::IdentityCreditTransferTransitionActionV0;
+use crate::state_transition_action::identity::identity_credit_transfer::v1::IdentityCreditTransferTransitionActionV1;
+use crate::state_transition_action::identity::identity_credit_transfer::v2::IdentityCreditTransferTransitionActionV2;
 use crate::state_transition_action::identity::identity_credit_transfer::IdentityCreditTransferTransitionAction;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::pending_transfer::PendingTransfer;
+use dpp::identity::KeyID;
 use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+use dpp::ProtocolError;
 
-impl From<IdentityCreditTransferTransition> for IdentityCreditTransferTransitionAction {
-    fn from(value: IdentityCreditTransferTransition) -> Self {
+impl IdentityCreditTransferTransitionAction {
+    /// from borrowed transition, the cancelled transfer of a version 2 transition
+    /// and the spending of the signing key are the ones fetched from state
+    pub fn try_from_borrowed_transition(
+        value: &IdentityCreditTransferTransition,
+        cancelled_pending_transfer: Option<PendingTransfer>,
+        signing_key_spending: Option<(KeyID, KeySpending)>,
+    ) -> Result<Self, ProtocolError> {
         match value {
-            IdentityCreditTransferTransition::V0(v0) => {
-                IdentityCreditTransferTransitionActionV0::from(v0).into()
-            }
-        }
-    }
-}
-
-impl From<&IdentityCreditTransferTransition> for IdentityCreditTransferTransitionAction {
-    fn from(value: &IdentityCreditTransferTransition) -> Self {
-        match value {
-            IdentityCreditTransferTransition::V0(v0) => {
-                IdentityCreditTransferTransitionActionV0::from(v0).into()
-            }
+            IdentityCreditTransferTransition::V0(v0) => Ok(
+                IdentityCreditTransferTransitionActionV0::from_borrowed_transition(
+                    v0,
+                    signing_key_spending,
+                )
+                .into(),
+            ),
+            IdentityCreditTransferTransition::V1(v1) => Ok(
+                IdentityCreditTransferTransitionActionV1::from_borrowed_transition(
+                    v1,
+                    signing_key_spending,
+                )
+                .into(),
+            ),
+            IdentityCreditTransferTransition::V2(v2) => Ok(
+                IdentityCreditTransferTransitionActionV2::try_from_borrowed_transition(
+                    v2,
+                    cancelled_pending_transfer,
+                    signing_key_spending,
+                )?
+                .into(),
+            ),
         }
     }
 }
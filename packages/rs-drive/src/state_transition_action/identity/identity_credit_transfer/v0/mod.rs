@@ -1,6 +1,8 @@
 mod transformer;
 
 use dpp::fee::Credits;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
 use dpp::platform_value::Identifier;
 use dpp::prelude::{IdentityNonce, Revision};
 use serde::{Deserialize, Serialize};
@@ -17,4 +19,6 @@ pub struct IdentityCreditTransferTransitionActionV0 {
     pub identity_id: Identifier,
     /// nonce
     pub nonce: IdentityNonce,
+    /// the spending of the signing key during the epoch, if it has a spending limit
+    pub signing_key_spending: Option<(KeyID, KeySpending)>,
 }
@@ -1,8 +1,14 @@
 use crate::state_transition_action::identity::identity_credit_transfer::v0::IdentityCreditTransferTransitionActionV0;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
 use dpp::state_transition::state_transitions::identity::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
 
-impl From<IdentityCreditTransferTransitionV0> for IdentityCreditTransferTransitionActionV0 {
-    fn from(value: IdentityCreditTransferTransitionV0) -> Self {
+impl IdentityCreditTransferTransitionActionV0 {
+    /// from transition, the spending of the signing key is the one validated against state
+    pub fn from_transition(
+        value: IdentityCreditTransferTransitionV0,
+        signing_key_spending: Option<(KeyID, KeySpending)>,
+    ) -> Self {
         let IdentityCreditTransferTransitionV0 {
             identity_id,
             recipient_id,
@@ -15,12 +21,15 @@ impl From<IdentityCreditTransferTransitionV0> for IdentityCreditTransferTransiti
             recipient_id,
             transfer_amount: amount,
             nonce,
+            signing_key_spending,
         }
     }
-}
 
-impl From<&IdentityCreditTransferTransitionV0> for IdentityCreditTransferTransitionActionV0 {
-    fn from(value: &IdentityCreditTransferTransitionV0) -> Self {
+    /// from borrowed transition, the spending of the signing key is the one validated against state
+    pub fn from_borrowed_transition(
+        value: &IdentityCreditTransferTransitionV0,
+        signing_key_spending: Option<(KeyID, KeySpending)>,
+    ) -> Self {
         let IdentityCreditTransferTransitionV0 {
             identity_id,
             recipient_id,
@@ -33,6 +42,7 @@ impl From<&IdentityCreditTransferTransitionV0> for IdentityCreditTransferTransit
             recipient_id: *recipient_id,
             transfer_amount: *amount,
             nonce: *nonce,
+            signing_key_spending,
         }
     }
 }
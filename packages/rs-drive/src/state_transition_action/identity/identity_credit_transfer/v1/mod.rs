@@ -0,0 +1,23 @@
+mod transformer;
+
+use dpp::fee::Credits;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
+use dpp::platform_value::Identifier;
+use dpp::prelude::IdentityNonce;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// action v1
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityCreditTransferTransitionActionV1 {
+    /// amount transferred to each recipient
+    pub recipients: BTreeMap<Identifier, Credits>,
+    /// identity id
+    pub identity_id: Identifier,
+    /// nonce
+    pub nonce: IdentityNonce,
+    /// the spending of the signing key during the epoch, if it has a spending limit
+    pub signing_key_spending: Option<(KeyID, KeySpending)>,
+}
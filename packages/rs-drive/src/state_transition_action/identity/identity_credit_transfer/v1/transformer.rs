@@ -0,0 +1,44 @@
+use crate::state_transition_action::identity::identity_credit_transfer::v1::IdentityCreditTransferTransitionActionV1;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
+use dpp::state_transition::state_transitions::identity::identity_credit_transfer_transition::v1::IdentityCreditTransferTransitionV1;
+
+impl IdentityCreditTransferTransitionActionV1 {
+    /// from transition, the spending of the signing key is the one validated against state
+    pub fn from_transition(
+        value: IdentityCreditTransferTransitionV1,
+        signing_key_spending: Option<(KeyID, KeySpending)>,
+    ) -> Self {
+        let IdentityCreditTransferTransitionV1 {
+            identity_id,
+            recipients,
+            nonce,
+            ..
+        } = value;
+        IdentityCreditTransferTransitionActionV1 {
+            identity_id,
+            recipients,
+            nonce,
+            signing_key_spending,
+        }
+    }
+
+    /// from borrowed transition, the spending of the signing key is the one validated against state
+    pub fn from_borrowed_transition(
+        value: &IdentityCreditTransferTransitionV1,
+        signing_key_spending: Option<(KeyID, KeySpending)>,
+    ) -> Self {
+        let IdentityCreditTransferTransitionV1 {
+            identity_id,
+            recipients,
+            nonce,
+            ..
+        } = value;
+        IdentityCreditTransferTransitionActionV1 {
+            identity_id: *identity_id,
+            recipients: recipients.clone(),
+            nonce: *nonce,
+            signing_key_spending,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+mod transformer;
+
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::pending_transfer::PendingTransfer;
+use dpp::identity::KeyID;
+use dpp::platform_value::Identifier;
+use dpp::prelude::IdentityNonce;
+use serde::{Deserialize, Serialize};
+
+/// operation on a pending transfer of the identity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PendingTransferActionOperation {
+    /// the scheduled transfer
+    Schedule(PendingTransfer),
+    /// the cancelled transfer, as it was stored
+    Cancel(PendingTransfer),
+}
+
+/// action v2
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityCreditTransferTransitionActionV2 {
+    /// identity id
+    pub identity_id: Identifier,
+    /// operation on the pending transfer
+    pub operation: PendingTransferActionOperation,
+    /// nonce
+    pub nonce: IdentityNonce,
+    /// the spending of the signing key during the epoch, if it has a spending limit
+    pub signing_key_spending: Option<(KeyID, KeySpending)>,
+}
@@ -0,0 +1,63 @@
+use crate::state_transition_action::identity::identity_credit_transfer::v2::{
+    IdentityCreditTransferTransitionActionV2, PendingTransferActionOperation,
+};
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::pending_transfer::PendingTransfer;
+use dpp::identity::KeyID;
+use dpp::state_transition::state_transitions::identity::identity_credit_transfer_transition::v2::{
+    IdentityCreditTransferTransitionV2, PendingTransferOperation,
+};
+use dpp::ProtocolError;
+
+impl IdentityCreditTransferTransitionActionV2 {
+    /// from borrowed transition, the cancelled transfer and the spending of the signing key
+    /// are the ones fetched from state
+    pub fn try_from_borrowed_transition(
+        value: &IdentityCreditTransferTransitionV2,
+        cancelled_pending_transfer: Option<PendingTransfer>,
+        signing_key_spending: Option<(KeyID, KeySpending)>,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityCreditTransferTransitionV2 {
+            identity_id,
+            operation,
+            nonce,
+            ..
+        } = value;
+
+        let operation = match operation {
+            PendingTransferOperation::Schedule {
+                recipient_id,
+                amount,
+                release_condition,
+            } => PendingTransferActionOperation::Schedule(PendingTransfer {
+                id: PendingTransfer::id_from_sender_and_nonce(*identity_id, *nonce),
+                sender_id: *identity_id,
+                recipient_id: *recipient_id,
+                amount: *amount,
+                release_condition: *release_condition,
+            }),
+            PendingTransferOperation::Cancel { transfer_id } => {
+                let Some(pending_transfer) = cancelled_pending_transfer else {
+                    return Err(ProtocolError::CorruptedCodeExecution(
+                        "the cancelled pending transfer must be fetched from state".to_string(),
+                    ));
+                };
+
+                if pending_transfer.id != *transfer_id {
+                    return Err(ProtocolError::CorruptedCodeExecution(
+                        "the cancelled pending transfer does not match the transition".to_string(),
+                    ));
+                }
+
+                PendingTransferActionOperation::Cancel(pending_transfer)
+            }
+        };
+
+        Ok(IdentityCreditTransferTransitionActionV2 {
+            identity_id: *identity_id,
+            operation,
+            nonce: *nonce,
+            signing_key_spending,
+        })
+    }
+}
@@ -6,6 +6,8 @@ pub mod v0;
 use crate::state_transition_action::identity::identity_credit_withdrawal::v0::IdentityCreditWithdrawalTransitionActionV0;
 use derive_more::From;
 use dpp::document::Document;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
 
 use dpp::platform_value::Identifier;
 use dpp::prelude::{IdentityNonce, Revision};
@@ -39,6 +41,15 @@ impl IdentityCreditWithdrawalTransitionAction {
         }
     }
 
+    /// The spending of the signing key during the epoch, if it has a spending limit
+    pub fn signing_key_spending(&self) -> Option<(KeyID, KeySpending)> {
+        match self {
+            IdentityCreditWithdrawalTransitionAction::V0(transition) => {
+                transition.signing_key_spending
+            }
+        }
+    }
+
     /// Recipient Id
     pub fn prepared_withdrawal_document(&self) -> &Document {
         match self {
@@ -2,6 +2,8 @@ mod transformer;
 
 use dpp::document::Document;
 use dpp::identifier::Identifier;
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
 use dpp::prelude::{IdentityNonce, Revision};
 
 use serde::{Deserialize, Serialize};
@@ -18,4 +20,6 @@ pub struct IdentityCreditWithdrawalTransitionActionV0 {
     pub prepared_withdrawal_document: Document,
     /// amount
     pub amount: u64,
+    /// the spending of the signing key during the epoch, if it has a spending limit
+    pub signing_key_spending: Option<(KeyID, KeySpending)>,
 }
@@ -2,15 +2,18 @@ use crate::state_transition_action::identity::identity_credit_withdrawal::v0::Id
 use dpp::data_contracts::withdrawals_contract;
 use dpp::data_contracts::withdrawals_contract::v1::document_types::withdrawal;
 use dpp::document::{Document, DocumentV0};
+use dpp::identity::key_spending::KeySpending;
+use dpp::identity::KeyID;
 use dpp::platform_value::platform_value;
 use dpp::state_transition::state_transitions::identity::identity_credit_withdrawal_transition::v0::IdentityCreditWithdrawalTransitionV0;
-use dpp::withdrawal::Pooling;
 
 impl IdentityCreditWithdrawalTransitionActionV0 {
-    /// from identity credit withdrawal
+    /// from identity credit withdrawal, the spending of the signing key is the one validated
+    /// against state
     pub fn from_identity_credit_withdrawal(
         identity_credit_withdrawal: &IdentityCreditWithdrawalTransitionV0,
         creation_time_ms: u64,
+        signing_key_spending: Option<(KeyID, KeySpending)>,
     ) -> Self {
         let mut entropy = Vec::new();
         entropy.extend_from_slice(&identity_credit_withdrawal.nonce.to_be_bytes());
@@ -26,8 +29,7 @@ impl IdentityCreditWithdrawalTransitionActionV0 {
         let document_data = platform_value!({
             withdrawal::properties::AMOUNT: identity_credit_withdrawal.amount,
             withdrawal::properties::CORE_FEE_PER_BYTE: identity_credit_withdrawal.core_fee_per_byte,
-            // TODO(withdrawals): replace with actual value from state transition once pooling is done
-            withdrawal::properties::POOLING: Pooling::Never,
+            withdrawal::properties::POOLING: identity_credit_withdrawal.pooling,
             withdrawal::properties::OUTPUT_SCRIPT: identity_credit_withdrawal.output_script.as_bytes(),
             withdrawal::properties::STATUS: withdrawals_contract::WithdrawalStatus::QUEUED,
         });
@@ -47,6 +49,7 @@ impl IdentityCreditWithdrawalTransitionActionV0 {
             nonce: identity_credit_withdrawal.nonce,
             prepared_withdrawal_document: withdrawal_document,
             amount: identity_credit_withdrawal.amount,
+            signing_key_spending,
         }
     }
 }
@@ -0,0 +1,39 @@
+/// v0
+pub mod v0;
+
+use crate::state_transition_action::identity::identity_recover::v0::{
+    IdentityRecoverActionOperation, IdentityRecoverTransitionActionV0,
+};
+use derive_more::From;
+use dpp::platform_value::Identifier;
+use dpp::prelude::IdentityNonce;
+
+/// action
+#[derive(Debug, Clone, From)]
+pub enum IdentityRecoverTransitionAction {
+    /// v0
+    V0(IdentityRecoverTransitionActionV0),
+}
+
+impl IdentityRecoverTransitionAction {
+    /// Identity Id
+    pub fn identity_id(&self) -> Identifier {
+        match self {
+            IdentityRecoverTransitionAction::V0(transition) => transition.identity_id,
+        }
+    }
+
+    /// Operation on the recovery proposal or the master keys of the identity
+    pub fn operation(&self) -> &IdentityRecoverActionOperation {
+        match self {
+            IdentityRecoverTransitionAction::V0(transition) => &transition.operation,
+        }
+    }
+
+    /// Nonce
+    pub fn nonce(&self) -> IdentityNonce {
+        match self {
+            IdentityRecoverTransitionAction::V0(transition) => transition.nonce,
+        }
+    }
+}
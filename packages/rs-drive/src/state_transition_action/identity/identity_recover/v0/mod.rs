@@ -0,0 +1,40 @@
+use dpp::identity::recovery_proposal::IdentityRecoveryProposal;
+use dpp::identity::{IdentityPublicKey, KeyID, TimestampMillis};
+use dpp::platform_value::Identifier;
+use dpp::prelude::{IdentityNonce, Revision};
+use serde::{Deserialize, Serialize};
+
+/// operation on the recovery proposal or the master keys of the identity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IdentityRecoverActionOperation {
+    /// the proposal once created or approved
+    SetRecoveryProposal(IdentityRecoveryProposal),
+    /// the proposal was vetoed
+    RemoveRecoveryProposal,
+    /// the identity set how long its master keys can veto a proposal
+    SetRecoveryDelay(TimestampMillis),
+    /// the proposal was executed, replacing the master keys of the identity
+    ReplaceMasterKeys {
+        /// the enabled master keys of the identity
+        master_keys_to_disable: Vec<KeyID>,
+        /// the master keys of the executed proposal
+        new_master_keys: Vec<IdentityPublicKey>,
+        /// the time of the block executing the proposal
+        disabled_at: TimestampMillis,
+        /// the revision of the identity after the recovery
+        revision: Revision,
+    },
+}
+
+/// action v0
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityRecoverTransitionActionV0 {
+    /// identity id
+    pub identity_id: Identifier,
+    /// operation on the recovery proposal or the master keys of the identity
+    pub operation: IdentityRecoverActionOperation,
+    /// nonce used to prevent replay attacks
+    pub nonce: IdentityNonce,
+}
@@ -5,6 +5,7 @@ pub mod v0;
 
 use crate::state_transition_action::identity::identity_update::v0::IdentityUpdateTransitionActionV0;
 use derive_more::From;
+use dpp::fee::Credits;
 use dpp::identity::{IdentityPublicKey, KeyID, TimestampMillis};
 use dpp::platform_value::Identifier;
 use dpp::prelude::Revision;
@@ -39,6 +40,15 @@ impl IdentityUpdateTransitionAction {
         }
     }
 
+    /// Spending limits per epoch to set on existing keys, none removes the limit
+    pub fn public_keys_spending_limits(&self) -> &Vec<(KeyID, Option<Credits>)> {
+        match self {
+            IdentityUpdateTransitionAction::V0(transition) => {
+                &transition.public_keys_spending_limits
+            }
+        }
+    }
+
     /// Public Keys Disabled At
     pub fn public_keys_disabled_at(&self) -> Option<TimestampMillis> {
         match self {
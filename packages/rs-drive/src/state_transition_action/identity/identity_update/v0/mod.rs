@@ -1,5 +1,6 @@
 mod transformer;
 
+use dpp::fee::Credits;
 use dpp::identifier::Identifier;
 use dpp::identity::{IdentityPublicKey, KeyID, TimestampMillis};
 use dpp::prelude::{IdentityNonce, Revision};
@@ -13,8 +14,10 @@ pub struct IdentityUpdateTransitionActionV0 {
     pub add_public_keys: Vec<IdentityPublicKey>,
     /// disable public keys
     pub disable_public_keys: Vec<KeyID>,
-    /// public keys disabled at
+    /// public keys disabled at, after the grace period when keys are rotated
     pub public_keys_disabled_at: Option<TimestampMillis>,
+    /// spending limits per epoch to set on existing public keys
+    pub public_keys_spending_limits: Vec<(KeyID, Option<Credits>)>,
     /// identity id
     pub identity_id: Identifier,
     /// revision
@@ -1,5 +1,17 @@
 use crate::state_transition_action::identity::identity_update::v0::IdentityUpdateTransitionActionV0;
+use dpp::identity::TimestampMillis;
 use dpp::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
+use dpp::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
+
+/// Keys rotated with a grace period are only disabled once the grace period is over
+fn scheduled_public_keys_disabled_at(
+    public_keys_disabled_at: Option<TimestampMillis>,
+    public_keys_disable_grace_period_ms: Option<u64>,
+) -> Option<TimestampMillis> {
+    public_keys_disabled_at.map(|disabled_at| {
+        disabled_at.saturating_add(public_keys_disable_grace_period_ms.unwrap_or_default())
+    })
+}
 
 impl From<IdentityUpdateTransitionV0> for IdentityUpdateTransitionActionV0 {
     fn from(value: IdentityUpdateTransitionV0) -> Self {
@@ -16,6 +28,7 @@ impl From<IdentityUpdateTransitionV0> for IdentityUpdateTransitionActionV0 {
             add_public_keys: add_public_keys.into_iter().map(|a| a.into()).collect(),
             disable_public_keys,
             public_keys_disabled_at,
+            public_keys_spending_limits: vec![],
             identity_id,
             revision,
             nonce,
@@ -41,6 +54,66 @@ impl From<&IdentityUpdateTransitionV0> for IdentityUpdateTransitionActionV0 {
                 .collect(),
             disable_public_keys: disable_public_keys.clone(),
             public_keys_disabled_at: *public_keys_disabled_at,
+            public_keys_spending_limits: vec![],
+            identity_id: *identity_id,
+            revision: *revision,
+            nonce: *nonce,
+        }
+    }
+}
+
+impl From<IdentityUpdateTransitionV1> for IdentityUpdateTransitionActionV0 {
+    fn from(value: IdentityUpdateTransitionV1) -> Self {
+        let IdentityUpdateTransitionV1 {
+            identity_id,
+            add_public_keys,
+            disable_public_keys,
+            public_keys_disabled_at,
+            public_keys_disable_grace_period_ms,
+            public_keys_spending_limits,
+            revision,
+            nonce,
+            ..
+        } = value;
+        IdentityUpdateTransitionActionV0 {
+            add_public_keys: add_public_keys.into_iter().map(|a| a.into()).collect(),
+            disable_public_keys,
+            public_keys_disabled_at: scheduled_public_keys_disabled_at(
+                public_keys_disabled_at,
+                public_keys_disable_grace_period_ms,
+            ),
+            public_keys_spending_limits,
+            identity_id,
+            revision,
+            nonce,
+        }
+    }
+}
+
+impl From<&IdentityUpdateTransitionV1> for IdentityUpdateTransitionActionV0 {
+    fn from(value: &IdentityUpdateTransitionV1) -> Self {
+        let IdentityUpdateTransitionV1 {
+            identity_id,
+            add_public_keys,
+            disable_public_keys,
+            public_keys_disabled_at,
+            public_keys_disable_grace_period_ms,
+            public_keys_spending_limits,
+            revision,
+            nonce,
+            ..
+        } = value;
+        IdentityUpdateTransitionActionV0 {
+            add_public_keys: add_public_keys
+                .iter()
+                .map(|key| key.clone().into())
+                .collect(),
+            disable_public_keys: disable_public_keys.clone(),
+            public_keys_disabled_at: scheduled_public_keys_disabled_at(
+                *public_keys_disabled_at,
+                *public_keys_disable_grace_period_ms,
+            ),
+            public_keys_spending_limits: public_keys_spending_limits.clone(),
             identity_id: *identity_id,
             revision: *revision,
             nonce: *nonce,
@@ -4,6 +4,8 @@ pub mod identity_create;
 pub mod identity_credit_transfer;
 /// identity credit withdrawal
 pub mod identity_credit_withdrawal;
+/// identity recover
+pub mod identity_recover;
 /// identity topup
 pub mod identity_topup;
 /// identity update
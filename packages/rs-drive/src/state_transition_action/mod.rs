@@ -14,6 +14,7 @@ use crate::state_transition_action::document::documents_batch::DocumentsBatchTra
 use crate::state_transition_action::identity::identity_create::IdentityCreateTransitionAction;
 use crate::state_transition_action::identity::identity_credit_transfer::IdentityCreditTransferTransitionAction;
 use crate::state_transition_action::identity::identity_credit_withdrawal::IdentityCreditWithdrawalTransitionAction;
+use crate::state_transition_action::identity::identity_recover::IdentityRecoverTransitionAction;
 use crate::state_transition_action::identity::identity_topup::IdentityTopUpTransitionAction;
 use crate::state_transition_action::identity::identity_update::IdentityUpdateTransitionAction;
 use crate::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceAction;
@@ -39,6 +40,8 @@ pub enum StateTransitionAction {
     IdentityUpdateAction(IdentityUpdateTransitionAction),
     /// identity credit transfer
     IdentityCreditTransferAction(IdentityCreditTransferTransitionAction),
+    /// identity recover
+    IdentityRecoverAction(IdentityRecoverTransitionAction),
     /// bump identity nonce action
     /// this can only come in this form from identity state transitions that do not use asset locks
     /// it will also only happen if the state validation fails
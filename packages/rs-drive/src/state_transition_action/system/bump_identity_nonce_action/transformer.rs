@@ -1,11 +1,13 @@
 use crate::state_transition_action::identity::identity_credit_transfer::IdentityCreditTransferTransitionAction;
 use crate::state_transition_action::identity::identity_credit_withdrawal::IdentityCreditWithdrawalTransitionAction;
+use crate::state_transition_action::identity::identity_recover::IdentityRecoverTransitionAction;
 use crate::state_transition_action::identity::identity_update::IdentityUpdateTransitionAction;
 use crate::state_transition_action::system::bump_identity_nonce_action::{
     BumpIdentityNonceAction, BumpIdentityNonceActionV0,
 };
 use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
 use dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
+use dpp::state_transition::identity_recover_transition::IdentityRecoverTransition;
 use dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
 use dpp::ProtocolError;
 
@@ -18,6 +20,10 @@ impl BumpIdentityNonceAction {
             IdentityUpdateTransition::V0(v0) => {
                 Ok(BumpIdentityNonceActionV0::try_from_identity_update(v0)?.into())
             }
+            IdentityUpdateTransition::V1(v1) => Ok(
+                BumpIdentityNonceActionV0::try_from_identity_update_with_spending_limits(v1)?
+                    .into(),
+            ),
         }
     }
 
@@ -29,6 +35,12 @@ impl BumpIdentityNonceAction {
             IdentityUpdateTransition::V0(v0) => {
                 Ok(BumpIdentityNonceActionV0::try_from_borrowed_identity_update(v0)?.into())
             }
+            IdentityUpdateTransition::V1(v1) => Ok(
+                BumpIdentityNonceActionV0::try_from_borrowed_identity_update_with_spending_limits(
+                    v1,
+                )?
+                .into(),
+            ),
         }
     }
 
@@ -62,6 +74,13 @@ impl BumpIdentityNonceAction {
             IdentityCreditTransferTransition::V0(v0) => {
                 Ok(BumpIdentityNonceActionV0::try_from_identity_credit_transfer(v0)?.into())
             }
+            IdentityCreditTransferTransition::V1(v1) => Ok(
+                BumpIdentityNonceActionV0::try_from_identity_credit_transfer_to_identities(v1)?
+                    .into(),
+            ),
+            IdentityCreditTransferTransition::V2(v2) => Ok(
+                BumpIdentityNonceActionV0::try_from_pending_identity_credit_transfer(v2)?.into(),
+            ),
         }
     }
 
@@ -73,6 +92,16 @@ impl BumpIdentityNonceAction {
             IdentityCreditTransferTransition::V0(v0) => Ok(
                 BumpIdentityNonceActionV0::try_from_borrowed_identity_credit_transfer(v0)?.into(),
             ),
+            IdentityCreditTransferTransition::V1(v1) => Ok(
+                BumpIdentityNonceActionV0::try_from_borrowed_identity_credit_transfer_to_identities(
+                    v1,
+                )?
+                .into(),
+            ),
+            IdentityCreditTransferTransition::V2(v2) => Ok(
+                BumpIdentityNonceActionV0::try_from_borrowed_pending_identity_credit_transfer(v2)?
+                    .into(),
+            ),
         }
     }
 
@@ -84,6 +113,16 @@ impl BumpIdentityNonceAction {
             IdentityCreditTransferTransitionAction::V0(v0) => {
                 Ok(BumpIdentityNonceActionV0::try_from_identity_credit_transfer_action(v0)?.into())
             }
+            IdentityCreditTransferTransitionAction::V1(v1) => Ok(
+                BumpIdentityNonceActionV0::try_from_identity_credit_transfer_to_identities_action(
+                    v1,
+                )?
+                .into(),
+            ),
+            IdentityCreditTransferTransitionAction::V2(v2) => Ok(
+                BumpIdentityNonceActionV0::try_from_pending_identity_credit_transfer_action(v2)?
+                    .into(),
+            ),
         }
     }
 
@@ -96,6 +135,14 @@ impl BumpIdentityNonceAction {
                 BumpIdentityNonceActionV0::try_from_borrowed_identity_credit_transfer_action(v0)?
                     .into(),
             ),
+            IdentityCreditTransferTransitionAction::V1(v1) => Ok(
+                BumpIdentityNonceActionV0::try_from_borrowed_identity_credit_transfer_to_identities_action(v1)?
+                    .into(),
+            ),
+            IdentityCreditTransferTransitionAction::V2(v2) => Ok(
+                BumpIdentityNonceActionV0::try_from_borrowed_pending_identity_credit_transfer_action(v2)?
+                    .into(),
+            ),
         }
     }
 
@@ -143,4 +190,48 @@ impl BumpIdentityNonceAction {
             ),
         }
     }
+
+    /// from identity recover
+    pub fn from_identity_recover_transition(
+        value: IdentityRecoverTransition,
+    ) -> Result<Self, ProtocolError> {
+        match value {
+            IdentityRecoverTransition::V0(v0) => {
+                Ok(BumpIdentityNonceActionV0::try_from_identity_recover(v0)?.into())
+            }
+        }
+    }
+
+    /// from borrowed identity recover
+    pub fn from_borrowed_identity_recover_transition(
+        value: &IdentityRecoverTransition,
+    ) -> Result<Self, ProtocolError> {
+        match value {
+            IdentityRecoverTransition::V0(v0) => {
+                Ok(BumpIdentityNonceActionV0::try_from_borrowed_identity_recover(v0)?.into())
+            }
+        }
+    }
+
+    /// from identity recover action
+    pub fn from_identity_recover_transition_action(
+        value: IdentityRecoverTransitionAction,
+    ) -> Result<Self, ProtocolError> {
+        match value {
+            IdentityRecoverTransitionAction::V0(v0) => {
+                Ok(BumpIdentityNonceActionV0::try_from_identity_recover_action(v0)?.into())
+            }
+        }
+    }
+
+    /// from borrowed identity recover action
+    pub fn from_borrowed_identity_recover_transition_action(
+        value: &IdentityRecoverTransitionAction,
+    ) -> Result<Self, ProtocolError> {
+        match value {
+            IdentityRecoverTransitionAction::V0(v0) => Ok(
+                BumpIdentityNonceActionV0::try_from_borrowed_identity_recover_action(v0)?.into(),
+            ),
+        }
+    }
 }
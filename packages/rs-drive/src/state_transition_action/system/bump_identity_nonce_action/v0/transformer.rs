@@ -1,10 +1,17 @@
 use crate::state_transition_action::identity::identity_credit_transfer::v0::IdentityCreditTransferTransitionActionV0;
+use crate::state_transition_action::identity::identity_credit_transfer::v1::IdentityCreditTransferTransitionActionV1;
+use crate::state_transition_action::identity::identity_credit_transfer::v2::IdentityCreditTransferTransitionActionV2;
 use crate::state_transition_action::identity::identity_credit_withdrawal::v0::IdentityCreditWithdrawalTransitionActionV0;
+use crate::state_transition_action::identity::identity_recover::v0::IdentityRecoverTransitionActionV0;
 use crate::state_transition_action::identity::identity_update::v0::IdentityUpdateTransitionActionV0;
 use crate::state_transition_action::system::bump_identity_nonce_action::BumpIdentityNonceActionV0;
 use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+use dpp::state_transition::identity_credit_transfer_transition::v1::IdentityCreditTransferTransitionV1;
+use dpp::state_transition::identity_credit_transfer_transition::v2::IdentityCreditTransferTransitionV2;
 use dpp::state_transition::identity_credit_withdrawal_transition::v0::IdentityCreditWithdrawalTransitionV0;
+use dpp::state_transition::identity_recover_transition::v0::IdentityRecoverTransitionV0;
 use dpp::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
+use dpp::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
 use dpp::ProtocolError;
 
 impl BumpIdentityNonceActionV0 {
@@ -34,6 +41,32 @@ impl BumpIdentityNonceActionV0 {
         })
     }
 
+    /// try from identity update setting key spending limits
+    pub fn try_from_identity_update_with_spending_limits(
+        value: IdentityUpdateTransitionV1,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityUpdateTransitionV1 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id,
+            identity_nonce: nonce,
+        })
+    }
+
+    /// try from borrowed identity update setting key spending limits
+    pub fn try_from_borrowed_identity_update_with_spending_limits(
+        value: &IdentityUpdateTransitionV1,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityUpdateTransitionV1 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id: *identity_id,
+            identity_nonce: *nonce,
+        })
+    }
+
     /// try from identity update action
     pub fn try_from_identity_update_action(
         value: IdentityUpdateTransitionActionV0,
@@ -112,6 +145,110 @@ impl BumpIdentityNonceActionV0 {
         })
     }
 
+    /// try from multi-recipient identity credit transfer
+    pub fn try_from_identity_credit_transfer_to_identities(
+        value: IdentityCreditTransferTransitionV1,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityCreditTransferTransitionV1 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id,
+            identity_nonce: nonce,
+        })
+    }
+
+    /// try from borrowed multi-recipient identity credit transfer
+    pub fn try_from_borrowed_identity_credit_transfer_to_identities(
+        value: &IdentityCreditTransferTransitionV1,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityCreditTransferTransitionV1 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id: *identity_id,
+            identity_nonce: *nonce,
+        })
+    }
+
+    /// try from multi-recipient identity credit transfer action
+    pub fn try_from_identity_credit_transfer_to_identities_action(
+        value: IdentityCreditTransferTransitionActionV1,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityCreditTransferTransitionActionV1 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id,
+            identity_nonce: nonce,
+        })
+    }
+
+    /// try from borrowed multi-recipient identity credit transfer action
+    pub fn try_from_borrowed_identity_credit_transfer_to_identities_action(
+        value: &IdentityCreditTransferTransitionActionV1,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityCreditTransferTransitionActionV1 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id: *identity_id,
+            identity_nonce: *nonce,
+        })
+    }
+
+    /// try from pending identity credit transfer
+    pub fn try_from_pending_identity_credit_transfer(
+        value: IdentityCreditTransferTransitionV2,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityCreditTransferTransitionV2 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id,
+            identity_nonce: nonce,
+        })
+    }
+
+    /// try from borrowed pending identity credit transfer
+    pub fn try_from_borrowed_pending_identity_credit_transfer(
+        value: &IdentityCreditTransferTransitionV2,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityCreditTransferTransitionV2 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id: *identity_id,
+            identity_nonce: *nonce,
+        })
+    }
+
+    /// try from pending identity credit transfer action
+    pub fn try_from_pending_identity_credit_transfer_action(
+        value: IdentityCreditTransferTransitionActionV2,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityCreditTransferTransitionActionV2 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id,
+            identity_nonce: nonce,
+        })
+    }
+
+    /// try from borrowed pending identity credit transfer action
+    pub fn try_from_borrowed_pending_identity_credit_transfer_action(
+        value: &IdentityCreditTransferTransitionActionV2,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityCreditTransferTransitionActionV2 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id: *identity_id,
+            identity_nonce: *nonce,
+        })
+    }
+
     /// try from identity credit withdrawal
     pub fn try_from_identity_credit_withdrawal(
         value: IdentityCreditWithdrawalTransitionV0,
@@ -163,4 +300,56 @@ impl BumpIdentityNonceActionV0 {
             identity_nonce: *nonce,
         })
     }
+
+    /// try from identity recover
+    pub fn try_from_identity_recover(
+        value: IdentityRecoverTransitionV0,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityRecoverTransitionV0 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id,
+            identity_nonce: nonce,
+        })
+    }
+
+    /// try from borrowed identity recover
+    pub fn try_from_borrowed_identity_recover(
+        value: &IdentityRecoverTransitionV0,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityRecoverTransitionV0 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id: *identity_id,
+            identity_nonce: *nonce,
+        })
+    }
+
+    /// try from identity recover action
+    pub fn try_from_identity_recover_action(
+        value: IdentityRecoverTransitionActionV0,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityRecoverTransitionActionV0 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id,
+            identity_nonce: nonce,
+        })
+    }
+
+    /// try from borrowed identity recover action
+    pub fn try_from_borrowed_identity_recover_action(
+        value: &IdentityRecoverTransitionActionV0,
+    ) -> Result<Self, ProtocolError> {
+        let IdentityRecoverTransitionActionV0 {
+            identity_id, nonce, ..
+        } = value;
+        Ok(BumpIdentityNonceActionV0 {
+            identity_id: *identity_id,
+            identity_nonce: *nonce,
+        })
+    }
 }
@@ -116,7 +116,7 @@ use drive::drive::document::query::QuerySerializedDocumentsOutcome;
 use drive::drive::object_size_info::DocumentInfo;
 use drive::drive::object_size_info::DocumentInfo::DocumentRefInfo;
 
-use drive::query::{WhereClause, WhereOperator};
+use drive::query::{AggregateFunction, WhereClause, WhereOperator};
 use drive::tests::helpers::setup::setup_drive_with_initial_state_structure;
 
 #[cfg(feature = "full")]
@@ -217,6 +217,29 @@ impl PersonWithOptionalValues {
 #[cfg(feature = "full")]
 /// Inserts the test "family" contract and adds `count` documents containing randomly named people to it.
 pub fn setup_family_tests(count: u32, seed: u64) -> (Drive, DataContract) {
+    setup_family_tests_for_contract(
+        count,
+        seed,
+        "tests/supporting_files/contract/family/family-contract.json",
+    )
+}
+
+#[cfg(feature = "full")]
+/// Same as `setup_family_tests` but with a "family" contract declaring sums on its indices.
+pub fn setup_family_tests_with_sums(count: u32, seed: u64) -> (Drive, DataContract) {
+    setup_family_tests_for_contract(
+        count,
+        seed,
+        "tests/supporting_files/contract/family/family-contract-with-sums.json",
+    )
+}
+
+#[cfg(feature = "full")]
+fn setup_family_tests_for_contract(
+    count: u32,
+    seed: u64,
+    contract_path: &str,
+) -> (Drive, DataContract) {
     let drive_config = DriveConfig::default();
 
     let drive = setup_drive(Some(drive_config));
@@ -235,12 +258,7 @@ pub fn setup_family_tests(count: u32, seed: u64) -> (Drive, DataContract) {
         .expect("expected to create contracts tree successfully");
 
     // setup code
-    let contract = common::setup_contract(
-        &drive,
-        "tests/supporting_files/contract/family/family-contract.json",
-        None,
-        Some(&db_transaction),
-    );
+    let contract = common::setup_contract(&drive, contract_path, None, Some(&db_transaction));
 
     let people = Person::random_people(count, seed);
     for person in people {
@@ -916,6 +934,7 @@ fn test_reference_proof_single_index() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -964,6 +983,7 @@ fn test_non_existence_reference_proof_single_index() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -1031,6 +1051,7 @@ fn test_family_basic_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -1362,6 +1383,7 @@ fn test_family_basic_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -1418,6 +1440,7 @@ fn test_family_basic_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -1469,6 +1492,7 @@ fn test_family_basic_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -1521,6 +1545,7 @@ fn test_family_basic_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -1581,6 +1606,7 @@ fn test_family_basic_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -1629,6 +1655,7 @@ fn test_family_basic_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -1689,6 +1716,7 @@ fn test_family_basic_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -1748,6 +1776,7 @@ fn test_family_basic_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -2411,6 +2440,7 @@ fn test_family_person_update() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -2493,6 +2523,7 @@ fn test_family_starts_at_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -2552,6 +2583,7 @@ fn test_family_starts_at_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -2605,6 +2637,7 @@ fn test_family_starts_at_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -2664,6 +2697,7 @@ fn test_family_starts_at_queries() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -2701,6 +2735,7 @@ fn test_family_starts_at_queries() {
 #[cfg(feature = "full")]
 #[test]
 fn test_family_sql_query() {
+    let platform_version = PlatformVersion::latest();
     // These helpers confirm that sql statements produce the same drive query
     // as their json counterparts, helpers above confirm that the json queries
     // produce the correct result set
@@ -2726,12 +2761,18 @@ fn test_family_sql_query() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("should build query");
 
     let sql_string = "select * from person order by firstName asc limit 100";
-    let query2 = DriveQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
-        .expect("should build query");
+    let query2 = DriveQuery::from_sql_expr(
+        sql_string,
+        &contract,
+        Some(&DriveConfig::default()),
+        platform_version,
+    )
+    .expect("should build query");
 
     assert_eq!(query1, query2);
 
@@ -2750,12 +2791,18 @@ fn test_family_sql_query() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("should build query");
 
     let sql_string = "select * from person where firstName = 'Chris'";
-    let query2 = DriveQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
-        .expect("should build query");
+    let query2 = DriveQuery::from_sql_expr(
+        sql_string,
+        &contract,
+        Some(&DriveConfig::default()),
+        platform_version,
+    )
+    .expect("should build query");
 
     assert_eq!(query1, query2);
 
@@ -2778,13 +2825,19 @@ fn test_family_sql_query() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("should build query");
 
     let sql_string =
         "select * from person where firstName < 'Chris' order by firstName asc limit 100";
-    let query2 = DriveQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
-        .expect("should build query");
+    let query2 = DriveQuery::from_sql_expr(
+        sql_string,
+        &contract,
+        Some(&DriveConfig::default()),
+        platform_version,
+    )
+    .expect("should build query");
 
     assert_eq!(query1, query2);
 
@@ -2807,13 +2860,19 @@ fn test_family_sql_query() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("should build query");
 
     let sql_string =
         "select * from person where firstName like 'C%' order by firstName asc limit 100";
-    let query2 = DriveQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
-        .expect("should build query");
+    let query2 = DriveQuery::from_sql_expr(
+        sql_string,
+        &contract,
+        Some(&DriveConfig::default()),
+        platform_version,
+    )
+    .expect("should build query");
 
     assert_eq!(query1, query2);
 
@@ -2837,12 +2896,18 @@ fn test_family_sql_query() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("should build query");
 
     let sql_string = "select * from person where firstName > 'Chris' and firstName <= 'Noellyn' order by firstName asc limit 100";
-    let query2 = DriveQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
-        .expect("should build query");
+    let query2 = DriveQuery::from_sql_expr(
+        sql_string,
+        &contract,
+        Some(&DriveConfig::default()),
+        platform_version,
+    )
+    .expect("should build query");
 
     assert_eq!(query1, query2);
 
@@ -2866,56 +2931,62 @@ fn test_family_sql_query() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("should build query");
 
     let sql_string =
         "select * from person where firstName in ('a', 'b') order by firstName limit 100";
-    let query2 = DriveQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
-        .expect("should build query");
+    let query2 = DriveQuery::from_sql_expr(
+        sql_string,
+        &contract,
+        Some(&DriveConfig::default()),
+        platform_version,
+    )
+    .expect("should build query");
 
     assert_eq!(query1, query2);
 }
 
 #[cfg(feature = "full")]
 #[test]
-fn test_family_with_nulls_query() {
-    let (drive, contract) = setup_family_tests_with_nulls(10, 30004);
-
+fn test_family_or_queries() {
+    let (drive, contract) = setup_family_tests(10, 73509);
     let platform_version = PlatformVersion::latest();
 
-    let db_transaction = drive.grove.start_transaction();
-
     let root_hash = drive
         .grove
-        .root_hash(Some(&db_transaction))
+        .root_hash(None)
         .unwrap()
         .expect("there is always a root hash");
 
-    let expected_app_hash = vec![
-        100, 192, 55, 56, 28, 89, 114, 51, 246, 239, 246, 155, 157, 46, 242, 255, 61, 30, 111, 226,
-        252, 227, 130, 243, 99, 202, 83, 249, 253, 202, 142, 36,
-    ];
-
-    assert_eq!(root_hash.as_slice(), expected_app_hash);
-
-    let all_names = [
-        "".to_string(),
-        "".to_string(),
-        "".to_string(),
-        "".to_string(),
-        "".to_string(),
-        "".to_string(),
-        "Alexia".to_string(),
-        "Gerti".to_string(),
-        "Latisha".to_string(),
-        "Norry".to_string(),
-    ];
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
 
-    // A query getting all elements by firstName
+    let first_names = |results: &Vec<Vec<u8>>| -> Vec<String> {
+        results
+            .iter()
+            .map(|result| {
+                let document =
+                    Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                        .expect("we should be able to deserialize the document");
+                document
+                    .get("firstName")
+                    .and_then(|first_name| first_name.as_text())
+                    .expect("the first name should be a string")
+                    .to_string()
+            })
+            .collect()
+    };
 
+    // Branches on different values of the same index
     let query_value = json!({
         "where": [
+            {"or": [
+                ["firstName", "==", "Meta"],
+                ["firstName", "==", "Adey"],
+            ]}
         ],
         "limit": 100,
         "orderBy": [
@@ -2924,118 +2995,35 @@ fn test_family_with_nulls_query() {
     });
     let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
         .expect("expected to serialize to cbor");
-    let person_document_type = contract
-        .document_type_for_name("person")
-        .expect("contract should have a person document type");
     let query = DriveQuery::from_cbor(
         where_cbor.as_slice(),
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
-    let (results, _, _) = query
-        .execute_raw_results_no_proof(&drive, None, Some(&db_transaction), platform_version)
-        .expect("proof should be executed");
-    let names: Vec<String> = results
-        .clone()
-        .into_iter()
-        .map(|result| {
-            let document =
-                Document::from_bytes(result.as_slice(), person_document_type, platform_version)
-                    .expect("we should be able to deserialize the document");
-            document
-                .get("firstName")
-                .map(|value| {
-                    let first_name_value = value
-                        .as_text()
-                        .expect("the normalized label should be a string");
-                    String::from(first_name_value)
-                })
-                .unwrap_or_default()
-        })
-        .collect();
+    assert!(query.is_disjunctive());
 
-    assert_eq!(names, all_names);
+    let (results, _, _) = query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    assert_eq!(first_names(&results), vec!["Adey", "Meta"]);
 
     let (proof_root_hash, proof_results, _) = query
+        .clone()
         .execute_with_proof_only_get_elements(&drive, None, None, platform_version)
         .expect("we should be able to a proof");
     assert_eq!(root_hash, proof_root_hash);
     assert_eq!(results, proof_results);
 
-    let ids: Vec<String> = results
-        .iter()
-        .map(|result| {
-            let document =
-                Document::from_bytes(result.as_slice(), person_document_type, platform_version)
-                    .expect("we should be able to deserialize the document");
-            base64::encode(document.id().as_slice())
-        })
-        .collect();
-
-    for i in 0..10 {
-        drive
-            .delete_document_for_contract(
-                base64::decode(ids.get(i).unwrap())
-                    .expect("expected to decode from base64")
-                    .try_into()
-                    .expect("expected to get 32 bytes"),
-                &contract,
-                "person",
-                BlockInfo::genesis(),
-                true,
-                Some(&db_transaction),
-                platform_version,
-            )
-            .expect("expected to be able to delete the document");
-    }
-
-    drive
-        .grove
-        .commit_transaction(db_transaction)
-        .unwrap()
-        .expect("unable to commit transaction");
-}
-
-#[cfg(feature = "full")]
-#[test]
-fn test_query_with_cached_contract() {
-    let (drive, contract) = setup_family_tests(10, 73509);
-
-    let platform_version = PlatformVersion::latest();
-
-    let db_transaction = drive.grove.start_transaction();
-
-    let root_hash = drive
-        .grove
-        .root_hash(Some(&db_transaction))
-        .unwrap()
-        .expect("there is always a root hash");
-
-    // Make sure the state is deterministic
-    let expected_app_hash = vec![
-        89, 169, 192, 133, 222, 85, 217, 165, 166, 122, 195, 124, 55, 108, 16, 158, 1, 80, 226, 24,
-        74, 1, 201, 200, 22, 166, 46, 239, 191, 250, 138, 149,
-    ];
-
-    assert_eq!(root_hash.as_slice(), expected_app_hash);
-
-    // Make sure contract is not cached
-    let contract_ref = drive
-        .get_cached_contract_with_fetch_info(
-            *contract.id_ref().as_bytes(),
-            Some(&db_transaction),
-            &platform_version.drive,
-        )
-        .expect("should return a contract ref");
-
-    assert!(contract_ref.is_none());
-
-    // A query getting all elements by firstName
-
+    // Overlapping branches only return documents once
     let query_value = json!({
         "where": [
+            {"or": [
+                ["firstName", "==", "Briney"],
+                ["firstName", "<", "Cammi"],
+            ]}
         ],
         "limit": 100,
         "orderBy": [
@@ -3044,36 +3032,1534 @@ fn test_query_with_cached_contract() {
     });
     let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
         .expect("expected to serialize to cbor");
+    let query = DriveQuery::from_cbor(
+        where_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+        platform_version,
+    )
+    .expect("query should be built");
 
-    let QuerySerializedDocumentsOutcome { items, .. } = drive
-        .query_documents_cbor_with_document_type_lookup(
-            where_cbor.as_slice(),
-            *contract.id_ref().as_bytes(),
-            "person",
-            None,
-            Some(&db_transaction),
-            Some(platform_version.protocol_version),
-        )
+    let (results, _, _) = query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
         .expect("query should be executed");
+    assert_eq!(first_names(&results), vec!["Adey", "Briney"]);
 
-    assert_eq!(items.len(), 10);
+    let (proof_root_hash, proof_results, _) = query
+        .execute_with_proof_only_get_elements(&drive, None, None, platform_version)
+        .expect("we should be able to a proof");
+    assert_eq!(root_hash, proof_root_hash);
+    assert_eq!(results, proof_results);
 
-    // Cache was populated and there only two ref two the cached fetched info (here and cache)
-    let contract_ref = drive
-        .get_cached_contract_with_fetch_info(
-            *contract.id_ref().as_bytes(),
-            Some(&db_transaction),
-            &platform_version.drive,
-        )
-        .expect("should return a contract ref")
-        .expect("expected a reference counter to the contract");
+    // The limit applies to the union of the branches
+    let query_value = json!({
+        "where": [
+            {"or": [
+                ["firstName", ">", "Meta"],
+                ["firstName", "<", "Cammi"],
+            ]}
+        ],
+        "limit": 3,
+        "orderBy": [
+            ["firstName", "desc"]
+        ]
+    });
+    let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+        .expect("expected to serialize to cbor");
+    let query = DriveQuery::from_cbor(
+        where_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+        platform_version,
+    )
+    .expect("query should be built");
 
-    assert_eq!(Arc::strong_count(&contract_ref), 2);
-}
+    let (results, _, _) = query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    assert_eq!(first_names(&results), vec!["Prissie", "Noellyn", "Briney"]);
 
-#[cfg(feature = "full")]
-#[test]
-fn test_dpns_query_contract_verification() {
+    let (proof, _) = query
+        .clone()
+        .execute_with_proof(&drive, None, None, platform_version)
+        .expect("we should be able to a proof");
+    let (proof_root_hash, documents) = query
+        .verify_proof(proof.as_slice(), platform_version)
+        .expect("we should be able to verify the proof");
+    assert_eq!(root_hash, proof_root_hash);
+    assert_eq!(documents.len(), 3);
+
+    // Without order by clauses every branch is bounded by the limit and the union of their
+    // documents is ordered by id
+    let unordered_query = |start_after: Option<String>| {
+        let mut query_value = json!({
+            "where": [
+                {"or": [
+                    ["firstName", ">", "Meta"],
+                    ["firstName", "<", "Cammi"],
+                ]}
+            ],
+            "limit": 2,
+        });
+        if let Some(start_after) = start_after {
+            query_value["startAfter"] = json!(start_after);
+        }
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            person_document_type,
+            &drive.config,
+            platform_version,
+        )
+        .expect("query should be built")
+    };
+    let ids = |results: &Vec<Vec<u8>>| -> Vec<Identifier> {
+        results
+            .iter()
+            .map(|result| {
+                Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                    .expect("we should be able to deserialize the document")
+                    .id()
+            })
+            .collect()
+    };
+
+    let query = unordered_query(None);
+    assert!(query
+        .disjunctive_branch_queries(platform_version)
+        .expect("query should be split")
+        .iter()
+        .all(|branch_query| branch_query.limit == Some(2)));
+
+    let (results, _, _) = query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    let (proof_root_hash, proof_results, _) = query
+        .execute_with_proof_only_get_elements(&drive, None, None, platform_version)
+        .expect("we should be able to a proof");
+    assert_eq!(root_hash, proof_root_hash);
+    assert_eq!(results, proof_results);
+
+    let result_ids = ids(&results);
+    let mut sorted_ids = result_ids.clone();
+    sorted_ids.sort();
+    sorted_ids.dedup();
+    assert_eq!(result_ids, sorted_ids);
+    assert_eq!(result_ids.len(), 2);
+
+    // Paging through the union would require reading every branch entirely
+    let start_after = Some(result_ids[0].to_string_with_encoding_string(Some("base58")));
+    assert!(matches!(
+        unordered_query(start_after).execute_raw_results_no_proof(
+            &drive,
+            None,
+            None,
+            platform_version
+        ),
+        Err(Error::Query(QuerySyntaxError::InvalidOrClause(_)))
+    ));
+
+    // The sql query builds the same drive query
+    let query_value = json!({
+        "where": [
+            {"or": [
+                ["firstName", "==", "Meta"],
+                ["firstName", "==", "Adey"],
+            ]}
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    });
+    let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+        .expect("expected to serialize to cbor");
+    let query1 = DriveQuery::from_cbor(
+        where_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+        platform_version,
+    )
+    .expect("query should be built");
+
+    let sql_string = "select * from person where firstName = 'Meta' or firstName = 'Adey' order by firstName asc limit 100";
+    let query2 = DriveQuery::from_sql_expr(
+        sql_string,
+        &contract,
+        Some(&DriveConfig::default()),
+        platform_version,
+    )
+    .expect("should build query");
+
+    assert_eq!(query1, query2);
+
+    // The query survives a cbor round trip
+    let cbor = query1.to_cbor().expect("should serialize cbor");
+    let deserialized = DriveQuery::from_cbor(
+        cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+        platform_version,
+    )
+    .expect("should deserialize cbor");
+    assert_eq!(query1, deserialized);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_or_queries_descending_ties() {
+    // Many people share an age, so the limit cuts branches in the middle of equal ages
+    let (drive, contract) = setup_family_tests(1600, 73509);
+    let platform_version = PlatformVersion::latest();
+
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+
+    let build_query = |query_value: serde_json::Value| {
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            person_document_type,
+            &drive.config,
+            platform_version,
+        )
+        .expect("query should be built")
+    };
+    let ages_and_ids = |results: &Vec<Vec<u8>>| -> Vec<(u8, Identifier)> {
+        results
+            .iter()
+            .map(|result| {
+                let document =
+                    Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                        .expect("we should be able to deserialize the document");
+                let age: u8 = document
+                    .get("age")
+                    .expect("we should be able to get the age")
+                    .to_integer()
+                    .expect("expected u8 value");
+                (age, document.id())
+            })
+            .collect()
+    };
+
+    let query = build_query(json!({
+        "where": [
+            {"or": [
+                ["age", ">=", 80],
+                ["age", "<", 10],
+            ]}
+        ],
+        "limit": 5,
+        "orderBy": [
+            ["age", "desc"]
+        ]
+    }));
+
+    let (results, _, _) = query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    let results = ages_and_ids(&results);
+    assert_eq!(results.len(), 5);
+
+    // Documents of equal age are ordered by descending id, like the branches return them
+    assert!(results.windows(2).any(|pair| pair[0].0 == pair[1].0));
+    assert!(results
+        .windows(2)
+        .all(|pair| pair[0].0 > pair[1].0 || (pair[0].0 == pair[1].0 && pair[0].1 > pair[1].1)));
+
+    // The union starts with the documents returned by the branch with the oldest people
+    let branch_query = build_query(json!({
+        "where": [
+            ["age", ">=", 80],
+        ],
+        "limit": 5,
+        "orderBy": [
+            ["age", "desc"]
+        ]
+    }));
+    let (branch_results, _, _) = branch_query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    assert_eq!(results, ages_and_ids(&branch_results));
+
+    let (proof, _) = query
+        .clone()
+        .execute_with_proof(&drive, None, None, platform_version)
+        .expect("we should be able to a proof");
+    let (_, documents) = query
+        .verify_proof(proof.as_slice(), platform_version)
+        .expect("we should be able to verify the proof");
+    let proved_ids: Vec<Identifier> = documents.iter().map(|document| document.id()).collect();
+    let ids: Vec<Identifier> = results.iter().map(|(_, id)| *id).collect();
+    assert_eq!(proved_ids, ids);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_residual_range_queries() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+    let platform_version = PlatformVersion::latest();
+
+    let root_hash = drive
+        .grove
+        .root_hash(None)
+        .unwrap()
+        .expect("there is always a root hash");
+
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+
+    let build_query = |query_value: serde_json::Value| {
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            person_document_type,
+            &drive.config,
+            platform_version,
+        )
+        .expect("query should be built")
+    };
+
+    // The results of the index query on the first range, filtered by hand on the second range
+    let query = build_query(json!({
+        "where": [
+            ["firstName", ">", "Briney"],
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }));
+    let (index_results, _, _) = query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    let expected_results = index_results
+        .into_iter()
+        .filter(|result| {
+            let document =
+                Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                    .expect("we should be able to deserialize the document");
+            let age: u64 = document
+                .get("age")
+                .expect("the person should have an age")
+                .to_integer()
+                .expect("the age should be an integer");
+            age < 50
+        })
+        .collect::<Vec<_>>();
+
+    let query = build_query(json!({
+        "where": [
+            ["firstName", ">", "Briney"],
+            ["age", "<", 50],
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }));
+    assert!(query.has_residual_range_clauses());
+
+    let (results, _, _) = query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    assert_eq!(results, expected_results);
+
+    let (proof_root_hash, proof_results, _) = query
+        .execute_with_proof_only_get_elements(&drive, None, None, platform_version)
+        .expect("we should be able to a proof");
+    assert_eq!(root_hash, proof_root_hash);
+    assert_eq!(results, proof_results);
+
+    // A small limit makes the index query be executed page by page
+    let query = build_query(json!({
+        "where": [
+            ["firstName", ">", "Briney"],
+            ["age", "<", 50],
+        ],
+        "limit": 2,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }));
+
+    let (results, _, _) = query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    assert_eq!(
+        results,
+        expected_results.iter().take(2).cloned().collect::<Vec<_>>()
+    );
+
+    let (proof, _) = query
+        .clone()
+        .execute_with_proof(&drive, None, None, platform_version)
+        .expect("we should be able to a proof");
+    let (proof_root_hash, proof_results) = query
+        .verify_proof_keep_serialized(proof.as_slice(), platform_version)
+        .expect("we should be able to verify the proof");
+    assert_eq!(root_hash, proof_root_hash);
+    assert_eq!(results, proof_results);
+
+    // The sql query also filters on the second range
+    let sql_string = "select * from person where firstName > 'Briney' and age < 50 order by firstName asc limit 2";
+    let sql_query = DriveQuery::from_sql_expr(
+        sql_string,
+        &contract,
+        Some(&DriveConfig::default()),
+        platform_version,
+    )
+    .expect("should build query");
+    assert!(sql_query.has_residual_range_clauses());
+
+    let (sql_results, _, _) = sql_query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    assert_eq!(results, sql_results);
+
+    // Queries needing more pages than allowed fail instead of returning partial results
+    let query = build_query(json!({
+        "where": [
+            ["firstName", ">", "A"],
+            ["age", ">", 1000],
+        ],
+        "limit": 1,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }));
+
+    let result = query.execute_raw_results_no_proof(&drive, None, None, platform_version);
+    assert!(matches!(
+        result,
+        Err(Error::Query(
+            QuerySyntaxError::ResidualRangeFilterPagesExceeded(_)
+        ))
+    ));
+
+    let result = query.execute_with_proof(&drive, None, None, platform_version);
+    assert!(matches!(
+        result,
+        Err(Error::Query(
+            QuerySyntaxError::ResidualRangeFilterPagesExceeded(_)
+        ))
+    ));
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_query_matches_document() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+    let platform_version = PlatformVersion::latest();
+
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+
+    let build_query = |query_value: serde_json::Value| {
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            person_document_type,
+            &drive.config,
+            platform_version,
+        )
+        .expect("query should be built")
+    };
+
+    let all_documents_query = build_query(json!({
+        "where": [],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }));
+    let (all_documents, _, _) = all_documents_query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    assert_eq!(all_documents.len(), 10);
+
+    for query_value in [
+        json!({
+            "where": [
+                ["firstName", ">", "Briney"],
+                ["age", "<", 50],
+            ],
+            "limit": 100,
+            "orderBy": [
+                ["firstName", "asc"]
+            ]
+        }),
+        json!({
+            "where": [
+                ["firstName", "in", ["Cammi", "Meta", "Unknown"]],
+            ],
+            "limit": 100,
+            "orderBy": [
+                ["firstName", "asc"]
+            ]
+        }),
+        json!({
+            "where": [
+                ["firstName", "startsWith", "C"],
+            ],
+            "limit": 100,
+            "orderBy": [
+                ["firstName", "asc"]
+            ]
+        }),
+    ] {
+        let query = build_query(query_value);
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None, platform_version)
+            .expect("query should be executed");
+
+        // A document matches the query exactly when the query returns it
+        for serialized_document in &all_documents {
+            let document = Document::from_bytes(
+                serialized_document.as_slice(),
+                person_document_type,
+                platform_version,
+            )
+            .expect("we should be able to deserialize the document");
+            let matches = query
+                .matches_document(&document, platform_version)
+                .expect("expected to match the document");
+            assert_eq!(matches, results.contains(serialized_document));
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_negated_queries() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+    let platform_version = PlatformVersion::latest();
+
+    let root_hash = drive
+        .grove
+        .root_hash(None)
+        .unwrap()
+        .expect("there is always a root hash");
+
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+
+    let first_names = |results: &Vec<Vec<u8>>| -> Vec<String> {
+        results
+            .iter()
+            .map(|result| {
+                let document =
+                    Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                        .expect("we should be able to deserialize the document");
+                document
+                    .get("firstName")
+                    .and_then(|first_name| first_name.as_text())
+                    .expect("the first name should be a string")
+                    .to_string()
+            })
+            .collect()
+    };
+
+    let build_query = |query_value: serde_json::Value| {
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            person_document_type,
+            &drive.config,
+            platform_version,
+        )
+        .expect("query should be built")
+    };
+
+    let check_results_and_proof = |query: DriveQuery, expected_first_names: Vec<&str>| {
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None, platform_version)
+            .expect("query should be executed");
+        assert_eq!(first_names(&results), expected_first_names);
+
+        let (proof, _) = query
+            .clone()
+            .execute_with_proof(&drive, None, None, platform_version)
+            .expect("we should be able to a proof");
+        let (proof_root_hash, proof_results) = query
+            .verify_proof_keep_serialized(proof.as_slice(), platform_version)
+            .expect("we should be able to verify the proof");
+        assert_eq!(root_hash, proof_root_hash);
+        assert_eq!(results, proof_results);
+        results
+    };
+
+    let query = build_query(json!({
+        "where": [
+            ["firstName", "!=", "Meta"],
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }));
+    check_results_and_proof(
+        query,
+        vec![
+            "Adey", "Briney", "Cammi", "Celinda", "Dalia", "Gilligan", "Kevina", "Noellyn",
+            "Prissie",
+        ],
+    );
+
+    let query = build_query(json!({
+        "where": [
+            ["firstName", "NotIn", ["Adey", "Dalia", "Prissie"]],
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "desc"]
+        ]
+    }));
+    let results = check_results_and_proof(
+        query.clone(),
+        vec![
+            "Noellyn", "Meta", "Kevina", "Gilligan", "Celinda", "Cammi", "Briney",
+        ],
+    );
+
+    // Starting after a document only keeps the end of the complementary ranges
+    let start_after_document = Document::from_bytes(
+        results
+            .get(3)
+            .expect("expected a fourth document")
+            .as_slice(),
+        person_document_type,
+        platform_version,
+    )
+    .expect("we should be able to deserialize the document");
+    let mut start_after_query = query;
+    start_after_query.start_at = Some(start_after_document.id().to_buffer());
+    start_after_query.start_at_included = false;
+    check_results_and_proof(start_after_query, vec!["Celinda", "Cammi", "Briney"]);
+
+    let query = build_query(json!({
+        "where": [
+            ["firstName", "NotStartsWith", "C"],
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }));
+    check_results_and_proof(
+        query.clone(),
+        vec![
+            "Adey", "Briney", "Dalia", "Gilligan", "Kevina", "Meta", "Noellyn", "Prissie",
+        ],
+    );
+
+    // The sql queries build the same drive queries
+    let sql_string =
+        "select * from person where firstName NOT LIKE 'C%' order by firstName asc limit 100";
+    let sql_query = DriveQuery::from_sql_expr(
+        sql_string,
+        &contract,
+        Some(&DriveConfig::default()),
+        platform_version,
+    )
+    .expect("should build query");
+    assert_eq!(query, sql_query);
+
+    let sql_string = "select * from person where firstName NOT IN ('Adey', 'Dalia', 'Prissie') order by firstName desc limit 100";
+    let sql_query = DriveQuery::from_sql_expr(
+        sql_string,
+        &contract,
+        Some(&DriveConfig::default()),
+        platform_version,
+    )
+    .expect("should build query");
+    let query = build_query(json!({
+        "where": [
+            ["firstName", "NotIn", ["Adey", "Dalia", "Prissie"]],
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "desc"]
+        ]
+    }));
+    assert_eq!(query, sql_query);
+
+    let sql_string =
+        "select * from person where firstName != 'Meta' order by firstName asc limit 100";
+    let sql_query = DriveQuery::from_sql_expr(
+        sql_string,
+        &contract,
+        Some(&DriveConfig::default()),
+        platform_version,
+    )
+    .expect("should build query");
+    let query = build_query(json!({
+        "where": [
+            ["firstName", "!=", "Meta"],
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }));
+    assert_eq!(query, sql_query);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_aggregate_queries() {
+    let (drive, contract) = setup_family_tests_with_sums(10, 73509);
+    let platform_version = PlatformVersion::latest();
+
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+
+    let build_query = |query_value: serde_json::Value| {
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            person_document_type,
+            &drive.config,
+            platform_version,
+        )
+        .expect("query should be built")
+    };
+
+    // The documents matched by a query, to aggregate them by hand
+    let documents = |query: &DriveQuery| -> Vec<Document> {
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None, platform_version)
+            .expect("query should be executed");
+        results
+            .iter()
+            .map(|result| {
+                Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                    .expect("we should be able to deserialize the document")
+            })
+            .collect()
+    };
+    let age = |document: &Document| -> i64 {
+        document
+            .get("age")
+            .expect("the person should have an age")
+            .to_integer()
+            .expect("the age should be an integer")
+    };
+    let sum = AggregateFunction::Sum("age".to_string());
+
+    // Aggregates are the same with and without a proof, they cover every matched document
+    let assert_aggregate = |query: &DriveQuery, function: &AggregateFunction, expected: i64| {
+        let query = DriveQuery {
+            limit: None,
+            ..query.clone()
+        };
+        let (value, _) = query
+            .execute_aggregate_no_proof(function, &drive, None, None, platform_version)
+            .expect("aggregate should be executed");
+        assert_eq!(value, expected);
+
+        let (proof, _) = query
+            .clone()
+            .execute_aggregate_with_proof(function, &drive, None, None, platform_version)
+            .expect("we should be able to a proof");
+        let (proof_root_hash, proof_value) = query
+            .verify_aggregate_proof(function, proof.as_slice(), platform_version)
+            .expect("we should be able to verify the proof");
+        let root_hash = drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("there is always a root hash");
+        assert_eq!(root_hash, proof_root_hash);
+        assert_eq!(proof_value, expected);
+    };
+
+    let all_query = build_query(json!({
+        "limit": 100,
+    }));
+    let all_people = documents(&all_query);
+    assert_eq!(all_people.len(), 10);
+    assert_aggregate(&all_query, &AggregateFunction::Count, 10);
+
+    // A limit would truncate the aggregate
+    for function in [AggregateFunction::Count, sum.clone()] {
+        assert!(matches!(
+            all_query.execute_aggregate_no_proof(&function, &drive, None, None, platform_version),
+            Err(Error::Query(QuerySyntaxError::InvalidLimit(_)))
+        ));
+    }
+    assert_aggregate(&all_query, &sum, all_people.iter().map(age).sum());
+
+    // Counts above the maximum of the platform version are rejected, with and without a proof
+    let mut capped_platform_version = platform_version.clone();
+    capped_platform_version
+        .drive
+        .methods
+        .document
+        .query
+        .max_aggregate_count = 9;
+    let uncapped_query = DriveQuery {
+        limit: None,
+        ..all_query.clone()
+    };
+    assert!(matches!(
+        uncapped_query.execute_aggregate_no_proof(
+            &AggregateFunction::Count,
+            &drive,
+            None,
+            None,
+            &capped_platform_version
+        ),
+        Err(Error::Query(QuerySyntaxError::AggregateCountLimitExceeded(
+            _
+        )))
+    ));
+    let (proof, _) = uncapped_query
+        .clone()
+        .execute_aggregate_with_proof(
+            &AggregateFunction::Count,
+            &drive,
+            None,
+            None,
+            &capped_platform_version,
+        )
+        .expect("we should be able to a proof");
+    assert!(matches!(
+        uncapped_query.verify_aggregate_proof(
+            &AggregateFunction::Count,
+            proof.as_slice(),
+            &capped_platform_version
+        ),
+        Err(Error::Query(QuerySyntaxError::AggregateCountLimitExceeded(
+            _
+        )))
+    ));
+    // The count proof only holds one document more than the maximum
+    let (_, proved_documents) = DriveQuery {
+        limit: Some(10),
+        ..all_query.clone()
+    }
+    .verify_proof_keep_serialized(proof.as_slice(), platform_version)
+    .expect("we should be able to verify the proof");
+    assert_eq!(proved_documents.len(), 10);
+    // Sums are read from sum trees and are not capped
+    assert_eq!(
+        uncapped_query
+            .execute_aggregate_no_proof(&sum, &drive, None, None, &capped_platform_version)
+            .expect("aggregate should be executed")
+            .0,
+        all_people.iter().map(age).sum::<i64>()
+    );
+
+    capped_platform_version
+        .drive
+        .methods
+        .document
+        .query
+        .max_aggregate_count = 10;
+    assert_eq!(
+        uncapped_query
+            .execute_aggregate_no_proof(
+                &AggregateFunction::Count,
+                &drive,
+                None,
+                None,
+                &capped_platform_version
+            )
+            .expect("aggregate should be executed")
+            .0,
+        10
+    );
+
+    let range_query = build_query(json!({
+        "where": [
+            ["firstName", ">", "Briney"],
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }));
+    let range_people = documents(&range_query);
+    assert_eq!(range_people.len(), 8);
+    assert_aggregate(&range_query, &AggregateFunction::Count, 8);
+    assert_aggregate(&range_query, &sum, range_people.iter().map(age).sum());
+
+    // The sum over a last name is held by the last name index
+    let last_name = all_people[0]
+        .get("lastName")
+        .expect("the person should have a last name")
+        .clone();
+    let last_name_query = build_query(json!({
+        "where": [
+            ["lastName", "==", last_name.to_str().expect("the last name should be a string")],
+        ],
+        "limit": 100,
+    }));
+    assert_aggregate(
+        &last_name_query,
+        &sum,
+        all_people
+            .iter()
+            .filter(|person| person.get("lastName") == Some(&last_name))
+            .map(age)
+            .sum(),
+    );
+
+    // No index declares the sum of ages for a range over ages
+    let age_query = build_query(json!({
+        "where": [
+            ["age", ">", 10],
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["age", "asc"]
+        ]
+    }));
+    assert!(matches!(
+        age_query.execute_aggregate_no_proof(&sum, &drive, None, None, platform_version),
+        Err(Error::Query(QuerySyntaxError::NoSumIndexForQuery(_)))
+    ));
+
+    // Sums follow updates and deletions of documents
+    let mut updated_person = range_people[1].clone();
+    updated_person.set("age", Value::U64(age(&updated_person) as u64 + 10));
+    drive
+        .update_document_for_contract(
+            &updated_person,
+            &contract,
+            person_document_type,
+            None,
+            BlockInfo::genesis(),
+            true,
+            None,
+            None,
+            platform_version,
+        )
+        .expect("expected to update the document");
+
+    drive
+        .delete_document_for_contract(
+            range_people[0].id().to_buffer(),
+            &contract,
+            "person",
+            BlockInfo::genesis(),
+            true,
+            None,
+            platform_version,
+        )
+        .expect("expected to be able to delete the document");
+
+    assert_aggregate(&range_query, &AggregateFunction::Count, 7);
+    assert_aggregate(
+        &range_query,
+        &sum,
+        range_people.iter().map(age).sum::<i64>() - age(&range_people[0]) + 10,
+    );
+    assert_aggregate(
+        &all_query,
+        &sum,
+        all_people.iter().map(age).sum::<i64>() - age(&range_people[0]) + 10,
+    );
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_contains_word_queries() {
+    let (drive, contract) = setup_family_tests_for_contract(
+        5,
+        73509,
+        "tests/supporting_files/contract/family/family-contract-with-tokens.json",
+    );
+    let platform_version = PlatformVersion::latest();
+
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+
+    let build_query = |query_value: serde_json::Value| {
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            person_document_type,
+            &drive.config,
+            platform_version,
+        )
+    };
+
+    let all_query = build_query(json!({
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }))
+    .expect("query should be built");
+    let (results, _, _) = all_query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    let people: Vec<Document> = results
+        .iter()
+        .map(|result| {
+            Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                .expect("we should be able to deserialize the document")
+        })
+        .collect();
+    assert_eq!(people.len(), 5);
+
+    // Words are indexed when messages are set on the people
+    let messages = [
+        "Computers are useless. They can only give you answers.",
+        "I think computers will be the death of me!",
+        "The best way to predict the future is to invent it.",
+    ];
+    for (person, message) in people.iter().zip(messages) {
+        let mut person = person.clone();
+        person.set("message", Value::Text(message.to_string()));
+        drive
+            .update_document_for_contract(
+                &person,
+                &contract,
+                person_document_type,
+                None,
+                BlockInfo::genesis(),
+                true,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to update the document");
+    }
+
+    // Word queries return the ids of the matching people, with and without a proof
+    let assert_word_query = |word: &str, expected: Vec<&Document>| {
+        let query = build_query(json!({
+            "where": [
+                ["message", "containsWord", word],
+            ],
+            "limit": 100,
+        }))
+        .expect("query should be built");
+
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None, platform_version)
+            .expect("query should be executed");
+        let ids: Vec<Identifier> = results
+            .iter()
+            .map(|result| {
+                Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                    .expect("we should be able to deserialize the document")
+                    .id()
+            })
+            .collect();
+        let mut expected_ids: Vec<Identifier> = expected.iter().map(|person| person.id()).collect();
+        expected_ids.sort();
+        assert_eq!(ids, expected_ids);
+
+        let (proof, _) = query
+            .clone()
+            .execute_with_proof(&drive, None, None, platform_version)
+            .expect("we should be able to a proof");
+        let (proof_root_hash, documents) = query
+            .verify_proof(proof.as_slice(), platform_version)
+            .expect("we should be able to verify the proof");
+        let root_hash = drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("there is always a root hash");
+        assert_eq!(root_hash, proof_root_hash);
+        assert_eq!(
+            documents
+                .iter()
+                .map(|document| document.id())
+                .collect::<Vec<Identifier>>(),
+            expected_ids
+        );
+    };
+
+    assert_word_query("computers", vec![&people[0], &people[1]]);
+    // Words are matched regardless of their case
+    assert_word_query("COMPUTERS", vec![&people[0], &people[1]]);
+    assert_word_query("the", vec![&people[1], &people[2]]);
+    assert_word_query("future", vec![&people[2]]);
+    assert_word_query("keyboard", vec![]);
+
+    // Only a single word can be queried
+    assert!(matches!(
+        build_query(json!({
+            "where": [
+                ["message", "containsWord", "the future"],
+            ],
+        })),
+        Err(Error::Query(QuerySyntaxError::InvalidContainsWordClause(_)))
+    ));
+
+    // A word query can not be combined with other clauses
+    assert!(matches!(
+        build_query(json!({
+            "where": [
+                ["message", "containsWord", "computers"],
+                ["firstName", "==", "Meta"],
+            ],
+        })),
+        Err(Error::Query(QuerySyntaxError::InvalidContainsWordClause(_)))
+    ));
+
+    // Words can only be queried on properties with a tokenized index
+    let first_name_query = build_query(json!({
+        "where": [
+            ["firstName", "containsWord", "meta"],
+        ],
+    }))
+    .expect("query should be built");
+    assert!(matches!(
+        first_name_query.execute_raw_results_no_proof(&drive, None, None, platform_version),
+        Err(Error::Query(
+            QuerySyntaxError::WhereClauseOnNonIndexedProperty(_)
+        ))
+    ));
+
+    // Word indices follow updates and deletions of documents
+    let mut updated_person = people[1].clone();
+    updated_person.set("message", Value::Text("Nothing to see here".to_string()));
+    drive
+        .update_document_for_contract(
+            &updated_person,
+            &contract,
+            person_document_type,
+            None,
+            BlockInfo::genesis(),
+            true,
+            None,
+            None,
+            platform_version,
+        )
+        .expect("expected to update the document");
+
+    assert_word_query("computers", vec![&people[0]]);
+    assert_word_query("the", vec![&people[2]]);
+    assert_word_query("nothing", vec![&people[1]]);
+
+    drive
+        .delete_document_for_contract(
+            people[0].id().to_buffer(),
+            &contract,
+            "person",
+            BlockInfo::genesis(),
+            true,
+            None,
+            platform_version,
+        )
+        .expect("expected to be able to delete the document");
+
+    assert_word_query("computers", vec![]);
+    assert_word_query("useless", vec![]);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_array_item_queries() {
+    let (drive, contract) = setup_family_tests_for_contract(
+        5,
+        73509,
+        "tests/supporting_files/contract/family/family-contract-with-tags.json",
+    );
+    let platform_version = PlatformVersion::latest();
+
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+
+    let build_query = |query_value: serde_json::Value| {
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            person_document_type,
+            &drive.config,
+            platform_version,
+        )
+    };
+
+    let all_query = build_query(json!({
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    }))
+    .expect("query should be built");
+    let (results, _, _) = all_query
+        .execute_raw_results_no_proof(&drive, None, None, platform_version)
+        .expect("query should be executed");
+    let people: Vec<Document> = results
+        .iter()
+        .map(|result| {
+            Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                .expect("we should be able to deserialize the document")
+        })
+        .collect();
+    assert_eq!(people.len(), 5);
+
+    let set_tags = |person: &Document, tags: &[&str]| {
+        let mut person = person.clone();
+        person.set(
+            "tags",
+            Value::Array(
+                tags.iter()
+                    .map(|tag| Value::Text(tag.to_string()))
+                    .collect(),
+            ),
+        );
+        drive
+            .update_document_for_contract(
+                &person,
+                &contract,
+                person_document_type,
+                None,
+                BlockInfo::genesis(),
+                true,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to update the document");
+    };
+
+    // Every tag of a person gets its own index entry, duplicated tags are indexed once
+    set_tags(&people[0], &["chess", "music"]);
+    set_tags(&people[1], &["music", "music", "travel"]);
+    set_tags(&people[2], &["chess"]);
+
+    // Tag queries return the ids of the matching people, with and without a proof
+    let assert_tag_query = |where_clause: serde_json::Value, expected: Vec<&Document>| {
+        let query = build_query(json!({
+            "where": [where_clause],
+            "limit": 100,
+        }))
+        .expect("query should be built");
+
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None, platform_version)
+            .expect("query should be executed");
+        let mut ids: Vec<Identifier> = results
+            .iter()
+            .map(|result| {
+                Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                    .expect("we should be able to deserialize the document")
+                    .id()
+            })
+            .collect();
+        ids.sort();
+        let mut expected_ids: Vec<Identifier> = expected.iter().map(|person| person.id()).collect();
+        expected_ids.sort();
+        assert_eq!(ids, expected_ids);
+
+        let (proof, _) = query
+            .clone()
+            .execute_with_proof(&drive, None, None, platform_version)
+            .expect("we should be able to a proof");
+        let (proof_root_hash, documents) = query
+            .verify_proof(proof.as_slice(), platform_version)
+            .expect("we should be able to verify the proof");
+        let root_hash = drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("there is always a root hash");
+        assert_eq!(root_hash, proof_root_hash);
+        let mut proved_ids: Vec<Identifier> =
+            documents.iter().map(|document| document.id()).collect();
+        proved_ids.sort();
+        assert_eq!(proved_ids, expected_ids);
+    };
+
+    assert_tag_query(json!(["tags", "==", "chess"]), vec![&people[0], &people[2]]);
+    assert_tag_query(json!(["tags", "==", "music"]), vec![&people[0], &people[1]]);
+    assert_tag_query(json!(["tags", "==", "cooking"]), vec![]);
+    // A person having several of the tags is returned once
+    assert_tag_query(
+        json!(["tags", "in", ["chess", "travel"]]),
+        vec![&people[0], &people[1], &people[2]],
+    );
+    assert_tag_query(json!(["tags", "in", ["travel"]]), vec![&people[1]]);
+
+    // Tags can not be queried by range
+    assert!(matches!(
+        build_query(json!({
+            "where": [
+                ["tags", "startsWith", "mus"],
+            ],
+        })),
+        Err(Error::Query(QuerySyntaxError::InvalidArrayClause(_)))
+    ));
+
+    // In clauses on tags can not expand to more branches than the maximum
+    let too_many_tags: Vec<String> = (0..11).map(|i| format!("tag{}", i)).collect();
+    assert!(matches!(
+        build_query(json!({
+            "where": [
+                ["tags", "in", too_many_tags],
+            ],
+        })),
+        Err(Error::Query(QuerySyntaxError::InvalidOrClause(_)))
+    ));
+    assert!(matches!(
+        build_query(json!({
+            "where": [
+                {"or": [
+                    ["tags", "in", &too_many_tags[..6]],
+                    ["tags", "in", &too_many_tags[6..]],
+                ]}
+            ],
+        })),
+        Err(Error::Query(QuerySyntaxError::InvalidOrClause(_)))
+    ));
+
+    // Tag indices follow updates and deletions of documents
+    set_tags(&people[0], &["music", "cooking"]);
+
+    assert_tag_query(json!(["tags", "==", "chess"]), vec![&people[2]]);
+    assert_tag_query(json!(["tags", "==", "music"]), vec![&people[0], &people[1]]);
+    assert_tag_query(json!(["tags", "==", "cooking"]), vec![&people[0]]);
+
+    drive
+        .delete_document_for_contract(
+            people[0].id().to_buffer(),
+            &contract,
+            "person",
+            BlockInfo::genesis(),
+            true,
+            None,
+            platform_version,
+        )
+        .expect("expected to be able to delete the document");
+
+    assert_tag_query(json!(["tags", "==", "music"]), vec![&people[1]]);
+    assert_tag_query(json!(["tags", "==", "cooking"]), vec![]);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_with_nulls_query() {
+    let (drive, contract) = setup_family_tests_with_nulls(10, 30004);
+
+    let platform_version = PlatformVersion::latest();
+
+    let db_transaction = drive.grove.start_transaction();
+
+    let root_hash = drive
+        .grove
+        .root_hash(Some(&db_transaction))
+        .unwrap()
+        .expect("there is always a root hash");
+
+    let expected_app_hash = vec![
+        100, 192, 55, 56, 28, 89, 114, 51, 246, 239, 246, 155, 157, 46, 242, 255, 61, 30, 111, 226,
+        252, 227, 130, 243, 99, 202, 83, 249, 253, 202, 142, 36,
+    ];
+
+    assert_eq!(root_hash.as_slice(), expected_app_hash);
+
+    let all_names = [
+        "".to_string(),
+        "".to_string(),
+        "".to_string(),
+        "".to_string(),
+        "".to_string(),
+        "".to_string(),
+        "Alexia".to_string(),
+        "Gerti".to_string(),
+        "Latisha".to_string(),
+        "Norry".to_string(),
+    ];
+
+    // A query getting all elements by firstName
+
+    let query_value = json!({
+        "where": [
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    });
+    let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+        .expect("expected to serialize to cbor");
+    let person_document_type = contract
+        .document_type_for_name("person")
+        .expect("contract should have a person document type");
+    let query = DriveQuery::from_cbor(
+        where_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+        platform_version,
+    )
+    .expect("query should be built");
+    let (results, _, _) = query
+        .execute_raw_results_no_proof(&drive, None, Some(&db_transaction), platform_version)
+        .expect("proof should be executed");
+    let names: Vec<String> = results
+        .clone()
+        .into_iter()
+        .map(|result| {
+            let document =
+                Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                    .expect("we should be able to deserialize the document");
+            document
+                .get("firstName")
+                .map(|value| {
+                    let first_name_value = value
+                        .as_text()
+                        .expect("the normalized label should be a string");
+                    String::from(first_name_value)
+                })
+                .unwrap_or_default()
+        })
+        .collect();
+
+    assert_eq!(names, all_names);
+
+    let (proof_root_hash, proof_results, _) = query
+        .execute_with_proof_only_get_elements(&drive, None, None, platform_version)
+        .expect("we should be able to a proof");
+    assert_eq!(root_hash, proof_root_hash);
+    assert_eq!(results, proof_results);
+
+    let ids: Vec<String> = results
+        .iter()
+        .map(|result| {
+            let document =
+                Document::from_bytes(result.as_slice(), person_document_type, platform_version)
+                    .expect("we should be able to deserialize the document");
+            base64::encode(document.id().as_slice())
+        })
+        .collect();
+
+    for i in 0..10 {
+        drive
+            .delete_document_for_contract(
+                base64::decode(ids.get(i).unwrap())
+                    .expect("expected to decode from base64")
+                    .try_into()
+                    .expect("expected to get 32 bytes"),
+                &contract,
+                "person",
+                BlockInfo::genesis(),
+                true,
+                Some(&db_transaction),
+                platform_version,
+            )
+            .expect("expected to be able to delete the document");
+    }
+
+    drive
+        .grove
+        .commit_transaction(db_transaction)
+        .unwrap()
+        .expect("unable to commit transaction");
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_query_with_cached_contract() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+
+    let platform_version = PlatformVersion::latest();
+
+    let db_transaction = drive.grove.start_transaction();
+
+    let root_hash = drive
+        .grove
+        .root_hash(Some(&db_transaction))
+        .unwrap()
+        .expect("there is always a root hash");
+
+    // Make sure the state is deterministic
+    let expected_app_hash = vec![
+        89, 169, 192, 133, 222, 85, 217, 165, 166, 122, 195, 124, 55, 108, 16, 158, 1, 80, 226, 24,
+        74, 1, 201, 200, 22, 166, 46, 239, 191, 250, 138, 149,
+    ];
+
+    assert_eq!(root_hash.as_slice(), expected_app_hash);
+
+    // Make sure contract is not cached
+    let contract_ref = drive
+        .get_cached_contract_with_fetch_info(
+            *contract.id_ref().as_bytes(),
+            Some(&db_transaction),
+            &platform_version.drive,
+        )
+        .expect("should return a contract ref");
+
+    assert!(contract_ref.is_none());
+
+    // A query getting all elements by firstName
+
+    let query_value = json!({
+        "where": [
+        ],
+        "limit": 100,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    });
+    let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+        .expect("expected to serialize to cbor");
+
+    let QuerySerializedDocumentsOutcome { items, .. } = drive
+        .query_documents_cbor_with_document_type_lookup(
+            where_cbor.as_slice(),
+            *contract.id_ref().as_bytes(),
+            "person",
+            None,
+            Some(&db_transaction),
+            Some(platform_version.protocol_version),
+        )
+        .expect("query should be executed");
+
+    assert_eq!(items.len(), 10);
+
+    // Cache was populated and there only two ref two the cached fetched info (here and cache)
+    let contract_ref = drive
+        .get_cached_contract_with_fetch_info(
+            *contract.id_ref().as_bytes(),
+            Some(&db_transaction),
+            &platform_version.drive,
+        )
+        .expect("should return a contract ref")
+        .expect("expected a reference counter to the contract");
+
+    assert_eq!(Arc::strong_count(&contract_ref), 2);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_dpns_query_contract_verification() {
     let (drive, contract) = setup_dpns_tests_with_batches(10, 11456);
 
     let platform_version = PlatformVersion::latest();
@@ -3204,6 +4690,7 @@ fn test_dpns_query() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -3255,6 +4742,7 @@ fn test_dpns_query() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -3335,6 +4823,7 @@ fn test_dpns_query() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -3393,6 +4882,7 @@ fn test_dpns_query() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -3468,6 +4958,7 @@ fn test_dpns_query() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -3524,6 +5015,7 @@ fn test_dpns_query() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -3572,6 +5064,7 @@ fn test_dpns_query() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -3761,6 +5254,7 @@ fn test_dpns_query_start_at() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -3855,6 +5349,7 @@ fn test_dpns_query_start_after() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -3949,6 +5444,7 @@ fn test_dpns_query_start_at_desc() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -4043,6 +5539,7 @@ fn test_dpns_query_start_after_desc() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -4234,6 +5731,7 @@ fn test_dpns_query_start_at_with_null_id() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
 
@@ -4435,6 +5933,7 @@ fn test_dpns_query_start_after_with_null_id() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
 
@@ -4650,6 +6149,7 @@ fn test_dpns_query_start_after_with_null_id_desc() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -4704,6 +6204,7 @@ fn test_dpns_query_start_after_with_null_id_desc() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -4758,6 +6259,7 @@ fn test_dpns_query_start_after_with_null_id_desc() {
         &contract,
         domain_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -5027,6 +6529,7 @@ fn test_query_documents_by_created_at() {
         &contract,
         document_type,
         &DriveConfig::default(),
+        platform_version,
     )
     .expect("should create a query from cbor");
 
@@ -329,6 +329,7 @@ fn test_query_historical() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -578,6 +579,7 @@ fn test_query_historical() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -628,6 +630,7 @@ fn test_query_historical() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -674,6 +677,7 @@ fn test_query_historical() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -754,6 +758,7 @@ fn test_query_historical() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -808,6 +813,7 @@ fn test_query_historical() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -856,6 +862,7 @@ fn test_query_historical() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -898,6 +905,7 @@ fn test_query_historical() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -973,6 +981,7 @@ fn test_query_historical() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -1027,6 +1036,7 @@ fn test_query_historical() {
         &contract,
         person_document_type,
         &drive.config,
+        platform_version,
     )
     .expect("query should be built");
     let (results, _, _) = query
@@ -46,6 +46,8 @@ pub struct DocumentsBatchTransitionVersions {
 pub struct DocumentsBatchTransitionValidationVersions {
     pub find_duplicates_by_id: FeatureVersion,
     pub validate_base_structure: FeatureVersion,
+    /// The maximum number of document transitions in a documents batch
+    pub max_transitions_in_documents_batch: u16,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -108,6 +110,7 @@ pub struct StateTransitionSerializationVersions {
     pub identity_top_up_state_transition: FeatureVersionBounds,
     pub identity_credit_withdrawal_state_transition: FeatureVersionBounds,
     pub identity_credit_transfer_state_transition: FeatureVersionBounds,
+    pub identity_recover_state_transition: Option<FeatureVersionBounds>,
     pub contract_create_state_transition: FeatureVersionBounds,
     pub contract_update_state_transition: FeatureVersionBounds,
     pub documents_batch_state_transition: FeatureVersionBounds,
@@ -115,6 +118,9 @@ pub struct StateTransitionSerializationVersions {
     pub document_create_state_transition: DocumentFeatureVersionBounds,
     pub document_replace_state_transition: DocumentFeatureVersionBounds,
     pub document_delete_state_transition: DocumentFeatureVersionBounds,
+    pub document_transfer_state_transition: DocumentFeatureVersionBounds,
+    pub document_update_price_state_transition: DocumentFeatureVersionBounds,
+    pub document_purchase_state_transition: DocumentFeatureVersionBounds,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -217,4 +223,5 @@ pub struct DocumentMethodVersions {
     pub hash: FeatureVersion,
     pub get_raw_for_contract: FeatureVersion,
     pub get_raw_for_document_type: FeatureVersion,
+    pub get_raw_array_elements_for_document_type: FeatureVersion,
 }
@@ -13,6 +13,8 @@ pub struct DriveAbciQueryVersions {
     pub response_metadata: FeatureVersion,
     pub proofs_query: FeatureVersionBounds,
     pub document_query: FeatureVersionBounds,
+    pub document_aggregate_query: FeatureVersionBounds,
+    pub document_subscription: FeatureVersionBounds,
     pub identity_based_queries: DriveAbciQueryIdentityVersions,
     pub data_contract_based_queries: DriveAbciQueryDataContractVersions,
     pub system: DriveAbciQuerySystemVersions,
@@ -29,6 +31,8 @@ pub struct DriveAbciQueryIdentityVersions {
     pub balance_and_revision: FeatureVersionBounds,
     pub identity_by_public_key_hash: FeatureVersionBounds,
     pub identities_by_public_key_hashes: FeatureVersionBounds,
+    pub pending_transfers: FeatureVersionBounds,
+    pub withdrawals: FeatureVersionBounds,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -97,9 +101,15 @@ pub struct DriveAbciDocumentsStateTransitionValidationVersions {
     pub document_create_transition_structure_validation: FeatureVersion,
     pub document_delete_transition_structure_validation: FeatureVersion,
     pub document_replace_transition_structure_validation: FeatureVersion,
+    pub document_transfer_transition_structure_validation: FeatureVersion,
+    pub document_update_price_transition_structure_validation: FeatureVersion,
+    pub document_purchase_transition_structure_validation: FeatureVersion,
     pub document_create_transition_state_validation: FeatureVersion,
     pub document_delete_transition_state_validation: FeatureVersion,
     pub document_replace_transition_state_validation: FeatureVersion,
+    pub document_transfer_transition_state_validation: FeatureVersion,
+    pub document_update_price_transition_state_validation: FeatureVersion,
+    pub document_purchase_transition_state_validation: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -138,6 +148,9 @@ pub struct DriveAbciStateTransitionValidationVersions {
     pub identity_top_up_state_transition: DriveAbciStateTransitionValidationVersion,
     pub identity_credit_withdrawal_state_transition: DriveAbciStateTransitionValidationVersion,
     pub identity_credit_transfer_state_transition: DriveAbciStateTransitionValidationVersion,
+    pub identity_recover_state_transition: DriveAbciStateTransitionValidationVersion,
+    pub identity_recovery_delay_ms: u64, //how long the master keys of an identity can veto its recovery unless the identity set its own delay, for v1 it's 14 days.
+    pub min_identity_recovery_delay_ms: u64, //the shortest recovery delay an identity can set, for v1 it's 1 day.
     pub contract_create_state_transition: DriveAbciStateTransitionValidationVersion,
     pub contract_update_state_transition: DriveAbciStateTransitionValidationVersion,
     pub documents_batch_state_transition: DriveAbciDocumentsStateTransitionValidationVersions,
@@ -146,6 +159,7 @@ pub struct DriveAbciStateTransitionValidationVersions {
 #[derive(Clone, Debug, Default)]
 pub struct DriveAbciStateTransitionCommonValidationVersions {
     pub asset_locks: DriveAbciAssetLockValidationVersions,
+    pub validate_identity_key_spending_limit: FeatureVersion,
     pub validate_identity_public_key_contract_bounds: FeatureVersion,
     pub validate_identity_public_key_ids_dont_exist_in_state: FeatureVersion,
     pub validate_identity_public_key_ids_exist_in_state: FeatureVersion,
@@ -249,6 +263,8 @@ pub struct DriveAbciBlockEndMethodVersions {
     pub update_state_cache: FeatureVersion,
     pub update_drive_cache: FeatureVersion,
     pub validator_set_update: FeatureVersion,
+    pub publish_document_changes: FeatureVersion,
+    pub release_pending_transfers: OptionalFeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -258,12 +274,15 @@ pub struct DriveAbciIdentityCreditWithdrawalMethodVersions {
     pub fetch_transactions_block_inclusion_status: FeatureVersion,
     pub pool_withdrawals_into_transactions_queue: FeatureVersion,
     pub update_broadcasted_withdrawal_statuses: FeatureVersion,
+    pub refund_expired_withdrawals: FeatureVersion,
     pub append_signatures_and_broadcast_withdrawal_transactions: FeatureVersion,
+    pub store_withdrawal_transaction_ids: OptionalFeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct DriveAbciProtocolUpgradeMethodVersions {
     pub check_for_desired_protocol_upgrade: FeatureVersion,
+    pub perform_events_on_first_block_of_protocol_change: OptionalFeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
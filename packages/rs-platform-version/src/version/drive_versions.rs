@@ -82,11 +82,13 @@ pub struct DriveVerifyDocumentMethodVersions {
     pub verify_proof: FeatureVersion,
     pub verify_proof_keep_serialized: FeatureVersion,
     pub verify_start_at_document_in_proof: FeatureVersion,
+    pub verify_aggregate_proof: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct DriveVerifyIdentityMethodVersions {
     pub verify_full_identities_by_public_key_hashes: FeatureVersion,
+    pub verify_full_identities_by_identity_ids: FeatureVersion,
     pub verify_full_identity_by_identity_id: FeatureVersion,
     pub verify_full_identity_by_public_key_hash: FeatureVersion,
     pub verify_identity_balance_for_identity_id: FeatureVersion,
@@ -96,6 +98,11 @@ pub struct DriveVerifyIdentityMethodVersions {
     pub verify_identity_keys_by_identity_id: FeatureVersion,
     pub verify_identity_nonce: FeatureVersion,
     pub verify_identity_contract_nonce: FeatureVersion,
+    pub verify_pending_transfer: FeatureVersion,
+    pub verify_pending_transfers_for_identity_id: FeatureVersion,
+    pub verify_identity_recovery_proposal: FeatureVersion,
+    pub verify_identity_recovery_delay: FeatureVersion,
+    pub verify_withdrawals_for_identity_id: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -248,12 +255,20 @@ pub struct DriveDocumentMethodVersions {
 #[derive(Clone, Debug, Default)]
 pub struct DriveDocumentQueryMethodVersions {
     pub query_documents: FeatureVersion,
+    /// The maximum number of conjunctive branches a query with or clauses, or with in clauses
+    /// on array properties, can be split into
+    pub max_disjunctive_branches: u16,
+    /// The maximum number of documents a count aggregate query can count, counts are computed
+    /// by reading every matched document
+    pub max_aggregate_count: u16,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct DriveDocumentEstimationCostsMethodVersions {
     pub add_estimation_costs_for_add_document_to_primary_storage: FeatureVersion,
     pub stateless_delete_of_non_tree_for_costs: FeatureVersion,
+    pub add_estimation_costs_for_token_indices: FeatureVersion,
+    pub add_estimation_costs_for_index_sums: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -266,6 +281,8 @@ pub struct DriveDocumentInsertMethodVersions {
     pub add_indices_for_index_level_for_contract_operations: FeatureVersion,
     pub add_indices_for_top_index_level_for_contract_operations: FeatureVersion,
     pub add_reference_for_index_level_for_contract_operations: FeatureVersion,
+    pub add_index_sums_for_document_operations: FeatureVersion,
+    pub add_token_indices_for_document_operations: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -277,6 +294,9 @@ pub struct DriveDocumentUpdateMethodVersions {
     pub update_document_for_contract_operations: FeatureVersion,
     pub update_document_with_serialization_for_contract: FeatureVersion,
     pub update_serialized_document_for_contract: FeatureVersion,
+    pub update_index_sums_for_document_operations: FeatureVersion,
+    pub update_token_indices_for_document_operations: FeatureVersion,
+    pub update_multikey_indices_for_document_operations: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -289,6 +309,8 @@ pub struct DriveDocumentDeleteMethodVersions {
     pub remove_reference_for_index_level_for_contract_operations: FeatureVersion,
     pub remove_indices_for_index_level_for_contract_operations: FeatureVersion,
     pub remove_indices_for_top_index_level_for_contract_operations: FeatureVersion,
+    pub remove_index_sums_for_document_operations: FeatureVersion,
+    pub remove_token_indices_for_document_operations: FeatureVersion,
     pub delete_document_for_contract_id_with_named_type_operations: FeatureVersion,
     pub delete_document_for_contract_with_named_type_operations: FeatureVersion,
     pub delete_document_for_contract_operations: FeatureVersion,
@@ -438,12 +460,14 @@ pub struct DriveIdentityWithdrawalMethodVersions {
 pub struct DriveIdentityWithdrawalDocumentMethodVersions {
     pub fetch_oldest_withdrawal_documents_by_status: FeatureVersion,
     pub find_up_to_100_withdrawal_documents_by_status_and_transaction_indices: FeatureVersion,
+    pub prove_withdrawals_for_identity_id: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct DriveIdentityWithdrawalTransactionMethodVersions {
     pub index: DriveIdentityWithdrawalTransactionIndexMethodVersions,
     pub queue: DriveIdentityWithdrawalTransactionQueueMethodVersions,
+    pub ids: DriveIdentityWithdrawalTransactionIdsMethodVersions,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -456,6 +480,15 @@ pub struct DriveIdentityWithdrawalTransactionIndexMethodVersions {
 pub struct DriveIdentityWithdrawalTransactionQueueMethodVersions {
     pub add_enqueue_untied_withdrawal_transaction_operations: FeatureVersion,
     pub dequeue_untied_withdrawal_transactions: FeatureVersion,
+    pub add_enqueue_unsigned_withdrawal_transaction_operations: FeatureVersion,
+    pub dequeue_unsigned_withdrawal_transactions: FeatureVersion,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveIdentityWithdrawalTransactionIdsMethodVersions {
+    pub add_insert_withdrawal_transaction_ids_operations: FeatureVersion,
+    pub fetch_withdrawal_transaction_ids: FeatureVersion,
+    pub prove_withdrawal_transaction_ids: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -474,6 +507,7 @@ pub struct DriveIdentityCostEstimationMethodVersions {
     pub for_contract_info_group_key_purpose: FeatureVersion,
     pub for_keys_for_identity_id: FeatureVersion,
     pub for_negative_credit: FeatureVersion,
+    pub for_pending_transfers: FeatureVersion,
     pub for_purpose_in_key_reference_tree: FeatureVersion,
     pub for_root_key_reference_tree: FeatureVersion,
     pub for_update_revision: FeatureVersion,
@@ -592,6 +626,7 @@ pub struct DriveIdentityUpdateMethodVersions {
     pub initialize_identity_revision: FeatureVersion,
     pub disable_identity_keys: FeatureVersion,
     pub re_enable_identity_keys: FeatureVersion,
+    pub update_identity_key_spending_limits: FeatureVersion,
     pub add_new_non_unique_keys_to_identity: FeatureVersion,
     pub add_new_unique_keys_to_identity: FeatureVersion,
     pub add_new_keys_to_identity: FeatureVersion,
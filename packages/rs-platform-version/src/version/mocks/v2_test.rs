@@ -53,6 +53,7 @@ use crate::version::drive_versions::{
     DriveIdentityKeysProveMethodVersions, DriveIdentityMethodVersions,
     DriveIdentityProveMethodVersions, DriveIdentityUpdateMethodVersions,
     DriveIdentityWithdrawalDocumentMethodVersions, DriveIdentityWithdrawalMethodVersions,
+    DriveIdentityWithdrawalTransactionIdsMethodVersions,
     DriveIdentityWithdrawalTransactionIndexMethodVersions,
     DriveIdentityWithdrawalTransactionMethodVersions,
     DriveIdentityWithdrawalTransactionQueueMethodVersions, DriveInitializationMethodVersions,
@@ -153,7 +154,11 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 calculate_total_credits_balance: 0,
             },
             document: DriveDocumentMethodVersions {
-                query: DriveDocumentQueryMethodVersions { query_documents: 0 },
+                query: DriveDocumentQueryMethodVersions {
+                    query_documents: 0,
+                    max_disjunctive_branches: 10,
+                    max_aggregate_count: 1000,
+                },
                 delete: DriveDocumentDeleteMethodVersions {
                     add_estimation_costs_for_remove_document_to_primary_storage: 0,
                     delete_document_for_contract: 0,
@@ -163,6 +168,8 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     remove_reference_for_index_level_for_contract_operations: 0,
                     remove_indices_for_index_level_for_contract_operations: 0,
                     remove_indices_for_top_index_level_for_contract_operations: 0,
+                    remove_index_sums_for_document_operations: 0,
+                    remove_token_indices_for_document_operations: 0,
                     delete_document_for_contract_id_with_named_type_operations: 0,
                     delete_document_for_contract_with_named_type_operations: 0,
                     delete_document_for_contract_operations: 0,
@@ -176,6 +183,8 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     add_indices_for_index_level_for_contract_operations: 0,
                     add_indices_for_top_index_level_for_contract_operations: 0,
                     add_reference_for_index_level_for_contract_operations: 0,
+                    add_index_sums_for_document_operations: 0,
+                    add_token_indices_for_document_operations: 0,
                 },
                 update: DriveDocumentUpdateMethodVersions {
                     add_update_multiple_documents_operations: 0,
@@ -185,10 +194,15 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     update_document_for_contract_operations: 0,
                     update_document_with_serialization_for_contract: 0,
                     update_serialized_document_for_contract: 0,
+                    update_index_sums_for_document_operations: 0,
+                    update_token_indices_for_document_operations: 0,
+                    update_multikey_indices_for_document_operations: 0,
                 },
                 estimation_costs: DriveDocumentEstimationCostsMethodVersions {
                     add_estimation_costs_for_add_document_to_primary_storage: 0,
                     stateless_delete_of_non_tree_for_costs: 0,
+                    add_estimation_costs_for_token_indices: 0,
+                    add_estimation_costs_for_index_sums: 0,
                 },
                 index_uniqueness: DriveDocumentIndexUniquenessMethodVersions {
                     validate_document_uniqueness: 0,
@@ -242,9 +256,11 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     verify_proof: 0,
                     verify_proof_keep_serialized: 0,
                     verify_start_at_document_in_proof: 0,
+                    verify_aggregate_proof: 0,
                 },
                 identity: DriveVerifyIdentityMethodVersions {
                     verify_full_identities_by_public_key_hashes: 0,
+                    verify_full_identities_by_identity_ids: 0,
                     verify_full_identity_by_identity_id: 0,
                     verify_full_identity_by_public_key_hash: 0,
                     verify_identity_balance_for_identity_id: 0,
@@ -254,6 +270,11 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     verify_identity_keys_by_identity_id: 0,
                     verify_identity_nonce: 0,
                     verify_identity_contract_nonce: 0,
+                    verify_pending_transfer: 0,
+                    verify_pending_transfers_for_identity_id: 0,
+                    verify_identity_recovery_proposal: 0,
+                    verify_identity_recovery_delay: 0,
+                    verify_withdrawals_for_identity_id: 0,
                 },
                 single_document: DriveVerifySingleDocumentMethodVersions {
                     verify_proof: 0,
@@ -347,6 +368,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     initialize_identity_revision: 0,
                     disable_identity_keys: 0,
                     re_enable_identity_keys: 0,
+                    update_identity_key_spending_limits: 0,
                     add_new_non_unique_keys_to_identity: 0,
                     add_new_unique_keys_to_identity: 0,
                     add_new_keys_to_identity: 0,
@@ -373,6 +395,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     for_contract_info_group_key_purpose: 0,
                     for_keys_for_identity_id: 0,
                     for_negative_credit: 0,
+                    for_pending_transfers: 0,
                     for_purpose_in_key_reference_tree: 0,
                     for_root_key_reference_tree: 0,
                     for_update_revision: 0,
@@ -381,6 +404,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     document: DriveIdentityWithdrawalDocumentMethodVersions {
                         fetch_oldest_withdrawal_documents_by_status: 0,
                         find_up_to_100_withdrawal_documents_by_status_and_transaction_indices: 0,
+                        prove_withdrawals_for_identity_id: 0,
                     },
                     transaction: DriveIdentityWithdrawalTransactionMethodVersions {
                         index: DriveIdentityWithdrawalTransactionIndexMethodVersions {
@@ -390,6 +414,13 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                         queue: DriveIdentityWithdrawalTransactionQueueMethodVersions {
                             add_enqueue_untied_withdrawal_transaction_operations: 0,
                             dequeue_untied_withdrawal_transactions: 0,
+                            add_enqueue_unsigned_withdrawal_transaction_operations: 0,
+                            dequeue_unsigned_withdrawal_transactions: 0,
+                        },
+                        ids: DriveIdentityWithdrawalTransactionIdsMethodVersions {
+                            add_insert_withdrawal_transaction_ids_operations: 0,
+                            fetch_withdrawal_transaction_ids: 0,
+                            prove_withdrawal_transaction_ids: 0,
                         },
                     },
                 },
@@ -528,6 +559,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
             },
             protocol_upgrade: DriveAbciProtocolUpgradeMethodVersions {
                 check_for_desired_protocol_upgrade: 0,
+                perform_events_on_first_block_of_protocol_change: None,
             },
             block_fee_processing: DriveAbciBlockFeeProcessingMethodVersions {
                 add_process_epoch_change_operations: 0,
@@ -560,7 +592,9 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 fetch_transactions_block_inclusion_status: 0,
                 pool_withdrawals_into_transactions_queue: 0,
                 update_broadcasted_withdrawal_statuses: 0,
+                refund_expired_withdrawals: 0,
                 append_signatures_and_broadcast_withdrawal_transactions: 0,
+                store_withdrawal_transaction_ids: None,
             },
             state_transition_processing: DriveAbciStateTransitionProcessingMethodVersions {
                 execute_event: 0,
@@ -578,6 +612,8 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 update_state_cache: 0,
                 update_drive_cache: 0,
                 validator_set_update: 0,
+                publish_document_changes: 0,
+                release_pending_transfers: None,
             },
             platform_state_storage: DriveAbciPlatformStateStorageMethodVersions {
                 fetch_platform_state: 0,
@@ -590,6 +626,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     asset_locks: DriveAbciAssetLockValidationVersions {
                         fetch_asset_lock_transaction_output_sync: 0,
                     },
+                    validate_identity_key_spending_limit: 0,
                     validate_identity_public_key_contract_bounds: 0,
                     validate_identity_public_key_ids_dont_exist_in_state: 0,
                     validate_identity_public_key_ids_exist_in_state: 0,
@@ -643,6 +680,17 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                         state: 0,
                         transform_into_action: 0,
                     },
+                identity_recover_state_transition: DriveAbciStateTransitionValidationVersion {
+                    base_structure: 0,
+                    advanced_structure: None,
+                    identity_signatures: None,
+                    balance: None,
+                    nonce: None,
+                    state: 0,
+                    transform_into_action: 0,
+                },
+                identity_recovery_delay_ms: 1_209_600_000,
+                min_identity_recovery_delay_ms: 86_400_000,
                 contract_create_state_transition: DriveAbciStateTransitionValidationVersion {
                     base_structure: 0,
                     advanced_structure: None,
@@ -683,9 +731,15 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                         document_create_transition_structure_validation: 0,
                         document_delete_transition_structure_validation: 0,
                         document_replace_transition_structure_validation: 0,
+                        document_transfer_transition_structure_validation: 0,
+                        document_update_price_transition_structure_validation: 0,
+                        document_purchase_transition_structure_validation: 0,
                         document_create_transition_state_validation: 0,
                         document_delete_transition_state_validation: 0,
                         document_replace_transition_state_validation: 0,
+                        document_transfer_transition_state_validation: 0,
+                        document_update_price_transition_state_validation: 0,
+                        document_purchase_transition_state_validation: 0,
                     },
             },
             process_state_transition: 0,
@@ -703,6 +757,16 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 max_version: 0,
                 default_current_version: 0,
             },
+            document_aggregate_query: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
+            document_subscription: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
             identity_based_queries: DriveAbciQueryIdentityVersions {
                 identity: FeatureVersionBounds {
                     min_version: 0,
@@ -749,6 +813,16 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     max_version: 0,
                     default_current_version: 0,
                 },
+                pending_transfers: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+                withdrawals: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
             },
             data_contract_based_queries: DriveAbciQueryDataContractVersions {
                 data_contract: FeatureVersionBounds {
@@ -837,6 +911,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 max_version: 0,
                 default_current_version: 0,
             },
+            identity_recover_state_transition: None,
             contract_create_state_transition: FeatureVersionBounds {
                 min_version: 0,
                 max_version: 0,
@@ -878,6 +953,27 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     default_current_version: 0,
                 },
             },
+            document_transfer_state_transition: DocumentFeatureVersionBounds {
+                bounds: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+            },
+            document_update_price_state_transition: DocumentFeatureVersionBounds {
+                bounds: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+            },
+            document_purchase_state_transition: DocumentFeatureVersionBounds {
+                bounds: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+            },
         },
         state_transition_conversion_versions: StateTransitionConversionVersions {
             identity_to_identity_create_transition: 0,
@@ -901,6 +997,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     validation: DocumentsBatchTransitionValidationVersions {
                         find_duplicates_by_id: 0,
                         validate_base_structure: 0,
+                        max_transitions_in_documents_batch: 1,
                     },
                 },
             },
@@ -977,6 +1074,7 @@ pub(crate) const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 hash: 0,
                 get_raw_for_contract: 0,
                 get_raw_for_document_type: 0,
+                get_raw_array_elements_for_document_type: 0,
             },
         },
         identity_versions: IdentityVersions {
@@ -7,5 +7,6 @@ pub mod drive_versions;
 #[cfg(feature = "mock-versions")]
 pub mod mocks;
 mod v1;
+mod v2;
 
-pub const LATEST_VERSION: u32 = 1;
+pub const LATEST_VERSION: u32 = 2;
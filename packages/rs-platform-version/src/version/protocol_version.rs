@@ -10,6 +10,7 @@ use crate::version::mocks::v3_test::TEST_PLATFORM_V3;
 #[cfg(feature = "mock-versions")]
 use crate::version::mocks::TEST_BYTES;
 use crate::version::v1::PLATFORM_V1;
+use crate::version::v2::PLATFORM_V2;
 
 pub type FeatureVersion = u16;
 pub type OptionalFeatureVersion = Option<u16>; //This is a feature that didn't always exist
@@ -52,12 +53,12 @@ pub struct PlatformVersion {
     pub system_data_contracts: SystemDataContractVersions,
 }
 
-pub const PLATFORM_VERSIONS: &[PlatformVersion] = &[PLATFORM_V1];
+pub const PLATFORM_VERSIONS: &[PlatformVersion] = &[PLATFORM_V1, PLATFORM_V2];
 
 #[cfg(feature = "mock-versions")]
 pub const PLATFORM_TEST_VERSIONS: &[PlatformVersion] = &[TEST_PLATFORM_V2, TEST_PLATFORM_V3]; //this starts at 2
 
-pub const LATEST_PLATFORM_VERSION: &PlatformVersion = &PLATFORM_V1;
+pub const LATEST_PLATFORM_VERSION: &PlatformVersion = &PLATFORM_V2;
 
 impl PlatformVersion {
     pub fn get<'a>(version: u32) -> Result<&'a Self, PlatformVersionError> {
@@ -8,7 +8,8 @@ use dashcore_rpc::{
     dashcore_rpc_json as json, Auth, Client, RpcApi,
 };
 use drive_proof_verifier::error::ContextProviderError;
-use std::{fmt::Debug, sync::Mutex};
+use rs_dapi_client::Uri;
+use std::{fmt::Debug, net::SocketAddr, sync::Mutex};
 
 use crate::error::Error;
 
@@ -123,3 +124,124 @@ impl CoreClient {
         Ok(pubkey)
     }
 }
+
+// Masternode list functions
+impl CoreClient {
+    /// Retrieve DAPI endpoints of evonodes from the current deterministic masternode list.
+    ///
+    /// Evonodes banned by PoSe and evonodes without platform HTTP port are skipped.
+    pub fn get_evonode_dapi_uris(&self) -> Result<Vec<Uri>, Error> {
+        let core = self.core.lock().expect("Core lock poisoned");
+
+        let block_count = core.get_block_count().map_err(Error::CoreClientError)?;
+        let block_height = u32::try_from(block_count)
+            .map_err(|e| Error::Generic(format!("invalid core block height: {}", e)))?;
+
+        let masternode_list = core
+            .get_protx_listdiff(1, block_height)
+            .map_err(Error::CoreClientError)?;
+
+        Ok(evonode_dapi_uris(masternode_list.added_mns))
+    }
+}
+
+/// DAPI endpoints of evonodes from the `masternodes` list.
+fn evonode_dapi_uris(masternodes: Vec<json::MasternodeListItem>) -> Vec<Uri> {
+    masternodes
+        .into_iter()
+        .filter(|masternode| {
+            masternode.node_type == json::MasternodeType::Evo
+                && masternode.state.pose_ban_height.is_none()
+        })
+        .filter_map(|masternode| {
+            let port = u16::try_from(masternode.state.platform_http_port?).ok()?;
+            let address = SocketAddr::new(masternode.state.service.ip(), port);
+
+            match Uri::try_from(format!("https://{}", address)) {
+                Ok(uri) => Some(uri),
+                Err(error) => {
+                    tracing::warn!(
+                        pro_tx_hash = ?masternode.pro_tx_hash,
+                        ?error,
+                        "skipping evonode with invalid DAPI address {}",
+                        address
+                    );
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dashcore_rpc::dashcore::{ProTxHash, Txid};
+
+    fn masternode(
+        node_type: json::MasternodeType,
+        service: &str,
+        platform_http_port: Option<u32>,
+        pose_ban_height: Option<u32>,
+    ) -> json::MasternodeListItem {
+        json::MasternodeListItem {
+            node_type,
+            pro_tx_hash: ProTxHash::from_byte_array([1; 32]),
+            collateral_hash: Txid::from_byte_array([2; 32]),
+            collateral_index: 0,
+            collateral_address: [0; 20],
+            operator_reward: 0.0,
+            state: json::DMNState {
+                service: service.parse().expect("expected a socket address"),
+                registered_height: 0,
+                pose_revived_height: None,
+                pose_ban_height,
+                revocation_reason: 0,
+                owner_address: [0; 20],
+                voting_address: [0; 20],
+                payout_address: [0; 20],
+                pub_key_operator: vec![],
+                operator_payout_address: None,
+                platform_node_id: None,
+                platform_p2p_port: None,
+                platform_http_port,
+            },
+        }
+    }
+
+    #[test]
+    fn test_evonode_dapi_uris_skip_regular_banned_and_portless_nodes() {
+        let masternodes = vec![
+            masternode(
+                json::MasternodeType::Evo,
+                "10.0.0.1:20001",
+                Some(1443),
+                None,
+            ),
+            masternode(
+                json::MasternodeType::Regular,
+                "10.0.0.2:20001",
+                Some(1443),
+                None,
+            ),
+            masternode(
+                json::MasternodeType::Evo,
+                "10.0.0.3:20001",
+                Some(1443),
+                Some(100),
+            ),
+            masternode(json::MasternodeType::Evo, "10.0.0.4:20001", None, None),
+            masternode(
+                json::MasternodeType::Evo,
+                "10.0.0.5:20001",
+                Some(70000),
+                None,
+            ),
+        ];
+
+        assert_eq!(
+            evonode_dapi_uris(masternodes),
+            vec![Uri::from_static("https://10.0.0.1:1443")]
+        );
+    }
+}
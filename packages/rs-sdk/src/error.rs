@@ -5,7 +5,7 @@ use std::time::Duration;
 use dpp::bls_signatures::BlsError;
 use dpp::version::PlatformVersionError;
 use dpp::ProtocolError;
-use rs_dapi_client::DapiClientError;
+use rs_dapi_client::{CanRetry, DapiClientError};
 
 pub use drive_proof_verifier::error::ContextProviderError;
 
@@ -72,6 +72,32 @@ impl<T: Debug> From<DapiClientError<T>> for Error {
     }
 }
 
+impl CanRetry for Error {
+    /// Only errors of proof verification the node is responsible for, like an invalid proof or
+    /// signature, are node failures; errors of the request or the context provider are not.
+    fn is_node_failure(&self) -> bool {
+        use drive_proof_verifier::Error as ProofError;
+
+        match self {
+            Error::Proof(error) => match error {
+                ProofError::NoProofInResult
+                | ProofError::EmptyResponseMetadata
+                | ProofError::ResponseDecodeError { .. }
+                | ProofError::SignatureVerificationError { .. }
+                | ProofError::InvalidSignatureFormat { .. }
+                | ProofError::InvalidSignature { .. } => true,
+                // Drive errors are only kept as strings; invalid proofs are reported as proof or
+                // grovedb errors, while other errors come from the request, eg. an invalid query
+                ProofError::DriveError { error } => {
+                    error.starts_with("proof: ") || error.starts_with("grovedb: ")
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
 impl From<PlatformVersionError> for Error {
     fn from(value: PlatformVersionError) -> Self {
         Self::Protocol(value.into())
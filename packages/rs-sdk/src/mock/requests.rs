@@ -162,6 +162,22 @@ impl MockResponse for drive_proof_verifier::types::IdentityBalance {
     }
 }
 
+impl MockResponse for drive_proof_verifier::types::DocumentsAggregate {
+    fn mock_serialize(&self, _sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        (self.0).to_be_bytes().to_vec()
+    }
+
+    fn mock_deserialize(_sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        drive_proof_verifier::types::DocumentsAggregate(i64::from_be_bytes(
+            buf.try_into()
+                .expect("documents aggregate should be 8 bytes"),
+        ))
+    }
+}
+
 impl MockResponse for drive_proof_verifier::types::IdentityNonceFetcher {
     fn mock_serialize(&self, _sdk: &MockDashPlatformSdk) -> Vec<u8> {
         (self.0).to_be_bytes().to_vec()
@@ -17,7 +17,10 @@ use std::{collections::BTreeMap, path::PathBuf, sync::Arc};
 use tokio::sync::Mutex;
 
 use crate::{
-    platform::{types::identity::IdentityRequest, DocumentQuery, Fetch, FetchMany, Query},
+    platform::{
+        types::identity::IdentityRequest, DocumentAggregateQuery, DocumentQuery, Fetch, FetchMany,
+        Query,
+    },
     Error,
 };
 
@@ -107,6 +110,10 @@ impl MockDashPlatformSdk {
 
             match request_type {
                 "DocumentQuery" => self.load_expectation::<DocumentQuery>(filename).await?,
+                "DocumentAggregateQuery" => {
+                    self.load_expectation::<DocumentAggregateQuery>(filename)
+                        .await?
+                }
                 "GetEpochsInfoRequest" => {
                     self.load_expectation::<proto::GetEpochsInfoRequest>(filename)
                         .await?
@@ -115,6 +122,10 @@ impl MockDashPlatformSdk {
                     self.load_expectation::<proto::GetDataContractRequest>(filename)
                         .await?
                 }
+                "GetDataContractHistoryRequest" => {
+                    self.load_expectation::<proto::GetDataContractHistoryRequest>(filename)
+                        .await?
+                }
                 "GetDataContractsRequest" => {
                     self.load_expectation::<proto::GetDataContractsRequest>(filename)
                         .await?
@@ -125,6 +136,14 @@ impl MockDashPlatformSdk {
                         .await?
                 }
 
+                "GetIdentitiesRequest" => {
+                    self.load_expectation::<proto::GetIdentitiesRequest>(filename)
+                        .await?
+                }
+                "GetIdentitiesByPublicKeyHashesRequest" => {
+                    self.load_expectation::<proto::GetIdentitiesByPublicKeyHashesRequest>(filename)
+                        .await?
+                }
                 "GetIdentityBalanceRequest" => {
                     self.load_expectation::<proto::GetIdentityBalanceRequest>(filename)
                         .await?
@@ -141,6 +160,10 @@ impl MockDashPlatformSdk {
                     self.load_expectation::<proto::GetIdentityKeysRequest>(filename)
                         .await?
                 }
+                "GetIdentityWithdrawalsRequest" => {
+                    self.load_expectation::<proto::GetIdentityWithdrawalsRequest>(filename)
+                        .await?
+                }
                 "GetProtocolVersionUpgradeStateRequest" => {
                     self.load_expectation::<proto::GetProtocolVersionUpgradeStateRequest>(filename)
                         .await?
@@ -6,7 +6,9 @@
 // and while it will change the substance, the API structure will remain the same.
 
 mod delegate;
+mod document_aggregate_query;
 mod document_query;
+mod document_subscription;
 mod fetch;
 mod fetch_many;
 mod query;
@@ -25,7 +27,9 @@ pub use drive_proof_verifier::ContextProvider;
 pub use drive_proof_verifier::MockContextProvider;
 pub use rs_dapi_client as dapi;
 pub use {
+    document_aggregate_query::DocumentAggregateQuery,
     document_query::DocumentQuery,
+    document_subscription::DocumentChange,
     fetch::Fetch,
     fetch_many::FetchMany,
     query::{LimitQuery, Query, DEFAULT_EPOCH_QUERY_LIMIT},
@@ -13,6 +13,8 @@
 /// Given two enums, request and response, that wrap multiple requests/responses for one object type, this macro
 /// implements [TransportRequest](crate::platform::dapi::transport::TransportRequest) for the request enum and
 /// delegates the execution of the transport request to the appropriate variant.
+/// It also implements [TransportResponse](crate::platform::dapi::transport::TransportResponse) for the response
+/// enum.
 ///
 /// Each variant in request enum must have a corresponding variant in response enum.
 /// Variant names in request and response enums must match.
@@ -61,6 +63,19 @@ macro_rules! delegate_transport_request_variant {
                 )*}
             }
         }
+
+        impl $crate::platform::dapi::transport::TransportResponse for $response {
+            fn reported_height(&self) -> Option<u64> {
+                use $response::*;
+
+                match self {
+                    Unknown => None,
+                    $(
+                        $variant(response) => response.reported_height(),
+                    )*
+                }
+            }
+        }
     }
 }
 
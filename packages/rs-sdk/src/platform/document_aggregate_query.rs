@@ -0,0 +1,138 @@
+//! Method to query a count or sum of documents from the Drive.
+
+use crate::{error::Error, platform::DocumentQuery};
+use dapi_grpc::platform::v0::{
+    self as platform_proto,
+    get_documents_aggregate_request::{GetDocumentsAggregateRequestV0, Version},
+    GetDocumentsAggregateRequest, GetDocumentsRequest, ResponseMetadata,
+};
+use drive::query::{AggregateFunction, DriveQuery};
+use drive_proof_verifier::{types::DocumentsAggregate, FromProof};
+use rs_dapi_client::transport::{
+    AppliedRequestSettings, BoxFuture, TransportClient, TransportRequest,
+};
+
+/// Request that is used to query a count or sum of documents from the Dash Platform.
+///
+/// Documents are selected with a [DocumentQuery]; they are counted, or one of their integer properties is summed
+/// when an index of the document type declares this property as its sum. Every matched document is aggregated, the
+/// limit of the document query is ignored.
+///
+/// Conversions are implemented between this type, [GetDocumentsAggregateRequest] and [DriveQuery] using [TryFrom] trait.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, dapi_grpc_macros::Mockable)]
+pub struct DocumentAggregateQuery {
+    /// Documents to aggregate
+    pub document_query: DocumentQuery,
+    /// Integer property to sum; documents are counted when not set
+    pub sum_property: Option<String>,
+}
+
+impl DocumentAggregateQuery {
+    /// Create new query counting documents matched by the provided document query.
+    pub fn count(document_query: DocumentQuery) -> Self {
+        Self {
+            document_query,
+            sum_property: None,
+        }
+    }
+
+    /// Create new query summing an integer property of documents matched by the provided document query.
+    pub fn sum(document_query: DocumentQuery, sum_property: &str) -> Self {
+        Self {
+            document_query,
+            sum_property: Some(sum_property.to_string()),
+        }
+    }
+
+    /// Aggregate function computed by this query.
+    pub fn function(&self) -> AggregateFunction {
+        match &self.sum_property {
+            Some(sum_property) => AggregateFunction::Sum(sum_property.clone()),
+            None => AggregateFunction::Count,
+        }
+    }
+}
+
+impl TransportRequest for DocumentAggregateQuery {
+    type Client = <GetDocumentsAggregateRequest as TransportRequest>::Client;
+    type Response = <GetDocumentsAggregateRequest as TransportRequest>::Response;
+    const SETTINGS_OVERRIDES: rs_dapi_client::RequestSettings =
+        <GetDocumentsAggregateRequest as TransportRequest>::SETTINGS_OVERRIDES;
+
+    fn request_name(&self) -> &'static str {
+        "GetDocumentsAggregateRequest"
+    }
+
+    fn method_name(&self) -> &'static str {
+        "get_documents_aggregate"
+    }
+
+    fn execute_transport<'c>(
+        self,
+        client: &'c mut Self::Client,
+        settings: &AppliedRequestSettings,
+    ) -> BoxFuture<'c, Result<Self::Response, <Self::Client as TransportClient>::Error>> {
+        let request: GetDocumentsAggregateRequest = self
+            .try_into()
+            .expect("DocumentAggregateQuery should always be valid");
+        request.execute_transport(client, settings)
+    }
+}
+
+impl FromProof<DocumentAggregateQuery> for DocumentsAggregate {
+    type Request = DocumentAggregateQuery;
+    type Response = platform_proto::GetDocumentsAggregateResponse;
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        version: &dpp::version::PlatformVersion,
+        provider: &'a dyn drive_proof_verifier::ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata), drive_proof_verifier::Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let mut drive_query: DriveQuery = (&request.document_query).try_into().map_err(|e| {
+            drive_proof_verifier::Error::RequestDecodeError {
+                error: format!("Failed to convert DocumentQuery to DriveQuery: {}", e),
+            }
+        })?;
+        // aggregates cover every matched document, the limit of the document query is not sent
+        drive_query.limit = None;
+
+        <DocumentsAggregate as FromProof<(DriveQuery, AggregateFunction)>>::maybe_from_proof_with_metadata(
+            (drive_query, request.function()),
+            response,
+            version,
+            provider,
+        )
+    }
+}
+
+impl TryFrom<DocumentAggregateQuery> for GetDocumentsAggregateRequest {
+    type Error = Error;
+    fn try_from(dapi_request: DocumentAggregateQuery) -> Result<Self, Self::Error> {
+        let sum_property = dapi_request.sum_property.unwrap_or_default();
+        let documents_request: GetDocumentsRequest = dapi_request.document_query.try_into()?;
+
+        let Some(platform_proto::get_documents_request::Version::V0(documents_request)) =
+            documents_request.version
+        else {
+            return Err(Error::Generic(
+                "documents request should have a version".to_string(),
+            ));
+        };
+
+        Ok(GetDocumentsAggregateRequest {
+            version: Some(Version::V0(GetDocumentsAggregateRequestV0 {
+                data_contract_id: documents_request.data_contract_id,
+                document_type: documents_request.document_type,
+                r#where: documents_request.r#where,
+                order_by: documents_request.order_by,
+                limit: 0,
+                sum_property,
+                prove: true,
+            })),
+        })
+    }
+}
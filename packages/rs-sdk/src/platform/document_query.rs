@@ -18,6 +18,7 @@ use dpp::{
     platform_value::{platform_value, Value},
     prelude::{DataContract, Identifier},
 };
+use drive::query::conditions::OR_CLAUSE_KEY;
 use drive::query::{DriveQuery, InternalClauses, OrderClause, WhereClause, WhereOperator};
 use drive_proof_verifier::{types::Documents, FromProof};
 use rs_dapi_client::transport::{
@@ -42,6 +43,10 @@ pub struct DocumentQuery {
     pub document_type_name: String,
     /// `where` clauses for the query
     pub where_clauses: Vec<WhereClause>,
+    /// `or` branches for the query; when not empty, documents must match the `where` clauses and
+    /// all clauses of at least one branch
+    #[serde(default)]
+    pub or_clauses: Vec<Vec<WhereClause>>,
     /// `order_by` clauses for the query
     pub order_by_clauses: Vec<OrderClause>,
     /// queryset limit
@@ -64,6 +69,7 @@ impl DocumentQuery {
             data_contract: Arc::clone(&contract),
             document_type_name: document_type_name.to_string(),
             where_clauses: vec![],
+            or_clauses: vec![],
             order_by_clauses: vec![],
             limit: 0,
             start: None,
@@ -114,6 +120,28 @@ impl DocumentQuery {
         self
     }
 
+    /// Add a disjunction to the query: documents must match all clauses of at least one branch.
+    ///
+    /// Calling this method several times requires documents to match every disjunction.
+    pub fn with_or(mut self, branches: Vec<Vec<WhereClause>>) -> Self {
+        self.or_clauses = if self.or_clauses.is_empty() {
+            branches
+        } else {
+            self.or_clauses
+                .iter()
+                .flat_map(|existing_branch| {
+                    branches.iter().map(move |branch| {
+                        let mut combined = existing_branch.clone();
+                        combined.extend(branch.iter().cloned());
+                        combined
+                    })
+                })
+                .collect()
+        };
+
+        self
+    }
+
     /// Add order by clause to the query.
     ///
     /// Existing order by clauses will be preserved.
@@ -215,7 +243,16 @@ impl TryFrom<DocumentQuery> for platform_proto::GetDocumentsRequest {
     fn try_from(dapi_request: DocumentQuery) -> Result<Self, Self::Error> {
         // TODO implement where and order_by clause
 
-        let where_clauses = serialize_vec_to_cbor(dapi_request.where_clauses.clone())
+        let mut where_values: Vec<Value> = dapi_request
+            .where_clauses
+            .iter()
+            .cloned()
+            .map(Value::from)
+            .collect();
+        if !dapi_request.or_clauses.is_empty() {
+            where_values.push(or_clauses_to_value(&dapi_request.or_clauses));
+        }
+        let where_clauses = serialize_vec_to_cbor(where_values)
             .expect("where clauses serialization should never fail");
         let order_by = serialize_vec_to_cbor(dapi_request.order_by_clauses.clone())?;
         // Order clause
@@ -239,7 +276,7 @@ impl<'a> From<&'a DriveQuery<'a>> for DocumentQuery {
     fn from(value: &'a DriveQuery<'a>) -> Self {
         let data_contract = value.contract.clone();
         let document_type_name = value.document_type.name();
-        let where_clauses = value.internal_clauses.clone().into();
+        let (where_clauses, or_clauses) = split_internal_clauses(&value.internal_clauses);
         let order_by_clauses = value.order_by.iter().map(|(_, v)| v.clone()).collect();
         let limit = value.limit.unwrap_or(0) as u32;
 
@@ -256,6 +293,7 @@ impl<'a> From<&'a DriveQuery<'a>> for DocumentQuery {
             data_contract: Arc::new(data_contract),
             document_type_name: document_type_name.to_string(),
             where_clauses,
+            or_clauses,
             order_by_clauses,
             limit,
             start,
@@ -267,7 +305,7 @@ impl<'a> From<DriveQuery<'a>> for DocumentQuery {
     fn from(value: DriveQuery<'a>) -> Self {
         let data_contract = value.contract.clone();
         let document_type_name = value.document_type.name();
-        let where_clauses = value.internal_clauses.clone().into();
+        let (where_clauses, or_clauses) = split_internal_clauses(&value.internal_clauses);
         let order_by_clauses = value.order_by.iter().map(|(_, v)| v.clone()).collect();
         let limit = value.limit.unwrap_or(0) as u32;
 
@@ -284,6 +322,7 @@ impl<'a> From<DriveQuery<'a>> for DocumentQuery {
             data_contract: Arc::new(data_contract),
             document_type_name: document_type_name.to_string(),
             where_clauses,
+            or_clauses,
             order_by_clauses,
             limit,
             start,
@@ -300,8 +339,11 @@ impl<'a> TryFrom<&'a DocumentQuery> for DriveQuery<'a> {
             .data_contract
             .document_type_for_name(&request.document_type_name)?;
 
-        let internal_clauses = InternalClauses::extract_from_clauses(request.where_clauses.clone())
-            .map_err(Error::Drive)?;
+        let internal_clauses = InternalClauses::extract_from_disjunctive_clauses(
+            request.where_clauses.clone(),
+            request.or_clauses.clone(),
+        )
+        .map_err(Error::Drive)?;
 
         let limit = if request.limit != 0 {
             Some(request.limit as u16)
@@ -329,6 +371,35 @@ impl<'a> TryFrom<&'a DocumentQuery> for DriveQuery<'a> {
     }
 }
 
+/// Converts `or` branches to the `{"or": [...]}` where clause understood by Drive.
+fn or_clauses_to_value(or_clauses: &[Vec<WhereClause>]) -> Value {
+    let branches = or_clauses
+        .iter()
+        .map(|branch| Value::Array(branch.iter().cloned().map(Value::from).collect()))
+        .collect();
+
+    Value::Map(vec![(
+        Value::Text(OR_CLAUSE_KEY.to_string()),
+        Value::Array(branches),
+    )])
+}
+
+/// Splits internal clauses of a [DriveQuery] into `where` clauses and `or` branches.
+fn split_internal_clauses(
+    internal_clauses: &InternalClauses,
+) -> (Vec<WhereClause>, Vec<Vec<WhereClause>>) {
+    if internal_clauses.is_disjunctive() {
+        let or_clauses = internal_clauses
+            .or_clauses
+            .iter()
+            .map(|branch| branch.clone().into())
+            .collect();
+        (vec![], or_clauses)
+    } else {
+        (internal_clauses.clone().into(), vec![])
+    }
+}
+
 fn serialize_vec_to_cbor<T: Into<Value>>(input: Vec<T>) -> Result<Vec<u8>, Error> {
     let values = Value::Array(
         input
@@ -0,0 +1,146 @@
+//! Subscription to documents changed by each block committed on the Drive.
+
+use std::sync::Arc;
+
+use crate::{error::Error, platform::DocumentQuery, sdk::Sdk};
+use dapi_grpc::platform::v0::{
+    self as platform_proto,
+    subscribe_to_documents_request::{SubscribeToDocumentsRequestV0, Version},
+    subscribe_to_documents_response::{
+        self, subscribe_to_documents_response_v0::ChangeType, SubscribeToDocumentsResponseV0,
+    },
+    GetDocumentsRequest, SubscribeToDocumentsRequest, SubscribeToDocumentsResponse,
+};
+use dpp::{
+    data_contract::accessors::v0::DataContractV0Getters,
+    document::{serialization_traits::DocumentPlatformConversionMethodsV0, Document},
+    prelude::{DataContract, Identifier},
+    version::PlatformVersion,
+};
+use futures::{Stream, StreamExt};
+use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+
+/// A document changed by a committed block.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DocumentChange {
+    /// The document was created
+    Created(Document),
+    /// The document was replaced, transferred, repriced or purchased
+    Replaced(Document),
+    /// The document with this id was deleted
+    Deleted(Identifier),
+}
+
+impl TryFrom<DocumentQuery> for SubscribeToDocumentsRequest {
+    type Error = Error;
+    fn try_from(document_query: DocumentQuery) -> Result<Self, Self::Error> {
+        let documents_request: GetDocumentsRequest = document_query.try_into()?;
+
+        let Some(platform_proto::get_documents_request::Version::V0(documents_request)) =
+            documents_request.version
+        else {
+            return Err(Error::Generic(
+                "documents request should have a version".to_string(),
+            ));
+        };
+
+        Ok(SubscribeToDocumentsRequest {
+            version: Some(Version::V0(SubscribeToDocumentsRequestV0 {
+                data_contract_id: documents_request.data_contract_id,
+                document_type: documents_request.document_type,
+                r#where: documents_request.r#where,
+                prove: false,
+            })),
+        })
+    }
+}
+
+impl Sdk {
+    /// Subscribes to the documents matching the `where` clauses of the query that are created,
+    /// replaced or deleted by each block committed from now on.
+    ///
+    /// Every item of the returned stream holds the changes of one block, blocks without matching
+    /// changes are skipped. Deletions of the document type are always reported, as a deleted document
+    /// can't be matched against the query anymore. Order, limit and start of the query are ignored.
+    ///
+    /// Changes are not proved; fetch the documents with [Fetch](crate::platform::Fetch) if they need
+    /// to be verified.
+    pub async fn subscribe_to_documents(
+        &self,
+        query: DocumentQuery,
+    ) -> Result<impl Stream<Item = Result<Vec<DocumentChange>, Error>>, Error> {
+        let data_contract = Arc::clone(&query.data_contract);
+        let document_type_name = query.document_type_name.clone();
+
+        let request: SubscribeToDocumentsRequest = query.try_into()?;
+
+        let stream = self
+            .execute(request, RequestSettings::default())
+            .await
+            .map_err(|e| Error::DapiClientError(e.to_string()))?;
+
+        let platform_version = self.version();
+
+        Ok(stream.map(move |response| {
+            let response = response
+                .map_err(|e| Error::DapiClientError(format!("can't receive message: {e}")))?;
+
+            document_changes_from_response(
+                response,
+                &data_contract,
+                &document_type_name,
+                platform_version,
+            )
+        }))
+    }
+}
+
+fn document_changes_from_response(
+    response: SubscribeToDocumentsResponse,
+    data_contract: &DataContract,
+    document_type_name: &str,
+    platform_version: &PlatformVersion,
+) -> Result<Vec<DocumentChange>, Error> {
+    let Some(subscribe_to_documents_response::Version::V0(SubscribeToDocumentsResponseV0 {
+        changes,
+        ..
+    })) = response.version
+    else {
+        return Err(Error::Generic(
+            "documents subscription response should have a version".to_string(),
+        ));
+    };
+
+    let document_type = data_contract.document_type_for_name(document_type_name)?;
+
+    changes
+        .into_iter()
+        .map(|change| {
+            let change_type = ChangeType::try_from(change.change_type).map_err(|_| {
+                Error::Generic(format!(
+                    "unknown document change type {}",
+                    change.change_type
+                ))
+            })?;
+
+            let document_change = match change_type {
+                ChangeType::Created => DocumentChange::Created(Document::from_bytes(
+                    &change.document,
+                    document_type,
+                    platform_version,
+                )?),
+                ChangeType::Replaced => DocumentChange::Replaced(Document::from_bytes(
+                    &change.document,
+                    document_type,
+                    platform_version,
+                )?),
+                ChangeType::Deleted => DocumentChange::Deleted(
+                    Identifier::from_bytes(&change.document_id)
+                        .map_err(|e| Error::Protocol(e.into()))?,
+                ),
+            };
+
+            Ok(document_change)
+        })
+        .collect()
+}
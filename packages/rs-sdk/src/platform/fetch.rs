@@ -20,7 +20,7 @@ use rs_dapi_client::{transport::TransportRequest, DapiRequest, RequestSettings};
 use std::fmt::Debug;
 
 use super::types::identity::IdentityRequest;
-use super::DocumentQuery;
+use super::{DocumentAggregateQuery, DocumentQuery};
 
 /// Trait implemented by objects that can be fetched from the platform.
 ///
@@ -116,17 +116,10 @@ where
     ) -> Result<(Option<Self>, ResponseMetadata), Error> {
         let request = query.query(sdk.prove())?;
 
-        let response = request
-            .clone()
-            .execute(sdk, settings.unwrap_or_default())
+        let (object, response_metadata): (Option<Self>, ResponseMetadata) = sdk
+            .execute_and_parse_proof(request, settings.unwrap_or_default())
             .await?;
 
-        let object_type = std::any::type_name::<Self>().to_string();
-        tracing::trace!(request = ?request, response = ?response, object_type, "fetched object from platform");
-
-        let (object, response_metadata): (Option<Self>, ResponseMetadata) =
-            sdk.parse_proof_with_metadata(request, response)?;
-
         match object {
             Some(item) => Ok((item.into(), response_metadata)),
             None => Ok((None, response_metadata)),
@@ -204,6 +197,14 @@ impl Fetch for Document {
     type Request = DocumentQuery;
 }
 
+impl Fetch for drive_proof_verifier::types::DocumentsAggregate {
+    type Request = DocumentAggregateQuery;
+}
+
+impl Fetch for drive_proof_verifier::types::DataContractHistory {
+    type Request = platform_proto::GetDataContractHistoryRequest;
+}
+
 impl Fetch for drive_proof_verifier::types::IdentityBalance {
     type Request = platform_proto::GetIdentityBalanceRequest;
 }
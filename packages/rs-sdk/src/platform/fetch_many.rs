@@ -12,8 +12,10 @@ use crate::{
     Sdk,
 };
 use dapi_grpc::platform::v0::{
-    GetDataContractsRequest, GetDocumentsResponse, GetEpochsInfoRequest, GetIdentityKeysRequest,
-    GetProtocolVersionUpgradeStateRequest, GetProtocolVersionUpgradeVoteStatusRequest,
+    GetDataContractsRequest, GetDocumentsResponse, GetEpochsInfoRequest,
+    GetIdentitiesByPublicKeyHashesRequest, GetIdentitiesRequest, GetIdentityKeysRequest,
+    GetIdentityWithdrawalsRequest, GetProtocolVersionUpgradeStateRequest,
+    GetProtocolVersionUpgradeVoteStatusRequest,
 };
 use dashcore_rpc::dashcore::ProTxHash;
 use dpp::block::epoch::EpochIndex;
@@ -21,9 +23,10 @@ use dpp::block::extended_epoch_info::ExtendedEpochInfo;
 use dpp::data_contract::DataContract;
 use dpp::document::Document;
 use dpp::identity::KeyID;
-use dpp::prelude::{Identifier, IdentityPublicKey};
+use dpp::prelude::{Identifier, Identity, IdentityPublicKey};
 use dpp::util::deserializer::ProtocolVersion;
 use dpp::version::ProtocolVersionVoteCount;
+use dpp::withdrawal::identity_withdrawal::IdentityWithdrawal;
 use drive_proof_verifier::types::{MasternodeProtocolVote, RetrievedObjects};
 use drive_proof_verifier::{types::Documents, FromProof};
 use rs_dapi_client::{transport::TransportRequest, DapiRequest, RequestSettings};
@@ -130,19 +133,13 @@ where
     ) -> Result<RetrievedObjects<K, Self>, Error> {
         let request = query.query(sdk.prove())?;
 
-        let response = request
-            .clone()
-            .execute(sdk, RequestSettings::default())
+        let (object, _) = sdk
+            .execute_and_parse_proof::<_, BTreeMap<K, Option<Self>>>(
+                request,
+                RequestSettings::default(),
+            )
             .await?;
-
-        let object_type = std::any::type_name::<Self>().to_string();
-        tracing::trace!(request = ?request, response = ?response, object_type, "fetched object from platform");
-
-        let object: BTreeMap<K, Option<Self>> = sdk
-            .parse_proof::<<Self as FetchMany<K>>::Request, BTreeMap<K, Option<Self>>>(
-                request, response,
-            )?
-            .unwrap_or_default();
+        let object = object.unwrap_or_default();
 
         Ok(object)
     }
@@ -308,3 +305,43 @@ impl FetchMany<ProTxHash> for MasternodeProtocolVote {
 impl FetchMany<Identifier> for DataContract {
     type Request = GetDataContractsRequest;
 }
+
+/// Fetch multiple identities.
+///
+/// Returns [Identities](drive_proof_verifier::types::Identities) indexed by [Identifier](dpp::prelude::Identifier).
+///
+/// ## Supported query types
+///
+/// * [`Vec<Identifier>`](dpp::prelude::Identifier) - identifiers of identities to fetch; see also
+/// [FetchMany::fetch_by_identifiers()].
+impl FetchMany<Identifier> for Identity {
+    type Request = GetIdentitiesRequest;
+}
+
+/// Fetch multiple identities by their unique public key hashes.
+///
+/// Returns [IdentitiesByPublicKeyHashes](drive_proof_verifier::types::IdentitiesByPublicKeyHashes)
+/// indexed by public key hash.
+///
+/// ## Supported query types
+///
+/// * [`Vec<PublicKeyHash>`](super::types::identity::PublicKeyHash) - hashes of unique public keys of
+/// identities to fetch
+impl FetchMany<[u8; 20]> for Identity {
+    type Request = GetIdentitiesByPublicKeyHashesRequest;
+}
+
+/// Fetch withdrawals of an identity, most recently updated first.
+///
+/// Returns [IdentityWithdrawals](drive_proof_verifier::types::IdentityWithdrawals) indexed by
+/// the [Identifier](dpp::prelude::Identifier) of the withdrawal document.
+///
+/// ## Supported query types
+///
+/// * [Identifier] - [Identity](crate::platform::Identity) ID for which to retrieve withdrawals; will return up to
+/// [DEFAULT_QUERY_LIMIT](drive::drive::config::DEFAULT_QUERY_LIMIT) objects
+/// * [`LimitQuery<Identifier>`](super::LimitQuery) - limit query that allows to specify maximum number of objects
+/// to fetch; see also [FetchMany::fetch_many_with_limit()].
+impl FetchMany<Identifier> for IdentityWithdrawal {
+    type Request = GetIdentityWithdrawalsRequest;
+}
@@ -114,6 +114,20 @@ impl Query<proto::GetDataContractsRequest> for Vec<Identifier> {
     }
 }
 
+impl Query<proto::GetIdentitiesRequest> for Vec<Identifier> {
+    fn query(self, prove: bool) -> Result<proto::GetIdentitiesRequest, Error> {
+        if !prove {
+            unimplemented!("queries without proofs are not supported yet");
+        }
+        let ids = self.into_iter().map(|id| id.to_vec()).collect();
+        Ok(proto::GetIdentitiesRequest {
+            version: Some(proto::get_identities_request::Version::V0(
+                proto::get_identities_request::GetIdentitiesRequestV0 { ids, prove },
+            )),
+        })
+    }
+}
+
 impl Query<proto::GetIdentityKeysRequest> for Identifier {
     /// Get all keys for an identity with provided identifier.
     fn query(self, prove: bool) -> Result<proto::GetIdentityKeysRequest, Error> {
@@ -137,6 +151,31 @@ impl Query<proto::GetIdentityKeysRequest> for Identifier {
     }
 }
 
+impl Query<proto::GetIdentityWithdrawalsRequest> for Identifier {
+    /// Get withdrawals of an identity with provided identifier, most recently updated first.
+    fn query(self, prove: bool) -> Result<proto::GetIdentityWithdrawalsRequest, Error> {
+        LimitQuery::from(self).query(prove)
+    }
+}
+
+impl Query<proto::GetIdentityWithdrawalsRequest> for LimitQuery<Identifier> {
+    fn query(self, prove: bool) -> Result<proto::GetIdentityWithdrawalsRequest, Error> {
+        if !prove {
+            unimplemented!("queries without proofs are not supported yet");
+        }
+
+        Ok(proto::GetIdentityWithdrawalsRequest {
+            version: Some(proto::get_identity_withdrawals_request::Version::V0(
+                proto::get_identity_withdrawals_request::GetIdentityWithdrawalsRequestV0 {
+                    identity_id: self.query.to_vec(),
+                    limit: self.limit,
+                    prove,
+                },
+            )),
+        })
+    }
+}
+
 impl<'a> Query<DocumentQuery> for DriveQuery<'a> {
     fn query(self, prove: bool) -> Result<DocumentQuery, Error> {
         if !prove {
@@ -3,12 +3,17 @@ pub mod broadcast;
 pub(crate) mod broadcast_identity;
 pub mod broadcast_request;
 pub(crate) mod context;
+pub mod multisig;
+pub mod purchase_document;
 mod put_contract;
 pub mod put_document;
 pub mod put_identity;
 pub mod put_settings;
 pub mod top_up_identity;
+pub mod transfer_document;
+pub mod transfer_to_identities;
 mod txid;
+pub mod update_price_of_document;
 pub mod withdraw_from_identity;
 
 pub use context::*;
@@ -0,0 +1,156 @@
+//! Collecting the signatures of a state transition signed with a `BIP13_SCRIPT_HASH` key.
+//!
+//! A multisig identity public key holds the hash of a redeem script listing N secp256k1 public
+//! keys, M of which must sign the state transition. Every participant signs the same signable
+//! bytes with their own key, possibly with a different [Signer], and the [MultisigSignatureCollector]
+//! assembles the signatures into the signature of the state transition once M of them are collected.
+use std::collections::BTreeMap;
+
+use dpp::dashcore::signer;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::signer::Signer;
+use dpp::identity::{IdentityPublicKey, KeyType};
+use dpp::platform_value::BinaryData;
+use dpp::serialization::Signable;
+use dpp::state_transition::StateTransition;
+use dpp::util::hash::ripemd160_sha256;
+use dpp::util::multisig_signer::{parse_multisig_redeem_script, MultisigSignature};
+
+use crate::Error;
+
+/// Collects the signatures of the participants of a multisig identity public key
+/// and signs the state transition once the threshold is reached
+#[derive(Debug, Clone)]
+pub struct MultisigSignatureCollector {
+    state_transition: StateTransition,
+    identity_public_key: IdentityPublicKey,
+    redeem_script: Vec<u8>,
+    threshold: usize,
+    public_keys: Vec<Vec<u8>>,
+    signable_bytes: Vec<u8>,
+    signatures: BTreeMap<usize, BinaryData>,
+}
+
+impl MultisigSignatureCollector {
+    /// Creates a collector for the state transition to be signed with the multisig identity
+    /// public key, whose data is the hash of the redeem script
+    pub fn new(
+        state_transition: StateTransition,
+        identity_public_key: IdentityPublicKey,
+        redeem_script: Vec<u8>,
+    ) -> Result<Self, Error> {
+        if identity_public_key.key_type() != KeyType::BIP13_SCRIPT_HASH {
+            return Err(Error::Generic(format!(
+                "identity public key {} is not a multisig key",
+                identity_public_key.id()
+            )));
+        }
+
+        if ripemd160_sha256(&redeem_script).as_slice() != identity_public_key.data().as_slice() {
+            return Err(Error::Generic(format!(
+                "redeem script doesn't match identity public key {}",
+                identity_public_key.id()
+            )));
+        }
+
+        let (threshold, public_keys) = parse_multisig_redeem_script(&redeem_script)?;
+        let public_keys = public_keys.into_iter().map(|key| key.to_vec()).collect();
+
+        let signable_bytes = state_transition.signable_bytes()?;
+
+        Ok(Self {
+            state_transition,
+            identity_public_key,
+            redeem_script,
+            threshold,
+            public_keys,
+            signable_bytes,
+            signatures: BTreeMap::new(),
+        })
+    }
+
+    /// The bytes every participant has to sign
+    pub fn signable_bytes(&self) -> &[u8] {
+        &self.signable_bytes
+    }
+
+    /// The number of signatures required to sign the state transition
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Adds the signature made by the participant with the given secp256k1 public key
+    pub fn add_signature(&mut self, public_key: &[u8], signature: BinaryData) -> Result<(), Error> {
+        let index = self
+            .public_keys
+            .iter()
+            .position(|key| key.as_slice() == public_key)
+            .ok_or_else(|| {
+                Error::Generic("public key is not a participant of the multisig".to_string())
+            })?;
+
+        signer::verify_data_signature(&self.signable_bytes, signature.as_slice(), public_key)
+            .map_err(|e| {
+                Error::Generic(format!("invalid multisig participant signature: {}", e))
+            })?;
+
+        self.signatures.insert(index, signature);
+
+        Ok(())
+    }
+
+    /// Signs with the signer of the participant, whose `ECDSA_SECP256K1` key must be
+    /// one of the public keys of the redeem script
+    pub fn sign_with<S: Signer>(
+        &mut self,
+        participant_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<(), Error> {
+        if participant_key.key_type() != KeyType::ECDSA_SECP256K1 {
+            return Err(Error::Generic(format!(
+                "multisig participant key {} must be an ECDSA_SECP256K1 key",
+                participant_key.id()
+            )));
+        }
+
+        let signature = signer.sign(participant_key, &self.signable_bytes)?;
+
+        self.add_signature(participant_key.data().as_slice(), signature)
+    }
+
+    /// Returns true once enough signatures were collected
+    pub fn is_complete(&self) -> bool {
+        self.signatures.len() >= self.threshold
+    }
+
+    /// Returns the state transition signed with the collected signatures
+    pub fn into_signed_state_transition(self) -> Result<StateTransition, Error> {
+        if !self.is_complete() {
+            return Err(Error::Generic(format!(
+                "multisig requires {} signatures, got {}",
+                self.threshold,
+                self.signatures.len()
+            )));
+        }
+
+        // signatures must be in the order of the public keys of the redeem script
+        let signatures = self
+            .signatures
+            .into_values()
+            .take(self.threshold)
+            .map(|signature| signature.to_vec())
+            .collect();
+
+        let signature = MultisigSignature {
+            redeem_script: self.redeem_script,
+            signatures,
+        }
+        .to_bytes()?;
+
+        let mut state_transition = self.state_transition;
+        state_transition.set_signature(signature.into());
+        state_transition.set_signature_public_key_id(self.identity_public_key.id());
+
+        Ok(state_transition)
+    }
+}
@@ -0,0 +1,181 @@
+use crate::platform::transition::broadcast_request::BroadcastRequestForStateTransition;
+use std::sync::Arc;
+
+use crate::{Error, Sdk};
+
+use crate::platform::transition::put_settings::PutSettings;
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::DocumentType;
+use dpp::data_contract::DataContract;
+use dpp::document::document_methods::DocumentMethodsV0;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::fee::Credits;
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::prelude::Identifier;
+use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+use dpp::state_transition::StateTransition;
+use drive::drive::Drive;
+use rs_dapi_client::{DapiRequest, RequestSettings};
+
+#[async_trait::async_trait]
+/// A trait for purchasing a document that is for sale on platform
+pub trait PurchaseDocument<S: Signer> {
+    /// Purchases the document for the purchaser identity at the given price,
+    /// the price must match the price the document is currently listed at.
+    /// Setting settings to `None` sets default connection behavior
+    async fn purchase_document(
+        &self,
+        price: Credits,
+        sdk: &Sdk,
+        document_type: DocumentType,
+        purchaser_id: Identifier,
+        identity_public_key: IdentityPublicKey,
+        signer: &S,
+        settings: Option<PutSettings>,
+    ) -> Result<StateTransition, Error>;
+
+    /// Waits for the response of a state transition after it has been broadcast
+    async fn wait_for_response(
+        &self,
+        sdk: &Sdk,
+        state_transition: StateTransition,
+        data_contract: Arc<DataContract>,
+    ) -> Result<Document, Error>;
+
+    /// Purchases the document for the purchaser identity and waits for the confirmation proof
+    async fn purchase_document_and_wait_for_response(
+        &self,
+        price: Credits,
+        sdk: &Sdk,
+        document_type: DocumentType,
+        purchaser_id: Identifier,
+        identity_public_key: IdentityPublicKey,
+        data_contract: Arc<DataContract>,
+        signer: &S,
+    ) -> Result<Document, Error>;
+}
+
+#[async_trait::async_trait]
+impl<S: Signer> PurchaseDocument<S> for Document {
+    async fn purchase_document(
+        &self,
+        price: Credits,
+        sdk: &Sdk,
+        document_type: DocumentType,
+        purchaser_id: Identifier,
+        identity_public_key: IdentityPublicKey,
+        signer: &S,
+        settings: Option<PutSettings>,
+    ) -> Result<StateTransition, Error> {
+        // the purchase is signed by the purchaser, so the nonce is theirs
+        let new_identity_contract_nonce = sdk
+            .get_identity_contract_nonce(
+                purchaser_id,
+                document_type.data_contract_id(),
+                true,
+                settings,
+            )
+            .await?;
+
+        // a purchase is a new revision of the document
+        let mut document = self.clone();
+        document.increment_revision()?;
+
+        let transition = DocumentsBatchTransition::new_document_purchase_transition_from_document(
+            document,
+            document_type.as_ref(),
+            purchaser_id,
+            price,
+            &identity_public_key,
+            new_identity_contract_nonce,
+            signer,
+            sdk.version(),
+            None,
+            None,
+            None,
+        )?;
+
+        let request = transition.broadcast_request_for_state_transition()?;
+
+        request
+            .clone()
+            .execute(sdk, settings.unwrap_or_default().request_settings)
+            .await?;
+
+        // response is empty for a broadcast, result comes from the stream wait for state transition result
+
+        Ok(transition)
+    }
+
+    async fn wait_for_response(
+        &self,
+        sdk: &Sdk,
+        state_transition: StateTransition,
+        data_contract: Arc<DataContract>,
+    ) -> Result<Document, Error> {
+        let request = state_transition.wait_for_state_transition_result_request()?;
+
+        let response = request.execute(sdk, RequestSettings::default()).await?;
+
+        let proof = response.proof_owned()?;
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &state_transition,
+            proof.grovedb_proof.as_slice(),
+            &|_| Ok(Some(data_contract.clone())),
+            sdk.version(),
+        )?;
+
+        match result {
+            StateTransitionProofResult::VerifiedDocuments(mut documents) => {
+                let document = documents
+                    .remove(self.id_ref())
+                    .ok_or(Error::InvalidProvedResponse(
+                        "did not prove the purchased document".to_string(),
+                    ))?
+                    .ok_or(Error::InvalidProvedResponse(
+                        "expected there to actually be a document".to_string(),
+                    ))?;
+                Ok(document)
+            }
+            _ => Err(Error::DapiClientError("proved a non document".to_string())),
+        }
+    }
+
+    async fn purchase_document_and_wait_for_response(
+        &self,
+        price: Credits,
+        sdk: &Sdk,
+        document_type: DocumentType,
+        purchaser_id: Identifier,
+        identity_public_key: IdentityPublicKey,
+        data_contract: Arc<DataContract>,
+        signer: &S,
+    ) -> Result<Document, Error> {
+        let state_transition = self
+            .purchase_document(
+                price,
+                sdk,
+                document_type,
+                purchaser_id,
+                identity_public_key,
+                signer,
+                None,
+            )
+            .await?;
+
+        let document = <Self as PurchaseDocument<S>>::wait_for_response(
+            self,
+            sdk,
+            state_transition,
+            data_contract,
+        )
+        .await?;
+
+        Ok(document)
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::fee::Credits;
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::identity::signer::Signer;
+use dpp::identity::Identity;
+use dpp::prelude::Identifier;
+
+use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+
+use crate::platform::transition::broadcast_request::BroadcastRequestForStateTransition;
+use crate::platform::transition::put_settings::PutSettings;
+use crate::{Error, Sdk};
+use dpp::state_transition::identity_credit_transfer_transition::methods::IdentityCreditTransferTransitionMethodsV0;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+use drive::drive::Drive;
+use rs_dapi_client::{DapiRequest, RequestSettings};
+
+#[async_trait::async_trait]
+pub trait TransferToIdentities {
+    /// Function to transfer credits from an identity to several identities in a single
+    /// state transition, all recipients are credited atomically.
+    /// Returns the final identity balance.
+    async fn transfer_credits_to_identities<S: Signer + Send>(
+        &self,
+        sdk: &Sdk,
+        recipients: BTreeMap<Identifier, Credits>,
+        signer: S,
+        settings: Option<PutSettings>,
+    ) -> Result<u64, Error>;
+}
+
+#[async_trait::async_trait]
+impl TransferToIdentities for Identity {
+    async fn transfer_credits_to_identities<S: Signer + Send>(
+        &self,
+        sdk: &Sdk,
+        recipients: BTreeMap<Identifier, Credits>,
+        signer: S,
+        settings: Option<PutSettings>,
+    ) -> Result<u64, Error> {
+        let new_identity_nonce = sdk.get_identity_nonce(self.id(), true, settings).await?;
+        let state_transition = IdentityCreditTransferTransition::try_from_identity_to_recipients(
+            self,
+            recipients,
+            signer,
+            new_identity_nonce,
+            sdk.version(),
+            None,
+        )?;
+
+        let request = state_transition.broadcast_request_for_state_transition()?;
+
+        request
+            .clone()
+            .execute(sdk, settings.unwrap_or_default().request_settings)
+            .await?;
+
+        let request = state_transition.wait_for_state_transition_result_request()?;
+
+        let response = request.execute(sdk, RequestSettings::default()).await?;
+
+        let proof = response.proof_owned()?;
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &state_transition,
+            proof.grovedb_proof.as_slice(),
+            &|_| Ok(None),
+            sdk.version(),
+        )?;
+
+        match result {
+            StateTransitionProofResult::VerifiedBalanceTransferToIdentities(identity, _) => {
+                identity.balance.ok_or(Error::DapiClientError(
+                    "expected an identity balance".to_string(),
+                ))
+            }
+            _ => Err(Error::DapiClientError(
+                "proved something that was not a transfer to identities".to_string(),
+            )),
+        }
+    }
+}
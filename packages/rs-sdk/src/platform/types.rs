@@ -1,4 +1,5 @@
 //! Type-specific implementation for various dpp object types to make queries more convenient and intuitive.
+pub mod data_contract;
 pub mod epoch;
 pub mod identity;
 pub mod version_votes;
@@ -0,0 +1,132 @@
+//! Data contract related types and helpers
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use dapi_grpc::platform::v0::{
+    get_data_contract_history_request,
+    get_data_contract_history_request::GetDataContractHistoryRequestV0,
+    GetDataContractHistoryRequest,
+};
+use dpp::prelude::{DataContract, Identifier, TimestampMillis};
+use drive::drive::contract::MAX_CONTRACT_HISTORY_FETCH_LIMIT;
+use drive_proof_verifier::types::DataContractHistory;
+
+use crate::{
+    platform::{Fetch, LimitQuery, Query},
+    Error, Sdk,
+};
+
+#[async_trait]
+
+/// Helper trait for fetching historical versions of a data contract
+pub trait DataContractEx: Sized {
+    /// Fetch the version of a data contract that was active at the block time `time_ms`.
+    ///
+    /// This is the data contract that documents written at `time_ms` were validated against,
+    /// so it can be used to deserialize documents written under an older schema.
+    ///
+    /// Returns `None` if the data contract doesn't exist, doesn't keep history
+    /// or was created after `time_ms`.
+    async fn fetch_at_time(
+        sdk: &Sdk,
+        id: Identifier,
+        time_ms: TimestampMillis,
+    ) -> Result<Option<Self>, Error>;
+}
+
+#[async_trait]
+impl DataContractEx for DataContract {
+    async fn fetch_at_time(
+        sdk: &Sdk,
+        id: Identifier,
+        time_ms: TimestampMillis,
+    ) -> Result<Option<Self>, Error> {
+        let limit = MAX_CONTRACT_HISTORY_FETCH_LIMIT as u32;
+        let mut offset = 0;
+
+        loop {
+            let query = LimitQuery {
+                query: DataContractHistoryQuery {
+                    id,
+                    start_at_ms: 0,
+                    offset: Some(offset),
+                },
+                limit: Some(limit),
+            };
+
+            let Some(history) = DataContractHistory::fetch(sdk, query).await? else {
+                return Ok(None);
+            };
+
+            // Pages are returned from the most recent version, so the first page containing
+            // a version older than `time_ms` contains the version we are looking for
+            if let Some((_, data_contract)) = history.range(..=time_ms).next_back() {
+                return Ok(Some(data_contract.clone()));
+            }
+
+            if (history.len() as u32) < limit {
+                return Ok(None);
+            }
+
+            offset += history.len() as u32;
+        }
+    }
+}
+
+/// Query used to fetch the history of a data contract from the platform.
+///
+/// Versions of the data contract are returned from the most recent one.
+/// Use `offset` together with [LimitQuery] to page through the history.
+#[derive(Clone, Debug)]
+pub struct DataContractHistoryQuery {
+    /// Identifier of the data contract
+    pub id: Identifier,
+    /// Only versions of the data contract updated after this block time (in milliseconds) are returned.
+    pub start_at_ms: TimestampMillis,
+    /// Number of the most recent versions to skip.
+    pub offset: Option<u32>,
+}
+
+impl From<Identifier> for DataContractHistoryQuery {
+    fn from(id: Identifier) -> Self {
+        Self {
+            id,
+            start_at_ms: 0,
+            offset: None,
+        }
+    }
+}
+
+impl Query<GetDataContractHistoryRequest> for DataContractHistoryQuery {
+    fn query(self, prove: bool) -> Result<GetDataContractHistoryRequest, Error> {
+        LimitQuery::from(self).query(prove)
+    }
+}
+
+impl Query<GetDataContractHistoryRequest> for Identifier {
+    fn query(self, prove: bool) -> Result<GetDataContractHistoryRequest, Error> {
+        DataContractHistoryQuery::from(self).query(prove)
+    }
+}
+
+impl<Q: Into<DataContractHistoryQuery> + Clone + Debug + Send> Query<GetDataContractHistoryRequest>
+    for LimitQuery<Q>
+{
+    fn query(self, prove: bool) -> Result<GetDataContractHistoryRequest, Error> {
+        if !prove {
+            unimplemented!("queries without proofs are not supported yet");
+        }
+        let inner: DataContractHistoryQuery = self.query.into();
+        Ok(GetDataContractHistoryRequest {
+            version: Some(get_data_contract_history_request::Version::V0(
+                GetDataContractHistoryRequestV0 {
+                    id: inner.id.to_vec(),
+                    limit: self.limit,
+                    offset: inner.offset,
+                    start_at_ms: inner.start_at_ms,
+                    prove,
+                },
+            )),
+        })
+    }
+}
@@ -1,5 +1,6 @@
 //! Identity related types and functions
 
+use dapi_grpc::platform::v0::get_identities_by_public_key_hashes_request::GetIdentitiesByPublicKeyHashesRequestV0;
 use dapi_grpc::platform::v0::get_identity_balance_and_revision_request::GetIdentityBalanceAndRevisionRequestV0;
 use dapi_grpc::platform::v0::get_identity_balance_request::GetIdentityBalanceRequestV0;
 use dapi_grpc::platform::v0::get_identity_by_public_key_hash_request::GetIdentityByPublicKeyHashRequestV0;
@@ -7,9 +8,10 @@ use dapi_grpc::platform::v0::get_identity_contract_nonce_request::GetIdentityCon
 use dapi_grpc::platform::v0::get_identity_nonce_request::GetIdentityNonceRequestV0;
 use dapi_grpc::platform::v0::get_identity_request::GetIdentityRequestV0;
 use dapi_grpc::platform::v0::{
-    get_identity_balance_and_revision_request, get_identity_balance_request,
-    get_identity_by_public_key_hash_request, get_identity_contract_nonce_request,
-    get_identity_nonce_request, get_identity_request, GetIdentityBalanceAndRevisionRequest,
+    get_identities_by_public_key_hashes_request, get_identity_balance_and_revision_request,
+    get_identity_balance_request, get_identity_by_public_key_hash_request,
+    get_identity_contract_nonce_request, get_identity_nonce_request, get_identity_request,
+    GetIdentitiesByPublicKeyHashesRequest, GetIdentityBalanceAndRevisionRequest,
     GetIdentityBalanceRequest, GetIdentityByPublicKeyHashRequest, GetIdentityContractNonceRequest,
     GetIdentityNonceRequest, GetIdentityRequest, ResponseMetadata,
 };
@@ -49,7 +51,8 @@ impl Query<IdentityRequest> for dpp::prelude::Identifier {
 /// Public key hash that can be used as a [Query] to find an identity.
 ///
 /// You can use [`Fetch::fetch(PublicKeyHash)`](crate::platform::Fetch::fetch()) to fetch an identity
-/// by its public key hash.
+/// by its public key hash, or [`FetchMany::fetch_many(Vec<PublicKeyHash>)`](crate::platform::FetchMany::fetch_many())
+/// to fetch multiple identities at once.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PublicKeyHash(pub [u8; 20]);
 
@@ -71,6 +74,26 @@ impl Query<IdentityRequest> for PublicKeyHash {
     }
 }
 
+impl Query<GetIdentitiesByPublicKeyHashesRequest> for Vec<PublicKeyHash> {
+    fn query(self, prove: bool) -> Result<GetIdentitiesByPublicKeyHashesRequest, Error> {
+        if !prove {
+            unimplemented!("queries without proofs are not supported yet");
+        }
+        let public_key_hashes = self
+            .into_iter()
+            .map(|public_key_hash| public_key_hash.0.to_vec())
+            .collect();
+        Ok(GetIdentitiesByPublicKeyHashesRequest {
+            version: Some(get_identities_by_public_key_hashes_request::Version::V0(
+                GetIdentitiesByPublicKeyHashesRequestV0 {
+                    public_key_hashes,
+                    prove,
+                },
+            )),
+        })
+    }
+}
+
 impl Query<GetIdentityBalanceRequest> for dpp::prelude::Identifier {
     fn query(self, prove: bool) -> Result<GetIdentityBalanceRequest, Error> {
         if !prove {
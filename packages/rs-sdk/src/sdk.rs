@@ -4,6 +4,7 @@ use std::collections::btree_map::Entry;
 use std::sync::Arc;
 use std::{fmt::Debug, num::NonZeroUsize, ops::DerefMut};
 
+use crate::core_client::CoreClient;
 use crate::error::Error;
 use crate::internal_cache::InternalSdkCache;
 use crate::mock::MockResponse;
@@ -31,7 +32,7 @@ use rs_dapi_client::{
 };
 #[cfg(feature = "mocks")]
 use std::path::{Path, PathBuf};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 #[cfg(feature = "mocks")]
 use tokio::sync::Mutex;
 use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
@@ -89,6 +90,11 @@ pub struct Sdk {
     /// Note that setting this to None can panic.
     context_provider: std::sync::Mutex<Option<Box<dyn ContextProvider>>>,
 
+    /// Dash Core client used to retrieve the masternode list.
+    ///
+    /// Set when the Sdk is configured with [SdkBuilder::with_core()].
+    core_client: Option<Arc<CoreClient>>,
+
     /// Cancellation token; once cancelled, all pending requests should be aborted.
     pub(crate) cancel_token: CancellationToken,
 
@@ -205,6 +211,40 @@ impl Sdk {
         }
     }
 
+    /// Execute `request` and retrieve object `O` from proof contained in the response.
+    ///
+    /// When hedged requests are enabled in [RequestSettings], the request is sent to multiple nodes
+    /// and the first response with a valid proof is used. See [DapiClient::execute_verified()].
+    pub(crate) async fn execute_and_parse_proof<R, O>(
+        &self,
+        request: R,
+        settings: RequestSettings,
+    ) -> Result<(Option<O>, ResponseMetadata), Error>
+    where
+        R: TransportRequest,
+        O: FromProof<R, Request = R, Response = R::Response> + MockResponse,
+    {
+        let object_type = std::any::type_name::<O>().to_string();
+
+        let parse_proof = |response: R::Response| {
+            tracing::trace!(request = ?request, response = ?response, object_type, "fetched object from platform");
+
+            self.parse_proof_with_metadata::<R, O>(request.clone(), response)
+                .map_err(Error::from)
+        };
+
+        match self.inner {
+            SdkInstance::Dapi { ref dapi, .. } => {
+                dapi.execute_verified(request.clone(), settings, parse_proof)
+                    .await?
+            }
+            #[cfg(feature = "mocks")]
+            SdkInstance::Mock { .. } => {
+                parse_proof(DapiRequestExecutor::execute(self, request.clone(), settings).await?)
+            }
+        }
+    }
+
     /// Returns a mutable reference to the `MockDashPlatformSdk` instance.
     ///
     /// Use returned object to configure mock responses with methods like `expect_fetch`.
@@ -450,6 +490,76 @@ impl Sdk {
     pub fn shutdown(&self) {
         self.cancel_token.cancel();
     }
+
+    /// Replace DAPI addresses with platform endpoints of evonodes from the current deterministic
+    /// masternode list, retrieved from Dash Core.
+    ///
+    /// Addresses provided to [SdkBuilder::new()] are only used to bootstrap the Sdk; nodes that
+    /// are not in the masternode list anymore are removed.
+    ///
+    /// Returns number of addresses in the updated list.
+    ///
+    /// Requires Dash Core configured with [SdkBuilder::with_core()]. The masternode list is not
+    /// bootstrapped from the seed DAPI nodes: DAPI doesn't serve the masternode list, and a list
+    /// returned by a single node couldn't be verified without an SPV client, so a malicious seed
+    /// could replace the whole address list with its own nodes.
+    pub async fn refresh_address_list(&self) -> Result<usize, Error> {
+        let SdkInstance::Dapi { ref dapi, .. } = self.inner else {
+            return Err(Error::Config(
+                "address list can't be refreshed in mock mode".to_string(),
+            ));
+        };
+
+        let Some(core_client) = &self.core_client else {
+            return Err(Error::Config(
+                "Dash Core is not configured; use SdkBuilder::with_core()".to_string(),
+            ));
+        };
+
+        // Core RPC client is blocking, so it must not block the async runtime
+        let core_client = Arc::clone(core_client);
+        let uris = tokio::task::spawn_blocking(move || core_client.get_evonode_dapi_uris())
+            .await
+            .map_err(|e| Error::Generic(format!("masternode list retrieval failed: {}", e)))??;
+
+        Self::update_dapi_addresses(dapi, uris)
+    }
+
+    /// Replace addresses of `dapi` with `uris`, unless `uris` is empty.
+    fn update_dapi_addresses(dapi: &DapiClient, uris: Vec<Uri>) -> Result<usize, Error> {
+        // Keep current addresses if Core returned nothing, eg. because it's not synced yet
+        if uris.is_empty() {
+            return Err(Error::Generic(
+                "masternode list doesn't contain any evonodes".to_string(),
+            ));
+        }
+
+        let count = uris.len();
+        dapi.update_addresses(uris);
+
+        tracing::debug!(count, "refreshed DAPI address list from masternode list");
+
+        Ok(count)
+    }
+
+    /// Refresh DAPI addresses from the masternode list every `interval`, until the Sdk is shut down.
+    ///
+    /// Failed refreshes are logged and the current address list is kept.
+    /// This method is intended to be spawned as a background task by long-running services.
+    ///
+    /// See [Sdk::refresh_address_list()] for details.
+    pub async fn refresh_address_list_periodically(&self, interval: Duration) {
+        loop {
+            if let Err(error) = self.refresh_address_list().await {
+                tracing::warn!(?error, "failed to refresh DAPI address list");
+            }
+
+            tokio::select! {
+                _ = self.cancelled() => return,
+                _ = tokio::time::sleep(interval) => {}
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -641,6 +751,8 @@ impl SdkBuilder {
     ///
     /// For more control over the configuration, use [SdkBuilder::with_wallet()] and [SdkBuilder::with_context_provider()].
     ///
+    /// Dash Core is also used to refresh DAPI addresses from the masternode list, see [Sdk::refresh_address_list()].
+    ///
     /// This is temporary implementation, intended for development purposes.
     pub fn with_core(mut self, ip: &str, port: u16, user: &str, password: &str) -> Self {
         self.core_ip = ip.to_string();
@@ -687,10 +799,19 @@ impl SdkBuilder {
                 #[cfg(feature = "mocks")]
                 let dapi = dapi.dump_dir(self.dump_dir.clone());
 
+                let core_client = if self.core_ip.is_empty() {
+                    None
+                } else {
+                    Some(Arc::new(CoreClient::new(
+                        &self.core_ip, self.core_port, &self.core_user, &self.core_password,
+                    )?))
+                };
+
                 let sdk= Sdk{
                     inner:SdkInstance::Dapi { dapi,  version:self.version },
                     proofs:self.proofs,
                     context_provider: std::sync:: Mutex::new(self.context_provider),
+                    core_client,
                     cancel_token: self.cancel_token,
                     #[cfg(feature = "mocks")]
                     dump_dir: self.dump_dir,
@@ -743,6 +864,7 @@ impl SdkBuilder {
                     proofs:self.proofs,
                     internal_cache: Default::default(),
                     context_provider:  std::sync:: Mutex::new( Some(context_provider)),
+                    core_client: None,
                     cancel_token: self.cancel_token,
                 };
                 Ok(Arc::new(sdk))
@@ -754,3 +876,57 @@ impl SdkBuilder {
         sdk
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_refresh_address_list_requires_core() {
+        let sdk = SdkBuilder::new(AddressList::from("http://127.0.0.1:1"))
+            .build()
+            .expect("expected to build the sdk");
+
+        assert!(matches!(
+            sdk.refresh_address_list().await,
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[cfg(feature = "mocks")]
+    #[tokio::test]
+    async fn test_refresh_address_list_in_mock_mode() {
+        let sdk = SdkBuilder::new_mock()
+            .build()
+            .expect("expected to build the sdk");
+
+        assert!(matches!(
+            sdk.refresh_address_list().await,
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_update_dapi_addresses() {
+        let dapi = DapiClient::new(
+            AddressList::from("http://127.0.0.1:1"),
+            RequestSettings::default(),
+        );
+
+        let count = Sdk::update_dapi_addresses(
+            &dapi,
+            vec![
+                Uri::from_static("https://127.0.0.1:2"),
+                Uri::from_static("https://127.0.0.1:3"),
+            ],
+        )
+        .expect("expected to update addresses");
+
+        assert_eq!(count, 2);
+        assert_eq!(dapi.address_list().read().expect("lock poisoned").len(), 2);
+
+        // An empty masternode list keeps current addresses
+        assert!(Sdk::update_dapi_addresses(&dapi, vec![]).is_err());
+        assert_eq!(dapi.address_list().read().expect("lock poisoned").len(), 2);
+    }
+}
@@ -4,7 +4,7 @@
 use super::common::{mock_data_contract, mock_document_type};
 use dpp::{
     data_contract::{
-        accessors::v0::DataContractV0Getters,
+        accessors::v0::{DataContractV0Getters, DataContractV0Setters},
         document_type::{
             accessors::DocumentTypeV0Getters, random_document::CreateRandomDocument, DocumentType,
         },
@@ -13,8 +13,13 @@ use dpp::{
     identity::{accessors::IdentityGettersV0, IdentityV0},
     prelude::{DataContract, Identifier, Identity},
 };
+use drive::drive::contract::MAX_CONTRACT_HISTORY_FETCH_LIMIT;
+use drive_proof_verifier::types::DataContractHistory;
 use rs_sdk::{
-    platform::{DocumentQuery, Fetch},
+    platform::{
+        types::data_contract::{DataContractEx, DataContractHistoryQuery},
+        DocumentQuery, Fetch, LimitQuery,
+    },
     Sdk,
 };
 
@@ -107,3 +112,49 @@ async fn test_mock_fetch_document() {
 
     assert_eq!(retrieved, expected);
 }
+
+/// Given some data contract updated at block time 2000, when I fetch the data contract as of block time 1500,
+/// I get the version created at block time 1000.
+#[tokio::test]
+async fn test_mock_fetch_data_contract_at_time() {
+    let sdk = Sdk::new_mock();
+
+    let document_type: DocumentType = mock_document_type();
+    let created = mock_data_contract(Some(&document_type));
+    let id = created.id();
+
+    let mut updated = created.clone();
+    updated.set_version(created.version() + 1);
+
+    let history = DataContractHistory::from([(1000, created.clone()), (2000, updated.clone())]);
+
+    let query = LimitQuery {
+        query: DataContractHistoryQuery {
+            id,
+            start_at_ms: 0,
+            offset: Some(0),
+        },
+        limit: Some(MAX_CONTRACT_HISTORY_FETCH_LIMIT as u32),
+    };
+
+    sdk.mock()
+        .expect_fetch(query.clone(), Some(history.clone()))
+        .await;
+
+    let retrieved = DataContractHistory::fetch(&sdk, query)
+        .await
+        .unwrap()
+        .expect("history should exist");
+    assert_eq!(retrieved, history);
+
+    let retrieved = DataContract::fetch_at_time(&sdk, id, 1500)
+        .await
+        .unwrap()
+        .expect("data contract should exist");
+    assert_eq!(retrieved, created);
+
+    let retrieved = DataContract::fetch_at_time(&sdk, id, 500)
+        .await
+        .expect("fetch should succeed");
+    assert!(retrieved.is_none());
+}
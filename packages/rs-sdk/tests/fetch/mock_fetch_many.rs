@@ -9,9 +9,10 @@ use dpp::{
         },
     },
     document::{Document, DocumentV0Getters},
+    prelude::{Identifier, Identity},
 };
 use rs_sdk::{
-    platform::{DocumentQuery, FetchMany},
+    platform::{types::identity::PublicKeyHash, DocumentQuery, FetchMany},
     Sdk,
 };
 
@@ -46,3 +47,49 @@ async fn test_mock_document_fetch_many() {
     assert!(!retrieved.is_empty());
     assert_eq!(retrieved, expected);
 }
+
+/// Given some identity and some random identity ID, when I request both identities, I get the existing identity
+/// and None for the missing one.
+#[tokio::test]
+async fn test_mock_identity_fetch_many() {
+    let sdk = Sdk::new_mock();
+
+    let existing_id = Identifier::random();
+    let missing_id = Identifier::random();
+
+    let expected_identity = Identity::create_basic_identity(existing_id.to_buffer(), sdk.version())
+        .expect("identity should be created");
+    let expected = BTreeMap::from([(existing_id, Some(expected_identity)), (missing_id, None)]);
+
+    let query = vec![existing_id, missing_id];
+    sdk.mock()
+        .expect_fetch_many(query.clone(), Some(expected.clone()))
+        .await;
+
+    let retrieved: BTreeMap<Identifier, Option<Identity>> =
+        Identity::fetch_many(&sdk, query).await.unwrap();
+
+    assert_eq!(retrieved, expected);
+}
+
+/// Given some identity, when I request identities by public key hashes, I get that identity for its key hash.
+#[tokio::test]
+async fn test_mock_identity_fetch_many_by_public_key_hashes() {
+    let sdk = Sdk::new_mock();
+
+    let public_key_hash = [1u8; 20];
+    let expected_identity =
+        Identity::create_basic_identity(Identifier::random().to_buffer(), sdk.version())
+            .expect("identity should be created");
+    let expected = BTreeMap::from([(public_key_hash, Some(expected_identity))]);
+
+    let query = vec![PublicKeyHash(public_key_hash)];
+    sdk.mock()
+        .expect_fetch_many(query.clone(), Some(expected.clone()))
+        .await;
+
+    let retrieved: BTreeMap<[u8; 20], Option<Identity>> =
+        Identity::fetch_many(&sdk, query).await.unwrap();
+
+    assert_eq!(retrieved, expected);
+}
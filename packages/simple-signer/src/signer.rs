@@ -1,7 +1,6 @@
 use anyhow::anyhow;
 use dashcore_rpc::dashcore::signer;
 use dpp::bincode::{Decode, Encode};
-use dpp::ed25519_dalek::Signer as BlsSigner;
 use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
 use dpp::identity::signer::Signer;
 use dpp::identity::{IdentityPublicKey, KeyType};
@@ -9,7 +8,8 @@ use dpp::platform_value::BinaryData;
 use dpp::state_transition::errors::{
     InvalidIdentityPublicKeyTypeError, InvalidSignaturePublicKeyError,
 };
-use dpp::{bls_signatures, ed25519_dalek, ProtocolError};
+use dpp::util::ed25519_signer;
+use dpp::{bls_signatures, ProtocolError};
 use std::collections::HashMap;
 
 /// This simple signer is only to be used in tests
@@ -65,13 +65,8 @@ impl Signer for SimpleSigner {
                 Ok(pk.sign(data).to_bytes().to_vec().into())
             }
             KeyType::EDDSA_25519_HASH160 => {
-                let key: [u8; 32] = private_key.clone().try_into().expect("expected 32 bytes");
-                let pk = ed25519_dalek::SigningKey::try_from(&key).map_err(|_e| {
-                    ProtocolError::Error(anyhow!(
-                        "eddsa 25519 private key from bytes isn't correct"
-                    ))
-                })?;
-                Ok(pk.sign(data).to_vec().into())
+                let signature = ed25519_signer::sign(data, private_key)?;
+                Ok(signature.to_vec().into())
             }
             // the default behavior from
             // https://github.com/dashevo/platform/blob/6b02b26e5cd3a7c877c5fdfe40c4a4385a8dda15/packages/js-dpp/lib/stateTransition/AbstractStateTransition.js#L187
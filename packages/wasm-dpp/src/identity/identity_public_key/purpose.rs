@@ -15,6 +15,8 @@ pub enum PurposeWasm {
     SYSTEM = 4,
     /// this key cannot be used for signing documents
     VOTING = 5,
+    /// this key can only be used by a guardian to recover the identity
+    RECOVERY = 6,
 }
 
 impl From<Purpose> for PurposeWasm {
@@ -26,6 +28,7 @@ impl From<Purpose> for PurposeWasm {
             Purpose::WITHDRAW => PurposeWasm::WITHDRAW,
             Purpose::SYSTEM => PurposeWasm::SYSTEM,
             Purpose::VOTING => PurposeWasm::VOTING,
+            Purpose::RECOVERY => PurposeWasm::RECOVERY,
         }
     }
 }
@@ -118,6 +118,8 @@ impl IdentityCreditTransferTransitionWasm {
 
         let version = match self.0 {
             IdentityCreditTransferTransition::V0(_) => "0",
+            IdentityCreditTransferTransition::V1(_) => "1",
+            IdentityCreditTransferTransition::V2(_) => "2",
         };
 
         js_sys::Reflect::set(&js_object, &"$version".to_owned().into(), &version.into())?;
@@ -189,6 +191,8 @@ impl IdentityCreditTransferTransitionWasm {
 
         let version = match self.0 {
             IdentityCreditTransferTransition::V0(_) => "0",
+            IdentityCreditTransferTransition::V1(_) => "1",
+            IdentityCreditTransferTransition::V2(_) => "2",
         };
 
         js_sys::Reflect::set(&js_object, &"$version".to_owned().into(), &version.into())?;